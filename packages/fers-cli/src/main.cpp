@@ -140,7 +140,7 @@ int main(const int argc, char* argv[])
 
 	// Run the simulation via the C-API
 	LOG(FERS_LOG_INFO, "Starting simulation...");
-	if (fers_run_simulation(context, nullptr, nullptr) != 0)
+	if (fers_run_simulation(context, nullptr, nullptr, nullptr) != 0)
 	{
 		char* err = fers_get_last_error_message();
 		LOG(FERS_LOG_FATAL, "Simulation run failed: {}", err ? err : "Unknown error");
@@ -28,8 +28,13 @@
 
 use std::ffi::{c_void, CStr, CString};
 use std::os::raw::c_char;
+use std::sync::atomic::AtomicBool;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter};
 
+use crate::history::{ScenarioHistory, DEFAULT_HISTORY_DEPTH};
+
 /// Raw FFI bindings generated by `bindgen` from `libfers/api.h`.
 ///
 /// This inner module is kept private to prevent direct access to unsafe FFI
@@ -154,6 +159,8 @@ pub struct FersContext {
     /// context in a Mutex, ensuring that access to the non-thread-safe C++ object
     /// is properly synchronized.
     ptr: *mut ffi::fers_context_t,
+    /// Earlier and undone scenario states, recorded by `update_scenario_from_json`.
+    history: Mutex<ScenarioHistory>,
 }
 
 // SAFETY: The FersContext will be protected by a Mutex. All C-API calls on a single
@@ -211,27 +218,178 @@ fn get_last_error() -> String {
     }
 }
 
-/// Data structure for simulation progress events emitted to the frontend.
-#[derive(serde::Serialize, Clone)]
-struct ProgressPayload {
-    message: String,
-    current: i32,
-    total: i32,
+/// Where a simulation run writes its output files.
+#[derive(Debug, Clone)]
+pub struct OutputLocation {
+    /// An existing directory for the output files.
+    pub directory: String,
+    /// A prefix for every output file name, or empty for none.
+    pub basename: String,
+}
+
+/// How a simulation run ended when it did not fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SimulationOutcome {
+    /// The run reached the end of the scenario and all output was written.
+    Completed,
+    /// The run was stopped early through its cancellation flag.
+    Cancelled,
+}
+
+/// Maps a return code from the simulation entry points to an outcome.
+///
+/// The C-API returns 2 when a run is cancelled and any other non-zero value on failure.
+fn simulation_outcome(result: i32) -> Result<SimulationOutcome, String> {
+    match result {
+        0 => Ok(SimulationOutcome::Completed),
+        2 => Ok(SimulationOutcome::Cancelled),
+        _ => Err(get_last_error()),
+    }
+}
+
+/// The stage of a simulation run that a progress event refers to.
+#[derive(serde::Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SimulationPhase {
+    /// Setting up receivers and finalizer threads.
+    Initializing,
+    /// Advancing the event-driven simulation loop.
+    Simulating,
+    /// Rendering a receiver's signal, interference and noise.
+    Rendering,
+    /// Writing receiver output to HDF5.
+    Exporting,
+    /// The run has finished and all output is written.
+    Complete,
+}
+
+impl SimulationPhase {
+    fn from_ffi(phase: ffi::fers_progress_phase_t) -> Self {
+        match phase {
+            ffi::fers_progress_phase_t_FERS_PROGRESS_INITIALIZING => Self::Initializing,
+            ffi::fers_progress_phase_t_FERS_PROGRESS_RENDERING => Self::Rendering,
+            ffi::fers_progress_phase_t_FERS_PROGRESS_EXPORTING => Self::Exporting,
+            ffi::fers_progress_phase_t_FERS_PROGRESS_COMPLETE => Self::Complete,
+            _ => Self::Simulating,
+        }
+    }
+}
+
+/// Data structure for `simulation-progress` events emitted to the frontend.
+#[derive(serde::Serialize, Clone, Debug)]
+pub struct SimulationProgress {
+    /// The stage of the run.
+    pub phase: SimulationPhase,
+    /// A description of the current operation, e.g. the receiver being rendered.
+    pub message: String,
+    /// The current step of the operation.
+    pub current: i32,
+    /// The total number of steps, or 0 if unknown.
+    pub total: i32,
+    /// Completion of the operation in percent (0-100), or `None` if its total is unknown.
+    pub percent: Option<f64>,
+    /// Seconds since the run started.
+    pub elapsed_seconds: f64,
+    /// Estimated seconds until the simulation loop finishes, extrapolated from its
+    /// progress so far. Only available while the phase is `Simulating`.
+    pub eta_seconds: Option<f64>,
+}
+
+/// Minimum time between two progress events, limiting the IPC traffic to about 10 Hz.
+pub const PROGRESS_EMIT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Accounts elapsed time and throttles progress updates for one simulation run.
+///
+/// Updates arriving within `PROGRESS_EMIT_INTERVAL` of the last emitted one are dropped,
+/// except for phase changes and finished operations, which are always delivered.
+pub struct ProgressTracker {
+    started: Instant,
+    state: Mutex<ProgressTrackerState>,
+}
+
+#[derive(Default)]
+struct ProgressTrackerState {
+    last_emit: Option<Instant>,
+    last_phase: Option<SimulationPhase>,
+    simulating_since: Option<Instant>,
+}
+
+impl ProgressTracker {
+    /// Creates a tracker for a run starting at `started`.
+    pub fn new(started: Instant) -> Self {
+        Self { started, state: Mutex::new(ProgressTrackerState::default()) }
+    }
+
+    /// Builds the event for an update received at `now`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(SimulationProgress)` - If the update should be emitted.
+    /// * `None` - If it falls within the rate limit and can be dropped.
+    pub fn update(
+        &self,
+        phase: SimulationPhase,
+        message: String,
+        current: i32,
+        total: i32,
+        now: Instant,
+    ) -> Option<SimulationProgress> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let phase_changed = state.last_phase != Some(phase);
+        let finished = total > 0 && current >= total;
+        let throttled = state
+            .last_emit
+            .is_some_and(|last| now.saturating_duration_since(last) < PROGRESS_EMIT_INTERVAL);
+        if phase == SimulationPhase::Simulating && state.simulating_since.is_none() {
+            state.simulating_since = Some(now);
+        }
+        if throttled && !phase_changed && !finished {
+            return None;
+        }
+        state.last_emit = Some(now);
+        state.last_phase = Some(phase);
+
+        let fraction = (total > 0).then(|| f64::from(current.clamp(0, total)) / f64::from(total));
+        let eta_seconds = match (phase, fraction, state.simulating_since) {
+            (SimulationPhase::Simulating, Some(done), Some(since)) if done > 0.0 => {
+                let spent = now.saturating_duration_since(since).as_secs_f64();
+                Some(spent * (1.0 - done) / done)
+            }
+            _ => None,
+        };
+        Some(SimulationProgress {
+            phase,
+            message,
+            current,
+            total,
+            percent: fraction.map(|done| done * 100.0),
+            elapsed_seconds: now.saturating_duration_since(self.started).as_secs_f64(),
+            eta_seconds,
+        })
+    }
+}
+
+/// The `user_data` handed to `simulation_progress_callback`.
+struct ProgressSink<'a> {
+    app_handle: &'a AppHandle,
+    tracker: ProgressTracker,
 }
 
 /// The C-style callback function passed to `fers_run_simulation`.
 ///
 /// This function is invoked by the C++ core to report progress. It reconstructs the
-/// `AppHandle` from the `user_data` pointer and emits a Tauri event to the frontend.
+/// `ProgressSink` from the `user_data` pointer and emits a throttled Tauri event to the
+/// frontend.
 ///
 /// # Safety
 ///
 /// This function is marked `unsafe` because it dereferences raw pointers (`message`, `user_data`).
 /// The caller (the C++ library) must guarantee that `message` is a valid, null-terminated
-/// UTF-8 string and that `user_data` is a valid pointer to an `AppHandle`. The pointer
+/// UTF-8 string and that `user_data` is a valid pointer to a `ProgressSink`. The pointer
 /// is only valid for the duration of the `fers_run_simulation` call.
 #[allow(clippy::similar_names)]
 extern "C" fn simulation_progress_callback(
+    phase: ffi::fers_progress_phase_t,
     message: *const c_char,
     current: i32,
     total: i32,
@@ -240,22 +398,66 @@ extern "C" fn simulation_progress_callback(
     if user_data.is_null() {
         return;
     }
-    // SAFETY: This is safe because we know `user_data` is a pointer to the AppHandle,
+    // SAFETY: This is safe because we know `user_data` is a pointer to the ProgressSink,
     // which is guaranteed to be valid for the lifetime of the simulation call.
-    let app_handle = unsafe { &*(user_data as *const AppHandle) };
+    let sink = unsafe { &*(user_data as *const ProgressSink) };
 
     // SAFETY: `message` is guaranteed by the C-API to be a valid, null-terminated string.
     let message_str = unsafe { CStr::from_ptr(message) }.to_string_lossy().into_owned();
 
-    let payload = ProgressPayload { message: message_str, current, total };
+    let Some(payload) = sink.tracker.update(
+        SimulationPhase::from_ffi(phase),
+        message_str,
+        current,
+        total,
+        Instant::now(),
+    ) else {
+        return;
+    };
 
     // Emit the event to the frontend. If this fails, there's little we can do
     // from the callback, so we just let it panic in debug builds.
-    app_handle
+    sink.app_handle
         .emit("simulation-progress", payload)
         .expect("Failed to emit simulation-progress event");
 }
 
+/// The callback that receives finished receive windows from `run_simulation_streaming`.
+///
+/// Its arguments are the receiver name, window start time, fullscale factor and the
+/// interleaved I/Q samples.
+pub type WindowCallback<'a> = &'a (dyn Fn(&str, f64, f64, &[f64]) + Sync);
+
+/// The C-style callback function passed to `fers_run_simulation_streaming`.
+///
+/// # Safety
+///
+/// `user_data` must point to a `WindowCallback` that outlives the simulation call, and
+/// `samples` must hold `2 * sample_count` values for the duration of the call.
+extern "C" fn simulation_window_callback(
+    receiver_name: *const c_char,
+    start_time: f64,
+    fullscale: f64,
+    samples: *const f64,
+    sample_count: usize,
+    user_data: *mut c_void,
+) {
+    if user_data.is_null() || receiver_name.is_null() {
+        return;
+    }
+    // SAFETY: `user_data` points to the `WindowCallback` held by `run_simulation_streaming`.
+    let on_window = unsafe { *(user_data as *const WindowCallback) };
+    // SAFETY: `receiver_name` is guaranteed by the C-API to be a valid, null-terminated string.
+    let receiver = unsafe { CStr::from_ptr(receiver_name) }.to_string_lossy();
+    let samples = if samples.is_null() || sample_count == 0 {
+        &[][..]
+    } else {
+        // SAFETY: The C-API guarantees `2 * sample_count` interleaved I/Q values.
+        unsafe { std::slice::from_raw_parts(samples, sample_count * 2) }
+    };
+    on_window(&receiver, start_time, fullscale, samples);
+}
+
 /// A safe RAII wrapper for the antenna pattern data returned by the C-API.
 struct FersAntennaPatternData(*mut ffi::fers_antenna_pattern_data_t);
 impl Drop for FersAntennaPatternData {
@@ -274,13 +476,13 @@ impl Drop for FersAntennaPatternData {
 pub struct AntennaPatternData {
     /// Flattened array of linear gain values (normalized 0.0 to 1.0).
     /// Ordered row-major: Elevation rows, then Azimuth columns.
-    gains: Vec<f64>,
+    pub gains: Vec<f64>,
     /// Number of samples along the azimuth axis (360 degrees).
-    az_count: usize,
+    pub az_count: usize,
     /// Number of samples along the elevation axis (180 degrees).
-    el_count: usize,
+    pub el_count: usize,
     /// The peak linear gain found in the pattern, used for normalization.
-    max_gain: f64,
+    pub max_gain: f64,
 }
 
 // Helper wrapper for the visual link list
@@ -319,335 +521,2795 @@ pub struct VisualLink {
     pub origin_name: String,
 }
 
-impl FersContext {
-    /// Creates a new `FersContext` by calling the C-API constructor.
-    ///
-    /// This function allocates a new C++ `FersContext` object on the heap via
-    /// `fers_context_create`. The returned context is initially empty and must be
-    /// populated by loading a scenario (via `load_scenario_from_xml_file` or
-    /// `update_scenario_from_json`).
-    ///
-    /// # Returns
-    ///
-    /// * `Some(FersContext)` - If the context was successfully created.
-    /// * `None` - If allocation failed (e.g., out of memory) or if the C++ constructor
-    ///   threw an exception.
-    ///
-    /// # Example
-    ///
-    /// ```no_run
-    /// let context = FersContext::new().expect("Failed to create FERS context");
-    /// ```
-    pub fn new() -> Option<Self> {
-        // SAFETY: `fers_context_create` is a simple constructor function with no preconditions.
-        let ptr = unsafe { ffi::fers_context_create() };
-        if ptr.is_null() {
-            None
-        } else {
-            Some(Self { ptr })
+/// A safe RAII wrapper for a platform track list returned by the C-API.
+struct FersPlatformTrackList(*mut ffi::fers_platform_track_list_t);
+
+impl Drop for FersPlatformTrackList {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            // SAFETY: The pointer is valid and owned by this struct.
+            unsafe { ffi::fers_free_platform_tracks(self.0) };
         }
     }
+}
 
-    /// Loads a FERS scenario from an XML file into the context.
-    ///
-    /// This method replaces any existing scenario in the context with the one parsed
-    /// from the specified file. The XML is validated against the FERS schema if
-    /// validation is enabled in the C++ library.
-    ///
-    /// # Parameters
-    ///
-    /// * `filepath` - A UTF-8 string containing the absolute or relative path to the
-    ///   FERS XML scenario file.
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(())` - If the scenario was successfully loaded and parsed.
-    /// * `Err(String)` - If the file could not be read, the XML was invalid, or a
-    ///   C++ exception was thrown. The error string contains details.
-    ///
-    /// # Example
-    ///
-    /// ```no_run
-    /// context.load_scenario_from_xml_file("/path/to/scenario.xml")?;
-    /// ```
-    pub fn load_scenario_from_xml_file(&self, filepath: &str) -> Result<(), String> {
-        let c_filepath = CString::new(filepath).map_err(|e| e.to_string())?;
-        // SAFETY: We pass a valid context pointer and a null-terminated C string.
-        // The function returns 0 on success.
-        let result =
-            unsafe { ffi::fers_load_scenario_from_xml_file(self.ptr, c_filepath.as_ptr(), 1) };
-        if result == 0 {
-            Ok(())
-        } else {
-            Err(get_last_error())
+/// The role of a platform, derived from the components it carries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrackKind {
+    /// No radar components or targets.
+    None,
+    /// Carries at least one transmitter.
+    Transmitter,
+    /// Carries receivers only.
+    Receiver,
+    /// Carries a monostatic radar.
+    Monostatic,
+    /// Carries targets only.
+    Target,
+}
+
+/// The sampled trajectory of a single platform.
+pub struct PlatformTrack {
+    /// The name of the platform.
+    pub name: String,
+    /// The role of the platform.
+    pub kind: TrackKind,
+    /// Samples evenly spaced over the simulation time.
+    pub points: Vec<crate::InterpolatedMotionPoint>,
+}
+
+/// A safe RAII wrapper for an interference report returned by the C-API.
+struct FersInterferenceReport(*mut ffi::fers_interference_report_t);
+
+impl Drop for FersInterferenceReport {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            // SAFETY: The pointer is valid and owned by this struct.
+            unsafe { ffi::fers_free_interference_report(self.0) };
         }
     }
+}
 
-    /// Retrieves the current in-memory scenario as a JSON string.
-    ///
-    /// This method serializes the C++ `World` object into JSON format, which mirrors
-    /// the structure used by the frontend. It is typically used to populate the UI
-    /// after loading a scenario from XML.
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(String)` - The JSON representation of the scenario.
-    /// * `Err(String)` - If serialization failed or the JSON contains invalid UTF-8.
-    ///
-    /// # Memory Management
-    ///
-    /// The returned string is a Rust-owned `String`. The underlying C-allocated memory
-    /// is automatically freed by the `FersOwnedString` wrapper.
-    ///
-    /// # Example
-    ///
-    /// ```no_run
-    /// let json = context.get_scenario_as_json()?;
-    /// let scenario: serde_json::Value = serde_json::from_str(&json)?;
-    /// ```
-    pub fn get_scenario_as_json(&self) -> Result<String, String> {
-        // SAFETY: We pass a valid context pointer. The function returns a C string
-        // that we must free.
-        let json_ptr = unsafe { ffi::fers_get_scenario_as_json(self.ptr) };
-        if json_ptr.is_null() {
-            return Err(get_last_error());
+/// A safe RAII wrapper for a spectrum occupancy series returned by the C-API.
+struct FersSpectrumOccupancy(*mut ffi::fers_spectrum_occupancy_t);
+
+impl Drop for FersSpectrumOccupancy {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            // SAFETY: The pointer is valid and owned by this struct.
+            unsafe { ffi::fers_free_spectrum_occupancy(self.0) };
         }
-        // FersOwnedString takes ownership and will free the memory on drop.
-        FersOwnedString(json_ptr).into_string().map_err(|e| e.to_string())
     }
+}
 
-    /// Retrieves the current in-memory scenario as a FERS XML string.
-    ///
-    /// This method serializes the C++ `World` object into the standard FERS XML format.
-    /// It is typically used when the user wants to export the scenario (potentially
-    /// modified in the UI) back to a file.
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(String)` - The XML representation of the scenario.
-    /// * `Err(String)` - If serialization failed or the XML contains invalid UTF-8.
-    ///
-    /// # Memory Management
-    ///
-    /// The returned string is a Rust-owned `String`. The underlying C-allocated memory
-    /// is automatically freed by the `FersOwnedString` wrapper.
-    ///
-    /// # Example
-    ///
-    /// ```no_run
-    /// let xml = context.get_scenario_as_xml()?;
-    /// std::fs::write("exported_scenario.xml", xml)?;
-    /// ```
-    pub fn get_scenario_as_xml(&self) -> Result<String, String> {
-        // SAFETY: We pass a valid context pointer. The function returns a C string
-        // that we must free.
-        let xml_ptr = unsafe { ffi::fers_get_scenario_as_xml(self.ptr) };
-        if xml_ptr.is_null() {
-            return Err(get_last_error());
+/// A safe RAII wrapper for a list of blanked intervals returned by the C-API.
+struct FersBlankedIntervalList(*mut ffi::fers_blanked_interval_list_t);
+
+impl Drop for FersBlankedIntervalList {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            // SAFETY: The pointer is valid and owned by this struct.
+            unsafe { ffi::fers_free_blanked_intervals(self.0) };
         }
-        // FersOwnedString takes ownership and will free the memory on drop.
-        FersOwnedString(xml_ptr).into_string().map_err(|e| e.to_string())
     }
+}
 
-    /// Updates the in-memory scenario from a JSON string.
-    ///
-    /// This method is the primary way for the UI to push modified scenario data back
-    /// to the C++ simulation engine. It deserializes the JSON and rebuilds the internal
-    /// `World` object, replacing any existing scenario.
-    ///
-    /// # Parameters
-    ///
-    /// * `json` - A UTF-8 JSON string representing the scenario. The structure must
-    ///   match the schema expected by `libfers` (the same format returned by
-    ///   `get_scenario_as_json`).
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(())` - If the scenario was successfully deserialized and loaded.
-    /// * `Err(String)` - If the JSON was malformed, contained invalid data, or a C++
-    ///   exception was thrown. The error string contains details.
-    ///
-    /// # Example
-    ///
-    /// ```no_run
-    /// let modified_json = /* JSON from UI */;
-    /// context.update_scenario_from_json(&modified_json)?;
-    /// ```
-    pub fn update_scenario_from_json(&self, json: &str) -> Result<(), String> {
-        let c_json = CString::new(json).map_err(|e| e.to_string())?;
-        // SAFETY: We pass a valid context pointer and a null-terminated C string.
-        // The function returns 0 on success.
-        let result = unsafe { ffi::fers_update_scenario_from_json(self.ptr, c_json.as_ptr()) };
-        if result == 0 {
-            Ok(())
-        } else {
-            Err(get_last_error())
+/// A safe RAII wrapper for a time-division schedule returned by the C-API.
+struct FersTdmSchedule(*mut ffi::fers_tdm_schedule_t);
+
+impl Drop for FersTdmSchedule {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            // SAFETY: The pointer is valid and owned by this struct.
+            unsafe { ffi::fers_free_tdm_schedule(self.0) };
         }
     }
+}
 
-    /// Runs the simulation defined in the context.
-    ///
-    /// This is a blocking call that executes the simulation on a separate thread pool
-    /// managed by the C++ core. It accepts a Tauri `AppHandle` to enable progress
-    /// reporting via events.
-    ///
-    /// # Parameters
-    ///
-    /// * `app_handle` - A reference to the Tauri application handle, used for emitting events.
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(())` - If the simulation completed successfully.
-    /// * `Err(String)` - If the simulation failed.
-    pub fn run_simulation(&self, app_handle: &AppHandle) -> Result<(), String> {
-        // The AppHandle is passed as a raw pointer through the `user_data` argument.
-        // This is safe because this function is blocking, and the app_handle reference
-        // will be valid for the entire duration of the C++ call.
-        let user_data_ptr = app_handle as *const _ as *mut c_void;
-
-        // SAFETY: We pass a valid context pointer, a valid function pointer for the callback,
-        // and a valid user_data pointer that points to the AppHandle.
-        let result = unsafe {
-            ffi::fers_run_simulation(self.ptr, Some(simulation_progress_callback), user_data_ptr)
-        };
+/// A safe RAII wrapper for a blind zone coverage report returned by the C-API.
+struct FersBlindZoneCoverage(*mut ffi::fers_blind_zone_coverage_t);
 
-        if result == 0 {
-            Ok(())
-        } else {
-            Err(get_last_error())
+impl Drop for FersBlindZoneCoverage {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            // SAFETY: The pointer is valid and owned by this struct.
+            unsafe { ffi::fers_free_blind_zone_coverage(self.0) };
         }
     }
+}
 
-    /// Generates a KML file for the current scenario.
+/// A safe RAII wrapper for a grating lobe report returned by the C-API.
+struct FersGratingLobeReport(*mut ffi::fers_grating_lobe_report_t);
+
+impl Drop for FersGratingLobeReport {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            // SAFETY: The pointer is valid and owned by this struct.
+            unsafe { ffi::fers_free_grating_lobe_report(self.0) };
+        }
+    }
+}
+
+/// A safe RAII wrapper for a pulse collision list returned by the C-API.
+struct FersPulseCollisionList(*mut ffi::fers_pulse_collision_list_t);
+
+impl Drop for FersPulseCollisionList {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            // SAFETY: The pointer is valid and owned by this struct.
+            unsafe { ffi::fers_free_pulse_collisions(self.0) };
+        }
+    }
+}
+
+/// A safe RAII wrapper for a phase-noise series returned by the C-API.
+struct FersPhaseNoiseSeries(*mut ffi::fers_phase_noise_series_t);
+
+impl Drop for FersPhaseNoiseSeries {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            // SAFETY: The pointer is valid and owned by this struct.
+            unsafe { ffi::fers_free_phase_noise_series(self.0) };
+        }
+    }
+}
+
+/// A safe RAII wrapper for a received-pulse preview returned by the C-API.
+struct FersReceivedPulse(*mut ffi::fers_received_pulse_t);
+
+impl Drop for FersReceivedPulse {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            // SAFETY: The pointer is valid and owned by this struct.
+            unsafe { ffi::fers_free_received_pulse(self.0) };
+        }
+    }
+}
+
+/// A safe RAII wrapper for beamforming weights returned by the C-API.
+struct FersBeamformingWeights(*mut ffi::fers_beamforming_weights_t);
+
+impl Drop for FersBeamformingWeights {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            // SAFETY: The pointer is valid and owned by this struct.
+            unsafe { ffi::fers_free_beamforming_weights(self.0) };
+        }
+    }
+}
+
+/// A safe RAII wrapper for an XML validation outcome returned by the C-API.
+struct FersXmlValidation(*mut ffi::fers_xml_validation_t);
+
+impl Drop for FersXmlValidation {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            // SAFETY: The pointer is valid and owned by this struct.
+            unsafe { ffi::fers_free_xml_validation(self.0) };
+        }
+    }
+}
+
+/// A safe RAII wrapper for a string list returned by the C-API.
+struct FersStringList(*mut ffi::fers_string_list_t);
+
+impl Drop for FersStringList {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            // SAFETY: The pointer is valid and owned by this struct.
+            unsafe { ffi::fers_free_string_list(self.0) };
+        }
+    }
+}
+
+impl FersStringList {
+    /// Copies the strings into owned Rust strings.
+    fn to_vec(&self) -> Vec<String> {
+        // SAFETY: The pointer is non-null and valid for the lifetime of `self`.
+        let list = unsafe { &*self.0 };
+        if list.count == 0 || list.items.is_null() {
+            return Vec::new();
+        }
+        // SAFETY: `items` points to `count` valid null-terminated strings.
+        unsafe { std::slice::from_raw_parts(list.items, list.count) }
+            .iter()
+            .map(|&item| {
+                // SAFETY: Each entry is a valid null-terminated string owned by the C-API.
+                unsafe { CStr::from_ptr(item) }.to_string_lossy().into_owned()
+            })
+            .collect()
+    }
+}
+
+/// A safe RAII wrapper for a range interval list returned by the C-API.
+struct FersRangeIntervalList(*mut ffi::fers_range_interval_list_t);
+
+impl Drop for FersRangeIntervalList {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            // SAFETY: The pointer is valid and owned by this struct.
+            unsafe { ffi::fers_free_range_intervals(self.0) };
+        }
+    }
+}
+
+/// The direct-path power received from a single interfering emitter.
+#[derive(serde::Serialize)]
+pub struct InterferenceContribution {
+    /// The name of the interfering transmitter.
+    pub transmitter: String,
+    /// The received power in watts.
+    pub power: f64,
+}
+
+/// The aggregate interference seen by a receiver at a single instant.
+#[derive(serde::Serialize)]
+pub struct InterferenceReport {
+    /// The sum of all contributions in watts.
+    pub total_power: f64,
+    /// The equivalent interference temperature in Kelvin.
+    pub temperature: f64,
+    /// The per-emitter contributions.
+    pub contributions: Vec<InterferenceContribution>,
+}
+
+/// The grating lobes of an array antenna at a given scan angle.
+#[derive(serde::Serialize)]
+pub struct GratingLobeReport {
+    /// The wavelength used, in meters.
+    pub wavelength: f64,
+    /// The element spacing in wavelengths.
+    pub spacing_wavelengths: f64,
+    /// Whether any grating lobe falls in visible space.
+    pub has_grating_lobes: bool,
+    /// The grating lobe azimuths relative to boresight in degrees, in ascending order.
+    pub lobe_angles_deg: Vec<f64>,
+}
+
+/// Two pulses from different transmitters that overlap in time at a receiver.
+#[derive(serde::Serialize, Debug)]
+pub struct PulseCollision {
+    /// The transmitter whose pulse arrives first.
+    pub first_transmitter: String,
+    /// The index of that pulse among its transmitter's pulses.
+    pub first_pulse_index: usize,
+    /// The transmitter whose pulse arrives second.
+    pub second_transmitter: String,
+    /// The index of that pulse among its transmitter's pulses.
+    pub second_pulse_index: usize,
+    /// The start of the overlap at the receiver in seconds.
+    pub overlap_start: f64,
+    /// The end of the overlap at the receiver in seconds.
+    pub overlap_end: f64,
+}
+
+/// The blind ranges and blind speeds of a radar operating at a single PRF.
+#[derive(serde::Serialize)]
+pub struct PrfBlindZones {
+    /// The pulse repetition frequency in Hz.
+    pub prf: f64,
+    /// The eclipsed `(range_start, range_end)` intervals in meters.
+    pub blind_ranges: Vec<(f64, f64)>,
+    /// The non-zero blind radial speeds in m/s.
+    pub blind_speeds: Vec<f64>,
+}
+
+/// The combined range and velocity blind zones of a staggered PRF set.
+#[derive(serde::Serialize)]
+pub struct BlindZoneReport {
+    /// The blind zones of each PRF, in the order given.
+    pub prfs: Vec<PrfBlindZones>,
+    /// The range intervals in meters that are blind at every PRF.
+    pub unrecoverable_ranges: Vec<(f64, f64)>,
+    /// The range intervals in meters that are blind at some, but not all, PRFs.
+    pub recoverable_ranges: Vec<(f64, f64)>,
+    /// The speeds in m/s that are blind at every PRF.
+    pub unrecoverable_speeds: Vec<f64>,
+    /// The speeds in m/s that are blind at some, but not all, PRFs.
+    pub recoverable_speeds: Vec<f64>,
+    /// The end of the evaluated range span in meters.
+    pub max_range: f64,
+    /// The end of the evaluated speed span in m/s.
+    pub max_speed: f64,
+}
+
+/// The outcome of checking a scenario, either an XML file or the loaded state.
+#[derive(serde::Serialize)]
+pub struct ValidationResult {
+    /// Whether the scenario would load successfully.
+    pub valid: bool,
+    /// The errors that prevent the scenario from loading.
+    pub errors: Vec<String>,
+    /// The warnings raised while parsing the scenario.
+    pub warnings: Vec<String>,
+}
+
+/// Position error statistics between a platform path and a reference trajectory.
+#[derive(serde::Serialize)]
+pub struct TrajectoryError {
+    /// The root-mean-square position error in meters.
+    pub rms_error: f64,
+    /// The maximum position error in meters.
+    pub max_error: f64,
+    /// The start of the compared time span in seconds.
+    pub start_time: f64,
+    /// The end of the compared time span in seconds.
+    pub end_time: f64,
+    /// The number of matched time samples.
+    pub sample_count: usize,
+}
+
+/// Synthetic aperture geometry and the resulting SAR image resolution.
+#[derive(serde::Serialize)]
+pub struct SarResolution {
+    /// The distance travelled by the phase centre during illumination in meters.
+    pub synthetic_aperture: f64,
+    /// The phase centre to target range at mid-illumination in meters.
+    pub slant_range: f64,
+    /// The azimuth resolution in meters.
+    pub cross_range_resolution: f64,
+    /// The slant range resolution in meters.
+    pub range_resolution: f64,
+    /// The start of the illumination interval in seconds.
+    pub start_time: f64,
+    /// The end of the illumination interval in seconds.
+    pub end_time: f64,
+}
+
+/// Dimensions of the radar resolution cell at a target.
+#[derive(serde::Serialize)]
+pub struct ResolutionCell {
+    /// The slant range resolution in meters.
+    pub range_resolution: f64,
+    /// The cross-range extent in the azimuth plane in meters.
+    pub azimuth_extent: f64,
+    /// The cross-range extent in the elevation plane in meters.
+    pub elevation_extent: f64,
+    /// The cell volume in cubic meters.
+    pub volume: f64,
+}
+
+/// The number of items of each kind added by a scenario merge.
+#[derive(serde::Serialize)]
+pub struct MergeSummary {
+    /// The number of waveforms added.
+    pub waveforms: usize,
+    /// The number of timing sources added.
+    pub timings: usize,
+    /// The number of antennas added.
+    pub antennas: usize,
+    /// The number of platforms added.
+    pub platforms: usize,
+}
+
+/// The header row of the debug trace CSV written by the engine.
+const DEBUG_TRACE_HEADER: &str =
+    "pulse_index,transmitter,receiver,target,emission_time,delay,power_gain,phase,doppler";
+
+/// The propagation of one transmitted pulse along one path, as written to the debug trace.
+#[derive(serde::Serialize, Debug, PartialEq)]
+pub struct DebugTraceRecord {
+    /// The index of the pulse among those emitted by its transmitter.
+    pub pulse_index: usize,
+    /// The name of the transmitter.
+    pub transmitter: String,
+    /// The name of the receiver.
+    pub receiver: String,
+    /// The name of the reflecting target, or `None` for the direct path.
+    pub target: Option<String>,
+    /// The emission time of the pulse in seconds.
+    pub emission_time: f64,
+    /// The propagation delay at the start of the pulse in seconds.
+    pub delay: f64,
+    /// The power gain relative to the transmitted power.
+    pub power_gain: f64,
+    /// The propagation phase at the start of the pulse in radians.
+    pub phase: f64,
+    /// The Doppler shift over the pulse in Hz.
+    pub doppler: f64,
+}
+
+/// The contents of a debug trace file written by a simulation run.
+#[derive(serde::Serialize, Debug)]
+pub struct DebugTrace {
+    /// Every traced pulse path, in emission order.
+    pub records: Vec<DebugTraceRecord>,
+}
+
+/// The terms of the two-way radar equation for one transmitter, target and receiver.
+#[derive(serde::Serialize)]
+pub struct LinkBudget {
+    /// The waveform power in dBW.
+    pub transmit_power_dbw: f64,
+    /// The transmit antenna gain towards the target in dBi.
+    pub tx_gain_dbi: f64,
+    /// The receive antenna gain towards the target, with calibration, in dBi.
+    pub rx_gain_dbi: f64,
+    /// The target RCS for the transmit and receive directions in dBsm.
+    pub rcs_dbsm: f64,
+    /// The two-way spreading loss in dB.
+    pub path_loss_db: f64,
+    /// The echo power at the receiver in dBW.
+    pub received_power_dbw: f64,
+    /// The receiver thermal noise kTB in dBW.
+    pub noise_power_dbw: f64,
+    /// The single-pulse (or single-sample, for CW) SNR in dB.
+    pub snr_db: f64,
+    /// The coherent integration gain over the simulated span in dB.
+    pub integration_gain_db: f64,
+    /// The SNR after integration in dB.
+    pub integrated_snr_db: f64,
+}
+
+/// The rate at which a simulation run writes receiver samples to its output files.
+#[derive(serde::Serialize)]
+pub struct DataRateReport {
+    /// The output rate while every active receiver is recording, in bytes per second.
+    pub peak_bytes_per_second: f64,
+    /// The output rate averaged over the simulation span, in bytes per second.
+    pub average_bytes_per_second: f64,
+    /// The number of receivers that record during the simulation span.
+    pub active_receivers: usize,
+}
+
+/// The coherent processing interval needed for a velocity resolution, against a receiver's dwell.
+#[derive(serde::Serialize)]
+pub struct IntegrationTimeReport {
+    /// The coherent processing interval in seconds.
+    pub required_time: f64,
+    /// The longest continuous span the receiver records, in seconds.
+    pub available_dwell: f64,
+    /// Whether the available dwell covers the required interval.
+    pub sufficient: bool,
+}
+
+/// Swerling target fluctuation cases.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SwerlingCase {
+    /// Non-fluctuating target.
+    Swerling0,
+    /// Exponential RCS, constant over the integrated pulses.
+    Swerling1,
+    /// Exponential RCS, independent from pulse to pulse.
+    Swerling2,
+    /// Chi-square (4 DOF) RCS, constant over the integrated pulses.
+    Swerling3,
+    /// Chi-square (4 DOF) RCS, independent from pulse to pulse.
+    Swerling4,
+}
+
+impl std::str::FromStr for SwerlingCase {
+    type Err = String;
+
+    /// Parses a case number `"0"` to `"4"`, optionally prefixed with `"swerling"`
+    /// (case-insensitive), e.g. `"1"`, `"Swerling 1"` or `"swerling1"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.trim().to_ascii_lowercase();
+        match lower.strip_prefix("swerling").unwrap_or(&lower).trim() {
+            "0" => Ok(Self::Swerling0),
+            "1" => Ok(Self::Swerling1),
+            "2" => Ok(Self::Swerling2),
+            "3" => Ok(Self::Swerling3),
+            "4" => Ok(Self::Swerling4),
+            _ => Err(format!("Unknown Swerling case '{s}'; expected 0, 1, 2, 3 or 4")),
+        }
+    }
+}
+
+/// Sample differences between a simulation output file and a reference file.
+#[derive(serde::Serialize)]
+pub struct ComparisonReport {
+    /// The largest magnitude of a complex sample difference.
+    pub max_difference: f64,
+    /// The root-mean-square magnitude of the complex sample differences.
+    pub rms_difference: f64,
+    /// The number of I/Q samples compared.
+    pub sample_count: usize,
+    /// Whether `max_difference` is within the requested tolerance.
+    pub within_tolerance: bool,
+}
+
+/// One aspect of a target's RCS pattern.
+#[derive(serde::Serialize)]
+pub struct RcsSample {
+    /// The aspect azimuth in the target body frame in degrees.
+    pub azimuth: f64,
+    /// The aspect elevation in the target body frame in degrees.
+    pub elevation: f64,
+    /// The RCS in square meters.
+    pub value: f64,
+}
+
+/// The RCS pattern of a target description file over its sampled aspects.
+#[derive(serde::Serialize)]
+pub struct RcsPreview {
+    /// One sample per azimuth and elevation pair, elevation-major.
+    pub samples: Vec<RcsSample>,
+    /// The smallest RCS in square meters.
+    pub min: f64,
+    /// The largest RCS in square meters.
+    pub max: f64,
+}
+
+/// A safe RAII wrapper for an RCS preview returned by the C-API.
+struct FersRcsPreview(*mut ffi::fers_rcs_preview_t);
+
+impl Drop for FersRcsPreview {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            // SAFETY: The pointer is valid and owned by this struct.
+            unsafe { ffi::fers_free_rcs_preview(self.0) };
+        }
+    }
+}
+
+/// A safe RAII wrapper for a polyline list returned by the C-API.
+struct FersPolylineList(*mut ffi::fers_polyline_list_t);
+
+impl Drop for FersPolylineList {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            // SAFETY: The pointer is valid and owned by this struct.
+            unsafe { ffi::fers_free_polyline_list(self.0) };
+        }
+    }
+}
+
+impl FersPolylineList {
+    /// Copies the C-allocated polylines into Rust vectors of `[x, y]` points.
+    fn to_vec(&self) -> Vec<Vec<[f64; 2]>> {
+        // SAFETY: The pointer is non-null and valid for the lifetime of `self`.
+        let (polylines_ptr, count) = unsafe { ((*self.0).polylines, (*self.0).count) };
+        if count == 0 || polylines_ptr.is_null() {
+            return Vec::new();
+        }
+        // SAFETY: `polylines_ptr` points to `count` initialized polylines.
+        let polylines = unsafe { std::slice::from_raw_parts(polylines_ptr, count) };
+        polylines
+            .iter()
+            .map(|line| {
+                if line.count == 0 || line.points.is_null() {
+                    return Vec::new();
+                }
+                // SAFETY: Each polyline owns `count` initialized points.
+                let points = unsafe { std::slice::from_raw_parts(line.points, line.count) };
+                points.iter().map(|p| [p.x, p.y]).collect()
+            })
+            .collect()
+    }
+}
+
+/// Converts a frontend `CoverageGrid` into the C-API grid description.
+fn to_ffi_grid(grid: &crate::CoverageGrid) -> ffi::fers_ground_grid_t {
+    ffi::fers_ground_grid_t {
+        x_min: grid.x_min,
+        x_max: grid.x_max,
+        y_min: grid.y_min,
+        y_max: grid.y_max,
+        z: grid.z,
+        nx: grid.nx,
+        ny: grid.ny,
+    }
+}
+
+impl FersContext {
+    /// Creates a new `FersContext` by calling the C-API constructor.
+    ///
+    /// This function allocates a new C++ `FersContext` object on the heap via
+    /// `fers_context_create`. The returned context is initially empty and must be
+    /// populated by loading a scenario (via `load_scenario_from_xml_file` or
+    /// `update_scenario_from_json`).
+    ///
+    /// # Returns
+    ///
+    /// * `Some(FersContext)` - If the context was successfully created.
+    /// * `None` - If allocation failed (e.g., out of memory) or if the C++ constructor
+    ///   threw an exception.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let context = FersContext::new().expect("Failed to create FERS context");
+    /// ```
+    pub fn new() -> Option<Self> {
+        // SAFETY: `fers_context_create` is a simple constructor function with no preconditions.
+        let ptr = unsafe { ffi::fers_context_create() };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(Self { ptr, history: Mutex::new(ScenarioHistory::new(DEFAULT_HISTORY_DEPTH)) })
+        }
+    }
+
+    /// Creates a separate context holding a copy of the scenario reduced to some platforms.
+    ///
+    /// The copy keeps only the named platforms and the waveforms, timings and antennas
+    /// they use; this context is left unchanged. Running the copy simulates just those
+    /// platforms.
+    ///
+    /// # Parameters
+    ///
+    /// * `platform_names` - The names of the platforms to keep.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FersContext)` - The new context, with an empty undo history.
+    /// * `Err(String)` - If a name matches no platform or the selection lacks a receiver
+    ///   or a transmitter (a monostatic radar counts as both).
+    pub fn subset(&self, platform_names: &[String]) -> Result<FersContext, String> {
+        let c_names = platform_names
+            .iter()
+            .map(|name| CString::new(name.as_str()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+        let name_ptrs: Vec<*const c_char> = c_names.iter().map(|name| name.as_ptr()).collect();
+        // SAFETY: We pass a valid context pointer and an array of `name_ptrs.len()`
+        // null-terminated strings that outlive the call. The returned context is owned by us.
+        let ptr = unsafe {
+            ffi::fers_context_create_subset(self.ptr, name_ptrs.as_ptr(), name_ptrs.len())
+        };
+        if ptr.is_null() {
+            return Err(get_last_error());
+        }
+        Ok(Self { ptr, history: Mutex::new(ScenarioHistory::new(DEFAULT_HISTORY_DEPTH)) })
+    }
+
+    /// Loads a FERS scenario from an XML file into the context.
+    ///
+    /// This method replaces any existing scenario in the context with the one parsed
+    /// from the specified file. The XML is validated against the FERS schema if
+    /// validation is enabled in the C++ library.
+    ///
+    /// # Parameters
+    ///
+    /// * `filepath` - A UTF-8 string containing the absolute or relative path to the
+    ///   FERS XML scenario file.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the scenario was successfully loaded and parsed.
+    /// * `Err(String)` - If the file could not be read, the XML was invalid, or a
+    ///   C++ exception was thrown. The error string contains details.
+    ///
+    /// The undo/redo history is cleared, as it belongs to the replaced scenario.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// context.load_scenario_from_xml_file("/path/to/scenario.xml")?;
+    /// ```
+    pub fn load_scenario_from_xml_file(&self, filepath: &str) -> Result<(), String> {
+        let c_filepath = CString::new(filepath).map_err(|e| e.to_string())?;
+        // SAFETY: We pass a valid context pointer and a null-terminated C string.
+        // The function returns 0 on success.
+        let result =
+            unsafe { ffi::fers_load_scenario_from_xml_file(self.ptr, c_filepath.as_ptr(), 1) };
+        if result == 0 {
+            self.history.lock().map_err(|e| e.to_string())?.clear();
+            Ok(())
+        } else {
+            Err(get_last_error())
+        }
+    }
+
+    /// Loads a scenario from a JSON file in the layout of `get_scenario_as_json`.
+    ///
+    /// # Parameters
+    ///
+    /// * `filepath` - The path of the JSON scenario file.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the scenario was loaded.
+    /// * `Err(String)` - If the file could not be read or the JSON does not describe a
+    ///   scenario. Deserialization errors name the path of the offending field, e.g.
+    ///   `simulation.platforms[0].name`.
+    ///
+    /// The undo/redo history is cleared, as it belongs to the replaced scenario.
+    pub fn load_scenario_from_json_file(&self, filepath: &str) -> Result<(), String> {
+        let json = std::fs::read_to_string(filepath)
+            .map_err(|e| format!("Failed to read scenario '{filepath}': {e}"))?;
+        self.apply_scenario_json(&json)?;
+        self.history.lock().map_err(|e| e.to_string())?.clear();
+        Ok(())
+    }
+
+    /// Writes the current scenario to a file as JSON.
+    ///
+    /// # Parameters
+    ///
+    /// * `filepath` - The path of the JSON file to create or overwrite.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the file was written.
+    /// * `Err(String)` - If the scenario could not be serialized or the file written.
+    pub fn export_scenario_as_json_file(&self, filepath: &str) -> Result<(), String> {
+        let json = self.get_scenario_as_json()?;
+        std::fs::write(filepath, json)
+            .map_err(|e| format!("Failed to write scenario '{filepath}': {e}"))
+    }
+
+    /// Retrieves the current in-memory scenario as a JSON string.
+    ///
+    /// This method serializes the C++ `World` object into JSON format, which mirrors
+    /// the structure used by the frontend. It is typically used to populate the UI
+    /// after loading a scenario from XML.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The JSON representation of the scenario.
+    /// * `Err(String)` - If serialization failed or the JSON contains invalid UTF-8.
+    ///
+    /// # Memory Management
+    ///
+    /// The returned string is a Rust-owned `String`. The underlying C-allocated memory
+    /// is automatically freed by the `FersOwnedString` wrapper.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let json = context.get_scenario_as_json()?;
+    /// let scenario: serde_json::Value = serde_json::from_str(&json)?;
+    /// ```
+    pub fn get_scenario_as_json(&self) -> Result<String, String> {
+        // SAFETY: We pass a valid context pointer. The function returns a C string
+        // that we must free.
+        let json_ptr = unsafe { ffi::fers_get_scenario_as_json(self.ptr) };
+        if json_ptr.is_null() {
+            return Err(get_last_error());
+        }
+        // FersOwnedString takes ownership and will free the memory on drop.
+        FersOwnedString(json_ptr).into_string().map_err(|e| e.to_string())
+    }
+
+    /// Retrieves the current in-memory scenario as a FERS XML string.
+    ///
+    /// This method serializes the C++ `World` object into the standard FERS XML format.
+    /// It is typically used when the user wants to export the scenario (potentially
+    /// modified in the UI) back to a file.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The XML representation of the scenario.
+    /// * `Err(String)` - If serialization failed or the XML contains invalid UTF-8.
+    ///
+    /// # Memory Management
+    ///
+    /// The returned string is a Rust-owned `String`. The underlying C-allocated memory
+    /// is automatically freed by the `FersOwnedString` wrapper.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let xml = context.get_scenario_as_xml()?;
+    /// std::fs::write("exported_scenario.xml", xml)?;
+    /// ```
+    pub fn get_scenario_as_xml(&self) -> Result<String, String> {
+        // SAFETY: We pass a valid context pointer. The function returns a C string
+        // that we must free.
+        let xml_ptr = unsafe { ffi::fers_get_scenario_as_xml(self.ptr) };
+        if xml_ptr.is_null() {
+            return Err(get_last_error());
+        }
+        // FersOwnedString takes ownership and will free the memory on drop.
+        FersOwnedString(xml_ptr).into_string().map_err(|e| e.to_string())
+    }
+
+    /// Retrieves the current in-memory scenario as FERS XML followed by a checksum comment.
+    ///
+    /// The comment holds the SHA-256 of the document before it. Loading the file verifies
+    /// the checksum and warns if the file was corrupted or edited after export.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The XML representation of the scenario with its checksum.
+    /// * `Err(String)` - If serialization failed or the XML contains invalid UTF-8.
+    pub fn get_scenario_as_xml_with_checksum(&self) -> Result<String, String> {
+        // SAFETY: We pass a valid context pointer. The function returns a C string
+        // that we must free.
+        let xml_ptr = unsafe { ffi::fers_get_scenario_as_xml_with_checksum(self.ptr) };
+        if xml_ptr.is_null() {
+            return Err(get_last_error());
+        }
+        FersOwnedString(xml_ptr).into_string().map_err(|e| e.to_string())
+    }
+
+    /// Retrieves the current in-memory scenario as FERS XML checked against the bundled schema.
+    ///
+    /// The document declares `fers-xml.dtd` as its doctype and is validated against that DTD
+    /// and the XSD before it is returned.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The XML representation of the scenario.
+    /// * `Err(String)` - The first validation error if the generated XML is invalid, or the
+    ///   serialization error.
+    pub fn get_scenario_as_xml_validated(&self) -> Result<String, String> {
+        // SAFETY: We pass a valid context pointer. The function returns a C string
+        // that we must free.
+        let xml_ptr = unsafe { ffi::fers_get_scenario_as_xml_validated(self.ptr) };
+        if xml_ptr.is_null() {
+            return Err(get_last_error());
+        }
+        FersOwnedString(xml_ptr).into_string().map_err(|e| e.to_string())
+    }
+
+    /// Checks the loaded scenario for NaN and infinite numbers and for transmitters
+    /// that exceed their maximum duty cycle, and warns about pulsed receivers whose
+    /// listening windows overlap.
+    ///
+    /// Non-finite values cannot be exported to XML, so `get_scenario_as_xml` fails while
+    /// any remain. Each such error names the offending field by its path in the scenario
+    /// JSON; each duty-cycle error names the transmitter and its computed duty cycle.
+    /// Each window warning names the receiver, its PRI and the overlap.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ValidationResult)` - The non-finite fields and duty-cycle violations found, if
+    ///   any, with window overlaps as warnings.
+    /// * `Err(String)` - If the scenario could not be checked.
+    pub fn validate_scenario(&self) -> Result<ValidationResult, String> {
+        // SAFETY: We pass a valid context pointer. The returned list is owned by us.
+        let list_ptr = unsafe { ffi::fers_find_non_finite_values(self.ptr) };
+        if list_ptr.is_null() {
+            return Err(get_last_error());
+        }
+        let mut errors = FersStringList(list_ptr).to_vec();
+
+        // SAFETY: As above; the list is owned by us and freed on drop.
+        let list_ptr = unsafe { ffi::fers_find_duty_cycle_violations(self.ptr) };
+        if list_ptr.is_null() {
+            return Err(get_last_error());
+        }
+        errors.extend(FersStringList(list_ptr).to_vec());
+
+        // SAFETY: As above; the list is owned by us and freed on drop.
+        let list_ptr = unsafe { ffi::fers_find_receiver_window_overlaps(self.ptr) };
+        if list_ptr.is_null() {
+            return Err(get_last_error());
+        }
+        let warnings = FersStringList(list_ptr).to_vec();
+
+        Ok(ValidationResult { valid: errors.is_empty(), errors, warnings })
+    }
+
+    /// Updates the in-memory scenario from a JSON string.
+    ///
+    /// This method is the primary way for the UI to push modified scenario data back
+    /// to the C++ simulation engine. It deserializes the JSON and rebuilds the internal
+    /// `World` object, replacing any existing scenario. The replaced state is recorded
+    /// for `undo_scenario`, and anything previously undone can no longer be redone.
+    ///
+    /// # Parameters
+    ///
+    /// * `json` - A UTF-8 JSON string representing the scenario. The structure must
+    ///   match the schema expected by `libfers` (the same format returned by
+    ///   `get_scenario_as_json`).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the scenario was successfully deserialized and loaded.
+    /// * `Err(String)` - If the JSON was malformed, contained invalid data, or a C++
+    ///   exception was thrown. The error string contains details.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// let modified_json = /* JSON from UI */;
+    /// context.update_scenario_from_json(&modified_json)?;
+    /// ```
+    pub fn update_scenario_from_json(&self, json: &str) -> Result<(), String> {
+        self.record_change(|| self.apply_scenario_json(json))
+    }
+
+    /// Restores the scenario state before the last recorded change.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The restored scenario JSON, or the current one if there is
+    ///   nothing to undo.
+    /// * `Err(String)` - If the scenario could not be serialized or restored. The
+    ///   scenario and history are unchanged on error.
+    pub fn undo_scenario(&self) -> Result<String, String> {
+        self.step_history(true)
+    }
+
+    /// Reapplies the scenario state most recently undone.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The restored scenario JSON, or the current one if there is
+    ///   nothing to redo.
+    /// * `Err(String)` - If the scenario could not be serialized or restored. The
+    ///   scenario and history are unchanged on error.
+    pub fn redo_scenario(&self) -> Result<String, String> {
+        self.step_history(false)
+    }
+
+    /// Sets how many scenario states are kept for undo, discarding the oldest if needed.
+    ///
+    /// # Parameters
+    ///
+    /// * `depth` - The number of states to keep; zero disables undo.
+    pub fn set_history_depth(&self, depth: usize) -> Result<(), String> {
+        self.history.lock().map_err(|e| e.to_string())?.set_depth(depth);
+        Ok(())
+    }
+
+    /// Moves one step back (`undo`) or forward through the history and loads that state.
+    fn step_history(&self, undo: bool) -> Result<String, String> {
+        let mut history = self.history.lock().map_err(|e| e.to_string())?;
+        let current = self.get_scenario_as_json()?;
+        let restored =
+            if undo { history.undo(current.clone()) } else { history.redo(current.clone()) };
+        let Some(restored) = restored else {
+            return Ok(current);
+        };
+        if let Err(e) = self.apply_scenario_json(&restored) {
+            history.revert(restored, undo);
+            return Err(e);
+        }
+        Ok(restored)
+    }
+
+    /// Runs a change to the scenario and records the state it replaced for undo.
+    ///
+    /// Nothing is recorded if the change fails, which leaves the scenario unchanged.
+    fn record_change<T>(&self, change: impl FnOnce() -> Result<T, String>) -> Result<T, String> {
+        let mut history = self.history.lock().map_err(|e| e.to_string())?;
+        // A state that cannot be serialized cannot be restored either, so it is not recorded
+        let previous = self.get_scenario_as_json().ok();
+        let result = change()?;
+        if let Some(previous) = previous {
+            history.record(previous);
+        }
+        Ok(result)
+    }
+
+    /// Replaces the scenario with the given JSON without recording history.
+    fn apply_scenario_json(&self, json: &str) -> Result<(), String> {
+        let c_json = CString::new(json).map_err(|e| e.to_string())?;
+        // SAFETY: We pass a valid context pointer and a null-terminated C string.
+        // The function returns 0 on success.
+        let result = unsafe { ffi::fers_update_scenario_from_json(self.ptr, c_json.as_ptr()) };
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(get_last_error())
+        }
+    }
+
+    /// Adds a platform for each entity in a captured log of DIS Entity State PDUs.
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - The path of the binary PDU log.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The number of platforms created.
+    /// * `Err(String)` - If the log could not be read, contained a truncated PDU, or an
+    ///   imported platform name is already in use. The scenario is unchanged on error.
+    pub fn import_dis_entity_states(&self, path: &str) -> Result<usize, String> {
+        let c_path = CString::new(path).map_err(|e| e.to_string())?;
+        self.record_change(|| {
+            let mut count = 0;
+            // SAFETY: We pass a valid context pointer, a null-terminated string and a valid output pointer.
+            let result = unsafe {
+                ffi::fers_import_dis_entity_states(self.ptr, c_path.as_ptr(), &mut count)
+            };
+            if result != 0 {
+                return Err(get_last_error());
+            }
+            Ok(count)
+        })
+    }
+
+    /// Replaces the scenario with the platform geometry recorded in a FERS output file.
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - The path of an HDF5 receiver output file.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the platforms and their waypoints were reconstructed.
+    /// * `Err(String)` - If the file could not be read or carries no geometry metadata.
+    ///
+    /// The undo/redo history is cleared, as it belongs to the replaced scenario.
+    pub fn import_scenario_from_output(&self, path: &str) -> Result<(), String> {
+        let c_path = CString::new(path).map_err(|e| e.to_string())?;
+        // SAFETY: We pass a valid context pointer and a null-terminated C string.
+        let result = unsafe { ffi::fers_import_scenario_from_output(self.ptr, c_path.as_ptr()) };
+        if result == 0 {
+            self.history.lock().map_err(|e| e.to_string())?.clear();
+            Ok(())
+        } else {
+            Err(get_last_error())
+        }
+    }
+
+    /// Scales the scenario timeline so platforms follow the same paths at a different pace.
+    ///
+    /// # Parameters
+    ///
+    /// * `factor` - Multiplies the start/end times and all waypoint times; velocities
+    ///   scale by `1 / factor`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the scenario was rescaled.
+    /// * `Err(String)` - If `factor` is not positive.
+    pub fn time_scale_scenario(&self, factor: f64) -> Result<(), String> {
+        self.record_change(|| {
+            // SAFETY: We pass a valid context pointer.
+            let result = unsafe { ffi::fers_time_scale_scenario(self.ptr, factor) };
+            if result == 0 {
+                Ok(())
+            } else {
+                Err(get_last_error())
+            }
+        })
+    }
+
+    /// Adds a deep copy of a platform, including its paths and components, to the scenario.
+    ///
+    /// # Parameters
+    ///
+    /// * `platform_name` - The name of the platform to copy.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The name of the new platform, the original's with a " (copy)" suffix.
+    /// * `Err(String)` - If no platform has the given name.
+    pub fn duplicate_platform(&self, platform_name: &str) -> Result<String, String> {
+        let c_platform_name = CString::new(platform_name).map_err(|e| e.to_string())?;
+        let name_ptr = self.record_change(|| {
+            // SAFETY: We pass a valid context pointer and a valid C string. The function
+            // returns a C string that we must free.
+            let name_ptr =
+                unsafe { ffi::fers_duplicate_platform(self.ptr, c_platform_name.as_ptr()) };
+            if name_ptr.is_null() {
+                return Err(get_last_error());
+            }
+            Ok(FersOwnedString(name_ptr))
+        })?;
+        name_ptr.into_string().map_err(|e| e.to_string())
+    }
+
+    /// Imports the assets of another FERS XML scenario into the current one.
+    ///
+    /// # Parameters
+    ///
+    /// * `filepath` - The path of the scenario file to import from.
+    /// * `include_platforms` - Whether to import the file's platforms as well as its assets.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MergeSummary)` - The number of waveforms, timings, antennas and platforms added.
+    /// * `Err(String)` - If the file could not be loaded. The scenario is unchanged on error.
+    pub fn merge_scenario_from_xml_file(
+        &self,
+        filepath: &str,
+        include_platforms: bool,
+    ) -> Result<MergeSummary, String> {
+        let c_filepath = CString::new(filepath).map_err(|e| e.to_string())?;
+        self.record_change(|| {
+            let mut out =
+                ffi::fers_merge_summary_t { waveforms: 0, timings: 0, antennas: 0, platforms: 0 };
+            // SAFETY: We pass a valid context pointer, a null-terminated string and a valid output pointer.
+            let result = unsafe {
+                ffi::fers_merge_scenario_from_xml_file(
+                    self.ptr,
+                    c_filepath.as_ptr(),
+                    i32::from(include_platforms),
+                    &mut out,
+                )
+            };
+            if result != 0 {
+                return Err(get_last_error());
+            }
+            Ok(MergeSummary {
+                waveforms: out.waveforms,
+                timings: out.timings,
+                antennas: out.antennas,
+                platforms: out.platforms,
+            })
+        })
+    }
+
+    /// Runs the simulation defined in the context.
+    ///
+    /// This is a blocking call that executes the simulation on a separate thread pool
+    /// managed by the C++ core. It accepts a Tauri `AppHandle` to enable progress
+    /// reporting via events.
+    ///
+    /// # Parameters
+    ///
+    /// * `app_handle` - The Tauri application handle used for emitting progress events, or
+    ///   `None` to disable progress reporting.
+    /// * `output` - Where to write the output files, or `None` for the working directory.
+    /// * `cancel` - Checked by the C++ core before each pulse and receive-window event;
+    ///   setting it from another thread stops the run at the next boundary.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SimulationOutcome)` - Whether the simulation completed or was cancelled.
+    /// * `Err(String)` - If the simulation failed or the output directory does not exist.
+    pub fn run_simulation(
+        &self,
+        app_handle: Option<&AppHandle>,
+        output: Option<&OutputLocation>,
+        cancel: &AtomicBool,
+    ) -> Result<SimulationOutcome, String> {
+        // The progress sink is passed as a raw pointer through the `user_data` argument.
+        // This is safe because this function is blocking, and the sink will be valid for
+        // the entire duration of the C++ call.
+        let sink = app_handle.map(|app_handle| ProgressSink {
+            app_handle,
+            tracker: ProgressTracker::new(Instant::now()),
+        });
+        let user_data_ptr = sink
+            .as_ref()
+            .map_or(std::ptr::null_mut(), |sink| sink as *const ProgressSink as *mut c_void);
+        let callback: ffi::fers_progress_callback_t =
+            sink.as_ref().map(|_| simulation_progress_callback as _);
+
+        let result = match output {
+            // SAFETY: We pass a valid context pointer, an optional callback with a user_data
+            // pointer to the ProgressSink, and a flag that outlives the call. The core only
+            // reads the flag atomically.
+            None => unsafe {
+                ffi::fers_run_simulation(self.ptr, callback, user_data_ptr, cancel.as_ptr())
+            },
+            Some(output) => {
+                let c_dir = CString::new(output.directory.as_str()).map_err(|e| e.to_string())?;
+                let c_base = CString::new(output.basename.as_str()).map_err(|e| e.to_string())?;
+                // SAFETY: As above, plus two null-terminated strings for the destination.
+                unsafe {
+                    ffi::fers_run_simulation_to(
+                        self.ptr,
+                        c_dir.as_ptr(),
+                        c_base.as_ptr(),
+                        callback,
+                        user_data_ptr,
+                        cancel.as_ptr(),
+                    )
+                }
+            }
+        };
+        simulation_outcome(result)
+    }
+
+    /// Lists the files a simulation run of the current scenario writes.
+    ///
+    /// # Parameters
+    ///
+    /// * `output` - The destination passed to `run_simulation`, or `None` for the
+    ///   working directory.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The receiver HDF5 files, followed by the pulse timestamp
+    ///   CSV if that export is enabled.
+    /// * `Err(String)` - If the list could not be built.
+    pub fn list_output_files(
+        &self,
+        output: Option<&OutputLocation>,
+    ) -> Result<Vec<String>, String> {
+        let c_dir = output
+            .map(|output| CString::new(output.directory.as_str()))
+            .transpose()
+            .map_err(|e| e.to_string())?;
+        let c_base = output
+            .map(|output| CString::new(output.basename.as_str()))
+            .transpose()
+            .map_err(|e| e.to_string())?;
+        // SAFETY: We pass a valid context pointer and null or null-terminated strings.
+        // The returned list is owned by us.
+        let list_ptr = unsafe {
+            ffi::fers_list_output_files(
+                self.ptr,
+                c_dir.as_ref().map_or(std::ptr::null(), |dir| dir.as_ptr()),
+                c_base.as_ref().map_or(std::ptr::null(), |base| base.as_ptr()),
+            )
+        };
+        if list_ptr.is_null() {
+            return Err(get_last_error());
+        }
+        Ok(FersStringList(list_ptr).to_vec())
+    }
+
+    /// Runs the simulation, handing each finished receive window to `on_window`.
+    ///
+    /// Output is still written to HDF5 as with `run_simulation`. The callback is invoked
+    /// from the C++ finalizer threads one call at a time and should return quickly.
+    ///
+    /// # Parameters
+    ///
+    /// * `app_handle` - The Tauri application handle for progress events, or `None` to
+    ///   disable progress reporting.
+    /// * `output` - Where to write the output files, or `None` for the working directory.
+    /// * `on_window` - Receives the receiver name, start time, fullscale factor and
+    ///   interleaved I/Q samples of each window.
+    /// * `cancel` - Stops the run at the next pulse or window boundary once set.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SimulationOutcome)` - Whether the simulation completed or was cancelled.
+    /// * `Err(String)` - If the simulation failed or the output directory does not exist.
+    pub fn run_simulation_streaming(
+        &self,
+        app_handle: Option<&AppHandle>,
+        output: Option<&OutputLocation>,
+        on_window: WindowCallback,
+        cancel: &AtomicBool,
+    ) -> Result<SimulationOutcome, String> {
+        let sink = app_handle.map(|app_handle| ProgressSink {
+            app_handle,
+            tracker: ProgressTracker::new(Instant::now()),
+        });
+        let progress_user_data = sink
+            .as_ref()
+            .map_or(std::ptr::null_mut(), |sink| sink as *const ProgressSink as *mut c_void);
+        let progress_callback: ffi::fers_progress_callback_t =
+            app_handle.map(|_| simulation_progress_callback as _);
+        let window_user_data = &on_window as *const WindowCallback as *mut c_void;
+        let c_dir = output
+            .map(|output| CString::new(output.directory.as_str()))
+            .transpose()
+            .map_err(|e| e.to_string())?;
+        let c_base = output
+            .map(|output| CString::new(output.basename.as_str()))
+            .transpose()
+            .map_err(|e| e.to_string())?;
+
+        // SAFETY: We pass a valid context pointer and null or null-terminated strings for
+        // the destination. Both user_data pointers and the cancellation flag refer to
+        // values that outlive this blocking call.
+        let result = unsafe {
+            ffi::fers_run_simulation_streaming(
+                self.ptr,
+                c_dir.as_ref().map_or(std::ptr::null(), |dir| dir.as_ptr()),
+                c_base.as_ref().map_or(std::ptr::null(), |base| base.as_ptr()),
+                progress_callback,
+                progress_user_data,
+                Some(simulation_window_callback),
+                window_user_data,
+                cancel.as_ptr(),
+            )
+        };
+        simulation_outcome(result)
+    }
+
+    /// Generates a KML file for the current scenario.
+    ///
+    /// # Parameters
+    ///
+    /// * `output_path` - The path where the KML file will be saved.
+    /// * `origin` - If set, the (latitude, longitude, altitude) of the ENU origin to use in
+    ///   place of the scenario's own.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the KML file was generated successfully.
+    /// * `Err(String)` - If KML generation failed, or the origin is invalid or given for a
+    ///   scenario outside the ENU coordinate frame.
+    pub fn generate_kml(
+        &self,
+        output_path: &str,
+        origin: Option<(f64, f64, f64)>,
+    ) -> Result<(), String> {
+        let c_output_path = CString::new(output_path).map_err(|e| e.to_string())?;
+        // SAFETY: We pass a valid context pointer and a null-terminated C string for the path.
+        let result = unsafe {
+            match origin {
+                Some((latitude, longitude, altitude)) => ffi::fers_generate_kml_with_origin(
+                    self.ptr,
+                    c_output_path.as_ptr(),
+                    latitude,
+                    longitude,
+                    altitude,
+                ),
+                None => ffi::fers_generate_kml(self.ptr, c_output_path.as_ptr()),
+            }
+        };
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(get_last_error())
+        }
+    }
+
+    /// Retrieves a sampled gain pattern for a specified antenna.
+    ///
+    /// # Parameters
+    ///
+    /// * `antenna_name` - The name of the antenna asset to sample.
+    /// * `az_samples` - The resolution along the azimuth axis.
+    /// * `el_samples` - The resolution along the elevation axis.
+    /// * `frequency` - The frequency in Hz to use for calculation.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AntennaPatternData)` - If the pattern was successfully sampled.
+    /// * `Err(String)` - If the antenna was not found or an error occurred.
+    pub fn get_antenna_pattern(
+        &self,
+        antenna_name: &str,
+        az_samples: usize,
+        el_samples: usize,
+        frequency: f64,
+    ) -> Result<AntennaPatternData, String> {
+        let c_antenna_name = CString::new(antenna_name).map_err(|e| e.to_string())?;
+        // SAFETY: We pass a valid context pointer and valid arguments.
+        let result_ptr = unsafe {
+            ffi::fers_get_antenna_pattern(
+                self.ptr,
+                c_antenna_name.as_ptr(),
+                az_samples,
+                el_samples,
+                frequency,
+            )
+        };
+
+        if result_ptr.is_null() {
+            return Err(get_last_error());
+        }
+
+        let owned_data = FersAntennaPatternData(result_ptr);
+
+        // SAFETY: Dereferencing the non-null pointer returned by the FFI.
+        // The data is valid for the lifetime of `owned_data`.
+        let (gains_ptr, az_count, el_count, max_gain) = unsafe {
+            (
+                (*owned_data.0).gains,
+                (*owned_data.0).az_count,
+                (*owned_data.0).el_count,
+                (*owned_data.0).max_gain,
+            )
+        };
+        let total_samples = az_count * el_count;
+
+        // SAFETY: The gains_ptr is valid for `total_samples` elements.
+        let gains_slice = unsafe { std::slice::from_raw_parts(gains_ptr, total_samples) };
+
+        Ok(AntennaPatternData { gains: gains_slice.to_vec(), az_count, el_count, max_gain })
+    }
+
+    /// Finds the grating lobes in visible space of an array antenna steered off boresight.
+    ///
+    /// # Parameters
+    ///
+    /// * `antenna` - The array antenna definition.
+    /// * `scan_angle_deg` - The azimuth of the main beam relative to boresight, in degrees.
+    /// * `frequency` - The carrier frequency in Hz, or `None` to use the carrier of the first
+    ///   transmitter fitted with the scenario antenna of the same name.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GratingLobeReport)` - The wavelength, spacing and grating lobe directions.
+    /// * `Err(String)` - If the antenna is not an array or no carrier is available.
+    pub fn find_grating_lobes(
+        &self,
+        antenna: &crate::Antenna,
+        scan_angle_deg: f64,
+        frequency: Option<f64>,
+    ) -> Result<GratingLobeReport, String> {
+        let ffi_antenna = FfiAntenna::new(antenna)?;
+        let c_antenna_name =
+            antenna.name.as_deref().map(CString::new).transpose().map_err(|e| e.to_string())?;
+        // SAFETY: We pass a valid context pointer, an antenna definition kept valid by
+        // `ffi_antenna`, and a null-terminated string or null.
+        let report_ptr = unsafe {
+            ffi::fers_find_grating_lobes(
+                self.ptr,
+                &ffi_antenna.def,
+                c_antenna_name.as_ref().map_or(std::ptr::null(), |name| name.as_ptr()),
+                scan_angle_deg,
+                frequency.unwrap_or(0.0),
+            )
+        };
+        if report_ptr.is_null() {
+            return Err(get_last_error());
+        }
+
+        let owned_report = FersGratingLobeReport(report_ptr);
+        // SAFETY: The pointer is non-null and valid for the lifetime of `owned_report`.
+        let report = unsafe { &*owned_report.0 };
+        let lobe_angles_deg = if report.lobe_count == 0 || report.lobe_angles_deg.is_null() {
+            Vec::new()
+        } else {
+            // SAFETY: `lobe_angles_deg` points to `lobe_count` initialized entries.
+            unsafe { std::slice::from_raw_parts(report.lobe_angles_deg, report.lobe_count) }
+                .to_vec()
+        };
+
+        Ok(GratingLobeReport {
+            wavelength: report.wavelength,
+            spacing_wavelengths: report.spacing_wavelengths,
+            has_grating_lobes: !lobe_angles_deg.is_empty(),
+            lobe_angles_deg,
+        })
+    }
+
+    pub fn calculate_preview_links(&self, time: f64) -> Result<Vec<VisualLink>, String> {
+        let list_ptr = unsafe { ffi::fers_calculate_preview_links(self.ptr, time) };
+        if list_ptr.is_null() {
+            let err_msg = get_last_error();
+            // If we receive a NULL ptr but no error message, it might be a logic error
+            // or an unhandled edge case in C++. We default to the retrieved message.
+            return Err(err_msg);
+        }
+
+        let owned_list = FersVisualLinkList(list_ptr);
+        let count = unsafe { (*owned_list.0).count };
+        let links_ptr = unsafe { (*owned_list.0).links };
+
+        let mut result = Vec::with_capacity(count);
+        if count > 0 && !links_ptr.is_null() {
+            let slice = unsafe { std::slice::from_raw_parts(links_ptr, count) };
+            for l in slice {
+                let link_type_val = match l.type_ {
+                    ffi::fers_link_type_t_FERS_LINK_MONOSTATIC => 0,
+                    ffi::fers_link_type_t_FERS_LINK_BISTATIC_TX_TGT => 1,
+                    ffi::fers_link_type_t_FERS_LINK_BISTATIC_TGT_RX => 2,
+                    ffi::fers_link_type_t_FERS_LINK_DIRECT_TX_RX => 3,
+                    _ => 0,
+                };
+
+                let quality_val = match l.quality {
+                    ffi::fers_link_quality_t_FERS_LINK_STRONG => 0,
+                    _ => 1,
+                };
+
+                let label =
+                    unsafe { CStr::from_ptr(l.label.as_ptr()) }.to_string_lossy().into_owned();
+                let source_name = unsafe { CStr::from_ptr(l.source_name.as_ptr()) }
+                    .to_string_lossy()
+                    .into_owned();
+                let dest_name =
+                    unsafe { CStr::from_ptr(l.dest_name.as_ptr()) }.to_string_lossy().into_owned();
+                let origin_name = unsafe { CStr::from_ptr(l.origin_name.as_ptr()) }
+                    .to_string_lossy()
+                    .into_owned();
+
+                result.push(VisualLink {
+                    link_type: link_type_val,
+                    quality: quality_val,
+                    label,
+                    source_name,
+                    dest_name,
+                    origin_name,
+                });
+            }
+        }
+        Ok(result)
+    }
+
+    /// Calculates the aggregate interference power at a receiver.
+    ///
+    /// # Parameters
+    ///
+    /// * `rx_name` - The name of the receiver.
+    /// * `time` - The simulation time in seconds at which the geometry is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(InterferenceReport)` - The total and per-emitter received interference power.
+    /// * `Err(String)` - If the receiver was not found.
+    pub fn calculate_interference(
+        &self,
+        rx_name: &str,
+        time: f64,
+    ) -> Result<InterferenceReport, String> {
+        let c_rx_name = CString::new(rx_name).map_err(|e| e.to_string())?;
+        // SAFETY: We pass a valid context pointer and a null-terminated C string.
+        let report_ptr =
+            unsafe { ffi::fers_calculate_interference(self.ptr, c_rx_name.as_ptr(), time) };
+        if report_ptr.is_null() {
+            return Err(get_last_error());
+        }
+
+        let owned_report = FersInterferenceReport(report_ptr);
+        // SAFETY: The pointer is non-null and valid for the lifetime of `owned_report`.
+        let report = unsafe { &*owned_report.0 };
+        let contributions = if report.count > 0 && !report.contributions.is_null() {
+            // SAFETY: `contributions` points to `count` initialized entries.
+            let slice = unsafe { std::slice::from_raw_parts(report.contributions, report.count) };
+            slice
+                .iter()
+                .map(|c| InterferenceContribution {
+                    transmitter: unsafe { CStr::from_ptr(c.transmitter_name.as_ptr()) }
+                        .to_string_lossy()
+                        .into_owned(),
+                    power: c.power,
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Ok(InterferenceReport {
+            total_power: report.total_power,
+            temperature: report.temperature,
+            contributions,
+        })
+    }
+
+    /// Calculates the total bandwidth occupied by active emitters over the simulation.
+    ///
+    /// # Parameters
+    ///
+    /// * `num_points` - The number of evenly spaced samples between the start and end time.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(f64, f64)>)` - `(time, occupied_hz)` pairs in time order.
+    /// * `Err(String)` - If fewer than two points were requested.
+    pub fn calculate_spectrum_occupancy(
+        &self,
+        num_points: usize,
+    ) -> Result<Vec<(f64, f64)>, String> {
+        // SAFETY: We pass a valid context pointer.
+        let series_ptr = unsafe { ffi::fers_calculate_spectrum_occupancy(self.ptr, num_points) };
+        if series_ptr.is_null() {
+            return Err(get_last_error());
+        }
+
+        let owned_series = FersSpectrumOccupancy(series_ptr);
+        // SAFETY: The pointer is non-null and valid for the lifetime of `owned_series`.
+        let series = unsafe { &*owned_series.0 };
+        if series.count == 0 || series.samples.is_null() {
+            return Ok(Vec::new());
+        }
+        // SAFETY: `samples` points to `count` initialized entries.
+        let slice = unsafe { std::slice::from_raw_parts(series.samples, series.count) };
+        Ok(slice.iter().map(|s| (s.time, s.occupied_bandwidth)).collect())
+    }
+
+    /// Finds the moment when the most transmitters are active at once.
+    ///
+    /// # Parameters
+    ///
+    /// * `num_points` - The number of evenly spaced samples between the start and end time.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((f64, usize))` - The earliest time of the peak and the number of active transmitters.
+    /// * `Err(String)` - If fewer than two points were requested.
+    pub fn calculate_peak_emitter_count(&self, num_points: usize) -> Result<(f64, usize), String> {
+        let mut time = 0.0;
+        let mut count = 0;
+        // SAFETY: We pass a valid context pointer and valid pointers to the output values.
+        let result = unsafe {
+            ffi::fers_calculate_peak_emitter_count(self.ptr, num_points, &mut time, &mut count)
+        };
+        if result == 0 {
+            Ok((time, count))
+        } else {
+            Err(get_last_error())
+        }
+    }
+
+    /// Calculates a time-division schedule in which no two transmitters emit at once.
+    ///
+    /// # Parameters
+    ///
+    /// * `guard_time` - The idle time in seconds after each slot.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(String, f64, f64)>)` - `(transmitter_name, slot_start, slot_end)` entries
+    ///   in time order.
+    /// * `Err(String)` - If a transmitter is not pulsed or the pulses and guard times do not
+    ///   fit within the shortest PRI.
+    pub fn calculate_tdm_schedule(
+        &self,
+        guard_time: f64,
+    ) -> Result<Vec<(String, f64, f64)>, String> {
+        // SAFETY: We pass a valid context pointer.
+        let schedule_ptr = unsafe { ffi::fers_calculate_tdm_schedule(self.ptr, guard_time) };
+        if schedule_ptr.is_null() {
+            return Err(get_last_error());
+        }
+
+        let owned_schedule = FersTdmSchedule(schedule_ptr);
+        // SAFETY: The pointer is non-null and valid for the lifetime of `owned_schedule`.
+        let schedule = unsafe { &*owned_schedule.0 };
+        if schedule.count == 0 || schedule.slots.is_null() {
+            return Ok(Vec::new());
+        }
+        // SAFETY: `slots` points to `count` initialized entries.
+        let slice = unsafe { std::slice::from_raw_parts(schedule.slots, schedule.count) };
+        Ok(slice
+            .iter()
+            .map(|s| {
+                // SAFETY: `transmitter_name` is a null-terminated buffer filled by the C-API.
+                let name = unsafe { CStr::from_ptr(s.transmitter_name.as_ptr()) };
+                (name.to_string_lossy().into_owned(), s.slot_start, s.slot_end)
+            })
+            .collect())
+    }
+
+    /// Finds pulses from different transmitters that arrive at a receiver at the same time.
+    ///
+    /// # Parameters
+    ///
+    /// * `rx_name` - The name of the receiver.
+    /// * `time_window` - The `(start, end)` of the arrival window in seconds.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<PulseCollision>)` - The collisions ordered by the arrival of the first pulse.
+    /// * `Err(String)` - If the receiver was not found or the window is empty.
+    pub fn calculate_pulse_collisions(
+        &self,
+        rx_name: &str,
+        time_window: (f64, f64),
+    ) -> Result<Vec<PulseCollision>, String> {
+        let c_rx_name = CString::new(rx_name).map_err(|e| e.to_string())?;
+        // SAFETY: We pass a valid context pointer and a null-terminated string.
+        let list_ptr = unsafe {
+            ffi::fers_calculate_pulse_collisions(
+                self.ptr,
+                c_rx_name.as_ptr(),
+                time_window.0,
+                time_window.1,
+            )
+        };
+        if list_ptr.is_null() {
+            return Err(get_last_error());
+        }
+
+        let owned_list = FersPulseCollisionList(list_ptr);
+        // SAFETY: The pointer is non-null and valid for the lifetime of `owned_list`.
+        let list = unsafe { &*owned_list.0 };
+        if list.count == 0 || list.collisions.is_null() {
+            return Ok(Vec::new());
+        }
+        // SAFETY: `collisions` points to `count` initialized entries.
+        let slice = unsafe { std::slice::from_raw_parts(list.collisions, list.count) };
+        Ok(slice
+            .iter()
+            .map(|c| {
+                // SAFETY: Both names are null-terminated buffers filled by the C-API.
+                let (first, second) = unsafe {
+                    (
+                        CStr::from_ptr(c.first_transmitter.as_ptr()),
+                        CStr::from_ptr(c.second_transmitter.as_ptr()),
+                    )
+                };
+                PulseCollision {
+                    first_transmitter: first.to_string_lossy().into_owned(),
+                    first_pulse_index: c.first_pulse_index,
+                    second_transmitter: second.to_string_lossy().into_owned(),
+                    second_pulse_index: c.second_pulse_index,
+                    overlap_start: c.overlap_start,
+                    overlap_end: c.overlap_end,
+                }
+            })
+            .collect())
+    }
+
+    /// Calculates the bistatic range Tx -> Target -> Rx between antenna phase centers.
+    ///
+    /// # Parameters
+    ///
+    /// * `tx_name` - The name of the transmitter.
+    /// * `target_name` - The name of the target.
+    /// * `rx_name` - The name of the receiver.
+    /// * `time` - The simulation time in seconds at which the geometry is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The total path length in meters.
+    /// * `Err(String)` - If a component was not found.
+    pub fn calculate_phase_center_range(
+        &self,
+        tx_name: &str,
+        target_name: &str,
+        rx_name: &str,
+        time: f64,
+    ) -> Result<f64, String> {
+        let c_tx_name = CString::new(tx_name).map_err(|e| e.to_string())?;
+        let c_target_name = CString::new(target_name).map_err(|e| e.to_string())?;
+        let c_rx_name = CString::new(rx_name).map_err(|e| e.to_string())?;
+        let mut range = 0.0;
+        // SAFETY: We pass a valid context pointer, null-terminated strings and a valid
+        // pointer to the output value.
+        let result = unsafe {
+            ffi::fers_calculate_phase_center_range(
+                self.ptr,
+                c_tx_name.as_ptr(),
+                c_target_name.as_ptr(),
+                c_rx_name.as_ptr(),
+                time,
+                &mut range,
+            )
+        };
+        if result == 0 {
+            Ok(range)
+        } else {
+            Err(get_last_error())
+        }
+    }
+
+    /// Calculates the thermal noise floor kTB of a receiver.
+    ///
+    /// # Parameters
+    ///
+    /// * `rx_name` - The name of the receiver.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The noise power in dBm.
+    /// * `Err(String)` - If the receiver was not found or has no positive noise bandwidth.
+    pub fn calculate_noise_floor(&self, rx_name: &str) -> Result<f64, String> {
+        let c_rx_name = CString::new(rx_name).map_err(|e| e.to_string())?;
+        let mut noise_dbm = 0.0;
+        // SAFETY: We pass a valid context pointer, a null-terminated string and a valid
+        // pointer to the output value.
+        let result = unsafe {
+            ffi::fers_calculate_noise_floor(self.ptr, c_rx_name.as_ptr(), &mut noise_dbm)
+        };
+        if result == 0 {
+            Ok(noise_dbm)
+        } else {
+            Err(get_last_error())
+        }
+    }
+
+    /// Calculates the two-way link budget of a transmitter, target and receiver at a given range.
+    ///
+    /// # Parameters
+    ///
+    /// * `tx_name` - The name of the transmitter.
+    /// * `rx_name` - The name of the receiver.
+    /// * `target_name` - The name of the target.
+    /// * `range` - The assumed range to the target in meters.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(LinkBudget)` - The gains, losses, powers and SNRs in dB.
+    /// * `Err(String)` - If a component or the waveform was not found, the range is not
+    ///   positive or the target coincides with the transmitter or receiver.
+    pub fn calculate_link_budget(
+        &self,
+        tx_name: &str,
+        rx_name: &str,
+        target_name: &str,
+        range: f64,
+    ) -> Result<LinkBudget, String> {
+        let c_tx_name = CString::new(tx_name).map_err(|e| e.to_string())?;
+        let c_rx_name = CString::new(rx_name).map_err(|e| e.to_string())?;
+        let c_target_name = CString::new(target_name).map_err(|e| e.to_string())?;
+        let mut out = ffi::fers_link_budget_t {
+            transmit_power_dbw: 0.0,
+            tx_gain_dbi: 0.0,
+            rx_gain_dbi: 0.0,
+            rcs_dbsm: 0.0,
+            path_loss_db: 0.0,
+            received_power_dbw: 0.0,
+            noise_power_dbw: 0.0,
+            snr_db: 0.0,
+            integration_gain_db: 0.0,
+            integrated_snr_db: 0.0,
+        };
+        // SAFETY: We pass a valid context pointer, null-terminated strings and a valid output pointer.
+        let result = unsafe {
+            ffi::fers_calculate_link_budget(
+                self.ptr,
+                c_tx_name.as_ptr(),
+                c_rx_name.as_ptr(),
+                c_target_name.as_ptr(),
+                range,
+                &mut out,
+            )
+        };
+        if result != 0 {
+            return Err(get_last_error());
+        }
+        Ok(LinkBudget {
+            transmit_power_dbw: out.transmit_power_dbw,
+            tx_gain_dbi: out.tx_gain_dbi,
+            rx_gain_dbi: out.rx_gain_dbi,
+            rcs_dbsm: out.rcs_dbsm,
+            path_loss_db: out.path_loss_db,
+            received_power_dbw: out.received_power_dbw,
+            noise_power_dbw: out.noise_power_dbw,
+            snr_db: out.snr_db,
+            integration_gain_db: out.integration_gain_db,
+            integrated_snr_db: out.integrated_snr_db,
+        })
+    }
+
+    /// Synthesizes the phase noise a receiver's timing source adds to its downconversion.
+    ///
+    /// # Parameters
+    ///
+    /// * `rx_name` - The name of the receiver.
+    /// * `duration` - The length of the preview in seconds.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<f64>)` - The phase noise in radians, evenly spaced over `duration`.
+    /// * `Err(String)` - If the receiver or its timing source was not found, or the
+    ///   duration is not positive.
+    pub fn preview_receiver_phase_noise(
+        &self,
+        rx_name: &str,
+        duration: f64,
+    ) -> Result<Vec<f64>, String> {
+        let c_rx_name = CString::new(rx_name).map_err(|e| e.to_string())?;
+        // SAFETY: We pass a valid context pointer and a null-terminated string.
+        let series_ptr = unsafe {
+            ffi::fers_preview_receiver_phase_noise(self.ptr, c_rx_name.as_ptr(), duration)
+        };
+        if series_ptr.is_null() {
+            return Err(get_last_error());
+        }
+
+        let owned_series = FersPhaseNoiseSeries(series_ptr);
+        // SAFETY: The pointer is non-null and valid for the lifetime of `owned_series`.
+        let series = unsafe { &*owned_series.0 };
+        if series.count == 0 || series.samples.is_null() {
+            return Ok(Vec::new());
+        }
+        // SAFETY: `samples` points to `count` initialized entries.
+        Ok(unsafe { std::slice::from_raw_parts(series.samples, series.count) }.to_vec())
+    }
+
+    /// Computes the closed-form echo of a single pulse off one target, without noise.
+    ///
+    /// # Parameters
+    ///
+    /// * `tx_name` - The name of the pulsed transmitter.
+    /// * `target_name` - The name of the target.
+    /// * `rx_name` - The name of the receiver.
+    /// * `time` - The emission time of the pulse in seconds.
+    /// * `num_samples` - The number of receiver samples, taken at the simulation rate from `time`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<[f64; 2]>)` - The `[i, q]` baseband samples of the echo.
+    /// * `Err(String)` - If a component was not found, the transmitter is not pulsed, or no
+    ///   samples were requested.
+    pub fn preview_received_pulse(
+        &self,
+        tx_name: &str,
+        target_name: &str,
+        rx_name: &str,
+        time: f64,
+        num_samples: usize,
+    ) -> Result<Vec<[f64; 2]>, String> {
+        let c_tx_name = CString::new(tx_name).map_err(|e| e.to_string())?;
+        let c_target_name = CString::new(target_name).map_err(|e| e.to_string())?;
+        let c_rx_name = CString::new(rx_name).map_err(|e| e.to_string())?;
+        // SAFETY: We pass a valid context pointer and null-terminated strings.
+        let pulse_ptr = unsafe {
+            ffi::fers_preview_received_pulse(
+                self.ptr,
+                c_tx_name.as_ptr(),
+                c_target_name.as_ptr(),
+                c_rx_name.as_ptr(),
+                time,
+                num_samples,
+            )
+        };
+        if pulse_ptr.is_null() {
+            return Err(get_last_error());
+        }
+
+        let owned_pulse = FersReceivedPulse(pulse_ptr);
+        // SAFETY: The pointer is non-null and valid for the lifetime of `owned_pulse`.
+        let pulse = unsafe { &*owned_pulse.0 };
+        if pulse.count == 0 || pulse.iq.is_null() {
+            return Ok(Vec::new());
+        }
+        // SAFETY: `iq` points to `2 * count` initialized entries.
+        let iq = unsafe { std::slice::from_raw_parts(pulse.iq, 2 * pulse.count) };
+        Ok(iq.chunks_exact(2).map(|pair| [pair[0], pair[1]]).collect())
+    }
+
+    /// Computes the weights that steer a receiver's element array to a look direction.
+    ///
+    /// # Parameters
+    ///
+    /// * `rx_name` - The name of the receiver.
+    /// * `azimuth` - The look azimuth in degrees, positive to the left of boresight.
+    /// * `elevation` - The look elevation in degrees.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<[f64; 2]>)` - One `[re, im]` weight per array element.
+    /// * `Err(String)` - If the receiver was not found, has no element array, or no
+    ///   transmitter has a waveform to set the wavelength.
+    pub fn preview_beamforming_weights(
+        &self,
+        rx_name: &str,
+        azimuth: f64,
+        elevation: f64,
+    ) -> Result<Vec<[f64; 2]>, String> {
+        let c_rx_name = CString::new(rx_name).map_err(|e| e.to_string())?;
+        // SAFETY: We pass a valid context pointer and a null-terminated string.
+        let weights_ptr = unsafe {
+            ffi::fers_preview_beamforming_weights(self.ptr, c_rx_name.as_ptr(), azimuth, elevation)
+        };
+        if weights_ptr.is_null() {
+            return Err(get_last_error());
+        }
+
+        let owned_weights = FersBeamformingWeights(weights_ptr);
+        // SAFETY: The pointer is non-null and valid for the lifetime of `owned_weights`.
+        let weights = unsafe { &*owned_weights.0 };
+        if weights.count == 0 || weights.iq.is_null() {
+            return Ok(Vec::new());
+        }
+        // SAFETY: `iq` points to `2 * count` initialized entries.
+        let iq = unsafe { std::slice::from_raw_parts(weights.iq, 2 * weights.count) };
+        Ok(iq.chunks_exact(2).map(|pair| [pair[0], pair[1]]).collect())
+    }
+
+    /// Calculates the lowest PRF that measures a target's radial velocity unambiguously.
+    ///
+    /// # Parameters
+    ///
+    /// * `tx_name` - The name of the transmitter.
+    /// * `target_name` - The name of the target.
+    /// * `rx_name` - The name of the receiver.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The minimum PRF in Hz.
+    /// * `Err(String)` - If a component was not found or the transmitter has no waveform.
+    pub fn calculate_min_prf_for_velocity(
+        &self,
+        tx_name: &str,
+        target_name: &str,
+        rx_name: &str,
+    ) -> Result<f64, String> {
+        let c_tx_name = CString::new(tx_name).map_err(|e| e.to_string())?;
+        let c_target_name = CString::new(target_name).map_err(|e| e.to_string())?;
+        let c_rx_name = CString::new(rx_name).map_err(|e| e.to_string())?;
+        let mut prf = 0.0;
+        // SAFETY: We pass a valid context pointer, null-terminated strings and a valid
+        // pointer to the output value.
+        let result = unsafe {
+            ffi::fers_calculate_min_prf_for_velocity(
+                self.ptr,
+                c_tx_name.as_ptr(),
+                c_target_name.as_ptr(),
+                c_rx_name.as_ptr(),
+                &mut prf,
+            )
+        };
+        if result == 0 {
+            Ok(prf)
+        } else {
+            Err(get_last_error())
+        }
+    }
+
+    /// Calculates the output data rate of a simulation run.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(DataRateReport)` - The peak and average output rates in bytes per second.
+    /// * `Err(String)` - If the calculation failed.
+    pub fn calculate_data_rate(&self) -> Result<DataRateReport, String> {
+        let mut out = ffi::fers_data_rate_t {
+            peak_bytes_per_second: 0.0,
+            average_bytes_per_second: 0.0,
+            active_receivers: 0,
+        };
+        // SAFETY: We pass a valid context pointer and a valid output pointer.
+        let result = unsafe { ffi::fers_calculate_data_rate(self.ptr, &mut out) };
+        if result != 0 {
+            return Err(get_last_error());
+        }
+        Ok(DataRateReport {
+            peak_bytes_per_second: out.peak_bytes_per_second,
+            average_bytes_per_second: out.average_bytes_per_second,
+            active_receivers: out.active_receivers,
+        })
+    }
+
+    /// Calculates the coherent processing interval needed to achieve a velocity resolution.
+    ///
+    /// # Parameters
+    ///
+    /// * `rx_name` - The name of the receiver whose dwell is checked.
+    /// * `velocity_resolution` - The velocity resolution in m/s.
+    /// * `carrier` - The carrier frequency in Hz.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(IntegrationTimeReport)` - The required interval and the receiver's available dwell.
+    /// * `Err(String)` - If the receiver is missing or an argument is not positive.
+    pub fn calculate_required_integration_time(
+        &self,
+        rx_name: &str,
+        velocity_resolution: f64,
+        carrier: f64,
+    ) -> Result<IntegrationTimeReport, String> {
+        let c_rx_name = CString::new(rx_name).map_err(|e| e.to_string())?;
+        let mut out = ffi::fers_integration_time_t {
+            required_time: 0.0,
+            available_dwell: 0.0,
+            sufficient: 0,
+        };
+        // SAFETY: We pass a valid context pointer, a valid C string and a valid output pointer.
+        let result = unsafe {
+            ffi::fers_calculate_required_integration_time(
+                self.ptr,
+                c_rx_name.as_ptr(),
+                velocity_resolution,
+                carrier,
+                &mut out,
+            )
+        };
+        if result != 0 {
+            return Err(get_last_error());
+        }
+        Ok(IntegrationTimeReport {
+            required_time: out.required_time,
+            available_dwell: out.available_dwell,
+            sufficient: out.sufficient != 0,
+        })
+    }
+
+    /// Calculates the Cramér-Rao lower bound on range estimation for a Tx -> Target -> Rx echo.
+    ///
+    /// # Parameters
+    ///
+    /// * `tx_name` - The name of the transmitter.
+    /// * `target_name` - The name of the target.
+    /// * `rx_name` - The name of the receiver.
+    /// * `time` - The simulation time in seconds at which the geometry is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The range standard deviation bound in meters.
+    /// * `Err(String)` - If a component was not found or the waveform bandwidth is unknown.
+    pub fn calculate_range_crlb(
+        &self,
+        tx_name: &str,
+        target_name: &str,
+        rx_name: &str,
+        time: f64,
+    ) -> Result<f64, String> {
+        let c_tx_name = CString::new(tx_name).map_err(|e| e.to_string())?;
+        let c_target_name = CString::new(target_name).map_err(|e| e.to_string())?;
+        let c_rx_name = CString::new(rx_name).map_err(|e| e.to_string())?;
+        let mut sigma_m = 0.0;
+        // SAFETY: We pass a valid context pointer, null-terminated strings and a valid
+        // pointer to the output value.
+        let result = unsafe {
+            ffi::fers_calculate_range_crlb(
+                self.ptr,
+                c_tx_name.as_ptr(),
+                c_target_name.as_ptr(),
+                c_rx_name.as_ptr(),
+                time,
+                &mut sigma_m,
+            )
+        };
+        if result == 0 {
+            Ok(sigma_m)
+        } else {
+            Err(get_last_error())
+        }
+    }
+
+    /// Calculates the transmit power needed to reach a target SNR via a target.
+    ///
+    /// # Parameters
+    ///
+    /// * `tx_name` - The name of the transmitter.
+    /// * `target_name` - The name of the target.
+    /// * `rx_name` - The name of the receiver.
+    /// * `target_snr_db` - The desired SNR in dB.
+    /// * `time` - The simulation time in seconds at which the geometry is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The required transmit power in dBW.
+    /// * `Err(String)` - If a component was not found or the receiver has no noise.
+    pub fn calculate_required_tx_power(
+        &self,
+        tx_name: &str,
+        target_name: &str,
+        rx_name: &str,
+        target_snr_db: f64,
+        time: f64,
+    ) -> Result<f64, String> {
+        let c_tx_name = CString::new(tx_name).map_err(|e| e.to_string())?;
+        let c_target_name = CString::new(target_name).map_err(|e| e.to_string())?;
+        let c_rx_name = CString::new(rx_name).map_err(|e| e.to_string())?;
+        let mut power_dbw = 0.0;
+        // SAFETY: We pass a valid context pointer, null-terminated strings and a valid
+        // pointer to the output value.
+        let result = unsafe {
+            ffi::fers_calculate_required_tx_power(
+                self.ptr,
+                c_tx_name.as_ptr(),
+                c_target_name.as_ptr(),
+                c_rx_name.as_ptr(),
+                target_snr_db,
+                time,
+                &mut power_dbw,
+            )
+        };
+        if result == 0 {
+            Ok(power_dbw)
+        } else {
+            Err(get_last_error())
+        }
+    }
+
+    /// Calculates the range at which a monostatic radar detects a target at a threshold SNR.
     ///
     /// # Parameters
     ///
-    /// * `output_path` - The path where the KML file will be saved.
+    /// * `monostatic_name` - The name of the monostatic radar.
+    /// * `target_rcs` - The radar cross section of the target in square meters.
+    /// * `snr_threshold_db` - The detection threshold SNR in dB.
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - If the KML file was generated successfully.
-    /// * `Err(String)` - If KML generation failed.
-    pub fn generate_kml(&self, output_path: &str) -> Result<(), String> {
-        let c_output_path = CString::new(output_path).map_err(|e| e.to_string())?;
-        // SAFETY: We pass a valid context pointer and a null-terminated C string for the path.
-        let result = unsafe { ffi::fers_generate_kml(self.ptr, c_output_path.as_ptr()) };
+    /// * `Ok(f64)` - The maximum detection range in meters.
+    /// * `Err(String)` - If the radar was not found or is not monostatic, or the range is undefined.
+    pub fn calculate_max_detection_range(
+        &self,
+        monostatic_name: &str,
+        target_rcs: f64,
+        snr_threshold_db: f64,
+    ) -> Result<f64, String> {
+        let c_name = CString::new(monostatic_name).map_err(|e| e.to_string())?;
+        let mut range = 0.0;
+        // SAFETY: We pass a valid context pointer, a null-terminated string and a valid
+        // pointer to the output value.
+        let result = unsafe {
+            ffi::fers_calculate_max_detection_range(
+                self.ptr,
+                c_name.as_ptr(),
+                target_rcs,
+                snr_threshold_db,
+                &mut range,
+            )
+        };
         if result == 0 {
-            Ok(())
+            Ok(range)
         } else {
             Err(get_last_error())
         }
     }
 
-    /// Retrieves a sampled gain pattern for a specified antenna.
+    /// Calculates the matched-filter SNR loss from a receive window shorter than the pulse.
     ///
     /// # Parameters
     ///
-    /// * `antenna_name` - The name of the antenna asset to sample.
-    /// * `az_samples` - The resolution along the azimuth axis.
-    /// * `el_samples` - The resolution along the elevation axis.
-    /// * `frequency` - The frequency in Hz to use for calculation.
+    /// * `rx_name` - The name of the pulsed receiver.
+    /// * `tx_name` - The name of the pulsed transmitter.
     ///
     /// # Returns
     ///
-    /// * `Ok(AntennaPatternData)` - If the pattern was successfully sampled.
-    /// * `Err(String)` - If the antenna was not found or an error occurred.
-    pub fn get_antenna_pattern(
+    /// * `Ok(f64)` - The SNR loss in dB, or zero if the window contains the whole pulse.
+    /// * `Err(String)` - If a component was not found or either component is not pulsed.
+    pub fn calculate_window_truncation_loss(
         &self,
-        antenna_name: &str,
-        az_samples: usize,
-        el_samples: usize,
-        frequency: f64,
-    ) -> Result<AntennaPatternData, String> {
-        let c_antenna_name = CString::new(antenna_name).map_err(|e| e.to_string())?;
-        // SAFETY: We pass a valid context pointer and valid arguments.
-        let result_ptr = unsafe {
-            ffi::fers_get_antenna_pattern(
+        rx_name: &str,
+        tx_name: &str,
+    ) -> Result<f64, String> {
+        let c_rx_name = CString::new(rx_name).map_err(|e| e.to_string())?;
+        let c_tx_name = CString::new(tx_name).map_err(|e| e.to_string())?;
+        let mut loss_db = 0.0;
+        // SAFETY: We pass a valid context pointer, null-terminated strings and a valid
+        // pointer to the output value.
+        let result = unsafe {
+            ffi::fers_calculate_window_truncation_loss(
                 self.ptr,
-                c_antenna_name.as_ptr(),
-                az_samples,
-                el_samples,
-                frequency,
+                c_rx_name.as_ptr(),
+                c_tx_name.as_ptr(),
+                &mut loss_db,
             )
         };
+        if result == 0 {
+            Ok(loss_db)
+        } else {
+            Err(get_last_error())
+        }
+    }
 
-        if result_ptr.is_null() {
+    /// Calculates the ranges at which a pulsed receiver is blind to a transmitter's returns.
+    ///
+    /// # Parameters
+    ///
+    /// * `rx_name` - The name of the pulsed receiver.
+    /// * `tx_name` - The name of the pulsed transmitter.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(f64, f64)>)` - The eclipsed `(range_start, range_end)` intervals in meters.
+    /// * `Err(String)` - If a component was not found, is not pulsed, or the PRFs differ.
+    pub fn calculate_blind_ranges(
+        &self,
+        rx_name: &str,
+        tx_name: &str,
+    ) -> Result<Vec<(f64, f64)>, String> {
+        let c_rx_name = CString::new(rx_name).map_err(|e| e.to_string())?;
+        let c_tx_name = CString::new(tx_name).map_err(|e| e.to_string())?;
+        // SAFETY: We pass a valid context pointer and null-terminated strings.
+        let list_ptr = unsafe {
+            ffi::fers_calculate_blind_ranges(self.ptr, c_rx_name.as_ptr(), c_tx_name.as_ptr())
+        };
+        if list_ptr.is_null() {
             return Err(get_last_error());
         }
 
-        let owned_data = FersAntennaPatternData(result_ptr);
+        let owned_list = FersRangeIntervalList(list_ptr);
+        // SAFETY: The pointer is non-null and valid for the lifetime of `owned_list`.
+        let list = unsafe { &*owned_list.0 };
+        if list.count == 0 || list.intervals.is_null() {
+            return Ok(Vec::new());
+        }
+        // SAFETY: `intervals` points to `count` initialized entries.
+        let slice = unsafe { std::slice::from_raw_parts(list.intervals, list.count) };
+        Ok(slice.iter().map(|i| (i.range_start, i.range_end)).collect())
+    }
 
-        // SAFETY: Dereferencing the non-null pointer returned by the FFI.
-        // The data is valid for the lifetime of `owned_data`.
-        let (gains_ptr, az_count, el_count, max_gain) = unsafe {
-            (
-                (*owned_data.0).gains,
-                (*owned_data.0).az_count,
-                (*owned_data.0).el_count,
-                (*owned_data.0).max_gain,
+    /// Calculates the combined range and velocity blind zones of a staggered PRF set.
+    ///
+    /// # Parameters
+    ///
+    /// * `rx_name` - The name of the pulsed receiver.
+    /// * `tx_name` - The name of the pulsed transmitter.
+    /// * `prfs` - The PRFs to stagger between, in Hz.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BlindZoneReport)` - The per-PRF blind zones and their combination.
+    /// * `Err(String)` - If a component was not found or is not pulsed, or a PRF is not positive.
+    pub fn calculate_blind_zone_coverage(
+        &self,
+        rx_name: &str,
+        tx_name: &str,
+        prfs: &[f64],
+    ) -> Result<BlindZoneReport, String> {
+        let c_rx_name = CString::new(rx_name).map_err(|e| e.to_string())?;
+        let c_tx_name = CString::new(tx_name).map_err(|e| e.to_string())?;
+        // SAFETY: We pass a valid context pointer, null-terminated strings and a slice
+        // pointer with its length.
+        let coverage_ptr = unsafe {
+            ffi::fers_calculate_blind_zone_coverage(
+                self.ptr,
+                c_rx_name.as_ptr(),
+                c_tx_name.as_ptr(),
+                prfs.as_ptr(),
+                prfs.len(),
             )
         };
-        let total_samples = az_count * el_count;
+        if coverage_ptr.is_null() {
+            return Err(get_last_error());
+        }
 
-        // SAFETY: The gains_ptr is valid for `total_samples` elements.
-        let gains_slice = unsafe { std::slice::from_raw_parts(gains_ptr, total_samples) };
+        let owned_coverage = FersBlindZoneCoverage(coverage_ptr);
+        // SAFETY: The pointer is non-null and valid for the lifetime of `owned_coverage`.
+        let coverage = unsafe { &*owned_coverage.0 };
+        let ranges = |ptr: *const ffi::fers_range_interval_t, count: usize| -> Vec<(f64, f64)> {
+            if ptr.is_null() || count == 0 {
+                return Vec::new();
+            }
+            // SAFETY: `ptr` points to `count` initialized entries owned by `owned_coverage`.
+            let slice = unsafe { std::slice::from_raw_parts(ptr, count) };
+            slice.iter().map(|i| (i.range_start, i.range_end)).collect()
+        };
+        let speeds = |ptr: *const f64, count: usize| -> Vec<f64> {
+            if ptr.is_null() || count == 0 {
+                return Vec::new();
+            }
+            // SAFETY: `ptr` points to `count` initialized entries owned by `owned_coverage`.
+            unsafe { std::slice::from_raw_parts(ptr, count) }.to_vec()
+        };
+        let per_prf = if coverage.prfs.is_null() || coverage.prf_count == 0 {
+            &[][..]
+        } else {
+            // SAFETY: `prfs` points to `prf_count` initialized entries.
+            unsafe { std::slice::from_raw_parts(coverage.prfs, coverage.prf_count) }
+        };
 
-        Ok(AntennaPatternData { gains: gains_slice.to_vec(), az_count, el_count, max_gain })
+        Ok(BlindZoneReport {
+            prfs: per_prf
+                .iter()
+                .map(|p| PrfBlindZones {
+                    prf: p.prf,
+                    blind_ranges: ranges(p.blind_ranges, p.blind_range_count),
+                    blind_speeds: speeds(p.blind_speeds, p.blind_speed_count),
+                })
+                .collect(),
+            unrecoverable_ranges: ranges(
+                coverage.unrecoverable_ranges,
+                coverage.unrecoverable_range_count,
+            ),
+            recoverable_ranges: ranges(
+                coverage.recoverable_ranges,
+                coverage.recoverable_range_count,
+            ),
+            unrecoverable_speeds: speeds(
+                coverage.unrecoverable_speeds,
+                coverage.unrecoverable_speed_count,
+            ),
+            recoverable_speeds: speeds(
+                coverage.recoverable_speeds,
+                coverage.recoverable_speed_count,
+            ),
+            max_range: coverage.max_range,
+            max_speed: coverage.max_speed,
+        })
     }
 
-    pub fn calculate_preview_links(&self, time: f64) -> Result<Vec<VisualLink>, String> {
-        let list_ptr = unsafe { ffi::fers_calculate_preview_links(self.ptr, time) };
+    /// Compares a platform's motion path against a reference trajectory.
+    ///
+    /// # Parameters
+    ///
+    /// * `platform_name` - The name of the platform.
+    /// * `reference` - The reference trajectory waypoints.
+    /// * `num_points` - The number of time samples over the overlapping time span.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(TrajectoryError)` - The RMS and maximum position error.
+    /// * `Err(String)` - If the platform was not found or the time spans do not overlap.
+    pub fn compare_trajectory(
+        &self,
+        platform_name: &str,
+        reference: &[crate::MotionWaypoint],
+        num_points: usize,
+    ) -> Result<TrajectoryError, String> {
+        let c_platform_name = CString::new(platform_name).map_err(|e| e.to_string())?;
+        let c_reference: Vec<ffi::fers_motion_waypoint_t> = reference
+            .iter()
+            .map(|wp| ffi::fers_motion_waypoint_t {
+                time: wp.time,
+                x: wp.x,
+                y: wp.y,
+                z: wp.altitude,
+            })
+            .collect();
+        let mut out = ffi::fers_trajectory_error_t {
+            rms_error: 0.0,
+            max_error: 0.0,
+            start_time: 0.0,
+            end_time: 0.0,
+            sample_count: 0,
+        };
+        // SAFETY: We pass a valid context pointer, a null-terminated string, a slice pointer
+        // valid for `c_reference.len()` elements and a valid output pointer.
+        let result = unsafe {
+            ffi::fers_compare_trajectory(
+                self.ptr,
+                c_platform_name.as_ptr(),
+                c_reference.as_ptr(),
+                c_reference.len(),
+                num_points,
+                &mut out,
+            )
+        };
+        if result != 0 {
+            return Err(get_last_error());
+        }
+        Ok(TrajectoryError {
+            rms_error: out.rms_error,
+            max_error: out.max_error,
+            start_time: out.start_time,
+            end_time: out.end_time,
+            sample_count: out.sample_count,
+        })
+    }
+
+    /// Computes the synthetic aperture and resolution of a SAR collection.
+    ///
+    /// # Parameters
+    ///
+    /// * `tx_name` - The name of the transmitter.
+    /// * `target_name` - The name of the imaged target.
+    /// * `rx_name` - The name of the receiver.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SarResolution)` - The aperture geometry and the cross-range and range resolution.
+    /// * `Err(String)` - If a component was not found, the waveform has no bandwidth or the
+    ///   radar does not move during illumination.
+    pub fn calculate_sar_resolution(
+        &self,
+        tx_name: &str,
+        target_name: &str,
+        rx_name: &str,
+    ) -> Result<SarResolution, String> {
+        let c_tx_name = CString::new(tx_name).map_err(|e| e.to_string())?;
+        let c_target_name = CString::new(target_name).map_err(|e| e.to_string())?;
+        let c_rx_name = CString::new(rx_name).map_err(|e| e.to_string())?;
+        let mut out = ffi::fers_sar_resolution_t {
+            synthetic_aperture: 0.0,
+            slant_range: 0.0,
+            cross_range_resolution: 0.0,
+            range_resolution: 0.0,
+            start_time: 0.0,
+            end_time: 0.0,
+        };
+        // SAFETY: We pass a valid context pointer, null-terminated strings and a valid output pointer.
+        let result = unsafe {
+            ffi::fers_calculate_sar_resolution(
+                self.ptr,
+                c_tx_name.as_ptr(),
+                c_target_name.as_ptr(),
+                c_rx_name.as_ptr(),
+                &mut out,
+            )
+        };
+        if result != 0 {
+            return Err(get_last_error());
+        }
+        Ok(SarResolution {
+            synthetic_aperture: out.synthetic_aperture,
+            slant_range: out.slant_range,
+            cross_range_resolution: out.cross_range_resolution,
+            range_resolution: out.range_resolution,
+            start_time: out.start_time,
+            end_time: out.end_time,
+        })
+    }
+
+    /// Computes the resolution cell of a transmitter/receiver pair at a target.
+    ///
+    /// # Parameters
+    ///
+    /// * `tx_name` - The name of the transmitter.
+    /// * `target_name` - The name of the target.
+    /// * `rx_name` - The name of the receiver.
+    /// * `time` - The simulation time in seconds.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ResolutionCell)` - The range resolution, cross-range extents and volume.
+    /// * `Err(String)` - If a component was not found, the waveform has no bandwidth or an
+    ///   antenna has no half-power beam edge.
+    pub fn compute_resolution_cell(
+        &self,
+        tx_name: &str,
+        target_name: &str,
+        rx_name: &str,
+        time: f64,
+    ) -> Result<ResolutionCell, String> {
+        let c_tx_name = CString::new(tx_name).map_err(|e| e.to_string())?;
+        let c_target_name = CString::new(target_name).map_err(|e| e.to_string())?;
+        let c_rx_name = CString::new(rx_name).map_err(|e| e.to_string())?;
+        let mut out = ffi::fers_resolution_cell_t {
+            range_resolution: 0.0,
+            azimuth_extent: 0.0,
+            elevation_extent: 0.0,
+            volume: 0.0,
+        };
+        // SAFETY: We pass a valid context pointer, null-terminated strings and a valid output pointer.
+        let result = unsafe {
+            ffi::fers_compute_resolution_cell(
+                self.ptr,
+                c_tx_name.as_ptr(),
+                c_target_name.as_ptr(),
+                c_rx_name.as_ptr(),
+                time,
+                &mut out,
+            )
+        };
+        if result != 0 {
+            return Err(get_last_error());
+        }
+        Ok(ResolutionCell {
+            range_resolution: out.range_resolution,
+            azimuth_extent: out.azimuth_extent,
+            elevation_extent: out.elevation_extent,
+            volume: out.volume,
+        })
+    }
+
+    /// Computes iso-Doppler contour lines on a ground grid.
+    ///
+    /// # Parameters
+    ///
+    /// * `tx_name` - The name of the transmitter.
+    /// * `rx_name` - The name of the receiver.
+    /// * `doppler_values` - The Doppler levels in Hz for which contours are extracted.
+    /// * `grid` - The ground sampling grid.
+    /// * `time` - The simulation time in seconds at which platform velocities are evaluated.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Vec<[f64; 2]>>)` - The contour polylines as lists of `[x, y]` points.
+    /// * `Err(String)` - If a component was not found or the grid is invalid.
+    pub fn compute_isodoppler_contours(
+        &self,
+        tx_name: &str,
+        rx_name: &str,
+        doppler_values: &[f64],
+        grid: &crate::CoverageGrid,
+        time: f64,
+    ) -> Result<Vec<Vec<[f64; 2]>>, String> {
+        let c_tx_name = CString::new(tx_name).map_err(|e| e.to_string())?;
+        let c_rx_name = CString::new(rx_name).map_err(|e| e.to_string())?;
+        let c_grid = to_ffi_grid(grid);
+        // SAFETY: We pass a valid context pointer, null-terminated strings, and a slice
+        // pointer that is valid for `doppler_values.len()` elements.
+        let list_ptr = unsafe {
+            ffi::fers_compute_isodoppler_contours(
+                self.ptr,
+                c_tx_name.as_ptr(),
+                c_rx_name.as_ptr(),
+                doppler_values.as_ptr(),
+                doppler_values.len(),
+                &c_grid,
+                time,
+            )
+        };
         if list_ptr.is_null() {
-            let err_msg = get_last_error();
-            // If we receive a NULL ptr but no error message, it might be a logic error
-            // or an unhandled edge case in C++. We default to the retrieved message.
-            return Err(err_msg);
+            return Err(get_last_error());
         }
+        Ok(FersPolylineList(list_ptr).to_vec())
+    }
 
-        let owned_list = FersVisualLinkList(list_ptr);
-        let count = unsafe { (*owned_list.0).count };
-        let links_ptr = unsafe { (*owned_list.0).links };
+    /// Computes the ground region illuminated by a transmitter and seen by a receiver.
+    ///
+    /// # Parameters
+    ///
+    /// * `tx_name` - The name of the transmitter.
+    /// * `rx_name` - The name of the receiver.
+    /// * `ground_z` - The altitude of the ground plane in meters.
+    /// * `time` - The simulation time in seconds.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<[f64; 3]>)` - The overlap polygon as `[x, y, z]` vertices on the ground
+    ///   plane, empty if the half-power footprints do not intersect.
+    /// * `Err(String)` - If a component was not found or a footprint cannot be traced.
+    pub fn compute_bistatic_footprint_overlap(
+        &self,
+        tx_name: &str,
+        rx_name: &str,
+        ground_z: f64,
+        time: f64,
+    ) -> Result<Vec<[f64; 3]>, String> {
+        let c_tx_name = CString::new(tx_name).map_err(|e| e.to_string())?;
+        let c_rx_name = CString::new(rx_name).map_err(|e| e.to_string())?;
+        // SAFETY: We pass a valid context pointer and null-terminated strings.
+        let list_ptr = unsafe {
+            ffi::fers_compute_bistatic_footprint_overlap(
+                self.ptr,
+                c_tx_name.as_ptr(),
+                c_rx_name.as_ptr(),
+                ground_z,
+                time,
+            )
+        };
+        if list_ptr.is_null() {
+            return Err(get_last_error());
+        }
+        Ok(FersPolylineList(list_ptr)
+            .to_vec()
+            .into_iter()
+            .flatten()
+            .map(|[x, y]| [x, y, ground_z])
+            .collect())
+    }
 
-        let mut result = Vec::with_capacity(count);
-        if count > 0 && !links_ptr.is_null() {
-            let slice = unsafe { std::slice::from_raw_parts(links_ptr, count) };
-            for l in slice {
-                let link_type_val = match l.type_ {
-                    ffi::fers_link_type_t_FERS_LINK_MONOSTATIC => 0,
-                    ffi::fers_link_type_t_FERS_LINK_BISTATIC_TX_TGT => 1,
-                    ffi::fers_link_type_t_FERS_LINK_BISTATIC_TGT_RX => 2,
-                    ffi::fers_link_type_t_FERS_LINK_DIRECT_TX_RX => 3,
-                    _ => 0,
-                };
+    /// Computes the grazing angle at which a platform's line of sight meets the ground.
+    ///
+    /// # Parameters
+    ///
+    /// * `platform_name` - The name of the platform.
+    /// * `ground_point` - The `[x, y]` ground intercept in meters.
+    /// * `ground_z` - The altitude of the ground plane in meters, used outside the terrain.
+    /// * `time` - The simulation time in seconds.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The grazing angle in degrees (90 at nadir).
+    /// * `Err(String)` - If the platform was not found, is not above the ground or the
+    ///   terrain hides the ground point.
+    pub fn compute_grazing_angle(
+        &self,
+        platform_name: &str,
+        ground_point: [f64; 2],
+        ground_z: f64,
+        time: f64,
+    ) -> Result<f64, String> {
+        let c_platform_name = CString::new(platform_name).map_err(|e| e.to_string())?;
+        let c_point = ffi::fers_ground_point_t { x: ground_point[0], y: ground_point[1] };
+        let mut angle_deg = 0.0;
+        // SAFETY: We pass a valid context pointer, a null-terminated string and valid
+        // pointers to the input point and output value.
+        let result = unsafe {
+            ffi::fers_compute_grazing_angle(
+                self.ptr,
+                c_platform_name.as_ptr(),
+                &c_point,
+                ground_z,
+                time,
+                &mut angle_deg,
+            )
+        };
+        if result == 0 {
+            Ok(angle_deg)
+        } else {
+            Err(get_last_error())
+        }
+    }
 
-                let quality_val = match l.quality {
-                    ffi::fers_link_quality_t_FERS_LINK_STRONG => 0,
-                    _ => 1,
-                };
+    /// Checks whether two platforms can see each other over the scenario terrain.
+    ///
+    /// # Parameters
+    ///
+    /// * `from_platform` - The name of the first platform.
+    /// * `to_platform` - The name of the second platform.
+    /// * `time` - The simulation time in seconds.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - Whether the straight path between the platforms clears the terrain.
+    /// * `Err(String)` - If a platform was not found.
+    pub fn check_line_of_sight(
+        &self,
+        from_platform: &str,
+        to_platform: &str,
+        time: f64,
+    ) -> Result<bool, String> {
+        let c_from = CString::new(from_platform).map_err(|e| e.to_string())?;
+        let c_to = CString::new(to_platform).map_err(|e| e.to_string())?;
+        let mut visible = 0;
+        // SAFETY: We pass a valid context pointer, null-terminated strings and a valid output pointer.
+        let result = unsafe {
+            ffi::fers_check_line_of_sight(
+                self.ptr,
+                c_from.as_ptr(),
+                c_to.as_ptr(),
+                time,
+                &mut visible,
+            )
+        };
+        if result == 0 {
+            Ok(visible != 0)
+        } else {
+            Err(get_last_error())
+        }
+    }
 
-                let label =
-                    unsafe { CStr::from_ptr(l.label.as_ptr()) }.to_string_lossy().into_owned();
-                let source_name = unsafe { CStr::from_ptr(l.source_name.as_ptr()) }
-                    .to_string_lossy()
-                    .into_owned();
-                let dest_name =
-                    unsafe { CStr::from_ptr(l.dest_name.as_ptr()) }.to_string_lossy().into_owned();
-                let origin_name = unsafe { CStr::from_ptr(l.origin_name.as_ptr()) }
-                    .to_string_lossy()
-                    .into_owned();
+    /// Computes the area of the ground clutter patch illuminated at a slant range.
+    ///
+    /// # Parameters
+    ///
+    /// * `rx_name` - The name of the receiver.
+    /// * `tx_name` - The name of the pulsed transmitter.
+    /// * `range` - The slant range to the patch in meters.
+    /// * `time` - The simulation time in seconds.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The patch area in square meters.
+    /// * `Err(String)` - If a component was not found, the transmitter is not pulsed, the
+    ///   range does not exceed the receiver height or an antenna has no half-power beam edge.
+    pub fn compute_clutter_cell_area(
+        &self,
+        rx_name: &str,
+        tx_name: &str,
+        range: f64,
+        time: f64,
+    ) -> Result<f64, String> {
+        let c_rx_name = CString::new(rx_name).map_err(|e| e.to_string())?;
+        let c_tx_name = CString::new(tx_name).map_err(|e| e.to_string())?;
+        let mut area = 0.0;
+        // SAFETY: We pass a valid context pointer, null-terminated strings and a valid output pointer.
+        let result = unsafe {
+            ffi::fers_compute_clutter_cell_area(
+                self.ptr,
+                c_rx_name.as_ptr(),
+                c_tx_name.as_ptr(),
+                range,
+                time,
+                &mut area,
+            )
+        };
+        if result == 0 {
+            Ok(area)
+        } else {
+            Err(get_last_error())
+        }
+    }
+
+    /// Samples the motion path of every platform over the simulation time span.
+    ///
+    /// # Parameters
+    ///
+    /// * `num_points` - The number of samples per platform (at least 2).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<PlatformTrack>)` - One track per platform, in scenario order.
+    /// * `Err(String)` - If fewer than two points were requested.
+    pub fn get_platform_tracks(&self, num_points: usize) -> Result<Vec<PlatformTrack>, String> {
+        // SAFETY: We pass a valid context pointer.
+        let list_ptr = unsafe { ffi::fers_get_platform_tracks(self.ptr, num_points) };
+        if list_ptr.is_null() {
+            return Err(get_last_error());
+        }
 
-                result.push(VisualLink {
-                    link_type: link_type_val,
-                    quality: quality_val,
-                    label,
-                    source_name,
-                    dest_name,
-                    origin_name,
-                });
-            }
+        let owned_list = FersPlatformTrackList(list_ptr);
+        // SAFETY: The pointer is non-null and valid for the lifetime of `owned_list`.
+        let list = unsafe { &*owned_list.0 };
+        if list.count == 0 || list.tracks.is_null() {
+            return Ok(Vec::new());
         }
-        Ok(result)
+        // SAFETY: `tracks` points to `count` initialized tracks.
+        let tracks = unsafe { std::slice::from_raw_parts(list.tracks, list.count) };
+        Ok(tracks
+            .iter()
+            .map(|track| {
+                let kind = match track.kind {
+                    ffi::fers_track_kind_t_FERS_TRACK_TRANSMITTER => TrackKind::Transmitter,
+                    ffi::fers_track_kind_t_FERS_TRACK_RECEIVER => TrackKind::Receiver,
+                    ffi::fers_track_kind_t_FERS_TRACK_MONOSTATIC => TrackKind::Monostatic,
+                    ffi::fers_track_kind_t_FERS_TRACK_TARGET => TrackKind::Target,
+                    _ => TrackKind::None,
+                };
+                let points = if track.count == 0 || track.points.is_null() {
+                    Vec::new()
+                } else {
+                    // SAFETY: Each track owns `count` initialized points.
+                    unsafe { std::slice::from_raw_parts(track.points, track.count) }
+                        .iter()
+                        .map(|p| crate::InterpolatedMotionPoint {
+                            x: p.x,
+                            y: p.y,
+                            z: p.z,
+                            vx: p.vx,
+                            vy: p.vy,
+                            vz: p.vz,
+                        })
+                        .collect()
+                };
+                PlatformTrack {
+                    // SAFETY: `platform_name` is a null-terminated C string.
+                    name: unsafe { CStr::from_ptr(track.platform_name.as_ptr()) }
+                        .to_string_lossy()
+                        .into_owned(),
+                    kind,
+                    points,
+                }
+            })
+            .collect())
+    }
+}
+
+/// Sorts motion waypoints by time, rejecting non-finite and duplicate timestamps that
+/// would make the interpolation undefined.
+fn sort_waypoints_by_time(
+    waypoints: Vec<crate::MotionWaypoint>,
+) -> Result<Vec<crate::MotionWaypoint>, String> {
+    if let Some(index) = waypoints.iter().position(|wp| !wp.time.is_finite()) {
+        return Err(format!("Waypoint {} has a non-finite time.", index + 1));
     }
+    let mut indexed: Vec<(usize, crate::MotionWaypoint)> =
+        waypoints.into_iter().enumerate().collect();
+    indexed.sort_by(|(_, a), (_, b)| a.time.total_cmp(&b.time));
+    if let Some(pair) = indexed.windows(2).find(|pair| pair[0].1.time == pair[1].1.time) {
+        let (first, second) = (pair[0].0.min(pair[1].0), pair[0].0.max(pair[1].0));
+        return Err(format!(
+            "Waypoints {} and {} share the time {} s; waypoint times must be distinct.",
+            first + 1,
+            second + 1,
+            pair[0].1.time
+        ));
+    }
+    Ok(indexed.into_iter().map(|(_, wp)| wp).collect())
 }
 
 /// A safe wrapper for the stateless `fers_get_interpolated_motion_path` C-API function.
@@ -660,18 +3322,44 @@ impl FersContext {
 /// * `waypoints` - A vector of motion waypoints from the frontend.
 /// * `interp_type` - The interpolation algorithm to use.
 /// * `num_points` - The desired number of points in the output path.
+/// * `options` - The fast mode, position noise and time window of the preview. In fast
+///   mode, cubic paths are sampled from a piecewise-linear approximation that stays within
+///   the tolerance of the exact path. Noise without a correlation time uses the engine's
+///   default interval.
+///
+/// Waypoints may arrive in any order and are sorted by time first. Waypoints are named
+/// by their 1-based position in the input in error messages.
 ///
 /// # Returns
 /// * `Ok(Vec<InterpolatedPoint>)` - A vector of points representing the calculated path.
-/// * `Err(String)` - An error message if the FFI call failed.
+/// * `Err(String)` - An error message if a waypoint time is not finite, two waypoints share
+///   a time, a cubic path has fewer than two waypoints, the time window does not overlap
+///   the waypoints, or the FFI call failed.
 pub fn get_interpolated_motion_path(
     waypoints: Vec<crate::MotionWaypoint>,
     interp_type: crate::InterpolationType,
     num_points: usize,
+    options: &crate::PathPreviewOptions,
 ) -> Result<Vec<crate::InterpolatedMotionPoint>, String> {
     if waypoints.is_empty() || num_points == 0 {
         return Ok(Vec::new());
     }
+    let waypoints = sort_waypoints_by_time(waypoints)?;
+    if matches!(interp_type, crate::InterpolationType::Cubic) && waypoints.len() < 2 {
+        return Err(
+            "Cubic interpolation requires at least two waypoints with distinct times.".to_string()
+        );
+    }
+    let tolerance =
+        options.fast.then(|| options.tolerance.unwrap_or(crate::DEFAULT_PATH_TOLERANCE));
+    if tolerance.is_some_and(|tolerance| !(tolerance > 0.0)) {
+        return Err("The approximation tolerance must be a positive number.".to_string());
+    }
+    let window_start = options.t_start.unwrap_or(f64::NEG_INFINITY);
+    let window_end = options.t_end.unwrap_or(f64::INFINITY);
+    // The same clamping the engine applies, so noise is sampled at the returned times
+    let start_time = window_start.max(waypoints[0].time);
+    let end_time = window_end.min(waypoints[waypoints.len() - 1].time);
 
     let c_waypoints: Vec<ffi::fers_motion_waypoint_t> = waypoints
         .into_iter()
@@ -687,11 +3375,14 @@ pub fn get_interpolated_motion_path(
     // SAFETY: We are calling the stateless FFI function with valid, well-formed arguments.
     // The pointer returned is owned by us and must be freed.
     let result_ptr = unsafe {
-        ffi::fers_get_interpolated_motion_path(
+        ffi::fers_get_motion_path_window(
             c_waypoints.as_ptr(),
             c_waypoints.len(),
             c_interp_type,
             num_points,
+            window_start,
+            window_end,
+            tolerance.unwrap_or(0.0),
         )
     };
 
@@ -713,6 +3404,27 @@ pub fn get_interpolated_motion_path(
 
     let owned_path = FersInterpolatedMotionPath(result_ptr);
 
+    if let Some(noise) = &options.noise {
+        // SAFETY: `owned_path.0` is a valid path returned by the FFI, and the
+        // function only modifies the points within its `count`.
+        let result = unsafe {
+            ffi::fers_apply_position_noise(
+                owned_path.0,
+                start_time,
+                end_time,
+                noise.x,
+                noise.y,
+                noise.z,
+                // Zero selects the engine's default correlation time
+                noise.correlation_time.unwrap_or(0.0),
+                noise.seed,
+            )
+        };
+        if result != 0 {
+            return Err(get_last_error());
+        }
+    }
+
     // SAFETY: We are accessing the fields of a non-null pointer returned by the FFI.
     // The `count` and `points` fields are guaranteed to be valid for the lifetime of `owned_path`.
     let result_slice =
@@ -733,6 +3445,162 @@ pub fn get_interpolated_motion_path(
     Ok(points)
 }
 
+/// A safe wrapper for the stateless `fers_preview_hop_sequence` C-API function.
+///
+/// # Parameters
+///
+/// * `spec` - The hop set and optional hop pattern.
+/// * `num_pulses` - The number of pulses to list.
+///
+/// # Returns
+///
+/// * `Ok(Vec<f64>)` - The carrier frequency in Hz used by each pulse, in emission order.
+/// * `Err(String)` - An error message if the hop sequence is invalid.
+pub fn preview_hop_sequence(
+    spec: &crate::HopSequence,
+    num_pulses: usize,
+) -> Result<Vec<f64>, String> {
+    let mut carriers = vec![0.0; num_pulses];
+    // SAFETY: The frequency and pattern slices are valid for their lengths, and
+    // `carriers` has room for exactly `num_pulses` values.
+    let result = unsafe {
+        ffi::fers_preview_hop_sequence(
+            spec.frequencies.as_ptr(),
+            spec.frequencies.len(),
+            spec.pattern.as_ptr(),
+            spec.pattern.len(),
+            num_pulses,
+            carriers.as_mut_ptr(),
+        )
+    };
+    if result != 0 {
+        return Err(get_last_error());
+    }
+    Ok(carriers)
+}
+
+/// A safe wrapper for the stateless `fers_validate_inline_waveform` C-API function.
+///
+/// # Parameters
+///
+/// * `waveform` - The inline I/Q samples and their sample rate.
+///
+/// # Returns
+///
+/// * `Ok(usize)` - The number of complex samples.
+/// * `Err(String)` - An error message if the samples do not decode or the rate is invalid.
+pub fn validate_inline_waveform(waveform: &crate::InlineWaveform) -> Result<usize, String> {
+    let c_samples = CString::new(waveform.samples.as_str()).map_err(|e| e.to_string())?;
+    let mut count = 0;
+    // SAFETY: We pass a null-terminated C string and a valid pointer to the output value.
+    let result = unsafe {
+        ffi::fers_validate_inline_waveform(c_samples.as_ptr(), waveform.rate, &mut count)
+    };
+    if result != 0 {
+        return Err(get_last_error());
+    }
+    Ok(count)
+}
+
+/// A safe wrapper for the stateless `fers_geodetic_to_local` C-API function.
+///
+/// # Parameters
+///
+/// * `origin` - The geodetic origin of the local frame as (latitude, longitude, altitude).
+/// * `points` - The waypoints in WGS84 latitude/longitude/altitude.
+///
+/// # Returns
+///
+/// * `Ok(Vec<MotionWaypoint>)` - The waypoints in the local ENU frame, in input order.
+/// * `Err(String)` - If the origin is not a valid geodetic position.
+pub fn geodetic_to_local(
+    origin: (f64, f64, f64),
+    points: &[crate::GeoPoint],
+) -> Result<Vec<crate::MotionWaypoint>, String> {
+    let c_points: Vec<ffi::fers_geodetic_waypoint_t> = points
+        .iter()
+        .map(|p| ffi::fers_geodetic_waypoint_t {
+            time: p.time,
+            latitude: p.latitude,
+            longitude: p.longitude,
+            altitude: p.altitude,
+        })
+        .collect();
+    let mut out =
+        vec![ffi::fers_motion_waypoint_t { time: 0.0, x: 0.0, y: 0.0, z: 0.0 }; points.len()];
+    // SAFETY: `c_points` and `out` both hold exactly `points.len()` elements.
+    let result = unsafe {
+        ffi::fers_geodetic_to_local(
+            origin.0,
+            origin.1,
+            origin.2,
+            c_points.as_ptr(),
+            c_points.len(),
+            out.as_mut_ptr(),
+        )
+    };
+    if result != 0 {
+        return Err(get_last_error());
+    }
+    Ok(out
+        .iter()
+        .map(|wp| crate::MotionWaypoint { time: wp.time, x: wp.x, y: wp.y, altitude: wp.z })
+        .collect())
+}
+
+/// A safe wrapper for the stateless `fers_local_to_geodetic` C-API function.
+///
+/// # Parameters
+///
+/// * `origin` - The geodetic origin of the local frame as (latitude, longitude, altitude).
+/// * `waypoints` - The waypoints in the local ENU frame.
+///
+/// # Returns
+///
+/// * `Ok(Vec<GeoPoint>)` - The waypoints in WGS84 latitude/longitude/altitude, in input order.
+/// * `Err(String)` - If the origin is not a valid geodetic position.
+pub fn local_to_geodetic(
+    origin: (f64, f64, f64),
+    waypoints: &[crate::MotionWaypoint],
+) -> Result<Vec<crate::GeoPoint>, String> {
+    let c_waypoints: Vec<ffi::fers_motion_waypoint_t> = waypoints
+        .iter()
+        .map(|wp| ffi::fers_motion_waypoint_t { time: wp.time, x: wp.x, y: wp.y, z: wp.altitude })
+        .collect();
+    let mut out = vec![
+        ffi::fers_geodetic_waypoint_t {
+            time: 0.0,
+            latitude: 0.0,
+            longitude: 0.0,
+            altitude: 0.0
+        };
+        waypoints.len()
+    ];
+    // SAFETY: `c_waypoints` and `out` both hold exactly `waypoints.len()` elements.
+    let result = unsafe {
+        ffi::fers_local_to_geodetic(
+            origin.0,
+            origin.1,
+            origin.2,
+            c_waypoints.as_ptr(),
+            c_waypoints.len(),
+            out.as_mut_ptr(),
+        )
+    };
+    if result != 0 {
+        return Err(get_last_error());
+    }
+    Ok(out
+        .iter()
+        .map(|p| crate::GeoPoint {
+            time: p.time,
+            latitude: p.latitude,
+            longitude: p.longitude,
+            altitude: p.altitude,
+        })
+        .collect())
+}
+
 /// A safe wrapper for the stateless `fers_get_interpolated_rotation_path` C-API function.
 ///
 /// This function converts Rust-native rotation waypoints into C-compatible types,
@@ -810,3 +3678,510 @@ pub fn get_interpolated_rotation_path(
 
     Ok(points)
 }
+
+/// A safe wrapper for the stateless `fers_compare_output` C-API function.
+///
+/// # Parameters
+///
+/// * `actual_path` - The path of the HDF5 output file under test.
+/// * `reference_path` - The path of the reference HDF5 file.
+/// * `tolerance` - The largest acceptable sample difference.
+///
+/// # Returns
+///
+/// * `Ok(ComparisonReport)` - The maximum and RMS sample difference.
+/// * `Err(String)` - If a file could not be read or the files contain different windows.
+pub fn compare_output(
+    actual_path: &str,
+    reference_path: &str,
+    tolerance: f64,
+) -> Result<ComparisonReport, String> {
+    let c_actual_path = CString::new(actual_path).map_err(|e| e.to_string())?;
+    let c_reference_path = CString::new(reference_path).map_err(|e| e.to_string())?;
+    let mut out = ffi::fers_output_comparison_t {
+        max_difference: 0.0,
+        rms_difference: 0.0,
+        sample_count: 0,
+        within_tolerance: 0,
+    };
+    // SAFETY: We pass null-terminated strings and a valid output pointer.
+    let result = unsafe {
+        ffi::fers_compare_output(
+            c_actual_path.as_ptr(),
+            c_reference_path.as_ptr(),
+            tolerance,
+            &mut out,
+        )
+    };
+    if result != 0 {
+        return Err(get_last_error());
+    }
+    Ok(ComparisonReport {
+        max_difference: out.max_difference,
+        rms_difference: out.rms_difference,
+        sample_count: out.sample_count,
+        within_tolerance: out.within_tolerance != 0,
+    })
+}
+
+/// A safe wrapper for the stateless `fers_read_blanked_intervals` C-API function.
+///
+/// # Parameters
+///
+/// * `hdf5_path` - The path of a pulsed receiver's HDF5 output file.
+///
+/// # Returns
+///
+/// * `Ok(Vec<(f64, f64)>)` - The `(start, end)` times of every blanked interval, in window order.
+/// * `Err(String)` - If the file could not be read.
+pub fn read_blanked_intervals(hdf5_path: &str) -> Result<Vec<(f64, f64)>, String> {
+    let c_hdf5_path = CString::new(hdf5_path).map_err(|e| e.to_string())?;
+    // SAFETY: We pass a null-terminated C string.
+    let list_ptr = unsafe { ffi::fers_read_blanked_intervals(c_hdf5_path.as_ptr()) };
+    if list_ptr.is_null() {
+        return Err(get_last_error());
+    }
+
+    let owned_list = FersBlankedIntervalList(list_ptr);
+    // SAFETY: The pointer is non-null and valid for the lifetime of `owned_list`.
+    let list = unsafe { &*owned_list.0 };
+    if list.count == 0 || list.intervals.is_null() {
+        return Ok(Vec::new());
+    }
+    // SAFETY: `intervals` points to `count` initialized entries.
+    let slice = unsafe { std::slice::from_raw_parts(list.intervals, list.count) };
+    Ok(slice.iter().map(|i| (i.start_time, i.end_time)).collect())
+}
+
+/// Reads the debug trace CSV written by a run with the debug export enabled.
+///
+/// # Parameters
+///
+/// * `path` - The path of the debug trace file.
+///
+/// # Returns
+///
+/// * `Ok(DebugTrace)` - The traced pulse paths, in file order.
+/// * `Err(String)` - If the file could not be read or a row is malformed.
+pub fn read_debug_trace(path: &str) -> Result<DebugTrace, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read debug trace '{path}': {e}"))?;
+
+    let mut lines = contents.lines();
+    if lines.next().map(str::trim) != Some(DEBUG_TRACE_HEADER) {
+        return Err(format!("'{path}' is not a FERS debug trace"));
+    }
+
+    let mut records = Vec::new();
+    for (index, line) in lines.enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let invalid = || format!("Invalid debug trace row {}: '{line}'", index + 2);
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let [pulse_index, transmitter, receiver, target, numbers @ ..] = fields.as_slice() else {
+            return Err(invalid());
+        };
+        let numbers: Vec<f64> = numbers
+            .iter()
+            .map(|field| field.parse::<f64>())
+            .collect::<Result<_, _>>()
+            .map_err(|_| invalid())?;
+        let &[emission_time, delay, power_gain, phase, doppler] = numbers.as_slice() else {
+            return Err(invalid());
+        };
+        records.push(DebugTraceRecord {
+            pulse_index: pulse_index.parse().map_err(|_| invalid())?,
+            transmitter: transmitter.to_string(),
+            receiver: receiver.to_string(),
+            target: (!target.is_empty()).then(|| target.to_string()),
+            emission_time,
+            delay,
+            power_gain,
+            phase,
+            doppler,
+        });
+    }
+    Ok(DebugTrace { records })
+}
+
+/// A safe wrapper for the stateless `fers_validate_xml_file` C-API function.
+///
+/// The file is parsed into a scratch scenario, so no `FersContext` is modified.
+///
+/// # Parameters
+///
+/// * `filepath` - The path of the FERS XML scenario file.
+///
+/// # Returns
+///
+/// * `Ok(ValidationResult)` - Whether the file would load, with any errors and warnings.
+/// * `Err(String)` - If the validation itself could not be run.
+pub fn validate_xml_file(filepath: &str) -> Result<ValidationResult, String> {
+    let c_filepath = CString::new(filepath).map_err(|e| e.to_string())?;
+    // SAFETY: We pass a null-terminated C string.
+    let validation_ptr = unsafe { ffi::fers_validate_xml_file(c_filepath.as_ptr()) };
+    if validation_ptr.is_null() {
+        return Err(get_last_error());
+    }
+
+    let owned_validation = FersXmlValidation(validation_ptr);
+    // SAFETY: The pointer is non-null and valid for the lifetime of `owned_validation`.
+    let validation = unsafe { &*owned_validation.0 };
+    let errors = if validation.error_message.is_null() {
+        Vec::new()
+    } else {
+        // SAFETY: `error_message` is a valid null-terminated string owned by the C-API.
+        vec![unsafe { CStr::from_ptr(validation.error_message) }.to_string_lossy().into_owned()]
+    };
+    let warnings = if validation.warning_count == 0 || validation.warnings.is_null() {
+        Vec::new()
+    } else {
+        // SAFETY: `warnings` points to `warning_count` valid null-terminated strings.
+        unsafe { std::slice::from_raw_parts(validation.warnings, validation.warning_count) }
+            .iter()
+            .map(|&warning| {
+                // SAFETY: Each entry is a valid null-terminated string owned by the C-API.
+                unsafe { CStr::from_ptr(warning) }.to_string_lossy().into_owned()
+            })
+            .collect()
+    };
+
+    Ok(ValidationResult { valid: validation.valid != 0, errors, warnings })
+}
+
+/// A safe wrapper for the stateless `fers_calculate_detection_probabilities` C-API function.
+///
+/// # Parameters
+///
+/// * `snr_db` - The average single-pulse SNR in dB.
+/// * `threshold_db` - The detection threshold relative to the single-pulse noise power, in dB.
+/// * `num_integrated` - The number of non-coherently integrated pulses.
+/// * `swerling` - The target fluctuation case.
+///
+/// # Returns
+///
+/// * `Ok((f64, f64))` - The probability of detection and the probability of false alarm.
+/// * `Err(String)` - If no pulses are integrated or an input is not finite.
+pub fn calculate_detection_probabilities(
+    snr_db: f64,
+    threshold_db: f64,
+    num_integrated: usize,
+    swerling: SwerlingCase,
+) -> Result<(f64, f64), String> {
+    let c_swerling = match swerling {
+        SwerlingCase::Swerling0 => ffi::fers_swerling_case_t_FERS_SWERLING_0,
+        SwerlingCase::Swerling1 => ffi::fers_swerling_case_t_FERS_SWERLING_1,
+        SwerlingCase::Swerling2 => ffi::fers_swerling_case_t_FERS_SWERLING_2,
+        SwerlingCase::Swerling3 => ffi::fers_swerling_case_t_FERS_SWERLING_3,
+        SwerlingCase::Swerling4 => ffi::fers_swerling_case_t_FERS_SWERLING_4,
+    };
+    let mut pd = 0.0;
+    let mut pfa = 0.0;
+    // SAFETY: We pass valid output pointers to a stateless function.
+    let result = unsafe {
+        ffi::fers_calculate_detection_probabilities(
+            snr_db,
+            threshold_db,
+            num_integrated,
+            c_swerling,
+            &mut pd,
+            &mut pfa,
+        )
+    };
+    if result != 0 {
+        return Err(get_last_error());
+    }
+    Ok((pd, pfa))
+}
+
+/// Calculates the matched-filter SNR gain of an LFM pulse.
+///
+/// # Parameters
+///
+/// * `pulse` - The pulse description.
+///
+/// # Returns
+///
+/// * `Ok(f64)` - The pulse compression gain `10 log10(B * tau)` in dB.
+/// * `Err(String)` - If the pulse is not LFM or its bandwidth or duration is not positive.
+pub fn calculate_pulse_compression_gain(pulse: &crate::Pulse) -> Result<f64, String> {
+    let crate::Pulse::Lfm { bandwidth, duration } = *pulse else {
+        return Err("Pulse compression gain is only defined for LFM pulses".to_string());
+    };
+    let mut gain_db = 0.0;
+    // SAFETY: We pass a valid output pointer to a stateless function.
+    let result =
+        unsafe { ffi::fers_calculate_pulse_compression_gain(bandwidth, duration, &mut gain_db) };
+    if result != 0 {
+        return Err(get_last_error());
+    }
+    Ok(gain_db)
+}
+
+/// Maps a level in dB from `libfers`, which reports a null as negative infinity, to `None`
+/// at a null so it serializes as JSON `null`.
+fn finite_db(db: f64) -> Option<f64> {
+    db.is_finite().then_some(db)
+}
+
+/// Computes the energy spectral density of a pulse about its carrier.
+///
+/// # Parameters
+///
+/// * `pulse` - The pulse, either a built-in shape or a waveform file.
+/// * `num_bins` - The number of frequency bins, a power of two of at least 2.
+///
+/// # Returns
+///
+/// * `Ok(Vec<(f64, Option<f64>)>)` - `(frequency_offset_hz, power_db)` pairs, normalized to
+///   the peak, with `None` marking a null.
+/// * `Err(String)` - If the pulse is invalid, the file cannot be loaded or `num_bins` is not
+///   a power of two.
+pub fn compute_waveform_spectrum(
+    pulse: &crate::Pulse,
+    num_bins: usize,
+) -> Result<Vec<(f64, Option<f64>)>, String> {
+    let mut c_filename = None;
+    let (modulation, bandwidth, duration) = match pulse {
+        crate::Pulse::Unmodulated { duration } => {
+            (ffi::fers_pulse_modulation_t_FERS_PULSE_UNMODULATED, 0.0, *duration)
+        }
+        crate::Pulse::Lfm { bandwidth, duration } => {
+            (ffi::fers_pulse_modulation_t_FERS_PULSE_LFM, *bandwidth, *duration)
+        }
+        crate::Pulse::File { filename } => {
+            c_filename = Some(CString::new(filename.as_str()).map_err(|e| e.to_string())?);
+            (ffi::fers_pulse_modulation_t_FERS_PULSE_FILE, 0.0, 0.0)
+        }
+    };
+    let def = ffi::fers_pulse_t {
+        modulation,
+        bandwidth,
+        duration,
+        filename: c_filename.as_ref().map_or(std::ptr::null(), |name| name.as_ptr()),
+    };
+
+    let mut freqs = vec![0.0; num_bins];
+    let mut power_db = vec![0.0; num_bins];
+    // SAFETY: `def` and its filename outlive the call, and both output buffers hold
+    // `num_bins` doubles.
+    let result = unsafe {
+        ffi::fers_compute_waveform_spectrum(
+            &def,
+            num_bins,
+            freqs.as_mut_ptr(),
+            power_db.as_mut_ptr(),
+        )
+    };
+    if result != 0 {
+        return Err(get_last_error());
+    }
+    Ok(freqs.into_iter().zip(power_db.into_iter().map(finite_db)).collect())
+}
+
+/// An antenna definition laid out for `libfers`, along with the storage its pointers refer to.
+struct FfiAntenna<'a> {
+    def: ffi::fers_antenna_t,
+    // Owns the filename `def` points to.
+    _filename: Option<CString>,
+    // Ties `def` to the antenna whose taper it points into.
+    _antenna: std::marker::PhantomData<&'a crate::Antenna>,
+}
+
+impl<'a> FfiAntenna<'a> {
+    /// Builds the `libfers` definition of an antenna received from the UI.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern filename contains a null byte.
+    fn new(antenna: &'a crate::Antenna) -> Result<Self, String> {
+        let mut def = ffi::fers_antenna_t {
+            pattern: ffi::fers_antenna_pattern_t_FERS_ANTENNA_ISOTROPIC,
+            efficiency: antenna.efficiency.unwrap_or(1.0),
+            alpha: 0.0,
+            beta: 0.0,
+            gamma: 0.0,
+            azscale: 0.0,
+            elscale: 0.0,
+            diameter: 0.0,
+            elements: 0,
+            spacing: 0.0,
+            taper: std::ptr::null(),
+            taper_count: 0,
+            filename: std::ptr::null(),
+        };
+        let mut c_filename = None;
+        match &antenna.pattern {
+            crate::AntennaPattern::Isotropic => {}
+            crate::AntennaPattern::Sinc { alpha, beta, gamma } => {
+                def.pattern = ffi::fers_antenna_pattern_t_FERS_ANTENNA_SINC;
+                (def.alpha, def.beta, def.gamma) = (*alpha, *beta, *gamma);
+            }
+            crate::AntennaPattern::Gaussian { azscale, elscale } => {
+                def.pattern = ffi::fers_antenna_pattern_t_FERS_ANTENNA_GAUSSIAN;
+                (def.azscale, def.elscale) = (*azscale, *elscale);
+            }
+            crate::AntennaPattern::Squarehorn { diameter } => {
+                def.pattern = ffi::fers_antenna_pattern_t_FERS_ANTENNA_SQUAREHORN;
+                def.diameter = *diameter;
+            }
+            crate::AntennaPattern::Parabolic { diameter } => {
+                def.pattern = ffi::fers_antenna_pattern_t_FERS_ANTENNA_PARABOLIC;
+                def.diameter = *diameter;
+            }
+            crate::AntennaPattern::Array { elements, spacing, taper } => {
+                def.pattern = ffi::fers_antenna_pattern_t_FERS_ANTENNA_ARRAY;
+                (def.elements, def.spacing) = (*elements, *spacing);
+                (def.taper, def.taper_count) = (taper.as_ptr(), taper.len());
+            }
+            crate::AntennaPattern::Xml { filename } => {
+                def.pattern = ffi::fers_antenna_pattern_t_FERS_ANTENNA_XML;
+                let name = CString::new(filename.as_str()).map_err(|e| e.to_string())?;
+                def.filename = c_filename.insert(name).as_ptr();
+            }
+            crate::AntennaPattern::File { filename } => {
+                def.pattern = ffi::fers_antenna_pattern_t_FERS_ANTENNA_FILE;
+                let name = CString::new(filename.as_str()).map_err(|e| e.to_string())?;
+                def.filename = c_filename.insert(name).as_ptr();
+            }
+        }
+        Ok(Self { def, _filename: c_filename, _antenna: std::marker::PhantomData })
+    }
+}
+
+/// Samples the gain of an antenna definition along an azimuth cut at fixed elevation.
+///
+/// # Parameters
+///
+/// * `antenna` - The antenna definition.
+/// * `az_range` - The first and last azimuth of the cut in degrees.
+/// * `el_cut` - The elevation of the cut in degrees.
+/// * `num_points` - The number of evenly spaced azimuths, including both ends.
+///
+/// # Returns
+///
+/// * `Ok(Vec<Option<f64>>)` - The gains in dBi, with `None` marking a null.
+/// * `Err(String)` - If a pattern file cannot be loaded or `num_points` is zero.
+pub fn sample_antenna_pattern(
+    antenna: &crate::Antenna,
+    az_range: (f64, f64),
+    el_cut: f64,
+    num_points: usize,
+) -> Result<Vec<Option<f64>>, String> {
+    let ffi_antenna = FfiAntenna::new(antenna)?;
+    let mut gains_db = vec![0.0; num_points];
+    // SAFETY: `ffi_antenna` keeps the definition's pointers valid for the call, and
+    // `gains_db` holds `num_points` elements.
+    let result = unsafe {
+        ffi::fers_sample_antenna_pattern(
+            &ffi_antenna.def,
+            az_range.0,
+            az_range.1,
+            el_cut,
+            antenna.design_frequency.unwrap_or(0.0),
+            num_points,
+            gains_db.as_mut_ptr(),
+        )
+    };
+    if result != 0 {
+        return Err(get_last_error());
+    }
+    Ok(gains_db.into_iter().map(finite_db).collect())
+}
+
+/// Computes the effective aperture of an antenna definition from its sampled peak gain.
+///
+/// # Parameters
+///
+/// * `antenna` - The antenna definition.
+/// * `frequency` - The carrier frequency in Hz.
+///
+/// # Returns
+///
+/// * `Ok((f64, Option<f64>))` - The effective aperture `G * lambda^2 / (4 * pi)` in m², then
+///   the physical aperture times efficiency in m² for parabolic and square horn antennas,
+///   or `None` for patterns without a diameter.
+/// * `Err(String)` - If a pattern file cannot be loaded or the frequency is not positive.
+pub fn calculate_effective_aperture(
+    antenna: &crate::Antenna,
+    frequency: f64,
+) -> Result<(f64, Option<f64>), String> {
+    let ffi_antenna = FfiAntenna::new(antenna)?;
+    let mut effective = 0.0;
+    let mut physical = 0.0;
+    // SAFETY: `ffi_antenna` keeps the definition's pointers valid for the call, and both
+    // output pointers are valid.
+    let result = unsafe {
+        ffi::fers_calculate_effective_aperture(
+            &ffi_antenna.def,
+            frequency,
+            &mut effective,
+            &mut physical,
+        )
+    };
+    if result != 0 {
+        return Err(get_last_error());
+    }
+    Ok((effective, (!physical.is_nan()).then_some(physical)))
+}
+
+/// Computes the -3 dB beamwidths of an antenna definition's main lobe.
+///
+/// # Parameters
+///
+/// * `antenna` - The antenna definition.
+/// * `frequency` - The carrier frequency in Hz.
+///
+/// # Returns
+///
+/// * `Ok((f64, f64))` - The full azimuth and elevation beamwidths in degrees.
+/// * `Err(String)` - If a pattern file cannot be loaded, the frequency is not positive, or
+///   the pattern has no main lobe at boresight that falls 3 dB below its peak.
+pub fn compute_beamwidth(antenna: &crate::Antenna, frequency: f64) -> Result<(f64, f64), String> {
+    let ffi_antenna = FfiAntenna::new(antenna)?;
+    let mut azimuth = 0.0;
+    let mut elevation = 0.0;
+    // SAFETY: `ffi_antenna` keeps the definition's pointers valid for the call, and both
+    // output pointers are valid.
+    let result = unsafe {
+        ffi::fers_compute_beamwidth(&ffi_antenna.def, frequency, &mut azimuth, &mut elevation)
+    };
+    if result != 0 {
+        return Err(get_last_error());
+    }
+    Ok((azimuth, elevation))
+}
+
+/// Parses the RCS file of a file target and samples its pattern.
+///
+/// # Parameters
+///
+/// * `filename` - The path to the target description file.
+///
+/// # Returns
+///
+/// * `Ok(RcsPreview)` - The RCS at every azimuth and elevation sample pair, with its range.
+/// * `Err(String)` - If the file is missing, malformed or not made of azimuth and elevation cuts.
+pub fn load_rcs_file(filename: &str) -> Result<RcsPreview, String> {
+    let c_filename = CString::new(filename).map_err(|e| e.to_string())?;
+    // SAFETY: We pass a valid null-terminated string to a stateless function.
+    let preview_ptr = unsafe { ffi::fers_load_rcs_file(c_filename.as_ptr()) };
+    if preview_ptr.is_null() {
+        return Err(get_last_error());
+    }
+
+    let owned_preview = FersRcsPreview(preview_ptr);
+    // SAFETY: The pointer is non-null and valid for the lifetime of `owned_preview`.
+    let preview = unsafe { &*owned_preview.0 };
+    let samples = if preview.count == 0 || preview.samples.is_null() {
+        Vec::new()
+    } else {
+        // SAFETY: `samples` points to `count` initialized entries.
+        unsafe { std::slice::from_raw_parts(preview.samples, preview.count) }
+            .iter()
+            .map(|s| RcsSample { azimuth: s.azimuth_deg, elevation: s.elevation_deg, value: s.rcs })
+            .collect()
+    };
+    Ok(RcsPreview { samples, min: preview.min_rcs, max: preview.max_rcs })
+}
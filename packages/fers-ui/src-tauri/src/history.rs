@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+//! # Scenario History
+//!
+//! A bounded undo/redo history of scenario states, each stored as the scenario JSON
+//! produced by the engine. The oldest state is discarded once the history is full, and
+//! recording a new state discards the redo branch.
+
+use std::collections::VecDeque;
+
+/// Number of scenario states kept for undo unless configured otherwise.
+pub const DEFAULT_HISTORY_DEPTH: usize = 50;
+
+/// Undo and redo stacks of serialized scenario states.
+pub struct ScenarioHistory {
+    undo: VecDeque<String>,
+    redo: Vec<String>,
+    /// The oldest states the last `redo` discarded for capacity, kept so `revert` can
+    /// restore them.
+    evicted: Vec<String>,
+    depth: usize,
+}
+
+impl ScenarioHistory {
+    /// Creates an empty history that keeps at most `depth` states for undo.
+    pub fn new(depth: usize) -> Self {
+        Self { undo: VecDeque::new(), redo: Vec::new(), evicted: Vec::new(), depth }
+    }
+
+    /// Records the state that a change is about to replace and discards the redo branch.
+    ///
+    /// # Parameters
+    ///
+    /// * `previous` - The scenario JSON before the change.
+    pub fn record(&mut self, previous: String) {
+        self.redo.clear();
+        self.undo.push_back(previous);
+        self.trim();
+    }
+
+    /// Steps back one state.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The scenario JSON being replaced, which becomes redoable.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(String)` - The state to restore.
+    /// * `None` - If there is nothing to undo; `current` is not kept.
+    pub fn undo(&mut self, current: String) -> Option<String> {
+        self.evicted.clear();
+        let previous = self.undo.pop_back()?;
+        self.redo.push(current);
+        Some(previous)
+    }
+
+    /// Steps forward one state after an undo.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The scenario JSON being replaced, which becomes undoable again.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(String)` - The state to restore.
+    /// * `None` - If there is nothing to redo; `current` is not kept.
+    pub fn redo(&mut self, current: String) -> Option<String> {
+        let next = self.redo.pop()?;
+        self.undo.push_back(current);
+        self.evicted = self.trim();
+        Some(next)
+    }
+
+    /// Reverses the last `undo` or `redo` when its state could not be restored.
+    ///
+    /// # Parameters
+    ///
+    /// * `restored` - The state that `undo` or `redo` returned.
+    /// * `was_undo` - Whether the step being reverted was an undo.
+    pub fn revert(&mut self, restored: String, was_undo: bool) {
+        if was_undo {
+            self.redo.pop();
+            self.undo.push_back(restored);
+        } else {
+            self.undo.pop_back();
+            self.redo.push(restored);
+            // Put back the baseline states the redo trimmed to make room
+            for state in std::mem::take(&mut self.evicted).into_iter().rev() {
+                self.undo.push_front(state);
+            }
+        }
+    }
+
+    /// Changes the number of states kept for undo, discarding the oldest if needed.
+    pub fn set_depth(&mut self, depth: usize) {
+        self.depth = depth;
+        self.trim();
+    }
+
+    /// Discards all undo and redo states.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.evicted.clear();
+    }
+
+    /// Discards the oldest undo states beyond the configured depth and returns them,
+    /// oldest first.
+    fn trim(&mut self) -> Vec<String> {
+        let excess = self.undo.len().saturating_sub(self.depth);
+        self.undo.drain(..excess).collect()
+    }
+}
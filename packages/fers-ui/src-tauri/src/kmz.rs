@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+//! # KMZ Export
+//!
+//! Packages the KML visualization of a scenario into a single KMZ archive: a ZIP file
+//! whose first entry is `doc.kml`. Icons the KML references by local file path are
+//! embedded under `files/` and their `<href>` rewritten to point into the archive;
+//! remote icon URLs are left untouched.
+
+use std::borrow::Cow;
+use std::io::Write;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::fers_api::FersContext;
+
+/// Name of the KML document inside the archive, as expected by KMZ readers.
+const DOC_KML: &str = "doc.kml";
+
+/// Distinguishes the temporary KML files of concurrent exports.
+static EXPORT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// A file stored in the archive.
+struct Entry {
+    name: String,
+    data: Vec<u8>,
+}
+
+/// Generates the KML for the scenario in `context` and writes it as a KMZ archive.
+///
+/// # Parameters
+/// * `context` - The context holding the scenario.
+/// * `output_path` - The path of the `.kmz` file to write.
+/// * `origin` - If set, the (latitude, longitude, altitude) of the ENU origin to use in
+///   place of the scenario's own.
+///
+/// # Returns
+/// * `Ok(())` - If the archive was written.
+/// * `Err(String)` - If KML generation failed or a file could not be read or written.
+pub fn generate_kmz(
+    context: &FersContext,
+    output_path: &str,
+    origin: Option<(f64, f64, f64)>,
+) -> Result<(), String> {
+    let kml_path = std::env::temp_dir().join(format!(
+        "fers_kmz_{}_{}.kml",
+        std::process::id(),
+        EXPORT_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let kml_path_str = kml_path.to_str().ok_or("Temporary directory path is not valid UTF-8")?;
+    let generated = context.generate_kml(kml_path_str, origin).and_then(|()| {
+        std::fs::read_to_string(&kml_path).map_err(|e| format!("Failed to read generated KML: {e}"))
+    });
+    let _ = std::fs::remove_file(&kml_path);
+    write_kmz(&generated?, output_path)
+}
+
+/// Writes a KML document and the local icon files it references into a KMZ archive.
+///
+/// # Parameters
+/// * `kml` - The KML document.
+/// * `output_path` - The path of the `.kmz` file to write.
+///
+/// # Returns
+/// * `Ok(())` - If the archive was written.
+/// * `Err(String)` - If a referenced icon or the output file could not be accessed.
+pub fn write_kmz(kml: &str, output_path: &str) -> Result<(), String> {
+    let (doc, resources) = embed_local_resources(kml)?;
+    let mut entries = vec![Entry { name: DOC_KML.to_string(), data: doc.into_bytes() }];
+    entries.extend(resources);
+
+    let write_error =
+        |e: &dyn std::fmt::Display| format!("Failed to write KMZ '{output_path}': {e}");
+    let file = std::fs::File::create(output_path).map_err(|e| write_error(&e))?;
+    let mut archive = ZipWriter::new(file);
+    // Every entry is dated 1980-01-01, the earliest ZIP timestamp, so the same scenario
+    // always gives the same archive
+    let options = SimpleFileOptions::default()
+        .compression_method(CompressionMethod::Deflated)
+        .last_modified_time(zip::DateTime::default());
+    for entry in &entries {
+        archive.start_file(entry.name.as_str(), options).map_err(|e| write_error(&e))?;
+        archive.write_all(&entry.data).map_err(|e| write_error(&e))?;
+    }
+    archive.finish().map_err(|e| write_error(&e))?;
+    Ok(())
+}
+
+/// Replaces every `<href>` that names an existing local file with its path inside the
+/// archive, returning the rewritten KML and the files to embed.
+fn embed_local_resources(kml: &str) -> Result<(String, Vec<Entry>), String> {
+    let mut doc = String::with_capacity(kml.len());
+    let mut resources: Vec<(String, Entry)> = Vec::new();
+    let mut rest = kml;
+    while let Some(start) = rest.find("<href>") {
+        let value_start = start + "<href>".len();
+        let Some(length) = rest[value_start..].find("</href>") else {
+            break;
+        };
+        let href = rest[value_start..value_start + length].trim();
+        let path = unescape_xml(href);
+        doc.push_str(&rest[..value_start]);
+
+        let local = Path::new(path.as_ref());
+        if path.contains("://") || !local.is_file() {
+            doc.push_str(href);
+        } else if let Some((_, entry)) = resources.iter().find(|(source, _)| *source == path) {
+            doc.push_str(&escape_xml(&entry.name));
+        } else {
+            let file_name = local.file_name().unwrap_or_default().to_string_lossy();
+            let name = format!("files/{}_{file_name}", resources.len());
+            let data = std::fs::read(local)
+                .map_err(|e| format!("Failed to read KML resource '{path}': {e}"))?;
+            doc.push_str(&escape_xml(&name));
+            resources.push((path.into_owned(), Entry { name, data }));
+        }
+        rest = &rest[value_start + length..];
+    }
+    doc.push_str(rest);
+    Ok((doc, resources.into_iter().map(|(_, entry)| entry).collect()))
+}
+
+/// Resolves the predefined and numeric character references in XML text.
+fn unescape_xml(text: &str) -> Cow<'_, str> {
+    if !text.contains('&') {
+        return Cow::Borrowed(text);
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let resolved = rest.find(';').and_then(|end| {
+            let character = match &rest[1..end] {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                reference => reference
+                    .strip_prefix("#x")
+                    .map(|hex| u32::from_str_radix(hex, 16))
+                    .or_else(|| reference.strip_prefix('#').map(str::parse))
+                    .and_then(Result::ok)
+                    .and_then(char::from_u32),
+            };
+            character.map(|c| (c, end))
+        });
+        match resolved {
+            Some((character, end)) => {
+                out.push(character);
+                rest = &rest[end + 1..];
+            }
+            // Not a reference, so the ampersand is kept as written
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    Cow::Owned(out)
+}
+
+/// Escapes the characters that cannot appear literally in XML text.
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// Reads every entry of a ZIP archive in order, checking each entry's CRC-32.
+#[cfg(test)]
+pub(crate) fn read_zip(path: &str) -> Result<Vec<(String, Vec<u8>)>, String> {
+    use std::io::Read;
+
+    let file = std::fs::File::open(path).map_err(|e| e.to_string())?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| e.to_string())?;
+    let mut entries = Vec::with_capacity(archive.len());
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index).map_err(|e| e.to_string())?;
+        let mut data = Vec::new();
+        // The CRC-32 is verified once the entry has been read to the end
+        entry.read_to_end(&mut data).map_err(|e| e.to_string())?;
+        entries.push((entry.name().to_string(), data));
+    }
+    Ok(entries)
+}
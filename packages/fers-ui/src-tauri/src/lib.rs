@@ -30,8 +30,13 @@
 //! Tauri's IPC mechanism. They can be invoked asynchronously from JavaScript/TypeScript.
 
 mod fers_api;
+mod history;
+mod kmz;
+mod streaming;
+mod thumbnail;
 
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 use tauri::{AppHandle, Emitter, Manager, State};
 
 /// Data structure for a single motion waypoint received from the UI.
@@ -49,6 +54,143 @@ pub struct MotionWaypoint {
     altitude: f64,
 }
 
+/// A motion waypoint in WGS84 geodetic coordinates, as planned in the field.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GeoPoint {
+    /// Time in seconds.
+    time: f64,
+    /// Latitude in decimal degrees.
+    latitude: f64,
+    /// Longitude in decimal degrees.
+    longitude: f64,
+    /// Altitude above the WGS84 ellipsoid in meters.
+    altitude: f64,
+}
+
+/// Gaussian position noise applied to a platform's nominal path, received from the UI.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct PositionNoise {
+    /// Standard deviation of the X deviation in meters.
+    x: f64,
+    /// Standard deviation of the Y deviation in meters.
+    y: f64,
+    /// Standard deviation of the Z deviation in meters.
+    z: f64,
+    /// Interval between independent draws in seconds. Defaults to 1.
+    #[serde(default)]
+    correlation_time: Option<f64>,
+    /// Seed selecting the noise realization.
+    #[serde(default)]
+    seed: u32,
+}
+
+/// Optional settings for previewing an interpolated motion path, received from the UI.
+#[derive(Default, serde::Deserialize)]
+pub struct PathPreviewOptions {
+    /// If true, cubic paths are approximated piecewise-linearly for faster interactive
+    /// previews. The simulation itself always uses exact interpolation.
+    #[serde(default)]
+    fast: bool,
+    /// The maximum position error in meters for the fast mode. Defaults to
+    /// `DEFAULT_PATH_TOLERANCE`.
+    #[serde(default)]
+    tolerance: Option<f64>,
+    /// If set, the platform's position noise realization is added to the preview so it
+    /// shows the path the simulation will actually follow.
+    #[serde(default)]
+    noise: Option<PositionNoise>,
+    /// The start of the previewed window in seconds. Defaults to the first waypoint and
+    /// is clamped to it.
+    #[serde(default)]
+    t_start: Option<f64>,
+    /// The end of the previewed window in seconds. Defaults to the last waypoint and is
+    /// clamped to it.
+    #[serde(default)]
+    t_end: Option<f64>,
+}
+
+/// A frequency-hopping specification for a pulsed transmitter, received from the UI.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct HopSequence {
+    /// The hop set of carrier frequencies in Hz.
+    frequencies: Vec<f64>,
+    /// Indices into `frequencies` giving the hop order. Empty cycles through the hop set.
+    #[serde(default)]
+    pattern: Vec<u32>,
+}
+
+/// A waveform defined by I/Q samples given inline in the scenario, received from the UI.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct InlineWaveform {
+    /// Comma-separated I,Q pairs, e.g. `1,0, 0,1`.
+    samples: String,
+    /// The sample rate in Hz.
+    rate: f64,
+}
+
+/// A single radar pulse received from the UI, tagged by its `modulation` name.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "modulation", rename_all = "lowercase")]
+pub enum Pulse {
+    /// A linear frequency-modulated chirp of the given swept bandwidth in Hz and
+    /// duration in seconds.
+    Lfm { bandwidth: f64, duration: f64 },
+    /// A constant-frequency rectangular pulse of the given duration in seconds.
+    Unmodulated { duration: f64 },
+    /// Samples read from a CSV or HDF5 waveform file.
+    File { filename: String },
+}
+
+/// An antenna definition received from the UI, which need not be part of the scenario yet.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Antenna {
+    /// The name of the scenario antenna this definition was edited from, if any.
+    #[serde(default)]
+    name: Option<String>,
+    /// The aperture efficiency factor. Defaults to 1.
+    #[serde(default)]
+    efficiency: Option<f64>,
+    /// The frequency in Hz at which wavelength-dependent patterns are evaluated.
+    #[serde(default)]
+    design_frequency: Option<f64>,
+    /// The gain pattern and its parameters.
+    #[serde(flatten)]
+    pattern: AntennaPattern,
+}
+
+/// The gain pattern of an antenna received from the UI, tagged by its `pattern` name.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "pattern", rename_all = "lowercase")]
+pub enum AntennaPattern {
+    /// Unit gain in every direction.
+    Isotropic,
+    /// A `sinc`-shaped main beam.
+    Sinc { alpha: f64, beta: f64, gamma: f64 },
+    /// A Gaussian main beam.
+    Gaussian { azscale: f64, elscale: f64 },
+    /// A square horn of the given aperture dimension in meters.
+    Squarehorn { diameter: f64 },
+    /// A parabolic dish of the given diameter in meters.
+    Parabolic { diameter: f64 },
+    /// A uniform linear array with an optional amplitude taper.
+    Array {
+        elements: usize,
+        spacing: f64,
+        #[serde(default)]
+        taper: Vec<f64>,
+    },
+    /// A pattern read from an XML description.
+    Xml {
+        #[serde(default)]
+        filename: String,
+    },
+    /// A pattern read from an HDF5 or `.pat` file.
+    File {
+        #[serde(default)]
+        filename: String,
+    },
+}
+
 /// Enum for the interpolation type received from the UI.
 #[derive(serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -97,6 +239,27 @@ pub struct InterpolatedRotationPoint {
     elevation_deg: f64,
 }
 
+/// A regular sampling grid on a horizontal ground plane, received from the UI.
+///
+/// Coordinates are in the scenario's local frame (e.g. ENU), in meters.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct CoverageGrid {
+    /// Minimum X (East) coordinate.
+    x_min: f64,
+    /// Maximum X (East) coordinate.
+    x_max: f64,
+    /// Minimum Y (North) coordinate.
+    y_min: f64,
+    /// Maximum Y (North) coordinate.
+    y_max: f64,
+    /// Altitude of the ground plane.
+    z: f64,
+    /// Number of samples along X (at least 2).
+    nx: usize,
+    /// Number of samples along Y (at least 2).
+    ny: usize,
+}
+
 /// Type alias for the managed Tauri state that holds the simulation context.
 ///
 /// The `FersContext` is wrapped in a `Mutex` to ensure thread-safe access, as Tauri
@@ -104,6 +267,18 @@ pub struct InterpolatedRotationPoint {
 /// the function signatures of Tauri commands.
 type FersState = Mutex<fers_api::FersContext>;
 
+/// Type alias for the managed cancellation flag of the running simulation.
+///
+/// The flag lives outside `FersState` because the simulation thread holds the context
+/// lock for the whole run; `cancel_simulation` must be able to set it meanwhile.
+type CancelState = Arc<AtomicBool>;
+
+/// Default maximum position error in meters for fast motion path previews.
+const DEFAULT_PATH_TOLERANCE: f64 = 0.5;
+
+/// Number of samples per platform track when rendering scenario thumbnails.
+const THUMBNAIL_TRACK_POINTS: usize = 256;
+
 // --- Tauri Commands ---
 
 /// Loads a FERS scenario from an XML file into the simulation context.
@@ -136,6 +311,120 @@ fn load_scenario_from_xml_file(
     state.lock().map_err(|e| e.to_string())?.load_scenario_from_xml_file(&filepath)
 }
 
+/// Imports the waveforms, timings and antennas of another scenario file into the current one.
+///
+/// Existing assets, platforms and parameters are left intact. Imported items whose names
+/// are already taken get a numbered suffix such as " (2)", and imported components are
+/// pointed at the renamed assets. The frontend assigns fresh IDs to the new items when it
+/// reloads the scenario.
+///
+/// # Parameters
+///
+/// * `filepath` - The path of the FERS XML scenario file to import from.
+/// * `include_platforms` - Whether to import the file's platforms as well.
+/// * `state` - Tauri-managed state containing the shared `FersContext`.
+///
+/// # Returns
+///
+/// * `Ok(MergeSummary)` - How many waveforms, timings, antennas and platforms were added.
+/// * `Err(String)` - Error if the file could not be loaded; the scenario is unchanged.
+#[tauri::command]
+fn merge_scenario_from_xml_file(
+    filepath: String,
+    include_platforms: bool,
+    state: State<'_, FersState>,
+) -> Result<fers_api::MergeSummary, String> {
+    state
+        .lock()
+        .map_err(|e| e.to_string())?
+        .merge_scenario_from_xml_file(&filepath, include_platforms)
+}
+
+/// Checks that a FERS XML scenario file would load, without loading it.
+///
+/// The file is parsed and schema-validated into a throwaway scenario, so the scenario
+/// held in the shared `FersContext` is left untouched whatever the outcome.
+///
+/// # Parameters
+///
+/// * `filepath` - The absolute or relative path to the FERS XML scenario file.
+///
+/// # Returns
+///
+/// * `Ok(ValidationResult)` - Whether the file would load, with any errors and warnings.
+/// * `Err(String)` - Error if the validation could not be run (e.g. an invalid path string).
+#[tauri::command]
+fn validate_xml_file(filepath: String) -> Result<fers_api::ValidationResult, String> {
+    fers_api::validate_xml_file(&filepath)
+}
+
+/// Checks the loaded scenario for NaN and infinite numbers and for transmitters that
+/// exceed their maximum duty cycle.
+///
+/// Hand-edited files or bad imports can introduce non-finite values, which the XML
+/// export cannot write in a form the parser reads back. Each such error names the field by
+/// its path in the scenario JSON, e.g. `platforms[0].motionpath.positionwaypoints[1].x is NaN`.
+/// Duty-cycle errors name the transmitter and give its pulse length times PRF.
+///
+/// Pulsed receivers and monostatic radars whose `window_skip + window_length` exceeds the
+/// PRI `1 / prf` are reported as warnings, since consecutive windows would overlap.
+///
+/// # Parameters
+///
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+///
+/// * `Ok(ValidationResult)` - Whether the scenario passed, with one error per bad field
+///   or transmitter and one warning per receiver with overlapping windows.
+/// * `Err(String)` - Error if the scenario could not be checked.
+#[tauri::command]
+fn validate_scenario(state: State<'_, FersState>) -> Result<fers_api::ValidationResult, String> {
+    state.lock().map_err(|e| e.to_string())?.validate_scenario()
+}
+
+/// Adds platforms to the scenario from a captured log of DIS Entity State PDUs.
+///
+/// Each unique entity id becomes a platform whose motion waypoints are its successive
+/// position updates. DIS geocentric coordinates are mapped into the scenario frame using
+/// the scenario origin.
+///
+/// # Parameters
+///
+/// * `path` - The path of the binary PDU log.
+/// * `state` - Tauri-managed state containing the shared `FersContext`.
+///
+/// # Returns
+///
+/// * `Ok(usize)` - The number of platforms imported.
+/// * `Err(String)` - An error message if the log could not be parsed or an imported
+///   platform name is already in use.
+#[tauri::command]
+fn import_from_dis(path: String, state: State<'_, FersState>) -> Result<usize, String> {
+    state.lock().map_err(|e| e.to_string())?.import_dis_entity_states(&path)
+}
+
+/// Replaces the scenario with the platform geometry recorded in a FERS output file.
+///
+/// Simulation output files carry the motion and rotation paths of every platform in the
+/// run. The reconstructed scenario contains those platforms and their waypoints only;
+/// waveforms, antennas, timing sources and radar components must be added again.
+///
+/// # Parameters
+/// * `hdf5_path` - The path of an HDF5 receiver output file.
+/// * `state` - Tauri-managed state containing the shared `FersContext`.
+///
+/// # Returns
+/// * `Ok(())` - If the scenario was reconstructed.
+/// * `Err(String)` - If the file could not be read or has no geometry metadata.
+#[tauri::command]
+fn import_scenario_from_output(
+    hdf5_path: String,
+    state: State<'_, FersState>,
+) -> Result<(), String> {
+    state.lock().map_err(|e| e.to_string())?.import_scenario_from_output(&hdf5_path)
+}
+
 /// Retrieves the current in-memory scenario as a JSON string.
 ///
 /// This command serializes the simulation state into JSON format, allowing the
@@ -172,6 +461,8 @@ fn get_scenario_as_json(state: State<'_, FersState>) -> Result<String, String> {
 ///
 /// # Parameters
 ///
+/// * `checksum` - Whether to append a comment holding the SHA-256 of the document, which
+///   is verified when the file is loaded again to detect corruption or hand edits.
 /// * `state` - Tauri-managed state containing the shared `FersContext`.
 ///
 /// # Returns
@@ -188,8 +479,36 @@ fn get_scenario_as_json(state: State<'_, FersState>) -> Result<String, String> {
 /// // Save scenarioXml to a file using Tauri's fs plugin
 /// ```
 #[tauri::command]
-fn get_scenario_as_xml(state: State<'_, FersState>) -> Result<String, String> {
-    state.lock().map_err(|e| e.to_string())?.get_scenario_as_xml()
+fn get_scenario_as_xml(
+    checksum: Option<bool>,
+    state: State<'_, FersState>,
+) -> Result<String, String> {
+    let context = state.lock().map_err(|e| e.to_string())?;
+    if checksum.unwrap_or(false) {
+        context.get_scenario_as_xml_with_checksum()
+    } else {
+        context.get_scenario_as_xml()
+    }
+}
+
+/// Retrieves the current scenario as FERS XML after validating it against `fers-xml.dtd`.
+///
+/// Use this before writing an export that other tools must accept; an invalid document
+/// is reported instead of returned.
+///
+/// # Parameters
+///
+/// * `state` - Tauri-managed state containing the shared `FersContext`.
+///
+/// # Returns
+///
+/// * `Ok(String)` containing the validated XML representation of the scenario.
+/// * `Err(String)` containing the first validation error, a serialization error, or an
+///   error if the Mutex could not be locked.
+#[tauri::command]
+fn get_scenario_as_xml_validated(state: State<'_, FersState>) -> Result<String, String> {
+    let context = state.lock().map_err(|e| e.to_string())?;
+    context.get_scenario_as_xml_validated()
 }
 
 /// Updates the in-memory scenario from a JSON string provided by the frontend.
@@ -221,276 +540,6157 @@ fn update_scenario_from_json(json: String, state: State<'_, FersState>) -> Resul
     state.lock().map_err(|e| e.to_string())?.update_scenario_from_json(&json)
 }
 
-/// Triggers the simulation based on the current in-memory scenario.
+/// Writes the current scenario to a file in the JSON layout of `get_scenario_as_json`.
 ///
-/// This command immediately returns `Ok(())` and spawns a background thread to
-/// perform the actual computationally intensive simulation. This prevents the UI
-/// from freezing. The result of the simulation (success or failure) is
-/// communicated back to the frontend via Tauri events.
+/// JSON files diff cleanly under version control and are easy to generate from scripts.
 ///
 /// # Parameters
 ///
-/// * `app_handle` - The Tauri application handle, used to access managed state
-///   and emit events.
+/// * `filepath` - The path of the JSON file to create or overwrite.
+/// * `state` - Tauri-managed state containing the shared `FersContext`.
 ///
-/// # Events Emitted
+/// # Returns
 ///
-/// * `simulation-complete` - Emitted with `()` as payload on successful completion.
-/// * `simulation-error` - Emitted with a `String` error message on failure.
-/// * `simulation-progress` - Emitted periodically with `{ message: String, current: i32, total: i32 }`.
+/// * `Ok(())` if the file was written.
+/// * `Err(String)` if the scenario could not be serialized or the file written.
 #[tauri::command]
-fn run_simulation(app_handle: AppHandle) -> Result<(), String> {
-    // Clone the AppHandle so we can move it into the background thread.
-    let app_handle_clone = app_handle.clone();
-
-    // Spawn a new thread to run the blocking C++ simulation.
-    std::thread::spawn(move || {
-        // Retrieve the managed state within the new thread.
-        let fers_state: State<'_, FersState> = app_handle_clone.state();
-        let result = fers_state
-            .lock()
-            .map_err(|e| e.to_string())
-            .and_then(|context| context.run_simulation(&app_handle_clone));
-
-        // Emit an event to the frontend based on the simulation result.
-        match result {
-            Ok(_) => {
-                app_handle_clone
-                    .emit("simulation-complete", ())
-                    .expect("Failed to emit simulation-complete event");
-            }
-            Err(e) => {
-                app_handle_clone
-                    .emit("simulation-error", e)
-                    .expect("Failed to emit simulation-error event");
-            }
-        }
-    });
-
-    // Return immediately, allowing the UI to remain responsive.
-    Ok(())
+fn export_scenario_as_json_file(
+    filepath: String,
+    state: State<'_, FersState>,
+) -> Result<(), String> {
+    state.lock().map_err(|e| e.to_string())?.export_scenario_as_json_file(&filepath)
 }
 
-/// Generates a KML visualization file for the current in-memory scenario.
-///
-/// This command spawns a background thread to handle file I/O and KML generation,
-/// preventing the UI from freezing. The result is communicated via events.
+/// Replaces the in-memory scenario with one read from a JSON scenario file.
 ///
 /// # Parameters
 ///
-/// * `output_path` - The absolute file path where the KML file should be saved.
-/// * `app_handle` - The Tauri application handle.
+/// * `filepath` - The path of a JSON file in the layout of `get_scenario_as_json`.
+/// * `state` - Tauri-managed state containing the shared `FersContext`.
 ///
-/// # Events Emitted
+/// # Returns
 ///
-/// * `kml-generation-complete` - Emitted with the output path `String` on success.
-/// * `kml-generation-error` - Emitted with a `String` error message on failure.
+/// * `Ok(())` if the scenario was loaded.
+/// * `Err(String)` if the file could not be read or does not describe a scenario. The
+///   error names the path of the first field that failed to deserialize.
 #[tauri::command]
-fn generate_kml(output_path: String, app_handle: AppHandle) -> Result<(), String> {
-    let app_handle_clone = app_handle.clone();
-    std::thread::spawn(move || {
-        let fers_state: State<'_, FersState> = app_handle_clone.state();
-        let result = fers_state
-            .lock()
-            .map_err(|e| e.to_string())
-            .and_then(|context| context.generate_kml(&output_path));
-
-        match result {
-            Ok(_) => {
-                app_handle_clone
-                    .emit("kml-generation-complete", &output_path)
-                    .expect("Failed to emit kml-generation-complete event");
-            }
-            Err(e) => {
-                app_handle_clone
-                    .emit("kml-generation-error", e)
-                    .expect("Failed to emit kml-generation-error event");
-            }
-        }
-    });
-    Ok(())
+fn import_scenario_from_json_file(
+    filepath: String,
+    state: State<'_, FersState>,
+) -> Result<(), String> {
+    state.lock().map_err(|e| e.to_string())?.load_scenario_from_json_file(&filepath)
 }
 
-/// A stateless command to calculate an interpolated motion path.
-///
-/// This command delegates to the `libfers` core to calculate a path from a given
-/// set of waypoints, ensuring the UI visualization is identical to the path
-/// the simulation would use.
+/// Restores the scenario as it was before the last recorded change.
 ///
 /// # Parameters
-/// * `waypoints` - A vector of motion waypoints.
-/// * `interp_type` - The interpolation algorithm to use ('static', 'linear', 'cubic').
-/// * `num_points` - The desired number of points for the final path.
+/// * `state` - Tauri-managed state containing the shared `FersContext`.
 ///
 /// # Returns
-/// * `Ok(Vec<InterpolatedPoint>)` - The calculated path points.
-/// * `Err(String)` - An error message if the path calculation failed.
+/// * `Ok(String)` - The restored scenario JSON, or the current one if there is nothing to undo.
+/// * `Err(String)` - If the scenario could not be serialized or restored.
 #[tauri::command]
-fn get_interpolated_motion_path(
-    waypoints: Vec<MotionWaypoint>,
-    interp_type: InterpolationType,
-    num_points: usize,
-) -> Result<Vec<InterpolatedMotionPoint>, String> {
-    fers_api::get_interpolated_motion_path(waypoints, interp_type, num_points)
+fn undo_scenario(state: State<'_, FersState>) -> Result<String, String> {
+    state.lock().map_err(|e| e.to_string())?.undo_scenario()
 }
 
-/// A stateless command to calculate an interpolated rotation path.
-///
-/// This command delegates to the `libfers` core to calculate a rotation path from a given
-/// set of waypoints. It is used by the UI to preview how the simulation will interpolate
-/// orientation changes over time.
+/// Reapplies the scenario state most recently undone with `undo_scenario`.
 ///
 /// # Parameters
-/// * `waypoints` - A vector of rotation waypoints (azimuth/elevation in compass degrees).
-/// * `interp_type` - The interpolation algorithm to use ('static', 'linear', 'cubic').
-/// * `num_points` - The desired number of points for the final path.
+/// * `state` - Tauri-managed state containing the shared `FersContext`.
 ///
 /// # Returns
-/// * `Ok(Vec<InterpolatedRotationPoint>)` - The calculated rotation points.
-/// * `Err(String)` - An error message if the calculation failed.
+/// * `Ok(String)` - The restored scenario JSON, or the current one if there is nothing to redo.
+/// * `Err(String)` - If the scenario could not be serialized or restored.
 #[tauri::command]
-fn get_interpolated_rotation_path(
-    waypoints: Vec<RotationWaypoint>,
-    interp_type: InterpolationType,
-    num_points: usize,
-) -> Result<Vec<InterpolatedRotationPoint>, String> {
-    fers_api::get_interpolated_rotation_path(waypoints, interp_type, num_points)
+fn redo_scenario(state: State<'_, FersState>) -> Result<String, String> {
+    state.lock().map_err(|e| e.to_string())?.redo_scenario()
 }
 
-/// Retrieves a 2D antenna gain pattern for visualization.
-///
-/// This command samples the antenna model loaded in the current simulation context.
-/// It is stateful because it relies on the antenna assets defined in the loaded scenario.
+/// Sets how many scenario states are kept for undo (50 by default).
 ///
 /// # Parameters
-/// * `antenna_name` - The unique name of the antenna asset to sample.
-/// * `az_samples` - Resolution along the azimuth axis (e.g., 360).
-/// * `el_samples` - Resolution along the elevation axis (e.g., 180).
-/// * `frequency` - The frequency in Hz at which to calculate gain (relevant for frequency-dependent antennas).
-/// * `state` - The shared simulation state.
+/// * `depth` - The number of states to keep; zero disables undo.
+/// * `state` - Tauri-managed state containing the shared `FersContext`.
 ///
 /// # Returns
-/// * `Ok(AntennaPatternData)` - Struct containing flattened gain array and dimensions.
-/// * `Err(String)` - Error if antenna not found or context locked.
+/// * `Ok(())` - If the depth was set; the oldest states beyond it are discarded.
+/// * `Err(String)` - If the Mutex could not be locked.
 #[tauri::command]
-fn get_antenna_pattern(
-    antenna_name: String,
-    az_samples: usize,
-    el_samples: usize,
-    frequency: f64,
-    state: State<'_, FersState>,
-) -> Result<fers_api::AntennaPatternData, String> {
-    state.lock().map_err(|e| e.to_string())?.get_antenna_pattern(
-        &antenna_name,
-        az_samples,
-        el_samples,
-        frequency,
-    )
+fn set_scenario_history_depth(depth: usize, state: State<'_, FersState>) -> Result<(), String> {
+    state.lock().map_err(|e| e.to_string())?.set_history_depth(depth)
 }
 
-/// Calculates visual radio links between platforms at a specific time.
+/// Speeds up or slows down the whole scenario by scaling its timeline.
 ///
-/// This command performs a lightweight geometric and physics check to determine
-/// which platforms can "see" each other. It distinguishes between monostatic,
-/// bistatic, and interference paths based on signal-to-noise ratios.
+/// Start/end times and every motion and rotation waypoint time are multiplied by
+/// `factor`, and fixed rotation rates are divided by it, so spatial paths are preserved
+/// while platform velocities scale by `1 / factor`. Radar schedules and PRFs are unchanged.
 ///
 /// # Parameters
-/// * `time` - The simulation time in seconds to evaluate.
-/// * `state` - The shared simulation state containing platforms and physics models.
+/// * `factor` - The time-scaling factor; must be positive.
+/// * `state` - Tauri-managed state containing the shared `FersContext`.
 ///
 /// # Returns
-/// * `Ok(Vec<VisualLink>)` - A list of renderable link segments with metadata (type, quality, label).
-/// * `Err(String)` - Error if context access fails.
+/// * `Ok(())` - If the scenario was rescaled.
+/// * `Err(String)` - If `factor` is not a positive number.
 #[tauri::command]
-fn get_preview_links(
-    time: f64,
-    state: State<'_, FersState>,
-) -> Result<Vec<fers_api::VisualLink>, String> {
-    state.lock().map_err(|e| e.to_string())?.calculate_preview_links(time)
+fn time_scale_scenario(factor: f64, state: State<'_, FersState>) -> Result<(), String> {
+    state.lock().map_err(|e| e.to_string())?.time_scale_scenario(factor)
 }
 
-/// Initializes and runs the Tauri application.
+/// Copies a platform so arrays of identical sensors can be built without rebuilding it.
 ///
-/// This function is the main entry point for the desktop application. It performs
-/// the following setup steps:
+/// The copy keeps the original's paths and components; the platform and its components
+/// are renamed with a " (copy)" suffix. The frontend assigns fresh IDs to the copy and
+/// its waypoints when it reloads the scenario.
 ///
-/// 1. Creates a new `FersContext` by calling the FFI layer. If this fails, it
-///    indicates a linking or initialization problem with `libfers`.
-/// 2. Registers Tauri plugins for file dialogs, file system access, and shell operations.
-/// 3. Stores the `FersContext` in Tauri's managed state, protected by a `Mutex`.
-/// 4. Registers all Tauri commands so they can be invoked from the frontend.
-/// 5. Launches the Tauri application event loop.
+/// # Parameters
+/// * `platform_name` - The name of the platform to copy.
+/// * `state` - Tauri-managed state containing the shared `FersContext`.
 ///
-/// # Panics
+/// # Returns
+/// * `Ok(String)` - The name of the new platform.
+/// * `Err(String)` - If no platform has the given name.
+#[tauri::command]
+fn duplicate_platform(
+    platform_name: String,
+    state: State<'_, FersState>,
+) -> Result<String, String> {
+    state.lock().map_err(|e| e.to_string())?.duplicate_platform(&platform_name)
+}
+
+/// Triggers the simulation based on the current in-memory scenario.
 ///
-/// This function will panic if:
-/// * The `FersContext` cannot be created (indicating a problem with `libfers`).
-/// * The Tauri application fails to start due to misconfiguration.
+/// This command immediately returns `Ok(())` and spawns a background thread to
+/// perform the actual computationally intensive simulation. This prevents the UI
+/// from freezing. The result of the simulation (success or failure) is
+/// communicated back to the frontend via Tauri events.
 ///
-/// # Example
+/// # Parameters
 ///
-/// This function is typically called from `main.rs`:
+/// * `app_handle` - The Tauri application handle, used to access managed state
+///   and emit events.
 ///
-/// ```rust
-/// fers_ui_lib::run();
-/// ```
-#[cfg_attr(mobile, tauri::mobile_entry_point)]
-pub fn run() {
-    // Attempt to create the FFI context. This validates that libfers is correctly linked.
-    let context = fers_api::FersContext::new()
-        .expect("Failed to create FERS context. Is libfers linked correctly?");
+/// # Events Emitted
+///
+/// * `simulation-complete` - Emitted on successful completion with the paths of the
+///   output files (`Vec<String>`), relative to the working directory.
+/// * `simulation-cancelled` - Emitted with `()` as payload if `cancel_simulation` stopped the run.
+/// * `simulation-error` - Emitted with a `String` error message on failure.
+/// * `simulation-progress` - Emitted at most ~10 times per second with a `fers_api::SimulationProgress`
+///   carrying the phase, message, step counts, percentage, elapsed time and estimated time remaining.
+#[tauri::command]
+fn run_simulation(app_handle: AppHandle) -> Result<(), String> {
+    spawn_simulation(app_handle, None);
 
-    tauri::Builder::default()
-        // Register Tauri plugins for UI functionality
-        .plugin(tauri_plugin_dialog::init())
-        .plugin(tauri_plugin_opener::init())
-        .plugin(tauri_plugin_fs::init())
-        // Store the FersContext as managed state, accessible from all commands
-        .manage(Mutex::new(context))
-        // Register all Tauri commands that can be invoked from the frontend
-        .invoke_handler(tauri::generate_handler![
-            load_scenario_from_xml_file,
-            get_scenario_as_json,
-            get_scenario_as_xml,
-            update_scenario_from_json,
-            run_simulation,
-            generate_kml,
-            get_interpolated_motion_path,
-            get_interpolated_rotation_path,
-            get_antenna_pattern,
-            get_preview_links,
-        ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+    // Return immediately, allowing the UI to remain responsive.
+    Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::fers_api;
+/// Triggers the simulation, writing the receiver output to a chosen directory.
+///
+/// Each receiver writes `<output_dir>/<basename>_<receiver>_results.h5`. The directory is
+/// checked before the background thread is spawned, so a missing or read-only directory
+/// is reported as an error from this command rather than as a `simulation-error` event.
+///
+/// # Parameters
+///
+/// * `output_dir` - An existing, writable directory for the output files.
+/// * `basename` - A prefix for the output file names; empty for none. It must not
+///   contain path separators.
+/// * `app_handle` - The Tauri application handle, used to access managed state
+///   and emit events.
+///
+/// # Events Emitted
+///
+/// * `simulation-complete` - Emitted on successful completion with the paths of the
+///   output files (`Vec<String>`).
+/// * `simulation-cancelled`, `simulation-error`, `simulation-progress` - As for `run_simulation`.
+#[tauri::command]
+fn run_simulation_to(
+    output_dir: String,
+    basename: String,
+    app_handle: AppHandle,
+) -> Result<(), String> {
+    check_output_location(&output_dir, &basename)?;
+    spawn_simulation(
+        app_handle,
+        Some(fers_api::OutputLocation { directory: output_dir, basename }),
+    );
+    Ok(())
+}
 
-    /// Verifies that the `libfers` C++ library is correctly linked.
-    ///
-    /// This test ensures that:
-    /// 1. The Rust linker can find the `libfers.a` static library.
-    /// 2. All required FFI symbols are present and callable.
-    /// 3. The `FersContext::new()` wrapper successfully creates a C++ context.
-    ///
-    /// If this test fails:
-    /// * Check that the `build.rs` script correctly specifies library search paths.
-    /// * Verify that the C++ libraries have been built by CMake.
-    /// * Ensure that `bindgen` generated the correct bindings from `api.h`.
-    ///
-    /// # Panics
-    ///
-    /// This test will panic if `FersContext::new()` returns `None`, indicating
-    /// a failure to allocate or initialize the C++ context.
-    #[test]
-    fn it_links_libfers_and_creates_context() {
-        // This test will fail at link time if the library is not found.
-        // It will fail at runtime if the symbols don't match or creation fails.
-        let _context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+/// Triggers a simulation of only some of the scenario's platforms.
+///
+/// A copy of the scenario holding the named platforms and the assets they use is built
+/// before this command returns, so an unknown name or a selection without a receiver or
+/// without a transmitter (a monostatic radar counts as both) is reported as an error from
+/// this command. The copy is then run in the background like `run_simulation`; the
+/// in-memory scenario is never modified.
+///
+/// # Parameters
+///
+/// * `platform_ids` - The names of the platforms to simulate.
+/// * `app_handle` - The Tauri application handle, used to access managed state
+///   and emit events.
+///
+/// # Events Emitted
+///
+/// * `simulation-complete` - Emitted on successful completion with the paths of the
+///   output files written for the selected receivers (`Vec<String>`).
+/// * `simulation-cancelled`, `simulation-error`, `simulation-progress` - As for `run_simulation`.
+#[tauri::command]
+fn run_simulation_subset(platform_ids: Vec<String>, app_handle: AppHandle) -> Result<(), String> {
+    let subset =
+        app_handle.state::<FersState>().lock().map_err(|e| e.to_string())?.subset(&platform_ids)?;
+    let cancel = app_handle.state::<CancelState>().inner().clone();
+    cancel.store(false, Ordering::Relaxed);
 
-        // The context is automatically destroyed when it goes out of scope due to `Drop`.
-        // No explicit destroy call is needed.
+    std::thread::spawn(move || {
+        let fers_state: State<'_, FersState> = app_handle.state();
+        // Holding the shared lock keeps other runs and scenario edits from overlapping
+        // this one, as they would for a full run.
+        let result = fers_state.lock().map_err(|e| e.to_string()).and_then(|_context| {
+            let outcome = subset.run_simulation(Some(&app_handle), None, &cancel);
+            with_output_files(&subset, None, outcome)
+        });
+        emit_simulation_result(&app_handle, result);
+    });
+    Ok(())
+}
+
+/// Checks that an output directory exists and is writable and that a basename is a
+/// plain file name prefix.
+fn check_output_location(output_dir: &str, basename: &str) -> Result<(), String> {
+    if basename.contains(['/', '\\']) {
+        return Err(format!("Output basename '{basename}' must not contain path separators."));
+    }
+    let dir = std::path::Path::new(output_dir);
+    if !dir.is_dir() {
+        return Err(format!("Output directory '{output_dir}' does not exist."));
+    }
+    // Permission bits do not reliably reflect writability (e.g. ACLs, read-only mounts),
+    // so probe by creating and removing a file.
+    let probe = dir.join(format!(".fers-write-test-{}", std::process::id()));
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)
+        .map_err(|e| format!("Output directory '{output_dir}' is not writable: {e}"))?;
+    let _ = std::fs::remove_file(&probe);
+    Ok(())
+}
+
+/// Runs the simulation on a background thread and reports the result via events.
+fn spawn_simulation(app_handle: AppHandle, output: Option<fers_api::OutputLocation>) {
+    // A cancellation left over from an earlier run must not stop this one.
+    let cancel = app_handle.state::<CancelState>().inner().clone();
+    cancel.store(false, Ordering::Relaxed);
+
+    // Spawn a new thread to run the blocking C++ simulation.
+    std::thread::spawn(move || {
+        // Retrieve the managed state within the new thread.
+        let fers_state: State<'_, FersState> = app_handle.state();
+        let result = fers_state.lock().map_err(|e| e.to_string()).and_then(|context| {
+            let outcome = context.run_simulation(Some(&app_handle), output.as_ref(), &cancel);
+            with_output_files(&context, output.as_ref(), outcome)
+        });
+
+        // Emit an event to the frontend based on the simulation result.
+        emit_simulation_result(&app_handle, result);
+    });
+}
+
+/// How a background simulation run ended and, if it completed, the files it wrote.
+type SimulationResult = Result<(fers_api::SimulationOutcome, Vec<String>), String>;
+
+/// Adds the output file list to the outcome of a completed run.
+fn with_output_files(
+    context: &fers_api::FersContext,
+    output: Option<&fers_api::OutputLocation>,
+    outcome: Result<fers_api::SimulationOutcome, String>,
+) -> SimulationResult {
+    match outcome? {
+        fers_api::SimulationOutcome::Completed => {
+            Ok((fers_api::SimulationOutcome::Completed, context.list_output_files(output)?))
+        }
+        cancelled => Ok((cancelled, Vec::new())),
+    }
+}
+
+/// Emits the event that reports how a background simulation run ended.
+fn emit_simulation_result(app_handle: &AppHandle, result: SimulationResult) {
+    match result {
+        Ok((fers_api::SimulationOutcome::Completed, files)) => {
+            app_handle
+                .emit("simulation-complete", files)
+                .expect("Failed to emit simulation-complete event");
+        }
+        Ok((fers_api::SimulationOutcome::Cancelled, _)) => {
+            app_handle
+                .emit("simulation-cancelled", ())
+                .expect("Failed to emit simulation-cancelled event");
+        }
+        Err(e) => {
+            app_handle.emit("simulation-error", e).expect("Failed to emit simulation-error event");
+        }
+    }
+}
+
+/// Requests that the running simulation stop.
+///
+/// The C++ core checks the flag before each pulse and receive-window event, so the run
+/// ends at the next boundary and `run_simulation` emits `simulation-cancelled`. Receive
+/// windows completed before the cancellation are still written to HDF5.
+///
+/// If no simulation is running, including when the request arrives just after a run has
+/// completed, this is a no-op: the flag is cleared when the next run starts.
+///
+/// # Parameters
+///
+/// * `app_handle` - The Tauri application handle, used to access the cancellation flag.
+#[tauri::command]
+fn cancel_simulation(app_handle: AppHandle) {
+    app_handle.state::<CancelState>().store(true, Ordering::Relaxed);
+}
+
+/// Triggers the simulation and streams each finished receive window over a local TCP socket.
+///
+/// The socket is bound on `127.0.0.1:<port>` before this command returns, and frames are
+/// written to the first consumer to connect, using the framing described in the
+/// `streaming` module. Output is still written to HDF5. If the consumer is absent or too
+/// slow, windows are dropped rather than stalling the simulation.
+///
+/// # Parameters
+///
+/// * `port` - The local TCP port on which the consumer connects.
+/// * `app_handle` - The Tauri application handle, used to access managed state
+///   and emit events.
+///
+/// # Events Emitted
+///
+/// * `simulation-complete` - Emitted on successful completion with the paths of the
+///   output files (`Vec<String>`), relative to the working directory.
+/// * `simulation-cancelled` - Emitted with `()` as payload if `cancel_simulation` stopped the run.
+/// * `simulation-error` - Emitted with a `String` error message on failure.
+/// * `simulation-progress` - Emitted at most ~10 times per second with a `fers_api::SimulationProgress`
+///   carrying the phase, message, step counts, percentage, elapsed time and estimated time remaining.
+/// * `simulation-stream-stats` - Emitted on completion with `{ sent: usize, dropped: usize }`.
+#[tauri::command]
+fn run_simulation_streaming(port: u16, app_handle: AppHandle) -> Result<(), String> {
+    let listener = std::net::TcpListener::bind(("127.0.0.1", port)).map_err(|e| e.to_string())?;
+    let (stream, forwarder) =
+        streaming::WindowStream::spawn(listener, streaming::STREAM_BUFFER_FRAMES)
+            .map_err(|e| e.to_string())?;
+    let app_handle_clone = app_handle.clone();
+    let cancel = app_handle.state::<CancelState>().inner().clone();
+    cancel.store(false, Ordering::Relaxed);
+
+    std::thread::spawn(move || {
+        let fers_state: State<'_, FersState> = app_handle_clone.state();
+        let result = fers_state.lock().map_err(|e| e.to_string()).and_then(|context| {
+            let outcome = context.run_simulation_streaming(
+                Some(&app_handle_clone),
+                None,
+                &|receiver: &str, start_time: f64, fullscale: f64, samples: &[f64]| {
+                    stream.publish(receiver, start_time, fullscale, samples);
+                },
+                &cancel,
+            );
+            with_output_files(&context, None, outcome)
+        });
+
+        // Closing the producer lets the forwarder drain its buffer and exit.
+        let producer_dropped = stream.finish();
+        if let Ok(mut stats) = forwarder.join() {
+            stats.dropped += producer_dropped;
+            let _ = app_handle_clone.emit("simulation-stream-stats", stats);
+        }
+
+        emit_simulation_result(&app_handle_clone, result);
+    });
+
+    Ok(())
+}
+
+/// Generates a KML visualization file for the current in-memory scenario.
+///
+/// This command spawns a background thread to handle file I/O and KML generation,
+/// preventing the UI from freezing. The result is communicated via events.
+///
+/// # Parameters
+///
+/// * `output_path` - The absolute file path where the KML file should be saved.
+/// * `app_handle` - The Tauri application handle.
+///
+/// # Events Emitted
+///
+/// * `kml-generation-complete` - Emitted with the output path `String` on success.
+/// * `kml-generation-error` - Emitted with a `String` error message on failure.
+#[tauri::command]
+fn generate_kml(output_path: String, app_handle: AppHandle) -> Result<(), String> {
+    let app_handle_clone = app_handle.clone();
+    std::thread::spawn(move || {
+        let fers_state: State<'_, FersState> = app_handle_clone.state();
+        let result = fers_state
+            .lock()
+            .map_err(|e| e.to_string())
+            .and_then(|context| context.generate_kml(&output_path, None));
+
+        match result {
+            Ok(_) => {
+                app_handle_clone
+                    .emit("kml-generation-complete", &output_path)
+                    .expect("Failed to emit kml-generation-complete event");
+            }
+            Err(e) => {
+                app_handle_clone
+                    .emit("kml-generation-error", e)
+                    .expect("Failed to emit kml-generation-error event");
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Exports the current scenario as a self-contained KMZ archive.
+///
+/// The KML is generated as by `generate_kml` and zipped as `doc.kml` together with any
+/// icon files it references locally. Like `generate_kml`, the work runs on a background
+/// thread and reports via events.
+///
+/// # Parameters
+///
+/// * `output_path` - The absolute file path where the KMZ file should be saved.
+/// * `origin_lat` - If set with `origin_lon`, the latitude of the ENU origin to export
+///   about in place of the scenario's own, in decimal degrees.
+/// * `origin_lon` - If set with `origin_lat`, the longitude of that origin in decimal degrees.
+/// * `origin_alt` - The altitude of that origin in meters; defaults to 0.
+/// * `app_handle` - The Tauri application handle.
+///
+/// # Returns
+///
+/// * `Ok(())` - If the export was started.
+/// * `Err(String)` - If only one of `origin_lat` and `origin_lon` is set.
+///
+/// # Events Emitted
+///
+/// * `kmz-generation-complete` - Emitted with the output path `String` on success.
+/// * `kmz-generation-error` - Emitted with a `String` error message on failure, including
+///   an invalid origin or an origin given for a scenario outside the ENU frame.
+#[tauri::command]
+fn generate_kmz(
+    output_path: String,
+    origin_lat: Option<f64>,
+    origin_lon: Option<f64>,
+    origin_alt: Option<f64>,
+    app_handle: AppHandle,
+) -> Result<(), String> {
+    let origin = (origin_lat.is_some() || origin_lon.is_some())
+        .then(|| require_origin(origin_lat, origin_lon, origin_alt))
+        .transpose()?;
+    let app_handle_clone = app_handle.clone();
+    std::thread::spawn(move || {
+        let fers_state: State<'_, FersState> = app_handle_clone.state();
+        let result = fers_state
+            .lock()
+            .map_err(|e| e.to_string())
+            .and_then(|context| kmz::generate_kmz(&context, &output_path, origin));
+
+        match result {
+            Ok(_) => {
+                app_handle_clone
+                    .emit("kmz-generation-complete", &output_path)
+                    .expect("Failed to emit kmz-generation-complete event");
+            }
+            Err(e) => {
+                app_handle_clone
+                    .emit("kmz-generation-error", e)
+                    .expect("Failed to emit kmz-generation-error event");
+            }
+        }
+    });
+    Ok(())
+}
+
+/// A stateless command to calculate an interpolated motion path.
+///
+/// This command delegates to the `libfers` core to calculate a path from a given
+/// set of waypoints, ensuring the UI visualization is identical to the path
+/// the simulation would use.
+///
+/// # Parameters
+/// * `waypoints` - A vector of motion waypoints.
+/// * `interp_type` - The interpolation algorithm to use ('static', 'linear', 'cubic').
+/// * `num_points` - The desired number of points for the final path.
+/// * `options` - The fast mode, position noise and time window of the preview. All of
+///   them are off by default.
+///
+/// The `num_points` samples are spread evenly over the window, so a zoomed timeline is
+/// previewed at full resolution.
+///
+/// # Returns
+/// * `Ok(Vec<InterpolatedPoint>)` - The calculated path points.
+/// * `Err(String)` - An error message if the path calculation failed or the window does
+///   not overlap the waypoints.
+#[tauri::command]
+fn get_interpolated_motion_path(
+    waypoints: Vec<MotionWaypoint>,
+    interp_type: InterpolationType,
+    num_points: usize,
+    options: Option<PathPreviewOptions>,
+) -> Result<Vec<InterpolatedMotionPoint>, String> {
+    fers_api::get_interpolated_motion_path(
+        waypoints,
+        interp_type,
+        num_points,
+        &options.unwrap_or_default(),
+    )
+}
+
+/// A stateless command to calculate the speed along an interpolated motion path.
+///
+/// The speeds are the magnitudes of the velocities `libfers` reports for the exact
+/// interpolation the simulation uses, which are analytic derivatives of the linear and
+/// cubic interpolants, so speed-vs-time plots match the simulated Doppler.
+///
+/// # Parameters
+/// * `waypoints` - A vector of motion waypoints.
+/// * `interp_type` - The interpolation algorithm to use ('static', 'linear', 'cubic').
+/// * `num_points` - The number of samples, evenly spaced between the first and last waypoint.
+///
+/// # Returns
+/// * `Ok(Vec<f64>)` - The speed in m/s at each sample; all zeros for static paths.
+/// * `Err(String)` - An error message if the path calculation failed.
+#[tauri::command]
+fn get_interpolated_velocity_profile(
+    waypoints: Vec<MotionWaypoint>,
+    interp_type: InterpolationType,
+    num_points: usize,
+) -> Result<Vec<f64>, String> {
+    let stationary = matches!(interp_type, InterpolationType::Static);
+    let points = fers_api::get_interpolated_motion_path(
+        waypoints,
+        interp_type,
+        num_points,
+        &PathPreviewOptions::default(),
+    )?;
+    Ok(points
+        .iter()
+        .map(|p| if stationary { 0.0 } else { (p.vx * p.vx + p.vy * p.vy + p.vz * p.vz).sqrt() })
+        .collect())
+}
+
+/// A stateless command listing the carrier frequency of each pulse of a frequency-hopping transmitter.
+///
+/// # Parameters
+/// * `spec` - The hop set and optional hop pattern.
+/// * `num_pulses` - The number of pulses to list.
+///
+/// # Returns
+/// * `Ok(Vec<f64>)` - The carrier frequency in Hz used by each pulse.
+/// * `Err(String)` - An error message if the hop sequence is invalid.
+#[tauri::command]
+fn preview_hop_sequence(spec: HopSequence, num_pulses: usize) -> Result<Vec<f64>, String> {
+    fers_api::preview_hop_sequence(&spec, num_pulses)
+}
+
+/// A stateless command checking that an inline waveform decodes and could be loaded.
+///
+/// # Parameters
+/// * `waveform` - The inline I/Q samples and their sample rate.
+///
+/// # Returns
+/// * `Ok(usize)` - The number of complex samples in the waveform.
+/// * `Err(String)` - An error message naming the first value that does not decode, or
+///   reporting an incomplete I/Q pair, an empty list or a bad sample rate.
+#[tauri::command]
+fn validate_inline_waveform(waveform: InlineWaveform) -> Result<usize, String> {
+    fers_api::validate_inline_waveform(&waveform)
+}
+
+/// Resolves a geodetic origin, which is optional in the frontend.
+fn require_origin(
+    origin_lat: Option<f64>,
+    origin_lon: Option<f64>,
+    origin_alt: Option<f64>,
+) -> Result<(f64, f64, f64), String> {
+    match (origin_lat, origin_lon) {
+        (Some(lat), Some(lon)) => Ok((lat, lon, origin_alt.unwrap_or(0.0))),
+        _ => Err("A geodetic origin (latitude and longitude) must be set.".to_string()),
+    }
+}
+
+/// A stateless command converting waypoints planned in WGS84 latitude/longitude to the
+/// local ENU frame used by the simulation.
+///
+/// # Parameters
+/// * `origin_lat` - The origin latitude in decimal degrees.
+/// * `origin_lon` - The origin longitude in decimal degrees.
+/// * `origin_alt` - The origin altitude in meters; defaults to 0.
+/// * `points` - The geodetic waypoints to convert.
+///
+/// # Returns
+/// * `Ok(Vec<MotionWaypoint>)` - The local waypoints, in input order.
+/// * `Err(String)` - If the origin is unset or not a valid geodetic position.
+#[tauri::command]
+fn geodetic_to_local(
+    origin_lat: Option<f64>,
+    origin_lon: Option<f64>,
+    origin_alt: Option<f64>,
+    points: Vec<GeoPoint>,
+) -> Result<Vec<MotionWaypoint>, String> {
+    fers_api::geodetic_to_local(require_origin(origin_lat, origin_lon, origin_alt)?, &points)
+}
+
+/// A stateless command converting local ENU waypoints to WGS84 latitude/longitude, the
+/// inverse of `geodetic_to_local` and the conversion the KML export uses.
+///
+/// # Parameters
+/// * `origin_lat` - The origin latitude in decimal degrees.
+/// * `origin_lon` - The origin longitude in decimal degrees.
+/// * `origin_alt` - The origin altitude in meters; defaults to 0.
+/// * `waypoints` - The local waypoints to convert.
+///
+/// # Returns
+/// * `Ok(Vec<GeoPoint>)` - The geodetic waypoints, in input order.
+/// * `Err(String)` - If the origin is unset or not a valid geodetic position.
+#[tauri::command]
+fn local_to_geodetic(
+    origin_lat: Option<f64>,
+    origin_lon: Option<f64>,
+    origin_alt: Option<f64>,
+    waypoints: Vec<MotionWaypoint>,
+) -> Result<Vec<GeoPoint>, String> {
+    fers_api::local_to_geodetic(require_origin(origin_lat, origin_lon, origin_alt)?, &waypoints)
+}
+
+/// A stateless command to calculate an interpolated rotation path.
+///
+/// This command delegates to the `libfers` core to calculate a rotation path from a given
+/// set of waypoints. It is used by the UI to preview how the simulation will interpolate
+/// orientation changes over time.
+///
+/// # Parameters
+/// * `waypoints` - A vector of rotation waypoints (azimuth/elevation in compass degrees).
+/// * `interp_type` - The interpolation algorithm to use ('static', 'linear', 'cubic').
+/// * `num_points` - The desired number of points for the final path.
+///
+/// # Returns
+/// * `Ok(Vec<InterpolatedRotationPoint>)` - The calculated rotation points.
+/// * `Err(String)` - An error message if the calculation failed.
+#[tauri::command]
+fn get_interpolated_rotation_path(
+    waypoints: Vec<RotationWaypoint>,
+    interp_type: InterpolationType,
+    num_points: usize,
+) -> Result<Vec<InterpolatedRotationPoint>, String> {
+    fers_api::get_interpolated_rotation_path(waypoints, interp_type, num_points)
+}
+
+/// Retrieves a 2D antenna gain pattern for visualization.
+///
+/// This command samples the antenna model loaded in the current simulation context.
+/// It is stateful because it relies on the antenna assets defined in the loaded scenario.
+///
+/// # Parameters
+/// * `antenna_name` - The unique name of the antenna asset to sample.
+/// * `az_samples` - Resolution along the azimuth axis (e.g., 360).
+/// * `el_samples` - Resolution along the elevation axis (e.g., 180).
+/// * `frequency` - The frequency in Hz at which to calculate gain (relevant for frequency-dependent antennas).
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(AntennaPatternData)` - Struct containing flattened gain array and dimensions.
+/// * `Err(String)` - Error if antenna not found or context locked.
+#[tauri::command]
+fn get_antenna_pattern(
+    antenna_name: String,
+    az_samples: usize,
+    el_samples: usize,
+    frequency: f64,
+    state: State<'_, FersState>,
+) -> Result<fers_api::AntennaPatternData, String> {
+    state.lock().map_err(|e| e.to_string())?.get_antenna_pattern(
+        &antenna_name,
+        az_samples,
+        el_samples,
+        frequency,
+    )
+}
+
+/// A stateless command to sample an antenna's gain along an azimuth cut for plotting.
+///
+/// The antenna is built with the same pattern models the simulator uses, so the preview
+/// matches the gain applied during a run. Wavelength-dependent patterns are evaluated at
+/// the antenna's design frequency, or 1 GHz if it has none.
+///
+/// # Parameters
+/// * `antenna` - The antenna definition.
+/// * `az_range` - The first and last azimuth of the cut in degrees from boresight.
+/// * `el_cut` - The elevation of the cut in degrees.
+/// * `num_points` - The number of evenly spaced azimuths, including both ends.
+///
+/// # Returns
+/// * `Ok(Vec<Option<f64>>)` - The gain at each azimuth in dBi, or `None` at a null.
+/// * `Err(String)` - Error if a pattern file cannot be loaded or no points were requested.
+#[tauri::command]
+fn sample_antenna_pattern(
+    antenna: Antenna,
+    az_range: (f64, f64),
+    el_cut: f64,
+    num_points: usize,
+) -> Result<Vec<Option<f64>>, String> {
+    fers_api::sample_antenna_pattern(&antenna, az_range, el_cut, num_points)
+}
+
+/// Checks an array antenna for grating lobes in visible space at a given scan angle.
+///
+/// Element spacing beyond half a wavelength lets copies of the main beam appear where
+/// `sin(theta) = sin(scan) + m * lambda / d` for a non-zero integer `m`.
+///
+/// # Parameters
+/// * `antenna` - The array antenna definition.
+/// * `scan_angle_deg` - The azimuth of the main beam relative to boresight, in degrees.
+/// * `carrier_frequency` - The carrier frequency in Hz that sets the wavelength. Optional:
+///   if omitted, the antenna's design frequency is used, and failing that the scenario
+///   carrier, taken from the waveform of the first transmitter fitted with the scenario
+///   antenna of the same name.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(GratingLobeReport)` - The wavelength, element spacing and grating lobe directions.
+/// * `Err(String)` - Error if the antenna is not an array, or if no carrier frequency is
+///   supplied, the antenna has no design frequency and no transmitter uses it.
+#[tauri::command]
+fn check_grating_lobes(
+    antenna: Antenna,
+    scan_angle_deg: f64,
+    carrier_frequency: Option<f64>,
+    state: State<'_, FersState>,
+) -> Result<fers_api::GratingLobeReport, String> {
+    state.lock().map_err(|e| e.to_string())?.find_grating_lobes(
+        &antenna,
+        scan_angle_deg,
+        carrier_frequency.or(antenna.design_frequency),
+    )
+}
+
+/// A stateless command to compute the effective aperture of an antenna definition,
+/// `G * lambda^2 / (4 * pi)`, from its sampled peak gain.
+///
+/// The physical aperture is returned alongside so the UI can show the cross-check for
+/// dishes and horns; a warning is logged if the two differ by more than 5%.
+///
+/// # Parameters
+/// * `antenna` - The antenna definition.
+/// * `frequency` - The carrier frequency in Hz.
+///
+/// # Returns
+/// * `Ok((f64, Option<f64>))` - The effective aperture in square meters, then the physical
+///   aperture times efficiency in square meters. The second value is `Some` for parabolic
+///   and square horn antennas, which have a diameter, and `None` for every other pattern.
+/// * `Err(String)` - Error if a pattern file cannot be loaded or the frequency is not positive.
+#[tauri::command]
+fn compute_effective_aperture(
+    antenna: Antenna,
+    frequency: f64,
+) -> Result<(f64, Option<f64>), String> {
+    fers_api::calculate_effective_aperture(&antenna, frequency)
+}
+
+/// A stateless command to compute the -3 dB beamwidths of an antenna definition from its
+/// gain pattern.
+///
+/// The azimuth and elevation cuts through boresight are searched for the angles at which
+/// the gain falls to half its peak.
+///
+/// # Parameters
+/// * `antenna` - The antenna definition.
+/// * `frequency` - The carrier frequency in Hz.
+///
+/// # Returns
+/// * `Ok((f64, f64))` - The full azimuth and elevation beamwidths in degrees.
+/// * `Err(String)` - Error if a pattern file cannot be loaded, the frequency is not positive,
+///   or the pattern has no clear main lobe at boresight (e.g. an isotropic antenna).
+#[tauri::command]
+fn compute_beamwidth(antenna: Antenna, frequency: f64) -> Result<(f64, f64), String> {
+    fers_api::compute_beamwidth(&antenna, frequency)
+}
+
+/// Calculates visual radio links between platforms at a specific time.
+///
+/// This command performs a lightweight geometric and physics check to determine
+/// which platforms can "see" each other. It distinguishes between monostatic,
+/// bistatic, and interference paths based on signal-to-noise ratios.
+///
+/// # Parameters
+/// * `time` - The simulation time in seconds to evaluate.
+/// * `state` - The shared simulation state containing platforms and physics models.
+///
+/// # Returns
+/// * `Ok(Vec<VisualLink>)` - A list of renderable link segments with metadata (type, quality, label).
+/// * `Err(String)` - Error if context access fails.
+#[tauri::command]
+fn get_preview_links(
+    time: f64,
+    state: State<'_, FersState>,
+) -> Result<Vec<fers_api::VisualLink>, String> {
+    state.lock().map_err(|e| e.to_string())?.calculate_preview_links(time)
+}
+
+/// Computes the aggregate interference power seen by a receiver.
+///
+/// Sums the one-way received power from every active transmitter, excluding the
+/// receiver's own monostatic transmitter and target echoes, and reports the total
+/// alongside each emitter's contribution.
+///
+/// # Parameters
+/// * `rx` - The name of the receiver.
+/// * `time` - The simulation time in seconds.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(InterferenceReport)` - The total power, interference temperature and contributions.
+/// * `Err(String)` - Error if the receiver was not found.
+#[tauri::command]
+fn compute_interference(
+    rx: String,
+    time: f64,
+    state: State<'_, FersState>,
+) -> Result<fers_api::InterferenceReport, String> {
+    state.lock().map_err(|e| e.to_string())?.calculate_interference(&rx, time)
+}
+
+/// Computes the total occupied bandwidth of all active emitters over the simulation.
+///
+/// Each active transmitter occupies a band centred on its carrier, as wide as its
+/// waveform's sample rate; CW emitters are pure tones and occupy none. Overlapping
+/// bands are merged so shared spectrum is counted once.
+///
+/// # Parameters
+/// * `num_points` - The number of evenly spaced samples between the start and end time.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(Vec<(f64, f64)>)` - `(time, occupied_hz)` pairs.
+/// * `Err(String)` - Error if fewer than two points were requested.
+#[tauri::command]
+fn compute_spectrum_occupancy(
+    num_points: usize,
+    state: State<'_, FersState>,
+) -> Result<Vec<(f64, f64)>, String> {
+    state.lock().map_err(|e| e.to_string())?.calculate_spectrum_occupancy(num_points)
+}
+
+/// Finds the moment when the most emitters are active at once.
+///
+/// Activity comes from each transmitter's schedule; unscheduled transmitters are always
+/// active. Besides the evenly spaced samples, the start of every schedule period is
+/// checked, so brief overlaps between samples are still found.
+///
+/// # Parameters
+/// * `num_points` - The number of evenly spaced samples between the start and end time.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok((f64, usize))` - The earliest time of the peak and the number of active emitters.
+/// * `Err(String)` - Error if fewer than two points were requested.
+#[tauri::command]
+fn compute_peak_emitter_count(
+    num_points: usize,
+    state: State<'_, FersState>,
+) -> Result<(f64, usize), String> {
+    state.lock().map_err(|e| e.to_string())?.calculate_peak_emitter_count(num_points)
+}
+
+/// Computes a time-division schedule that keeps cooperative transmitters from interfering.
+///
+/// Transmitters take turns within a frame as long as the shortest PRI in the scenario.
+/// Each slot lasts one pulse and is followed by the guard time, and the frame repeats
+/// across the simulation time span.
+///
+/// # Parameters
+/// * `guard_time` - The idle time in seconds after each slot.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(Vec<(String, f64, f64)>)` - `(transmitter_name, slot_start, slot_end)` entries in time order.
+/// * `Err(String)` - Error if a transmitter is not pulsed or the total duty cycle, including
+///   guard times, does not fit within the shortest PRI.
+#[tauri::command]
+fn compute_tdm_schedule(
+    guard_time: f64,
+    state: State<'_, FersState>,
+) -> Result<Vec<(String, f64, f64)>, String> {
+    state.lock().map_err(|e| e.to_string())?.calculate_tdm_schedule(guard_time)
+}
+
+/// Finds pulses from different transmitters that arrive at a receiver at the same time.
+///
+/// Each pulsed transmitter emits from the start time at its PRF, following its schedule.
+/// A pulse reaches the receiver after the direct-path delay from the interpolated geometry
+/// at its emission time and lasts one pulse length. CW transmitters and the receiver's own
+/// monostatic transmitter are ignored.
+///
+/// # Parameters
+/// * `rx` - The name of the receiver.
+/// * `time_window` - The `(start, end)` of the arrival window in seconds.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(Vec<PulseCollision>)` - The overlapping pulse pairs, clipped to the window.
+/// * `Err(String)` - Error if the receiver was not found or the window is empty.
+#[tauri::command]
+fn compute_pulse_collisions(
+    rx: String,
+    time_window: (f64, f64),
+    state: State<'_, FersState>,
+) -> Result<Vec<fers_api::PulseCollision>, String> {
+    state.lock().map_err(|e| e.to_string())?.calculate_pulse_collisions(&rx, time_window)
+}
+
+/// Computes the transmit power required to achieve a target SNR.
+///
+/// Inverts the two-way radar equation (Tx -> Target -> Rx) at the given time against
+/// the receiver's thermal noise to find the transmit power.
+///
+/// # Parameters
+/// * `tx` - The name of the transmitter.
+/// * `target` - The name of the target.
+/// * `rx` - The name of the receiver.
+/// * `target_snr_db` - The desired SNR in dB.
+/// * `time` - The simulation time in seconds.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(f64)` - The required transmit power in dBW.
+/// * `Err(String)` - Error if a component was not found or the receiver has no noise.
+#[tauri::command]
+fn required_tx_power(
+    tx: String,
+    target: String,
+    rx: String,
+    target_snr_db: f64,
+    time: f64,
+    state: State<'_, FersState>,
+) -> Result<f64, String> {
+    state.lock().map_err(|e| e.to_string())?.calculate_required_tx_power(
+        &tx,
+        &target,
+        &rx,
+        target_snr_db,
+        time,
+    )
+}
+
+/// Computes the maximum range at which a monostatic radar detects a target.
+///
+/// Solves the monostatic radar equation for the range at which a target of `target_rcs`
+/// on the antenna boresight yields `snr_threshold_db`, using the waveform power and
+/// carrier, the antenna gain and the receiver's thermal noise.
+///
+/// # Parameters
+/// * `monostatic` - The name of the monostatic radar.
+/// * `target_rcs` - The radar cross section of the target in square meters.
+/// * `snr_threshold_db` - The detection threshold SNR in dB.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(f64)` - The maximum detection range in meters.
+/// * `Err(String)` - Error if the radar was not found or is not monostatic, or the range is undefined.
+#[tauri::command]
+fn compute_max_detection_range(
+    monostatic: String,
+    target_rcs: f64,
+    snr_threshold_db: f64,
+    state: State<'_, FersState>,
+) -> Result<f64, String> {
+    state.lock().map_err(|e| e.to_string())?.calculate_max_detection_range(
+        &monostatic,
+        target_rcs,
+        snr_threshold_db,
+    )
+}
+
+/// Computes the bistatic range Tx -> Target -> Rx between antenna phase centers.
+///
+/// Each end of the path is the component's platform position plus its
+/// `phase_center_offset`, rotated from the body frame by the platform attitude.
+///
+/// # Parameters
+/// * `tx` - The name of the transmitter.
+/// * `target` - The name of the target.
+/// * `rx` - The name of the receiver.
+/// * `time` - The simulation time in seconds.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(f64)` - The total path length in meters.
+/// * `Err(String)` - Error if a component was not found.
+#[tauri::command]
+fn compute_phase_center_range(
+    tx: String,
+    target: String,
+    rx: String,
+    time: f64,
+    state: State<'_, FersState>,
+) -> Result<f64, String> {
+    state.lock().map_err(|e| e.to_string())?.calculate_phase_center_range(&tx, &target, &rx, time)
+}
+
+/// Computes the thermal noise floor kTB of a receiver in dBm.
+///
+/// The temperature is the receiver's `noise_temp`, or 290 K when unset. The bandwidth
+/// is `1 / window_length` for pulsed receivers; CW receivers use the bandwidth of the
+/// simulated thermal noise, half the output sample rate divided by the oversampling ratio.
+///
+/// # Parameters
+/// * `rx` - The name of the receiver.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(f64)` - The noise power in dBm.
+/// * `Err(String)` - Error if the receiver was not found.
+#[tauri::command]
+fn compute_noise_floor(rx: String, state: State<'_, FersState>) -> Result<f64, String> {
+    state.lock().map_err(|e| e.to_string())?.calculate_noise_floor(&rx)
+}
+
+/// Computes the two-way link budget of a transmitter, target and receiver at a given range.
+///
+/// The received power follows the bistatic radar equation `Pt·Gt·Gr·σ·λ² / ((4π)³·R⁴)`
+/// with the target assumed `range` from both the transmitter and the receiver, while the
+/// antenna gains and RCS are taken along the actual directions to the target at the start
+/// of the simulation. The noise is kTB as in `compute_noise_floor`. Pulsed transmitters
+/// integrate every pulse over the simulated span and CW transmitters every noise-bandwidth
+/// sample.
+///
+/// # Parameters
+/// * `tx_id` - The name of the transmitter.
+/// * `rx_id` - The name of the receiver.
+/// * `target_id` - The name of the target.
+/// * `range` - The assumed range to the target in meters.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(LinkBudget)` - The path loss, received power, noise power and SNRs in dB.
+/// * `Err(String)` - Error if a component or the waveform was not found or the range is
+///   not positive.
+#[tauri::command]
+fn compute_link_budget(
+    tx_id: String,
+    rx_id: String,
+    target_id: String,
+    range: f64,
+    state: State<'_, FersState>,
+) -> Result<fers_api::LinkBudget, String> {
+    state
+        .lock()
+        .map_err(|e| e.to_string())?
+        .calculate_link_budget(&tx_id, &rx_id, &target_id, range)
+}
+
+/// Previews the phase noise a receiver's timing source adds to its downconversion.
+///
+/// The series is synthesized from the power-law spectrum `sum weight^2 * f^-alpha` of
+/// the timing's noise entries, with random phases seeded by the scenario's random seed,
+/// so its variance matches the integrated PSD. Samples are evenly spaced over `duration`
+/// at the simulation rate, thinned to at most 4096 points.
+///
+/// # Parameters
+/// * `rx` - The name of the receiver.
+/// * `duration` - The length of the preview in seconds.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(Vec<f64>)` - The phase noise in radians.
+/// * `Err(String)` - Error if the receiver or its timing source was not found.
+#[tauri::command]
+fn preview_receiver_phase_noise(
+    rx: String,
+    duration: f64,
+    state: State<'_, FersState>,
+) -> Result<Vec<f64>, String> {
+    state.lock().map_err(|e| e.to_string())?.preview_receiver_phase_noise(&rx, duration)
+}
+
+/// Previews the echo of a single pulse off one target in closed form.
+///
+/// The pulse is emitted at `time` and the receiver is sampled at the simulation rate from
+/// that instant, so the echo begins after the Tx -> Target -> Rx delay. The amplitude follows
+/// the bistatic radar equation, and the range rate of the whole path, including the
+/// transmitter's own motion, both Doppler shifts the carrier and time-scales the waveform.
+/// Noise and timing offsets are not included.
+///
+/// # Parameters
+/// * `tx` - The name of the pulsed transmitter.
+/// * `target` - The name of the target.
+/// * `rx` - The name of the receiver.
+/// * `time` - The emission time of the pulse in seconds.
+/// * `num_samples` - The number of receiver samples to compute.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(Vec<[f64; 2]>)` - The `[i, q]` baseband samples.
+/// * `Err(String)` - Error if a component was not found or the transmitter is not pulsed.
+#[tauri::command]
+fn preview_received_pulse(
+    tx: String,
+    target: String,
+    rx: String,
+    time: f64,
+    num_samples: usize,
+    state: State<'_, FersState>,
+) -> Result<Vec<[f64; 2]>, String> {
+    state.lock().map_err(|e| e.to_string())?.preview_received_pulse(
+        &tx,
+        &target,
+        &rx,
+        time,
+        num_samples,
+    )
+}
+
+/// Computes the weights that steer a receiver's element array to a look direction.
+///
+/// The weights are the array's plane-wave response in the look direction divided by the
+/// element count, so the beamformer output `sum(conj(w_n) * x_n)` passes a wave from that
+/// direction with unit gain. The look direction is relative to the receiver's platform body
+/// frame at the simulation start, and the wavelength is that of the monostatic transmitter's
+/// waveform, or else of the first transmitter with a waveform.
+///
+/// # Parameters
+/// * `rx` - The name of the receiver.
+/// * `look_azimuth` - The look azimuth in degrees, positive to the left of boresight.
+/// * `look_elevation` - The look elevation in degrees.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(Vec<[f64; 2]>)` - One `[re, im]` weight per array element.
+/// * `Err(String)` - Error if the receiver was not found or has no element array.
+#[tauri::command]
+fn preview_beamforming_weights(
+    rx: String,
+    look_azimuth: f64,
+    look_elevation: f64,
+    state: State<'_, FersState>,
+) -> Result<Vec<[f64; 2]>, String> {
+    state.lock().map_err(|e| e.to_string())?.preview_beamforming_weights(
+        &rx,
+        look_azimuth,
+        look_elevation,
+    )
+}
+
+/// Suggests the lowest PRF that measures a target's radial velocity without ambiguity.
+///
+/// The target's radial velocity (half the bistatic range rate, i.e. the line-of-sight
+/// velocity for a monostatic radar) is maximised over the simulation span, and the PRF
+/// is chosen so that the unambiguous velocity `lambda * prf / 4` reaches it. Raising the
+/// PRF shortens the unambiguous range `c / (2 * prf)`, so this often conflicts with the
+/// PRF that a distant target needs for unambiguous range.
+///
+/// # Parameters
+/// * `tx` - The name of the transmitter.
+/// * `target` - The name of the target.
+/// * `rx` - The name of the receiver.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(f64)` - The minimum PRF in Hz.
+/// * `Err(String)` - Error if a component was not found or the transmitter has no waveform.
+#[tauri::command]
+fn suggest_prf_for_velocity(
+    tx: String,
+    target: String,
+    rx: String,
+    state: State<'_, FersState>,
+) -> Result<f64, String> {
+    state.lock().map_err(|e| e.to_string())?.calculate_min_prf_for_velocity(&tx, &target, &rx)
+}
+
+/// Computes the output data rate a simulation run will produce.
+///
+/// Every receiver writes I and Q values at the output sample rate. CW receivers record
+/// continuously and pulsed receivers only during their windows, so a pulsed receiver
+/// averages `window_length * prf` of its peak rate. Receivers with a schedule are scaled
+/// by the share of the run they are active; the peak assumes all windows overlap.
+///
+/// # Parameters
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(DataRateReport)` - The peak and average rates in bytes per second.
+/// * `Err(String)` - Error if the calculation failed.
+#[tauri::command]
+fn compute_data_rate(state: State<'_, FersState>) -> Result<fers_api::DataRateReport, String> {
+    state.lock().map_err(|e| e.to_string())?.calculate_data_rate()
+}
+
+/// Computes the coherent processing interval needed to achieve a velocity resolution.
+///
+/// Inverts the Doppler velocity resolution `lambda / (2 * CPI)`, and flags whether the
+/// receiver records long enough: its available dwell is the simulation span, or its
+/// longest schedule period within the span.
+///
+/// # Parameters
+/// * `rx_name` - The name of the receiver whose dwell is checked.
+/// * `target_velocity_resolution` - The velocity resolution in m/s.
+/// * `carrier` - The carrier frequency in Hz.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(IntegrationTimeReport)` - The required interval, the available dwell and whether it suffices.
+/// * `Err(String)` - Error if the receiver is missing or an argument is not positive.
+#[tauri::command]
+fn required_integration_time(
+    rx_name: String,
+    target_velocity_resolution: f64,
+    carrier: f64,
+    state: State<'_, FersState>,
+) -> Result<fers_api::IntegrationTimeReport, String> {
+    state.lock().map_err(|e| e.to_string())?.calculate_required_integration_time(
+        &rx_name,
+        target_velocity_resolution,
+        carrier,
+    )
+}
+
+/// Computes the Cramér-Rao lower bound on range estimation for a Tx -> Target -> Rx echo.
+///
+/// The SNR is the single-pulse link budget against the receiver's thermal noise, and
+/// the effective bandwidth is the RMS bandwidth of a flat spectrum spanning the
+/// waveform's sample rate. The bound is `c / (4 pi B_rms sqrt(SNR))`.
+///
+/// # Parameters
+/// * `tx` - The name of the transmitter.
+/// * `target` - The name of the target.
+/// * `rx` - The name of the receiver.
+/// * `time` - The simulation time in seconds.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(f64)` - The range standard deviation bound in meters.
+/// * `Err(String)` - Error if a component was not found or the bandwidth cannot be
+///   determined (e.g. a CW waveform).
+#[tauri::command]
+fn compute_range_crlb(
+    tx: String,
+    target: String,
+    rx: String,
+    time: f64,
+    state: State<'_, FersState>,
+) -> Result<f64, String> {
+    state.lock().map_err(|e| e.to_string())?.calculate_range_crlb(&tx, &target, &rx, time)
+}
+
+/// Computes the matched-filter SNR loss caused by receive-window truncation.
+///
+/// The fraction of pulse energy captured is the receiver's window length divided by
+/// the transmitter's pulse duration; the loss is that fraction expressed in dB.
+///
+/// # Parameters
+/// * `rx` - The name of the pulsed receiver.
+/// * `tx` - The name of the pulsed transmitter.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(f64)` - The SNR loss in dB, zero if the window fully contains the pulse.
+/// * `Err(String)` - Error if a component was not found or is not pulsed.
+#[tauri::command]
+fn compute_window_truncation_loss(
+    rx: String,
+    tx: String,
+    state: State<'_, FersState>,
+) -> Result<f64, String> {
+    state.lock().map_err(|e| e.to_string())?.calculate_window_truncation_loss(&rx, &tx)
+}
+
+/// Computes the blind (eclipsed) ranges of a pulsed receiver for a transmitter.
+///
+/// Returns are lost while the transmit pulse is on, before the receive window opens and
+/// after it closes. The result covers one pulse repetition interval, up to the
+/// unambiguous range.
+///
+/// # Parameters
+/// * `rx` - The name of the pulsed receiver.
+/// * `tx` - The name of the pulsed transmitter.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(Vec<(f64, f64)>)` - The eclipsed `(range_start, range_end)` intervals in meters.
+/// * `Err(String)` - Error if a component was not found, is not pulsed, or the receiver
+///   window PRF differs from the transmitter PRF.
+#[tauri::command]
+fn compute_blind_ranges(
+    rx: String,
+    tx: String,
+    state: State<'_, FersState>,
+) -> Result<Vec<(f64, f64)>, String> {
+    state.lock().map_err(|e| e.to_string())?.calculate_blind_ranges(&rx, &tx)
+}
+
+/// Computes the combined range and velocity blind zones of a staggered PRF set.
+///
+/// For each PRF, the receiver keeps its window skip and length and the blind speeds are
+/// the non-zero multiples of `lambda * prf / 2`. A range or speed that is blind at every
+/// PRF cannot be recovered by staggering; one that is blind at only some PRFs can.
+///
+/// # Parameters
+/// * `rx` - The name of the pulsed receiver.
+/// * `tx` - The name of the pulsed transmitter.
+/// * `prf_set` - The PRFs to stagger between, in Hz.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(BlindZoneReport)` - The per-PRF blind ranges and speeds, and the unrecoverable and
+///   recoverable zones of the set.
+/// * `Err(String)` - Error if a component was not found or is not pulsed, or a PRF is not positive.
+#[tauri::command]
+fn compute_blind_zone_coverage(
+    rx: String,
+    tx: String,
+    prf_set: Vec<f64>,
+    state: State<'_, FersState>,
+) -> Result<fers_api::BlindZoneReport, String> {
+    state.lock().map_err(|e| e.to_string())?.calculate_blind_zone_coverage(&rx, &tx, &prf_set)
+}
+
+/// A stateless command to compare a receiver's HDF5 output against a reference file.
+///
+/// Intended for regression testing engine changes against a stored golden output.
+/// Windows are aligned by dataset name and samples are compared in physical units.
+///
+/// # Parameters
+/// * `actual_hdf5` - The path of the HDF5 output file under test.
+/// * `reference_hdf5` - The path of the reference HDF5 file.
+/// * `tolerance` - The largest acceptable sample difference.
+///
+/// # Returns
+/// * `Ok(ComparisonReport)` - The maximum and RMS sample difference and whether the
+///   maximum is within `tolerance`.
+/// * `Err(String)` - Error if a file could not be read or the files contain different
+///   windows.
+#[tauri::command]
+fn compare_output(
+    actual_hdf5: String,
+    reference_hdf5: String,
+    tolerance: f64,
+) -> Result<fers_api::ComparisonReport, String> {
+    fers_api::compare_output(&actual_hdf5, &reference_hdf5, tolerance)
+}
+
+/// A stateless command to read the blanked intervals recorded in a receiver's output.
+///
+/// Each window of a pulsed receiver with blanking gates records the absolute times its
+/// gates covered, so plots can mark the zeroed samples.
+///
+/// # Parameters
+/// * `hdf5_path` - The path of the receiver's HDF5 output file.
+///
+/// # Returns
+/// * `Ok(Vec<(f64, f64)>)` - The `(start, end)` times of every blanked interval.
+/// * `Err(String)` - Error if the file could not be read.
+#[tauri::command]
+fn read_blanked_intervals(hdf5_path: String) -> Result<Vec<(f64, f64)>, String> {
+    fers_api::read_blanked_intervals(&hdf5_path)
+}
+
+/// A stateless command to read the debug trace written by a simulation run.
+///
+/// The trace is written when the scenario's `export.debug` option is set and lists the
+/// delay, power gain, phase and Doppler shift of every pulse along every direct and
+/// target path, for diagnosing propagation and antenna effects.
+///
+/// # Parameters
+/// * `path` - The path of the debug trace CSV.
+///
+/// # Returns
+/// * `Ok(DebugTrace)` - The traced pulse paths, in emission order.
+/// * `Err(String)` - Error if the file could not be read or is not a debug trace.
+#[tauri::command]
+fn read_debug_output(path: String) -> Result<fers_api::DebugTrace, String> {
+    fers_api::read_debug_trace(&path)
+}
+
+/// A stateless command to compute a point on the receiver operating characteristic.
+///
+/// Models a square-law detector that non-coherently integrates `num_integrated` pulses
+/// and compares the sum against a threshold relative to the single-pulse noise power.
+///
+/// # Parameters
+/// * `snr_db` - The average single-pulse SNR in dB.
+/// * `threshold_db` - The detection threshold relative to the single-pulse noise power, in dB.
+/// * `num_integrated` - The number of integrated pulses.
+/// * `swerling` - The Swerling case, `"0"` (non-fluctuating) to `"4"`, optionally written as
+///   `"Swerling 1"` or `"swerling1"`.
+///
+/// # Returns
+/// * `Ok((f64, f64))` - The probability of detection and the probability of false alarm.
+/// * `Err(String)` - Error if the Swerling case is unknown, no pulses are integrated or an
+///   input is not finite.
+#[tauri::command]
+fn compute_detection_probabilities(
+    snr_db: f64,
+    threshold_db: f64,
+    num_integrated: usize,
+    swerling: String,
+) -> Result<(f64, f64), String> {
+    let swerling = swerling.parse::<fers_api::SwerlingCase>()?;
+    fers_api::calculate_detection_probabilities(snr_db, threshold_db, num_integrated, swerling)
+}
+
+/// A stateless command to compute the SNR gain of matched filtering an LFM pulse.
+///
+/// The gain is the time-bandwidth product `10 * log10(B * tau)`.
+///
+/// # Parameters
+/// * `pulse` - The pulse, which must be linear FM.
+///
+/// # Returns
+/// * `Ok(f64)` - The pulse compression gain in dB.
+/// * `Err(String)` - Error if the pulse is not LFM or its bandwidth or duration is not positive.
+#[tauri::command]
+fn compute_pulse_compression_gain(pulse: Pulse) -> Result<f64, String> {
+    fers_api::calculate_pulse_compression_gain(&pulse)
+}
+
+/// A stateless command to compute the energy spectral density of a pulse about its carrier.
+///
+/// Built-in pulses are synthesized at a rate of `4 B + 16 / tau`, which shows eight
+/// sidelobes either side of a rectangular pulse's main lobe, while file pulses keep the
+/// rate they are loaded at. The bins span that rate, centered on the carrier.
+///
+/// # Parameters
+/// * `pulse` - The pulse, either a built-in shape or a waveform file.
+/// * `num_bins` - The number of frequency bins, a power of two of at least 2.
+///
+/// # Returns
+/// * `Ok(Vec<(f64, Option<f64>)>)` - `(frequency_offset_hz, power_db)` pairs in increasing
+///   frequency, normalized to the peak, with `None` marking a null.
+/// * `Err(String)` - Error if the pulse is invalid, the file cannot be loaded or `num_bins`
+///   is not a power of two.
+#[tauri::command]
+fn compute_waveform_spectrum(
+    pulse: Pulse,
+    num_bins: usize,
+) -> Result<Vec<(f64, Option<f64>)>, String> {
+    fers_api::compute_waveform_spectrum(&pulse, num_bins)
+}
+
+/// A stateless command to check a file target's RCS file and preview its angular data.
+///
+/// The file holds separable azimuth and elevation cuts, so the pattern is returned on the
+/// grid of every azimuth sample against every elevation sample, with the RCS at each aspect
+/// the product of the two cuts. The file is parsed exactly as a file target loads it.
+///
+/// # Parameters
+/// * `filename` - The path to the target description file.
+///
+/// # Returns
+/// * `Ok(RcsPreview)` - Azimuth/elevation/value samples in degrees and square meters, with
+///   the smallest and largest RCS for scaling.
+/// * `Err(String)` - Error naming the problem if the file is missing, malformed or not made
+///   of azimuth and elevation cuts.
+#[tauri::command]
+fn load_rcs_file(filename: String) -> Result<fers_api::RcsPreview, String> {
+    fers_api::load_rcs_file(&filename)
+}
+
+/// Renders a top-down preview image of the scenario to a PNG file.
+///
+/// Every platform's motion path is sampled over the simulation time span and drawn
+/// in the x/y plane, coloured by the platform's role (transmitter, receiver,
+/// monostatic radar or target). The view is auto-scaled to fit all tracks.
+///
+/// # Parameters
+/// * `width` - The image width in pixels.
+/// * `height` - The image height in pixels.
+/// * `path` - The output PNG file path.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(())` - If the image was written.
+/// * `Err(String)` - Error if the dimensions are zero or the file could not be written.
+#[tauri::command]
+fn render_scenario_thumbnail(
+    width: u32,
+    height: u32,
+    path: String,
+    state: State<'_, FersState>,
+) -> Result<(), String> {
+    let tracks =
+        state.lock().map_err(|e| e.to_string())?.get_platform_tracks(THUMBNAIL_TRACK_POINTS)?;
+    thumbnail::write_track_thumbnail(&tracks, width, height, &path)
+}
+
+/// Reads a reference trajectory from a CSV file.
+///
+/// Each row holds `time,x,y,z` in seconds and meters in the scenario's coordinate frame.
+/// Blank lines, lines starting with `#` and a leading header row are ignored.
+fn read_reference_csv(path: &str) -> Result<Vec<MotionWaypoint>, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read reference trajectory '{path}': {e}"))?;
+
+    let mut waypoints = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Result<Vec<f64>, _> =
+            line.split(',').map(|field| field.trim().parse::<f64>()).collect();
+        match fields {
+            Ok(values) if values.len() == 4 => waypoints.push(MotionWaypoint {
+                time: values[0],
+                x: values[1],
+                y: values[2],
+                altitude: values[3],
+            }),
+            // Tolerate a header row before any data.
+            Err(_) if waypoints.is_empty() => continue,
+            _ => {
+                return Err(format!(
+                    "Invalid reference trajectory row {}: expected 'time,x,y,z'",
+                    index + 1
+                ))
+            }
+        }
+    }
+    if waypoints.is_empty() {
+        return Err(format!("Reference trajectory '{path}' contains no points"));
+    }
+    Ok(waypoints)
+}
+
+/// Compares a platform's simulated motion path against a reference trajectory.
+///
+/// Both paths are sampled at `num_points` evenly spaced times over the intersection of
+/// their time spans and the position errors are summarized.
+///
+/// # Parameters
+/// * `platform_id` - The name of the platform.
+/// * `reference_csv` - The path to a CSV file with `time,x,y,z` rows.
+/// * `num_points` - The number of time samples to compare.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(TrajectoryError)` - The RMS and maximum position error over the matched times.
+/// * `Err(String)` - Error if the CSV is invalid, the platform was not found or the
+///   time spans do not overlap.
+#[tauri::command]
+fn compare_trajectories(
+    platform_id: String,
+    reference_csv: String,
+    num_points: usize,
+    state: State<'_, FersState>,
+) -> Result<fers_api::TrajectoryError, String> {
+    let reference = read_reference_csv(&reference_csv)?;
+    state.lock().map_err(|e| e.to_string())?.compare_trajectory(
+        &platform_id,
+        &reference,
+        num_points,
+    )
+}
+
+/// Computes the cross-range and range resolution of a SAR collection.
+///
+/// The synthetic aperture is the distance travelled by the radar phase centre (the midpoint
+/// of the transmitter and receiver) while the target is illuminated. The cross-range
+/// resolution is `λR / (2L)` and the range resolution is `c / (2B)` for the pulse bandwidth.
+///
+/// # Parameters
+/// * `tx` - The name of the transmitter.
+/// * `target` - The name of the imaged target.
+/// * `rx` - The name of the receiver.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(SarResolution)` - The synthetic aperture length, slant range and resolutions.
+/// * `Err(String)` - Error if a component was not found, the waveform has no bandwidth or
+///   the radar does not move during illumination.
+#[tauri::command]
+fn compute_sar_resolution(
+    tx: String,
+    target: String,
+    rx: String,
+    state: State<'_, FersState>,
+) -> Result<fers_api::SarResolution, String> {
+    state.lock().map_err(|e| e.to_string())?.calculate_sar_resolution(&tx, &target, &rx)
+}
+
+/// Computes the range x cross-range x elevation resolution cell at a target.
+///
+/// The range resolution is `c / (2B)` for the waveform bandwidth. The azimuth and elevation
+/// extents are the half-power beamwidths times the range to the target, using the narrower
+/// of the transmit and receive beams.
+///
+/// # Parameters
+/// * `tx` - The name of the transmitter.
+/// * `target` - The name of the target.
+/// * `rx` - The name of the receiver.
+/// * `time` - The simulation time in seconds.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(ResolutionCell)` - The cell dimensions and volume.
+/// * `Err(String)` - Error if a component was not found, the waveform has no bandwidth or an
+///   antenna has no half-power beam edge.
+#[tauri::command]
+fn compute_resolution_cell(
+    tx: String,
+    target: String,
+    rx: String,
+    time: f64,
+    state: State<'_, FersState>,
+) -> Result<fers_api::ResolutionCell, String> {
+    state.lock().map_err(|e| e.to_string())?.compute_resolution_cell(&tx, &target, &rx, time)
+}
+
+/// Computes iso-Doppler contours on the ground for a transmitter/receiver pair.
+///
+/// For every node of the ground grid, the Doppler shift of a stationary scatterer is
+/// computed from the transmitter and receiver platform velocities at the given time.
+/// Contour polylines are then extracted for each requested Doppler value.
+///
+/// # Parameters
+/// * `tx` - The name of the transmitter.
+/// * `rx` - The name of the receiver.
+/// * `doppler_values` - The Doppler levels in Hz to extract.
+/// * `grid` - The ground sampling grid.
+/// * `time` - The simulation time in seconds.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(Vec<Vec<[f64; 2]>>)` - The contour polylines as `[x, y]` points.
+/// * `Err(String)` - Error if a component was not found or the grid is invalid.
+#[tauri::command]
+fn compute_isodoppler_contours(
+    tx: String,
+    rx: String,
+    doppler_values: Vec<f64>,
+    grid: CoverageGrid,
+    time: f64,
+    state: State<'_, FersState>,
+) -> Result<Vec<Vec<[f64; 2]>>, String> {
+    state.lock().map_err(|e| e.to_string())?.compute_isodoppler_contours(
+        &tx,
+        &rx,
+        &doppler_values,
+        &grid,
+        time,
+    )
+}
+
+/// Computes the ground region illuminated by a transmitter and visible to a receiver.
+///
+/// Each antenna's half-power footprint is traced by casting its -3 dB beam edge onto the
+/// horizontal plane at `ground_z`, and the two footprints are intersected.
+///
+/// # Parameters
+/// * `tx` - The name of the transmitter.
+/// * `rx` - The name of the receiver.
+/// * `ground_z` - The altitude of the ground plane in meters.
+/// * `time` - The simulation time in seconds.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(Vec<[f64; 3]>)` - The overlap polygon vertices, empty if the footprints are disjoint.
+/// * `Err(String)` - Error if a component was not found, or a beam has no half-power edge
+///   or does not fully intersect the ground.
+#[tauri::command]
+fn compute_bistatic_footprint_overlap(
+    tx: String,
+    rx: String,
+    ground_z: f64,
+    time: f64,
+    state: State<'_, FersState>,
+) -> Result<Vec<[f64; 3]>, String> {
+    state
+        .lock()
+        .map_err(|e| e.to_string())?
+        .compute_bistatic_footprint_overlap(&tx, &rx, ground_z, time)
+}
+
+/// Computes the grazing angle at which a platform's line of sight meets the ground.
+///
+/// The angle is measured between the platform-to-ground-point vector and the horizontal
+/// plane at `ground_z`, so looking straight down gives 90 degrees. When the scenario has
+/// terrain, the ground point sits on the terrain surface instead and must be visible
+/// from the platform.
+///
+/// # Parameters
+/// * `platform_id` - The name of the platform.
+/// * `ground_point` - The `[x, y]` ground intercept in meters.
+/// * `ground_z` - The altitude of the ground plane in meters, used outside the terrain.
+/// * `time` - The simulation time in seconds.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(f64)` - The grazing angle in degrees.
+/// * `Err(String)` - Error if the platform was not found, is not above the ground or the
+///   terrain hides the ground point.
+#[tauri::command]
+fn compute_grazing_angle(
+    platform_id: String,
+    ground_point: [f64; 2],
+    ground_z: f64,
+    time: f64,
+    state: State<'_, FersState>,
+) -> Result<f64, String> {
+    state.lock().map_err(|e| e.to_string())?.compute_grazing_angle(
+        &platform_id,
+        ground_point,
+        ground_z,
+        time,
+    )
+}
+
+/// Checks whether two platforms can see each other over the scenario terrain.
+///
+/// The straight path between the platforms is sampled at half the terrain grid spacing
+/// and is blocked if any sample lies below the interpolated terrain height. Without
+/// terrain every pair of platforms is intervisible.
+///
+/// # Parameters
+/// * `from_platform` - The name of the first platform.
+/// * `to_platform` - The name of the second platform.
+/// * `time` - The simulation time in seconds.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(bool)` - Whether the platforms are intervisible.
+/// * `Err(String)` - Error if a platform was not found.
+#[tauri::command]
+fn check_line_of_sight(
+    from_platform: String,
+    to_platform: String,
+    time: f64,
+    state: State<'_, FersState>,
+) -> Result<bool, String> {
+    state.lock().map_err(|e| e.to_string())?.check_line_of_sight(&from_platform, &to_platform, time)
+}
+
+/// Computes the area of the ground clutter patch illuminated at a slant range.
+///
+/// The ground is the plane at zero altitude and the grazing angle `ψ` follows from the
+/// receiver height and `range`. The area is `R·θ_az` times the shorter of the pulse-limited
+/// depth `cτ / (2 cos ψ)` and the beam-limited depth `R·θ_el / sin ψ`, using the narrower of
+/// the transmit and receive beamwidths.
+///
+/// # Parameters
+/// * `rx` - The name of the receiver.
+/// * `tx` - The name of the pulsed transmitter.
+/// * `range` - The slant range to the patch in meters.
+/// * `time` - The simulation time in seconds.
+/// * `state` - The shared simulation state.
+///
+/// # Returns
+/// * `Ok(f64)` - The patch area in square meters.
+/// * `Err(String)` - Error if a component was not found, the transmitter is not pulsed, the
+///   range does not exceed the receiver height or an antenna has no half-power beam edge.
+#[tauri::command]
+fn compute_clutter_cell_area(
+    rx: String,
+    tx: String,
+    range: f64,
+    time: f64,
+    state: State<'_, FersState>,
+) -> Result<f64, String> {
+    state.lock().map_err(|e| e.to_string())?.compute_clutter_cell_area(&rx, &tx, range, time)
+}
+
+/// Initializes and runs the Tauri application.
+///
+/// This function is the main entry point for the desktop application. It performs
+/// the following setup steps:
+///
+/// 1. Creates a new `FersContext` by calling the FFI layer. If this fails, it
+///    indicates a linking or initialization problem with `libfers`.
+/// 2. Registers Tauri plugins for file dialogs, file system access, and shell operations.
+/// 3. Stores the `FersContext` in Tauri's managed state, protected by a `Mutex`,
+///    alongside the cancellation flag of the running simulation.
+/// 4. Registers all Tauri commands so they can be invoked from the frontend.
+/// 5. Launches the Tauri application event loop.
+///
+/// # Panics
+///
+/// This function will panic if:
+/// * The `FersContext` cannot be created (indicating a problem with `libfers`).
+/// * The Tauri application fails to start due to misconfiguration.
+///
+/// # Example
+///
+/// This function is typically called from `main.rs`:
+///
+/// ```rust
+/// fers_ui_lib::run();
+/// ```
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
+pub fn run() {
+    // Attempt to create the FFI context. This validates that libfers is correctly linked.
+    let context = fers_api::FersContext::new()
+        .expect("Failed to create FERS context. Is libfers linked correctly?");
+
+    tauri::Builder::default()
+        // Register Tauri plugins for UI functionality
+        .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_fs::init())
+        // Store the FersContext as managed state, accessible from all commands
+        .manage(Mutex::new(context))
+        .manage(CancelState::default())
+        // Register all Tauri commands that can be invoked from the frontend
+        .invoke_handler(tauri::generate_handler![
+            load_scenario_from_xml_file,
+            merge_scenario_from_xml_file,
+            validate_xml_file,
+            validate_scenario,
+            get_scenario_as_json,
+            get_scenario_as_xml,
+            get_scenario_as_xml_validated,
+            update_scenario_from_json,
+            export_scenario_as_json_file,
+            import_scenario_from_json_file,
+            undo_scenario,
+            redo_scenario,
+            set_scenario_history_depth,
+            run_simulation,
+            run_simulation_streaming,
+            run_simulation_to,
+            run_simulation_subset,
+            cancel_simulation,
+            generate_kml,
+            generate_kmz,
+            get_interpolated_motion_path,
+            get_interpolated_velocity_profile,
+            preview_hop_sequence,
+            validate_inline_waveform,
+            geodetic_to_local,
+            local_to_geodetic,
+            get_interpolated_rotation_path,
+            get_antenna_pattern,
+            sample_antenna_pattern,
+            get_preview_links,
+            compute_interference,
+            compute_spectrum_occupancy,
+            compute_peak_emitter_count,
+            required_tx_power,
+            compute_max_detection_range,
+            compute_window_truncation_loss,
+            render_scenario_thumbnail,
+            compute_sar_resolution,
+            compute_resolution_cell,
+            compute_blind_ranges,
+            compare_output,
+            read_blanked_intervals,
+            read_debug_output,
+            compute_detection_probabilities,
+            compute_pulse_compression_gain,
+            compute_waveform_spectrum,
+            load_rcs_file,
+            import_from_dis,
+            import_scenario_from_output,
+            time_scale_scenario,
+            duplicate_platform,
+            compute_tdm_schedule,
+            compute_pulse_collisions,
+            compute_blind_zone_coverage,
+            check_grating_lobes,
+            compute_effective_aperture,
+            compute_beamwidth,
+            compute_phase_center_range,
+            compute_noise_floor,
+            compute_link_budget,
+            preview_receiver_phase_noise,
+            preview_received_pulse,
+            preview_beamforming_weights,
+            suggest_prf_for_velocity,
+            compute_data_rate,
+            required_integration_time,
+            compute_range_crlb,
+            compare_trajectories,
+            compute_isodoppler_contours,
+            compute_bistatic_footprint_overlap,
+            compute_grazing_angle,
+            check_line_of_sight,
+            compute_clutter_cell_area,
+        ])
+        .run(tauri::generate_context!())
+        .expect("error while running tauri application");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fers_api;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    /// Verifies that the `libfers` C++ library is correctly linked.
+    ///
+    /// This test ensures that:
+    /// 1. The Rust linker can find the `libfers.a` static library.
+    /// 2. All required FFI symbols are present and callable.
+    /// 3. The `FersContext::new()` wrapper successfully creates a C++ context.
+    ///
+    /// If this test fails:
+    /// * Check that the `build.rs` script correctly specifies library search paths.
+    /// * Verify that the C++ libraries have been built by CMake.
+    /// * Ensure that `bindgen` generated the correct bindings from `api.h`.
+    ///
+    /// # Panics
+    ///
+    /// This test will panic if `FersContext::new()` returns `None`, indicating
+    /// a failure to allocate or initialize the C++ context.
+    #[test]
+    fn it_links_libfers_and_creates_context() {
+        // This test will fail at link time if the library is not found.
+        // It will fail at runtime if the symbols don't match or creation fails.
+        let _context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+
+        // The context is automatically destroyed when it goes out of scope due to `Drop`.
+        // No explicit destroy call is needed.
+    }
+
+    /// A motion path that keeps a platform fixed at the origin.
+    const STATIC_MOTION: &str = r#"{"interpolation": "static",
+        "positionwaypoints": [{"time": 0.0, "x": 0.0, "y": 0.0, "altitude": 0.0}]}"#;
+
+    /// Builds a minimal scenario JSON document with a single platform.
+    ///
+    /// The scenario defines one CW waveform (1 GHz), one isotropic antenna and one timing
+    /// source, all named `"default"`, so that components can reference them by name.
+    fn minimal_scenario_json(motion: &str, components: &str) -> String {
+        format!(
+            r#"{{"simulation": {{
+                "name": "test",
+                "parameters": {{
+                    "starttime": 0.0, "endtime": 1.0, "rate": 1000.0,
+                    "origin": {{"latitude": 0.0, "longitude": 0.0, "altitude": 0.0}},
+                    "coordinatesystem": {{"frame": "ENU"}}
+                }},
+                "waveforms": [{{"name": "default", "power": 1.0, "carrier_frequency": 1e9, "cw": {{}}}}],
+                "antennas": [{{"name": "default", "pattern": "isotropic"}}],
+                "timings": [{{"name": "default", "frequency": 1e6}}],
+                "platforms": [{{
+                    "name": "platform",
+                    "motionpath": {motion},
+                    "fixedrotation": {{
+                        "startazimuth": 0.0, "startelevation": 0.0,
+                        "azimuthrate": 0.0, "elevationrate": 0.0
+                    }},
+                    "components": [{components}]
+                }}]
+            }}}}"#
+        )
+    }
+
+    /// A file in the temporary directory that is removed when dropped, so a failing test
+    /// does not leave it behind.
+    struct TempFile(std::path::PathBuf);
+
+    /// Returns a path in the temporary directory that no other test in this run uses.
+    fn unique_temp_path(suffix: &str) -> std::path::PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let index = COUNTER.fetch_add(1, Ordering::Relaxed);
+        std::env::temp_dir().join(format!("fers_test_{}_{index}{suffix}", std::process::id()))
+    }
+
+    impl TempFile {
+        /// Reserves a unique path with the given extension.
+        fn new(extension: &str) -> Self {
+            Self(unique_temp_path(&format!(".{extension}")))
+        }
+
+        /// The path as a string, as the `FersContext` file functions take it.
+        fn path(&self) -> &str {
+            self.0.to_str().expect("temporary path should be valid UTF-8")
+        }
+    }
+
+    impl std::ops::Deref for TempFile {
+        type Target = std::path::Path;
+
+        fn deref(&self) -> &std::path::Path {
+            &self.0
+        }
+    }
+
+    impl AsRef<std::path::Path> for TempFile {
+        fn as_ref(&self) -> &std::path::Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    /// A directory in the temporary directory that is removed with its contents when
+    /// dropped, used as the output location of simulation runs.
+    struct TempDir(std::path::PathBuf);
+
+    impl TempDir {
+        /// Creates an empty directory at a unique path.
+        fn new() -> Self {
+            let path = unique_temp_path("");
+            std::fs::create_dir_all(&path).expect("temporary directory should be created");
+            Self(path)
+        }
+
+        /// An output location writing into this directory without a basename.
+        fn output(&self) -> fers_api::OutputLocation {
+            fers_api::OutputLocation {
+                directory: self.0.to_str().expect("temporary path should be valid UTF-8").into(),
+                basename: String::new(),
+            }
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Writes a four-sample unit pulse at `sample_rate` to a temporary file and returns the
+    /// file with the JSON of a 1 W waveform called `name` at `carrier` that reads it.
+    fn pulse_waveform_json(name: &str, sample_rate: f64, carrier: f64) -> (TempFile, String) {
+        let file = TempFile::new("csv");
+        std::fs::write(&file.0, format!("4 {sample_rate}\n(1,0) (1,0) (1,0) (1,0)\n"))
+            .expect("waveform file should be written");
+        let filename = file.path().replace('\\', "/");
+        let json = format!(
+            r#"{{"name": "{name}", "power": 1.0, "carrier_frequency": {carrier:e},
+                "pulsed_from_file": {{"filename": "{filename}"}}}}"#
+        );
+        (file, json)
+    }
+
+    /// Adds comma-separated waveform objects ahead of the default waveform in `json`.
+    fn with_waveforms(json: &str, waveforms: &str) -> String {
+        json.replacen(r#""waveforms": ["#, &format!(r#""waveforms": [{waveforms}, "#), 1)
+    }
+
+    /// Loads XML scenario text into a fresh context through a temporary file.
+    fn load_xml(xml: &str) -> Result<fers_api::FersContext, String> {
+        let file = TempFile::new("xml");
+        std::fs::write(&file.0, xml).expect("XML should be writable");
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.load_scenario_from_xml_file(file.path())?;
+        Ok(context)
+    }
+
+    /// Exports the scenario in `ctx` as XML and loads it into a fresh context.
+    fn reload_via_xml(ctx: &fers_api::FersContext) -> fers_api::FersContext {
+        let xml = ctx.get_scenario_as_xml().expect("scenario should serialize to XML");
+        load_xml(&xml).expect("exported XML should load")
+    }
+
+    /// Verifies that a monostatic component with only a name and PRF (plus its asset
+    /// references) deserializes, that the window defaults are filled in, and that a pulsed
+    /// monostatic without a positive PRF is rejected.
+    #[test]
+    fn monostatic_with_only_prf_uses_window_defaults() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"monostatic": {"name": "radar", "prf": 1000.0,
+                "waveform": "default", "antenna": "default", "timing": "default"}}"#,
+        );
+
+        context.update_scenario_from_json(&json).expect("monostatic should deserialize");
+
+        let round_trip = context.get_scenario_as_json().expect("scenario should serialize");
+        assert!(round_trip.contains(r#""window_skip": 0.0"#));
+        assert!(round_trip.contains(r#""window_length": 0.001"#));
+        assert!(round_trip.contains(r#""nodirect": false"#));
+
+        // Without a PRF there is no pulse repetition interval to default the window to
+        for component in [
+            r#"{"monostatic": {"name": "radar",
+                "waveform": "default", "antenna": "default", "timing": "default"}}"#,
+            r#"{"monostatic": {"name": "radar", "pulsed_mode": {"prf": 0.0},
+                "waveform": "default", "antenna": "default", "timing": "default"}}"#,
+        ] {
+            let error = context
+                .update_scenario_from_json(&minimal_scenario_json(STATIC_MOTION, component))
+                .expect_err("a pulsed monostatic without a positive PRF should be rejected");
+            assert!(error.contains("radar"), "error should name the component: {error}");
+        }
+    }
+
+    /// Verifies that the zero-Doppler contour of a sensor moving along X is the line
+    /// perpendicular to its velocity through its ground track.
+    #[test]
+    fn zero_doppler_contour_is_perpendicular_to_velocity() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            r#"{"interpolation": "linear", "positionwaypoints": [
+                {"time": 0.0, "x": -100.0, "y": 0.0, "altitude": 100.0},
+                {"time": 1.0, "x": 100.0, "y": 0.0, "altitude": 100.0}]}"#,
+            r#"{"monostatic": {"name": "radar", "cw_mode": {},
+                "waveform": "default", "antenna": "default", "timing": "default"}}"#,
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let grid = super::CoverageGrid {
+            x_min: -1000.0,
+            x_max: 1000.0,
+            y_min: -1000.0,
+            y_max: 1000.0,
+            z: 0.0,
+            nx: 21,
+            ny: 21,
+        };
+        let contours = context
+            .compute_isodoppler_contours("radar", "radar", &[0.0], &grid, 0.5)
+            .expect("contours should compute");
+
+        assert!(!contours.is_empty());
+        for point in contours.iter().flatten() {
+            assert!(point[0].abs() < 1e-6, "zero-Doppler point off the bisector: {point:?}");
+        }
+        let ys: Vec<f64> = contours.iter().flatten().map(|p| p[1]).collect();
+        assert!(ys.iter().cloned().fold(f64::INFINITY, f64::min) <= -1000.0 + 1e-6);
+        assert!(ys.iter().cloned().fold(f64::NEG_INFINITY, f64::max) >= 1000.0 - 1e-6);
+    }
+
+    /// Verifies that two platforms at the same local position but with different
+    /// per-platform origins are placed at distinct geodetic locations in the KML output.
+    #[test]
+    fn platform_origins_map_to_distinct_geodetic_locations() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let platform = |name: &str, lat: f64, lon: f64| {
+            format!(
+                r#"{{"name": "{name}",
+                    "origin": {{"latitude": {lat:.1}, "longitude": {lon:.1}, "altitude": 0.0}},
+                    "motionpath": {STATIC_MOTION},
+                    "fixedrotation": {{
+                        "startazimuth": 0.0, "startelevation": 0.0,
+                        "azimuthrate": 0.0, "elevationrate": 0.0
+                    }},
+                    "components": [{{"target": {{"name": "{name}-target",
+                        "rcs": {{"type": "isotropic", "value": 1.0}}}}}}]}}"#
+            )
+        };
+        let json = minimal_scenario_json(STATIC_MOTION, "").replacen(
+            r#""platforms": ["#,
+            &format!(
+                r#""platforms": [{}, {}, "#,
+                platform("a", 10.0, 20.0),
+                platform("b", -30.0, 40.0)
+            ),
+            1,
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let round_trip = context.get_scenario_as_json().expect("scenario should serialize");
+        assert!(round_trip.contains(r#""latitude": -30.0"#));
+
+        let path = TempFile::new("kml");
+        context.generate_kml(path.to_str().unwrap(), None).expect("KML should generate");
+        let kml = std::fs::read_to_string(&path).expect("KML should be readable");
+
+        assert!(kml.contains("20.000000,10.000000,0.000000"));
+        assert!(kml.contains("40.000000,-30.000000,0.000000"));
+    }
+
+    /// Verifies that the interference at a receiver is the sum of the one-way powers
+    /// received from two isotropic jammers at known distances.
+    #[test]
+    fn interference_from_two_jammers_is_their_power_sum() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let jammer = |name: &str, x: f64, y: f64| {
+            format!(
+                r#"{{"name": "{name}",
+                    "motionpath": {{"interpolation": "static", "positionwaypoints": [
+                        {{"time": 0.0, "x": {x:.1}, "y": {y:.1}, "altitude": 0.0}}]}},
+                    "fixedrotation": {{
+                        "startazimuth": 0.0, "startelevation": 0.0,
+                        "azimuthrate": 0.0, "elevationrate": 0.0
+                    }},
+                    "components": [{{"transmitter": {{"name": "{name}", "cw_mode": {{}},
+                        "waveform": "default", "antenna": "default", "timing": "default"}}}}]}}"#
+            )
+        };
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"receiver": {"name": "rx", "cw_mode": {},
+                "antenna": "default", "timing": "default"}}"#,
+        )
+        .replacen(
+            r#""platforms": ["#,
+            &format!(
+                r#""platforms": [{}, {}, "#,
+                jammer("jammer-a", 1000.0, 0.0),
+                jammer("jammer-b", 0.0, 2000.0)
+            ),
+            1,
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let report =
+            context.calculate_interference("rx", 0.0).expect("interference should compute");
+
+        let lambda = 299_792_458.0 / 1e9;
+        let one_way =
+            |dist: f64| lambda * lambda / (16.0 * std::f64::consts::PI.powi(2) * dist * dist);
+        let expected = one_way(1000.0) + one_way(2000.0);
+        assert_eq!(report.contributions.len(), 2);
+        assert!((report.total_power - expected).abs() < 1e-9 * expected);
+        let sum: f64 = report.contributions.iter().map(|c| c.power).sum();
+        assert!((report.total_power - sum).abs() < 1e-12 * expected);
+    }
+
+    /// Verifies that the fast piecewise-linear preview of a cubic path stays within the
+    /// requested tolerance of the exact interpolation.
+    #[test]
+    fn fast_motion_path_stays_within_tolerance() {
+        let waypoints = || {
+            (0..20)
+                .map(|i| {
+                    let t = i as f64;
+                    super::MotionWaypoint {
+                        time: t,
+                        x: 1000.0 * (t * 0.7).cos(),
+                        y: 1000.0 * (t * 0.7).sin(),
+                        altitude: 100.0 * t,
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+        let tolerance = 0.5;
+
+        let exact = fers_api::get_interpolated_motion_path(
+            waypoints(),
+            super::InterpolationType::Cubic,
+            5001,
+            &Default::default(),
+        )
+        .expect("exact path should interpolate");
+        let fast = fers_api::get_interpolated_motion_path(
+            waypoints(),
+            super::InterpolationType::Cubic,
+            5001,
+            &super::PathPreviewOptions {
+                fast: true,
+                tolerance: Some(tolerance),
+                ..Default::default()
+            },
+        )
+        .expect("fast path should interpolate");
+
+        assert_eq!(exact.len(), fast.len());
+        let max_error = exact
+            .iter()
+            .zip(&fast)
+            .map(|(a, b)| ((a.x - b.x).powi(2) + (a.y - b.y).powi(2) + (a.z - b.z).powi(2)).sqrt())
+            .fold(0.0, f64::max);
+        assert!(max_error <= tolerance, "approximation error {max_error} exceeds {tolerance}");
+        assert!(max_error > 0.0, "fast path should be an approximation");
+    }
+
+    /// Verifies that scenario metadata round-trips through XML and that updating the
+    /// scenario from JSON refreshes the `modified` timestamp.
+    #[test]
+    fn scenario_metadata_round_trips_and_tracks_modification() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(STATIC_MOTION, "").replacen(
+            r#""name": "test","#,
+            r#""name": "test", "metadata": {"author": "Jane Doe",
+                "created": "2020-01-01T00:00:00Z", "modified": "2020-01-01T00:00:00Z",
+                "tool_version": "0.1.0"},"#,
+            1,
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let updated = context.get_scenario_as_json().expect("scenario should serialize");
+        assert!(updated.contains(r#""created": "2020-01-01T00:00:00Z""#));
+        assert!(!updated.contains(r#""modified": "2020-01-01T00:00:00Z""#));
+
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(xml.contains("<author>Jane Doe</author>"));
+        let reloaded = reload_via_xml(&context);
+
+        let round_trip = reloaded.get_scenario_as_json().expect("scenario should serialize");
+        assert!(round_trip.contains(r#""author": "Jane Doe""#));
+        assert!(round_trip.contains(r#""tool_version": "0.1.0""#));
+    }
+
+    /// Verifies that the required transmit power, plugged back into the monostatic radar
+    /// equation with the receiver's kTB noise, reproduces the requested SNR.
+    #[test]
+    fn required_tx_power_reproduces_target_snr() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"monostatic": {"name": "radar", "cw_mode": {}, "noise_temp": 290.0,
+                "waveform": "default", "antenna": "default", "timing": "default"}}"#,
+        )
+        .replacen(
+            r#""platforms": ["#,
+            r#""platforms": [{"name": "aircraft",
+                "motionpath": {"interpolation": "static", "positionwaypoints": [
+                    {"time": 0.0, "x": 5000.0, "y": 0.0, "altitude": 0.0}]},
+                "fixedrotation": {"startazimuth": 0.0, "startelevation": 0.0,
+                    "azimuthrate": 0.0, "elevationrate": 0.0},
+                "components": [{"target": {"name": "aircraft",
+                    "rcs": {"type": "isotropic", "value": 10.0}}}]}, "#,
+            1,
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let target_snr_db = 13.0;
+        let power_dbw = context
+            .calculate_required_tx_power("radar", "aircraft", "radar", target_snr_db, 0.0)
+            .expect("required power should compute");
+
+        let lambda = 299_792_458.0 / 1e9;
+        let range: f64 = 5000.0;
+        let received = 10f64.powf(power_dbw / 10.0) * lambda * lambda * 10.0
+            / ((4.0 * std::f64::consts::PI).powi(3) * range.powi(4));
+        let noise = 1.380_650_3e-23 * 290.0 * (1000.0 / 2.0);
+        let snr_db = 10.0 * (received / noise).log10();
+        assert!((snr_db - target_snr_db).abs() < 1e-9, "got {snr_db} dB");
+    }
+
+    /// Verifies that a reference trajectory offset by a constant vector yields that
+    /// offset as both the RMS and maximum error, over the overlapping time span only.
+    #[test]
+    fn offset_reference_trajectory_has_constant_error() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            r#"{"interpolation": "linear", "positionwaypoints": [
+                {"time": 0.0, "x": 0.0, "y": 0.0, "altitude": 100.0},
+                {"time": 10.0, "x": 1000.0, "y": 0.0, "altitude": 100.0}]}"#,
+            "",
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let path = TempFile::new("csv");
+        std::fs::write(&path, "time,x,y,z\n5.0,500.0,3.0,104.0\n15.0,1500.0,3.0,104.0\n")
+            .expect("CSV should be writable");
+        let reference = super::read_reference_csv(path.to_str().unwrap());
+        let reference = reference.expect("CSV should parse");
+
+        let error = context
+            .compare_trajectory("platform", &reference, 101)
+            .expect("trajectories should compare");
+        assert!((error.rms_error - 5.0).abs() < 1e-9);
+        assert!((error.max_error - 5.0).abs() < 1e-9);
+        assert_eq!(error.start_time, 5.0);
+        assert_eq!(error.end_time, 10.0);
+    }
+
+    /// Verifies the grazing angle for a nadir view (90 degrees) and a distant, nearly
+    /// horizontal line of sight (a small angle).
+    #[test]
+    fn grazing_angle_at_nadir_and_near_horizon() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            r#"{"interpolation": "static", "positionwaypoints": [
+                {"time": 0.0, "x": 0.0, "y": 0.0, "altitude": 1000.0}]}"#,
+            "",
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let nadir = context
+            .compute_grazing_angle("platform", [0.0, 0.0], 0.0, 0.0)
+            .expect("nadir angle should compute");
+        assert!((nadir - 90.0).abs() < 1e-9);
+
+        let horizon = context
+            .compute_grazing_angle("platform", [100_000.0, 0.0], 0.0, 0.0)
+            .expect("horizon angle should compute");
+        let expected = (1000.0f64 / 100_000.0).atan().to_degrees();
+        assert!((horizon - expected).abs() < 1e-9);
+        assert!(horizon < 1.0);
+    }
+
+    /// Verifies that two partially overlapping emitters only count their shared band
+    /// once, and only while both are active.
+    #[test]
+    fn spectrum_occupancy_merges_overlapping_emitters() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let emitter = |name: &str, start: f64, end: f64| {
+            format!(
+                r#"{{"transmitter": {{"name": "{name}", "pulsed_mode": {{"prf": 100.0}},
+                    "schedule": [{{"start": {start:.1}, "end": {end:.1}}}],
+                    "waveform": "{name}", "antenna": "default", "timing": "default"}}}}"#
+            )
+        };
+        let (_file_a, pulse_a) = pulse_waveform_json("tx-a", 1e6, 1.0e9);
+        let (_file_b, pulse_b) = pulse_waveform_json("tx-b", 1e6, 1.0005e9);
+        let json = with_waveforms(
+            &minimal_scenario_json(
+                STATIC_MOTION,
+                &format!("{}, {}", emitter("tx-a", 0.0, 0.6), emitter("tx-b", 0.4, 1.0)),
+            ),
+            &format!("{pulse_a}, {pulse_b}"),
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let occupancy = context.calculate_spectrum_occupancy(11).expect("occupancy should compute");
+
+        assert_eq!(occupancy.len(), 11);
+        let at = |time: f64| {
+            occupancy
+                .iter()
+                .find(|(t, _)| (t - time).abs() < 1e-9)
+                .map(|&(_, hz)| hz)
+                .expect("sample time should exist")
+        };
+        assert!((at(0.2) - 1.0e6).abs() < 1e-3);
+        assert!((at(0.5) - 1.5e6).abs() < 1e-3);
+        assert!((at(0.8) - 1.0e6).abs() < 1e-3);
+    }
+
+    /// Verifies that an NSMA `.pat` antenna loads through the "file" pattern and that
+    /// the sampled gain matches the file's values at its grid points.
+    #[test]
+    fn nsma_pat_antenna_matches_file_values_at_grid_points() {
+        let pat_path = TempFile::new("pat");
+        std::fs::write(
+            &pat_path,
+            "'Synthetic test panel', 0, 10.0\n999\n1, 4\n0,\n0, 0\n90, -3\n180, -20\n270, -6\n\
+             1, 3\n0,\n-90, -10\n0, 0\n90, -10\n",
+        )
+        .expect("pattern file should be written");
+        let filename = pat_path.to_string_lossy().replace('\\', "/");
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(STATIC_MOTION, "").replacen(
+            r#""antennas": ["#,
+            &format!(
+                r#""antennas": [{{"name": "panel", "pattern": "file", "filename": "{filename}"}}, "#
+            ),
+            1,
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+        let pattern =
+            context.get_antenna_pattern("panel", 361, 181, 1e9).expect("pattern should sample");
+
+        assert!((pattern.max_gain - 10.0).abs() < 1e-9);
+        // Rows are elevations from -90 to 90 degrees, columns azimuths from -180 to 180 degrees.
+        let gain = |row: usize, col: usize| pattern.gains[row * pattern.az_count + col];
+        let from_db = |value: f64| 10f64.powf(value / 10.0);
+        assert!((gain(90, 180) - 1.0).abs() < 1e-9);
+        // Engine azimuths are counter-clockwise, so +90 degrees is a 270 degree bearing.
+        assert!((gain(90, 270) - from_db(-6.0)).abs() < 1e-9);
+        assert!((gain(90, 90) - from_db(-3.0)).abs() < 1e-9);
+        assert!((gain(90, 0) - from_db(-20.0)).abs() < 1e-9);
+        assert!((gain(180, 180) - from_db(-10.0)).abs() < 1e-9);
+        assert!((gain(0, 180) - from_db(-10.0)).abs() < 1e-9);
+    }
+
+    /// Verifies that a receive window half the pulse length loses half the pulse energy
+    /// (about 3 dB), and that a window covering the whole pulse loses nothing.
+    #[test]
+    fn half_length_window_loses_three_db() {
+        let (_waveform, pulse) = pulse_waveform_json("pulse", 1e3, 1e9);
+        let scenario = |window_length: f64| {
+            let json = minimal_scenario_json(
+                STATIC_MOTION,
+                &format!(
+                    r#"{{"monostatic": {{"name": "radar",
+                        "pulsed_mode": {{"prf": 10.0, "window_skip": 0.0, "window_length": {window_length}}},
+                        "waveform": "pulse", "antenna": "default", "timing": "default"}}}}"#
+                ),
+            );
+            with_waveforms(&json, &pulse)
+        };
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&scenario(0.002)).expect("scenario should load");
+        let half = context.calculate_window_truncation_loss("radar", "radar");
+        context.update_scenario_from_json(&scenario(0.01)).expect("scenario should load");
+        let full = context.calculate_window_truncation_loss("radar", "radar");
+
+        let half = half.expect("loss should compute");
+        assert!((half - 10.0 * 2f64.log10()).abs() < 1e-9);
+        assert_eq!(full.expect("loss should compute"), 0.0);
+    }
+
+    /// Verifies that components without asset references inherit the scenario defaults
+    /// instead of being dropped or left unresolved, and that the defaults round-trip.
+    #[test]
+    fn components_inherit_scenario_default_assets() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"receiver": {"name": "rx", "cw_mode": {}, "antenna": ""}},
+                {"transmitter": {"name": "tx", "cw_mode": {}, "waveform": "default"}}"#,
+        )
+        .replacen(
+            r#""rate": 1000.0,"#,
+            r#""rate": 1000.0, "default_antenna": "default", "default_timing": "default","#,
+            1,
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let round_trip = context.get_scenario_as_json().expect("scenario should serialize");
+        assert!(round_trip.contains(r#""default_antenna": "default""#));
+        assert!(round_trip.contains(r#""default_timing": "default""#));
+        assert!(round_trip.contains(r#""name": "tx""#));
+        assert!(!round_trip.contains(r#""antenna": """#));
+
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(xml.contains("<default_antenna>default</default_antenna>"));
+        assert!(xml.contains("<default_timing>default</default_timing>"));
+    }
+
+    /// Verifies that a colocated transmitter and receiver survive an XML round trip on one
+    /// platform, and that older JSON with a single `component` object still loads.
+    #[test]
+    fn platform_holds_multiple_components() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"transmitter": {"name": "tx", "cw_mode": {}, "waveform": "default",
+                    "antenna": "default", "timing": "default"}},
+                {"receiver": {"name": "rx", "cw_mode": {}, "antenna": "default",
+                    "timing": "default"}}"#,
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(
+            xml.contains(r#"<transmitter name="tx""#) && xml.contains(r#"<receiver name="rx""#)
+        );
+
+        let reloaded = reload_via_xml(&context);
+        let round_trip = reloaded.get_scenario_as_json().expect("scenario should serialize");
+        assert!(round_trip.contains(r#""name": "tx""#) && round_trip.contains(r#""name": "rx""#));
+
+        let legacy = minimal_scenario_json(STATIC_MOTION, "").replacen(
+            r#""components": []"#,
+            r#""component": {"receiver": {"name": "rx", "cw_mode": {}, "antenna": "default",
+                "timing": "default"}}"#,
+            1,
+        );
+        context.update_scenario_from_json(&legacy).expect("legacy scenario should load");
+        let upgraded = context.get_scenario_as_json().expect("scenario should serialize");
+        assert!(upgraded.contains(r#""components""#) && upgraded.contains(r#""name": "rx""#));
+        assert!(!upgraded.contains(r#""component":"#));
+    }
+
+    /// Verifies that a scenario thumbnail is written as a PNG with the requested
+    /// dimensions and that the platform tracks are actually drawn.
+    #[test]
+    fn scenario_thumbnail_has_requested_dimensions() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            r#"{"interpolation": "linear", "positionwaypoints": [
+                {"time": 0.0, "x": -500.0, "y": 0.0, "altitude": 100.0},
+                {"time": 1.0, "x": 500.0, "y": 200.0, "altitude": 100.0}]}"#,
+            r#"{"monostatic": {"name": "radar", "cw_mode": {},
+                "waveform": "default", "antenna": "default", "timing": "default"}}"#,
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let tracks = context.get_platform_tracks(super::THUMBNAIL_TRACK_POINTS).expect("tracks");
+        assert_eq!(tracks.len(), 1);
+        assert_eq!(tracks[0].kind, fers_api::TrackKind::Monostatic);
+
+        let path = TempFile::new("png");
+        let path_str = path.to_str().unwrap();
+        super::thumbnail::write_track_thumbnail(&tracks, 64, 48, path_str)
+            .expect("thumbnail should be written");
+
+        let decoder = png::Decoder::new(std::fs::File::open(&path).expect("thumbnail exists"));
+        let mut reader = decoder.read_info().expect("thumbnail should be a valid PNG");
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).expect("thumbnail should decode");
+
+        assert_eq!((info.width, info.height), (64, 48));
+        let background = [0x1e, 0x1e, 0x1e];
+        assert!(pixels[..info.buffer_size()].chunks(3).any(|px| px != background));
+    }
+
+    /// Verifies that a uniformly tapered linear array produces the expected sinc-like
+    /// array factor in azimuth, with its first null at asin(lambda / (N d)).
+    #[test]
+    fn uniform_array_taper_places_first_null() {
+        const ELEMENTS: f64 = 8.0;
+        const SPACING: f64 = 0.15;
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(STATIC_MOTION, "").replacen(
+            r#""antennas": ["#,
+            r#""antennas": [{"name": "array", "pattern": "array", "elements": 8, "spacing": 0.15,
+                "taper": [1, 1, 1, 1, 1, 1, 1, 1]}, "#,
+            1,
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+        let pattern =
+            context.get_antenna_pattern("array", 3601, 181, 1e9).expect("pattern should sample");
+
+        let wavelength = 299_792_458.0 / 1e9;
+        assert!((pattern.max_gain - ELEMENTS).abs() < 1e-6);
+        // Row 90 is zero elevation; columns step 0.1 degrees from -180 degrees.
+        let azimuth_gain = |col: usize| pattern.gains[90 * pattern.az_count + col];
+        let expected = |azimuth_deg: f64| {
+            let psi =
+                2.0 * std::f64::consts::PI * SPACING / wavelength * azimuth_deg.to_radians().sin();
+            if psi.abs() < 1e-12 {
+                return 1.0;
+            }
+            ((ELEMENTS * psi / 2.0).sin() / (ELEMENTS * (psi / 2.0).sin())).powi(2)
+        };
+        for col in (1800..2000).step_by(7) {
+            let azimuth_deg = col as f64 / 10.0 - 180.0;
+            assert!((azimuth_gain(col) - expected(azimuth_deg)).abs() < 1e-6);
+        }
+
+        let null_col = (1800..2000)
+            .min_by(|&a, &b| azimuth_gain(a).total_cmp(&azimuth_gain(b)))
+            .expect("azimuth range should not be empty");
+        let null_deg = null_col as f64 / 10.0 - 180.0;
+        let predicted_deg = (wavelength / (ELEMENTS * SPACING)).asin().to_degrees();
+        assert!((null_deg - predicted_deg).abs() <= 0.1);
+    }
+
+    /// Verifies that ECEF motion waypoints are converted into the local ENU frame of the
+    /// scenario origin on load, and written back as the same ECEF values on XML export.
+    #[test]
+    fn ecef_waypoints_map_to_local_frame_and_round_trip() {
+        // At latitude 0 and longitude 0, ENU east/north/up align with ECEF y/z/x.
+        const EQUATORIAL_RADIUS: f64 = 6_378_137.0;
+        let xml = format!(
+            r#"<?xml version="1.0" ?>
+<simulation name="ecef">
+    <parameters>
+        <starttime>0</starttime><endtime>1</endtime><rate>1000</rate>
+        <origin latitude="0" longitude="0" altitude="0"/>
+    </parameters>
+    <platform name="track">
+        <motionpath interpolation="linear" coordinates="ecef">
+            <positionwaypoint>
+                <x>{origin}</x><y>0</y><altitude>0</altitude><time>0</time>
+            </positionwaypoint>
+            <positionwaypoint>
+                <x>{offset}</x><y>250</y><altitude>-40</altitude><time>1</time>
+            </positionwaypoint>
+        </motionpath>
+        <fixedrotation>
+            <startazimuth>0</startazimuth><startelevation>0</startelevation>
+            <azimuthrate>0</azimuthrate><elevationrate>0</elevationrate>
+        </fixedrotation>
+    </platform>
+</simulation>
+"#,
+            origin = EQUATORIAL_RADIUS,
+            offset = EQUATORIAL_RADIUS + 100.0
+        );
+        let context = load_xml(&xml).expect("ECEF scenario should load");
+
+        let local_ends = |context: &fers_api::FersContext| {
+            let tracks = context.get_platform_tracks(2).expect("tracks should sample");
+            let points = &tracks[0].points;
+            [(points[0].x, points[0].y, points[0].z), (points[1].x, points[1].y, points[1].z)]
+        };
+        let close = |a: (f64, f64, f64), b: (f64, f64, f64)| {
+            (a.0 - b.0).abs() < 1e-6 && (a.1 - b.1).abs() < 1e-6 && (a.2 - b.2).abs() < 1e-6
+        };
+        let [start, end] = local_ends(&context);
+        assert!(close(start, (0.0, 0.0, 0.0)));
+        assert!(close(end, (250.0, -40.0, 100.0)));
+
+        let exported = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(exported.contains(r#"coordinates="ecef""#));
+        let reloaded = reload_via_xml(&context);
+        let [start, end] = local_ends(&reloaded);
+        assert!(close(start, (0.0, 0.0, 0.0)));
+        assert!(close(end, (250.0, -40.0, 100.0)));
+    }
+
+    /// Verifies the SAR resolution of a stripmap pass: a radar flying 100 m past a target
+    /// at 1 km broadside, with a 10 GHz carrier and a 100 MHz pulse bandwidth.
+    #[test]
+    fn stripmap_sar_resolution_matches_hand_calculation() {
+        let (_waveform, pulse) = pulse_waveform_json("pulse", 1e8, 1e10);
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            r#"{"interpolation": "linear", "positionwaypoints": [
+                {"time": 0.0, "x": -50.0, "y": 0.0, "altitude": 0.0},
+                {"time": 1.0, "x": 50.0, "y": 0.0, "altitude": 0.0}]}"#,
+            r#"{"monostatic": {"name": "radar", "pulsed_mode": {"prf": 10.0},
+                "waveform": "pulse", "antenna": "default", "timing": "default"}}"#,
+        )
+        .replacen(
+            r#""platforms": ["#,
+            r#""platforms": [{"name": "scene",
+                "motionpath": {"interpolation": "static", "positionwaypoints": [
+                    {"time": 0.0, "x": 0.0, "y": 1000.0, "altitude": 0.0}]},
+                "fixedrotation": {"startazimuth": 0.0, "startelevation": 0.0,
+                    "azimuthrate": 0.0, "elevationrate": 0.0},
+                "components": [{"target": {"name": "scatterer",
+                    "rcs": {"type": "isotropic", "value": 1.0}}}]}, "#,
+            1,
+        );
+        let json = with_waveforms(&json, &pulse);
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let resolution = context
+            .calculate_sar_resolution("radar", "scatterer", "radar")
+            .expect("SAR resolution should compute");
+
+        let c = 299_792_458.0;
+        let lambda = c / 1e10;
+        assert!((resolution.synthetic_aperture - 100.0).abs() < 1e-6);
+        assert!((resolution.slant_range - 1000.0).abs() < 1e-6);
+        assert!((resolution.cross_range_resolution - lambda * 1000.0 / 200.0).abs() < 1e-9);
+        assert!((resolution.range_resolution - c / 2e8).abs() < 1e-9);
+    }
+
+    /// Verifies that the `nodirect` and `nopropagationloss` flags of monostatic and
+    /// standalone receivers survive an XML export and reload, independently of each other
+    /// and of the other component, and default to false when the attributes are absent.
+    #[test]
+    fn monostatic_receive_flags_round_trip_through_xml() {
+        let reload = |xml: &str| {
+            let reloaded = load_xml(xml).expect("exported XML should load");
+            reloaded.get_scenario_as_json().expect("scenario should serialize")
+        };
+
+        let mut xml = String::new();
+        for (nodirect, nopropagationloss) in [(true, false), (false, true), (true, true)] {
+            // The listener's receiver takes the opposite flags to the monostatic
+            let json = minimal_scenario_json(
+                STATIC_MOTION,
+                &format!(
+                    r#"{{"monostatic": {{"name": "radar", "cw_mode": {{}},
+                        "nodirect": {nodirect}, "nopropagationloss": {nopropagationloss},
+                        "waveform": "default", "antenna": "default", "timing": "default"}}}}"#
+                ),
+            )
+            .replacen(
+                r#""platforms": ["#,
+                &format!(
+                    r#""platforms": [{{"name": "listener",
+                        "motionpath": {{"interpolation": "static", "positionwaypoints": [
+                            {{"time": 0.0, "x": 1000.0, "y": 0.0, "altitude": 0.0}}]}},
+                        "fixedrotation": {{"startazimuth": 0.0, "startelevation": 0.0,
+                            "azimuthrate": 0.0, "elevationrate": 0.0}},
+                        "components": [{{"receiver": {{"name": "rx", "cw_mode": {{}},
+                            "nodirect": {}, "nopropagationloss": {},
+                            "antenna": "default", "timing": "default"}}}}]}}, "#,
+                    !nodirect, !nopropagationloss
+                ),
+                1,
+            );
+            let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+            context.update_scenario_from_json(&json).expect("scenario should load");
+            xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+            let round_trip = reload(&xml);
+
+            // The listener platform is declared first, so its receiver precedes the monostatic
+            let receiver_start =
+                round_trip.find(r#""receiver""#).expect("receiver should round-trip");
+            let monostatic_start =
+                round_trip.find(r#""monostatic""#).expect("monostatic should round-trip");
+            assert!(receiver_start < monostatic_start);
+            let monostatic = &round_trip[monostatic_start..];
+            assert!(monostatic.contains(&format!(r#""nodirect": {nodirect}"#)));
+            assert!(monostatic.contains(&format!(r#""nopropagationloss": {nopropagationloss}"#)));
+            let receiver = &round_trip[receiver_start..monostatic_start];
+            assert!(receiver.contains(&format!(r#""nodirect": {}"#, !nodirect)));
+            assert!(receiver.contains(&format!(r#""nopropagationloss": {}"#, !nopropagationloss)));
+        }
+
+        let stripped = ["nodirect", "nopropagationloss"]
+            .iter()
+            .flat_map(|flag| ["true", "false"].map(|value| format!(r#" {flag}="{value}""#)))
+            .fold(xml, |xml, attribute| xml.replace(&attribute, ""));
+        assert!(!stripped.contains("nodirect") && !stripped.contains("nopropagationloss"));
+        let defaults = reload(&stripped);
+        assert!(!defaults.contains(r#""nodirect": true"#));
+        assert!(!defaults.contains(r#""nopropagationloss": true"#));
+        assert_eq!(defaults.matches(r#""nodirect": false"#).count(), 2);
+    }
+
+    /// Verifies the eclipsed ranges of a monostatic radar with a 4 ms pulse, a 10 Hz PRF and
+    /// a 50 ms receive window opened with each pulse: the pulse eclipses the first 4 ms of
+    /// delay, and the closed window the second half of the PRI.
+    #[test]
+    fn blind_ranges_start_at_zero_and_cover_closed_window() {
+        let (_waveform, pulse) = pulse_waveform_json("pulse", 1e3, 1e9);
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"monostatic": {"name": "radar",
+                "pulsed_mode": {"prf": 10.0, "window_skip": 0.0, "window_length": 0.05},
+                "waveform": "pulse", "antenna": "default", "timing": "default"}}"#,
+        );
+        let json = with_waveforms(&json, &pulse);
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let blind =
+            context.calculate_blind_ranges("radar", "radar").expect("blind ranges should compute");
+
+        let half_c = 299_792_458.0 / 2.0;
+        let expected = [(0.0, 0.004 * half_c), (0.05 * half_c, 0.1 * half_c)];
+        assert_eq!(blind.len(), expected.len());
+        for ((start, end), (expected_start, expected_end)) in blind.iter().zip(expected) {
+            assert!((start - expected_start).abs() < 1e-3);
+            assert!((end - expected_end).abs() < 1e-3);
+        }
+    }
+
+    /// Verifies that a target's micro-motion survives an XML export and reload, and that a
+    /// target without one does not gain a `micromotion` entry.
+    #[test]
+    fn target_micro_motion_round_trips_through_xml() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"target": {"name": "rotor", "rcs": {"type": "isotropic", "value": 1.0},
+                "micromotion": {"type": "rotation", "amplitude": 0.5, "frequency": 20.0,
+                    "axis": {"x": 0.0, "y": 0.0, "z": 1.0}}}},
+               {"target": {"name": "hull", "rcs": {"type": "isotropic", "value": 1.0}}}"#,
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(xml.contains(r#"<micromotion type="rotation">"#));
+        assert_eq!(xml.matches("<micromotion").count(), 1);
+        let reloaded = reload_via_xml(&context);
+
+        let round_trip = reloaded.get_scenario_as_json().expect("scenario should serialize");
+        assert_eq!(round_trip.matches(r#""micromotion""#).count(), 1);
+        assert!(round_trip.contains(r#""type": "rotation""#));
+        assert!(round_trip.contains(r#""amplitude": 0.5"#));
+        assert!(round_trip.contains(r#""frequency": 20.0"#));
+    }
+
+    /// Runs a CW monostatic radar viewing a target 1 km away and returns the path of the
+    /// receiver's HDF5 output, which is written to `output`.
+    fn simulate_cw_output(
+        output: &TempDir,
+        receiver: &str,
+        rcs: f64,
+        end_time: f64,
+    ) -> std::path::PathBuf {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            &format!(
+                r#"{{"monostatic": {{"name": "{receiver}", "cw_mode": {{}},
+                    "waveform": "default", "antenna": "default", "timing": "default"}}}}"#
+            ),
+        )
+        .replacen(r#""endtime": 1.0"#, &format!(r#""endtime": {end_time:?}"#), 1)
+        .replacen(
+            r#""platforms": ["#,
+            &format!(
+                r#""platforms": [{{"name": "scene",
+                    "motionpath": {{"interpolation": "static", "positionwaypoints": [
+                        {{"time": 0.0, "x": 0.0, "y": 1000.0, "altitude": 0.0}}]}},
+                    "fixedrotation": {{"startazimuth": 0.0, "startelevation": 0.0,
+                        "azimuthrate": 0.0, "elevationrate": 0.0}},
+                    "components": [{{"target": {{"name": "scatterer",
+                        "rcs": {{"type": "isotropic", "value": {rcs:?}}}}}}}]}}, "#
+            ),
+            1,
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+        context
+            .run_simulation(None, Some(&output.output()), &AtomicBool::new(false))
+            .expect("simulation should run");
+        output.0.join(format!("{receiver}_results.h5"))
+    }
+
+    /// Verifies that an output file matches itself exactly, that a copy with a stronger
+    /// target is reported as different, and that files with different windows are rejected.
+    #[test]
+    fn compare_output_reports_sample_differences() {
+        let output = TempDir::new();
+        let reference = simulate_cw_output(&output, "reference", 1.0, 1.0);
+        let perturbed = simulate_cw_output(&output, "perturbed", 4.0, 1.0);
+        let truncated = simulate_cw_output(&output, "truncated", 1.0, 0.5);
+        let path = |p: &std::path::PathBuf| p.to_str().unwrap().to_owned();
+
+        let identical = fers_api::compare_output(&path(&reference), &path(&reference), 0.0);
+        let different = fers_api::compare_output(&path(&perturbed), &path(&reference), 0.0);
+        let mismatched = fers_api::compare_output(&path(&truncated), &path(&reference), 1.0);
+
+        let identical = identical.expect("identical files should compare");
+        assert!(identical.sample_count > 0);
+        assert_eq!(identical.max_difference, 0.0);
+        assert_eq!(identical.rms_difference, 0.0);
+        assert!(identical.within_tolerance);
+
+        let different = different.expect("perturbed file should compare");
+        assert_eq!(different.sample_count, identical.sample_count);
+        assert!(different.max_difference > 0.0);
+        assert!(different.rms_difference > 0.0);
+        assert!(different.rms_difference <= different.max_difference);
+        assert!(!different.within_tolerance);
+
+        let error = mismatched.expect_err("files with different windows should not compare");
+        assert!(error.contains("samples"), "unexpected error: {error}");
+    }
+
+    /// Verifies that a ballistic target launched at 100 m/s east and 50 m/s up under
+    /// 10 m/s^2 gravity reaches its 125 m apex at 5 s, lands 1000 m downrange at 10 s and
+    /// stays there, both as loaded and after an XML round trip.
+    #[test]
+    fn ballistic_path_matches_projectile_solution() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            r#"{"interpolation": "ballistic",
+                "positionwaypoints": [{"time": 0.0, "x": 0.0, "y": 0.0, "altitude": 0.0}],
+                "launchvelocity": {"x": 100.0, "y": 0.0, "z": 50.0}, "gravity": 10.0}"#,
+            r#"{"target": {"name": "projectile", "rcs": {"type": "isotropic", "value": 1.0}}}"#,
+        )
+        .replacen(r#""endtime": 1.0"#, r#""endtime": 12.0"#, 1);
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let check = |context: &fers_api::FersContext| {
+            let tracks = context.get_platform_tracks(13).expect("tracks should sample");
+            let points = &tracks[0].points;
+            let at = |i: usize| (points[i].x, points[i].y, points[i].z);
+            let close = |a: (f64, f64, f64), b: (f64, f64, f64)| {
+                (a.0 - b.0).abs() < 1e-6 && (a.1 - b.1).abs() < 1e-6 && (a.2 - b.2).abs() < 1e-6
+            };
+            assert!(close(at(5), (500.0, 0.0, 125.0)), "apex was {:?}", at(5));
+            assert!(points[5].vz.abs() < 1e-6);
+            assert!(close(at(10), (1000.0, 0.0, 0.0)), "impact was {:?}", at(10));
+            assert!(close(at(12), (1000.0, 0.0, 0.0)));
+            assert_eq!((points[12].vx, points[12].vz), (0.0, 0.0));
+        };
+        check(&context);
+
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(xml.contains(r#"interpolation="ballistic""#));
+        let reloaded = reload_via_xml(&context);
+        check(&reloaded);
+    }
+
+    /// Checks the detection probabilities against published single-pulse values for
+    /// Pfa = 1e-6: Pd = 0.9 requires 13.2 dB SNR for a steady target and 21.2 dB for a
+    /// Swerling 1 target.
+    #[test]
+    fn detection_probabilities_match_published_values() {
+        let threshold_db = 10.0 * (1e6_f64).ln().log10();
+        let detect = |snr_db: f64, n: usize, swerling: &str| {
+            super::compute_detection_probabilities(snr_db, threshold_db, n, swerling.to_owned())
+                .expect("probabilities should compute")
+        };
+
+        let (pd, pfa) = detect(13.2, 1, "0");
+        assert!((pfa - 1e-6).abs() < 1e-12);
+        assert!((pd - 0.90).abs() < 0.005, "Swerling 0 Pd was {pd}");
+
+        let (pd, _) = detect(21.2, 1, "Swerling 1");
+        assert!((pd - 0.90).abs() < 0.005, "Swerling 1 Pd was {pd}");
+        // With a single pulse, scan-to-scan and pulse-to-pulse fluctuation coincide.
+        let (pd_2, _) = detect(21.2, 1, "swerling2");
+        assert!((pd - pd_2).abs() < 1e-12);
+
+        // The integrated noise is Gamma(N, 1): Pfa = e^-T * sum_{k<N} T^k / k!.
+        let threshold = (1e6_f64).ln();
+        let expected_pfa: f64 = (0..10)
+            .scan(1.0, |term, k| {
+                let current = *term;
+                *term *= threshold / f64::from(k + 1);
+                Some(current)
+            })
+            .sum::<f64>()
+            * (-threshold).exp();
+        let (pd_10, pfa_10) = detect(13.2, 10, "0");
+        assert!((pfa_10 - expected_pfa).abs() < 1e-12 * expected_pfa.max(1.0));
+        assert!(pd_10 > pd);
+
+        let err = super::compute_detection_probabilities(13.2, threshold_db, 1, "5".to_owned())
+            .expect_err("unknown Swerling case should be rejected");
+        assert!(err.contains("Swerling"));
+    }
+
+    /// Checks the compression gain of a 10 MHz x 20 us chirp against `10 log10(200)`, and
+    /// that unmodulated pulses are rejected.
+    #[test]
+    fn pulse_compression_gain_is_time_bandwidth_product() {
+        let chirp = super::Pulse::Lfm { bandwidth: 10e6, duration: 20e-6 };
+        let gain = super::compute_pulse_compression_gain(chirp).expect("gain should compute");
+        assert!((gain - 10.0 * 200.0_f64.log10()).abs() < 1e-9, "gain was {gain} dB");
+        assert!((gain - 23.0103).abs() < 1e-4);
+
+        let plain = super::Pulse::Unmodulated { duration: 20e-6 };
+        let err = super::compute_pulse_compression_gain(plain)
+            .expect_err("an unmodulated pulse should be rejected");
+        assert!(err.contains("LFM"));
+    }
+
+    /// Checks the spectrum of a 1 us rectangular pulse against `sinc^2(f tau)`: a 0 dB peak on
+    /// the carrier, nulls at multiples of `1 / tau` and sidelobes at the `sinc^2` level.
+    #[test]
+    fn rectangular_pulse_spectrum_is_sinc_squared() {
+        let tau = 1e-6;
+        let pulse = super::Pulse::Unmodulated { duration: tau };
+        let spectrum =
+            super::compute_waveform_spectrum(pulse, 1024).expect("spectrum should compute");
+        assert_eq!(spectrum.len(), 1024);
+
+        // The 16 MHz rate puts 64 bins between nulls, with the carrier at bin 512
+        let spacing = spectrum[1].0 - spectrum[0].0;
+        assert!((spacing - 16e6 / 1024.0).abs() < 1e-6);
+        assert_eq!(spectrum[512], (0.0, Some(0.0)));
+        for k in [1, 2, 3] {
+            assert!((spectrum[512 + 64 * k].0 * tau - k as f64).abs() < 1e-9);
+            assert!(spectrum[512 + 64 * k].1.is_none_or(|db| db < -100.0));
+            assert!(spectrum[512 - 64 * k].1.is_none_or(|db| db < -100.0));
+        }
+        for x in [0.5_f64, 1.5, 2.5] {
+            let sinc = (std::f64::consts::PI * x).sin() / (std::f64::consts::PI * x);
+            let expected = 10.0 * (sinc * sinc).log10();
+            let bin = 512 + (64.0 * x) as usize;
+            let db = spectrum[bin].1.expect("a sidelobe is not a null");
+            assert!((db - expected).abs() < 0.5, "{x}/tau: {db}");
+        }
+
+        let pulse = super::Pulse::Unmodulated { duration: tau };
+        assert!(super::compute_waveform_spectrum(pulse, 1000).is_err());
+        let missing = super::Pulse::File { filename: "missing_pulse.csv".to_owned() };
+        assert!(super::compute_waveform_spectrum(missing, 1024).is_err());
+    }
+
+    /// Checks an azimuth cut of a Gaussian antenna against `exp(-az^2 * azscale)`, and that
+    /// a file pattern whose file is missing is rejected.
+    #[test]
+    fn antenna_cut_matches_gaussian_pattern() {
+        let gaussian = super::Antenna {
+            name: None,
+            efficiency: None,
+            design_frequency: None,
+            pattern: super::AntennaPattern::Gaussian { azscale: 2.0, elscale: 1.0 },
+        };
+        let gains: Vec<f64> = super::sample_antenna_pattern(gaussian, (-30.0, 30.0), 0.0, 3)
+            .expect("cut should sample")
+            .into_iter()
+            .map(|gain| gain.expect("a Gaussian beam has no nulls"))
+            .collect();
+        let edge = 10.0 * (-2.0 * std::f64::consts::FRAC_PI_6.powi(2)).exp().log10();
+        assert_eq!(gains.len(), 3);
+        assert!(gains[1].abs() < 1e-9, "boresight gain was {} dB", gains[1]);
+        assert!((gains[0] - edge).abs() < 1e-9 && (gains[2] - edge).abs() < 1e-9);
+
+        let missing = super::Antenna {
+            name: None,
+            efficiency: None,
+            design_frequency: None,
+            pattern: super::AntennaPattern::File {
+                filename: "/nonexistent/fers_missing_pattern.pat".to_string(),
+            },
+        };
+        assert!(super::sample_antenna_pattern(missing, (-30.0, 30.0), 0.0, 3).is_err());
+    }
+
+    /// Encodes a DIS Entity State PDU at `seconds` past the hour for the given entity,
+    /// marking and geocentric location.
+    fn entity_state_pdu(seconds: f64, entity: [u16; 3], marking: &str, ecef: [f64; 3]) -> Vec<u8> {
+        let mut pdu = vec![0u8; 144];
+        pdu[0] = 7; // Protocol version (IEEE 1278.1-2012)
+        pdu[2] = 1; // Entity State
+        pdu[3] = 1; // Entity information/interaction family
+        let timestamp = (((seconds * 2f64.powi(31) / 3600.0) as u32) << 1) | 1;
+        pdu[4..8].copy_from_slice(&timestamp.to_be_bytes());
+        pdu[8..10].copy_from_slice(&144u16.to_be_bytes());
+        for (i, id) in entity.iter().enumerate() {
+            pdu[12 + 2 * i..14 + 2 * i].copy_from_slice(&id.to_be_bytes());
+        }
+        for (i, coordinate) in ecef.iter().enumerate() {
+            pdu[48 + 8 * i..56 + 8 * i].copy_from_slice(&coordinate.to_be_bytes());
+        }
+        pdu[128] = 1; // ASCII marking
+        pdu[129..129 + marking.len()].copy_from_slice(marking.as_bytes());
+        pdu
+    }
+
+    /// Verifies that a PDU log with two entities (one updated twice, one unnamed) and an
+    /// unrelated PDU imports two platforms whose geocentric positions are mapped into the
+    /// ENU frame of the equatorial scenario origin.
+    #[test]
+    fn dis_entity_states_import_as_platforms() {
+        // At latitude 0, longitude 0 the local East, North and Up axes are ECEF y, z and x.
+        const EQUATORIAL_RADIUS: f64 = 6_378_137.0;
+        let mut log = entity_state_pdu(0.0, [1, 2, 7], "TANK1", [EQUATORIAL_RADIUS, 0.0, 0.0]);
+        let mut fire_pdu = vec![0u8; 96];
+        fire_pdu[2] = 2; // Fire
+        fire_pdu[8..10].copy_from_slice(&96u16.to_be_bytes());
+        log.extend(fire_pdu);
+        log.extend(entity_state_pdu(0.5, [1, 2, 3], "", [EQUATORIAL_RADIUS + 50.0, -20.0, 30.0]));
+        log.extend(entity_state_pdu(
+            10.0,
+            [1, 2, 7],
+            "TANK1",
+            [EQUATORIAL_RADIUS + 100.0, 200.0, 300.0],
+        ));
+        let path = TempFile::new("pdu");
+        std::fs::write(&path, &log).expect("PDU log should be writable");
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context
+            .update_scenario_from_json(&minimal_scenario_json(STATIC_MOTION, ""))
+            .expect("scenario should load");
+        let imported = context.import_dis_entity_states(path.to_str().unwrap());
+        let reimported = context.import_dis_entity_states(path.to_str().unwrap());
+        assert_eq!(imported.expect("PDU log should import"), 2);
+        assert!(reimported.is_err(), "duplicate platform names should be rejected");
+
+        let json = context.get_scenario_as_json().expect("scenario should serialize");
+        assert!(json.contains(r#""name": "TANK1""#));
+        assert!(json.contains(r#""name": "DIS 1:2:3""#));
+
+        // Samples at t = 0 and t = 1 s: the tank covers a tenth of its 10 s leg.
+        let tracks = context.get_platform_tracks(2).expect("tracks should sample");
+        assert_eq!(tracks.len(), 3);
+        let close = |p: &super::InterpolatedMotionPoint, x: f64, y: f64, z: f64| {
+            (p.x - x).abs() < 1e-3 && (p.y - y).abs() < 1e-3 && (p.z - z).abs() < 1e-3
+        };
+        assert!(close(&tracks[1].points[0], 0.0, 0.0, 0.0));
+        assert!(close(&tracks[1].points[1], 20.0, 30.0, 10.0));
+        assert!(close(&tracks[2].points[1], -20.0, 30.0, 50.0));
+    }
+
+    /// Verifies that the TDM schedule for three transmitters never overlaps, keeps the
+    /// guard time between slots and serves every transmitter at least at its PRF.
+    #[test]
+    fn tdm_schedule_slots_do_not_overlap() {
+        let (_waveform, pulse) = pulse_waveform_json("pulse", 1e4, 1e9);
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let emitter = |name: &str, prf: f64| {
+            format!(
+                r#"{{"transmitter": {{"name": "{name}", "pulsed_mode": {{"prf": {prf:.1}}},
+                    "waveform": "pulse", "antenna": "default", "timing": "default"}}}}"#
+            )
+        };
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            &format!(
+                "{}, {}, {}",
+                emitter("tx-a", 100.0),
+                emitter("tx-b", 50.0),
+                emitter("tx-c", 200.0)
+            ),
+        );
+        let json = with_waveforms(&json, &pulse);
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let guard = 1e-3;
+        let slots = context.calculate_tdm_schedule(guard).expect("schedule should compute");
+        assert!(!slots.is_empty());
+        for (_, start, end) in &slots {
+            assert!((end - start - 4e-4).abs() < 1e-9);
+            assert!(*start >= 0.0 && *end <= 1.0);
+        }
+        for pair in slots.windows(2) {
+            assert!(pair[1].1 >= pair[0].2 + guard - 1e-9, "slots overlap: {pair:?}");
+        }
+        for (name, pri) in [("tx-a", 0.01), ("tx-b", 0.02), ("tx-c", 0.005)] {
+            let starts: Vec<f64> =
+                slots.iter().filter(|(n, _, _)| n == name).map(|&(_, start, _)| start).collect();
+            assert!(starts.len() >= 199, "{name} has {} slots", starts.len());
+            assert!(starts.windows(2).all(|w| w[1] - w[0] <= pri + 1e-9));
+        }
+
+        // Three 0.4 ms pulses with 2 ms guards no longer fit in the 5 ms frame
+        assert!(context.calculate_tdm_schedule(2e-3).is_err());
+    }
+
+    /// Writes a calibration table to a temporary file that is removed when dropped.
+    fn write_calibration_file(contents: &str) -> TempFile {
+        let file = TempFile::new("csv");
+        std::fs::write(&file, contents).expect("calibration file should be written");
+        file
+    }
+
+    /// Builds a scenario with a CW receiver that references the given calibration file.
+    fn calibrated_receiver_json(path: &std::path::Path) -> String {
+        let filename = path.to_string_lossy().replace('\\', "/");
+        minimal_scenario_json(
+            STATIC_MOTION,
+            &format!(
+                r#"{{"receiver": {{"name": "rx", "cw_mode": {{}}, "antenna": "default",
+                    "timing": "default", "calibration_file": "{filename}"}}}}"#
+            ),
+        )
+    }
+
+    /// Verifies that a receiver's calibration file reference survives an XML round trip.
+    #[test]
+    fn receiver_calibration_file_round_trips_through_xml() {
+        let table = write_calibration_file("frequency,gain\n0.9e9,-1.0\n1.1e9,-3.0\n");
+        let filename = table.to_string_lossy().replace('\\', "/");
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context
+            .update_scenario_from_json(&calibrated_receiver_json(&table))
+            .expect("scenario should load");
+
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(xml.contains(&format!(r#"<calibration filename="{filename}"/>"#)));
+        let reloaded = load_xml(&xml).expect("exported XML should load");
+
+        let round_trip = reloaded.get_scenario_as_json().expect("scenario should serialize");
+        assert!(round_trip.contains(&format!(r#""calibration_file": "{filename}""#)));
+    }
+
+    /// Verifies that calibration tables accept comments and a header row, and that rows
+    /// which are malformed or not increasing in frequency are rejected.
+    #[test]
+    fn receiver_calibration_table_format_is_validated() {
+        let load = |contents: &str| {
+            let table = write_calibration_file(contents);
+            let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+            context.update_scenario_from_json(&calibrated_receiver_json(&table))
+        };
+
+        load(
+            "# Measured 2025-01-01\nfrequency_hz,gain_db\n\n0.9e9, -1.5\n1.0e9,-2.0\n1.1e9 ,-2.5\n",
+        )
+        .expect("a well-formed table should load");
+
+        let error = load("1.0e9,-2.0\n0.9e9,-1.5\n")
+            .expect_err("decreasing frequencies should be rejected");
+        assert!(error.contains("strictly increasing"), "unexpected error: {error}");
+
+        let error =
+            load("1.0e9,-2.0\n1.0e9,-1.5\n").expect_err("repeated frequencies should be rejected");
+        assert!(error.contains("strictly increasing"), "unexpected error: {error}");
+
+        let error = load("1.0e9;-2.0\n").expect_err("rows without a comma should be rejected");
+        assert!(error.contains("line 1"), "unexpected error: {error}");
+
+        assert!(load("# no entries\n").is_err());
+    }
+
+    /// Verifies that staggering between 1000 Hz and 1200 Hz recovers the first blind
+    /// speed of the 1000 Hz PRF, leaving the first common blind speed at 6 kHz Doppler.
+    #[test]
+    fn staggered_prfs_recover_single_prf_blind_speed() {
+        let (_waveform, pulse) = pulse_waveform_json("pulse", 1e6, 1e9);
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"monostatic": {"name": "radar",
+                "pulsed_mode": {"prf": 1000.0, "window_skip": 0.0, "window_length": 0.0005},
+                "waveform": "pulse", "antenna": "default", "timing": "default"}}"#,
+        );
+        let json = with_waveforms(&json, &pulse);
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let half_lambda = 299_792_458.0 / 1e9 / 2.0;
+        let first_blind_speed = half_lambda * 1000.0;
+        let near = |speeds: &[f64], speed: f64| speeds.iter().any(|v| (v - speed).abs() < 1e-6);
+
+        let single = context
+            .calculate_blind_zone_coverage("radar", "radar", &[1000.0])
+            .expect("coverage should compute");
+        assert!(near(&single.unrecoverable_speeds, first_blind_speed));
+        assert!(single.recoverable_speeds.is_empty());
+        assert!(single.recoverable_ranges.is_empty());
+
+        let staggered = context
+            .calculate_blind_zone_coverage("radar", "radar", &[1000.0, 1200.0])
+            .expect("coverage should compute");
+        assert_eq!(staggered.prfs.len(), 2);
+        assert!(near(&staggered.prfs[0].blind_speeds, first_blind_speed));
+        assert!(!near(&staggered.unrecoverable_speeds, first_blind_speed));
+        assert!(near(&staggered.recoverable_speeds, first_blind_speed));
+        assert_eq!(staggered.unrecoverable_speeds.len(), 1);
+        assert!((staggered.unrecoverable_speeds[0] - half_lambda * 6000.0).abs() < 1e-6);
+        assert!((staggered.max_speed - half_lambda * 6000.0).abs() < 1e-6);
+
+        // Transmit eclipsing near zero range cannot be recovered by any PRF
+        let half_c = 299_792_458.0 / 2.0;
+        assert!((staggered.max_range - half_c / 1000.0).abs() < 1e-3);
+        let (start, end) = staggered.unrecoverable_ranges[0];
+        assert_eq!(start, 0.0);
+        assert!(end >= 4e-6 * half_c - 1e-3);
+    }
+
+    /// Verifies that a target's RCS decorrelation time survives an XML round trip and
+    /// that non-positive times are rejected.
+    #[test]
+    fn target_decorrelation_time_round_trips_through_xml() {
+        let target = |decorrelation_time: f64| {
+            minimal_scenario_json(
+                STATIC_MOTION,
+                &format!(
+                    r#"{{"target": {{"name": "aircraft", "rcs": {{"type": "isotropic", "value": 10.0}},
+                        "model": {{"type": "chisquare", "k": 1.0,
+                            "decorrelation_time": {decorrelation_time:?}}}}}}}"#
+                ),
+            )
+        };
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&target(0.25)).expect("scenario should load");
+
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(xml.contains("<decorrelation_time>0.25</decorrelation_time>"));
+        let reloaded = reload_via_xml(&context);
+
+        let round_trip = reloaded.get_scenario_as_json().expect("scenario should serialize");
+        assert!(round_trip.contains(r#""decorrelation_time": 0.25"#));
+
+        let rejected = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        assert!(rejected.update_scenario_from_json(&target(-1.0)).is_err());
+    }
+
+    /// Verifies that an array spaced one wavelength apart has a grating lobe at the
+    /// mirror of a 30 degree scan, and none once the spacing is half a wavelength.
+    #[test]
+    fn wavelength_spaced_array_has_grating_lobe() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"transmitter": {"name": "tx", "cw_mode": {}, "waveform": "default",
+                "antenna": "array", "timing": "default"}}"#,
+        )
+        .replacen(
+            r#""antennas": ["#,
+            r#""antennas": [{"name": "array", "pattern": "array", "elements": 8,
+                "spacing": 0.299792458}, "#,
+            1,
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+        let antenna = |name: Option<&str>, pattern| super::Antenna {
+            name: name.map(str::to_string),
+            efficiency: None,
+            design_frequency: None,
+            pattern,
+        };
+        let array = antenna(
+            Some("array"),
+            super::AntennaPattern::Array { elements: 8, spacing: 0.299792458, taper: Vec::new() },
+        );
+
+        // The carrier comes from the 1 GHz transmitter fitted with the scenario array
+        let report = context.find_grating_lobes(&array, 30.0, None).expect("lobes should compute");
+        assert!((report.spacing_wavelengths - 1.0).abs() < 1e-12);
+        assert!(report.has_grating_lobes);
+        assert_eq!(report.lobe_angles_deg.len(), 1);
+        assert!((report.lobe_angles_deg[0] + 30.0).abs() < 1e-9);
+
+        let broadside =
+            context.find_grating_lobes(&array, 0.0, None).expect("lobes should compute");
+        assert_eq!(broadside.lobe_angles_deg.len(), 2);
+        assert!((broadside.lobe_angles_deg[0] + 90.0).abs() < 1e-6);
+        assert!((broadside.lobe_angles_deg[1] - 90.0).abs() < 1e-6);
+
+        // A definition not yet in the scenario is checked as edited
+        let unsaved = antenna(
+            None,
+            super::AntennaPattern::Array { elements: 8, spacing: 0.15, taper: Vec::new() },
+        );
+        let half_wave =
+            context.find_grating_lobes(&unsaved, 30.0, Some(1e9)).expect("lobes should compute");
+        assert!((half_wave.spacing_wavelengths - 0.15 / 0.299792458).abs() < 1e-12);
+        assert!(!half_wave.has_grating_lobes);
+        assert!(context.find_grating_lobes(&unsaved, 30.0, None).is_err());
+
+        let isotropic = antenna(Some("default"), super::AntennaPattern::Isotropic);
+        assert!(context.find_grating_lobes(&isotropic, 0.0, Some(1e9)).is_err());
+    }
+
+    /// Verifies that validating an XML file reports its outcome without replacing or
+    /// altering the scenario already loaded in a context.
+    #[test]
+    fn validate_xml_file_leaves_loaded_scenario_unchanged() {
+        let other = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let other_json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"target": {"name": "aircraft", "rcs": {"type": "isotropic", "value": 10.0}}}"#,
+        )
+        .replace(r#""rate": 1000.0"#, r#""rate": 2000.0"#);
+        other.update_scenario_from_json(&other_json).expect("scenario should load");
+        let valid_path = TempFile::new("xml");
+        std::fs::write(&valid_path, other.get_scenario_as_xml().expect("XML should export"))
+            .expect("XML should be writable");
+        let invalid_path = TempFile::new("xml");
+        std::fs::write(&invalid_path, "<simulation name=\"broken\"><parameters>")
+            .expect("XML should be writable");
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context
+            .update_scenario_from_json(&minimal_scenario_json(STATIC_MOTION, ""))
+            .expect("scenario should load");
+        let before = context.get_scenario_as_json().expect("scenario should serialize");
+
+        let valid = validate_xml_file(valid_path.to_string_lossy().into_owned());
+        let invalid = validate_xml_file(invalid_path.to_string_lossy().into_owned());
+        let missing = validate_xml_file("/nonexistent/fers_scenario.xml".to_string());
+
+        let valid = valid.expect("validation should run");
+        assert!(valid.valid);
+        assert!(valid.errors.is_empty());
+        let invalid = invalid.expect("validation should run");
+        assert!(!invalid.valid);
+        assert_eq!(invalid.errors.len(), 1);
+        assert!(!missing.expect("validation should run").valid);
+
+        let after = context.get_scenario_as_json().expect("scenario should serialize");
+        assert_eq!(before, after);
+        assert!(!after.contains("aircraft"));
+    }
+
+    /// Verifies that the effective aperture of a dish sampled from its pattern matches
+    /// its physical aperture times efficiency, and that a pattern-only antenna has none.
+    #[test]
+    fn dish_effective_aperture_matches_physical_aperture() {
+        let dish = super::Antenna {
+            name: None,
+            efficiency: Some(0.6),
+            design_frequency: None,
+            pattern: super::AntennaPattern::Parabolic { diameter: 2.0 },
+        };
+        let (effective, physical) =
+            super::compute_effective_aperture(dish, 10e9).expect("aperture should compute");
+        let expected = std::f64::consts::PI * 2.0 * 2.0 / 4.0 * 0.6;
+        let physical = physical.expect("a dish has a physical aperture");
+        assert!((physical - expected).abs() < 1e-9);
+        assert!((effective - physical).abs() < 1e-6 * physical);
+
+        let isotropic = super::Antenna {
+            name: None,
+            efficiency: None,
+            design_frequency: None,
+            pattern: super::AntennaPattern::Isotropic,
+        };
+        let (isotropic, none) =
+            super::compute_effective_aperture(isotropic, 1e9).expect("aperture should compute");
+        let wavelength = 299_792_458.0 / 1e9;
+        assert!((isotropic - wavelength * wavelength / (4.0 * std::f64::consts::PI)).abs() < 1e-12);
+        assert!(none.is_none());
+
+        let dish = super::Antenna {
+            name: None,
+            efficiency: None,
+            design_frequency: None,
+            pattern: super::AntennaPattern::Parabolic { diameter: 2.0 },
+        };
+        assert!(super::compute_effective_aperture(dish, 0.0).is_err());
+    }
+
+    /// Verifies that a consumer connected to the output stream receives a well-formed
+    /// frame for the window produced by a CW receiver.
+    #[test]
+    fn streaming_consumer_receives_receiver_window() {
+        use std::io::Read;
+
+        let listener = std::net::TcpListener::bind(("127.0.0.1", 0)).expect("port should bind");
+        let address = listener.local_addr().expect("listener should have an address");
+        let mut consumer = std::net::TcpStream::connect(address).expect("consumer should connect");
+        let (stream, forwarder) =
+            super::streaming::WindowStream::spawn(listener, super::streaming::STREAM_BUFFER_FRAMES)
+                .expect("stream should start");
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"monostatic": {"name": "fers_streamed", "cw_mode": {},
+                "waveform": "default", "antenna": "default", "timing": "default"}}"#,
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+        let output = TempDir::new();
+        context
+            .run_simulation_streaming(
+                None,
+                Some(&output.output()),
+                &|receiver: &str, start: f64, fullscale: f64, samples: &[f64]| {
+                    stream.publish(receiver, start, fullscale, samples);
+                },
+                &AtomicBool::new(false),
+            )
+            .expect("simulation should run");
+        let producer_dropped = stream.finish();
+        let stats = forwarder.join().expect("forwarder should finish");
+        assert!(output.0.join("fers_streamed_results.h5").exists());
+        assert_eq!(producer_dropped, 0);
+        assert!(stats.sent >= 1);
+
+        let mut header = [0u8; 6];
+        consumer.read_exact(&mut header).expect("frame header should arrive");
+        let length = u32::from_le_bytes(header[..4].try_into().unwrap()) as usize;
+        let name_length = u16::from_le_bytes(header[4..6].try_into().unwrap()) as usize;
+        let mut body = vec![0u8; length - 2];
+        consumer.read_exact(&mut body).expect("frame body should arrive");
+        assert_eq!(&body[..name_length], b"fers_streamed");
+        let fields = &body[name_length..];
+        let start_time = f64::from_le_bytes(fields[..8].try_into().unwrap());
+        let sample_count = u32::from_le_bytes(fields[16..20].try_into().unwrap()) as usize;
+        assert_eq!(start_time, 0.0);
+        assert!(sample_count > 0);
+        assert_eq!(fields.len(), 20 + sample_count * 16);
+    }
+
+    /// Verifies that a receiver name longer than a frame can hold is cut at a character
+    /// boundary, so the streamed name stays valid UTF-8.
+    #[test]
+    fn streamed_receiver_name_is_truncated_on_char_boundary() {
+        // Two-byte characters put byte 65535 in the middle of one
+        let receiver = "é".repeat(40_000);
+        let frame = super::streaming::encode_frame(&receiver, 0.0, 1.0, &[1.0, 0.0])
+            .expect("window should encode");
+
+        let length = u32::from_le_bytes(frame[..4].try_into().unwrap()) as usize;
+        let name_length = u16::from_le_bytes(frame[4..6].try_into().unwrap()) as usize;
+        assert_eq!(frame.len(), 4 + length);
+        assert_eq!(name_length, 65_534);
+        let name = std::str::from_utf8(&frame[6..6 + name_length]).expect("name should be UTF-8");
+        assert!(receiver.starts_with(name));
+        assert_eq!(length, 2 + name_length + 20 + 16);
+    }
+
+    /// Verifies that a forward phase-center offset on a north-facing transmitter shortens
+    /// the path to a target due north by the offset, and that the offset survives XML.
+    #[test]
+    fn phase_center_offset_shortens_bistatic_range() {
+        let scenario = |offset: &str| {
+            minimal_scenario_json(
+                STATIC_MOTION,
+                &format!(
+                    r#"{{"transmitter": {{"name": "tx", "cw_mode": {{}}, "waveform": "default",
+                        "antenna": "default", "timing": "default"{offset}}}}},
+                    {{"receiver": {{"name": "rx", "cw_mode": {{}}, "antenna": "default",
+                        "timing": "default"}}}}"#
+                ),
+            )
+            .replacen(
+                r#""platforms": ["#,
+                r#""platforms": [{"name": "scene",
+                    "motionpath": {"interpolation": "static", "positionwaypoints": [
+                        {"time": 0.0, "x": 0.0, "y": 1000.0, "altitude": 0.0}]},
+                    "fixedrotation": {"startazimuth": 0.0, "startelevation": 0.0,
+                        "azimuthrate": 0.0, "elevationrate": 0.0},
+                    "components": [{"target": {"name": "aircraft",
+                        "rcs": {"type": "isotropic", "value": 1.0}}}]}, "#,
+                1,
+            )
+        };
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&scenario("")).expect("scenario should load");
+        let origin_range = context
+            .calculate_phase_center_range("tx", "aircraft", "rx", 0.0)
+            .expect("range should compute");
+        assert!((origin_range - 2000.0).abs() < 1e-9);
+
+        // Body x is boresight, which points north for a compass azimuth of zero
+        context
+            .update_scenario_from_json(&scenario(
+                r#", "phase_center_offset": {"x": 5.0, "y": 0.0, "z": 0.0}"#,
+            ))
+            .expect("scenario should load");
+        let offset_range = context
+            .calculate_phase_center_range("tx", "aircraft", "rx", 0.0)
+            .expect("range should compute");
+        assert!((offset_range - 1995.0).abs() < 1e-9);
+
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(xml.contains("<phase_center_offset"));
+        let reloaded = reload_via_xml(&context);
+        let reloaded_range = reloaded
+            .calculate_phase_center_range("tx", "aircraft", "rx", 0.0)
+            .expect("range should compute");
+        assert!((reloaded_range - 1995.0).abs() < 1e-6);
+    }
+
+    /// Verifies that an RCS file previews as the product grid of its cuts, and that missing,
+    /// malformed and unsupported files are rejected with the reason. A repeated angle is
+    /// rejected by the preview but only warned about when a file target loads it.
+    #[test]
+    fn rcs_file_preview_spans_azimuth_elevation_grid() {
+        let write = |contents: &str| {
+            let file = TempFile::new("xml");
+            std::fs::write(&file, contents).expect("RCS file should be writable");
+            file
+        };
+        let valid = write(
+            r#"<target>
+                <azimuth>
+                    <rcssample><angle>0</angle><rcs>10</rcs></rcssample>
+                    <rcssample><angle>-1.5707963267948966</angle><rcs>2</rcs></rcssample>
+                </azimuth>
+                <elevation>
+                    <rcssample><angle>0</angle><rcs>1</rcs></rcssample>
+                    <rcssample><angle>0.5235987755982988</angle><rcs>0.5</rcs></rcssample>
+                </elevation>
+            </target>"#,
+        );
+        let no_elevation = write("<target><azimuth><rcssample><angle>0</angle><rcs>1</rcs></rcssample></azimuth></target>",
+        );
+        let bad_value = write(
+            r#"<target>
+                <azimuth><rcssample><angle>0</angle><rcs>big</rcs></rcssample></azimuth>
+                <elevation><rcssample><angle>0</angle><rcs>1</rcs></rcssample></elevation>
+            </target>"#,
+        );
+        let not_xml = write("0 10\n90 2\n");
+        let repeated = write(
+            r#"<target>
+                <azimuth>
+                    <rcssample><angle>0</angle><rcs>1</rcs></rcssample>
+                    <rcssample><angle>0</angle><rcs>2</rcs></rcssample>
+                </azimuth>
+                <elevation><rcssample><angle>0</angle><rcs>1</rcs></rcssample></elevation>
+            </target>"#,
+        );
+
+        // A file target still loads a file with repeated angles, keeping the first sample
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let target = context.update_scenario_from_json(&minimal_scenario_json(
+            STATIC_MOTION,
+            &format!(
+                r#"{{"target": {{"name": "aircraft", "rcs": {{"type": "file", "filename": "{}"}}}}}}"#,
+                repeated.to_str().unwrap().replace('\\', "\\\\")
+            ),
+        ));
+
+        let preview = super::load_rcs_file(valid.to_string_lossy().into_owned());
+        let errors: Vec<String> = [&no_elevation, &bad_value, &not_xml, &repeated]
+            .iter()
+            .map(|path| {
+                super::load_rcs_file(path.to_string_lossy().into_owned())
+                    .err()
+                    .expect("invalid RCS file should be rejected")
+            })
+            .collect();
+        let missing = super::load_rcs_file("fers_rcs_missing.xml".to_owned())
+            .err()
+            .expect("missing RCS file should be rejected");
+
+        let preview = preview.expect("RCS file should load");
+        let expected = [(-90.0, 0.0, 2.0), (0.0, 0.0, 10.0), (-90.0, 30.0, 1.0), (0.0, 30.0, 5.0)];
+        assert_eq!(preview.samples.len(), expected.len());
+        for (sample, (azimuth, elevation, value)) in preview.samples.iter().zip(expected) {
+            assert!((sample.azimuth - azimuth).abs() < 1e-9);
+            assert!((sample.elevation - elevation).abs() < 1e-9);
+            assert!((sample.value - value).abs() < 1e-12);
+        }
+        assert_eq!((preview.min, preview.max), (1.0, 10.0));
+
+        assert!(missing.contains("does not exist"), "{missing}");
+        assert!(errors[0].contains("<elevation>"), "{}", errors[0]);
+        assert!(errors[1].contains("non-numeric <rcs> 'big'"), "{}", errors[1]);
+        assert!(errors[2].contains("not a valid XML"), "{}", errors[2]);
+        assert!(errors[3].contains("repeats the angle"), "{}", errors[3]);
+        target.expect("a file target should load despite the repeated angle");
+    }
+
+    #[test]
+    fn file_target_rcs_interpolation_round_trips_through_xml() {
+        let rcs_path = TempFile::new("xml");
+        std::fs::write(
+            &rcs_path,
+            r#"<target>
+                <elevation><rcssample><angle>0</angle><rcs>1</rcs></rcssample></elevation>
+                <azimuth>
+                    <rcssample><angle>-3.2</angle><rcs>1</rcs></rcssample>
+                    <rcssample><angle>0</angle><rcs>10</rcs></rcssample>
+                    <rcssample><angle>3.2</angle><rcs>1</rcs></rcssample>
+                </azimuth>
+            </target>"#,
+        )
+        .expect("RCS pattern should be writable");
+        let scenario = minimal_scenario_json(
+            STATIC_MOTION,
+            &format!(
+                r#"{{"target": {{"name": "aircraft", "rcs": {{"type": "file",
+                    "filename": "{}", "interpolation": "cubic"}}}}}}"#,
+                rcs_path.to_str().unwrap().replace('\\', "\\\\")
+            ),
+        );
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&scenario).expect("scenario should load");
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(xml.contains(r#"interpolation="cubic""#));
+
+        let reloaded = load_xml(&xml).expect("exported XML should load");
+        let json = reloaded.get_scenario_as_json().expect("scenario should serialize to JSON");
+        assert!(json.contains(r#""interpolation": "cubic""#));
+    }
+
+    #[test]
+    fn noise_floor_matches_ktb_at_reference_temperature() {
+        let scenario = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"receiver": {"name": "rx", "noise_temp": 290.0,
+                "pulsed_mode": {"prf": 10.0, "window_skip": 0.0, "window_length": 0.001},
+                "antenna": "default", "timing": "default"}}"#,
+        );
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&scenario).expect("scenario should load");
+
+        // -174 dBm/Hz at 290 K over a 1 kHz bandwidth (1 / window_length)
+        let noise_dbm = context.calculate_noise_floor("rx").expect("noise floor should compute");
+        let expected = -174.0 + 10.0 * 1000.0_f64.log10();
+        assert!((noise_dbm - expected).abs() < 0.1, "noise floor was {noise_dbm} dBm");
+    }
+
+    /// Verifies that enabling the timestamp export writes one CSV row per emitted pulse,
+    /// numbered per transmitter.
+    #[test]
+    fn pulse_timestamp_export_lists_every_pulse() {
+        let (_waveform, pulse) = pulse_waveform_json("pulse", 1e3, 1e9);
+
+        let emitter = |name: &str, prf: f64| {
+            format!(
+                r#"{{"transmitter": {{"name": "{name}", "pulsed_mode": {{"prf": {prf:.1}}},
+                    "waveform": "pulse", "antenna": "default", "timing": "default"}}}}"#
+            )
+        };
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            &format!("{}, {}", emitter("slow", 10.0), emitter("fast", 20.0)),
+        )
+        .replacen(r#""endtime": 1.0"#, r#""endtime": 0.975"#, 1)
+        .replacen(
+            r#""coordinatesystem": {"frame": "ENU"}"#,
+            r#""coordinatesystem": {"frame": "ENU"}, "export": {"timestamps": true}"#,
+            1,
+        );
+        let json = with_waveforms(&json, &pulse);
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&json).expect("scenario should load");
+        assert!(context
+            .get_scenario_as_xml()
+            .expect("scenario should serialize to XML")
+            .contains(r#"<export timestamps="true"/>"#));
+
+        let output = TempDir::new();
+        context
+            .run_simulation(None, Some(&output.output()), &AtomicBool::new(false))
+            .expect("simulation should run");
+        let csv = std::fs::read_to_string(output.0.join("pulse_timestamps.csv"))
+            .expect("timestamp file should be written");
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("pulse_index,transmitter,emission_time"));
+        let rows: Vec<&str> = lines.collect();
+        // 10 pulses at 10 Hz and 20 pulses at 20 Hz before the 0.975 s end time
+        assert_eq!(rows.len(), 30);
+        assert!(rows.contains(&"9,slow,0.9"));
+        assert!(rows.contains(&"19,fast,0.95"));
+    }
+
+    /// Verifies the velocity-ambiguity PRF for a target closing on a monostatic radar at
+    /// 30 m/s: at 1 GHz, `lambda * prf / 4 = 30` gives `prf = 120 / lambda`.
+    #[test]
+    fn prf_for_velocity_matches_closing_speed() {
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"monostatic": {"name": "radar", "cw_mode": {},
+                "waveform": "default", "antenna": "default", "timing": "default"}}"#,
+        )
+        .replacen(
+            r#""platforms": ["#,
+            r#""platforms": [{"name": "scene",
+                "motionpath": {"interpolation": "linear", "positionwaypoints": [
+                    {"time": 0.0, "x": 0.0, "y": 1000.0, "altitude": 0.0},
+                    {"time": 1.0, "x": 0.0, "y": 970.0, "altitude": 0.0}]},
+                "fixedrotation": {"startazimuth": 0.0, "startelevation": 0.0,
+                    "azimuthrate": 0.0, "elevationrate": 0.0},
+                "components": [{"target": {"name": "aircraft",
+                    "rcs": {"type": "isotropic", "value": 1.0}}}]}, "#,
+            1,
+        );
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let prf = context
+            .calculate_min_prf_for_velocity("radar", "aircraft", "radar")
+            .expect("PRF should compute");
+        let lambda = 299_792_458.0 / 1e9;
+        assert!((prf - 120.0 / lambda).abs() < 1e-6, "PRF was {prf} Hz");
+
+        let missing = context.calculate_min_prf_for_velocity("radar", "missing", "radar");
+        assert!(missing.unwrap_err().contains("not found"));
+    }
+
+    /// Verifies that the editor view state survives an XML round trip untouched, and that
+    /// a scenario without one still loads and exports without it.
+    #[test]
+    fn ui_state_round_trips_through_xml() {
+        let plain = minimal_scenario_json(STATIC_MOTION, "");
+        let with_ui = plain.replacen(
+            r#""name": "test","#,
+            r#""name": "test", "ui_state": {"camera": {"position": [10.0, 20.0, 30.0],
+                "target": [0.0, 0.0, 0.0]}, "selected_platform": "platform", "current_time": 0.5},"#,
+            1,
+        );
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&with_ui).expect("scenario should load");
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(xml.contains("<ui>"));
+
+        let reloaded = reload_via_xml(&context);
+        let json = reloaded.get_scenario_as_json().expect("scenario should serialize to JSON");
+        assert!(json.contains(r#""selected_platform": "platform""#));
+        assert!(json.contains(r#""current_time": 0.5"#));
+
+        context.update_scenario_from_json(&plain).expect("scenario without UI state should load");
+        let json = context.get_scenario_as_json().expect("scenario should serialize to JSON");
+        assert!(!json.contains("ui_state"));
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(!xml.contains("<ui>"));
+    }
+
+    /// Verifies that scenario and per-platform user metadata in the UI state, including
+    /// nulls and XML-special characters, survive an XML round trip unchanged.
+    #[test]
+    fn ui_metadata_round_trips_through_xml() {
+        let json = minimal_scenario_json(STATIC_MOTION, "").replacen(
+            r#""name": "test","#,
+            r##""name": "test", "ui_state": {"metadata": {"notes": "Range <5 km> & \"clear\"",
+                "owner": null}, "platforms": {"platform": {"color": "#ff8800", "group": 2}}},"##,
+            1,
+        );
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let reloaded = reload_via_xml(&context);
+        let json = reloaded.get_scenario_as_json().expect("scenario should serialize to JSON");
+        assert!(json.contains(r#""notes": "Range <5 km> & \"clear\"""#));
+        assert!(json.contains(r#""owner": null"#));
+        assert!(json.contains(r##""color": "#ff8800""##));
+        assert!(json.contains(r#""group": 2"#));
+    }
+
+    /// Verifies that a scenario survives a JSON file round trip and that a malformed file
+    /// is rejected with the path of the offending field.
+    #[test]
+    fn scenario_round_trips_through_json_file() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context
+            .update_scenario_from_json(&minimal_scenario_json(STATIC_MOTION, ""))
+            .expect("scenario should load");
+        let expected = context.get_scenario_as_json().expect("scenario should serialize to JSON");
+
+        let path = TempFile::new("json");
+        context
+            .export_scenario_as_json_file(path.to_str().unwrap())
+            .expect("scenario should be written");
+        let reloaded = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let result = reloaded.load_scenario_from_json_file(path.to_str().unwrap());
+        result.expect("exported JSON should load");
+        assert_eq!(
+            reloaded.get_scenario_as_json().expect("scenario should serialize to JSON"),
+            expected
+        );
+
+        let bad_path = TempFile::new("json");
+        let bad = minimal_scenario_json(STATIC_MOTION, "").replacen(
+            r#""name": "platform""#,
+            r#""name": 42"#,
+            1,
+        );
+        std::fs::write(&bad_path, bad).expect("JSON should be writable");
+        let result = reloaded.load_scenario_from_json_file(bad_path.to_str().unwrap());
+        let error = result.expect_err("a numeric platform name should be rejected");
+        assert!(error.contains("simulation.platforms[0].name"), "unexpected error: {error}");
+    }
+
+    /// Verifies that a NaN waypoint coordinate is reported by its path and blocks the XML
+    /// export instead of being written as text the parser cannot read back.
+    #[test]
+    fn nan_waypoint_is_reported_and_blocks_xml_export() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context
+            .update_scenario_from_json(&minimal_scenario_json(STATIC_MOTION, ""))
+            .expect("scenario should load");
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        let clean = context.validate_scenario().expect("validation should run");
+        assert!(clean.valid && clean.errors.is_empty());
+
+        // std::stod accepts "nan", so a hand-edited XML file can carry one into the scenario
+        let file = TempFile::new("xml");
+        let marker = "<x>0</x>";
+        assert!(xml.contains(marker), "unexpected waypoint format in {xml}");
+        std::fs::write(&file.0, xml.replacen(marker, "<x>nan</x>", 1))
+            .expect("XML should be writable");
+        context
+            .load_scenario_from_xml_file(file.path())
+            .expect("XML with a NaN coordinate should load");
+
+        let report = context.validate_scenario().expect("validation should run");
+        assert!(!report.valid);
+        assert_eq!(report.errors, vec!["platforms[0].motionpath.positionwaypoints[0].x is NaN"]);
+        let export = context.get_scenario_as_xml();
+        assert!(export.unwrap_err().contains("positionwaypoints[0].x is NaN"));
+    }
+
+    /// Verifies that platform position noise survives an XML round trip and that a
+    /// noise-free platform does not gain a `<positionnoise>` element on export.
+    #[test]
+    fn position_noise_round_trips_through_xml() {
+        let plain = minimal_scenario_json(STATIC_MOTION, "");
+        let noisy = plain.replacen(
+            r#""name": "platform","#,
+            r#""name": "platform", "position_noise": {"x": 1.5, "y": 2.5, "z": 0.5,
+                "correlation_time": 0.25, "seed": 42},"#,
+            1,
+        );
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&noisy).expect("scenario should load");
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(xml.contains("<positionnoise"));
+
+        let reloaded = reload_via_xml(&context);
+        let json = reloaded.get_scenario_as_json().expect("scenario should serialize to JSON");
+        assert!(json.contains(r#""position_noise""#));
+        assert!(json.contains(r#""x": 1.5"#));
+        assert!(json.contains(r#""seed": 42"#));
+        assert!(json.contains(r#""correlation_time": 0.25"#));
+
+        context.update_scenario_from_json(&plain).expect("noise-free scenario should load");
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(!xml.contains("<positionnoise"));
+    }
+
+    /// Verifies that the noisy path preview is deterministic for a fixed seed, deviates
+    /// from the nominal path, and changes when the seed changes.
+    #[test]
+    fn position_noise_preview_is_deterministic_for_fixed_seed() {
+        let waypoints = || {
+            vec![
+                super::MotionWaypoint { time: 0.0, x: 0.0, y: 0.0, altitude: 0.0 },
+                super::MotionWaypoint { time: 10.0, x: 1000.0, y: 0.0, altitude: 0.0 },
+            ]
+        };
+        let noise =
+            |seed| super::PositionNoise { x: 2.0, y: 2.0, z: 1.0, correlation_time: None, seed };
+        let preview = |noise: Option<super::PositionNoise>| {
+            fers_api::get_interpolated_motion_path(
+                waypoints(),
+                super::InterpolationType::Linear,
+                101,
+                &super::PathPreviewOptions { noise, ..Default::default() },
+            )
+            .expect("path should interpolate")
+        };
+
+        let nominal = preview(None);
+        let first = preview(Some(noise(7)));
+        let second = preview(Some(noise(7)));
+        let other = preview(Some(noise(8)));
+
+        let deviations: Vec<f64> = nominal
+            .iter()
+            .zip(&first)
+            .map(|(a, b)| ((a.x - b.x).powi(2) + (a.y - b.y).powi(2) + (a.z - b.z).powi(2)).sqrt())
+            .collect();
+        assert!(deviations.iter().all(|d| d.is_finite()));
+        assert!(deviations.iter().any(|&d| d > 0.1), "noise should move the path");
+        assert!(
+            first.iter().zip(&second).all(|(a, b)| a.x == b.x && a.y == b.y && a.z == b.z),
+            "the same seed must reproduce the same realization"
+        );
+        assert!(
+            first.iter().zip(&other).any(|(a, b)| a.x != b.x),
+            "a different seed should give a different realization"
+        );
+        assert!(
+            first.iter().zip(&nominal).any(|(a, b)| a.vx != b.vx),
+            "velocities gain the noise rate"
+        );
+    }
+
+    /// Verifies that position noise is correlated in time: samples a microsecond apart move
+    /// by far less than a wavelength, and the noisy velocity is the derivative of the noisy
+    /// position.
+    #[test]
+    fn position_noise_is_smooth_within_a_pulse() {
+        let preview = |noise: Option<super::PositionNoise>, t_start: f64, t_end: f64, points| {
+            fers_api::get_interpolated_motion_path(
+                vec![
+                    super::MotionWaypoint { time: 0.0, x: 0.0, y: 0.0, altitude: 0.0 },
+                    super::MotionWaypoint { time: 10.0, x: 1000.0, y: 0.0, altitude: 0.0 },
+                ],
+                super::InterpolationType::Linear,
+                points,
+                &super::PathPreviewOptions {
+                    noise,
+                    t_start: Some(t_start),
+                    t_end: Some(t_end),
+                    ..Default::default()
+                },
+            )
+            .expect("path should interpolate")
+        };
+        let noise =
+            || super::PositionNoise { x: 2.0, y: 2.0, z: 2.0, correlation_time: None, seed: 3 };
+        let offsets = |t_start, t_end, points| -> Vec<[f64; 3]> {
+            preview(None, t_start, t_end, points)
+                .iter()
+                .zip(&preview(Some(noise()), t_start, t_end, points))
+                .map(|(a, b)| [b.x - a.x, b.y - a.y, b.z - a.z])
+                .collect()
+        };
+
+        let pulse = offsets(2.0, 2.0 + 10e-6, 11);
+        for pair in pulse.windows(2) {
+            let step = (0..3).map(|i| (pair[1][i] - pair[0][i]).abs()).fold(0.0, f64::max);
+            assert!(step < 1e-3, "noise moved {step} m in a microsecond");
+        }
+
+        let h = 1e-3;
+        let around = offsets(2.3 - h, 2.3 + h, 3);
+        let nominal = preview(None, 2.3 - h, 2.3 + h, 3);
+        let noisy = preview(Some(noise()), 2.3 - h, 2.3 + h, 3);
+        let rate =
+            [noisy[1].vx - nominal[1].vx, noisy[1].vy - nominal[1].vy, noisy[1].vz - nominal[1].vz];
+        for i in 0..3 {
+            let slope = (around[2][i] - around[0][i]) / (2.0 * h);
+            assert!((slope - rate[i]).abs() < 1e-3, "axis {i}: {slope} vs {rate:?}");
+        }
+    }
+
+    /// Verifies that the noisy velocity is continuous across a draw of the position noise,
+    /// so the platform does not change speed abruptly once per correlation time.
+    #[test]
+    fn position_noise_velocity_is_continuous_across_draws() {
+        let velocities = |t_start: f64, t_end: f64| {
+            fers_api::get_interpolated_motion_path(
+                vec![
+                    super::MotionWaypoint { time: 0.0, x: 0.0, y: 0.0, altitude: 0.0 },
+                    super::MotionWaypoint { time: 10.0, x: 1000.0, y: 0.0, altitude: 0.0 },
+                ],
+                super::InterpolationType::Linear,
+                2,
+                &super::PathPreviewOptions {
+                    noise: Some(super::PositionNoise {
+                        x: 2.0,
+                        y: 2.0,
+                        z: 2.0,
+                        correlation_time: Some(0.5),
+                        seed: 11,
+                    }),
+                    t_start: Some(t_start),
+                    t_end: Some(t_end),
+                    ..Default::default()
+                },
+            )
+            .expect("path should interpolate")
+        };
+
+        // Draws fall at multiples of the 0.5 s correlation time
+        let h = 1e-6;
+        for knot in [1.0, 1.5, 4.0] {
+            let across = velocities(knot - h, knot + h);
+            let jump = [
+                across[1].vx - across[0].vx,
+                across[1].vy - across[0].vy,
+                across[1].vz - across[0].vz,
+            ];
+            assert!(jump.iter().all(|j| j.abs() < 1e-3), "velocity jumps by {jump:?} at {knot} s");
+        }
+    }
+
+    /// Verifies that noisy platforms without a seed get distinct seeds, and that a negative
+    /// standard deviation is rejected on JSON load.
+    #[test]
+    fn position_noise_seeds_differ_between_platforms() {
+        let noisy = minimal_scenario_json(STATIC_MOTION, "")
+            .replacen(
+                r#""name": "platform","#,
+                r#""name": "platform", "position_noise": {"x": 1.0, "y": 1.0, "z": 1.0},"#,
+                1,
+            )
+            .replacen(
+                r#""platforms": ["#,
+                &format!(
+                    r#""platforms": [{{"name": "other", "motionpath": {STATIC_MOTION},
+                        "position_noise": {{"x": 1.0, "y": 1.0, "z": 1.0}},
+                        "fixedrotation": {{"startazimuth": 0.0, "startelevation": 0.0,
+                            "azimuthrate": 0.0, "elevationrate": 0.0}},
+                        "components": []}}, "#
+                ),
+                1,
+            );
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&noisy).expect("scenario should load");
+        let json = context.get_scenario_as_json().expect("scenario should serialize");
+        let seeds: Vec<&str> = json
+            .match_indices(r#""position_noise""#)
+            .map(|(start, _)| {
+                let block = &json[start..start + json[start..].find('}').unwrap()];
+                let seed = &block[block.find(r#""seed": "#).expect("seed should be written") + 8..];
+                seed.split(|c: char| !c.is_ascii_digit()).next().unwrap()
+            })
+            .collect();
+        assert_eq!(seeds.len(), 2);
+        assert_ne!(seeds[0], seeds[1]);
+
+        let negative = noisy.replacen(r#""x": 1.0, "y": 1.0"#, r#""x": -1.0, "y": 1.0"#, 1);
+        let err = context
+            .update_scenario_from_json(&negative)
+            .expect_err("a negative standard deviation should be rejected");
+        assert!(err.contains("non-negative"), "{err}");
+    }
+
+    /// Verifies that a waypoint rotation path with cubic interpolation survives a
+    /// load, save and reload cycle through XML instead of collapsing to a fixed rotation.
+    #[test]
+    fn cubic_rotation_path_round_trips_through_xml() {
+        let json = minimal_scenario_json(STATIC_MOTION, "").replacen(
+            r#""fixedrotation": {
+                        "startazimuth": 0.0, "startelevation": 0.0,
+                        "azimuthrate": 0.0, "elevationrate": 0.0
+                    },"#,
+            r#""rotationpath": {"interpolation": "cubic", "rotationwaypoints": [
+                        {"time": 0.0, "azimuth": 10.0, "elevation": 0.0},
+                        {"time": 0.5, "azimuth": 45.0, "elevation": 5.0},
+                        {"time": 1.0, "azimuth": 90.0, "elevation": 10.0}
+                    ]},"#,
+            1,
+        );
+        assert!(json.contains("rotationpath"), "fixture should replace the fixed rotation");
+        let rotation_section = |json: &str| {
+            let start = json.find(r#""rotationpath""#).expect("rotation path should be present");
+            let end = start + json[start..].find(']').expect("waypoint list should close");
+            json[start..=end]
+                .split(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == 'e'))
+                .filter_map(|token| token.parse::<f64>().ok())
+                .collect::<Vec<_>>()
+        };
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&json).expect("scenario should load");
+        let original_json = context.get_scenario_as_json().expect("JSON should export");
+        assert!(original_json.contains(r#""interpolation": "cubic""#));
+        let original = rotation_section(&original_json);
+        assert_eq!(original.len(), 9, "three waypoints of time, azimuth and elevation");
+
+        let reloaded = reload_via_xml(&context);
+
+        let json = reloaded.get_scenario_as_json().expect("JSON should export");
+        assert!(!json.contains("fixedrotation"));
+        assert!(json.contains(r#""interpolation": "cubic""#));
+        let round_trip = rotation_section(&json);
+        assert_eq!(round_trip.len(), original.len());
+        assert!(round_trip.iter().zip(&original).all(|(a, b)| (a - b).abs() < 1e-9));
+    }
+
+    /// Verifies the data rate of a single pulsed receiver against a hand calculation:
+    /// 1000 samples/s of two 8-byte values is 16 kB/s while the window is open, and a
+    /// 1 ms window at 10 Hz is open 1% of the time.
+    #[test]
+    fn data_rate_matches_single_pulsed_receiver() {
+        let scenario = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"receiver": {"name": "rx",
+                "pulsed_mode": {"prf": 10.0, "window_skip": 0.0, "window_length": 0.001},
+                "antenna": "default", "timing": "default"}}"#,
+        );
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&scenario).expect("scenario should load");
+
+        let rate = context.calculate_data_rate().expect("data rate should compute");
+        assert_eq!(rate.active_receivers, 1);
+        assert!((rate.peak_bytes_per_second - 16_000.0).abs() < 1e-9);
+        assert!((rate.average_bytes_per_second - 160.0).abs() < 1e-9);
+    }
+
+    /// Verifies that a pulsed monostatic radar keeps its type, receive window, PRF, noise
+    /// temperature and asset references through an XML export and reload.
+    #[test]
+    fn pulsed_monostatic_round_trips_through_xml() {
+        let (_waveform, pulse) = pulse_waveform_json("pulse", 1e3, 1e9);
+
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"monostatic": {"name": "radar", "noise_temp": 150.0,
+                "pulsed_mode": {"prf": 250.0, "window_skip": 0.003, "window_length": 0.002},
+                "waveform": "pulse", "antenna": "default", "timing": "default"}}"#,
+        );
+        let json = with_waveforms(&json, &pulse);
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&json).expect("scenario should load");
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(xml.contains("<monostatic"));
+
+        let reloaded = reload_via_xml(&context);
+
+        let round_trip = reloaded.get_scenario_as_json().expect("scenario should serialize");
+        assert!(round_trip.contains(r#""monostatic""#));
+        assert!(round_trip.contains(r#""prf": 250.0"#));
+        assert!(round_trip.contains(r#""window_skip": 0.003"#));
+        assert!(round_trip.contains(r#""window_length": 0.002"#));
+        assert!(round_trip.contains(r#""noise_temp": 150.0"#));
+        assert!(round_trip.contains(r#""waveform": "pulse""#));
+        assert!(round_trip.contains(r#""antenna": "default""#));
+        assert!(round_trip.contains(r#""timing": "default""#));
+    }
+
+    /// Verifies that a pulsed transmitter's hop sequence survives an XML export and re-import.
+    #[test]
+    fn hop_sequence_round_trips_through_xml() {
+        let (_waveform, pulse) = pulse_waveform_json("pulse", 1e3, 1e9);
+
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"transmitter": {"name": "hopper", "pulsed_mode": {"prf": 250.0},
+                "hop_sequence": {"frequencies": [1e9, 1.1e9, 1.2e9], "pattern": [2, 0, 1]},
+                "waveform": "pulse", "antenna": "default", "timing": "default"}}"#,
+        );
+        let json = with_waveforms(&json, &pulse);
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&json).expect("scenario should load");
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(xml.contains("<hopsequence"));
+
+        let reloaded = reload_via_xml(&context);
+
+        let round_trip: String = reloaded
+            .get_scenario_as_json()
+            .expect("scenario should serialize")
+            .split_whitespace()
+            .collect();
+        assert!(round_trip.contains(r#""frequencies":[1000000000.0,1100000000.0,1200000000.0]"#));
+        assert!(round_trip.contains(r#""pattern":[2,0,1]"#));
+    }
+
+    /// Verifies that the hop preview cycles through the hop set, honours an explicit
+    /// pattern, and rejects pattern indices outside the hop set.
+    #[test]
+    fn preview_hop_sequence_cycles_carriers() {
+        let cycled = super::HopSequence { frequencies: vec![1e9, 2e9], pattern: Vec::new() };
+        assert_eq!(
+            fers_api::preview_hop_sequence(&cycled, 5).expect("preview should succeed"),
+            vec![1e9, 2e9, 1e9, 2e9, 1e9]
+        );
+
+        let patterned =
+            super::HopSequence { frequencies: vec![1e9, 2e9, 3e9], pattern: vec![2, 0, 1] };
+        assert_eq!(
+            fers_api::preview_hop_sequence(&patterned, 4).expect("preview should succeed"),
+            vec![3e9, 1e9, 2e9, 3e9]
+        );
+
+        let invalid = super::HopSequence { frequencies: vec![1e9], pattern: vec![1] };
+        assert!(fers_api::preview_hop_sequence(&invalid, 1).is_err());
+    }
+
+    /// Verifies that a transmitter keeps its pulsed or CW operating mode through an
+    /// XML export and re-import.
+    #[test]
+    fn transmitter_mode_round_trips_through_xml() {
+        let (_waveform, pulse) = pulse_waveform_json("pulse", 1e3, 1e9);
+
+        let round_trip = |component: &str, tag: &str| {
+            let json = with_waveforms(&minimal_scenario_json(STATIC_MOTION, component), &pulse);
+            let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+            context.update_scenario_from_json(&json).expect("scenario should load");
+            let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+            assert!(xml.contains(&format!("<{tag}")));
+
+            let reloaded = reload_via_xml(&context);
+            reloaded.get_scenario_as_json().expect("scenario should serialize")
+        };
+
+        let pulsed = round_trip(
+            r#"{"transmitter": {"name": "tx", "pulsed_mode": {"prf": 250.0},
+                "waveform": "pulse", "antenna": "default", "timing": "default"}}"#,
+            "pulsed_mode",
+        );
+        let cw = round_trip(
+            r#"{"transmitter": {"name": "tx", "cw_mode": {},
+                "waveform": "default", "antenna": "default", "timing": "default"}}"#,
+            "cw_mode",
+        );
+
+        assert!(pulsed.contains(r#""pulsed_mode""#));
+        assert!(pulsed.contains(r#""prf": 250.0"#));
+        assert!(pulsed.contains(r#""waveform": "pulse""#));
+        assert!(!pulsed.contains(r#""cw_mode""#));
+        assert!(cw.contains(r#""cw_mode""#));
+        assert!(cw.contains(r#""waveform": "default""#));
+        assert!(!cw.contains(r#""pulsed_mode""#));
+    }
+
+    /// Verifies the range CRLB against the analytic bound `c / (4 pi B_rms sqrt(SNR))` for a
+    /// monostatic radar with a known SNR and a 1 kHz pulse, and that CW waveforms are rejected.
+    #[test]
+    fn range_crlb_matches_analytic_bound() {
+        let (_waveform, pulse) = pulse_waveform_json("pulse", 1e3, 1e9);
+
+        let scenario = |mode: &str, waveform: &str| {
+            let json = minimal_scenario_json(
+                STATIC_MOTION,
+                &format!(
+                    r#"{{"monostatic": {{"name": "radar", {mode}, "noise_temp": 290.0,
+                        "waveform": "{waveform}", "antenna": "default", "timing": "default"}}}}"#
+                ),
+            )
+            .replacen(
+                r#""platforms": ["#,
+                r#""platforms": [{"name": "aircraft",
+                    "motionpath": {"interpolation": "static", "positionwaypoints": [
+                        {"time": 0.0, "x": 5000.0, "y": 0.0, "altitude": 0.0}]},
+                    "fixedrotation": {"startazimuth": 0.0, "startelevation": 0.0,
+                        "azimuthrate": 0.0, "elevationrate": 0.0},
+                    "components": [{"target": {"name": "aircraft",
+                        "rcs": {"type": "isotropic", "value": 10.0}}}]}, "#,
+                1,
+            );
+            with_waveforms(&json, &pulse)
+        };
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context
+            .update_scenario_from_json(&scenario(
+                r#""pulsed_mode": {"prf": 250.0, "window_skip": 0.0, "window_length": 0.002}"#,
+                "pulse",
+            ))
+            .expect("scenario should load");
+        let sigma = context
+            .calculate_range_crlb("radar", "aircraft", "radar", 0.0)
+            .expect("range CRLB should compute");
+
+        let c = 299_792_458.0;
+        let lambda = c / 1e9;
+        let range: f64 = 5000.0;
+        let received =
+            lambda * lambda * 10.0 / ((4.0 * std::f64::consts::PI).powi(3) * range.powi(4));
+        let noise = 1.380_650_3e-23 * 290.0 * (1000.0 / 2.0);
+        let rms_bandwidth = 1000.0 / 12f64.sqrt();
+        let expected = c / (4.0 * std::f64::consts::PI * rms_bandwidth * (received / noise).sqrt());
+        assert!((sigma - expected).abs() < 1e-9 * expected, "got {sigma} m, expected {expected} m");
+
+        let cw = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        cw.update_scenario_from_json(&scenario(r#""cw_mode": {}"#, "default"))
+            .expect("scenario should load");
+        assert!(cw.calculate_range_crlb("radar", "aircraft", "radar", 0.0).is_err());
+    }
+
+    /// Verifies that two antennas sharing a name are rejected with an error naming the
+    /// duplicate, rather than silently resolving both references to one asset.
+    #[test]
+    fn duplicate_antenna_names_are_rejected() {
+        let json = minimal_scenario_json(STATIC_MOTION, "").replacen(
+            r#""antennas": ["#,
+            r#""antennas": [{"name": "dish", "pattern": "isotropic"},
+                {"name": "dish", "pattern": "isotropic"}, "#,
+            1,
+        );
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let error = context
+            .update_scenario_from_json(&json)
+            .expect_err("duplicate antenna names should be rejected");
+        assert!(error.contains("dish"), "unexpected error: {error}");
+    }
+
+    /// Verifies that facing beams aimed at the same ground patch yield an overlap polygon
+    /// on the ground plane around that patch, and that beams aimed apart yield none.
+    #[test]
+    fn bistatic_footprint_overlap_detects_shared_and_disjoint_beams() {
+        // Each sensor sits 1000 m up and looks 45 degrees down, so its beam is centred on
+        // the ground 1000 m ahead along its heading.
+        let sensor = |name: &str, x: f64, azimuth: f64, component: &str| {
+            format!(
+                r#"{{"name": "{name}",
+                    "motionpath": {{"interpolation": "static", "positionwaypoints": [
+                        {{"time": 0.0, "x": {x}, "y": 0.0, "altitude": 1000.0}}]}},
+                    "fixedrotation": {{"startazimuth": {azimuth}, "startelevation": -45.0,
+                        "azimuthrate": 0.0, "elevationrate": 0.0}},
+                    "components": [{component}]}}, "#
+            )
+        };
+        let scenario = |rx_x: f64| {
+            let platforms = sensor(
+                "tx_platform",
+                0.0,
+                90.0,
+                r#"{"transmitter": {"name": "tx", "cw_mode": {}, "waveform": "default",
+                    "antenna": "beam", "timing": "default"}}"#,
+            ) + &sensor(
+                "rx_platform",
+                rx_x,
+                270.0,
+                r#"{"receiver": {"name": "rx", "cw_mode": {}, "antenna": "beam",
+                    "timing": "default"}}"#,
+            );
+            minimal_scenario_json(STATIC_MOTION, "")
+                .replacen(
+                    r#""antennas": ["#,
+                    r#""antennas": [{"name": "beam", "pattern": "sinc", "alpha": 1.0,
+                        "beta": 40.0, "gamma": 2.0}, "#,
+                    1,
+                )
+                .replacen(r#""platforms": ["#, &format!(r#""platforms": [{platforms}"#), 1)
+        };
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&scenario(2000.0)).expect("scenario should load");
+        let overlap = context
+            .compute_bistatic_footprint_overlap("tx", "rx", 0.0, 0.0)
+            .expect("overlap should compute");
+        assert!(overlap.len() >= 3, "expected an overlap polygon, got {overlap:?}");
+        for [x, y, z] in &overlap {
+            assert!(
+                (x - 1000.0).abs() < 150.0 && y.abs() < 150.0,
+                "vertex ({x}, {y}) is off target"
+            );
+            assert_eq!(*z, 0.0);
+        }
+
+        let apart = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        apart.update_scenario_from_json(&scenario(10000.0)).expect("scenario should load");
+        let disjoint = apart
+            .compute_bistatic_footprint_overlap("tx", "rx", 0.0, 0.0)
+            .expect("overlap should compute");
+        assert!(disjoint.is_empty(), "expected no overlap, got {disjoint:?}");
+    }
+
+    /// Verifies that a simulation output file records the platform geometry and that
+    /// importing it reconstructs the platforms without any radar components or assets.
+    #[test]
+    fn import_scenario_from_output_reconstructs_platforms() {
+        let dir = TempDir::new();
+        let output = simulate_cw_output(&dir, "replay", 1.0, 1.0);
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context
+            .import_scenario_from_output(output.to_str().unwrap())
+            .expect("output file should carry geometry");
+        let json = context.get_scenario_as_json().expect("scenario should serialize");
+        let compact: String = json.split_whitespace().collect();
+        assert!(compact.contains(r#""name":"scene""#), "{json}");
+        assert!(compact.contains(r#""name":"platform""#), "{json}");
+        assert!(compact.contains(r#""y":1000.0"#), "{json}");
+        assert!(!compact.contains(r#""target""#), "{json}");
+        assert!(!compact.contains(r#""monostatic""#), "{json}");
+
+        let not_output = TempFile::new("txt");
+        std::fs::write(&not_output, "not an hdf5 file").unwrap();
+        let result = context.import_scenario_from_output(not_output.to_str().unwrap());
+        assert!(result.is_err());
+    }
+
+    /// Verifies that a set cancellation flag stops the run before it produces output,
+    /// and that a cleared flag lets the same scenario run to completion.
+    #[test]
+    fn simulation_stops_when_cancel_flag_is_set() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"monostatic": {"name": "fers_cancelled", "cw_mode": {},
+                "waveform": "default", "antenna": "default", "timing": "default"}}"#,
+        );
+        let dir = TempDir::new();
+        let location = dir.output();
+        let output = dir.0.join("fers_cancelled_results.h5");
+
+        context.update_scenario_from_json(&json).expect("scenario should load");
+        let cancel = AtomicBool::new(true);
+        let outcome =
+            context.run_simulation(None, Some(&location), &cancel).expect("cancel is not an error");
+        assert_eq!(outcome, fers_api::SimulationOutcome::Cancelled);
+        assert!(!output.exists(), "a cancelled CW run should not export its receiver");
+
+        context.update_scenario_from_json(&json).expect("scenario should reload");
+        cancel.store(false, Ordering::Relaxed);
+        let outcome =
+            context.run_simulation(None, Some(&location), &cancel).expect("simulation should run");
+        assert_eq!(outcome, fers_api::SimulationOutcome::Completed);
+        assert!(output.exists());
+    }
+
+    /// Verifies that a time-scaling factor of 2 doubles the scenario and waypoint times
+    /// while keeping the path, so interpolated speeds are halved, and that non-positive
+    /// factors are rejected.
+    #[test]
+    fn time_scale_scenario_doubles_times_and_halves_speeds() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            r#"{"interpolation": "linear", "positionwaypoints": [
+                {"time": 0.0, "x": 0.0, "y": 0.0, "altitude": 0.0},
+                {"time": 1.0, "x": 100.0, "y": 0.0, "altitude": 0.0}]}"#,
+            "",
+        )
+        .replacen(r#""azimuthrate": 0.0"#, r#""azimuthrate": 10.0"#, 1);
+        context.update_scenario_from_json(&json).expect("scenario should load");
+        let before = context.get_platform_tracks(2).expect("tracks should sample");
+
+        context.time_scale_scenario(2.0).expect("factor should apply");
+        let scaled = context.get_scenario_as_json().expect("scenario should serialize");
+        let compact: String = scaled.split_whitespace().collect();
+        assert!(compact.contains(r#""endtime":2.0"#), "{scaled}");
+        assert!(compact.contains(r#""time":2.0,"x":100.0"#), "{scaled}");
+        assert!(compact.contains(r#""azimuthrate":5.0"#), "{scaled}");
+
+        let after = context.get_platform_tracks(2).expect("tracks should sample");
+        let (start, end) = (&before[0].points, &after[0].points);
+        assert!((end[1].x - start[1].x).abs() < 1e-9, "the path end should not move");
+        assert!((end[0].vx - start[0].vx / 2.0).abs() < 1e-9);
+
+        assert!(context.time_scale_scenario(0.0).is_err());
+        assert!(context.time_scale_scenario(-1.0).is_err());
+    }
+
+    /// Verifies that progress updates are throttled to the emit interval except for
+    /// phase changes and finished operations, and that the time remaining is
+    /// extrapolated from the simulation loop's progress.
+    #[test]
+    fn progress_tracker_throttles_and_estimates_time_remaining() {
+        use fers_api::{SimulationPhase, PROGRESS_EMIT_INTERVAL};
+        use std::time::Duration;
+
+        let start = std::time::Instant::now();
+        let tracker = fers_api::ProgressTracker::new(start);
+        let at = |ms: u64| start + Duration::from_millis(ms);
+        let simulate = |percent: i32, ms: u64| {
+            tracker.update(
+                SimulationPhase::Simulating,
+                "Simulating...".into(),
+                percent,
+                100,
+                at(ms),
+            )
+        };
+
+        let init = tracker
+            .update(SimulationPhase::Initializing, "Initializing...".into(), 0, 100, at(0))
+            .expect("first update should be emitted");
+        assert_eq!(init.percent, Some(0.0));
+        assert!(init.eta_seconds.is_none());
+
+        // The phase change is delivered immediately and starts the simulation clock.
+        let first = simulate(0, 10).expect("phase change should be emitted");
+        assert!(first.eta_seconds.is_none());
+        assert!(simulate(1, 20).is_none(), "updates within the interval should be dropped");
+
+        let later_ms = 10 + 2 * PROGRESS_EMIT_INTERVAL.as_millis() as u64;
+        let quarter = simulate(25, later_ms).expect("update after the interval should be emitted");
+        assert_eq!(quarter.percent, Some(25.0));
+        let elapsed = (later_ms - 10) as f64 / 1000.0;
+        assert!((quarter.eta_seconds.unwrap() - 3.0 * elapsed).abs() < 1e-9);
+        assert!((quarter.elapsed_seconds - later_ms as f64 / 1000.0).abs() < 1e-9);
+
+        // Finished operations are never dropped, even inside the interval.
+        let done = simulate(100, later_ms + 1).expect("finished step should be emitted");
+        assert_eq!(done.eta_seconds, Some(0.0));
+
+        let export = tracker
+            .update(
+                SimulationPhase::Exporting,
+                "Exporting rx: Chunk 3".into(),
+                3,
+                0,
+                at(later_ms + 2),
+            )
+            .expect("phase change should be emitted");
+        assert!(export.percent.is_none(), "unknown totals have no percentage");
+    }
+
+    /// Verifies that a run directed to an output directory writes the receiver file
+    /// under the basename there, that the listed output paths match, and that missing
+    /// directories and basenames with separators are rejected up front.
+    #[test]
+    fn simulation_writes_output_to_chosen_directory() {
+        let temp = TempDir::new();
+        let dir = &temp.0;
+        let output = fers_api::OutputLocation { basename: "trial".to_string(), ..temp.output() };
+        super::check_output_location(&output.directory, &output.basename)
+            .expect("temp directory should be usable");
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"monostatic": {"name": "rx", "cw_mode": {},
+                "waveform": "default", "antenna": "default", "timing": "default"}}"#,
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+        let outcome = context
+            .run_simulation(None, Some(&output), &AtomicBool::new(false))
+            .expect("simulation should run");
+        assert_eq!(outcome, fers_api::SimulationOutcome::Completed);
+
+        let files = context.list_output_files(Some(&output)).expect("files should list");
+        let expected = dir.join("trial_rx_results.h5");
+        assert_eq!(files, vec![expected.to_str().unwrap().to_string()]);
+        assert!(expected.exists());
+        assert_eq!(context.list_output_files(None).unwrap(), vec!["rx_results.h5".to_string()]);
+
+        let missing = dir.join("missing");
+        assert!(super::check_output_location(missing.to_str().unwrap(), "").is_err());
+        assert!(super::check_output_location(&output.directory, "a/b").is_err());
+        assert!(context
+            .run_simulation(
+                None,
+                Some(&fers_api::OutputLocation {
+                    directory: missing.to_str().unwrap().to_string(),
+                    basename: String::new(),
+                }),
+                &AtomicBool::new(false),
+            )
+            .is_err());
+    }
+
+    /// Verifies that the previewed phase noise is seeded and that its variance equals
+    /// the timing PSD integrated over the band the series resolves.
+    #[test]
+    fn phase_noise_preview_variance_matches_integrated_psd() {
+        let scenario = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"receiver": {"name": "rx", "noise_temp": 0.0,
+                "pulsed_mode": {"prf": 10.0, "window_skip": 0.0, "window_length": 0.001},
+                "antenna": "default", "timing": "default"}}"#,
+        )
+        .replace(
+            r#""timings": [{"name": "default", "frequency": 1e6}]"#,
+            r#""timings": [{"name": "default", "frequency": 1e6, "noise_entries": [
+                {"alpha": 0.0, "weight": 1e-3}, {"alpha": 2.0, "weight": 1e-4}]}]"#,
+        );
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&scenario).expect("scenario should load");
+
+        let series =
+            context.preview_receiver_phase_noise("rx", 1.0).expect("preview should generate");
+        assert_eq!(series.len(), 1000);
+        let repeat =
+            context.preview_receiver_phase_noise("rx", 1.0).expect("preview should generate");
+        assert_eq!(series, repeat);
+
+        // 1 Hz bins at 1 kHz: the series resolves 0.5 Hz up to 499.5 Hz.
+        let (low, high) = (0.5_f64, 499.5_f64);
+        let white = 1e-6 * (high - low);
+        let random_walk = 1e-8 * (1.0 / low - 1.0 / high);
+        let expected = white + random_walk;
+
+        let mean = series.iter().sum::<f64>() / series.len() as f64;
+        let variance = series.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / series.len() as f64;
+        assert!(
+            ((variance - expected) / expected).abs() < 1e-6,
+            "variance {variance} differs from integrated PSD {expected}"
+        );
+    }
+
+    /// Verifies that pulses from two transmitters at different ranges are reported as
+    /// colliding when their arrivals at the receiver are less than a pulse apart.
+    #[test]
+    fn pulse_collisions_report_overlapping_arrivals() {
+        let (_waveform, pulse) = pulse_waveform_json("pulse", 1e4, 1e9);
+
+        // 3 km and 60 km from the receiver: 10 us and 200 us one-way delays
+        let emitter = |name: &str, x: f64| {
+            format!(
+                r#"{{"name": "{name}-platform",
+                    "motionpath": {{"interpolation": "static",
+                        "positionwaypoints": [{{"time": 0.0, "x": {x:.1}, "y": 0.0, "altitude": 0.0}}]}},
+                    "fixedrotation": {{
+                        "startazimuth": 0.0, "startelevation": 0.0,
+                        "azimuthrate": 0.0, "elevationrate": 0.0
+                    }},
+                    "components": [{{"transmitter": {{"name": "{name}", "pulsed_mode": {{"prf": 10.0}},
+                        "waveform": "pulse", "antenna": "default", "timing": "default"}}}}]}}"#
+            )
+        };
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"receiver": {"name": "rx",
+                "pulsed_mode": {"prf": 10.0, "window_skip": 0.0, "window_length": 0.001},
+                "antenna": "default", "timing": "default"}}"#,
+        )
+        .replacen(
+            r#""platforms": ["#,
+            &format!(r#""platforms": [{}, {}, "#, emitter("near", 3000.0), emitter("far", 60000.0)),
+            1,
+        );
+        let json = with_waveforms(&json, &pulse);
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        // Both emit every 100 ms; each 0.4 ms pulse from the near transmitter overlaps the
+        // far one from 200 us until 410 us after emission.
+        let collisions = context
+            .calculate_pulse_collisions("rx", (0.0, 1.0))
+            .expect("collisions should compute");
+        assert_eq!(collisions.len(), 10);
+        let c = 299_792_458.0;
+        for (n, collision) in collisions.iter().enumerate() {
+            assert_eq!(collision.first_transmitter, "near");
+            assert_eq!(collision.second_transmitter, "far");
+            assert_eq!((collision.first_pulse_index, collision.second_pulse_index), (n, n));
+            let emitted = n as f64 * 0.1;
+            assert!((collision.overlap_start - (emitted + 60000.0 / c)).abs() < 1e-9);
+            assert!((collision.overlap_end - (emitted + 3000.0 / c + 4e-4)).abs() < 1e-9);
+        }
+
+        let windowed = context
+            .calculate_pulse_collisions("rx", (0.05, 0.15))
+            .expect("collisions should compute");
+        assert_eq!(windowed.len(), 1);
+        assert_eq!(windowed[0].first_pulse_index, 1);
+        assert!(context.calculate_pulse_collisions("rx", (0.5, 0.5)).is_err());
+    }
+
+    /// Verifies that disabling the HDF5 export survives the JSON and XML round-trips and
+    /// removes the receiver files from the run's outputs, and that a scenario which only
+    /// enables it still validates.
+    #[test]
+    fn h5_export_option_round_trips_and_validates() {
+        let receiver = r#"{"receiver": {"name": "rx",
+            "pulsed_mode": {"prf": 10.0, "window_skip": 0.0, "window_length": 0.001},
+            "antenna": "default", "timing": "default"}}"#;
+        let json = minimal_scenario_json(STATIC_MOTION, receiver).replacen(
+            r#""coordinatesystem": {"frame": "ENU"}"#,
+            r#""coordinatesystem": {"frame": "ENU"}, "export": {"h5": false}"#,
+            1,
+        );
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&json).expect("scenario should load");
+        assert!(context
+            .get_scenario_as_json()
+            .expect("scenario should serialize")
+            .contains(r#""h5": false"#));
+        assert!(context.list_output_files(None).expect("outputs should list").is_empty());
+
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(xml.contains(r#"<export h5="false"/>"#));
+        let reloaded = reload_via_xml(&context);
+        assert!(reloaded.list_output_files(None).expect("outputs should list").is_empty());
+
+        let enabled_xml = xml.replace(r#"<export h5="false"/>"#, r#"<export h5="true"/>"#);
+        let file = TempFile::new("xml");
+        std::fs::write(&file.0, &enabled_xml).expect("XML should be writable");
+        let validation = validate_xml_file(file.path().to_owned()).expect("validation should run");
+        assert!(validation.valid, "errors: {:?}", validation.errors);
+        let enabled = load_xml(&enabled_xml).expect("XML with only h5 enabled should load");
+        let outputs = enabled.list_output_files(None).expect("outputs should list");
+        assert_eq!(outputs, vec!["rx_results.h5".to_string()]);
+    }
+
+    /// Verifies that raising both antennas on a mount lengthens the bistatic range to a
+    /// ground-level target, and that the mount height survives the XML round-trip.
+    #[test]
+    fn mount_height_raises_antennas_and_round_trips() {
+        let scenario = |mount: &str| {
+            minimal_scenario_json(
+                STATIC_MOTION,
+                &format!(
+                    r#"{{"transmitter": {{"name": "tx", "cw_mode": {{}}, "waveform": "default",
+                        "antenna": "default", "timing": "default"{mount}}}}},
+                    {{"receiver": {{"name": "rx", "cw_mode": {{}}, "antenna": "default",
+                        "timing": "default"{mount}}}}}"#
+                ),
+            )
+            .replacen(
+                r#""platforms": ["#,
+                r#""platforms": [{"name": "scene",
+                    "motionpath": {"interpolation": "static", "positionwaypoints": [
+                        {"time": 0.0, "x": 0.0, "y": 1000.0, "altitude": 0.0}]},
+                    "fixedrotation": {"startazimuth": 0.0, "startelevation": 0.0,
+                        "azimuthrate": 0.0, "elevationrate": 0.0},
+                    "components": [{"target": {"name": "aircraft",
+                        "rcs": {"type": "isotropic", "value": 1.0}}}]}, "#,
+                1,
+            )
+        };
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&scenario("")).expect("scenario should load");
+        let ground_range = context
+            .calculate_phase_center_range("tx", "aircraft", "rx", 0.0)
+            .expect("range should compute");
+        assert!((ground_range - 2000.0).abs() < 1e-9);
+
+        // A 750 m mast turns each 1000 m leg into a 750-1000-1250 triangle
+        context
+            .update_scenario_from_json(&scenario(r#", "mount_height": 750.0"#))
+            .expect("scenario should load");
+        let mounted_range = context
+            .calculate_phase_center_range("tx", "aircraft", "rx", 0.0)
+            .expect("range should compute");
+        assert!((mounted_range - 2500.0).abs() < 1e-9);
+
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert_eq!(xml.matches("<mount_height>").count(), 2);
+        let reloaded = reload_via_xml(&context);
+        let json = reloaded.get_scenario_as_json().expect("scenario should serialize to JSON");
+        assert_eq!(json.matches(r#""mount_height": 750.0"#).count(), 2);
+        let reloaded_range = reloaded
+            .calculate_phase_center_range("tx", "aircraft", "rx", 0.0)
+            .expect("range should compute");
+        assert!((reloaded_range - 2500.0).abs() < 1e-9);
+    }
+
+    /// Verifies that a tilted multipath surface passes schema validation and that its
+    /// normal and offset survive the JSON-to-XML round-trip.
+    #[test]
+    fn multipath_surface_round_trips_through_xml() {
+        let receiver = r#"{"receiver": {"name": "rx", "cw_mode": {},
+            "antenna": "default", "timing": "default"}}"#;
+        let json = minimal_scenario_json(STATIC_MOTION, receiver).replacen(
+            r#""platforms": ["#,
+            r#""multipath": {"factor": 0.7, "nx": 0.0, "ny": 0.6, "nz": 0.8, "d": -12.5},
+                "platforms": ["#,
+            1,
+        );
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        for tag in ["<factor>0.7</factor>", "<ny>0.6</ny>", "<nz>0.8</nz>", "<d>-12.5</d>"] {
+            assert!(xml.contains(tag), "missing {tag} in:\n{xml}");
+        }
+        let file = TempFile::new("xml");
+        std::fs::write(&file.0, &xml).expect("XML should be writable");
+        let validation = validate_xml_file(file.path().to_owned()).expect("validation should run");
+        assert!(validation.valid, "errors: {:?}", validation.errors);
+        let reloaded = reload_via_xml(&context);
+        let json = reloaded.get_scenario_as_json().expect("scenario should serialize to JSON");
+        assert!(json.contains(r#""multipath""#));
+        for field in [r#""nx": 0.0"#, r#""ny": 0.6"#, r#""nz": 0.8"#, r#""d": -12.5"#] {
+            assert!(json.contains(field), "missing {field} in:\n{json}");
+        }
+    }
+
+    /// Verifies that a Swerling case supplies the chi-square `k` when it is omitted, that the
+    /// case and RCS sample rate survive the XML round-trip, and that cases outside 1-4 are rejected.
+    #[test]
+    fn swerling_case_sets_k_and_round_trips() {
+        let target = |model: &str| {
+            minimal_scenario_json(
+                STATIC_MOTION,
+                &format!(
+                    r#"{{"target": {{"name": "aircraft",
+                        "rcs": {{"type": "isotropic", "value": 1.0}}, "model": {model}}}}}"#
+                ),
+            )
+        };
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context
+            .update_scenario_from_json(&target(
+                r#"{"type": "chisquare", "swerling": 3, "sample_rate": 0.5}"#,
+            ))
+            .expect("scenario should load");
+
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        for tag in ["<k>2</k>", "<swerling>3</swerling>", "<sample_rate>0.5</sample_rate>"] {
+            assert!(xml.contains(tag), "missing {tag} in:\n{xml}");
+        }
+        let file = TempFile::new("xml");
+        std::fs::write(&file.0, &xml).expect("XML should be writable");
+        let validation = validate_xml_file(file.path().to_owned()).expect("validation should run");
+        assert!(validation.valid, "errors: {:?}", validation.errors);
+        let reloaded = reload_via_xml(&context);
+        let json = reloaded.get_scenario_as_json().expect("scenario should serialize to JSON");
+        for field in [r#""k": 2.0"#, r#""swerling": 3"#, r#""sample_rate": 0.5"#] {
+            assert!(json.contains(field), "missing {field} in:\n{json}");
+        }
+
+        context
+            .update_scenario_from_json(&target(r#"{"type": "chisquare", "k": 1.5}"#))
+            .expect("a model with only k should still load");
+        assert!(context
+            .update_scenario_from_json(&target(r#"{"type": "chisquare", "swerling": 5}"#))
+            .is_err());
+        assert!(context
+            .update_scenario_from_json(&target(r#"{"type": "chisquare", "k": 1.0, "swerling": 4}"#))
+            .is_err());
+    }
+
+    /// Verifies that a brief three-way overlap between sample points is found as the peak
+    /// emitter count, at the moment the last emitter switches on.
+    #[test]
+    fn peak_emitter_count_finds_brief_overlap() {
+        let emitter = |name: &str, start: f64, end: f64| {
+            format!(
+                r#"{{"transmitter": {{"name": "{name}", "cw_mode": {{}}, "waveform": "default",
+                    "antenna": "default", "timing": "default",
+                    "schedule": [{{"start": {start:?}, "end": {end:?}}}]}}}}"#
+            )
+        };
+        let components =
+            [emitter("tx1", 0.1, 0.6), emitter("tx2", 0.4, 0.9), emitter("tx3", 0.5501, 0.5503)]
+                .join(", ");
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context
+            .update_scenario_from_json(&minimal_scenario_json(STATIC_MOTION, &components))
+            .expect("scenario should load");
+
+        // With 11 points the samples fall at 0.5 and 0.6, on either side of tx3's burst
+        let (time, count) = context.calculate_peak_emitter_count(11).expect("peak should compute");
+        assert_eq!(count, 3);
+        assert!((time - 0.5501).abs() < 1e-12, "peak at {time}");
+        assert!(context.calculate_peak_emitter_count(1).is_err());
+    }
+
+    /// Builds a pulsed receiver component with 10 ms windows and the given blanking gates.
+    fn blanked_receiver(mode: &str, gates: &str) -> String {
+        format!(
+            r#"{{"receiver": {{"name": "rx", "noise_temp": 0.0, {mode},
+                "blanking_gates": {gates}, "antenna": "default", "timing": "default"}}}}"#
+        )
+    }
+
+    const BLANKED_PULSED_MODE: &str =
+        r#""pulsed_mode": {"prf": 10.0, "window_skip": 0.0, "window_length": 0.01}"#;
+
+    /// Verifies that blanking gates survive the XML round-trip and schema validation, and
+    /// that gates outside the receive window or on a CW receiver are rejected.
+    #[test]
+    fn blanking_gates_round_trip_and_validate() {
+        let gates = r#"[{"start": 0.002, "length": 0.003}]"#;
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context
+            .update_scenario_from_json(&minimal_scenario_json(
+                STATIC_MOTION,
+                &blanked_receiver(BLANKED_PULSED_MODE, gates),
+            ))
+            .expect("scenario should load");
+
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(xml.contains(r#"<gate start="0.002" length="0.003"/>"#), "{xml}");
+        let file = TempFile::new("xml");
+        std::fs::write(&file.0, &xml).expect("XML should be writable");
+        let validation = validate_xml_file(file.path().to_owned()).expect("validation should run");
+        assert!(validation.valid, "errors: {:?}", validation.errors);
+        let reloaded = reload_via_xml(&context);
+        let json = reloaded.get_scenario_as_json().expect("scenario should serialize to JSON");
+        assert!(json.contains(r#""blanking_gates""#));
+        assert!(json.contains(r#""start": 0.002"#) && json.contains(r#""length": 0.003"#));
+
+        let overlong = r#"[{"start": 0.008, "length": 0.003}]"#;
+        assert!(context
+            .update_scenario_from_json(&minimal_scenario_json(
+                STATIC_MOTION,
+                &blanked_receiver(BLANKED_PULSED_MODE, overlong),
+            ))
+            .is_err());
+        assert!(context
+            .update_scenario_from_json(&minimal_scenario_json(
+                STATIC_MOTION,
+                &blanked_receiver(r#""cw_mode": {}"#, gates),
+            ))
+            .is_err());
+    }
+
+    /// Verifies that every receive window records the absolute times its blanking gate
+    /// covered in the output metadata.
+    #[test]
+    fn blanked_intervals_are_marked_in_output() {
+        let dir = TempDir::new();
+        let output = fers_api::OutputLocation { basename: "blanked".to_string(), ..dir.output() };
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context
+            .update_scenario_from_json(&minimal_scenario_json(
+                STATIC_MOTION,
+                &blanked_receiver(BLANKED_PULSED_MODE, r#"[{"start": 0.002, "length": 0.003}]"#),
+            ))
+            .expect("scenario should load");
+        context
+            .run_simulation(None, Some(&output), &AtomicBool::new(false))
+            .expect("simulation should run");
+
+        let file = dir.0.join("blanked_rx_results.h5");
+        let intervals = fers_api::read_blanked_intervals(file.to_str().unwrap());
+        let unblanked =
+            fers_api::read_blanked_intervals(dir.0.join("missing.h5").to_str().unwrap());
+
+        let intervals = intervals.expect("output should be readable");
+        assert!(intervals.len() >= 9, "one interval per window, got {}", intervals.len());
+        for (window, (start, end)) in intervals.iter().enumerate() {
+            let opening = window as f64 * 0.1;
+            assert!((start - opening - 0.002).abs() < 1e-9, "window {window} starts at {start}");
+            assert!((end - start - 0.003).abs() < 1e-9, "window {window} ends at {end}");
+        }
+        assert!(unblanked.is_err());
+    }
+
+    /// Verifies that explicit waypoint velocities shape a cubic path and survive the XML
+    /// round-trip.
+    #[test]
+    fn waypoint_velocities_drive_cubic_path_and_round_trip() {
+        // Without velocities a two-waypoint cubic path is a straight line at 100 m/s; with
+        // the platform at rest at both ends it follows a smoothstep instead.
+        let motion = r#"{"interpolation": "cubic", "positionwaypoints": [
+            {"time": 0.0, "x": 0.0, "y": 0.0, "altitude": 0.0, "vx": 0.0, "vy": 0.0, "vz": 0.0},
+            {"time": 1.0, "x": 100.0, "y": 0.0, "altitude": 0.0, "vx": 0.0, "vy": 0.0, "vz": 0.0}]}"#;
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context
+            .update_scenario_from_json(&minimal_scenario_json(
+                motion,
+                r#"{"target": {"name": "tgt", "rcs": {"type": "isotropic", "value": 1.0}}}"#,
+            ))
+            .expect("scenario should load");
+
+        let check = |context: &fers_api::FersContext| {
+            let tracks = context.get_platform_tracks(5).expect("tracks should sample");
+            let points = &tracks[0].points;
+            assert!((points[1].x - 15.625).abs() < 1e-9, "x(0.25) = {}", points[1].x);
+            assert!((points[2].vx - 150.0).abs() < 1e-9, "vx(0.5) = {}", points[2].vx);
+            assert!(points[0].vx.abs() < 1e-9 && points[4].vx.abs() < 1e-9);
+        };
+        check(&context);
+
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(xml.contains("<vx>0</vx>"), "{xml}");
+        let file = TempFile::new("xml");
+        std::fs::write(&file.0, &xml).expect("XML should be writable");
+        let validation = validate_xml_file(file.path().to_owned()).expect("validation should run");
+        assert!(validation.valid, "errors: {:?}", validation.errors);
+        let reloaded = reload_via_xml(&context);
+        assert!(reloaded.get_scenario_as_json().unwrap().contains(r#""vx": 0.0"#));
+        check(&reloaded);
+    }
+
+    /// Verifies that a duplicated platform copies its path and components under fresh
+    /// names, that repeated copies are numbered, and that unknown platforms are rejected.
+    #[test]
+    fn duplicate_platform_copies_paths_and_components() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let motion = r#"{"interpolation": "linear", "positionwaypoints": [
+            {"time": 0.0, "x": 0.0, "y": 0.0, "altitude": 0.0},
+            {"time": 1.0, "x": 100.0, "y": 0.0, "altitude": 0.0}]}"#;
+        let json = minimal_scenario_json(
+            motion,
+            r#"{"receiver": {"name": "rx", "cw_mode": {},
+                "antenna": "default", "timing": "default"}}"#,
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let name = context.duplicate_platform("platform").expect("platform should duplicate");
+        assert_eq!(name, "platform (copy)");
+        let second = context.duplicate_platform("platform").expect("platform should duplicate");
+        assert_eq!(second, "platform (copy 2)");
+
+        let scenario = context.get_scenario_as_json().expect("scenario should serialize");
+        assert!(scenario.contains(r#""name": "platform (copy)""#));
+        assert!(scenario.contains(r#""name": "rx (copy)""#));
+        assert!(scenario.contains(r#""name": "rx (copy 2)""#));
+
+        let tracks = context.get_platform_tracks(2).expect("tracks should sample");
+        assert_eq!(tracks.len(), 3);
+        for track in &tracks {
+            assert!(
+                (track.points[1].x - 100.0).abs() < 1e-9,
+                "{} should follow the path",
+                track.name
+            );
+        }
+
+        let missing = context.duplicate_platform("nonexistent");
+        assert!(missing.unwrap_err().contains("nonexistent"));
+    }
+
+    /// Verifies that geodetic waypoints convert to the local ENU frame and back, and that
+    /// an unset or invalid origin is rejected.
+    #[test]
+    fn geodetic_waypoints_convert_to_local_and_back() {
+        let points = vec![
+            super::GeoPoint { time: 0.0, latitude: -33.9, longitude: 18.4, altitude: 100.0 },
+            super::GeoPoint { time: 5.0, latitude: -33.899, longitude: 18.401, altitude: 150.0 },
+        ];
+        let local = super::geodetic_to_local(Some(-33.9), Some(18.4), Some(100.0), points)
+            .expect("conversion should succeed");
+        assert_eq!(local.len(), 2);
+        assert!(
+            local[0].x.abs() < 1e-6 && local[0].y.abs() < 1e-6 && local[0].altitude.abs() < 1e-6
+        );
+        // 0.001 degrees is roughly 111 m north and 93 m east at this latitude.
+        assert_eq!(local[1].time, 5.0);
+        assert!((local[1].y - 110.9).abs() < 1.0, "north offset {}", local[1].y);
+        assert!((local[1].x - 92.5).abs() < 1.0, "east offset {}", local[1].x);
+
+        let back = super::local_to_geodetic(Some(-33.9), Some(18.4), Some(100.0), local)
+            .expect("inverse conversion should succeed");
+        assert!((back[1].latitude + 33.899).abs() < 1e-9);
+        assert!((back[1].longitude - 18.401).abs() < 1e-9);
+        assert!((back[1].altitude - 150.0).abs() < 1e-6);
+
+        let unset = super::geodetic_to_local(None, None, None, Vec::new());
+        assert!(unset.unwrap_err().contains("origin"));
+        assert!(super::geodetic_to_local(Some(95.0), Some(0.0), None, Vec::new()).is_err());
+    }
+
+    /// Verifies that a KMZ export is a valid ZIP archive whose first entry is the
+    /// scenario's `doc.kml`, and that locally referenced icons are embedded even when their
+    /// XML-escaped path differs from the file name.
+    #[test]
+    fn kmz_export_contains_doc_kml_and_local_icons() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context
+            .update_scenario_from_json(&minimal_scenario_json(
+                STATIC_MOTION,
+                r#"{"target": {"name": "tgt", "rcs": {"type": "isotropic", "value": 1.0}}}"#,
+            ))
+            .expect("scenario should load");
+        let path = TempFile::new("kmz");
+        let path_str = path.path();
+        super::kmz::generate_kmz(&context, path_str, None).expect("KMZ should generate");
+        let entries = super::kmz::read_zip(path_str);
+
+        let entries = entries.expect("KMZ should open as a ZIP archive");
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].0, "doc.kml");
+        let doc = String::from_utf8(entries[0].1.clone()).expect("doc.kml should be UTF-8");
+        assert!(doc.contains("<kml") && doc.contains("<name>platform</name>"));
+
+        // The icon name holds an ampersand, which the KML escapes
+        let icon = TempFile(unique_temp_path("_a&b.png"));
+        std::fs::write(&icon, [0x89, b'P', b'N', b'G']).unwrap();
+        let kml = format!(
+            "<kml><Style><IconStyle><Icon><href>{}</href></Icon></IconStyle></Style>\
+             <Style><IconStyle><Icon><href>https://example.com/a.png</href></Icon></IconStyle></Style></kml>",
+            icon.display().to_string().replace('&', "&amp;")
+        );
+        let result = super::kmz::write_kmz(&kml, path_str);
+        let entries = super::kmz::read_zip(path_str);
+
+        result.expect("KMZ should be written");
+        let entries = entries.expect("KMZ should open as a ZIP archive");
+        assert_eq!(entries.len(), 2);
+        let embedded = &entries[1].0;
+        assert!(embedded.starts_with("files/") && embedded.ends_with(".png"));
+        assert_eq!(entries[1].1, [0x89, b'P', b'N', b'G']);
+        let doc = String::from_utf8(entries[0].1.clone()).unwrap();
+        assert!(doc.contains(&format!("<href>{}</href>", embedded.replace('&', "&amp;"))));
+        assert!(doc.contains("<href>https://example.com/a.png</href>"));
+    }
+
+    /// Verifies that a KMZ export can be placed about another origin than the scenario's,
+    /// and that an origin outside the valid latitude range is rejected.
+    #[test]
+    fn kmz_export_uses_origin_override() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context
+            .update_scenario_from_json(&minimal_scenario_json(STATIC_MOTION, ""))
+            .expect("scenario should load");
+        let path = TempFile::new("kmz");
+
+        super::kmz::generate_kmz(&context, path.path(), Some((51.5, -0.1, 20.0)))
+            .expect("KMZ should generate");
+        let entries = super::kmz::read_zip(path.path()).expect("KMZ should open");
+        let doc = String::from_utf8(entries[0].1.clone()).expect("doc.kml should be UTF-8");
+        assert!(doc.contains("<latitude>51.5</latitude>"), "unexpected KML: {doc}");
+        assert!(doc.contains("<longitude>-0.1</longitude>"), "unexpected KML: {doc}");
+
+        let error = super::kmz::generate_kmz(&context, path.path(), Some((95.0, 0.0, 0.0)))
+            .expect_err("an out-of-range origin should be rejected");
+        assert!(error.contains("latitude"), "unexpected error: {error}");
+    }
+
+    /// Verifies the required integration time against `lambda / (2 * delta_v)` and that it
+    /// is flagged once it exceeds the receiver's dwell.
+    #[test]
+    fn required_integration_time_matches_analytic_formula() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context
+            .update_scenario_from_json(&minimal_scenario_json(
+                STATIC_MOTION,
+                r#"{"receiver": {"name": "rx", "cw_mode": {},
+                    "antenna": "default", "timing": "default"}}"#,
+            ))
+            .expect("scenario should load");
+
+        // At 10 GHz, lambda is about 3 cm, so 0.5 m/s takes lambda / (2 * 0.5) = 30 ms.
+        let report = context
+            .calculate_required_integration_time("rx", 0.5, 10e9)
+            .expect("integration time should compute");
+        let expected = 299_792_458.0 / 10e9 / (2.0 * 0.5);
+        assert!((report.required_time - expected).abs() < 1e-12);
+        assert!((report.available_dwell - 1.0).abs() < 1e-12);
+        assert!(report.sufficient);
+
+        // 1 cm/s needs 1.5 s, longer than the 1 s simulation.
+        let fine = context
+            .calculate_required_integration_time("rx", 0.01, 10e9)
+            .expect("integration time should compute");
+        assert!((fine.required_time - 1.49896229).abs() < 1e-8);
+        assert!(!fine.sufficient);
+
+        assert!(context.calculate_required_integration_time("rx", 0.0, 10e9).is_err());
+        assert!(context.calculate_required_integration_time("missing", 0.5, 10e9).is_err());
+    }
+
+    /// Verifies that a windowed preview starts and ends at the requested times, matches the
+    /// full-span path there including its velocities, and clamps the window to the waypoints.
+    #[test]
+    fn motion_path_preview_honours_time_window() {
+        let waypoints = || {
+            (0..=10)
+                .map(|t| {
+                    let time = f64::from(t);
+                    super::MotionWaypoint { time, x: time * time, y: 2.0 * time, altitude: 0.0 }
+                })
+                .collect::<Vec<_>>()
+        };
+        let preview = |t_start: Option<f64>, t_end: Option<f64>, num_points: usize| {
+            super::get_interpolated_motion_path(
+                waypoints(),
+                super::InterpolationType::Cubic,
+                num_points,
+                Some(super::PathPreviewOptions { t_start, t_end, ..Default::default() }),
+            )
+        };
+
+        // One sample per second over the full span, so index i is at t = i.
+        let full = preview(None, None, 11).expect("full path should interpolate");
+        let window = preview(Some(2.0), Some(6.0), 5).expect("window should interpolate");
+        assert_eq!(window.len(), 5);
+        for (sample, reference) in window.iter().zip(&full[2..=6]) {
+            assert!((sample.x - reference.x).abs() < 1e-9);
+            assert!((sample.y - reference.y).abs() < 1e-9);
+            assert!((sample.vx - reference.vx).abs() < 1e-9);
+            assert!((sample.vy - 2.0).abs() < 1e-9);
+        }
+
+        let clamped = preview(Some(-5.0), Some(4.0), 5).expect("window should be clamped");
+        assert!(clamped[0].x.abs() < 1e-9);
+        assert!((clamped[4].x - full[4].x).abs() < 1e-9);
+
+        let outside = preview(Some(20.0), Some(30.0), 5);
+        assert!(outside.unwrap_err().contains("does not overlap"));
+    }
+
+    /// Verifies that a single waypoint previews as a stationary path, that a cubic path
+    /// needs at least two waypoints, and that waypoints are sorted by time before
+    /// interpolation while duplicate timestamps are rejected.
+    #[test]
+    fn motion_path_preview_sorts_and_validates_waypoint_times() {
+        let wp = |time: f64, x: f64| super::MotionWaypoint { time, x, y: 0.0, altitude: 0.0 };
+
+        let single = fers_api::get_interpolated_motion_path(
+            vec![wp(2.0, 5.0)],
+            super::InterpolationType::Linear,
+            4,
+            &Default::default(),
+        )
+        .expect("a single waypoint should preview");
+        assert_eq!(single.len(), 4);
+        assert!(single.iter().all(|p| p.x == 5.0 && p.vx == 0.0));
+        let cubic = fers_api::get_interpolated_motion_path(
+            vec![wp(2.0, 5.0)],
+            super::InterpolationType::Cubic,
+            4,
+            &Default::default(),
+        );
+        assert!(cubic.unwrap_err().contains("at least two"));
+
+        let shuffled = fers_api::get_interpolated_motion_path(
+            vec![wp(2.0, 20.0), wp(0.0, 0.0), wp(1.0, 10.0)],
+            super::InterpolationType::Linear,
+            5,
+            &Default::default(),
+        )
+        .expect("out-of-order waypoints should preview");
+        let xs: Vec<f64> = shuffled.iter().map(|p| p.x).collect();
+        assert_eq!(xs, vec![0.0, 5.0, 10.0, 15.0, 20.0]);
+
+        let duplicated = fers_api::get_interpolated_motion_path(
+            vec![wp(0.0, 0.0), wp(1.0, 10.0), wp(1.0, 20.0)],
+            super::InterpolationType::Linear,
+            5,
+            &Default::default(),
+        );
+        assert!(duplicated.unwrap_err().contains("Waypoints 2 and 3"));
+    }
+
+    /// Verifies the velocity profile: zero for static paths, constant for a linear leg,
+    /// and the analytic derivative of a cubic path.
+    #[test]
+    fn velocity_profile_uses_analytic_path_derivatives() {
+        let wp = |time: f64, x: f64, y: f64| super::MotionWaypoint { time, x, y, altitude: 0.0 };
+        let legs = || vec![wp(0.0, 0.0, 0.0), wp(1.0, 30.0, 40.0), wp(2.0, 60.0, 80.0)];
+
+        let stationary =
+            super::get_interpolated_velocity_profile(legs(), super::InterpolationType::Static, 3)
+                .expect("static profile should compute");
+        assert_eq!(stationary, vec![0.0; 3]);
+
+        let linear =
+            super::get_interpolated_velocity_profile(legs(), super::InterpolationType::Linear, 5)
+                .expect("linear profile should compute");
+        assert!(linear.iter().all(|speed| (speed - 50.0).abs() < 1e-9), "{linear:?}");
+
+        // A natural cubic spline through evenly spaced collinear waypoints is the straight
+        // line, so its exact derivative is the constant 50 m/s; finite differences of a
+        // sampled path would deviate at the ends.
+        let cubic =
+            super::get_interpolated_velocity_profile(legs(), super::InterpolationType::Cubic, 7)
+                .expect("cubic profile should compute");
+        assert_eq!(cubic.len(), 7);
+        assert!(cubic.iter().all(|speed| (speed - 50.0).abs() < 1e-9), "{cubic:?}");
+
+        // Accelerating from rest: x = t^2 is matched by the spline only approximately, but
+        // the speed must rise monotonically.
+        let accelerating =
+            vec![wp(0.0, 0.0, 0.0), wp(1.0, 1.0, 0.0), wp(2.0, 4.0, 0.0), wp(3.0, 9.0, 0.0)];
+        let speeds = super::get_interpolated_velocity_profile(
+            accelerating,
+            super::InterpolationType::Cubic,
+            7,
+        )
+        .expect("cubic profile should compute");
+        assert!(speeds.windows(2).all(|pair| pair[1] > pair[0]), "{speeds:?}");
+    }
+
+    /// Verifies that a receiver window ending exactly at the next pulse passes, and that one
+    /// running past it is warned about with its PRI and overlap.
+    #[test]
+    fn receiver_window_longer_than_pri_is_warned() {
+        let scenario = |window_length: f64| {
+            minimal_scenario_json(
+                STATIC_MOTION,
+                &format!(
+                    r#"{{"receiver": {{"name": "rx",
+                        "pulsed_mode": {{"prf": 1000.0, "window_skip": 0.0002,
+                            "window_length": {window_length}}},
+                        "antenna": "default", "timing": "default"}}}}"#
+                ),
+            )
+        };
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&scenario(0.0008)).expect("scenario should load");
+        let boundary = context.validate_scenario().expect("validation should run");
+        assert!(boundary.valid);
+        assert!(boundary.warnings.is_empty(), "{:?}", boundary.warnings);
+
+        context.update_scenario_from_json(&scenario(0.0009)).expect("scenario should load");
+        let overlapping = context.validate_scenario().expect("validation should run");
+        assert!(overlapping.valid, "window overlap is a warning, not an error");
+        assert_eq!(overlapping.warnings.len(), 1);
+        let warning = &overlapping.warnings[0];
+        assert!(warning.starts_with("Receiver 'rx'"), "{warning}");
+        assert!(warning.contains("PRI of 0.001 s"), "{warning}");
+        assert!(warning.contains("listens 0.0001 s past"), "{warning}");
+    }
+
+    /// Verifies that a transmitter whose pulse length times PRF exceeds its 10% limit is
+    /// reported with its computed duty cycle, and that the limit round-trips through XML.
+    #[test]
+    fn duty_cycle_above_limit_is_reported() {
+        // Four samples at 1 kHz: a 4 ms pulse
+        let (_waveform, pulse) = pulse_waveform_json("pulse", 1e3, 1e9);
+
+        let scenario = |prf: f64| {
+            let json = minimal_scenario_json(
+                STATIC_MOTION,
+                &format!(
+                    r#"{{"transmitter": {{"name": "tx", "pulsed_mode": {{"prf": {prf}}},
+                        "max_duty_cycle": 0.1,
+                        "waveform": "pulse", "antenna": "default", "timing": "default"}}}}"#
+                ),
+            );
+            with_waveforms(&json, &pulse)
+        };
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&scenario(10.0)).expect("scenario should load");
+        let within = context.validate_scenario();
+        let xml = context.get_scenario_as_xml();
+        context.update_scenario_from_json(&scenario(50.0)).expect("scenario should load");
+        let exceeded = context.validate_scenario();
+
+        let within = within.expect("validation should run");
+        assert!(within.valid, "{:?}", within.errors);
+        assert!(xml
+            .expect("scenario should serialize to XML")
+            .contains("<max_duty_cycle>0.1</max_duty_cycle>"));
+
+        let exceeded = exceeded.expect("validation should run");
+        assert!(!exceeded.valid);
+        assert_eq!(
+            exceeded.errors,
+            vec!["Transmitter 'tx' has a duty cycle of 20%, above its 10% limit"]
+        );
+    }
+
+    /// Verifies that a target placed at the maximum detection range needs exactly the
+    /// configured transmit power to reach the threshold SNR, i.e. it sits at the threshold.
+    #[test]
+    fn max_detection_range_yields_threshold_snr() {
+        let snr_threshold_db = 13.0;
+        let scenario = |target_x: f64| {
+            minimal_scenario_json(
+                STATIC_MOTION,
+                r#"{"monostatic": {"name": "radar", "cw_mode": {}, "noise_temp": 290.0,
+                    "waveform": "default", "antenna": "default", "timing": "default"}}"#,
+            )
+            .replacen(
+                r#""platforms": ["#,
+                &format!(
+                    r#""platforms": [{{"name": "aircraft",
+                        "motionpath": {{"interpolation": "static", "positionwaypoints": [
+                            {{"time": 0.0, "x": {target_x}, "y": 0.0, "altitude": 0.0}}]}},
+                        "fixedrotation": {{"startazimuth": 0.0, "startelevation": 0.0,
+                            "azimuthrate": 0.0, "elevationrate": 0.0}},
+                        "components": [{{"target": {{"name": "aircraft",
+                            "rcs": {{"type": "isotropic", "value": 10.0}}}}}}]}}, "#
+                ),
+                1,
+            )
+        };
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&scenario(1000.0)).expect("scenario should load");
+        let range = context
+            .calculate_max_detection_range("radar", 10.0, snr_threshold_db)
+            .expect("detection range should compute");
+
+        let lambda = 299_792_458.0 / 1e9;
+        let noise = 1.380_650_3e-23 * 290.0 * (1000.0 / 2.0);
+        let received =
+            lambda * lambda * 10.0 / ((4.0 * std::f64::consts::PI).powi(3) * range.powi(4));
+        let snr_db = 10.0 * (received / noise).log10();
+        assert!((snr_db - snr_threshold_db).abs() < 1e-9, "got {snr_db} dB at {range} m");
+
+        // The default waveform transmits 1 W (0 dBW)
+        context.update_scenario_from_json(&scenario(range)).expect("scenario should load");
+        let power_dbw = context
+            .calculate_required_tx_power("radar", "aircraft", "radar", snr_threshold_db, 0.0)
+            .expect("required power should compute");
+        assert!(power_dbw.abs() < 1e-9, "target at {range} m needs {power_dbw} dBW");
+
+        assert!(context.calculate_max_detection_range("radar", 0.0, snr_threshold_db).is_err());
+        assert!(context.calculate_max_detection_range("missing", 10.0, snr_threshold_db).is_err());
+    }
+
+    /// Verifies that a file-based waveform keeps its filename through an XML export and
+    /// re-import, and that a `<pulsed_from_file>` without a filename is rejected by name.
+    #[test]
+    fn pulse_file_waveform_keeps_filename_and_requires_one() {
+        let (waveform, chirp) = pulse_waveform_json("chirp", 1e3, 1e9);
+        let filename = waveform.path().replace('\\', "/");
+        let json = with_waveforms(&minimal_scenario_json(STATIC_MOTION, ""), &chirp);
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&json).expect("scenario should load");
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        let element = format!(r#"<pulsed_from_file filename="{filename}"/>"#);
+        assert!(xml.contains(&element), "missing {element} in {xml}");
+
+        let reloaded = reload_via_xml(&context);
+        let round_trip =
+            reloaded.get_scenario_as_json().expect("scenario should serialize to JSON");
+        assert!(round_trip.contains(&format!(r#""filename": "{filename}""#)), "{round_trip}");
+        let unnamed = load_xml(&xml.replacen(&element, r#"<pulsed_from_file filename=""/>"#, 1));
+        let error = unnamed.err().expect("a pulse file without a filename should be rejected");
+        assert!(error.contains("Waveform 'chirp'") && error.contains("no filename"), "{error}");
+    }
+
+    /// Verifies that a short inline waveform survives JSON and XML round trips with its
+    /// samples and rate, and that `validate_inline_waveform` rejects undecodable input.
+    #[test]
+    fn inline_waveform_round_trips_and_validates() {
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"transmitter": {"name": "tx", "pulsed_mode": {"prf": 10.0},
+                "waveform": "custom", "antenna": "default", "timing": "default"}}"#,
+        )
+        .replacen(
+            r#""waveforms": ["#,
+            r#""waveforms": [{"name": "custom", "power": 2.0, "carrier_frequency": 1e9,
+                "inline_samples": {"rate": 1000.0, "samples": "1,0, 0.5,-0.5, 0,1, -0.25,0"}}, "#,
+            1,
+        );
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&json).expect("inline waveform should load");
+        let expected = r#""samples": "1,0, 0.5,-0.5, 0,1, -0.25,0""#;
+        let round_trip = context.get_scenario_as_json().expect("scenario should serialize");
+        assert!(round_trip.contains(expected), "{round_trip}");
+        assert!(round_trip.contains(r#""rate": 1000.0"#));
+
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(
+            xml.contains(
+                r#"<inline_samples rate="1000">1,0, 0.5,-0.5, 0,1, -0.25,0</inline_samples>"#
+            ),
+            "{xml}"
+        );
+        let reloaded = reload_via_xml(&context);
+        let reloaded_json = reloaded.get_scenario_as_json().expect("scenario should serialize");
+        assert!(reloaded_json.contains(expected), "{reloaded_json}");
+
+        let inline = |samples: &str, rate: f64| {
+            super::validate_inline_waveform(super::InlineWaveform {
+                samples: samples.to_string(),
+                rate,
+            })
+        };
+        assert_eq!(inline("1,0, 0,1,", 1e6), Ok(2));
+        assert!(inline("1,0, 0", 1e6).unwrap_err().contains("got 3 values"));
+        assert!(inline("1,0, x,1", 1e6).unwrap_err().contains("value 3 ('x')"));
+        assert!(inline("", 1e6).is_err());
+        assert!(inline("1,0", 0.0).unwrap_err().contains("must be positive"));
+    }
+
+    /// Verifies that isotropic and file-based antennas keep their pattern, filename and
+    /// efficiency through XML export and re-import.
+    #[test]
+    fn isotropic_and_file_antennas_round_trip_through_xml() {
+        let pat_path = TempFile::new("pat");
+        std::fs::write(
+            &pat_path,
+            "'Round trip panel', 0, 10.0\n999\n1, 4\n0,\n0, 0\n90, -3\n180, -20\n270, -6\n\
+             1, 3\n0,\n-90, -10\n0, 0\n90, -10\n",
+        )
+        .expect("pattern file should be written");
+        let filename = pat_path.to_string_lossy().replace('\\', "/");
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(STATIC_MOTION, "").replacen(
+            r#""antennas": ["#,
+            &format!(
+                r#""antennas": [{{"name": "omni", "pattern": "isotropic", "efficiency": 0.5}},
+                    {{"name": "panel", "pattern": "file", "filename": "{filename}"}}, "#
+            ),
+            1,
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(xml.contains(r#"name="omni" pattern="isotropic""#));
+        assert!(xml.contains(&format!(r#"pattern="file" filename="{filename}""#)));
+
+        let reloaded = reload_via_xml(&context);
+        let pattern = reloaded.get_antenna_pattern("panel", 361, 181, 1e9);
+        assert!((pattern.expect("pattern should sample").max_gain - 10.0).abs() < 1e-9);
+
+        let round_trip = reloaded.get_scenario_as_json().expect("scenario should serialize");
+        assert!(round_trip.contains(r#""pattern": "isotropic""#));
+        assert!(round_trip.contains(r#""efficiency": 0.5"#));
+        assert!(round_trip.contains(&format!(r#""filename": "{filename}""#)));
+    }
+
+    /// Verifies the resolution cell of a monostatic radar with a Gaussian beam looking at a
+    /// target 1 km along its boresight, against the hand-computed beamwidths and bandwidth.
+    #[test]
+    fn resolution_cell_matches_hand_calculation() {
+        let (_waveform, pulse) = pulse_waveform_json("pulse", 1e7, 1e10);
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"monostatic": {"name": "radar", "pulsed_mode": {"prf": 10.0},
+                "waveform": "pulse", "antenna": "beam", "timing": "default"}}"#,
+        )
+        .replacen(
+            r#""antennas": ["#,
+            r#""antennas": [{"name": "beam", "pattern": "gaussian",
+                "azscale": 100.0, "elscale": 400.0}, "#,
+            1,
+        )
+        .replacen(
+            r#""platforms": ["#,
+            r#""platforms": [{"name": "scene",
+                "motionpath": {"interpolation": "static", "positionwaypoints": [
+                    {"time": 0.0, "x": 0.0, "y": 1000.0, "altitude": 0.0}]},
+                "fixedrotation": {"startazimuth": 0.0, "startelevation": 0.0,
+                    "azimuthrate": 0.0, "elevationrate": 0.0},
+                "components": [{"target": {"name": "scatterer",
+                    "rcs": {"type": "isotropic", "value": 1.0}}}]}, "#,
+            1,
+        );
+        let json = with_waveforms(&json, &pulse);
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let cell = context
+            .compute_resolution_cell("radar", "scatterer", "radar", 0.0)
+            .expect("resolution cell should compute");
+
+        // A Gaussian pattern exp(-scale * theta^2) halves at theta = sqrt(ln 2 / scale).
+        let beamwidth = |scale: f64| 2.0 * (std::f64::consts::LN_2 / scale).sqrt();
+        let range_resolution = 299_792_458.0 / 2e7;
+        let azimuth_extent = 1000.0 * beamwidth(100.0);
+        let elevation_extent = 1000.0 * beamwidth(400.0);
+        assert!((cell.range_resolution - range_resolution).abs() < 1e-9);
+        assert!((cell.azimuth_extent - azimuth_extent).abs() < 1e-6);
+        assert!((cell.elevation_extent - elevation_extent).abs() < 1e-6);
+        let volume = range_resolution * azimuth_extent * elevation_extent;
+        assert!((cell.volume - volume).abs() < 1e-6 * volume);
+    }
+
+    /// Verifies that undo and redo step through scenario updates, that a new update
+    /// discards the redo branch, that the depth bounds the history, and that undo with an
+    /// empty history returns the current scenario unchanged.
+    #[test]
+    fn undo_and_redo_step_through_scenario_updates() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let scenario = |name: &str| {
+            minimal_scenario_json(STATIC_MOTION, "").replacen(
+                r#""name": "test""#,
+                &format!(r#""name": "{name}""#),
+                1,
+            )
+        };
+        let named = |json: &str, name: &str| json.contains(&format!(r#""name": "{name}""#));
+
+        context.update_scenario_from_json(&scenario("first")).expect("scenario should load");
+        context.set_history_depth(2).expect("depth should be set");
+        context.update_scenario_from_json(&scenario("second")).expect("scenario should load");
+        context.update_scenario_from_json(&scenario("third")).expect("scenario should load");
+
+        assert!(named(&context.undo_scenario().expect("undo should succeed"), "second"));
+        assert!(named(&context.undo_scenario().expect("undo should succeed"), "first"));
+        assert!(named(&context.redo_scenario().expect("redo should succeed"), "second"));
+
+        // A new update truncates the redo branch
+        context.update_scenario_from_json(&scenario("fourth")).expect("scenario should load");
+        assert!(named(&context.redo_scenario().expect("redo should be a no-op"), "fourth"));
+
+        // Only two states are kept, so the third undo has nothing left to restore
+        assert!(named(&context.undo_scenario().expect("undo should succeed"), "second"));
+        assert!(named(&context.undo_scenario().expect("undo should succeed"), "first"));
+        let unchanged = context.undo_scenario().expect("undo should be a no-op");
+        assert!(named(&unchanged, "first"));
+        assert!(named(&context.get_scenario_as_json().expect("scenario should export"), "first"));
+    }
+
+    /// Verifies that reverting a redo that trimmed the history at capacity restores the
+    /// trimmed baseline, so the history is exactly as it was before the redo.
+    #[test]
+    fn reverting_redo_at_capacity_keeps_baseline() {
+        let mut history = super::history::ScenarioHistory::new(2);
+        history.record("first".to_string());
+        history.record("second".to_string());
+        assert_eq!(history.undo("third".to_string()).as_deref(), Some("second"));
+
+        // With one state kept, redoing pushes "second" back and trims "first"
+        history.set_depth(1);
+        let restored = history.redo("second".to_string()).expect("redo should step forward");
+        assert_eq!(restored, "third");
+        history.revert(restored, false);
+
+        assert_eq!(history.undo("second".to_string()).as_deref(), Some("first"));
+        assert_eq!(history.redo("first".to_string()).as_deref(), Some("second"));
+        assert_eq!(history.redo("second".to_string()).as_deref(), Some("third"));
+    }
+
+    /// Verifies that duplicating a platform is recorded as its own undo step, so undo
+    /// removes only the duplicate and keeps the edit before it.
+    #[test]
+    fn undo_after_duplicate_platform_removes_only_the_duplicate() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let scenario = minimal_scenario_json(STATIC_MOTION, "");
+        context.update_scenario_from_json(&scenario).expect("scenario should load");
+        context
+            .update_scenario_from_json(&scenario.replacen(
+                r#""name": "test""#,
+                r#""name": "edited""#,
+                1,
+            ))
+            .expect("edit should load");
+
+        let copy = context.duplicate_platform("platform").expect("platform should duplicate");
+        assert!(context.get_scenario_as_json().unwrap().contains(&copy));
+
+        let restored = context.undo_scenario().expect("undo should succeed");
+        assert!(!restored.contains(&copy));
+        assert!(restored.contains(r#""name": "edited""#));
+        assert!(restored.contains(r#""name": "platform""#));
+        let redone = context.redo_scenario().expect("redo should succeed");
+        assert!(redone.contains(&copy));
+    }
+
+    /// Verifies that merging a scenario file appends its assets with de-duplicated names,
+    /// points imported components at the renamed assets, keeps the existing scenario and
+    /// can be undone.
+    #[test]
+    fn merge_appends_assets_with_unique_names() {
+        let library = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"transmitter": {"name": "beacon", "cw_mode": {},
+                "waveform": "default", "antenna": "dish", "timing": "default"}}"#,
+        )
+        .replacen(
+            r#""antennas": ["#,
+            r#""antennas": [{"name": "dish", "pattern": "parabolic", "diameter": 2.0}, "#,
+            1,
+        );
+        library.update_scenario_from_json(&json).expect("library should load");
+        let xml = library.get_scenario_as_xml().expect("library should serialize to XML");
+        let path = TempFile::new("xml");
+        std::fs::write(&path, &xml).expect("XML should be writable");
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let current = minimal_scenario_json(STATIC_MOTION, "").replacen(
+            r#""endtime": 1.0"#,
+            r#""endtime": 5.0"#,
+            1,
+        );
+        context.update_scenario_from_json(&current).expect("scenario should load");
+        let assets_only = context.merge_scenario_from_xml_file(path.to_str().unwrap(), false);
+        let with_platforms = context.merge_scenario_from_xml_file(path.to_str().unwrap(), true);
+
+        let summary = assets_only.expect("assets should merge");
+        assert_eq!(
+            (summary.waveforms, summary.timings, summary.antennas, summary.platforms),
+            (1, 1, 2, 0)
+        );
+        let summary = with_platforms.expect("assets and platforms should merge");
+        assert_eq!(summary.platforms, 1);
+
+        let merged = context.get_scenario_as_json().expect("scenario should serialize");
+        assert!(merged.contains(r#""endtime": 5.0"#));
+        for name in ["default (2)", "default (3)", "dish", "dish (2)", "platform (2)", "beacon"] {
+            assert!(merged.contains(&format!(r#""name": "{name}""#)), "missing {name}");
+        }
+        // The second merge's transmitter uses the second set of imported assets
+        assert!(merged.contains(r#""antenna": "dish (2)""#));
+        assert!(merged.contains(r#""waveform": "default (3)""#));
+
+        // Each merge is its own undo step
+        let undone = context.undo_scenario().expect("undo should succeed");
+        assert!(!undone.contains(r#""name": "beacon""#));
+        assert!(undone.contains(r#""name": "dish""#));
+    }
+
+    /// Verifies that a checksummed XML export loads without a checksum warning, and that
+    /// editing the file afterwards is reported as a mismatch.
+    #[test]
+    fn xml_checksum_validates_and_detects_tampering() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context
+            .update_scenario_from_json(&minimal_scenario_json(STATIC_MOTION, ""))
+            .expect("scenario should load");
+        let xml = context.get_scenario_as_xml_with_checksum().expect("XML should export");
+        assert!(xml.trim_end().ends_with("-->"));
+        assert!(xml.contains("<!-- fers-checksum sha256:"));
+
+        let check = |xml: &str| {
+            let path = TempFile::new("xml");
+            std::fs::write(&path, xml).expect("XML should be writable");
+            fers_api::validate_xml_file(path.path()).expect("validation should run")
+        };
+        let mismatch = |result: &fers_api::ValidationResult| {
+            result.warnings.iter().any(|w| w.contains("Checksum") && w.contains("does not match"))
+        };
+
+        let intact = check(&xml);
+        assert!(intact.valid);
+        assert!(!mismatch(&intact));
+
+        let edited = xml.replacen(r#"name="test""#, r#"name="edited""#, 1);
+        assert_ne!(edited, xml);
+        let tampered = check(&edited);
+        assert!(tampered.valid);
+        assert!(mismatch(&tampered));
+    }
+
+    /// Verifies the clutter patch of a radar 100 m above the ground at a 10 km range,
+    /// where the 4 µs pulse is far shorter than the beam footprint, against
+    /// `(cτ/2) (R θ_az) / cos ψ`.
+    #[test]
+    fn pulse_limited_clutter_cell_matches_analytic_area() {
+        let (_waveform, pulse) = pulse_waveform_json("pulse", 1e6, 1e10);
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            r#"{"interpolation": "static",
+                "positionwaypoints": [{"time": 0.0, "x": 0.0, "y": 0.0, "altitude": 100.0}]}"#,
+            r#"{"monostatic": {"name": "radar", "pulsed_mode": {"prf": 1000.0},
+                "waveform": "pulse", "antenna": "beam", "timing": "default"}}"#,
+        )
+        .replacen(
+            r#""antennas": ["#,
+            r#""antennas": [{"name": "beam", "pattern": "gaussian",
+                "azscale": 400.0, "elscale": 100.0}, "#,
+            1,
+        );
+        let json = with_waveforms(&json, &pulse);
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let range = 10_000.0;
+        let area = context
+            .compute_clutter_cell_area("radar", "radar", range, 0.0)
+            .expect("clutter cell area should compute");
+
+        let tau = 4e-6;
+        let grazing = (100.0f64 / range).asin();
+        let azimuth_width = 2.0 * (std::f64::consts::LN_2 / 400.0).sqrt();
+        let expected = (299_792_458.0 * tau / 2.0) * (range * azimuth_width) / grazing.cos();
+        assert!((area - expected).abs() < 1e-6 * expected);
+
+        assert!(context.compute_clutter_cell_area("radar", "radar", 50.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn validated_xml_export_declares_the_dtd() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context
+            .update_scenario_from_json(&minimal_scenario_json(STATIC_MOTION, ""))
+            .expect("scenario should load");
+
+        let xml = context.get_scenario_as_xml_validated().expect("exported XML should be valid");
+        assert!(xml.contains(r#"<!DOCTYPE simulation SYSTEM "fers-xml.dtd">"#));
+        assert_eq!(xml, context.get_scenario_as_xml().expect("plain export"));
+    }
+
+    /// Verifies that a debug trace is parsed row by row, with an empty target column
+    /// read as the direct path, and that a malformed row is rejected.
+    #[test]
+    fn debug_trace_file_is_parsed() {
+        let path = TempFile::new("csv");
+        std::fs::write(
+            &path,
+            "pulse_index,transmitter,receiver,target,emission_time,delay,power_gain,phase,doppler\n\
+             0,tx,rx,,0,3.33564095198e-06,1e-06,-0.5,0\n\
+             0,tx,rx,plane,0,6.67128190396e-06,1e-12,1.25,-200\n",
+        )
+        .expect("trace should be written");
+        let trace = fers_api::read_debug_trace(path.to_str().unwrap());
+        std::fs::write(
+            &path,
+            "pulse_index,transmitter,receiver,target,emission_time,delay,power_gain,phase,doppler\n\
+             0,tx,rx,,0,not-a-number,1,0,0\n",
+        )
+        .expect("trace should be written");
+        let malformed = fers_api::read_debug_trace(path.to_str().unwrap());
+
+        let records = trace.expect("trace should parse").records;
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].target, None);
+        assert_eq!(records[1].target.as_deref(), Some("plane"));
+        assert_eq!(records[1].pulse_index, 0);
+        assert!((records[1].delay - 6.67128190396e-06).abs() < 1e-18);
+        assert_eq!(records[1].doppler, -200.0);
+        assert!(malformed.unwrap_err().contains("row 2"));
+    }
+
+    /// Verifies that a platform subset keeps only the named platforms and the assets they
+    /// use, rejects selections that cannot be simulated, and leaves the scenario unchanged.
+    #[test]
+    fn platform_subset_keeps_referenced_assets_only() {
+        let platform = |name: &str, x: f64, component: &str| {
+            format!(
+                r#"{{"name": "{name}",
+                    "motionpath": {{"interpolation": "static", "positionwaypoints": [
+                        {{"time": 0.0, "x": {x:.1}, "y": 0.0, "altitude": 0.0}}]}},
+                    "fixedrotation": {{
+                        "startazimuth": 0.0, "startelevation": 0.0,
+                        "azimuthrate": 0.0, "elevationrate": 0.0
+                    }},
+                    "components": [{component}]}}"#
+            )
+        };
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"transmitter": {"name": "beacon", "cw_mode": {},
+                "waveform": "default", "antenna": "dish", "timing": "default"}}"#,
+        )
+        .replacen(
+            r#""antennas": ["#,
+            r#""antennas": [{"name": "dish", "pattern": "parabolic", "diameter": 2.0}, "#,
+            1,
+        )
+        .replacen(
+            r#""waveforms": ["#,
+            r#""waveforms": [{"name": "tone", "power": 5.0, "carrier_frequency": 2e9, "cw": {}}, "#,
+            1,
+        )
+        .replacen(
+            r#""platforms": ["#,
+            &format!(
+                r#""platforms": [{}, {}, "#,
+                platform(
+                    "station",
+                    1000.0,
+                    r#"{"receiver": {"name": "rx", "cw_mode": {},
+                        "antenna": "default", "timing": "default"}}"#
+                ),
+                platform(
+                    "jammer",
+                    2000.0,
+                    r#"{"transmitter": {"name": "noise", "cw_mode": {},
+                        "waveform": "tone", "antenna": "default", "timing": "default"}}"#
+                )
+            ),
+            1,
+        );
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&json).expect("scenario should load");
+        let before = context.get_scenario_as_json().expect("scenario should serialize");
+
+        let subset = context
+            .subset(&["platform".to_string(), "station".to_string()])
+            .expect("transmitter and receiver platforms should form a subset");
+        let subset_json = subset.get_scenario_as_json().expect("subset should serialize");
+        for name in ["platform", "station", "beacon", "rx", "dish", "default"] {
+            assert!(subset_json.contains(&format!(r#""name": "{name}""#)), "missing {name}");
+        }
+        for name in ["jammer", "noise", "tone"] {
+            assert!(!subset_json.contains(&format!(r#""name": "{name}""#)), "kept {name}");
+        }
+
+        let no_receiver = context.subset(&["platform".to_string(), "jammer".to_string()]);
+        assert!(no_receiver.err().expect("subset without receiver").contains("no receiver"));
+        assert!(context.subset(&["missing".to_string()]).is_err());
+        assert_eq!(context.get_scenario_as_json().expect("scenario should serialize"), before);
+    }
+
+    /// Verifies that the beamwidths of a Gaussian pattern `exp(-scale * theta^2)` are
+    /// `2 * sqrt(ln 2 / scale)` in each plane, and that an isotropic antenna has none.
+    #[test]
+    fn gaussian_beamwidth_matches_analytic_half_power_width() {
+        let antenna = |pattern| super::Antenna {
+            name: None,
+            efficiency: None,
+            design_frequency: None,
+            pattern,
+        };
+        let beam = antenna(super::AntennaPattern::Gaussian { azscale: 100.0, elscale: 400.0 });
+
+        let (azimuth, elevation) =
+            super::compute_beamwidth(beam, 1e9).expect("beamwidth should compute");
+        let width = |scale: f64| 2.0 * (std::f64::consts::LN_2 / scale).sqrt().to_degrees();
+        assert!((azimuth - width(100.0)).abs() < 1e-6, "azimuth beamwidth was {azimuth}");
+        assert!((elevation - width(400.0)).abs() < 1e-6, "elevation beamwidth was {elevation}");
+
+        let isotropic = super::compute_beamwidth(antenna(super::AntennaPattern::Isotropic), 1e9);
+        assert!(isotropic.err().expect("isotropic has no main lobe").contains("main lobe"));
+    }
+
+    /// Verifies the link budget of a CW monostatic radar against the radar equation, with
+    /// every noise-bandwidth sample of the one-second span integrated coherently.
+    #[test]
+    fn cw_link_budget_matches_radar_equation() {
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"monostatic": {"name": "radar", "cw_mode": {}, "noise_temp": 290.0,
+                "waveform": "default", "antenna": "default", "timing": "default"}}"#,
+        )
+        .replacen(
+            r#""platforms": ["#,
+            r#""platforms": [{"name": "aircraft",
+                "motionpath": {"interpolation": "static", "positionwaypoints": [
+                    {"time": 0.0, "x": 1000.0, "y": 0.0, "altitude": 0.0}]},
+                "fixedrotation": {"startazimuth": 0.0, "startelevation": 0.0,
+                    "azimuthrate": 0.0, "elevationrate": 0.0},
+                "components": [{"target": {"name": "aircraft",
+                    "rcs": {"type": "isotropic", "value": 10.0}}}]}, "#,
+            1,
+        );
+        context.update_scenario_from_json(&json).expect("scenario should load");
+
+        let range = 5000.0;
+        let budget = context
+            .calculate_link_budget("radar", "radar", "aircraft", range)
+            .expect("link budget should compute");
+
+        let lambda = 299_792_458.0 / 1e9;
+        let noise_bandwidth = 1000.0 / 2.0;
+        let received =
+            lambda * lambda * 10.0 / ((4.0 * std::f64::consts::PI).powi(3) * range.powi(4));
+        let noise = 1.380_650_3e-23 * 290.0 * noise_bandwidth;
+        assert!((budget.received_power_dbw - 10.0 * received.log10()).abs() < 1e-9);
+        assert!((budget.noise_power_dbw - 10.0 * noise.log10()).abs() < 1e-9);
+        assert!((budget.snr_db - 10.0 * (received / noise).log10()).abs() < 1e-9);
+        assert!((budget.integration_gain_db - 10.0 * noise_bandwidth.log10()).abs() < 1e-9);
+        let sum =
+            budget.transmit_power_dbw + budget.tx_gain_dbi + budget.rx_gain_dbi + budget.rcs_dbsm
+                - budget.path_loss_db;
+        assert!((sum - budget.received_power_dbw).abs() < 1e-9);
+
+        assert!(context.calculate_link_budget("radar", "radar", "missing", range).is_err());
+        assert!(context.calculate_link_budget("radar", "radar", "aircraft", 0.0).is_err());
+    }
+
+    /// Verifies that the previewed echo of a 4-sample pulse off a target 15 km from a
+    /// monostatic radar starts at the first sample after the round-trip time `2R/c`, lasts
+    /// the pulse length, and has the amplitude of the radar equation.
+    #[test]
+    fn received_pulse_delay_matches_round_trip_time() {
+        let (_waveform, pulse) = pulse_waveform_json("pulse", 1e6, 1e9);
+
+        let range = 15_000.0;
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"monostatic": {"name": "radar", "prf": 1000.0,
+                "waveform": "pulse", "antenna": "default", "timing": "default"}}"#,
+        )
+        .replacen(r#""rate": 1000.0"#, r#""rate": 1000000.0"#, 1)
+        .replacen(
+            r#""platforms": ["#,
+            &format!(
+                r#""platforms": [{{"name": "aircraft",
+                    "motionpath": {{"interpolation": "static", "positionwaypoints": [
+                        {{"time": 0.0, "x": {range}, "y": 0.0, "altitude": 0.0}}]}},
+                    "fixedrotation": {{"startazimuth": 0.0, "startelevation": 0.0,
+                        "azimuthrate": 0.0, "elevationrate": 0.0}},
+                    "components": [{{"target": {{"name": "aircraft",
+                        "rcs": {{"type": "isotropic", "value": 10.0}}}}}}]}}, "#
+            ),
+            1,
+        );
+        let json = with_waveforms(&json, &pulse);
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&json).expect("scenario should load");
+        let samples = context
+            .preview_received_pulse("radar", "aircraft", "radar", 0.0, 200)
+            .expect("echo should be computed");
+        assert!(context.preview_received_pulse("radar", "missing", "radar", 0.0, 200).is_err());
+
+        let magnitude = |[i, q]: [f64; 2]| i.hypot(q);
+        let first = (2.0 * range / 299_792_458.0 * 1e6_f64).ceil() as usize;
+        assert_eq!(samples.len(), 200);
+        assert!(samples[..first].iter().all(|&s| magnitude(s) == 0.0));
+        assert!(samples[first + 4..].iter().all(|&s| magnitude(s) == 0.0));
+
+        let lambda = 299_792_458.0 / 1e9;
+        let received =
+            lambda * lambda * 10.0 / ((4.0 * std::f64::consts::PI).powi(3) * range.powi(4));
+        for &sample in &samples[first..first + 4] {
+            assert!((magnitude(sample) / received.sqrt() - 1.0).abs() < 1e-9);
+        }
+    }
+
+    /// Loads a pulsed monostatic radar at 1 GHz whose receiver has two elements half a
+    /// wavelength apart along the body y axis, with `array` as the component JSON field.
+    /// The returned pulse file must outlive any reload of the scenario.
+    fn beamforming_context(array: &str) -> (TempFile, fers_api::FersContext) {
+        let (waveform, pulse) = pulse_waveform_json("pulse", 1e6, 1e9);
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        let json = minimal_scenario_json(
+            STATIC_MOTION,
+            &format!(
+                r#"{{"monostatic": {{"name": "radar",
+                    "pulsed_mode": {{"prf": 1000.0, "window_skip": 0.0, "window_length": 0.0005}},
+                    {array}"waveform": "pulse", "antenna": "default", "timing": "default"}}}}"#
+            ),
+        );
+        let json = with_waveforms(&json, &pulse);
+        context.update_scenario_from_json(&json).expect("scenario should load");
+        (waveform, context)
+    }
+
+    /// Verifies that a receiver element array survives the JSON and XML round-trips, and that
+    /// non-numeric or non-finite element coordinates are rejected.
+    #[test]
+    fn receiver_element_array_round_trips() {
+        let (_waveform, context) = beamforming_context(
+            r#""element_array": [{"x": 0.0, "y": 0.0, "z": 0.0}, {"x": 0.0, "y": 0.15, "z": 0.0}],"#,
+        );
+
+        let round_trip = context.get_scenario_as_json().expect("scenario should serialize");
+        assert!(round_trip.contains(r#""element_array""#));
+        let xml = context.get_scenario_as_xml().expect("scenario should serialize to XML");
+        assert!(xml.contains("<element_array>"));
+        assert_eq!(xml.matches("<element ").count(), 2);
+
+        let reloaded = reload_via_xml(&context);
+        let weights = reloaded
+            .preview_beamforming_weights("radar", 0.0, 0.0)
+            .expect("reloaded array should have weights");
+        assert_eq!(weights.len(), 2);
+
+        let malformed = xml.replacen(r#"y="0.15""#, r#"y="wide""#, 1);
+        assert_ne!(malformed, xml);
+        let error = load_xml(&malformed)
+            .err()
+            .expect("a non-numeric element coordinate should be rejected");
+        assert!(error.contains("element 1") && error.contains("'radar'"), "{error}");
+
+        // JSON numbers beyond the double range parse as infinity
+        let infinite = minimal_scenario_json(
+            STATIC_MOTION,
+            r#"{"receiver": {"name": "rx",
+                "pulsed_mode": {"prf": 10.0, "window_skip": 0.0, "window_length": 0.001},
+                "element_array": [{"x": 0.0, "y": 1e999, "z": 0.0}],
+                "antenna": "default", "timing": "default"}}"#,
+        );
+        let error = reloaded
+            .update_scenario_from_json(&infinite)
+            .expect_err("a non-finite element position should be rejected");
+        assert!(error.contains("non-finite"), "{error}");
+    }
+
+    /// Verifies the steering weights of two elements half a wavelength apart along the body
+    /// y axis: at 30 degrees azimuth the path difference is a quarter wavelength, so the
+    /// second element leads the first by `pi / 2`.
+    #[test]
+    fn steering_weights_match_half_wavelength_pair() {
+        let lambda = 299_792_458.0 / 1e9;
+        let (_waveform, context) = beamforming_context(&format!(
+            r#""element_array": [{{"x": 0.0, "y": 0.0, "z": 0.0}},
+                {{"x": 0.0, "y": {}, "z": 0.0}}],"#,
+            lambda / 2.0
+        ));
+        let weights = context
+            .preview_beamforming_weights("radar", 30.0, 0.0)
+            .expect("weights should be computed");
+        assert_eq!(weights.len(), 2);
+        assert!((weights[0][0] - 0.5).abs() < 1e-9 && weights[0][1].abs() < 1e-9);
+        assert!(weights[1][0].abs() < 1e-9 && (weights[1][1] - 0.5).abs() < 1e-9);
+
+        let (_plain_waveform, plain) = beamforming_context("");
+        assert!(plain.preview_beamforming_weights("radar", 30.0, 0.0).is_err());
+        assert!(context.preview_beamforming_weights("missing", 30.0, 0.0).is_err());
+    }
+
+    /// Verifies that a 500 m ridge in a terrain grid masks two platforms 100 m up on either
+    /// side of it, which see each other without the terrain, and that the terrain file
+    /// survives the XML round-trip.
+    #[test]
+    fn terrain_ridge_blocks_line_of_sight() {
+        // 21 x 3 nodes 0.001 degrees apart around the origin, with a ridge along longitude 0
+        let mut grid = String::from(
+            "ncols 21\nnrows 3\nxllcenter -0.01\nyllcenter -0.001\ncellsize 0.001\nNODATA_value -9999\n",
+        );
+        let row: Vec<&str> = (0..21).map(|i| if i == 10 { "500" } else { "0" }).collect();
+        for _ in 0..3 {
+            grid.push_str(&row.join(" "));
+            grid.push('\n');
+        }
+        let terrain_path = TempFile::new("asc");
+        std::fs::write(&terrain_path, grid).expect("terrain file should be written");
+        let filename = terrain_path.to_string_lossy().replace('\\', "/");
+
+        let platform = |name: &str, x: f64| {
+            format!(
+                r#"{{"name": "{name}",
+                    "motionpath": {{"interpolation": "static", "positionwaypoints": [
+                        {{"time": 0.0, "x": {x}, "y": 0.0, "altitude": 100.0}}]}},
+                    "fixedrotation": {{"startazimuth": 0.0, "startelevation": 0.0,
+                        "azimuthrate": 0.0, "elevationrate": 0.0}},
+                    "components": [{{"target": {{"name": "{name}",
+                        "rcs": {{"type": "isotropic", "value": 1.0}}}}}}]}}, "#
+            )
+        };
+        let scenario = |terrain: &str| {
+            minimal_scenario_json(STATIC_MOTION, "").replacen(
+                r#""platforms": ["#,
+                &format!(
+                    r#"{terrain}"platforms": [{}{}"#,
+                    platform("west", -500.0),
+                    platform("east", 500.0)
+                ),
+                1,
+            )
+        };
+
+        let context = fers_api::FersContext::new().expect("FersContext::new() returned None");
+        context.update_scenario_from_json(&scenario("")).expect("scenario should load");
+        assert!(context.check_line_of_sight("west", "east", 0.0).expect("check should run"));
+
+        let result = context.update_scenario_from_json(&scenario(&format!(
+            r#""terrain": {{"filename": "{filename}"}}, "#
+        )));
+        let xml = context.get_scenario_as_xml();
+        result.expect("scenario with terrain should load");
+
+        assert!(!context.check_line_of_sight("west", "east", 0.0).expect("check should run"));
+        assert!(context.compute_grazing_angle("west", [500.0, 0.0], 0.0, 0.0).is_err());
+        assert!(context.compute_grazing_angle("west", [-600.0, 0.0], 0.0, 0.0).is_ok());
+        assert!(context.check_line_of_sight("west", "missing", 0.0).is_err());
+
+        let xml = xml.expect("scenario should serialize to XML");
+        assert!(xml.contains(&format!(r#"<terrain filename="{filename}"/>"#)), "got:\n{xml}");
     }
 }
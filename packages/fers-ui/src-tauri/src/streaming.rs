@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+//! # Live Output Streaming
+//!
+//! Forwards receive windows from a running simulation to a consumer on a local TCP
+//! socket, so output can be displayed while it is produced rather than read back from
+//! HDF5 afterwards.
+//!
+//! ## Framing
+//!
+//! Each window is sent as one self-delimiting frame. All integers and floats are
+//! little-endian:
+//!
+//! | Field          | Type                     | Description                                   |
+//! |----------------|--------------------------|-----------------------------------------------|
+//! | `length`       | `u32`                    | Number of bytes in the frame after this field |
+//! | `name_length`  | `u16`                    | Number of bytes in `receiver`                 |
+//! | `receiver`     | UTF-8 bytes              | Name of the receiver that produced the window |
+//! | `start_time`   | `f64`                    | Window start time in seconds                  |
+//! | `fullscale`    | `f64`                    | Factor restoring samples to physical units    |
+//! | `sample_count` | `u32`                    | Number of complex samples                     |
+//! | `samples`      | `sample_count` × 2 `f64` | Interleaved I/Q samples                       |
+//!
+//! ## Backpressure
+//!
+//! Frames are queued in a bounded buffer. When the consumer falls behind and the buffer
+//! is full, new windows are dropped rather than stalling the simulation. Windows that
+//! arrive before a consumer has connected are dropped as well.
+
+use std::io::{self, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::thread::JoinHandle;
+
+/// Number of frames buffered for a slow consumer before windows are dropped.
+pub const STREAM_BUFFER_FRAMES: usize = 64;
+
+/// Delivery counts for a finished stream.
+#[derive(serde::Serialize, Debug, Clone, Copy)]
+pub struct StreamStats {
+    /// Frames written to the consumer.
+    pub sent: usize,
+    /// Windows dropped because the consumer was absent or too slow.
+    pub dropped: usize,
+}
+
+/// The producer side of a window stream, fed from the simulation's window callback.
+pub struct WindowStream {
+    sender: SyncSender<Vec<u8>>,
+    dropped: AtomicUsize,
+}
+
+impl WindowStream {
+    /// Starts a forwarding thread that writes frames to the first consumer to connect.
+    ///
+    /// # Parameters
+    ///
+    /// * `listener` - A bound listener on which the consumer connects.
+    /// * `capacity` - The number of frames buffered before windows are dropped.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((WindowStream, JoinHandle))` - The producer and the forwarding thread, which
+    ///   finishes once the producer is dropped.
+    /// * `Err(io::Error)` - If the listener could not be made non-blocking.
+    pub fn spawn(
+        listener: TcpListener,
+        capacity: usize,
+    ) -> io::Result<(Self, JoinHandle<StreamStats>)> {
+        listener.set_nonblocking(true)?;
+        let (sender, receiver) = mpsc::sync_channel(capacity);
+        let handle = std::thread::spawn(move || forward_frames(&listener, &receiver));
+        Ok((Self { sender, dropped: AtomicUsize::new(0) }, handle))
+    }
+
+    /// Queues a window for the consumer, dropping it if the buffer is full or the window
+    /// is too large to frame.
+    pub fn publish(&self, receiver: &str, start_time: f64, fullscale: f64, samples: &[f64]) {
+        let queued = encode_frame(receiver, start_time, fullscale, samples)
+            .is_ok_and(|frame| self.sender.try_send(frame).is_ok());
+        if !queued {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Closes the stream and returns the number of windows dropped by the producer.
+    pub fn finish(self) -> usize {
+        self.dropped.into_inner()
+    }
+}
+
+/// Encodes a window as a frame in the format documented at the module level.
+///
+/// A receiver name longer than `u16::MAX` bytes is truncated to the last whole character
+/// that fits, so the frame always carries valid UTF-8.
+///
+/// # Returns
+///
+/// * `Ok(Vec<u8>)` - The encoded frame.
+/// * `Err(io::Error)` - If the sample count or the frame length does not fit in a `u32`.
+pub fn encode_frame(
+    receiver: &str,
+    start_time: f64,
+    fullscale: f64,
+    samples: &[f64],
+) -> io::Result<Vec<u8>> {
+    let mut name_length = receiver.len().min(usize::from(u16::MAX));
+    while !receiver.is_char_boundary(name_length) {
+        name_length -= 1;
+    }
+    let name = &receiver.as_bytes()[..name_length];
+    let sample_count = samples.len() / 2;
+    let too_large = || io::Error::new(io::ErrorKind::InvalidInput, "window is too large to frame");
+    let sample_count_field = u32::try_from(sample_count).map_err(|_| too_large())?;
+    let length = sample_count
+        .checked_mul(16)
+        .and_then(|bytes| bytes.checked_add(2 + name.len() + 8 + 8 + 4))
+        .and_then(|length| u32::try_from(length).ok())
+        .ok_or_else(too_large)?;
+    let mut frame = Vec::with_capacity(4 + length as usize);
+    frame.extend_from_slice(&length.to_le_bytes());
+    frame.extend_from_slice(&(name.len() as u16).to_le_bytes());
+    frame.extend_from_slice(name);
+    frame.extend_from_slice(&start_time.to_le_bytes());
+    frame.extend_from_slice(&fullscale.to_le_bytes());
+    frame.extend_from_slice(&sample_count_field.to_le_bytes());
+    for value in &samples[..sample_count * 2] {
+        frame.extend_from_slice(&value.to_le_bytes());
+    }
+    Ok(frame)
+}
+
+/// Writes queued frames to the consumer until the producer hangs up.
+fn forward_frames(listener: &TcpListener, frames: &Receiver<Vec<u8>>) -> StreamStats {
+    let mut consumer: Option<TcpStream> = None;
+    let mut stats = StreamStats { sent: 0, dropped: 0 };
+    for frame in frames {
+        if consumer.is_none() {
+            consumer = listener
+                .accept()
+                .ok()
+                .and_then(|(stream, _)| stream.set_nonblocking(false).ok().map(|()| stream));
+        }
+        match consumer.as_mut().map(|stream| stream.write_all(&frame)) {
+            Some(Ok(())) => stats.sent += 1,
+            Some(Err(_)) => {
+                // The consumer went away; wait for another to connect.
+                consumer = None;
+                stats.dropped += 1;
+            }
+            None => stats.dropped += 1,
+        }
+    }
+    stats
+}
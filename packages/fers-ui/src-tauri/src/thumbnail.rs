@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+//! # Scenario Thumbnails
+//!
+//! Rasterizes a small top-down (x/y) preview of every platform track into a PNG.
+//! The view is scaled uniformly to fit the bounding box of all tracks, with North
+//! (positive y) pointing up. Tracks are coloured by the role of their platform.
+
+use crate::fers_api::{PlatformTrack, TrackKind};
+
+/// Background colour of the thumbnail.
+const BACKGROUND: [u8; 3] = [0x1e, 0x1e, 0x1e];
+
+/// Fraction of the smaller image dimension left empty around the tracks.
+const MARGIN_FRACTION: f64 = 0.05;
+
+/// Returns the drawing colour for a platform role.
+fn track_colour(kind: TrackKind) -> [u8; 3] {
+    match kind {
+        TrackKind::Transmitter => [0xef, 0x53, 0x50],
+        TrackKind::Receiver => [0x42, 0xa5, 0xf5],
+        TrackKind::Monostatic => [0xab, 0x47, 0xbc],
+        TrackKind::Target => [0x66, 0xbb, 0x6a],
+        TrackKind::None => [0x9e, 0x9e, 0x9e],
+    }
+}
+
+/// An RGB raster that clips all drawing to its bounds.
+struct Canvas {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    fn new(width: u32, height: u32) -> Self {
+        let pixels = BACKGROUND.repeat(width as usize * height as usize);
+        Self { width, height, pixels }
+    }
+
+    fn put(&mut self, x: i64, y: i64, colour: [u8; 3]) {
+        if x < 0 || y < 0 || x >= i64::from(self.width) || y >= i64::from(self.height) {
+            return;
+        }
+        let index = (y as usize * self.width as usize + x as usize) * 3;
+        self.pixels[index..index + 3].copy_from_slice(&colour);
+    }
+
+    fn line(&mut self, from: (f64, f64), to: (f64, f64), colour: [u8; 3]) {
+        let steps = (to.0 - from.0).abs().max((to.1 - from.1).abs()).ceil().max(1.0);
+        for step in 0..=steps as i64 {
+            let t = step as f64 / steps;
+            let x = from.0 + (to.0 - from.0) * t;
+            let y = from.1 + (to.1 - from.1) * t;
+            self.put(x.round() as i64, y.round() as i64, colour);
+        }
+    }
+
+    fn marker(&mut self, at: (f64, f64), colour: [u8; 3]) {
+        let (x, y) = (at.0.round() as i64, at.1.round() as i64);
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                self.put(x + dx, y + dy, colour);
+            }
+        }
+    }
+}
+
+/// Renders the tracks top-down and writes them to `path` as an RGB PNG.
+///
+/// Each track is drawn as a polyline with a marker at its final position, so
+/// static platforms appear as dots.
+///
+/// # Parameters
+/// * `tracks` - The sampled platform tracks.
+/// * `width` - The image width in pixels.
+/// * `height` - The image height in pixels.
+/// * `path` - The output file path.
+///
+/// # Returns
+/// * `Ok(())` - If the image was written.
+/// * `Err(String)` - If the dimensions are zero or the file could not be written.
+pub fn write_track_thumbnail(
+    tracks: &[PlatformTrack],
+    width: u32,
+    height: u32,
+    path: &str,
+) -> Result<(), String> {
+    if width == 0 || height == 0 {
+        return Err("Thumbnail dimensions must be positive".to_string());
+    }
+
+    let mut canvas = Canvas::new(width, height);
+    let points = || tracks.iter().flat_map(|track| track.points.iter());
+    if points().next().is_some() {
+        let (mut x_min, mut x_max, mut y_min, mut y_max) =
+            (f64::INFINITY, f64::NEG_INFINITY, f64::INFINITY, f64::NEG_INFINITY);
+        for p in points() {
+            x_min = x_min.min(p.x);
+            x_max = x_max.max(p.x);
+            y_min = y_min.min(p.y);
+            y_max = y_max.max(p.y);
+        }
+
+        // Scale uniformly so the larger extent fills the image inside the margin.
+        let margin = f64::from(width.min(height)) * MARGIN_FRACTION;
+        let usable_w = (f64::from(width - 1) - 2.0 * margin).max(0.0);
+        let usable_h = (f64::from(height - 1) - 2.0 * margin).max(0.0);
+        let scale_x = if x_max > x_min { usable_w / (x_max - x_min) } else { f64::INFINITY };
+        let scale_y = if y_max > y_min { usable_h / (y_max - y_min) } else { f64::INFINITY };
+        // A single stationary point has no extent; it is drawn at the centre.
+        let scale = scale_x.min(scale_y);
+        let scale = if scale.is_finite() { scale } else { 0.0 };
+        let (x_mid, y_mid) = ((x_min + x_max) / 2.0, (y_min + y_max) / 2.0);
+        let (cx, cy) = (f64::from(width - 1) / 2.0, f64::from(height - 1) / 2.0);
+        let to_pixel = |x: f64, y: f64| (cx + (x - x_mid) * scale, cy - (y - y_mid) * scale);
+
+        for track in tracks {
+            let colour = track_colour(track.kind);
+            let pixels: Vec<(f64, f64)> = track.points.iter().map(|p| to_pixel(p.x, p.y)).collect();
+            for pair in pixels.windows(2) {
+                canvas.line(pair[0], pair[1], colour);
+            }
+            if let Some(&last) = pixels.last() {
+                canvas.marker(last, colour);
+            }
+        }
+    }
+
+    let file = std::fs::File::create(path)
+        .map_err(|e| format!("Failed to create thumbnail '{path}': {e}"))?;
+    let mut encoder = png::Encoder::new(std::io::BufWriter::new(file), width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
+    writer.write_image_data(&canvas.pixels).map_err(|e| e.to_string())
+}
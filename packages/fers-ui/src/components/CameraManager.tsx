@@ -24,9 +24,25 @@ export default function CameraManager({ controlsRef }: CameraManagerProps) {
     const clearViewControlAction = useScenarioStore(
         (state) => state.clearViewControlAction
     );
+    const setCameraView = useScenarioStore((state) => state.setCameraView);
 
     const lastActionTimestamp = useRef(0);
 
+    // Record where the user leaves the camera so it can be saved with the scenario.
+    useEffect(() => {
+        const controls = controlsRef.current;
+        if (!controls) {
+            return;
+        }
+        const handleEnd = () =>
+            setCameraView({
+                position: camera.position.toArray(),
+                target: controls.target.toArray(),
+            });
+        controls.addEventListener('end', handleEnd);
+        return () => controls.removeEventListener('end', handleEnd);
+    }, [controlsRef, camera, setCameraView]);
+
     useEffect(() => {
         if (
             !controlsRef.current ||
@@ -75,6 +91,15 @@ export default function CameraManager({ controlsRef }: CameraManagerProps) {
                 controls.update();
             }
 
+            lastActionTimestamp.current = viewControlAction.timestamp;
+            clearViewControlAction();
+        } else if (type === 'restore') {
+            const cameraView = useScenarioStore.getState().cameraView;
+            if (cameraView) {
+                camera.position.fromArray(cameraView.position);
+                controls.target.fromArray(cameraView.target);
+                controls.update();
+            }
             lastActionTimestamp.current = viewControlAction.timestamp;
             clearViewControlAction();
         } else if (type === 'focus' && targetId) {
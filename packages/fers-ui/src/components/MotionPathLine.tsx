@@ -7,6 +7,7 @@ import { Vector3 } from 'three';
 import { invoke } from '@tauri-apps/api/core';
 import { useScenarioStore, Platform } from '@/stores/scenarioStore';
 import { fersColors } from '@/theme';
+import { omit } from '@/utils/typeUtils.ts';
 
 const NUM_PATH_POINTS = 100; // The resolution of the rendered path line.
 
@@ -30,6 +31,7 @@ export function MotionPathLine({ platform }: { platform: Platform }) {
     const showError = useScenarioStore((state) => state.showError);
 
     const { waypoints, interpolation } = platform.motionPath;
+    const { positionNoise } = platform;
 
     useEffect(() => {
         const fetchPath = async () => {
@@ -55,6 +57,13 @@ export function MotionPathLine({ platform }: { platform: Platform }) {
                         waypoints: waypoints,
                         interpType: interpolation as InterpolationType,
                         numPoints: NUM_PATH_POINTS,
+                        options: {
+                            // Show the realization the simulation will follow
+                            // when requested.
+                            noise: positionNoise?.preview
+                                ? omit(positionNoise, 'preview')
+                                : undefined,
+                        },
                     }
                 );
 
@@ -81,7 +90,7 @@ export function MotionPathLine({ platform }: { platform: Platform }) {
         };
 
         void fetchPath();
-    }, [waypoints, interpolation, platform.name, showError]);
+    }, [waypoints, interpolation, positionNoise, platform.name, showError]);
 
     const linePoints = useMemo(() => {
         if (!pathPoints || pathPoints.length < 2) return undefined;
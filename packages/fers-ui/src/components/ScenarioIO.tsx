@@ -11,6 +11,19 @@ import { writeTextFile } from '@tauri-apps/plugin-fs';
 import { useState } from 'react';
 import ConfirmDialog from './ConfirmDialog';
 
+const SCENARIO_FILTERS = [
+    {
+        name: 'FERS XML Scenario',
+        extensions: ['xml', 'fersxml'],
+    },
+    {
+        name: 'FERS JSON Scenario',
+        extensions: ['json'],
+    },
+];
+
+const isJsonPath = (path: string) => path.toLowerCase().endsWith('.json');
+
 export default function ScenarioIO() {
     const loadScenario = useScenarioStore((state) => state.loadScenario);
     const isDirty = useScenarioStore((state) => state.isDirty);
@@ -23,20 +36,22 @@ export default function ScenarioIO() {
         try {
             await useScenarioStore.getState().syncBackend();
 
-            const xmlContent = await invoke<string>('get_scenario_as_xml');
-
             const filePath = await save({
                 title: 'Export Scenario',
-                filters: [
-                    {
-                        name: 'FERS XML Scenario',
-                        extensions: ['xml', 'fersxml'],
-                    },
-                ],
+                filters: SCENARIO_FILTERS,
             });
 
             if (filePath) {
-                await writeTextFile(filePath, xmlContent);
+                if (isJsonPath(filePath)) {
+                    await invoke('export_scenario_as_json_file', {
+                        filepath: filePath,
+                    });
+                } else {
+                    const xmlContent = await invoke<string>(
+                        'get_scenario_as_xml'
+                    );
+                    await writeTextFile(filePath, xmlContent);
+                }
                 console.log('Scenario exported successfully to:', filePath);
             }
         } catch (error) {
@@ -52,19 +67,17 @@ export default function ScenarioIO() {
             const selectedPath = await open({
                 title: 'Import Scenario',
                 multiple: false,
-                filters: [
-                    {
-                        name: 'FERS XML Scenario',
-                        extensions: ['xml', 'fersxml'],
-                    },
-                ],
+                filters: SCENARIO_FILTERS,
             });
 
             if (typeof selectedPath === 'string') {
-                // Load the XML file into the C++ core
-                await invoke('load_scenario_from_xml_file', {
-                    filepath: selectedPath,
-                });
+                // Load the XML or JSON file into the C++ core
+                await invoke(
+                    isJsonPath(selectedPath)
+                        ? 'import_scenario_from_json_file'
+                        : 'load_scenario_from_xml_file',
+                    { filepath: selectedPath }
+                );
 
                 // Fetch the new state as JSON from the C++ core
                 const jsonState = await invoke<string>('get_scenario_as_json');
@@ -106,12 +119,12 @@ export default function ScenarioIO() {
 
     return (
         <>
-            <Tooltip title="Import Scenario (XML)">
+            <Tooltip title="Import Scenario (XML or JSON)">
                 <IconButton size="small" onClick={handleImport}>
                     <FileUploadIcon fontSize="inherit" />
                 </IconButton>
             </Tooltip>
-            <Tooltip title="Export Scenario (XML)">
+            <Tooltip title="Export Scenario (XML or JSON)">
                 <IconButton size="small" onClick={handleExport}>
                     <FileDownloadIcon fontSize="inherit" />
                 </IconButton>
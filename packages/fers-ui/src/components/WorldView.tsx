@@ -299,6 +299,7 @@ export default function WorldView({ controlsRef }: WorldViewProps) {
                         p.id,
                         p.motionPath.interpolation,
                         JSON.stringify(p.motionPath.waypoints),
+                        JSON.stringify(p.positionNoise ?? null),
                         rotKey,
                     ].join('|');
                 })
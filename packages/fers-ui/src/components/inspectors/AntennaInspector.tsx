@@ -49,7 +49,7 @@ export function AntennaInspector({ item }: AntennaInspectorProps) {
                     <MenuItem value="squarehorn">Square Horn</MenuItem>
                     <MenuItem value="parabolic">Parabolic</MenuItem>
                     <MenuItem value="xml">XML</MenuItem>
-                    <MenuItem value="file">File (H5)</MenuItem>
+                    <MenuItem value="file">File (H5 / NSMA .pat)</MenuItem>
                 </Select>
             </FormControl>
             <NumberField
@@ -121,7 +121,9 @@ export function AntennaInspector({ item }: AntennaInspectorProps) {
                         {
                             name: 'Antenna Pattern',
                             extensions:
-                                item.pattern === 'xml' ? ['xml'] : ['h5'],
+                                item.pattern === 'xml'
+                                    ? ['xml']
+                                    : ['h5', 'pat'],
                         },
                         { name: 'All Files', extensions: ['*'] },
                     ]}
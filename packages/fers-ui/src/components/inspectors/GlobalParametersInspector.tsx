@@ -10,7 +10,7 @@ import {
     TextField,
 } from '@mui/material';
 import { useScenarioStore, GlobalParameters } from '@/stores/scenarioStore';
-import { NumberField, Section } from './InspectorControls';
+import { FileInput, NumberField, Section } from './InspectorControls';
 
 interface GlobalParametersInspectorProps {
     item: GlobalParameters;
@@ -136,6 +136,19 @@ export function GlobalParametersInspector({
                         </FormControl>
                     </>
                 )}
+                {item.coordinateSystem.frame === 'ENU' && (
+                    <FileInput
+                        label="Terrain Height Field (.asc)"
+                        value={item.terrain?.filename}
+                        onChange={(v) =>
+                            handleChange('terrain', { filename: v })
+                        }
+                        filters={[
+                            { name: 'ESRI ASCII Grid', extensions: ['asc'] },
+                            { name: 'All Files', extensions: ['*'] },
+                        ]}
+                    />
+                )}
             </Section>
         </Box>
     );
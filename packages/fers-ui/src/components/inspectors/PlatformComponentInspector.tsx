@@ -44,6 +44,62 @@ export function PlatformComponentInspector({
     const handleChange = (path: string, value: unknown) =>
         updateItem(platformId, `components.${index}.${path}`, value);
 
+    const renderHopSequence = (
+        c: MonostaticComponent | TransmitterComponent
+    ) => {
+        const parseList = (text: string) =>
+            text
+                .split(/[\s,]+/)
+                .filter((token) => token.length > 0)
+                .map(Number)
+                .filter(Number.isFinite);
+
+        // Lists are committed on blur so partially typed numbers are not lost.
+        return (
+            <Section title="Frequency Hopping">
+                <TextField
+                    key={`${c.id}-hop-frequencies`}
+                    label="Hop Frequencies (Hz)"
+                    size="small"
+                    fullWidth
+                    placeholder="e.g. 1e9, 1.1e9, 1.2e9"
+                    defaultValue={c.hopSequence?.frequencies.join(', ') ?? ''}
+                    onBlur={(e) => {
+                        const frequencies = parseList(e.target.value);
+                        handleChange(
+                            'hopSequence',
+                            frequencies.length > 0
+                                ? {
+                                      frequencies,
+                                      pattern: c.hopSequence?.pattern ?? [],
+                                  }
+                                : undefined
+                        );
+                    }}
+                />
+                <TextField
+                    key={`${c.id}-hop-pattern`}
+                    label="Hop Pattern (indices, optional)"
+                    size="small"
+                    fullWidth
+                    disabled={!c.hopSequence}
+                    placeholder="e.g. 2, 0, 1"
+                    defaultValue={c.hopSequence?.pattern.join(', ') ?? ''}
+                    onBlur={(e) => {
+                        if (c.hopSequence) {
+                            handleChange('hopSequence', {
+                                ...c.hopSequence,
+                                pattern: parseList(e.target.value).map(
+                                    Math.trunc
+                                ),
+                            });
+                        }
+                    }}
+                />
+            </Section>
+        );
+    };
+
     const renderSchedule = (
         c: MonostaticComponent | TransmitterComponent | ReceiverComponent
     ) => {
@@ -263,6 +319,8 @@ export function PlatformComponentInspector({
                             onChange={(v) => handleChange('prf', v)}
                         />
                     )}
+                    {component.radarType === 'pulsed' &&
+                        renderHopSequence(component)}
                     {renderReceiverFields(component)}
                     {renderSchedule(component)}
                 </Box>
@@ -278,6 +336,8 @@ export function PlatformComponentInspector({
                             onChange={(v) => handleChange('prf', v)}
                         />
                     )}
+                    {component.radarType === 'pulsed' &&
+                        renderHopSequence(component)}
                     {renderSchedule(component)}
                 </Box>
             );
@@ -5,11 +5,13 @@ import { useState } from 'react';
 import {
     Box,
     Button,
+    Checkbox,
     Dialog,
     DialogActions,
     DialogContent,
     DialogTitle,
     FormControl,
+    FormControlLabel,
     IconButton,
     InputLabel,
     MenuItem,
@@ -21,6 +23,7 @@ import {
     useScenarioStore,
     Platform,
     PlatformComponent,
+    PositionNoise,
     PositionWaypoint,
     RotationWaypoint,
 } from '@/stores/scenarioStore';
@@ -165,6 +168,11 @@ export function PlatformInspector({ item }: PlatformInspectorProps) {
     const handleChange = (path: string, value: unknown) =>
         updateItem(item.id, path, value);
 
+    const handleNoiseChange = (changes: Partial<PositionNoise>) => {
+        if (!item.positionNoise) return;
+        handleChange('positionNoise', { ...item.positionNoise, ...changes });
+    };
+
     const allowMultiplePosWaypoints =
         item.motionPath.interpolation !== 'static';
 
@@ -318,6 +326,86 @@ export function PlatformInspector({ item }: PlatformInspectorProps) {
                 )}
             </Section>
 
+            <Section title="Position Noise">
+                <FormControlLabel
+                    control={
+                        <Checkbox
+                            checked={!!item.positionNoise}
+                            onChange={(e) =>
+                                handleChange(
+                                    'positionNoise',
+                                    e.target.checked
+                                        ? {
+                                              x: 0,
+                                              y: 0,
+                                              z: 0,
+                                              correlation_time: 1,
+                                              // A fresh seed per platform, so
+                                              // their noise does not cancel.
+                                              seed: Math.floor(
+                                                  Math.random() * 2 ** 32
+                                              ),
+                                              preview: false,
+                                          }
+                                        : undefined
+                                )
+                            }
+                        />
+                    }
+                    label="Enable Position Noise"
+                />
+                {item.positionNoise && (
+                    <>
+                        <NumberField
+                            label="X Std. Dev. (m)"
+                            value={item.positionNoise.x}
+                            onChange={(v) => handleNoiseChange({ x: v ?? 0 })}
+                        />
+                        <NumberField
+                            label="Y Std. Dev. (m)"
+                            value={item.positionNoise.y}
+                            onChange={(v) => handleNoiseChange({ y: v ?? 0 })}
+                        />
+                        <NumberField
+                            label="Z Std. Dev. (m)"
+                            value={item.positionNoise.z}
+                            onChange={(v) => handleNoiseChange({ z: v ?? 0 })}
+                        />
+                        <NumberField
+                            label="Correlation Time (s)"
+                            value={item.positionNoise.correlation_time}
+                            onChange={(v) =>
+                                handleNoiseChange({
+                                    correlation_time: v && v > 0 ? v : 1,
+                                })
+                            }
+                        />
+                        <NumberField
+                            label="Seed"
+                            value={item.positionNoise.seed}
+                            onChange={(v) =>
+                                handleNoiseChange({
+                                    seed: Math.max(0, Math.floor(v ?? 0)),
+                                })
+                            }
+                        />
+                        <FormControlLabel
+                            control={
+                                <Checkbox
+                                    checked={item.positionNoise.preview}
+                                    onChange={(e) =>
+                                        handleNoiseChange({
+                                            preview: e.target.checked,
+                                        })
+                                    }
+                                />
+                            }
+                            label="Preview Noisy Path"
+                        />
+                    </>
+                )}
+            </Section>
+
             <Section title="Rotation">
                 <FormControl fullWidth size="small">
                     <InputLabel>Rotation Type</InputLabel>
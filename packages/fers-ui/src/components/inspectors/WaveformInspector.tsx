@@ -41,6 +41,7 @@ export function WaveformInspector({ item }: WaveformInspectorProps) {
                     }
                 >
                     <MenuItem value="pulsed_from_file">Pulse File</MenuItem>
+                    <MenuItem value="inline_samples">Inline Samples</MenuItem>
                     <MenuItem value="cw">CW</MenuItem>
                 </Select>
             </FormControl>
@@ -65,6 +66,30 @@ export function WaveformInspector({ item }: WaveformInspectorProps) {
                     ]}
                 />
             )}
+            {item.waveformType === 'inline_samples' && (
+                <>
+                    <NumberField
+                        label="Sample Rate (Hz)"
+                        value={item.sampleRate ?? null}
+                        onChange={(v) =>
+                            handleChange('sampleRate', v ?? undefined)
+                        }
+                    />
+                    <TextField
+                        label="I/Q Samples (I,Q pairs)"
+                        variant="outlined"
+                        size="small"
+                        fullWidth
+                        multiline
+                        minRows={3}
+                        placeholder="1,0, 0,1, -1,0"
+                        value={item.samples ?? ''}
+                        onChange={(e) =>
+                            handleChange('samples', e.target.value)
+                        }
+                    />
+                </>
+            )}
         </Box>
     );
 }
@@ -25,6 +25,33 @@ export const GlobalParametersSchema = z.object({
         .number()
         .int()
         .min(1, 'Oversample ratio must be at least 1.'),
+    // Additional output files written alongside the receiver results.
+    export: z
+        .object({
+            h5: z.boolean().default(true),
+            timestamps: z.boolean(),
+            debug: z.boolean().default(false),
+        })
+        .optional(),
+    // Flat reflecting surface nx*x + ny*y + nz*z = d for ground-bounce modelling.
+    multipath: z
+        .object({
+            factor: z.number(),
+            nx: z.number(),
+            ny: z.number(),
+            nz: z.number(),
+            d: z.number(),
+        })
+        .refine((s) => s.nx !== 0 || s.ny !== 0 || s.nz !== 0, {
+            message: 'Multipath surface normal must not be zero.',
+        })
+        .optional(),
+    // ESRI ASCII grid of terrain altitudes, aligned to the ENU frame through the origin.
+    terrain: z
+        .object({
+            filename: z.string().min(1, 'Terrain file cannot be empty.'),
+        })
+        .optional(),
     origin: z.object({
         latitude: z.number().min(-90).max(90),
         longitude: z.number().min(-180).max(180),
@@ -54,12 +81,19 @@ export const WaveformSchema = z
         id: z.string().uuid(),
         type: z.literal('Waveform'),
         name: z.string().min(1, 'Waveform name cannot be empty.'),
-        waveformType: z.enum(['pulsed_from_file', 'cw']),
+        waveformType: z.enum(['pulsed_from_file', 'inline_samples', 'cw']),
         power: z.number().min(0, 'Power cannot be negative.'),
         carrier_frequency: z
             .number()
             .positive('Carrier frequency must be positive.'),
         filename: z.string().optional(),
+        // Inline I/Q samples as comma-separated I,Q pairs, e.g. "1,0, 0,1".
+        samples: z.string().optional(),
+        // Sample rate of the inline samples (Hz).
+        sampleRate: z
+            .number()
+            .positive('Sample rate must be positive.')
+            .optional(),
     })
     .refine(
         (data) => {
@@ -72,6 +106,17 @@ export const WaveformSchema = z
             message: 'A filename is required for this waveform type.',
             path: ['filename'],
         }
+    )
+    .refine(
+        (data) =>
+            data.waveformType !== 'inline_samples' ||
+            (data.samples !== undefined &&
+                data.samples.trim().length > 0 &&
+                data.sampleRate !== undefined),
+        {
+            message: 'Inline waveforms require samples and a sample rate.',
+            path: ['samples'],
+        }
     );
 
 export const NoiseEntrySchema = z.object({
@@ -146,10 +191,16 @@ export const PositionWaypointSchema = z.object({
     y: z.number(),
     altitude: z.number(),
     time: z.number().min(0, 'Time cannot be negative.'),
+    // Optional logged velocity (m/s); only cubic interpolation uses it.
+    vx: z.number().optional(),
+    vy: z.number().optional(),
+    vz: z.number().optional(),
 });
 
 export const MotionPathSchema = z.object({
     interpolation: z.enum(['static', 'linear', 'cubic']),
+    // Frame the waypoints were authored in; they are always held locally.
+    coordinates: z.enum(['local', 'ecef']).optional(),
     waypoints: z
         .array(PositionWaypointSchema)
         .min(1, 'At least one waypoint is required.'),
@@ -183,6 +234,31 @@ export const SchedulePeriodSchema = z.object({
     end: z.number().min(0, 'End time cannot be negative.'),
 });
 
+export const HopSequenceSchema = z.object({
+    // Hop set of carrier frequencies (Hz).
+    frequencies: z
+        .array(z.number().positive('Hop frequencies must be positive.'))
+        .min(1, 'At least one hop frequency is required.'),
+    // Indices into the hop set giving the per-pulse order; cycles in order if
+    // empty.
+    pattern: z.array(z.number().int().min(0)).default([]),
+});
+
+// An interval of each receive window (offset from the window opening, seconds)
+// whose samples are zeroed to suppress a known interferer.
+export const BlankingGateSchema = z.object({
+    start: z.number().min(0),
+    length: z.number().positive(),
+});
+
+// One element of a receive array, offset from the phase center in the body
+// frame (meters): x along boresight, y to the left, z up.
+export const ElementPositionSchema = z.object({
+    x: z.number(),
+    y: z.number(),
+    z: z.number(),
+});
+
 const MonostaticComponentSchema = z.object({
     id: z.string().uuid(),
     type: z.literal('monostatic'),
@@ -193,10 +269,34 @@ const MonostaticComponentSchema = z.object({
     prf: nullableNumber,
     antennaId: z.string().uuid().nullable(),
     waveformId: z.string().uuid().nullable(),
+    // Optional per-pulse carrier hopping; pulsed mode only.
+    hopSequence: HopSequenceSchema.optional(),
+    // Optional hardware duty-cycle limit as a fraction in (0, 1]; checked by
+    // scenario validation against pulse length times PRF.
+    maxDutyCycle: z
+        .number()
+        .gt(0, 'Maximum duty cycle must be positive.')
+        .max(1, 'Maximum duty cycle cannot exceed 1.')
+        .optional(),
+    // Optional blanking gates within each receive window; pulsed mode only.
+    blankingGates: z.array(BlankingGateSchema).optional(),
+    // Optional receive element positions in the body frame (meters) for
+    // digital beamforming; pulsed mode only.
+    elementArray: z.array(ElementPositionSchema).optional(),
     timingId: z.string().uuid().nullable(),
     noiseTemperature: nullableNumber.pipe(z.number().min(0).nullable()),
     noDirectPaths: z.boolean(),
     noPropagationLoss: z.boolean(),
+    // Optional CSV of frequency (Hz), gain (dB) rows applied to received signals.
+    calibrationFile: z.string().optional(),
+    // Optional antenna phase-center offset from the platform origin in the body
+    // frame (meters): x along boresight, y to the left, z up.
+    phaseCenterOffset: z
+        .object({ x: z.number(), y: z.number(), z: z.number() })
+        .optional(),
+    // Optional height of the antenna mount above the platform reference point
+    // (meters, vertical), e.g. a mast.
+    mountHeight: z.number().optional(),
     schedule: z.array(SchedulePeriodSchema).default([]),
 });
 
@@ -208,7 +308,24 @@ const TransmitterComponentSchema = z.object({
     prf: nullableNumber,
     antennaId: z.string().uuid().nullable(),
     waveformId: z.string().uuid().nullable(),
+    // Optional per-pulse carrier hopping; pulsed mode only.
+    hopSequence: HopSequenceSchema.optional(),
+    // Optional hardware duty-cycle limit as a fraction in (0, 1]; checked by
+    // scenario validation against pulse length times PRF.
+    maxDutyCycle: z
+        .number()
+        .gt(0, 'Maximum duty cycle must be positive.')
+        .max(1, 'Maximum duty cycle cannot exceed 1.')
+        .optional(),
     timingId: z.string().uuid().nullable(),
+    // Optional antenna phase-center offset from the platform origin in the body
+    // frame (meters): x along boresight, y to the left, z up.
+    phaseCenterOffset: z
+        .object({ x: z.number(), y: z.number(), z: z.number() })
+        .optional(),
+    // Optional height of the antenna mount above the platform reference point
+    // (meters, vertical), e.g. a mast.
+    mountHeight: z.number().optional(),
     schedule: z.array(SchedulePeriodSchema).default([]),
 });
 
@@ -220,11 +337,26 @@ const ReceiverComponentSchema = z.object({
     window_skip: nullableNumber,
     window_length: nullableNumber,
     prf: nullableNumber,
+    // Optional blanking gates within each receive window; pulsed mode only.
+    blankingGates: z.array(BlankingGateSchema).optional(),
+    // Optional receive element positions in the body frame (meters) for
+    // digital beamforming; pulsed mode only.
+    elementArray: z.array(ElementPositionSchema).optional(),
     antennaId: z.string().uuid().nullable(),
     timingId: z.string().uuid().nullable(),
     noiseTemperature: nullableNumber.pipe(z.number().min(0).nullable()),
     noDirectPaths: z.boolean(),
     noPropagationLoss: z.boolean(),
+    // Optional CSV of frequency (Hz), gain (dB) rows applied to received signals.
+    calibrationFile: z.string().optional(),
+    // Optional antenna phase-center offset from the platform origin in the body
+    // frame (meters): x along boresight, y to the left, z up.
+    phaseCenterOffset: z
+        .object({ x: z.number(), y: z.number(), z: z.number() })
+        .optional(),
+    // Optional height of the antenna mount above the platform reference point
+    // (meters, vertical), e.g. a mast.
+    mountHeight: z.number().optional(),
     schedule: z.array(SchedulePeriodSchema).default([]),
 });
 
@@ -235,8 +367,25 @@ const TargetComponentSchema = z.object({
     rcs_type: z.enum(['isotropic', 'file']),
     rcs_value: z.number().optional(),
     rcs_filename: z.string().optional(),
+    // How file-based RCS is interpolated over aspect angle; linear if unset.
+    rcs_interpolation: z.enum(['nearest', 'linear', 'cubic']).optional(),
     rcs_model: z.enum(['constant', 'chisquare', 'gamma']),
     rcs_k: z.number().optional(),
+    // Seconds over which the fluctuating RCS stays correlated; sampled every echo if unset.
+    rcs_decorrelation_time: z.number().positive().optional(),
+    // Alternative to the decorrelation time: how often (Hz) the fluctuating RCS is redrawn.
+    rcs_sample_rate: z.number().positive().optional(),
+    // Swerling case of a chi-square model; implies rcs_k when that is unset.
+    rcs_swerling_case: z.number().int().min(1).max(4).optional(),
+    // Optional vibration/rotation about the platform position (micro-Doppler).
+    micromotion: z
+        .object({
+            type: z.enum(['vibration', 'rotation']),
+            amplitude: z.number().min(0),
+            frequency: z.number().min(0),
+            axis: z.object({ x: z.number(), y: z.number(), z: z.number() }),
+        })
+        .optional(),
 });
 
 export const PlatformComponentSchema = z.discriminatedUnion('type', [
@@ -246,13 +395,28 @@ export const PlatformComponentSchema = z.discriminatedUnion('type', [
     TargetComponentSchema,
 ]);
 
+export const PositionNoiseSchema = z.object({
+    // Standard deviations (meters) of the Gaussian deviation from the nominal path.
+    x: z.number().min(0, 'Standard deviation cannot be negative.'),
+    y: z.number().min(0, 'Standard deviation cannot be negative.'),
+    z: z.number().min(0, 'Standard deviation cannot be negative.'),
+    // Interval (seconds) between independent draws, blended smoothly in between.
+    correlation_time: z.number().positive('Correlation time must be positive.'),
+    seed: z.number().int().min(0, 'Seed cannot be negative.'),
+    // Editor-only: draw the noisy realization instead of the nominal path.
+    preview: z.boolean(),
+});
+
 export const PlatformSchema = z.object({
     id: z.string().uuid(),
     type: z.literal('Platform'),
     name: z.string().min(1, 'Platform name cannot be empty.'),
     motionPath: MotionPathSchema,
+    positionNoise: PositionNoiseSchema.optional(),
     rotation: z.union([FixedRotationSchema, RotationPathSchema]),
     components: z.array(PlatformComponentSchema),
+    // Editor-only user data, saved in the scenario's UI state.
+    metadata: z.record(z.string(), z.unknown()).optional(),
 });
 
 export const ScenarioDataSchema = z.object({
@@ -33,6 +33,8 @@ export const useScenarioStore = create<ScenarioStore>()(
             message: '',
         },
         viewControlAction: { type: null, timestamp: 0 },
+        cameraView: null,
+        metadata: null,
         visibility: {
             showAxes: true,
             showPatterns: true,
@@ -120,6 +122,7 @@ export const useScenarioStore = create<ScenarioStore>()(
             set((state) => {
                 state.visibility[layer] = !state.visibility[layer];
             }),
+        setCameraView: (view) => set({ cameraView: view }),
 
         // Error Actions
         showError: (message) => set({ errorSnackbar: { open: true, message } }),
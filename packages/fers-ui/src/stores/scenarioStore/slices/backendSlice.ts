@@ -8,6 +8,7 @@ import {
     BackendActions,
     TargetComponent,
     Timing,
+    UiState,
 } from '../types';
 import { omit } from '@/utils/typeUtils.ts';
 
@@ -35,11 +36,16 @@ type BackendTarget = {
         type: TargetComponent['rcs_type'];
         value?: number;
         filename?: string;
+        interpolation?: TargetComponent['rcs_interpolation'];
     };
     model?: {
         type: Exclude<TargetComponent['rcs_model'], 'constant'>;
         k?: number;
+        swerling?: number;
+        sample_rate?: number;
+        decorrelation_time?: number;
     };
+    micromotion?: TargetComponent['micromotion'];
 };
 
 export const createBackendSlice: StateCreator<
@@ -50,8 +56,17 @@ export const createBackendSlice: StateCreator<
 > = (set, get) => ({
     syncBackend: async () => {
         set({ isBackendSyncing: true });
-        const { globalParameters, waveforms, timings, antennas, platforms } =
-            get();
+        const {
+            globalParameters,
+            waveforms,
+            timings,
+            antennas,
+            platforms,
+            selectedItemId,
+            currentTime,
+            cameraView,
+            metadata,
+        } = get();
 
         // Helper functions to map frontend asset IDs back to names for the backend
         const findAntennaName = (id: string | null) =>
@@ -62,7 +77,8 @@ export const createBackendSlice: StateCreator<
             timings.find((t) => t.id === id)?.name;
 
         const backendPlatforms = platforms.map((p) => {
-            const { components, motionPath, rotation, ...rest } = p;
+            const { components, motionPath, positionNoise, rotation, ...rest } =
+                p;
 
             // Map the list of components to backend objects
             const backendComponents = components.map((component) => {
@@ -82,6 +98,24 @@ export const createBackendSlice: StateCreator<
                             component.radarType === 'cw'
                           ? { cw_mode: {} }
                           : {};
+                // Hopping only applies to pulsed transmitters.
+                const hopSequence =
+                    'hopSequence' in component &&
+                    component.radarType === 'pulsed'
+                        ? component.hopSequence
+                        : undefined;
+                // Blanking gates sit inside receive windows, so they need pulsed mode.
+                const blankingGates =
+                    'blankingGates' in component &&
+                    component.radarType === 'pulsed'
+                        ? component.blankingGates
+                        : undefined;
+                // Per-element channels are only recorded in pulsed mode.
+                const elementArray =
+                    'elementArray' in component &&
+                    component.radarType === 'pulsed'
+                        ? component.elementArray
+                        : undefined;
 
                 switch (component.type) {
                     case 'monostatic':
@@ -97,7 +131,15 @@ export const createBackendSlice: StateCreator<
                                 noise_temp: component.noiseTemperature,
                                 nodirect: component.noDirectPaths,
                                 nopropagationloss: component.noPropagationLoss,
+                                calibration_file: component.calibrationFile,
+                                phase_center_offset:
+                                    component.phaseCenterOffset,
+                                mount_height: component.mountHeight,
                                 schedule: component.schedule,
+                                hop_sequence: hopSequence,
+                                max_duty_cycle: component.maxDutyCycle,
+                                blanking_gates: blankingGates,
+                                element_array: elementArray,
                             },
                         };
                         break;
@@ -111,7 +153,12 @@ export const createBackendSlice: StateCreator<
                                     component.waveformId
                                 ),
                                 timing: findTimingName(component.timingId),
+                                phase_center_offset:
+                                    component.phaseCenterOffset,
+                                mount_height: component.mountHeight,
                                 schedule: component.schedule,
+                                hop_sequence: hopSequence,
+                                max_duty_cycle: component.maxDutyCycle,
                             },
                         };
                         break;
@@ -125,7 +172,13 @@ export const createBackendSlice: StateCreator<
                                 noise_temp: component.noiseTemperature,
                                 nodirect: component.noDirectPaths,
                                 nopropagationloss: component.noPropagationLoss,
+                                calibration_file: component.calibrationFile,
+                                phase_center_offset:
+                                    component.phaseCenterOffset,
+                                mount_height: component.mountHeight,
                                 schedule: component.schedule,
+                                blanking_gates: blankingGates,
+                                element_array: elementArray,
                             },
                         };
                         break;
@@ -137,12 +190,18 @@ export const createBackendSlice: StateCreator<
                                     type: component.rcs_type,
                                     value: component.rcs_value,
                                     filename: component.rcs_filename,
+                                    interpolation: component.rcs_interpolation,
                                 },
+                                micromotion: component.micromotion,
                             };
                             if (component.rcs_model !== 'constant') {
                                 targetObj.model = {
                                     type: component.rcs_model,
                                     k: component.rcs_k,
+                                    swerling: component.rcs_swerling_case,
+                                    sample_rate: component.rcs_sample_rate,
+                                    decorrelation_time:
+                                        component.rcs_decorrelation_time,
                                 };
                             }
                             compObj = { target: targetObj };
@@ -174,10 +233,12 @@ export const createBackendSlice: StateCreator<
                 ...rest,
                 motionpath: {
                     interpolation: motionPath.interpolation,
+                    coordinates: motionPath.coordinates,
                     positionwaypoints: motionPath.waypoints.map((wp) =>
                         omit(wp, 'id')
                     ),
                 },
+                position_noise: positionNoise && omit(positionNoise, 'preview'),
                 ...backendRotation,
                 components: backendComponents,
             });
@@ -187,7 +248,14 @@ export const createBackendSlice: StateCreator<
             const waveformContent =
                 w.waveformType === 'cw'
                     ? { cw: {} }
-                    : { pulsed_from_file: { filename: w.filename } };
+                    : w.waveformType === 'inline_samples'
+                      ? {
+                            inline_samples: {
+                                rate: w.sampleRate,
+                                samples: w.samples,
+                            },
+                        }
+                      : { pulsed_from_file: { filename: w.filename } };
 
             return {
                 name: w.name,
@@ -225,6 +293,8 @@ export const createBackendSlice: StateCreator<
             random_seed,
             oversample_ratio,
             coordinateSystem,
+            multipath,
+            terrain,
             ...gpRest
         } = globalParameters;
 
@@ -237,14 +307,36 @@ export const createBackendSlice: StateCreator<
             coordinatesystem: coordinateSystem,
         };
 
+        const platformsWithMetadata = platforms.filter((p) => p.metadata);
+        const platformMetadata =
+            platformsWithMetadata.length > 0
+                ? Object.fromEntries(
+                      platformsWithMetadata.map((p) => [p.name, p.metadata])
+                  )
+                : undefined;
+
         const scenarioJson = {
             simulation: {
                 name: globalParameters.simulation_name,
                 parameters: cleanObject(gp_params),
+                multipath,
+                terrain,
                 waveforms: cleanObject(backendWaveforms),
                 timings: cleanObject(backendTimings),
                 antennas: cleanObject(backendAntennas),
                 platforms: backendPlatforms,
+                ui_state: {
+                    ...cleanObject<UiState>({
+                        camera: cameraView ?? undefined,
+                        selected_platform: platforms.find(
+                            (p) => p.id === selectedItemId
+                        )?.name,
+                        current_time: currentTime,
+                    }),
+                    // User metadata is passed through as-is, nulls included.
+                    metadata: metadata ?? undefined,
+                    platforms: platformMetadata,
+                },
             },
         };
 
@@ -11,6 +11,7 @@ import {
     PlatformComponent,
 } from '../types';
 import { createDefaultPlatform } from '../defaults';
+import { omit } from '@/utils/typeUtils.ts';
 
 const NUM_PATH_POINTS = 100;
 type InterpolationType = 'static' | 'linear' | 'cubic';
@@ -206,6 +207,7 @@ export const createPlatformSlice: StateCreator<
                     }
                 } else {
                     delete component.rcs_k;
+                    delete component.rcs_swerling_case;
                 }
                 state.isDirty = true;
             }
@@ -246,6 +248,11 @@ export const createPlatformSlice: StateCreator<
                         waypoints,
                         interpType: interpolation as InterpolationType,
                         numPoints: NUM_PATH_POINTS,
+                        options: {
+                            noise: platform.positionNoise?.preview
+                                ? omit(platform.positionNoise, 'preview')
+                                : undefined,
+                        },
                     }
                 );
                 // Convert ENU (Backend) to Three.js coordinates
@@ -15,7 +15,9 @@ import {
     FixedRotation,
     RotationPath,
     PlatformComponent,
+    TargetComponent,
     ScenarioData,
+    UiState,
 } from '../types';
 import { createDefaultPlatform, defaultGlobalParameters } from '../defaults';
 import { setPropertyByPath } from '../utils';
@@ -33,6 +35,16 @@ interface BackendPulsedMode {
     window_length?: number;
 }
 
+interface BackendHopSequence {
+    frequencies: number[];
+    pattern?: number[];
+}
+
+interface BackendBlankingGate {
+    start: number;
+    length: number;
+}
+
 interface BackendSchedulePeriod {
     start: number;
     end: number;
@@ -46,11 +58,30 @@ interface BackendPlatformComponentData {
     noise_temp?: number | null;
     nodirect?: boolean;
     nopropagationloss?: boolean;
+    calibration_file?: string;
+    phase_center_offset?: { x: number; y: number; z: number };
+    mount_height?: number;
     pulsed_mode?: BackendPulsedMode;
+    hop_sequence?: BackendHopSequence;
+    max_duty_cycle?: number;
     cw_mode?: object;
     schedule?: BackendSchedulePeriod[];
-    rcs?: { type: 'isotropic' | 'file'; value?: number; filename?: string };
-    model?: { type: 'constant' | 'chisquare' | 'gamma'; k?: number };
+    blanking_gates?: BackendBlankingGate[];
+    element_array?: { x: number; y: number; z: number }[];
+    rcs?: {
+        type: 'isotropic' | 'file';
+        value?: number;
+        filename?: string;
+        interpolation?: TargetComponent['rcs_interpolation'];
+    };
+    model?: {
+        type: 'constant' | 'chisquare' | 'gamma';
+        k?: number;
+        swerling?: number;
+        sample_rate?: number;
+        decorrelation_time?: number;
+    };
+    micromotion?: TargetComponent['micromotion'];
 }
 
 // Backend waypoint types (frontend type minus 'id')
@@ -59,6 +90,9 @@ interface BackendPositionWaypoint {
     y: number;
     altitude: number;
     time: number;
+    vx?: number;
+    vy?: number;
+    vz?: number;
 }
 
 interface BackendRotationWaypoint {
@@ -71,8 +105,16 @@ interface BackendPlatform {
     name: string;
     motionpath?: {
         interpolation: 'static' | 'linear' | 'cubic';
+        coordinates?: 'local' | 'ecef';
         positionwaypoints?: BackendPositionWaypoint[];
     };
+    position_noise?: {
+        x: number;
+        y: number;
+        z: number;
+        correlation_time?: number;
+        seed?: number;
+    };
     fixedrotation?: {
         startazimuth: number;
         startelevation: number;
@@ -94,6 +136,10 @@ interface BackendWaveform {
     pulsed_from_file?: {
         filename: string;
     };
+    inline_samples?: {
+        rate: number;
+        samples: string;
+    };
 }
 
 export const createScenarioSlice: StateCreator<
@@ -171,6 +217,7 @@ export const createScenarioSlice: StateCreator<
             selectedItemId: null,
             isDirty: false,
             currentTime: defaultGlobalParameters.start,
+            metadata: null,
         }),
     loadScenario: (backendData: unknown) => {
         try {
@@ -188,7 +235,14 @@ export const createScenarioSlice: StateCreator<
                           .simulation as Record<string, unknown>)
                     : (backendData as Record<string, unknown>);
 
-            const nameToIdMap = new Map<string, string>();
+            // Editor-only state, including user metadata, rides in `ui_state`.
+            const uiState = (data.ui_state as UiState | undefined) ?? {};
+
+            // Names are only unique within an asset kind, so each kind gets its
+            // own lookup; a waveform and an antenna may both be called "default".
+            const waveformIds = new Map<string, string>();
+            const timingIds = new Map<string, string>();
+            const antennaIds = new Map<string, string>();
             const assignId = <T extends object>(item: T) => ({
                 ...item,
                 id: uuidv4(),
@@ -208,6 +262,9 @@ export const createScenarioSlice: StateCreator<
                 random_seed: (params.randomseed as number) ?? null,
                 adc_bits: (params.adc_bits as number) ?? 12,
                 oversample_ratio: (params.oversample as number) ?? 1,
+                export: params.export as GlobalParameters['export'],
+                multipath: data.multipath as GlobalParameters['multipath'],
+                terrain: data.terrain as GlobalParameters['terrain'],
                 origin: {
                     latitude:
                         ((params.origin as Record<string, number>)
@@ -232,13 +289,15 @@ export const createScenarioSlice: StateCreator<
                 },
             };
 
-            // 2. Assets (and build name-to-id map)
+            // 2. Assets (and build name-to-id maps)
             const waveforms: Waveform[] = (
                 (data.waveforms as BackendWaveform[]) || []
             ).map((w) => {
                 const waveformType = w.cw
                     ? ('cw' as const)
-                    : ('pulsed_from_file' as const);
+                    : w.inline_samples
+                      ? ('inline_samples' as const)
+                      : ('pulsed_from_file' as const);
                 const filename = w.pulsed_from_file?.filename ?? '';
 
                 const waveform: Waveform = {
@@ -249,8 +308,10 @@ export const createScenarioSlice: StateCreator<
                     power: w.power,
                     carrier_frequency: w.carrier_frequency,
                     filename,
+                    samples: w.inline_samples?.samples,
+                    sampleRate: w.inline_samples?.rate,
                 };
-                nameToIdMap.set(waveform.name, waveform.id);
+                waveformIds.set(waveform.name, waveform.id);
                 return waveform;
             });
 
@@ -272,7 +333,7 @@ export const createScenarioSlice: StateCreator<
                           )
                         : [],
                 };
-                nameToIdMap.set(timing.name, timing.id);
+                timingIds.set(timing.name, timing.id);
                 return timing as Timing;
             });
 
@@ -283,7 +344,7 @@ export const createScenarioSlice: StateCreator<
                     ...assignId(a),
                     type: 'Antenna' as const,
                 };
-                nameToIdMap.set(antenna.name, antenna.id);
+                antennaIds.set(antenna.name, antenna.id);
                 return antenna as Antenna;
             });
 
@@ -293,6 +354,7 @@ export const createScenarioSlice: StateCreator<
             ).map((p): Platform => {
                 const motionPath: MotionPath = {
                     interpolation: p.motionpath?.interpolation ?? 'static',
+                    coordinates: p.motionpath?.coordinates,
                     waypoints: (p.motionpath?.positionwaypoints ?? []).map(
                         assignId
                     ),
@@ -338,15 +400,24 @@ export const createScenarioSlice: StateCreator<
 
                         const commonRadar = {
                             antennaId:
-                                nameToIdMap.get(cData.antenna ?? '') ?? null,
+                                antennaIds.get(cData.antenna ?? '') ?? null,
                             timingId:
-                                nameToIdMap.get(cData.timing ?? '') ?? null,
+                                timingIds.get(cData.timing ?? '') ?? null,
                             schedule: cData.schedule ?? [],
+                            phaseCenterOffset: cData.phase_center_offset,
+                            mountHeight: cData.mount_height,
+                        };
+                        const hopSequence = cData.hop_sequence && {
+                            frequencies: cData.hop_sequence.frequencies,
+                            pattern: cData.hop_sequence.pattern ?? [],
                         };
                         const commonReceiver = {
                             noiseTemperature: cData.noise_temp ?? null,
                             noDirectPaths: cData.nodirect ?? false,
                             noPropagationLoss: cData.nopropagationloss ?? false,
+                            calibrationFile: cData.calibration_file,
+                            blankingGates: cData.blanking_gates,
+                            elementArray: cData.element_array,
                         };
 
                         let newComp: PlatformComponent | null = null;
@@ -363,8 +434,10 @@ export const createScenarioSlice: StateCreator<
                                         pulsed?.window_length ?? null,
                                     prf: pulsed?.prf ?? null,
                                     waveformId:
-                                        nameToIdMap.get(cData.waveform ?? '') ??
+                                        waveformIds.get(cData.waveform ?? '') ??
                                         null,
+                                    hopSequence,
+                                    maxDutyCycle: cData.max_duty_cycle,
                                     ...commonRadar,
                                     ...commonReceiver,
                                 };
@@ -377,8 +450,10 @@ export const createScenarioSlice: StateCreator<
                                     radarType,
                                     prf: pulsed?.prf ?? null,
                                     waveformId:
-                                        nameToIdMap.get(cData.waveform ?? '') ??
+                                        waveformIds.get(cData.waveform ?? '') ??
                                         null,
+                                    hopSequence,
+                                    maxDutyCycle: cData.max_duty_cycle,
                                     ...commonRadar,
                                 };
                                 break;
@@ -404,8 +479,14 @@ export const createScenarioSlice: StateCreator<
                                     rcs_type: cData.rcs?.type ?? 'isotropic',
                                     rcs_value: cData.rcs?.value,
                                     rcs_filename: cData.rcs?.filename,
+                                    rcs_interpolation: cData.rcs?.interpolation,
                                     rcs_model: cData.model?.type ?? 'constant',
                                     rcs_k: cData.model?.k,
+                                    rcs_decorrelation_time:
+                                        cData.model?.decorrelation_time,
+                                    rcs_sample_rate: cData.model?.sample_rate,
+                                    rcs_swerling_case: cData.model?.swerling,
+                                    micromotion: cData.micromotion,
                                 };
                                 break;
                         }
@@ -421,8 +502,22 @@ export const createScenarioSlice: StateCreator<
                     type: 'Platform',
                     name: p.name,
                     motionPath,
+                    ...(p.position_noise && {
+                        positionNoise: {
+                            x: p.position_noise.x,
+                            y: p.position_noise.y,
+                            z: p.position_noise.z,
+                            correlation_time:
+                                p.position_noise.correlation_time ?? 1,
+                            seed: p.position_noise.seed ?? 0,
+                            preview: false,
+                        },
+                    }),
                     rotation,
                     components,
+                    ...(uiState.platforms?.[p.name] && {
+                        metadata: uiState.platforms[p.name],
+                    }),
                 };
             });
 
@@ -445,12 +540,29 @@ export const createScenarioSlice: StateCreator<
                 return;
             }
 
+            // Restore the saved view, if the file carries one.
+            const { start, end } = result.data.globalParameters;
+            const selectedPlatform = result.data.platforms.find(
+                (p) => p.name === uiState.selected_platform
+            );
+
             // Update state with the validated and parsed data.
             set({
                 ...result.data,
-                selectedItemId: null,
+                selectedItemId: selectedPlatform?.id ?? null,
                 isDirty: true,
-                currentTime: result.data.globalParameters.start,
+                currentTime: Math.max(
+                    start,
+                    Math.min(end, uiState.current_time ?? start)
+                ),
+                cameraView: uiState.camera ?? null,
+                metadata: uiState.metadata ?? null,
+                ...(uiState.camera && {
+                    viewControlAction: {
+                        type: 'restore' as const,
+                        timestamp: Date.now(),
+                    },
+                }),
             });
         } catch (error) {
             console.error(
@@ -17,6 +17,8 @@ import {
     RotationPathSchema,
     MotionPathSchema,
     SchedulePeriodSchema,
+    PositionNoiseSchema,
+    HopSequenceSchema,
 } from '../scenarioSchema';
 
 // --- Zod Inferred Types ---
@@ -31,6 +33,8 @@ export type FixedRotation = z.infer<typeof FixedRotationSchema>;
 export type RotationWaypoint = z.infer<typeof RotationWaypointSchema>;
 export type RotationPath = z.infer<typeof RotationPathSchema>;
 export type PlatformComponent = z.infer<typeof PlatformComponentSchema>;
+export type PositionNoise = z.infer<typeof PositionNoiseSchema>;
+export type HopSequence = z.infer<typeof HopSequenceSchema>;
 export type SchedulePeriod = z.infer<typeof SchedulePeriodSchema>;
 export type Platform = z.infer<typeof PlatformSchema> & {
     pathPoints?: {
@@ -92,11 +96,30 @@ export type ScenarioItem =
 
 // --- Store Shape ---
 export type ViewControlAction = {
-    type: 'frame' | 'focus' | 'follow' | null;
+    type: 'frame' | 'focus' | 'follow' | 'restore' | null;
     targetId?: string;
     timestamp: number;
 };
 
+// Camera placement saved with the scenario so a reopened file looks the same.
+export type CameraView = {
+    position: [number, number, number];
+    target: [number, number, number];
+};
+
+// Free-form user data (colors, notes, grouping) with no home in the FERS schema.
+export type UiMetadata = Record<string, unknown>;
+
+// Editor view state stored in the scenario file and ignored by the simulator.
+export type UiState = {
+    camera?: CameraView;
+    selected_platform?: string;
+    current_time?: number;
+    metadata?: UiMetadata;
+    // Per-platform metadata, keyed by platform name.
+    platforms?: Record<string, UiMetadata>;
+};
+
 export type ScenarioState = ScenarioData & {
     selectedItemId: string | null;
     isDirty: boolean;
@@ -112,6 +135,8 @@ export type ScenarioState = ScenarioData & {
     };
     viewControlAction: ViewControlAction;
     visibility: VisualizationLayers;
+    cameraView: CameraView | null;
+    metadata: UiMetadata | null;
 };
 
 // --- Action Slice Types ---
@@ -177,6 +202,7 @@ export type ViewControlActions = {
     toggleFollowItem: (itemId: string) => void;
     clearViewControlAction: () => void;
     toggleLayer: (layer: keyof VisualizationLayers) => void;
+    setCameraView: (view: CameraView) => void;
 };
 
 // --- Full Store Type ---
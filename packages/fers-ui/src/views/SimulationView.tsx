@@ -18,23 +18,44 @@ import {
     ListItemText,
 } from '@mui/material';
 import PlayCircleOutlineIcon from '@mui/icons-material/PlayCircleOutline';
+import StopCircleOutlinedIcon from '@mui/icons-material/StopCircleOutlined';
 import MapIcon from '@mui/icons-material/Map';
 import { useScenarioStore } from '@/stores/scenarioStore';
 import { invoke } from '@tauri-apps/api/core';
 import { save } from '@tauri-apps/plugin-dialog';
 import { listen } from '@tauri-apps/api/event';
 
+type SimulationPhase =
+    | 'initializing'
+    | 'simulating'
+    | 'rendering'
+    | 'exporting'
+    | 'complete';
+
 interface ProgressState {
+    phase: SimulationPhase;
     message: string;
     current: number;
     total: number;
+    percent: number | null;
+    elapsed_seconds: number;
+    eta_seconds: number | null;
 }
 
+const formatDuration = (seconds: number): string => {
+    const total = Math.round(seconds);
+    const h = Math.floor(total / 3600);
+    const m = Math.floor((total % 3600) / 60);
+    const s = total % 60;
+    return h > 0 ? `${h}h ${m}m ${s}s` : m > 0 ? `${m}m ${s}s` : `${s}s`;
+};
+
 export const SimulationView = React.memo(function SimulationView() {
     const isSimulating = useScenarioStore((state) => state.isSimulating);
     const setIsSimulating = useScenarioStore((state) => state.setIsSimulating);
     const showError = useScenarioStore((state) => state.showError);
     const [isGeneratingKml, setIsGeneratingKml] = useState(false);
+    const [isCancelling, setIsCancelling] = useState(false);
 
     // Use a Ref to store incoming data to avoid triggering re-renders on every event
     const progressRef = useRef<Record<string, ProgressState>>({});
@@ -54,13 +75,31 @@ export const SimulationView = React.memo(function SimulationView() {
             }
         };
 
-        const unlistenSimComplete = listen<void>('simulation-complete', () => {
-            console.log('Simulation completed successfully.');
-            setIsSimulating(false);
-            progressRef.current = {};
-            setDisplayProgress({});
-            cancelAnimationFrame(animationFrameId);
-        });
+        const unlistenSimComplete = listen<string[]>(
+            'simulation-complete',
+            (event) => {
+                console.log(
+                    'Simulation completed successfully. Output files:',
+                    event.payload
+                );
+                setIsSimulating(false);
+                progressRef.current = {};
+                setDisplayProgress({});
+                cancelAnimationFrame(animationFrameId);
+            }
+        );
+
+        const unlistenSimCancelled = listen<void>(
+            'simulation-cancelled',
+            () => {
+                console.log('Simulation cancelled.');
+                setIsSimulating(false);
+                setIsCancelling(false);
+                progressRef.current = {};
+                setDisplayProgress({});
+                cancelAnimationFrame(animationFrameId);
+            }
+        );
 
         const unlistenSimError = listen<string>('simulation-error', (event) => {
             const errorMessage = `Simulation failed: ${event.payload}`;
@@ -75,14 +114,11 @@ export const SimulationView = React.memo(function SimulationView() {
         const unlistenSimProgress = listen<ProgressState>(
             'simulation-progress',
             (event) => {
-                const { message } = event.payload;
+                const { message, phase } = event.payload;
                 let key = message;
 
                 // Grouping logic to keep the list clean
-                if (
-                    message.startsWith('Simulating') ||
-                    message.startsWith('Initializing')
-                ) {
+                if (phase === 'simulating' || phase === 'initializing') {
                     key = 'main';
                 } else if (
                     message.startsWith('Finalizing') ||
@@ -132,6 +168,7 @@ export const SimulationView = React.memo(function SimulationView() {
             cancelAnimationFrame(animationFrameId);
             Promise.all([
                 unlistenSimComplete,
+                unlistenSimCancelled,
                 unlistenSimError,
                 unlistenSimProgress,
                 unlistenKmlComplete,
@@ -145,6 +182,7 @@ export const SimulationView = React.memo(function SimulationView() {
     const handleRunSimulation = async () => {
         progressRef.current = {};
         setDisplayProgress({});
+        setIsCancelling(false);
         setIsSimulating(true);
         try {
             // Ensure the C++ backend has the latest scenario from the UI
@@ -159,6 +197,21 @@ export const SimulationView = React.memo(function SimulationView() {
         }
     };
 
+    const handleCancelSimulation = async () => {
+        setIsCancelling(true);
+        try {
+            // The run stops at its next pulse or window boundary and then
+            // emits 'simulation-cancelled'.
+            await invoke('cancel_simulation');
+        } catch (err) {
+            const errorMessage =
+                err instanceof Error ? err.message : String(err);
+            console.error('Failed to cancel simulation:', errorMessage);
+            showError(`Failed to cancel simulation: ${errorMessage}`);
+            setIsCancelling(false);
+        }
+    };
+
     const handleGenerateKml = async () => {
         try {
             const outputPath = await save({
@@ -230,6 +283,18 @@ export const SimulationView = React.memo(function SimulationView() {
                             >
                                 {isSimulating ? 'Running...' : 'Run Simulation'}
                             </Button>
+                            {isSimulating && (
+                                <Button
+                                    variant="outlined"
+                                    color="error"
+                                    size="large"
+                                    startIcon={<StopCircleOutlinedIcon />}
+                                    disabled={isCancelling}
+                                    onClick={handleCancelSimulation}
+                                >
+                                    {isCancelling ? 'Cancelling...' : 'Cancel'}
+                                </Button>
+                            )}
                         </CardActions>
                     </Card>
                 </Grid>
@@ -322,6 +387,19 @@ export const SimulationView = React.memo(function SimulationView() {
                             </Box>
                         </Box>
                     )}
+                    {mainProgress && mainProgress.eta_seconds !== null && (
+                        <Typography
+                            variant="body2"
+                            color="text.secondary"
+                            sx={{ textAlign: 'center' }}
+                        >
+                            {`Elapsed ${formatDuration(
+                                mainProgress.elapsed_seconds
+                            )} · about ${formatDuration(
+                                mainProgress.eta_seconds
+                            )} remaining`}
+                        </Typography>
+                    )}
 
                     {/* Finalizer Threads List */}
                     {otherProgresses.length > 0 && (
@@ -1,6 +1,7 @@
 #ifndef FERS_API_H
 #define FERS_API_H
 
+#include <stdbool.h>
 #include <stddef.h>
 
 #ifdef __cplusplus
@@ -24,19 +25,51 @@ struct fers_context;
 
 typedef struct fers_context fers_context_t;
 
+/**
+ * @brief The stage of a simulation run that a progress update refers to.
+ */
+typedef enum
+{
+	FERS_PROGRESS_INITIALIZING, ///< Setting up receivers and finalizer threads.
+	FERS_PROGRESS_SIMULATING, ///< Advancing the event-driven simulation loop.
+	FERS_PROGRESS_RENDERING, ///< Rendering a receiver's signal, interference and noise.
+	FERS_PROGRESS_EXPORTING, ///< Writing receiver output to HDF5.
+	FERS_PROGRESS_COMPLETE ///< The run has finished and all output is written.
+} fers_progress_phase_t;
+
 /**
  * @brief A function pointer type for progress reporting callbacks.
  *
  * This callback can be implemented by the client to receive progress updates
- * during long-running operations like `fers_run_simulation`.
+ * during long-running operations like `fers_run_simulation`. It may be called
+ * from several finalizer threads, but never concurrently.
  *
+ * @param phase The stage of the run the update refers to.
  * @param message A descriptive message about the current operation.
  * @param current The current progress step.
- * @param total The total number of steps for the operation.
+ * @param total The total number of steps for the operation, or 0 if unknown.
  * @param user_data An opaque pointer passed back to the caller, useful for
  *                  maintaining state (e.g., a class instance or application handle).
  */
-typedef void (*fers_progress_callback_t)(const char* message, int current, int total, void* user_data);
+typedef void (*fers_progress_callback_t)(fers_progress_phase_t phase, const char* message, int current, int total,
+										 void* user_data);
+
+/**
+ * @brief A function pointer type for receiving finished receive windows.
+ *
+ * The callback is invoked from the receiver finalizer threads, one call at a time, after
+ * each window has been quantized. The sample buffer is only valid for the duration of the
+ * call, so a consumer that defers work must copy it.
+ *
+ * @param receiver_name The name of the receiver that produced the window.
+ * @param start_time The start time of the window in seconds.
+ * @param fullscale The factor that restores the quantized samples to physical units.
+ * @param samples Interleaved I/Q samples, `2 * sample_count` values.
+ * @param sample_count The number of complex samples in the window.
+ * @param user_data An opaque pointer passed back to the caller.
+ */
+typedef void (*fers_window_callback_t)(const char* receiver_name, double start_time, double fullscale,
+									   const double* samples, size_t sample_count, void* user_data);
 
 
 // --- Context Lifecycle ---
@@ -57,6 +90,23 @@ typedef void (*fers_progress_callback_t)(const char* message, int current, int t
  */
 fers_context_t* fers_context_create();
 
+/**
+ * @brief Creates a new context holding a copy of a scenario reduced to some of its platforms.
+ *
+ * The copy contains only the named platforms and the waveforms, timings and antennas
+ * they use. The source context is not modified, so the copy can be run on its own to
+ * simulate just the platforms of interest.
+ *
+ * @param context A valid `fers_context_t` handle containing a loaded scenario.
+ * @param platform_names An array of `platform_count` null-terminated platform names.
+ * @param platform_count The number of names.
+ * @return A new context that must be destroyed with `fers_context_destroy()`, or NULL if a
+ *         name matches no platform or the selection has no receiver or no transmitter (a
+ *         monostatic radar counts as both).
+ */
+fers_context_t* fers_context_create_subset(const fers_context_t* context, const char* const* platform_names,
+										   size_t platform_count);
+
 /**
  * @brief Destroys a FERS simulation context and releases all associated memory.
  *
@@ -138,6 +188,89 @@ int fers_load_scenario_from_xml_file(fers_context_t* context, const char* xml_fi
  */
 int fers_load_scenario_from_xml_string(fers_context_t* context, const char* xml_content, int validate);
 
+/**
+ * @brief The outcome of a dry-run validation of a FERS XML scenario file.
+ * @note The caller owns this struct and MUST free it with `fers_free_xml_validation`.
+ */
+typedef struct
+{
+	int valid; // 1 if the scenario would load, 0 otherwise.
+	char* error_message; // Reason the load would fail, or NULL when valid.
+	char** warnings; // Warnings raised while parsing the scenario.
+	size_t warning_count; // Number of entries in `warnings`.
+} fers_xml_validation_t;
+
+/**
+ * @brief Checks that a FERS XML file parses and passes schema validation without loading it.
+ *
+ * The file is parsed into a scratch scenario that is discarded afterwards, so no
+ * context and no global simulation parameters are modified. A file that fails to
+ * parse is reported through the returned struct rather than as an API error.
+ *
+ * @param xml_filepath A null-terminated UTF-8 string for the XML file path.
+ * @return A pointer to the validation outcome, or NULL if the arguments were invalid.
+ */
+fers_xml_validation_t* fers_validate_xml_file(const char* xml_filepath);
+
+/**
+ * @brief Frees a validation outcome returned by `fers_validate_xml_file`.
+ * @param validation The struct to free.
+ */
+void fers_free_xml_validation(fers_xml_validation_t* validation);
+
+/**
+ * @brief A list of owned, null-terminated strings.
+ * @note The caller owns this struct and must free it with `fers_free_string_list`.
+ */
+typedef struct
+{
+	char** items;
+	size_t count;
+} fers_string_list_t;
+
+/**
+ * @brief Finds NaN and infinite numbers in the loaded scenario.
+ *
+ * Each entry names the offending field by its path in the JSON representation of the
+ * scenario, e.g. `platforms[0].motionpath.positionwaypoints[1].x is NaN`. Such values
+ * cannot be exported to XML, so `fers_get_scenario_as_xml` fails while any remain.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @return A pointer to the (possibly empty) list of issues, or NULL on error.
+ */
+fers_string_list_t* fers_find_non_finite_values(const fers_context_t* context);
+
+/**
+ * @brief Finds transmitters whose duty cycle exceeds their configured maximum.
+ *
+ * The duty cycle of a pulsed transmitter is its pulse length times its PRF; a CW
+ * transmitter is always emitting. Transmitters without a `max_duty_cycle` are not checked.
+ * Each entry names the transmitter and gives its computed duty cycle and limit.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @return A pointer to the (possibly empty) list of violations, or NULL on error.
+ */
+fers_string_list_t* fers_find_duty_cycle_violations(const fers_context_t* context);
+
+/**
+ * @brief Finds pulsed receivers whose listening windows overlap the next window.
+ *
+ * A window opens `window_skip` after each pulse and stays open for `window_length`, so
+ * the two must fit within the pulse repetition interval `1 / prf`; a window ending
+ * exactly at the next pulse is accepted. Monostatic radars are checked through their
+ * receiver. Each entry names the receiver and gives its PRI and the overlap.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @return A pointer to the (possibly empty) list of overlaps, or NULL on error.
+ */
+fers_string_list_t* fers_find_receiver_window_overlaps(const fers_context_t* context);
+
+/**
+ * @brief Frees a string list returned by the library.
+ * @param list The list to free.
+ */
+void fers_free_string_list(fers_string_list_t* list);
+
 /**
  * @brief Serializes the current simulation scenario into a JSON string.
  *
@@ -173,6 +306,32 @@ char* fers_get_scenario_as_json(fers_context_t* context);
  */
 char* fers_get_scenario_as_xml(fers_context_t* context);
 
+/**
+ * @brief Serializes the current simulation scenario into a FERS XML string with a checksum.
+ *
+ * The XML is the same as from `fers_get_scenario_as_xml`, followed by a comment holding the
+ * SHA-256 of the document. Loading the file verifies the checksum and logs a warning if the
+ * file has been corrupted or edited since export.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @return A dynamically allocated, null-terminated C-string that must be freed with
+ *         `fers_free_string()`, or NULL on failure.
+ */
+char* fers_get_scenario_as_xml_with_checksum(fers_context_t* context);
+
+/**
+ * @brief Serializes the current simulation scenario into a FERS XML string checked against the schema.
+ *
+ * The XML is the same as from `fers_get_scenario_as_xml`, validated against the bundled
+ * `fers-xml.dtd` and XSD before it is returned. If the generated document is invalid, NULL is
+ * returned and `fers_get_last_error_message()` reports the first validation error.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @return A dynamically allocated, null-terminated C-string that must be freed with
+ *         `fers_free_string()`, or NULL on failure.
+ */
+char* fers_get_scenario_as_xml_validated(fers_context_t* context);
+
 /**
  * @brief Updates the simulation scenario from a JSON string.
  *
@@ -188,6 +347,97 @@ char* fers_get_scenario_as_xml(fers_context_t* context);
  */
 int fers_update_scenario_from_json(fers_context_t* context, const char* scenario_json);
 
+/**
+ * @brief Adds platforms from a captured log of DIS Entity State PDUs to the scenario.
+ *
+ * Each unique entity id becomes a platform whose waypoints are its successive location
+ * updates, converted from geocentric coordinates into the scenario frame using the
+ * scenario origin. Other PDU types in the log are skipped.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param filepath A null-terminated UTF-8 string for the path of the binary PDU log.
+ * @param out_count On success, receives the number of platforms created.
+ * @return 0 on success, non-zero on failure. The scenario is unchanged on failure.
+ *         Use `fers_get_last_error_message()` for details.
+ */
+int fers_import_dis_entity_states(fers_context_t* context, const char* filepath, size_t* out_count);
+
+/**
+ * @brief Replaces the scenario with the platform geometry recorded in a FERS output file.
+ *
+ * Every receiver output file written by a simulation carries the platform motion and
+ * rotation paths of the run. Importing one reconstructs those platforms with their
+ * waypoints; waveforms, antennas, timing sources and radar components are not recorded
+ * and must be added again before the scenario can be simulated.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param hdf5_path A null-terminated UTF-8 string for the path of the HDF5 output file.
+ * @return 0 on success.
+ *         1 if the file cannot be read or carries no geometry metadata.
+ *         2 if the recorded geometry cannot be deserialized.
+ *         Use `fers_get_last_error_message()` to retrieve error details.
+ */
+int fers_import_scenario_from_output(fers_context_t* context, const char* hdf5_path);
+
+/**
+ * @brief Speeds up or slows down the whole scenario by scaling its timeline.
+ *
+ * The simulation start and end times and every motion and rotation waypoint time are
+ * multiplied by `factor`, and fixed rotation rates are divided by it. Platforms keep
+ * their spatial paths while their velocities scale by 1/`factor`. Radar schedules,
+ * PRFs and waveforms are not changed.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param factor The time-scaling factor; must be positive.
+ * @return 0 on success, non-zero on failure. The scenario is unchanged if `factor` is
+ *         rejected. Use `fers_get_last_error_message()` for details.
+ */
+int fers_time_scale_scenario(fers_context_t* context, double factor);
+
+/**
+ * @brief Adds a deep copy of a platform to the scenario.
+ *
+ * The copy keeps the original's motion and rotation paths and all of its components.
+ * The platform and component names get a " (copy)" suffix, numbered as " (copy 2)" and
+ * so on if that name is already taken.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param platform_name The name of the platform to copy.
+ * @return A heap-allocated, null-terminated string with the name of the new platform,
+ *         or NULL if the platform does not exist. The caller owns the returned string
+ *         and must free it with `fers_free_string()`.
+ */
+char* fers_duplicate_platform(fers_context_t* context, const char* platform_name);
+
+/**
+ * @brief Counts of the items added by `fers_merge_scenario_from_xml_file`.
+ */
+typedef struct
+{
+	size_t waveforms; /**< Number of waveforms added. */
+	size_t timings; /**< Number of timing sources added. */
+	size_t antennas; /**< Number of antennas added. */
+	size_t platforms; /**< Number of platforms added. */
+} fers_merge_summary_t;
+
+/**
+ * @brief Imports the assets of another scenario file into the current scenario.
+ *
+ * The waveforms, timings and antennas of the file are appended, and its platforms too
+ * if `include_platforms` is non-zero. Existing items and the current parameters are left
+ * untouched. Imported items whose names are taken get a numbered suffix such as " (2)",
+ * and imported components follow their renamed assets.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param xml_filepath A null-terminated UTF-8 string for the path of the scenario file.
+ * @param include_platforms Non-zero to import the file's platforms as well.
+ * @param out_summary On success, receives the number of items of each kind added.
+ * @return 0 on success, non-zero on failure. The scenario is unchanged on failure.
+ *         Use `fers_get_last_error_message()` for details.
+ */
+int fers_merge_scenario_from_xml_file(fers_context_t* context, const char* xml_filepath, int include_platforms,
+									  fers_merge_summary_t* out_summary);
+
 
 // --- Error Handling ---
 
@@ -236,10 +486,77 @@ void fers_free_string(char* str);
  * @param context A valid `fers_context_t` handle containing a loaded scenario.
  * @param callback A function pointer to a progress callback. Can be NULL.
  * @param user_data An opaque pointer passed to the callback function.
- * @return 0 on success, a non-zero error code on failure. Use
- *         `fers_get_last_error_message()` to retrieve error details.
+ * @param cancel_flag A flag that another thread sets to stop the run, or NULL. It is
+ *                    checked before each pulse and receive-window event, so the run stops
+ *                    at the next boundary. Pulsed windows already completed are still
+ *                    written; CW receiver output is not. Setting it after the run has
+ *                    returned has no effect on that run.
+ * @return 0 on success.
+ *         1 on error.
+ *         2 if the run was cancelled through `cancel_flag`.
+ *         Use `fers_get_last_error_message()` to retrieve error details.
+ */
+int fers_run_simulation(fers_context_t* context, fers_progress_callback_t callback, void* user_data,
+						const bool* cancel_flag);
+
+/**
+ * @brief Runs the simulation, writing its output files to a chosen directory.
+ *
+ * Behaves like `fers_run_simulation`, but receivers write
+ * `<output_dir>/<basename>_<receiver>_results.h5` (and the pulse timestamp CSV, if
+ * enabled, `<output_dir>/<basename>_pulse_timestamps.csv`, and the debug trace CSV, if
+ * enabled, `<output_dir>/<basename>_debug_trace.csv`). Use
+ * `fers_list_output_files` to obtain the paths.
+ *
+ * @param context A valid `fers_context_t` handle containing a loaded scenario.
+ * @param output_dir A null-terminated UTF-8 path of an existing directory.
+ * @param basename A null-terminated UTF-8 file name prefix, or NULL/empty for none.
+ * @param callback A function pointer to a progress callback. Can be NULL.
+ * @param user_data An opaque pointer passed to the callback function.
+ * @param cancel_flag A cancellation flag, or NULL, as for `fers_run_simulation`.
+ * @return 0 on success, 2 if cancelled, another non-zero error code on failure
+ *         (including a missing output directory).
+ */
+int fers_run_simulation_to(fers_context_t* context, const char* output_dir, const char* basename,
+						   fers_progress_callback_t callback, void* user_data, const bool* cancel_flag);
+
+/**
+ * @brief Lists the files a simulation run of the loaded scenario writes.
+ *
+ * @param context A valid `fers_context_t` handle containing a loaded scenario.
+ * @param output_dir The output directory as passed to `fers_run_simulation_to`, or NULL
+ *                   for the working directory used by `fers_run_simulation`.
+ * @param basename The file name prefix, or NULL/empty for none.
+ * @return The receiver HDF5 files followed by the pulse timestamp and debug trace CSVs if
+ *         those exports are enabled, or NULL on error. Caller must free with `fers_free_string_list`.
+ */
+fers_string_list_t* fers_list_output_files(const fers_context_t* context, const char* output_dir,
+										   const char* basename);
+
+/**
+ * @brief Runs the simulation and hands each finished receive window to a callback.
+ *
+ * Output is still written to HDF5 as with `fers_run_simulation_to`. Pulsed receivers
+ * deliver one call per receive window; continuous-wave receivers deliver their whole
+ * buffer in a single call once the simulation ends. The window callback blocks the
+ * finalizer that produced the window, so it should return quickly.
+ *
+ * @param context A valid `fers_context_t` handle containing a loaded scenario.
+ * @param output_dir An existing directory for the output files, as for `fers_run_simulation_to`,
+ *                   or NULL for the working directory.
+ * @param basename A file name prefix, or NULL/empty for none.
+ * @param progress_callback A function pointer to a progress callback. Can be NULL.
+ * @param progress_user_data An opaque pointer passed to the progress callback.
+ * @param window_callback A function pointer that receives each finished window.
+ * @param window_user_data An opaque pointer passed to the window callback.
+ * @param cancel_flag A cancellation flag, or NULL, as for `fers_run_simulation`.
+ * @return 0 on success, 2 if cancelled, another non-zero error code on failure
+ *         (including a missing output directory).
  */
-int fers_run_simulation(fers_context_t* context, fers_progress_callback_t callback, void* user_data);
+int fers_run_simulation_streaming(fers_context_t* context, const char* output_dir, const char* basename,
+								  fers_progress_callback_t progress_callback, void* progress_user_data,
+								  fers_window_callback_t window_callback, void* window_user_data,
+								  const bool* cancel_flag);
 
 
 // --- Utility Functions ---
@@ -258,6 +575,39 @@ int fers_run_simulation(fers_context_t* context, fers_progress_callback_t callba
  */
 int fers_generate_kml(const fers_context_t* context, const char* output_kml_filepath);
 
+/**
+ * @brief Generates a KML file for the scenario about a given ENU origin.
+ *
+ * Identical to `fers_generate_kml`, except that the local ENU frame is placed at the
+ * given origin instead of the scenario's own, so the layout can be previewed at another
+ * site without editing the scenario. Platforms that declare their own origin keep it.
+ *
+ * @param context A valid `fers_context_t` handle containing a loaded scenario.
+ * @param output_kml_filepath A null-terminated UTF-8 string for the output KML file path.
+ * @param origin_latitude The origin latitude in decimal degrees.
+ * @param origin_longitude The origin longitude in decimal degrees.
+ * @param origin_altitude The origin altitude above the ellipsoid in meters.
+ * @return 0 on success, a non-zero error code on failure (e.g., an origin outside the valid
+ *         latitude/longitude range, or a scenario not in the ENU coordinate frame). Use
+ *         `fers_get_last_error_message()` to retrieve error details.
+ */
+int fers_generate_kml_with_origin(const fers_context_t* context, const char* output_kml_filepath,
+								  double origin_latitude, double origin_longitude, double origin_altitude);
+
+/**
+ * @brief Checks that inline waveform samples decode and could be loaded.
+ *
+ * The samples use the format of `<inline_samples>`: comma-separated I,Q pairs such as
+ * `1,0, 0,1`. This is a stateless utility for validating a waveform while it is edited.
+ *
+ * @param samples A null-terminated CSV list of I/Q values.
+ * @param rate The sample rate in Hz. Must be positive.
+ * @param out_count On success, receives the number of complex samples.
+ * @return 0 on success, non-zero on failure (e.g., a value that is not a number, an odd number
+ *         of values, no samples or a bad rate). Use `fers_get_last_error_message()` for details.
+ */
+int fers_validate_inline_waveform(const char* samples, double rate, size_t* out_count);
+
 // --- Antenna Pattern Utilities ---
 
 /**
@@ -301,6 +651,135 @@ fers_antenna_pattern_data_t* fers_get_antenna_pattern(const fers_context_t* cont
  */
 void fers_free_antenna_pattern_data(fers_antenna_pattern_data_t* data);
 
+/**
+ * @brief The gain pattern models an antenna definition can use.
+ */
+typedef enum
+{
+	FERS_ANTENNA_ISOTROPIC,
+	FERS_ANTENNA_SINC,
+	FERS_ANTENNA_GAUSSIAN,
+	FERS_ANTENNA_SQUAREHORN,
+	FERS_ANTENNA_PARABOLIC,
+	FERS_ANTENNA_ARRAY,
+	FERS_ANTENNA_XML,
+	FERS_ANTENNA_FILE
+} fers_antenna_pattern_t;
+
+/**
+ * @brief A standalone antenna definition, as edited in the UI before it joins a scenario.
+ * Only the fields used by `pattern` are read.
+ */
+typedef struct
+{
+	fers_antenna_pattern_t pattern; // The gain pattern model.
+	double efficiency; // Aperture efficiency factor in [0, 1].
+	double alpha; // Sinc: peak gain.
+	double beta; // Sinc: beamwidth scale.
+	double gamma; // Sinc: sidelobe exponent.
+	double azscale; // Gaussian: azimuth scale.
+	double elscale; // Gaussian: elevation scale.
+	double diameter; // Square horn and parabolic: aperture dimension in meters.
+	size_t elements; // Array: number of elements.
+	double spacing; // Array: element spacing in meters.
+	const double* taper; // Array: element weights, or NULL for a uniform taper.
+	size_t taper_count; // Array: number of entries in `taper`.
+	const char* filename; // XML and file: path to the pattern file.
+} fers_antenna_t;
+
+/**
+ * @brief Samples the gain of an antenna definition along an azimuth cut at fixed elevation.
+ *
+ * The antenna is built with the same pattern classes the simulator uses, so the cut matches
+ * the gain applied during a run. Angles are relative to boresight, and the gains are absolute
+ * (not normalized) in dBi, with negative infinity marking a null. This is a stateless utility function.
+ *
+ * @param antenna The antenna definition.
+ * @param az_start_deg The first azimuth of the cut in degrees.
+ * @param az_end_deg The last azimuth of the cut in degrees.
+ * @param elevation_deg The elevation of the cut in degrees.
+ * @param frequency_hz The frequency in Hz for wavelength-dependent patterns; <= 0 uses 1 GHz.
+ * @param num_points The number of evenly spaced azimuths, including both ends. Must be > 0.
+ * @param out_gains_db A caller-allocated array of `num_points` doubles that receives the gains.
+ * @return 0 on success, non-zero on failure (e.g. a pattern file could not be loaded).
+ *         Use `fers_get_last_error_message()` for details.
+ */
+int fers_sample_antenna_pattern(const fers_antenna_t* antenna, double az_start_deg, double az_end_deg,
+								double elevation_deg, double frequency_hz, size_t num_points, double* out_gains_db);
+
+/**
+ * @brief The grating lobes of an array antenna at a given scan angle.
+ * @note The `lobe_angles_deg` array is owned by this struct and must be freed using
+ *       `fers_free_grating_lobe_report`.
+ */
+typedef struct
+{
+	double wavelength; /**< The wavelength used, in meters. */
+	double spacing_wavelengths; /**< The element spacing in wavelengths. */
+	double* lobe_angles_deg; /**< Grating lobe azimuths relative to boresight in degrees, ascending. */
+	size_t lobe_count; /**< Number of grating lobes in visible space. */
+} fers_grating_lobe_report_t;
+
+/**
+ * @brief Finds the grating lobes in visible space of an array antenna steered off boresight.
+ *
+ * A grating lobe appears wherever `sin(theta) = sin(scan) + m * lambda / d` has a solution
+ * with `|sin(theta)| <= 1` for a non-zero integer `m`.
+ *
+ * @param context A `fers_context_t` handle used only to look up a carrier, or NULL.
+ * @param antenna The array antenna definition.
+ * @param antenna_name The name of the scenario antenna the definition belongs to, used only to
+ *                     look up a carrier, or NULL.
+ * @param scan_angle_deg The azimuth of the main beam relative to boresight, in degrees.
+ * @param frequency_hz The carrier frequency in Hz. If zero or negative, the carrier of the
+ *                     first transmitter in `context` using the antenna named `antenna_name` is used.
+ * @return A pointer to the report, or NULL on failure (e.g. the antenna is not an array or
+ *         no carrier is available). The caller must free it with `fers_free_grating_lobe_report`.
+ */
+fers_grating_lobe_report_t* fers_find_grating_lobes(const fers_context_t* context, const fers_antenna_t* antenna,
+													const char* antenna_name, double scan_angle_deg,
+													double frequency_hz);
+
+/**
+ * @brief Frees the memory allocated for a grating lobe report.
+ * @param report The report to free.
+ */
+void fers_free_grating_lobe_report(fers_grating_lobe_report_t* report);
+
+/**
+ * @brief Computes the effective aperture of an antenna from its sampled peak gain.
+ *
+ * The effective aperture is `G * lambda^2 / (4 * pi)`. For antennas defined by their
+ * geometry (parabolic and square horn), the physical aperture scaled by the antenna
+ * efficiency is also returned as a cross-check. This is a stateless utility function.
+ *
+ * @param antenna The antenna definition.
+ * @param frequency_hz The carrier frequency in Hz.
+ * @param out_effective_aperture Output pointer for the effective aperture in square meters.
+ * @param out_physical_aperture Optional output pointer for the physical aperture times
+ *                              efficiency in square meters, set to NaN if the antenna has no
+ *                              physical dimensions. May be NULL.
+ * @return 0 on success, non-zero on failure.
+ */
+int fers_calculate_effective_aperture(const fers_antenna_t* antenna, double frequency_hz,
+									  double* out_effective_aperture, double* out_physical_aperture);
+
+/**
+ * @brief Computes the -3 dB beamwidths of an antenna's main lobe.
+ *
+ * The azimuth and elevation cuts through boresight are searched outwards for the angles
+ * at which the gain falls to half its boresight value. This is a stateless utility function.
+ *
+ * @param antenna The antenna definition.
+ * @param frequency_hz The carrier frequency in Hz.
+ * @param out_azimuth_deg Output pointer for the full azimuth beamwidth in degrees.
+ * @param out_elevation_deg Output pointer for the full elevation beamwidth in degrees.
+ * @return 0 on success, non-zero on failure, including patterns with no main lobe at
+ *         boresight or whose gain never falls 3 dB below the peak.
+ */
+int fers_compute_beamwidth(const fers_antenna_t* antenna, double frequency_hz, double* out_azimuth_deg,
+						   double* out_elevation_deg);
+
 
 // --- Path Interpolation Utilities ---
 
@@ -402,12 +881,140 @@ fers_interpolated_path_t* fers_get_interpolated_motion_path(const fers_motion_wa
 															size_t waypoint_count, fers_interp_type_t interp_type,
 															size_t num_points);
 
+/**
+ * @brief Calculates a fast, approximate motion path preview from a set of waypoints.
+ *
+ * For cubic paths, the exact path is replaced by a piecewise-linear approximation whose
+ * vertices are adaptively subdivided until the position error stays within `tolerance`.
+ * This trades accuracy for speed when scrubbing long paths interactively. Static and
+ * linear paths are sampled exactly. Simulations always use exact interpolation.
+ *
+ * @param waypoints An array of `fers_motion_waypoint_t` structs.
+ * @param waypoint_count The number of waypoints in the array.
+ * @param interp_type The interpolation algorithm to use.
+ * @param num_points The desired number of points in the output interpolated path.
+ * @param tolerance The maximum allowed position error in meters (must be positive).
+ * @return A pointer to a `fers_interpolated_path_t` struct containing the results.
+ *         Returns NULL on failure. The caller owns the returned struct and must
+ *         free it with `fers_free_interpolated_motion_path`.
+ */
+fers_interpolated_path_t* fers_get_approximate_motion_path(const fers_motion_waypoint_t* waypoints,
+														   size_t waypoint_count, fers_interp_type_t interp_type,
+														   size_t num_points, double tolerance);
+
+/**
+ * @brief Calculates a motion path preview over part of the waypoint time span.
+ *
+ * The points are spread evenly from `start_time` to `end_time`, each clamped to the
+ * times of the first and last waypoint, so a zoomed timeline can be previewed at full
+ * resolution. Either bound may be infinite to keep that end of the waypoint span.
+ *
+ * @param waypoints An array of `fers_motion_waypoint_t` structs.
+ * @param waypoint_count The number of waypoints in the array.
+ * @param interp_type The interpolation algorithm to use.
+ * @param num_points The desired number of points in the output interpolated path.
+ * @param start_time The time of the first point in seconds.
+ * @param end_time The time of the last point in seconds.
+ * @param tolerance Zero to sample the exact path, or the maximum position error in meters
+ *        of the approximation used by `fers_get_approximate_motion_path`.
+ * @return A pointer to a `fers_interpolated_path_t` struct containing the results.
+ *         Returns NULL on failure, including a window that does not overlap the waypoints.
+ *         The caller owns the returned struct and must free it with
+ *         `fers_free_interpolated_motion_path`.
+ */
+fers_interpolated_path_t* fers_get_motion_path_window(const fers_motion_waypoint_t* waypoints, size_t waypoint_count,
+													  fers_interp_type_t interp_type, size_t num_points,
+													  double start_time, double end_time, double tolerance);
+
 /**
  * @brief Frees the memory allocated for an interpolated motion path.
  * @param path A pointer to the `fers_interpolated_path_t` struct to free.
  */
 void fers_free_interpolated_motion_path(fers_interpolated_path_t* path);
 
+/**
+ * @brief Adds a platform position noise realization to a sampled motion path.
+ *
+ * The path's points are assumed to be evenly spaced in time from `start_time` to
+ * `end_time`, as produced by `fers_get_interpolated_motion_path`. The noise is the
+ * same deterministic function of seed and time that the simulation engine uses,
+ * so the result shows the exact deviation a simulation with this seed will follow.
+ * Velocities gain the rate of change of the noise, as they do in the engine.
+ *
+ * @param path The path to perturb in place.
+ * @param start_time The time of the first point in seconds.
+ * @param end_time The time of the last point in seconds.
+ * @param std_x Standard deviation of the x-axis noise in meters.
+ * @param std_y Standard deviation of the y-axis noise in meters.
+ * @param std_z Standard deviation of the z-axis noise in meters.
+ * @param correlation_time Interval between independent noise draws in seconds. Must be > 0,
+ *                         or exactly 0 to use the engine's default interval.
+ * @param seed The seed selecting the noise realization.
+ * @return 0 on success, non-zero on failure.
+ */
+int fers_apply_position_noise(fers_interpolated_path_t* path, double start_time, double end_time, double std_x,
+							  double std_y, double std_z, double correlation_time, unsigned seed);
+
+/**
+ * @brief Lists the carrier frequency each pulse of a frequency-hopping transmitter uses.
+ *
+ * Applies the same selection rule as the simulation engine: pulse `n` uses
+ * `frequencies[pattern[n % pattern_count]]`, or `frequencies[n % frequency_count]`
+ * when no pattern is given.
+ *
+ * @param frequencies The hop set of carrier frequencies in Hz.
+ * @param frequency_count The number of entries in `frequencies`. Must be > 0.
+ * @param pattern Optional indices into `frequencies` giving the hop order. May be NULL if `pattern_count` is 0.
+ * @param pattern_count The number of entries in `pattern`.
+ * @param num_pulses The number of pulses to list.
+ * @param out_carriers Caller-allocated array of `num_pulses` doubles that receives the carriers.
+ * @return 0 on success, non-zero on failure (e.g., an empty hop set or an out-of-range pattern index).
+ */
+int fers_preview_hop_sequence(const double* frequencies, size_t frequency_count, const unsigned* pattern,
+							  size_t pattern_count, size_t num_pulses, double* out_carriers);
+
+/**
+ * @brief Represents a single motion waypoint in WGS84 geodetic coordinates.
+ */
+typedef struct
+{
+	double time; /**< Time in seconds. */
+	double latitude; /**< Latitude in decimal degrees. */
+	double longitude; /**< Longitude in decimal degrees. */
+	double altitude; /**< Altitude above the WGS84 ellipsoid in meters. */
+} fers_geodetic_waypoint_t;
+
+/**
+ * @brief Converts geodetic waypoints to the local ENU frame of a geodetic origin.
+ *
+ * Uses the same WGS84 tangent-plane conversion as the KML export, so waypoints entered
+ * in latitude/longitude appear where they were placed. Times are copied unchanged.
+ *
+ * @param origin_latitude The origin latitude in decimal degrees.
+ * @param origin_longitude The origin longitude in decimal degrees.
+ * @param origin_altitude The origin altitude above the ellipsoid in meters.
+ * @param points An array of `count` geodetic waypoints.
+ * @param count The number of waypoints to convert.
+ * @param out_waypoints Caller-allocated array of `count` waypoints that receives the local positions.
+ * @return 0 on success, non-zero on failure (e.g., an origin outside the valid latitude/longitude range).
+ */
+int fers_geodetic_to_local(double origin_latitude, double origin_longitude, double origin_altitude,
+						   const fers_geodetic_waypoint_t* points, size_t count, fers_motion_waypoint_t* out_waypoints);
+
+/**
+ * @brief Converts local ENU waypoints to geodetic coordinates; the inverse of `fers_geodetic_to_local`.
+ *
+ * @param origin_latitude The origin latitude in decimal degrees.
+ * @param origin_longitude The origin longitude in decimal degrees.
+ * @param origin_altitude The origin altitude above the ellipsoid in meters.
+ * @param waypoints An array of `count` local waypoints.
+ * @param count The number of waypoints to convert.
+ * @param out_points Caller-allocated array of `count` waypoints that receives the geodetic positions.
+ * @return 0 on success, non-zero on failure (e.g., an origin outside the valid latitude/longitude range).
+ */
+int fers_local_to_geodetic(double origin_latitude, double origin_longitude, double origin_altitude,
+						   const fers_motion_waypoint_t* waypoints, size_t count, fers_geodetic_waypoint_t* out_points);
+
 /**
  * @brief Calculates an interpolated rotation path from a set of waypoints.
  * This function is a stateless utility for UI previews.
@@ -491,6 +1098,1093 @@ fers_visual_link_list_t* fers_calculate_preview_links(const fers_context_t* cont
  */
 void fers_free_preview_links(fers_visual_link_list_t* list);
 
+// --- Interference Analysis ---
+
+/**
+ * @brief The direct-path power received from a single interfering emitter.
+ */
+typedef struct
+{
+	char transmitter_name[64]; /**< Name of the interfering transmitter. */
+	double power; /**< Received power in watts. */
+} fers_interference_contribution_t;
+
+/**
+ * @brief The aggregate interference seen by a receiver at a single instant.
+ * @note The `contributions` array is owned by this struct and must be freed using
+ *       `fers_free_interference_report`.
+ */
+typedef struct
+{
+	double total_power; /**< Sum of all contributions in watts. */
+	double temperature; /**< Equivalent interference temperature in Kelvin. */
+	fers_interference_contribution_t* contributions;
+	size_t count;
+} fers_interference_report_t;
+
+/**
+ * @brief Calculates the aggregate interference power at a receiver.
+ *
+ * Sums the one-way received power from every active transmitter other than the
+ * receiver's own monostatic transmitter or transmitters sharing its platform.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param rx_name The name of the receiver.
+ * @param time The simulation time in seconds.
+ * @return A pointer to an interference report, or NULL on error. Caller must free with
+ *         `fers_free_interference_report`.
+ */
+fers_interference_report_t* fers_calculate_interference(const fers_context_t* context, const char* rx_name,
+														double time);
+
+/**
+ * @brief Frees the memory allocated for an interference report.
+ * @param report The report to free.
+ */
+void fers_free_interference_report(fers_interference_report_t* report);
+
+// --- Spectrum Occupancy ---
+
+/**
+ * @brief The occupied bandwidth at a single sample time.
+ */
+typedef struct
+{
+	double time; /**< Simulation time in seconds. */
+	double occupied_bandwidth; /**< Total occupied bandwidth in Hz. */
+} fers_spectrum_sample_t;
+
+/**
+ * @brief A time series of occupied bandwidth samples.
+ * @note The `samples` array is owned by this struct and must be freed using
+ *       `fers_free_spectrum_occupancy`.
+ */
+typedef struct
+{
+	fers_spectrum_sample_t* samples;
+	size_t count;
+} fers_spectrum_occupancy_t;
+
+/**
+ * @brief Calculates the total bandwidth occupied by active emitters over the simulation.
+ *
+ * At each sample time, every active transmitter occupies a band centred on its carrier
+ * with a width equal to its waveform sample rate (CW waveforms occupy none). Overlapping
+ * bands are merged before their widths are summed.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param num_points The number of evenly spaced samples between the start and end time (at least 2).
+ * @return A pointer to the occupancy series, or NULL on error. Caller must free with
+ *         `fers_free_spectrum_occupancy`.
+ */
+fers_spectrum_occupancy_t* fers_calculate_spectrum_occupancy(const fers_context_t* context, size_t num_points);
+
+/**
+ * @brief Frees the memory allocated for a spectrum occupancy series.
+ * @param occupancy The series to free.
+ */
+void fers_free_spectrum_occupancy(fers_spectrum_occupancy_t* occupancy);
+
+/**
+ * @brief Finds the moment when the most transmitters are active at once.
+ *
+ * Activity is taken from each transmitter's schedule; unscheduled transmitters are always
+ * active. The count is checked at `num_points` evenly spaced times and at every schedule
+ * period start inside the simulation, so brief overlaps are not missed.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param num_points The number of evenly spaced samples between the start and end time (at least 2).
+ * @param out_time On success, receives the earliest time of the peak in seconds.
+ * @param out_count On success, receives the number of transmitters active at that time.
+ * @return 0 on success, non-zero on failure. Use `fers_get_last_error_message()` for details.
+ */
+int fers_calculate_peak_emitter_count(const fers_context_t* context, size_t num_points, double* out_time,
+									  size_t* out_count);
+
+// --- Time-Division Scheduling ---
+
+/**
+ * @brief An emission slot reserved for one transmitter.
+ */
+typedef struct
+{
+	char transmitter_name[64]; /**< Name of the transmitter that owns the slot. */
+	double slot_start; /**< Slot start time in seconds. */
+	double slot_end; /**< Slot end time in seconds. */
+} fers_tdm_slot_t;
+
+/**
+ * @brief A time-ordered list of emission slots.
+ * @note The `slots` array is owned by this struct and must be freed using
+ *       `fers_free_tdm_schedule`.
+ */
+typedef struct
+{
+	fers_tdm_slot_t* slots;
+	size_t count;
+} fers_tdm_schedule_t;
+
+/**
+ * @brief Calculates a time-division schedule in which no two transmitters emit at once.
+ *
+ * Every transmitter must be pulsed. Transmitters take turns in a frame as long as the
+ * shortest PRI, each occupying its pulse length followed by the guard time, and frames
+ * repeat across the simulation time span.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param guard_time The idle time in seconds after each slot.
+ * @return A pointer to the schedule, or NULL on error (including when the pulses and guard
+ *         times do not fit within the shortest PRI). Caller must free with `fers_free_tdm_schedule`.
+ */
+fers_tdm_schedule_t* fers_calculate_tdm_schedule(const fers_context_t* context, double guard_time);
+
+/**
+ * @brief Frees the memory allocated for a time-division schedule.
+ * @param schedule The schedule to free.
+ */
+void fers_free_tdm_schedule(fers_tdm_schedule_t* schedule);
+
+// --- Pulse Collisions ---
+
+/**
+ * @brief Two pulses from different transmitters that overlap in time at a receiver.
+ */
+typedef struct
+{
+	char first_transmitter[64]; /**< Name of the transmitter whose pulse arrives first. */
+	size_t first_pulse_index; /**< Index of that pulse among its transmitter's pulses. */
+	char second_transmitter[64]; /**< Name of the transmitter whose pulse arrives second. */
+	size_t second_pulse_index; /**< Index of that pulse among its transmitter's pulses. */
+	double overlap_start; /**< Start of the overlap at the receiver in seconds. */
+	double overlap_end; /**< End of the overlap at the receiver in seconds. */
+} fers_pulse_collision_t;
+
+/**
+ * @brief A list of pulse collisions ordered by the arrival of the first pulse.
+ * @note The `collisions` array is owned by this struct and must be freed using
+ *       `fers_free_pulse_collisions`.
+ */
+typedef struct
+{
+	fers_pulse_collision_t* collisions;
+	size_t count;
+} fers_pulse_collision_list_t;
+
+/**
+ * @brief Finds pulses from different transmitters that arrive at a receiver at the same time.
+ *
+ * Each pulsed transmitter emits from the start time at its PRF, following its schedule.
+ * A pulse arrives after the direct-path delay at its emission time and lasts one pulse length.
+ * CW transmitters and the receiver's own monostatic transmitter are ignored.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param rx_name The name of the receiver.
+ * @param window_start The start of the arrival window in seconds.
+ * @param window_end The end of the arrival window in seconds.
+ * @return A pointer to the collision list, or NULL on error. Caller must free with
+ *         `fers_free_pulse_collisions`.
+ */
+fers_pulse_collision_list_t* fers_calculate_pulse_collisions(const fers_context_t* context, const char* rx_name,
+															 double window_start, double window_end);
+
+/**
+ * @brief Frees the memory allocated for a pulse collision list.
+ * @param list The list to free.
+ */
+void fers_free_pulse_collisions(fers_pulse_collision_list_t* list);
+
+// --- Link Budget Analysis ---
+
+/**
+ * @brief Calculates the transmit power required to reach a target SNR at a receiver.
+ *
+ * Inverts the bistatic radar equation (Tx -> Target -> Rx) at the given time against
+ * the receiver's thermal noise power.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param tx_name The name of the transmitter.
+ * @param target_name The name of the target.
+ * @param rx_name The name of the receiver.
+ * @param target_snr_db The desired SNR in dB.
+ * @param time The simulation time in seconds.
+ * @param out_power_dbw On success, receives the required transmit power in dBW.
+ * @return 0 on success, non-zero on failure. Use `fers_get_last_error_message()` for details.
+ */
+int fers_calculate_required_tx_power(const fers_context_t* context, const char* tx_name, const char* target_name,
+									 const char* rx_name, double target_snr_db, double time, double* out_power_dbw);
+
+/**
+ * @brief Calculates the maximum range at which a monostatic radar detects a target.
+ *
+ * Solves the monostatic radar equation for the range at which a target of the given RCS,
+ * on the antenna boresight, produces the threshold SNR against the receiver's thermal noise.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param monostatic_name The name of the monostatic radar.
+ * @param target_rcs The radar cross section of the target in square meters.
+ * @param snr_threshold_db The detection threshold SNR in dB.
+ * @param out_range On success, receives the maximum detection range in meters.
+ * @return 0 on success, non-zero on failure. Use `fers_get_last_error_message()` for details.
+ */
+int fers_calculate_max_detection_range(const fers_context_t* context, const char* monostatic_name, double target_rcs,
+									   double snr_threshold_db, double* out_range);
+
+/**
+ * @brief Calculates the bistatic range Tx -> Target -> Rx between antenna phase centers.
+ *
+ * Each path end is the component's platform position plus its phase-center offset,
+ * rotated by the platform attitude at the given time.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param tx_name The name of the transmitter.
+ * @param target_name The name of the target.
+ * @param rx_name The name of the receiver.
+ * @param time The simulation time in seconds.
+ * @param out_range On success, receives the total path length in meters.
+ * @return 0 on success, non-zero on failure. Use `fers_get_last_error_message()` for details.
+ */
+int fers_calculate_phase_center_range(const fers_context_t* context, const char* tx_name, const char* target_name,
+									  const char* rx_name, double time, double* out_range);
+
+/**
+ * @brief Calculates the thermal noise floor kTB of a receiver.
+ *
+ * Uses the receiver's noise temperature (290 K if unset) and a noise bandwidth of
+ * 1 / window length for pulsed receivers, or the simulated noise bandwidth for CW receivers.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param rx_name The name of the receiver.
+ * @param out_noise_dbm On success, receives the noise power in dBm.
+ * @return 0 on success, non-zero on failure. Use `fers_get_last_error_message()` for details.
+ */
+int fers_calculate_noise_floor(const fers_context_t* context, const char* rx_name, double* out_noise_dbm);
+
+/**
+ * @brief The terms of the two-way radar equation for one transmitter, target and receiver.
+ *
+ * `received_power_dbw` is `transmit_power_dbw + tx_gain_dbi + rx_gain_dbi + rcs_dbsm - path_loss_db`.
+ */
+typedef struct
+{
+	double transmit_power_dbw; /**< Waveform power in dBW. */
+	double tx_gain_dbi; /**< Transmit antenna gain towards the target in dBi. */
+	double rx_gain_dbi; /**< Receive antenna gain towards the target, with calibration, in dBi. */
+	double rcs_dbsm; /**< Target RCS for the transmit and receive directions in dBsm. */
+	double path_loss_db; /**< Spreading loss `(4 pi)^3 R^4 / lambda^2` in dB. */
+	double received_power_dbw; /**< Echo power at the receiver in dBW. */
+	double noise_power_dbw; /**< Receiver thermal noise kTB in dBW. */
+	double snr_db; /**< Single-pulse (or single-sample, for CW) SNR in dB. */
+	double integration_gain_db; /**< Coherent integration gain over the simulated span in dB. */
+	double integrated_snr_db; /**< SNR after integration in dB. */
+} fers_link_budget_t;
+
+/**
+ * @brief Calculates the two-way link budget of a transmitter, target and receiver at a given range.
+ *
+ * The antenna gains and the target RCS are taken along the actual directions to the target at
+ * the simulation start time, while the target is assumed to lie `range` from both the
+ * transmitter and the receiver. The noise is kTB as in `fers_calculate_noise_floor`. Pulsed
+ * transmitters integrate every pulse over the simulated span; CW transmitters integrate every
+ * noise-bandwidth sample over the span.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param tx_name The name of the transmitter.
+ * @param rx_name The name of the receiver.
+ * @param target_name The name of the target.
+ * @param range The assumed range to the target in meters. Must be > 0.
+ * @param out_budget On success, receives the link budget.
+ * @return 0 on success, non-zero on failure. Use `fers_get_last_error_message()` for details.
+ */
+int fers_calculate_link_budget(const fers_context_t* context, const char* tx_name, const char* rx_name,
+							   const char* target_name, double range, fers_link_budget_t* out_budget);
+
+/**
+ * @brief A phase-noise time series sampled at a uniform rate.
+ * @note The `samples` array is owned by this struct and must be freed using
+ *       `fers_free_phase_noise_series`.
+ */
+typedef struct
+{
+	double* samples; /**< Phase noise in radians, one value per sample. */
+	size_t count; /**< Number of samples. */
+	double sample_rate; /**< Sample rate of the series in Hz. */
+} fers_phase_noise_series_t;
+
+/**
+ * @brief Previews the phase noise a receiver's timing source adds to its downconversion.
+ *
+ * The series is synthesized from the power-law spectrum `sum weight^2 * f^-alpha` of the
+ * timing source's noise entries with random phases seeded by the scenario's random seed,
+ * so its variance matches the integrated PSD. It is sampled at the simulation rate, lowered
+ * when needed to keep the preview at most 4096 samples long.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param rx_name The name of the receiver.
+ * @param duration The length of the preview in seconds. Must be > 0.
+ * @return A pointer to the series, or NULL on failure (e.g. the receiver or its timing source
+ *         was not found). The caller must free it with `fers_free_phase_noise_series`.
+ */
+fers_phase_noise_series_t* fers_preview_receiver_phase_noise(const fers_context_t* context, const char* rx_name,
+															 double duration);
+
+/**
+ * @brief Frees the memory allocated for a phase-noise series.
+ * @param series The series to free.
+ */
+void fers_free_phase_noise_series(fers_phase_noise_series_t* series);
+
+/**
+ * @brief A closed-form echo of a single pulse as interleaved I/Q samples.
+ */
+typedef struct
+{
+	double* iq; /**< Interleaved in-phase and quadrature values, two per sample. */
+	size_t count; /**< Number of complex samples. */
+	double sample_rate; /**< Sample rate of the series in Hz. */
+} fers_received_pulse_t;
+
+/**
+ * @brief Previews the echo of a single pulse off one target, without noise.
+ *
+ * The pulse is emitted at `time` and the receiver is sampled at the simulation rate from that
+ * instant. The delay and amplitude follow the bistatic radar equation at the emission time,
+ * and the range rate of the whole Tx -> Target -> Rx path (including the transmitter's
+ * motion) Doppler shifts the carrier and time-scales the waveform envelope.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param tx_name The name of the pulsed transmitter.
+ * @param target_name The name of the target.
+ * @param rx_name The name of the receiver.
+ * @param time The emission time of the pulse in seconds.
+ * @param num_samples The number of receiver samples to compute. Must be > 0.
+ * @return A pointer to the echo, or NULL on failure (e.g. a component was not found). The
+ *         caller must free it with `fers_free_received_pulse`.
+ */
+fers_received_pulse_t* fers_preview_received_pulse(const fers_context_t* context, const char* tx_name,
+												   const char* target_name, const char* rx_name, double time,
+												   size_t num_samples);
+
+/**
+ * @brief Frees the memory allocated for a received-pulse preview.
+ * @param pulse The preview to free.
+ */
+void fers_free_received_pulse(fers_received_pulse_t* pulse);
+
+/**
+ * @brief Complex beamforming weights as interleaved I/Q values, one pair per array element.
+ */
+typedef struct
+{
+	double* iq; /**< Interleaved real and imaginary parts, two per weight. */
+	size_t count; /**< Number of weights (array elements). */
+} fers_beamforming_weights_t;
+
+/**
+ * @brief Computes the weights that steer a receiver's element array to a look direction.
+ *
+ * The weights are the array's plane-wave response in the look direction divided by the
+ * element count, so `sum(conj(w_n) * x_n)` passes a wave from that direction with unit
+ * gain. The look direction is relative to the receiver's platform body frame at the
+ * simulation start, and the wavelength is that of the monostatic transmitter's waveform,
+ * or else of the first transmitter with a waveform.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param rx_name The name of the receiver.
+ * @param azimuth_deg The look azimuth in degrees, positive to the left of boresight.
+ * @param elevation_deg The look elevation in degrees.
+ * @return A pointer to the weights, or NULL on failure (e.g. the receiver has no element
+ *         array). The caller must free it with `fers_free_beamforming_weights`.
+ */
+fers_beamforming_weights_t* fers_preview_beamforming_weights(const fers_context_t* context, const char* rx_name,
+															 double azimuth_deg, double elevation_deg);
+
+/**
+ * @brief Frees the memory allocated for a set of beamforming weights.
+ * @param weights The weights to free.
+ */
+void fers_free_beamforming_weights(fers_beamforming_weights_t* weights);
+
+/**
+ * @brief Calculates the lowest PRF that measures a target's radial velocity unambiguously.
+ *
+ * The radial velocity (half the bistatic range rate) is maximised over the simulation span,
+ * and the returned PRF makes the unambiguous velocity `lambda * prf / 4` equal to it.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param tx_name The name of the transmitter.
+ * @param target_name The name of the target.
+ * @param rx_name The name of the receiver.
+ * @param out_prf On success, receives the minimum PRF in Hz.
+ * @return 0 on success, non-zero on failure. Use `fers_get_last_error_message()` for details.
+ */
+int fers_calculate_min_prf_for_velocity(const fers_context_t* context, const char* tx_name, const char* target_name,
+										const char* rx_name, double* out_prf);
+
+/**
+ * @brief The rate at which a simulation run writes receiver samples to its output files.
+ */
+typedef struct
+{
+	double peak_bytes_per_second; /**< Output rate while every active receiver is recording. */
+	double average_bytes_per_second; /**< Output rate averaged over the simulation span. */
+	size_t active_receivers; /**< Receivers that record during the simulation span. */
+} fers_data_rate_t;
+
+/**
+ * @brief Calculates the output data rate of a simulation run.
+ *
+ * Each complex sample is written as an I and a Q value at the output sample rate. CW
+ * receivers record continuously; pulsed receivers record for `window_length * window_prf`
+ * of the time. Averages are scaled by the share of the run each receiver's schedule covers.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param out_rate On success, receives the peak and average rates in bytes per second.
+ * @return 0 on success, non-zero on failure. Use `fers_get_last_error_message()` for details.
+ */
+int fers_calculate_data_rate(const fers_context_t* context, fers_data_rate_t* out_rate);
+
+/**
+ * @brief The coherent processing interval needed for a velocity resolution, against a receiver's dwell.
+ */
+typedef struct
+{
+	double required_time; /**< Coherent processing interval in seconds. */
+	double available_dwell; /**< Longest continuous span the receiver records, in seconds. */
+	int sufficient; /**< 1 if `available_dwell` covers `required_time`, 0 otherwise. */
+} fers_integration_time_t;
+
+/**
+ * @brief Calculates the coherent processing interval needed to achieve a velocity resolution.
+ *
+ * Inverts `delta_v = lambda / (2 * CPI)`. The available dwell is the simulation span, or
+ * the longest schedule period of the receiver within it.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param rx_name The name of the receiver.
+ * @param velocity_resolution The velocity resolution in m/s.
+ * @param carrier The carrier frequency in Hz.
+ * @param out_time On success, receives the required interval and the receiver's dwell.
+ * @return 0 on success, non-zero on failure. Use `fers_get_last_error_message()` for details.
+ */
+int fers_calculate_required_integration_time(const fers_context_t* context, const char* rx_name,
+											 double velocity_resolution, double carrier,
+											 fers_integration_time_t* out_time);
+
+/**
+ * @brief Calculates the Cramér-Rao lower bound on range estimation for a Tx -> Target -> Rx echo.
+ *
+ * Combines the single-pulse link-budget SNR with the RMS bandwidth of the transmitter's
+ * waveform, taken as a flat spectrum spanning its source sample rate.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param tx_name The name of the transmitter.
+ * @param target_name The name of the target.
+ * @param rx_name The name of the receiver.
+ * @param time The simulation time in seconds.
+ * @param out_sigma_m On success, receives the range standard deviation bound in meters.
+ * @return 0 on success, non-zero on failure (e.g. a CW waveform whose bandwidth cannot be determined).
+ */
+int fers_calculate_range_crlb(const fers_context_t* context, const char* tx_name, const char* target_name,
+							  const char* rx_name, double time, double* out_sigma_m);
+
+/**
+ * @brief Calculates the matched-filter SNR loss when a receive window truncates a pulse.
+ *
+ * The captured energy fraction is the ratio of the receiver's window length to the
+ * transmitter's pulse duration, assuming the pulse energy is spread uniformly in time.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param rx_name The name of the pulsed receiver.
+ * @param tx_name The name of the pulsed transmitter.
+ * @param out_loss_db On success, receives the SNR loss in dB (0 if the window contains the pulse).
+ * @return 0 on success, non-zero on failure. Use `fers_get_last_error_message()` for details.
+ */
+int fers_calculate_window_truncation_loss(const fers_context_t* context, const char* rx_name, const char* tx_name,
+										  double* out_loss_db);
+
+/**
+ * @brief A closed interval of monostatic-equivalent range.
+ */
+typedef struct
+{
+	double range_start; /**< Start of the interval in meters. */
+	double range_end; /**< End of the interval in meters. */
+} fers_range_interval_t;
+
+/**
+ * @brief A list of range intervals.
+ * @note The caller owns this structure and must free it using `fers_free_range_intervals`.
+ */
+typedef struct
+{
+	fers_range_interval_t* intervals;
+	size_t count;
+} fers_range_interval_list_t;
+
+/**
+ * @brief Calculates the ranges at which a pulsed receiver is blind to a transmitter's returns.
+ *
+ * Echoes are eclipsed while the transmit pulse is radiating, before the receive window opens
+ * (the window skip) and after it closes. Ranges are `c * delay / 2` and are reported up to
+ * the unambiguous range of the transmitter's PRF.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param rx_name The name of the pulsed receiver.
+ * @param tx_name The name of the pulsed transmitter.
+ * @return A pointer to a `fers_range_interval_list_t`, or NULL on failure.
+ *         The caller owns the returned structure and must free it with
+ *         `fers_free_range_intervals`.
+ */
+fers_range_interval_list_t* fers_calculate_blind_ranges(const fers_context_t* context, const char* rx_name,
+														const char* tx_name);
+
+/**
+ * @brief Frees the memory allocated for a range interval list.
+ * @param list The list to free.
+ */
+void fers_free_range_intervals(fers_range_interval_list_t* list);
+
+/**
+ * @brief The blind ranges and blind speeds of a radar operating at a single PRF.
+ */
+typedef struct
+{
+	double prf; /**< The pulse repetition frequency in Hz. */
+	fers_range_interval_t* blind_ranges; /**< Eclipsed range intervals. */
+	size_t blind_range_count; /**< Number of entries in `blind_ranges`. */
+	double* blind_speeds; /**< Non-zero blind radial speeds in m/s. */
+	size_t blind_speed_count; /**< Number of entries in `blind_speeds`. */
+} fers_prf_blind_zones_t;
+
+/**
+ * @brief The combined blind zones of a staggered PRF set.
+ * @note All arrays are owned by this struct and must be freed using
+ *       `fers_free_blind_zone_coverage`.
+ */
+typedef struct
+{
+	fers_prf_blind_zones_t* prfs; /**< Blind zones of each PRF, in the order given. */
+	size_t prf_count; /**< Number of entries in `prfs`. */
+	fers_range_interval_t* unrecoverable_ranges; /**< Ranges blind at every PRF. */
+	size_t unrecoverable_range_count; /**< Number of entries in `unrecoverable_ranges`. */
+	fers_range_interval_t* recoverable_ranges; /**< Ranges blind at some, but not all, PRFs. */
+	size_t recoverable_range_count; /**< Number of entries in `recoverable_ranges`. */
+	double* unrecoverable_speeds; /**< Speeds blind at every PRF, in m/s. */
+	size_t unrecoverable_speed_count; /**< Number of entries in `unrecoverable_speeds`. */
+	double* recoverable_speeds; /**< Speeds blind at some, but not all, PRFs, in m/s. */
+	size_t recoverable_speed_count; /**< Number of entries in `recoverable_speeds`. */
+	double max_range; /**< End of the evaluated range span (the lowest PRF's unambiguous range) in meters. */
+	double max_speed; /**< End of the evaluated speed span in m/s. */
+} fers_blind_zone_coverage_t;
+
+/**
+ * @brief Calculates the combined range and velocity blind zones of a staggered PRF set.
+ *
+ * Each PRF keeps the receiver's window skip and length. Blind speeds are the non-zero
+ * multiples of `lambda * prf / 2`. Speeds are evaluated up to the first speed that is
+ * blind at every PRF, or 100 blind speeds of the highest PRF if there is none before then.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param rx_name The name of the pulsed receiver.
+ * @param tx_name The name of the pulsed transmitter.
+ * @param prfs An array of PRFs in Hz.
+ * @param prf_count The number of PRFs.
+ * @return A pointer to the coverage report, or NULL on failure. The caller owns the
+ *         returned structure and must free it with `fers_free_blind_zone_coverage`.
+ */
+fers_blind_zone_coverage_t* fers_calculate_blind_zone_coverage(const fers_context_t* context, const char* rx_name,
+															   const char* tx_name, const double* prfs,
+															   size_t prf_count);
+
+/**
+ * @brief Frees the memory allocated for a blind zone coverage report.
+ * @param coverage The report to free.
+ */
+void fers_free_blind_zone_coverage(fers_blind_zone_coverage_t* coverage);
+
+// --- Trajectory Analysis ---
+
+/**
+ * @brief Position error statistics between a platform path and a reference trajectory.
+ */
+typedef struct
+{
+	double rms_error; /**< Root-mean-square position error in meters. */
+	double max_error; /**< Maximum position error in meters. */
+	double start_time; /**< Start of the compared time span in seconds. */
+	double end_time; /**< End of the compared time span in seconds. */
+	size_t sample_count; /**< Number of matched time samples. */
+} fers_trajectory_error_t;
+
+/**
+ * @brief Compares a platform's motion path against a reference trajectory.
+ *
+ * The reference is linearly interpolated between its waypoints. Both paths are sampled
+ * at `num_points` evenly spaced times over the intersection of their time spans.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param platform_name The name of the platform.
+ * @param reference An array of reference waypoints in the scenario's coordinate frame.
+ * @param reference_count The number of reference waypoints.
+ * @param num_points The number of time samples to compare.
+ * @param out_error On success, receives the error statistics.
+ * @return 0 on success, non-zero on failure. Use `fers_get_last_error_message()` for details.
+ */
+int fers_compare_trajectory(const fers_context_t* context, const char* platform_name,
+							const fers_motion_waypoint_t* reference, size_t reference_count, size_t num_points,
+							fers_trajectory_error_t* out_error);
+
+/**
+ * @brief Synthetic aperture geometry and the resulting image resolution.
+ */
+typedef struct
+{
+	double synthetic_aperture; /**< Distance travelled by the phase centre during illumination, in meters. */
+	double slant_range; /**< Phase centre to target range at mid-illumination, in meters. */
+	double cross_range_resolution; /**< Azimuth resolution in meters. */
+	double range_resolution; /**< Slant range resolution in meters. */
+	double start_time; /**< Start of the illumination interval in seconds. */
+	double end_time; /**< End of the illumination interval in seconds. */
+} fers_sar_resolution_t;
+
+/**
+ * @brief Calculates the cross-range and range resolution of a SAR collection.
+ *
+ * The phase centre is the midpoint of the transmitter and receiver. Its path length over
+ * the illumination interval is the synthetic aperture `L`, giving a cross-range resolution
+ * of `λR / (2L)`. The range resolution is `c / (2B)` for the waveform bandwidth `B`.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param tx_name The name of the transmitter.
+ * @param target_name The name of the imaged target.
+ * @param rx_name The name of the receiver.
+ * @param out_resolution On success, receives the aperture geometry and resolutions.
+ * @return 0 on success, non-zero on failure. Use `fers_get_last_error_message()` for details.
+ */
+int fers_calculate_sar_resolution(const fers_context_t* context, const char* tx_name, const char* target_name,
+								  const char* rx_name, fers_sar_resolution_t* out_resolution);
+
+/**
+ * @brief Dimensions of the radar resolution cell at a target.
+ */
+typedef struct
+{
+	double range_resolution; /**< Slant range resolution in meters. */
+	double azimuth_extent; /**< Cross-range extent in the azimuth plane, in meters. */
+	double elevation_extent; /**< Cross-range extent in the elevation plane, in meters. */
+	double volume; /**< Cell volume in cubic meters. */
+} fers_resolution_cell_t;
+
+/**
+ * @brief Computes the range x cross-range x elevation resolution cell at a target.
+ *
+ * The range resolution is `c / (2B)` for the waveform bandwidth `B`. Each cross-range
+ * extent is the half-power beamwidth times the range to the target, taking the narrower
+ * of the transmit and receive beams.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param tx_name The name of the transmitter.
+ * @param target_name The name of the target.
+ * @param rx_name The name of the receiver.
+ * @param time The simulation time in seconds.
+ * @param out_cell On success, receives the cell dimensions and volume.
+ * @return 0 on success, non-zero on failure. Use `fers_get_last_error_message()` for details.
+ */
+int fers_compute_resolution_cell(const fers_context_t* context, const char* tx_name, const char* target_name,
+								 const char* rx_name, double time, fers_resolution_cell_t* out_cell);
+
+// --- Ground Coverage Analysis ---
+
+/**
+ * @brief Describes a regular sampling grid on a horizontal ground plane.
+ * Coordinates are in the scenario's local frame (meters).
+ */
+typedef struct
+{
+	double x_min; /**< Minimum X (East) coordinate. */
+	double x_max; /**< Maximum X (East) coordinate. */
+	double y_min; /**< Minimum Y (North) coordinate. */
+	double y_max; /**< Maximum Y (North) coordinate. */
+	double z; /**< Altitude of the ground plane. */
+	size_t nx; /**< Number of samples along X (at least 2). */
+	size_t ny; /**< Number of samples along Y (at least 2). */
+} fers_ground_grid_t;
+
+/**
+ * @brief A 2D point on the ground plane.
+ */
+typedef struct
+{
+	double x;
+	double y;
+} fers_ground_point_t;
+
+/**
+ * @brief An ordered list of ground points forming a contour line.
+ */
+typedef struct
+{
+	fers_ground_point_t* points;
+	size_t count;
+} fers_polyline_t;
+
+/**
+ * @brief A container for a list of polylines.
+ * @note The `polylines` array (and each polyline's points) is owned by this struct
+ *       and must be freed using `fers_free_polyline_list`.
+ */
+typedef struct
+{
+	fers_polyline_t* polylines;
+	size_t count;
+} fers_polyline_list_t;
+
+/**
+ * @brief Computes iso-Doppler contours on the ground for a transmitter/receiver pair.
+ *
+ * The Doppler shift of a stationary scatterer is evaluated at every grid node from the
+ * transmitter and receiver platform velocities at the given time, and contour polylines
+ * are extracted for each requested Doppler value.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param tx_name The name of the transmitter.
+ * @param rx_name The name of the receiver.
+ * @param doppler_values An array of Doppler levels in Hz.
+ * @param value_count The number of Doppler levels.
+ * @param grid The ground sampling grid.
+ * @param time The simulation time in seconds.
+ * @return A pointer to a polyline list, or NULL on error. Caller must free with
+ *         `fers_free_polyline_list`.
+ */
+fers_polyline_list_t* fers_compute_isodoppler_contours(const fers_context_t* context, const char* tx_name,
+													   const char* rx_name, const double* doppler_values,
+													   size_t value_count, const fers_ground_grid_t* grid,
+													   double time);
+
+/**
+ * @brief Computes the grazing angle at which a platform's line of sight meets the ground.
+ *
+ * When the scenario has terrain, the ground point is raised onto the terrain surface where
+ * the height field has data, and the call fails if the terrain hides the point.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param platform_name The name of the platform.
+ * @param ground_point The ground intercept on the plane.
+ * @param ground_z The altitude of the ground plane in meters, used outside the terrain.
+ * @param time The simulation time in seconds.
+ * @param out_angle_deg On success, receives the grazing angle in degrees (90 at nadir).
+ * @return 0 on success, non-zero on failure. Use `fers_get_last_error_message()` for details.
+ */
+int fers_compute_grazing_angle(const fers_context_t* context, const char* platform_name,
+							   const fers_ground_point_t* ground_point, double ground_z, double time,
+							   double* out_angle_deg);
+
+/**
+ * @brief Checks whether two platforms can see each other over the scenario terrain.
+ *
+ * The straight path between the platforms is tested against the terrain height field.
+ * Without terrain every pair of platforms is intervisible.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param from_platform The name of the first platform.
+ * @param to_platform The name of the second platform.
+ * @param time The simulation time in seconds.
+ * @param out_visible On success, receives 1 if the path clears the terrain, 0 otherwise.
+ * @return 0 on success, non-zero on failure. Use `fers_get_last_error_message()` for details.
+ */
+int fers_check_line_of_sight(const fers_context_t* context, const char* from_platform, const char* to_platform,
+							 double time, int* out_visible);
+
+/**
+ * @brief Computes the area of the ground clutter patch illuminated at a slant range.
+ *
+ * The ground is the plane at zero altitude, and the grazing angle follows from the
+ * receiver height and `range`. The patch is the range times the azimuth beamwidth wide,
+ * and as deep as the shorter of the pulse-limited extent `cτ / (2 cos ψ)` and the
+ * beam-limited extent `R·θ_el / sin ψ`. The narrower of the transmit and receive beams is used.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param rx_name The name of the receiver.
+ * @param tx_name The name of the pulsed transmitter.
+ * @param range The slant range to the patch in meters; must exceed the receiver height.
+ * @param time The simulation time in seconds.
+ * @param out_area On success, receives the patch area in square meters.
+ * @return 0 on success, non-zero on failure. Use `fers_get_last_error_message()` for details.
+ */
+int fers_compute_clutter_cell_area(const fers_context_t* context, const char* rx_name, const char* tx_name,
+								   double range, double time, double* out_area);
+
+/**
+ * @brief Computes the ground region illuminated by a transmitter and seen by a receiver.
+ *
+ * Each antenna's half-power beam footprint is traced on the horizontal plane at
+ * `ground_z` and the two footprints are intersected.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param tx_name The name of the transmitter.
+ * @param rx_name The name of the receiver.
+ * @param ground_z The altitude of the ground plane in meters.
+ * @param time The simulation time in seconds.
+ * @return A pointer to a polyline list holding the overlap polygon (first point not
+ *         repeated), or an empty list if the footprints are disjoint. NULL on error, e.g.
+ *         when a beam edge does not reach the ground. Caller must free with
+ *         `fers_free_polyline_list`.
+ */
+fers_polyline_list_t* fers_compute_bistatic_footprint_overlap(const fers_context_t* context, const char* tx_name,
+															  const char* rx_name, double ground_z, double time);
+
+/**
+ * @brief Frees the memory allocated for a polyline list.
+ * @param list The list to free.
+ */
+void fers_free_polyline_list(fers_polyline_list_t* list);
+
+// --- Scenario Preview ---
+
+/**
+ * @brief The role of a platform, derived from the components it carries.
+ */
+typedef enum
+{
+	FERS_TRACK_NONE, // No radar components or targets
+	FERS_TRACK_TRANSMITTER, // Carries at least one transmitter
+	FERS_TRACK_RECEIVER, // Carries receivers only
+	FERS_TRACK_MONOSTATIC, // Carries a monostatic radar
+	FERS_TRACK_TARGET // Carries targets only
+} fers_track_kind_t;
+
+/**
+ * @brief The sampled trajectory of a single platform.
+ */
+typedef struct
+{
+	char platform_name[64]; /**< Name of the platform. */
+	fers_track_kind_t kind; /**< Role of the platform. */
+	fers_interpolated_point_t* points; /**< Samples evenly spaced over the simulation time. */
+	size_t count;
+} fers_platform_track_t;
+
+/**
+ * @brief A container for the trajectories of every platform in the scenario.
+ * @note The `tracks` array is owned by this struct and must be freed using `fers_free_platform_tracks`.
+ */
+typedef struct
+{
+	fers_platform_track_t* tracks;
+	size_t count;
+} fers_platform_track_list_t;
+
+/**
+ * @brief Samples the motion path of every platform over the simulation time span.
+ *
+ * Intended for lightweight previews such as scenario thumbnails.
+ *
+ * @param context A valid `fers_context_t` handle.
+ * @param num_points The number of samples per platform (at least 2).
+ * @return A pointer to the track list, or NULL on error. Caller must free with `fers_free_platform_tracks`.
+ */
+fers_platform_track_list_t* fers_get_platform_tracks(const fers_context_t* context, size_t num_points);
+
+/**
+ * @brief Frees the memory allocated for a platform track list.
+ * @param list The list to free.
+ */
+void fers_free_platform_tracks(fers_platform_track_list_t* list);
+
+// --- Output Verification ---
+
+/**
+ * @brief Sample differences between two receiver output files.
+ */
+typedef struct
+{
+	double max_difference; /**< Largest magnitude of a complex sample difference. */
+	double rms_difference; /**< Root-mean-square magnitude of the complex sample differences. */
+	size_t sample_count; /**< Number of I/Q samples compared. */
+	int within_tolerance; /**< 1 if `max_difference` does not exceed the tolerance, 0 otherwise. */
+} fers_output_comparison_t;
+
+/**
+ * @brief Compares a receiver's HDF5 output against a reference file.
+ *
+ * Windows are aligned by dataset name and samples are rescaled by their `fullscale`
+ * attribute before differencing. This is a stateless utility function.
+ *
+ * @param actual_path Path to the HDF5 file under test.
+ * @param reference_path Path to the reference HDF5 file.
+ * @param tolerance The largest acceptable sample difference.
+ * @param out_comparison On success, receives the difference statistics.
+ * @return 0 on success, non-zero on failure (including files with different windows).
+ *         Use `fers_get_last_error_message()` for details.
+ */
+int fers_compare_output(const char* actual_path, const char* reference_path, double tolerance,
+						fers_output_comparison_t* out_comparison);
+
+/**
+ * @brief A time interval whose receiver samples were zeroed by a blanking gate.
+ */
+typedef struct
+{
+	double start_time; /**< Start of the blanked interval in seconds. */
+	double end_time; /**< End of the blanked interval in seconds. */
+} fers_blanked_interval_t;
+
+/**
+ * @brief A list of blanked intervals.
+ * @note The `intervals` array is owned by this struct and must be freed using
+ *       `fers_free_blanked_intervals`.
+ */
+typedef struct
+{
+	fers_blanked_interval_t* intervals;
+	size_t count;
+} fers_blanked_interval_list_t;
+
+/**
+ * @brief Reads the blanked intervals recorded in a pulsed receiver's HDF5 output.
+ *
+ * Each window chunk lists the absolute times its blanking gates covered in its `blanked`
+ * attribute. This is a stateless utility function.
+ *
+ * @param hdf5_path Path to the receiver's HDF5 output file.
+ * @return A pointer to the intervals in chunk order, or NULL on error. Caller must free with
+ *         `fers_free_blanked_intervals`.
+ */
+fers_blanked_interval_list_t* fers_read_blanked_intervals(const char* hdf5_path);
+
+/**
+ * @brief Frees the memory allocated for a list of blanked intervals.
+ * @param list The list to free.
+ */
+void fers_free_blanked_intervals(fers_blanked_interval_list_t* list);
+
+// --- Detection Theory ---
+
+/**
+ * @brief Swerling target fluctuation cases.
+ */
+typedef enum
+{
+	FERS_SWERLING_0, // Non-fluctuating target
+	FERS_SWERLING_1, // Exponential RCS, constant over the integrated pulses
+	FERS_SWERLING_2, // Exponential RCS, independent from pulse to pulse
+	FERS_SWERLING_3, // Chi-square (4 DOF) RCS, constant over the integrated pulses
+	FERS_SWERLING_4 // Chi-square (4 DOF) RCS, independent from pulse to pulse
+} fers_swerling_case_t;
+
+/**
+ * @brief Calculates the detection and false-alarm probabilities of a square-law detector.
+ *
+ * The detector non-coherently integrates `num_integrated` pulses and compares the sum of
+ * their squared magnitudes, normalized to the single-pulse noise power, against the
+ * threshold. This is a stateless utility function.
+ *
+ * @param snr_db The average single-pulse SNR in dB.
+ * @param threshold_db The detection threshold relative to the single-pulse noise power, in dB.
+ * @param num_integrated The number of integrated pulses (at least 1).
+ * @param swerling The target fluctuation case.
+ * @param out_pd On success, receives the probability of detection.
+ * @param out_pfa On success, receives the probability of false alarm.
+ * @return 0 on success, non-zero on failure. Use `fers_get_last_error_message()` for details.
+ */
+int fers_calculate_detection_probabilities(double snr_db, double threshold_db, size_t num_integrated,
+										   fers_swerling_case_t swerling, double* out_pd, double* out_pfa);
+
+/**
+ * @brief Calculates the matched-filter SNR gain of a linear FM pulse.
+ *
+ * The gain is the time-bandwidth product `10 log10(B * tau)`. This is a stateless
+ * utility function.
+ *
+ * @param bandwidth The swept bandwidth in Hz.
+ * @param duration The pulse duration in seconds.
+ * @param out_gain_db On success, receives the pulse compression gain in dB.
+ * @return 0 on success, non-zero on failure. Use `fers_get_last_error_message()` for details.
+ */
+int fers_calculate_pulse_compression_gain(double bandwidth, double duration, double* out_gain_db);
+
+/**
+ * @brief The intrapulse modulation of a standalone pulse definition.
+ */
+typedef enum
+{
+	FERS_PULSE_UNMODULATED, // A constant-frequency rectangular pulse.
+	FERS_PULSE_LFM, // A linear FM up-chirp.
+	FERS_PULSE_FILE // Samples read from a CSV or HDF5 waveform file.
+} fers_pulse_modulation_t;
+
+/**
+ * @brief A single pulse, as edited in the UI before it joins a scenario.
+ */
+typedef struct
+{
+	fers_pulse_modulation_t modulation; // The intrapulse modulation.
+	double bandwidth; // LFM: swept bandwidth in Hz.
+	double duration; // Unmodulated and LFM: pulse duration in seconds.
+	const char* filename; // File: path to the waveform file.
+} fers_pulse_t;
+
+/**
+ * @brief Calculates the energy spectral density of a pulse about its carrier.
+ *
+ * Built-in pulses are synthesized at a rate of `4 B + 16 / tau`, and file pulses keep
+ * the rate they are loaded at. The samples' Fourier transform is taken at `num_bins`
+ * offsets spaced `rate / num_bins` apart from `-rate / 2` upwards. The density is
+ * normalized to its peak, with negative infinity marking a null. This is a stateless utility function.
+ *
+ * @param pulse The pulse definition.
+ * @param num_bins The number of frequency bins, a power of two of at least 2.
+ * @param out_freqs A caller-allocated array of `num_bins` doubles that receives the offsets in Hz.
+ * @param out_power_db A caller-allocated array of `num_bins` doubles that receives the density in dB.
+ * @return 0 on success, non-zero on failure (e.g. the waveform file could not be loaded).
+ *         Use `fers_get_last_error_message()` for details.
+ */
+int fers_compute_waveform_spectrum(const fers_pulse_t* pulse, size_t num_bins, double* out_freqs,
+								   double* out_power_db);
+
+/**
+ * @brief One aspect of a target's RCS pattern.
+ */
+typedef struct
+{
+	double azimuth_deg; /**< Aspect azimuth in the target body frame in degrees. */
+	double elevation_deg; /**< Aspect elevation in the target body frame in degrees. */
+	double rcs; /**< RCS in square meters. */
+} fers_rcs_sample_t;
+
+/**
+ * @brief The RCS pattern of a target description file over its sampled aspects.
+ * @note The `samples` array is owned by this struct and must be freed using
+ *       `fers_free_rcs_preview`.
+ */
+typedef struct
+{
+	fers_rcs_sample_t* samples; /**< One entry per azimuth and elevation pair, elevation-major. */
+	size_t count; /**< Number of samples. */
+	double min_rcs; /**< Smallest RCS in square meters. */
+	double max_rcs; /**< Largest RCS in square meters. */
+} fers_rcs_preview_t;
+
+/**
+ * @brief Parses an RCS file of a file target and samples its pattern.
+ *
+ * The file holds separable azimuth and elevation cuts, so the pattern is given on the grid
+ * of every azimuth sample against every elevation sample, with the RCS at each aspect the
+ * product of the two cuts. This is a stateless utility function.
+ *
+ * @param filename The path to the target description file.
+ * @return A pointer to the preview, or NULL if the file is missing, malformed or not made of
+ *         azimuth and elevation cuts. Use `fers_get_last_error_message()` for details. The
+ *         caller owns the returned struct and must free it with `fers_free_rcs_preview`.
+ */
+fers_rcs_preview_t* fers_load_rcs_file(const char* filename);
+
+/**
+ * @brief Frees an RCS preview returned by `fers_load_rcs_file`.
+ * @param preview The preview to free. Does nothing if NULL.
+ */
+void fers_free_rcs_preview(fers_rcs_preview_t* preview);
+
 #ifdef __cplusplus
 }
 #endif
@@ -15,7 +15,11 @@
 #include <algorithm>
 #include <cmath>
 #include <complex>
+#include <filesystem>
+#include <format>
+#include <fstream>
 #include <optional>
+#include <sstream>
 #include <stdexcept>
 
 #include "core/config.h"
@@ -77,8 +81,73 @@ namespace
 			tmp = XmlElement(tmp.getNode()->next);
 		}
 	}
+
+	/**
+	 * @brief Reads the next non-empty line of a `.pat` file as comma/whitespace separated numbers.
+	 *
+	 * @param file The input stream positioned at the next line.
+	 * @param filename The filename, used for error messages.
+	 * @return The numeric fields of the line.
+	 * @throws std::runtime_error If the end of the file is reached or a field is not numeric.
+	 */
+	std::vector<RealType> readPatLine(std::ifstream& file, const std::string& filename)
+	{
+		std::string line;
+		while (std::getline(file, line))
+		{
+			std::ranges::replace(line, ',', ' ');
+			std::istringstream fields(line);
+			std::vector<RealType> values;
+			std::string field;
+			while (fields >> field)
+			{
+				try
+				{
+					values.push_back(std::stod(field));
+				}
+				catch (const std::exception&)
+				{
+					throw std::runtime_error("Malformed value '" + field + "' in antenna pattern file " + filename);
+				}
+			}
+			if (!values.empty())
+			{
+				return values;
+			}
+		}
+		throw std::runtime_error("Unexpected end of antenna pattern file " + filename);
+	}
+
+	/**
+	 * @brief Reads one pattern cut (count header, cut angle, then angle/dB pairs) from a `.pat` file.
+	 *
+	 * @param file The input stream positioned at the cut header.
+	 * @param filename The filename, used for error messages.
+	 * @param set The interpolation set to fill with linear relative gains keyed by angle in radians.
+	 * @throws std::runtime_error If the cut is malformed.
+	 */
+	void loadPatCut(std::ifstream& file, const std::string& filename, const interp::InterpSet* set)
+	{
+		const auto header = readPatLine(file, filename);
+		const auto count = static_cast<std::size_t>(header.back());
+		if (header.size() < 2 || count == 0)
+		{
+			throw std::runtime_error("Invalid pattern cut header in antenna pattern file " + filename);
+		}
+		readPatLine(file, filename); // The angle of the cut plane is not needed
+		for (std::size_t i = 0; i < count; ++i)
+		{
+			const auto sample = readPatLine(file, filename);
+			if (sample.size() < 2)
+			{
+				throw std::runtime_error("Invalid pattern sample in antenna pattern file " + filename);
+			}
+			set->insertSample(sample[0] * PI / 180.0, std::pow(10.0, sample[1] / 10.0));
+		}
+	}
 }
 
+
 namespace antenna
 {
 	void Antenna::setEfficiencyFactor(const RealType loss) noexcept
@@ -125,6 +194,154 @@ namespace antenna
 		return ge * std::pow(2 * j1C(x), 2) * getEfficiencyFactor();
 	}
 
+	LinearArray::LinearArray(const std::string_view name, const std::size_t elements, const RealType spacing,
+							 std::vector<RealType> taper) :
+		Antenna(name.data()), _spacing(spacing), _taper(std::move(taper))
+	{
+		if (elements == 0)
+		{
+			throw std::runtime_error("Array antenna '" + std::string(name) + "' must have at least one element");
+		}
+		if (!(spacing > 0))
+		{
+			throw std::runtime_error("Array antenna '" + std::string(name) + "' must have a positive element spacing");
+		}
+		if (_taper.empty())
+		{
+			_taper.assign(elements, 1.0);
+		}
+		else if (_taper.size() != elements)
+		{
+			throw std::runtime_error(std::format("Array antenna '{}' has {} taper weights for {} elements", name,
+												 _taper.size(), elements));
+		}
+		for (const RealType w : _taper)
+		{
+			_taper_power += w * w;
+		}
+		if (!(_taper_power > 0))
+		{
+			throw std::runtime_error("Array antenna '" + std::string(name) + "' has an all-zero taper");
+		}
+	}
+
+	RealType LinearArray::getGain(const SVec3& angle, const SVec3& refangle, const RealType wavelength) const noexcept
+	{
+		const SVec3 delta = angle - refangle;
+		// Inter-element phase progression for a broadside array lying along the horizontal axis
+		const RealType psi = 2 * PI * _spacing / wavelength * std::sin(delta.azimuth) * std::cos(delta.elevation);
+		ComplexType array_factor{0.0, 0.0};
+		for (std::size_t n = 0; n < _taper.size(); ++n)
+		{
+			array_factor += _taper[n] * std::polar(1.0, psi * static_cast<RealType>(n));
+		}
+		return std::norm(array_factor) / _taper_power * getEfficiencyFactor();
+	}
+
+	std::vector<RealType> LinearArray::getGratingLobes(const RealType scanAngle, const RealType wavelength) const
+	{
+		if (!(wavelength > 0))
+		{
+			throw std::runtime_error("Array antenna '" + getName() + "' needs a positive wavelength for grating lobes");
+		}
+
+		// Admit lobes that land on endfire to within rounding error
+		constexpr RealType tolerance = 1e-9;
+		const RealType main_sine = std::sin(scanAngle);
+		const RealType step = wavelength / _spacing;
+		const auto first = static_cast<long long>(std::ceil((-1.0 - main_sine) / step - tolerance));
+		const auto last = static_cast<long long>(std::floor((1.0 - main_sine) / step + tolerance));
+		std::vector<RealType> lobes;
+		for (long long m = first; m <= last; ++m)
+		{
+			if (m != 0)
+			{
+				lobes.push_back(std::asin(std::clamp(main_sine + static_cast<RealType>(m) * step, -1.0, 1.0)));
+			}
+		}
+		return lobes;
+	}
+
+	RealType findPeakGain(const Antenna& antenna, const RealType wavelength)
+	{
+		constexpr int degrees_azimuth = 180;
+		constexpr int degrees_elevation = 90;
+		const SVec3 boresight(1.0, 0.0, 0.0);
+		RealType peak = antenna.getGain(boresight, boresight, wavelength);
+		for (int el = -degrees_elevation; el <= degrees_elevation; ++el)
+		{
+			for (int az = -degrees_azimuth; az < degrees_azimuth; ++az)
+			{
+				const SVec3 direction(1.0, az * PI / 180.0, el * PI / 180.0);
+				peak = std::max(peak, antenna.getGain(direction, boresight, wavelength));
+			}
+		}
+		return peak;
+	}
+
+	RealType calculateEffectiveAperture(const Antenna& antenna, const RealType wavelength)
+	{
+		if (!(wavelength > 0))
+		{
+			throw std::runtime_error("Antenna '" + antenna.getName() +
+									 "' needs a positive wavelength for its effective aperture");
+		}
+		return findPeakGain(antenna, wavelength) * wavelength * wavelength / (4.0 * PI);
+	}
+
+	std::pair<RealType, RealType> halfPowerBeamwidths(const Antenna& antenna, const RealType wavelength)
+	{
+		constexpr RealType scan_step = 0.05 * PI / 180.0;
+		constexpr int bisection_steps = 40;
+		const SVec3 boresight(1.0, 0.0, 0.0);
+		const RealType peak = antenna.getGain(boresight, boresight, wavelength);
+		if (!(peak > 0))
+		{
+			throw std::runtime_error("Antenna '" + antenna.getName() + "' has no gain along its boresight.");
+		}
+		// Sampling error of the one degree grid is tolerated
+		if (findPeakGain(antenna, wavelength) > peak * (1.0 + 1e-6))
+		{
+			throw std::runtime_error("Antenna '" + antenna.getName() + "' has no clear main lobe at boresight.");
+		}
+
+		// Angle from boresight at which the gain first falls below half power along one half-cut
+		const auto edge = [&](const RealType azimuthSign, const RealType elevationSign, const RealType limit)
+		{
+			const auto below_half = [&](const RealType theta)
+			{
+				const SVec3 direction(1.0, azimuthSign * theta, elevationSign * theta);
+				return antenna.getGain(direction, boresight, wavelength) < 0.5 * peak;
+			};
+			RealType inner = 0.0;
+			RealType outer = scan_step;
+			while (!below_half(outer))
+			{
+				inner = outer;
+				outer += scan_step;
+				if (outer > limit)
+				{
+					throw std::runtime_error("Antenna '" + antenna.getName() +
+											 "' has no clear main lobe: its gain does not fall 3 dB below the peak.");
+				}
+			}
+			for (int n = 0; n < bisection_steps; ++n)
+			{
+				if (const RealType mid = 0.5 * (inner + outer); below_half(mid))
+				{
+					outer = mid;
+				}
+				else
+				{
+					inner = mid;
+				}
+			}
+			return 0.5 * (inner + outer);
+		};
+
+		return {edge(1.0, 0.0, PI) + edge(-1.0, 0.0, PI), edge(0.0, 1.0, PI / 2.0) + edge(0.0, -1.0, PI / 2.0)};
+	}
+
 	RealType XmlAntenna::getGain(const SVec3& angle, const SVec3& refangle, RealType /*wavelength*/) const
 	{
 		const SVec3 delta_angle = angle - refangle;
@@ -200,4 +417,73 @@ namespace antenna
 
 		return interp * getEfficiencyFactor();
 	}
+
+	RealType PatAntenna::getGain(const SVec3& angle, const SVec3& refangle, RealType /*wavelength*/) const
+	{
+		const SVec3 delta_angle = angle - refangle;
+
+		// The engine measures azimuth counter-clockwise, while the file measures it clockwise
+		RealType bearing = std::fmod(-delta_angle.azimuth, 2.0 * PI);
+		if (bearing < 0)
+		{
+			bearing += 2.0 * PI;
+		}
+		const std::optional<RealType> azi_value = _azi_samples->getValueAt(bearing);
+
+		if (const std::optional<RealType> elev_value = _elev_samples->getValueAt(delta_angle.elevation);
+			azi_value && elev_value)
+		{
+			return *azi_value * *elev_value * _max_gain * getEfficiencyFactor();
+		}
+
+		LOG(Level::FATAL, "Could not get antenna gain value");
+		throw std::runtime_error("Could not get antenna gain value");
+	}
+
+	void PatAntenna::loadPatternFile()
+	{
+		std::ifstream file(_filename);
+		if (!file)
+		{
+			LOG(Level::FATAL, "Could not open antenna pattern file {}", _filename);
+			throw std::runtime_error("Could not open antenna pattern file " + _filename);
+		}
+
+		// Header: 'description', azimuth offset, peak gain in dBi (the description may contain commas)
+		std::string header;
+		std::getline(file, header);
+		std::ranges::replace(header, ',', ' ');
+		std::istringstream header_fields(header.substr(header.rfind('\'') + 1));
+		RealType offset{}, peak_dbi{};
+		if (header.find('\'') == std::string::npos || !(header_fields >> offset >> peak_dbi))
+		{
+			throw std::runtime_error("Invalid header line in antenna pattern file " + _filename);
+		}
+		_max_gain = std::pow(10.0, peak_dbi / 10.0);
+
+		// The 999 marker introduces the horizontal cut, followed by the vertical cut
+		if (readPatLine(file, _filename).front() != 999)
+		{
+			throw std::runtime_error("Missing 999 marker in antenna pattern file " + _filename);
+		}
+		loadPatCut(file, _filename, _azi_samples.get());
+		loadPatCut(file, _filename, _elev_samples.get());
+
+		// Close the azimuth cut so that bearings just below 360 degrees interpolate back to 0
+		if (const auto boresight = _azi_samples->getValueAt(0.0))
+		{
+			_azi_samples->insertSample(2.0 * PI, *boresight);
+		}
+		LOG(Level::DEBUG, "Loaded antenna pattern '{}' with peak gain {} dBi", _filename, peak_dbi);
+	}
+
+	std::unique_ptr<Antenna> loadAntennaFromFile(const std::string_view name, const std::string& filename)
+	{
+		if (const auto extension = std::filesystem::path(filename).extension().string();
+			extension == ".pat" || extension == ".PAT")
+		{
+			return std::make_unique<PatAntenna>(name, filename);
+		}
+		return std::make_unique<H5Antenna>(name, filename);
+	}
 }
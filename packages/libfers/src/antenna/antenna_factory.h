@@ -12,7 +12,9 @@
 
 #pragma once
 
+#include <cstddef>
 #include <memory>
+#include <optional>
 #include <string>
 #include <string_view>
 #include <utility>
@@ -88,6 +90,13 @@ namespace antenna
 		// TODO: Implement noise temperature calculation
 		[[nodiscard]] virtual RealType getNoiseTemperature(const math::SVec3& /*angle*/) const noexcept { return 0; }
 
+		/**
+		 * @brief Retrieves the physical aperture area of the antenna, where its geometry defines one.
+		 *
+		 * @return The aperture area in square meters, or std::nullopt for pattern-only antennas.
+		 */
+		[[nodiscard]] virtual std::optional<RealType> getPhysicalAperture() const noexcept { return std::nullopt; }
+
 		/**
 		 * @brief Sets the efficiency factor of the antenna.
 		 *
@@ -304,6 +313,12 @@ namespace antenna
 		/** @brief Gets the dimension of the square horn. */
 		[[nodiscard]] RealType getDimension() const noexcept { return _dimension; }
 
+		/** @brief Gets the area of the square horn mouth. */
+		[[nodiscard]] std::optional<RealType> getPhysicalAperture() const noexcept override
+		{
+			return _dimension * _dimension;
+		}
+
 	private:
 		RealType _dimension; ///< Dimension of the square horn.
 	};
@@ -349,10 +364,89 @@ namespace antenna
 		/** @brief Gets the diameter of the parabolic reflector. */
 		[[nodiscard]] RealType getDiameter() const noexcept { return _diameter; }
 
+		/** @brief Gets the area of the circular reflector. */
+		[[nodiscard]] std::optional<RealType> getPhysicalAperture() const noexcept override
+		{
+			return PI * _diameter * _diameter / 4.0;
+		}
+
 	private:
 		RealType _diameter; ///< Diameter of the parabolic reflector.
 	};
 
+	/**
+	 * @class LinearArray
+	 * @brief Represents a uniformly spaced linear array of isotropic elements with per-element taper weights.
+	 *
+	 * The array axis lies in the horizontal plane, perpendicular to boresight, so the array factor shapes the
+	 * azimuth pattern. The gain is the array factor normalized by the taper's coherent power, which makes the
+	 * boresight gain of a uniform array equal to the element count.
+	 */
+	class LinearArray final : public Antenna
+	{
+	public:
+		/**
+		 * @brief Constructs a LinearArray antenna.
+		 *
+		 * @param name The name of the antenna.
+		 * @param elements The number of array elements.
+		 * @param spacing The element spacing in meters.
+		 * @param taper The amplitude weight of each element. An empty vector selects a uniform taper.
+		 * @throws std::runtime_error If the element count, spacing or taper length is invalid.
+		 */
+		LinearArray(std::string_view name, std::size_t elements, RealType spacing, std::vector<RealType> taper = {});
+
+		~LinearArray() override = default;
+
+		LinearArray(const LinearArray&) = delete;
+
+		LinearArray& operator=(const LinearArray&) = delete;
+
+		LinearArray(LinearArray&&) = delete;
+
+		LinearArray& operator=(LinearArray&&) = delete;
+
+		/**
+		 * @brief Computes the gain of the array from its array factor.
+		 *
+		 * @param angle The angle at which the gain is to be computed.
+		 * @param refangle The reference angle.
+		 * @param wavelength The wavelength of the signal.
+		 * @return The computed gain of the antenna.
+		 */
+		[[nodiscard]] RealType getGain(const math::SVec3& angle, const math::SVec3& refangle,
+									   RealType wavelength) const noexcept override;
+
+		/** @brief Gets the number of array elements. */
+		[[nodiscard]] std::size_t getElementCount() const noexcept { return _taper.size(); }
+
+		/** @brief Gets the element spacing in meters. */
+		[[nodiscard]] RealType getSpacing() const noexcept { return _spacing; }
+
+		/** @brief Gets the amplitude weight of each element. */
+		[[nodiscard]] const std::vector<RealType>& getTaper() const noexcept { return _taper; }
+
+		/**
+		 * @brief Finds the grating lobes in visible space for a beam steered off boresight.
+		 *
+		 * Grating lobes lie where the inter-element phase differs from that of the main beam by a
+		 * whole number of cycles, i.e. `sin(theta) = sin(scanAngle) + m * wavelength / spacing` for
+		 * a non-zero integer `m`. Only directions with `|sin(theta)| <= 1` are returned, so an array
+		 * spaced at no more than half a wavelength has none at any scan angle.
+		 *
+		 * @param scanAngle The azimuth of the main beam relative to boresight, in radians.
+		 * @param wavelength The signal wavelength in meters.
+		 * @return The grating lobe azimuths relative to boresight in radians, in ascending order.
+		 * @throws std::runtime_error If the wavelength is not positive.
+		 */
+		[[nodiscard]] std::vector<RealType> getGratingLobes(RealType scanAngle, RealType wavelength) const;
+
+	private:
+		RealType _spacing; ///< Element spacing in meters.
+		std::vector<RealType> _taper; ///< Amplitude weight of each element.
+		RealType _taper_power{}; ///< Sum of squared taper weights, used to normalize the gain.
+	};
+
 	/**
 	 * @class XmlAntenna
 	 * @brief Represents an antenna whose gain pattern is defined by an XML file.
@@ -426,6 +520,73 @@ namespace antenna
 		std::unique_ptr<interp::InterpSet> _elev_samples; ///< Interpolation set for elevation gain samples.
 	};
 
+	/**
+	 * @class PatAntenna
+	 * @brief Represents an antenna whose gain pattern is read from an NSMA/EDX `.pat` file.
+	 *
+	 * The file provides a peak gain in dBi together with a horizontal (azimuth) cut and a
+	 * vertical (elevation) cut in dB relative to the peak. Azimuths are measured clockwise
+	 * from boresight over [0, 360) degrees and elevations are positive above boresight.
+	 * The gain in any direction is the product of the two interpolated cuts.
+	 */
+	class PatAntenna final : public Antenna
+	{
+	public:
+		/**
+		 * @brief Constructs a PatAntenna with the specified name and `.pat` file.
+		 *
+		 * @param name The name of the antenna.
+		 * @param filename The path to the `.pat` file containing the antenna's gain pattern.
+		 * @throws std::runtime_error If the file cannot be opened or parsed.
+		 */
+		PatAntenna(const std::string_view name, const std::string& filename) :
+			Antenna(name.data()), _filename(filename), _azi_samples(std::make_unique<interp::InterpSet>()),
+			_elev_samples(std::make_unique<interp::InterpSet>())
+		{
+			loadPatternFile();
+		}
+
+		~PatAntenna() override = default;
+
+		PatAntenna(const PatAntenna&) = delete;
+
+		PatAntenna& operator=(const PatAntenna&) = delete;
+
+		PatAntenna(PatAntenna&&) = delete;
+
+		PatAntenna& operator=(PatAntenna&&) = delete;
+
+		/**
+		 * @brief Computes the gain of the antenna based on the input angle and reference angle.
+		 *
+		 * @param angle The angle at which the gain is to be computed.
+		 * @param refangle The reference angle.
+		 * @return The gain of the antenna at the specified angle.
+		 * @throws std::runtime_error If gain values cannot be retrieved from the interpolation sets.
+		 */
+		[[nodiscard]] RealType getGain(const math::SVec3& angle, const math::SVec3& refangle,
+									   RealType /*wavelength*/) const override;
+
+		/** @brief Gets the filename of the antenna description. */
+		[[nodiscard]] const std::string& getFilename() const noexcept { return _filename; }
+
+		/** @brief Gets the peak gain of the antenna (linear). */
+		[[nodiscard]] RealType getMaxGain() const noexcept { return _max_gain; }
+
+	private:
+		/**
+		 * @brief Loads the peak gain and both pattern cuts from the `.pat` file.
+		 *
+		 * @throws std::runtime_error If the file cannot be opened or is malformed.
+		 */
+		void loadPatternFile();
+
+		std::string _filename; ///< The original filename for the antenna description.
+		RealType _max_gain{}; ///< The peak gain of the antenna (linear).
+		std::unique_ptr<interp::InterpSet> _azi_samples; ///< Relative gain over clockwise azimuth in radians.
+		std::unique_ptr<interp::InterpSet> _elev_samples; ///< Relative gain over elevation in radians.
+	};
+
 	/**
 	 * @class H5Antenna
 	 * @brief Represents an antenna whose gain pattern is loaded from a HDF5 file.
@@ -477,4 +638,52 @@ namespace antenna
 		std::vector<std::vector<RealType>> _pattern; ///< The 2D pattern data.
 		std::string _filename; ///< The original filename for the antenna description.
 	};
+
+	/**
+	 * @brief Finds the peak gain of an antenna by sampling its pattern.
+	 *
+	 * The pattern is sampled on a one degree azimuth/elevation grid about boresight.
+	 *
+	 * @param antenna The antenna to sample.
+	 * @param wavelength The wavelength of the signal in meters.
+	 * @return The largest sampled gain (linear).
+	 */
+	[[nodiscard]] RealType findPeakGain(const Antenna& antenna, RealType wavelength);
+
+	/**
+	 * @brief Computes the effective aperture of an antenna, `G * lambda^2 / (4 * pi)`.
+	 *
+	 * @param antenna The antenna.
+	 * @param wavelength The wavelength of the signal in meters.
+	 * @return The effective aperture at the peak gain in square meters.
+	 * @throws std::runtime_error If the wavelength is not positive.
+	 */
+	[[nodiscard]] RealType calculateEffectiveAperture(const Antenna& antenna, RealType wavelength);
+
+	/**
+	 * @brief Finds the full -3 dB beamwidths of an antenna's main lobe.
+	 *
+	 * The main lobe must peak at boresight. Each principal cut through boresight is scanned
+	 * outwards on both sides until the gain falls below half the boresight gain, and the
+	 * edge is then refined by bisection.
+	 *
+	 * @param antenna The antenna.
+	 * @param wavelength The wavelength of the signal in meters.
+	 * @return The azimuth and elevation beamwidths in radians.
+	 * @throws std::runtime_error If the pattern has no gain at boresight, peaks elsewhere, or
+	 *         does not fall 3 dB below its peak on both sides of a cut.
+	 */
+	[[nodiscard]] std::pair<RealType, RealType> halfPowerBeamwidths(const Antenna& antenna, RealType wavelength);
+
+	/**
+	 * @brief Creates a file-based antenna, selecting the loader from the file extension.
+	 *
+	 * Files ending in `.pat` are read as NSMA/EDX patterns; all others are read as HDF5.
+	 *
+	 * @param name The name of the antenna.
+	 * @param filename The path to the pattern file.
+	 * @return A unique pointer to the loaded antenna.
+	 * @throws std::runtime_error If the pattern file cannot be loaded.
+	 */
+	[[nodiscard]] std::unique_ptr<Antenna> loadAntennaFromFile(std::string_view name, const std::string& filename);
 }
@@ -10,24 +10,49 @@
  * creation/destruction, exception catching, error reporting, and type casting.
  */
 
+#include <algorithm>
+#include <atomic>
+#include <chrono>
+#include <cmath>
 #include <core/logging.h>
 #include <core/parameters.h>
 #include <cstring>
+#include <filesystem>
+#include <format>
 #include <functional>
+#include <limits>
 #include <libfers/api.h>
 #include <math/path.h>
 #include <math/rotation_path.h>
+#include <memory>
 #include <nlohmann/json.hpp>
+#include <stdexcept>
 #include <string>
+#include <vector>
 
+#include "antenna/antenna_factory.h"
 #include "core/fers_context.h"
 #include "core/sim_threading.h"
 #include "core/thread_pool.h"
+#include "math/geodesy.h"
+#include "radar/platform.h"
+#include "radar/receiver.h"
+#include "radar/target.h"
+#include "radar/transmitter.h"
+#include "serial/dis_importer.h"
+#include "serial/hdf5_handler.h"
 #include "serial/json_serializer.h"
 #include "serial/kml_generator.h"
+#include "serial/waveform_factory.h"
 #include "serial/xml_parser.h"
 #include "serial/xml_serializer.h"
+#include "signal/radar_signal.h"
+#include "signal/spectrum.h"
 #include "simulation/channel_model.h"
+#include "simulation/coverage.h"
+#include "simulation/detection.h"
+#include "simulation/trajectory_analysis.h"
+#include "timing/phase_noise.h"
 
 // The fers_context struct is defined here as an alias for our C++ class.
 // This allows the C-API to return an opaque pointer, hiding the C++ implementation.
@@ -55,6 +80,159 @@ static void handle_api_exception(const std::exception& e, const std::string& fun
 	LOG(logging::Level::ERROR, "API Error in {}: {}", function_name, last_error_message);
 }
 
+/**
+ * @brief Converts a C grid description into its C++ counterpart.
+ */
+static simulation::GroundGrid to_cpp_grid(const fers_ground_grid_t& grid)
+{
+	return {grid.x_min, grid.x_max, grid.y_min, grid.y_max, grid.z, grid.nx, grid.ny};
+}
+
+/**
+ * @brief Copies a list of C++ polylines into a newly allocated C-API polyline list.
+ */
+static fers_polyline_list_t* to_c_polyline_list(const std::vector<simulation::Polyline>& polylines)
+{
+	auto* result = new fers_polyline_list_t();
+	result->count = polylines.size();
+	result->polylines = polylines.empty() ? nullptr : new fers_polyline_t[result->count];
+	for (size_t i = 0; i < result->count; ++i)
+	{
+		const auto& src = polylines[i];
+		auto& dst = result->polylines[i];
+		dst.count = src.size();
+		dst.points = src.empty() ? nullptr : new fers_ground_point_t[dst.count];
+		for (size_t k = 0; k < dst.count; ++k)
+		{
+			dst.points[k] = {src[k].x, src[k].y};
+		}
+	}
+	return result;
+}
+
+/**
+ * @brief Records a modification of the in-memory scenario in its provenance metadata.
+ *
+ * Sets the `modified` timestamp to the current UTC time, creating the metadata block
+ * (with a matching `created` timestamp) if the scenario does not have one yet.
+ */
+static void touch_scenario_metadata()
+{
+	const auto now_s = std::chrono::floor<std::chrono::seconds>(std::chrono::system_clock::now());
+	const auto now = std::format("{:%FT%TZ}", now_s);
+	auto& metadata = params::params.metadata;
+	if (!metadata)
+	{
+		metadata = params::ScenarioMetadata{};
+	}
+	if (metadata->created.empty())
+	{
+		metadata->created = now;
+	}
+	metadata->modified = now;
+}
+
+/**
+ * @brief Copies strings into a newly allocated C-API string list.
+ *
+ * @param strings The strings to copy.
+ * @return A list the caller must free with `fers_free_string_list`.
+ */
+static fers_string_list_t* to_c_string_list(const std::vector<std::string>& strings)
+{
+	auto* result = new fers_string_list_t{};
+	result->count = strings.size();
+	if (!strings.empty())
+	{
+		result->items = new char*[strings.size()];
+		for (size_t i = 0; i < strings.size(); ++i)
+		{
+			result->items[i] = strdup(strings[i].c_str());
+		}
+	}
+	return result;
+}
+
+/**
+ * @brief Maps a core progress phase to its C-API counterpart.
+ */
+static fers_progress_phase_t to_c_phase(const core::ProgressPhase phase)
+{
+	switch (phase)
+	{
+	case core::ProgressPhase::INITIALIZING:
+		return FERS_PROGRESS_INITIALIZING;
+	case core::ProgressPhase::SIMULATING:
+		return FERS_PROGRESS_SIMULATING;
+	case core::ProgressPhase::RENDERING:
+		return FERS_PROGRESS_RENDERING;
+	case core::ProgressPhase::EXPORTING:
+		return FERS_PROGRESS_EXPORTING;
+	case core::ProgressPhase::COMPLETE:
+		return FERS_PROGRESS_COMPLETE;
+	}
+	return FERS_PROGRESS_SIMULATING;
+}
+
+/**
+ * @brief Wraps a caller-owned cancellation flag in a check the simulation loop can poll.
+ *
+ * The flag is written from another thread (e.g. Rust's `AtomicBool`), so it is read atomically.
+ */
+static core::CancelCheck make_cancel_check(const bool* cancel_flag)
+{
+	if (!cancel_flag)
+	{
+		return {};
+	}
+	return [cancel_flag]
+	{ return std::atomic_ref(*const_cast<bool*>(cancel_flag)).load(std::memory_order_relaxed); };
+}
+
+/**
+ * @brief Classifies a platform by the components it carries, for display purposes.
+ *
+ * @param world The simulation world.
+ * @param platform The platform to classify.
+ * @return The role of the platform.
+ */
+static fers_track_kind_t classify_platform(const core::World& world, const radar::Platform* platform)
+{
+	bool has_tx = false;
+	bool has_monostatic = false;
+	for (const auto& tx : world.getTransmitters())
+	{
+		if (tx->getPlatform() == platform)
+		{
+			has_tx = true;
+			has_monostatic = has_monostatic || tx->getAttached() != nullptr;
+		}
+	}
+	if (has_monostatic)
+	{
+		return FERS_TRACK_MONOSTATIC;
+	}
+	if (has_tx)
+	{
+		return FERS_TRACK_TRANSMITTER;
+	}
+	for (const auto& rx : world.getReceivers())
+	{
+		if (rx->getPlatform() == platform)
+		{
+			return FERS_TRACK_RECEIVER;
+		}
+	}
+	for (const auto& target : world.getTargets())
+	{
+		if (target->getPlatform() == platform)
+		{
+			return FERS_TRACK_TARGET;
+		}
+	}
+	return FERS_TRACK_NONE;
+}
+
 extern "C" {
 
 fers_context_t* fers_context_create()
@@ -76,6 +254,48 @@ fers_context_t* fers_context_create()
 	}
 }
 
+fers_context_t* fers_context_create_subset(const fers_context_t* context, const char* const* platform_names,
+										   const size_t platform_count)
+{
+	last_error_message.clear();
+	if (!context || (!platform_names && platform_count > 0))
+	{
+		last_error_message = "Invalid arguments passed to fers_context_create_subset";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	const auto* ctx = reinterpret_cast<const FersContext*>(context);
+	// Loading the copy rewrites the global simulation parameters, so they are restored
+	// afterwards to leave the source scenario exactly as it was.
+	const params::Parameters saved_params = params::params;
+	try
+	{
+		std::vector<std::string> names;
+		for (size_t i = 0; i < platform_count; ++i)
+		{
+			if (!platform_names[i])
+			{
+				throw std::invalid_argument("Platform name must not be NULL.");
+			}
+			names.emplace_back(platform_names[i]);
+		}
+
+		nlohmann::json j = serial::world_to_json(*ctx->getWorld());
+		serial::select_scenario_platforms(j, names);
+		auto subset = std::make_unique<fers_context_t>();
+		serial::json_to_world(j, *subset->getWorld(), subset->getMasterSeeder());
+		params::params = saved_params;
+		return subset.release();
+	}
+	catch (const std::exception& e)
+	{
+		params::params = saved_params;
+		handle_api_exception(e, "fers_context_create_subset");
+		return nullptr;
+	}
+}
+
 void fers_context_destroy(fers_context_t* context)
 {
 	if (!context)
@@ -240,6 +460,185 @@ int fers_load_scenario_from_xml_string(fers_context_t* context, const char* xml_
 	}
 }
 
+fers_xml_validation_t* fers_validate_xml_file(const char* xml_filepath)
+{
+	last_error_message.clear();
+	if (!xml_filepath)
+	{
+		last_error_message = "Invalid arguments: xml_filepath is NULL.";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	// Parsing overwrites the global simulation parameters, so they are restored afterwards
+	// to leave the loaded scenario untouched.
+	const params::Parameters saved_params = params::params;
+	try
+	{
+		auto* result = new fers_xml_validation_t{};
+		std::vector<std::string> warnings;
+		{
+			const logging::LogCapture capture(logging::Level::WARNING);
+			try
+			{
+				FersContext scratch;
+				serial::parseSimulation(xml_filepath, scratch.getWorld(), true, scratch.getMasterSeeder());
+				result->valid = 1;
+			}
+			catch (const std::exception& e)
+			{
+				result->error_message = strdup(e.what());
+			}
+			for (const auto& message : capture.getMessages())
+			{
+				if (!result->error_message || message.find(result->error_message) == std::string::npos)
+				{
+					warnings.push_back(message);
+				}
+			}
+		}
+		params::params = saved_params;
+
+		result->warning_count = warnings.size();
+		if (!warnings.empty())
+		{
+			result->warnings = new char*[warnings.size()];
+			for (size_t i = 0; i < warnings.size(); ++i)
+			{
+				result->warnings[i] = strdup(warnings[i].c_str());
+			}
+		}
+		return result;
+	}
+	catch (const std::exception& e)
+	{
+		params::params = saved_params;
+		handle_api_exception(e, "fers_validate_xml_file");
+		return nullptr;
+	}
+}
+
+void fers_free_xml_validation(fers_xml_validation_t* validation)
+{
+	if (validation)
+	{
+		free(validation->error_message);
+		for (size_t i = 0; i < validation->warning_count; ++i)
+		{
+			free(validation->warnings[i]);
+		}
+		delete[] validation->warnings;
+		delete validation;
+	}
+}
+
+fers_string_list_t* fers_find_non_finite_values(const fers_context_t* context)
+{
+	last_error_message.clear();
+	if (!context)
+	{
+		last_error_message = "Invalid context provided to fers_find_non_finite_values.";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		return to_c_string_list(serial::find_non_finite_values(*ctx->getWorld()));
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_find_non_finite_values");
+		return nullptr;
+	}
+}
+
+fers_string_list_t* fers_find_duty_cycle_violations(const fers_context_t* context)
+{
+	last_error_message.clear();
+	if (!context)
+	{
+		last_error_message = "Invalid context provided to fers_find_duty_cycle_violations.";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		std::vector<std::string> violations;
+		for (const auto& tx : ctx->getWorld()->getTransmitters())
+		{
+			const auto limit = tx->getMaxDutyCycle();
+			if (const RealType duty_cycle = tx->getDutyCycle(); limit && duty_cycle > *limit)
+			{
+				violations.push_back(std::format("Transmitter '{}' has a duty cycle of {:g}%, above its {:g}% limit",
+												 tx->getName(), duty_cycle * 100, *limit * 100));
+			}
+		}
+		return to_c_string_list(violations);
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_find_duty_cycle_violations");
+		return nullptr;
+	}
+}
+
+fers_string_list_t* fers_find_receiver_window_overlaps(const fers_context_t* context)
+{
+	last_error_message.clear();
+	if (!context)
+	{
+		last_error_message = "Invalid context provided to fers_find_receiver_window_overlaps.";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		std::vector<std::string> overlaps;
+		for (const auto& rx : ctx->getWorld()->getReceivers())
+		{
+			if (rx->getMode() != radar::OperationMode::PULSED_MODE || !(rx->getWindowPrf() > 0))
+			{
+				continue;
+			}
+			const RealType pri = 1.0 / rx->getWindowPrf();
+			const RealType overlap = rx->getWindowSkip() + rx->getWindowLength() - pri;
+			// Skip and length that sum to the PRI in decimal may miss it by an ulp in binary
+			if (overlap > pri * 1e-9)
+			{
+				overlaps.push_back(std::format("{} '{}' listens {:g} s past its PRI of {:g} s (window skip {:g} s "
+											   "+ window length {:g} s), so consecutive windows overlap",
+											   rx->getAttached() ? "Monostatic radar" : "Receiver", rx->getName(),
+											   overlap, pri, rx->getWindowSkip(), rx->getWindowLength()));
+			}
+		}
+		return to_c_string_list(overlaps);
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_find_receiver_window_overlaps");
+		return nullptr;
+	}
+}
+
+void fers_free_string_list(fers_string_list_t* list)
+{
+	if (list)
+	{
+		for (size_t i = 0; i < list->count; ++i)
+		{
+			free(list->items[i]);
+		}
+		delete[] list->items;
+		delete list;
+	}
+}
+
 char* fers_get_scenario_as_json(fers_context_t* context)
 {
 	last_error_message.clear();
@@ -297,6 +696,56 @@ char* fers_get_scenario_as_xml(fers_context_t* context)
 	}
 }
 
+char* fers_get_scenario_as_xml_with_checksum(fers_context_t* context)
+{
+	last_error_message.clear();
+	if (!context)
+	{
+		last_error_message = "Invalid context provided to fers_get_scenario_as_xml_with_checksum.";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	const auto* ctx = reinterpret_cast<FersContext*>(context);
+	try
+	{
+		const std::string xml_str = serial::world_to_xml_string(*ctx->getWorld(), true);
+		return strdup(xml_str.c_str());
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_get_scenario_as_xml_with_checksum");
+		return nullptr;
+	}
+}
+
+char* fers_get_scenario_as_xml_validated(fers_context_t* context)
+{
+	last_error_message.clear();
+	if (!context)
+	{
+		last_error_message = "Invalid context provided to fers_get_scenario_as_xml_validated.";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	const auto* ctx = reinterpret_cast<FersContext*>(context);
+	try
+	{
+		const std::string xml_str = serial::world_to_xml_string(*ctx->getWorld());
+		if (const auto error = serial::findXmlValidationError(xml_str))
+		{
+			throw std::runtime_error("Generated XML failed validation: " + *error);
+		}
+		return strdup(xml_str.c_str());
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_get_scenario_as_xml_validated");
+		return nullptr;
+	}
+}
+
 int fers_update_scenario_from_json(fers_context_t* context, const char* scenario_json)
 {
 	last_error_message.clear();
@@ -312,6 +761,7 @@ int fers_update_scenario_from_json(fers_context_t* context, const char* scenario
 	{
 		const nlohmann::json j = nlohmann::json::parse(scenario_json);
 		serial::json_to_world(j, *ctx->getWorld(), ctx->getMasterSeeder());
+		touch_scenario_metadata();
 
 		return 0; // Success
 	}
@@ -320,7 +770,7 @@ int fers_update_scenario_from_json(fers_context_t* context, const char* scenario
 		// A specific catch block for JSON errors is used to provide more
 		// detailed feedback to the client (e.g., the UI), which can help
 		// developers diagnose schema or data format issues more easily.
-		last_error_message = "JSON parsing/deserialization error: " + std::string(e.what());
+		last_error_message = "JSON parsing/deserialization error: " + serial::describe_json_error(e);
 		LOG(logging::Level::ERROR, "API Error in {}: {}", "fers_update_scenario_from_json", last_error_message);
 		return 2; // JSON error
 	}
@@ -331,60 +781,344 @@ int fers_update_scenario_from_json(fers_context_t* context, const char* scenario
 	}
 }
 
-char* fers_get_last_error_message()
+int fers_import_dis_entity_states(fers_context_t* context, const char* filepath, size_t* out_count)
 {
-	if (last_error_message.empty())
+	last_error_message.clear();
+	if (!context || !filepath || !out_count)
 	{
-		return nullptr; // No error to report
+		last_error_message = "Invalid arguments passed to fers_import_dis_entity_states";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
 	}
-	// `strdup` allocates with `malloc`, which is part of the C standard ABI,
-	// making it safe to transfer ownership across the FFI boundary. The caller
-	// must then free this memory using `fers_free_string`.
-	return strdup(last_error_message.c_str());
-}
 
-void fers_free_string(char* str)
+	try
+	{
+		auto* ctx = reinterpret_cast<FersContext*>(context);
+		*out_count = serial::importDisEntityStates(*ctx->getWorld(), filepath);
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_import_dis_entity_states");
+		return 1;
+	}
+}
+
+int fers_import_scenario_from_output(fers_context_t* context, const char* hdf5_path)
 {
-	if (str)
+	last_error_message.clear();
+	if (!context || !hdf5_path)
 	{
-		free(str);
+		last_error_message = "Invalid arguments passed to fers_import_scenario_from_output";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	auto* ctx = reinterpret_cast<FersContext*>(context);
+	try
+	{
+		const nlohmann::json j = nlohmann::json::parse(serial::readGeometryMetadata(hdf5_path));
+		serial::json_to_world(j, *ctx->getWorld(), ctx->getMasterSeeder());
+		touch_scenario_metadata();
+		return 0;
+	}
+	catch (const nlohmann::json::exception& e)
+	{
+		last_error_message = "Invalid geometry metadata in output file: " + std::string(e.what());
+		LOG(logging::Level::ERROR, "API Error in {}: {}", "fers_import_scenario_from_output", last_error_message);
+		return 2;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_import_scenario_from_output");
+		return 1;
 	}
 }
 
-int fers_run_simulation(fers_context_t* context, fers_progress_callback_t callback, void* user_data)
+int fers_time_scale_scenario(fers_context_t* context, const double factor)
 {
 	last_error_message.clear();
 	if (!context)
 	{
-		last_error_message = "Invalid context provided to fers_run_simulation.";
+		last_error_message = "Invalid arguments passed to fers_time_scale_scenario";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	auto* ctx = reinterpret_cast<FersContext*>(context);
+	try
+	{
+		nlohmann::json j = serial::world_to_json(*ctx->getWorld());
+		serial::scale_scenario_time(j, factor);
+		serial::json_to_world(j, *ctx->getWorld(), ctx->getMasterSeeder());
+		touch_scenario_metadata();
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_time_scale_scenario");
+		return 1;
+	}
+}
+
+char* fers_duplicate_platform(fers_context_t* context, const char* platform_name)
+{
+	last_error_message.clear();
+	if (!context || !platform_name)
+	{
+		last_error_message = "Invalid arguments passed to fers_duplicate_platform";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	auto* ctx = reinterpret_cast<FersContext*>(context);
+	try
+	{
+		nlohmann::json j = serial::world_to_json(*ctx->getWorld());
+		const std::string name = serial::duplicate_platform(j, platform_name);
+		serial::json_to_world(j, *ctx->getWorld(), ctx->getMasterSeeder());
+		touch_scenario_metadata();
+		return strdup(name.c_str());
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_duplicate_platform");
+		return nullptr;
+	}
+}
+
+int fers_merge_scenario_from_xml_file(fers_context_t* context, const char* xml_filepath, const int include_platforms,
+									  fers_merge_summary_t* out_summary)
+{
+	last_error_message.clear();
+	if (!context || !xml_filepath || !out_summary)
+	{
+		last_error_message = "Invalid arguments passed to fers_merge_scenario_from_xml_file";
 		LOG(logging::Level::ERROR, last_error_message);
 		return -1;
 	}
 
 	auto* ctx = reinterpret_cast<FersContext*>(context);
+	// Parsing overwrites the global simulation parameters, so they are restored afterwards
+	// to keep those of the current scenario.
+	const params::Parameters saved_params = params::params;
+	try
+	{
+		nlohmann::json source;
+		{
+			FersContext scratch;
+			serial::parseSimulation(xml_filepath, scratch.getWorld(), true, scratch.getMasterSeeder());
+			source = serial::world_to_json(*scratch.getWorld());
+		}
+		params::params = saved_params;
+
+		const nlohmann::json original = serial::world_to_json(*ctx->getWorld());
+		nlohmann::json j = original;
+		const auto summary = serial::merge_scenario_assets(j, source, include_platforms != 0);
+		try
+		{
+			serial::json_to_world(j, *ctx->getWorld(), ctx->getMasterSeeder());
+		}
+		catch (const std::exception&)
+		{
+			serial::json_to_world(original, *ctx->getWorld(), ctx->getMasterSeeder());
+			throw;
+		}
+		touch_scenario_metadata();
+		*out_summary = {summary.waveforms, summary.timings, summary.antennas, summary.platforms};
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		params::params = saved_params;
+		handle_api_exception(e, "fers_merge_scenario_from_xml_file");
+		return 1;
+	}
+}
+
+char* fers_get_last_error_message()
+{
+	if (last_error_message.empty())
+	{
+		return nullptr; // No error to report
+	}
+	// `strdup` allocates with `malloc`, which is part of the C standard ABI,
+	// making it safe to transfer ownership across the FFI boundary. The caller
+	// must then free this memory using `fers_free_string`.
+	return strdup(last_error_message.c_str());
+}
 
+void fers_free_string(char* str)
+{
+	if (str)
+	{
+		free(str);
+	}
+}
+
+/**
+ * @brief Runs the simulation of a context, writing its output to the given location.
+ *
+ * Shared by `fers_run_simulation`, `fers_run_simulation_to` and `fers_run_simulation_streaming`.
+ *
+ * @return 0 on success, 2 if cancelled, 1 on error.
+ */
+static int run_simulation_to(FersContext* ctx, const core::OutputLocation& output, fers_progress_callback_t callback,
+							 void* user_data, const core::WindowStreamer::Callback& window_fn,
+							 const bool* cancel_flag, const char* function_name)
+{
 	// Wrap the C-style callback in a std::function for easier use in C++.
 	// This also handles the case where the callback is null.
-	std::function<void(const std::string&, int, int)> progress_fn;
+	core::ProgressReporter::Callback progress_fn;
 	if (callback)
 	{
-		progress_fn = [callback, user_data](const std::string& msg, const int current, const int total)
-		{ callback(msg.c_str(), current, total, user_data); };
+		progress_fn = [callback, user_data](const core::ProgressPhase phase, const std::string& msg, const int current,
+											const int total)
+		{ callback(to_c_phase(phase), msg.c_str(), current, total, user_data); };
 	}
 
 	try
 	{
 		pool::ThreadPool pool(params::renderThreads());
 
-		core::runEventDrivenSim(ctx->getWorld(), pool, progress_fn);
+		core::runEventDrivenSim(ctx->getWorld(), pool, progress_fn, window_fn, make_cancel_check(cancel_flag),
+								output);
 
 		return 0;
 	}
+	catch (const core::SimulationCancelled& e)
+	{
+		last_error_message = e.what();
+		LOG(logging::Level::INFO, last_error_message);
+		return 2;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, function_name);
+		return 1;
+	}
+}
+
+int fers_run_simulation(fers_context_t* context, fers_progress_callback_t callback, void* user_data,
+						const bool* cancel_flag)
+{
+	last_error_message.clear();
+	if (!context)
+	{
+		last_error_message = "Invalid context provided to fers_run_simulation.";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	return run_simulation_to(reinterpret_cast<FersContext*>(context), {}, callback, user_data, {}, cancel_flag,
+							 "fers_run_simulation");
+}
+
+/**
+ * @brief Checks that an output directory passed to the API exists.
+ *
+ * @return True if it does; otherwise false, with the last error message set.
+ */
+static bool check_output_directory(const char* output_dir)
+{
+	if (std::filesystem::is_directory(output_dir))
+	{
+		return true;
+	}
+	last_error_message = std::format("Output directory '{}' does not exist.", output_dir);
+	LOG(logging::Level::ERROR, last_error_message);
+	return false;
+}
+
+int fers_run_simulation_to(fers_context_t* context, const char* output_dir, const char* basename,
+						   fers_progress_callback_t callback, void* user_data, const bool* cancel_flag)
+{
+	last_error_message.clear();
+	if (!context || !output_dir)
+	{
+		last_error_message = "Invalid arguments passed to fers_run_simulation_to";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+	if (!check_output_directory(output_dir))
+	{
+		return 1;
+	}
+
+	const core::OutputLocation output{.directory = output_dir, .basename = basename ? basename : ""};
+	return run_simulation_to(reinterpret_cast<FersContext*>(context), output, callback, user_data, {}, cancel_flag,
+							 "fers_run_simulation_to");
+}
+
+fers_string_list_t* fers_list_output_files(const fers_context_t* context, const char* output_dir,
+										   const char* basename)
+{
+	last_error_message.clear();
+	if (!context)
+	{
+		last_error_message = "Invalid arguments passed to fers_list_output_files";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		const core::OutputLocation output{.directory = output_dir ? output_dir : "",
+										  .basename = basename ? basename : ""};
+		return to_c_string_list(core::listOutputFiles(*ctx->getWorld(), output));
+	}
 	catch (const std::exception& e)
 	{
-		handle_api_exception(e, "fers_run_simulation");
+		handle_api_exception(e, "fers_list_output_files");
+		return nullptr;
+	}
+}
+
+int fers_run_simulation_streaming(fers_context_t* context, const char* output_dir, const char* basename,
+								  fers_progress_callback_t progress_callback, void* progress_user_data,
+								  fers_window_callback_t window_callback, void* window_user_data,
+								  const bool* cancel_flag)
+{
+	last_error_message.clear();
+	if (!context || !window_callback)
+	{
+		last_error_message = "Invalid arguments: context or window_callback is NULL.";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+	if (output_dir && !check_output_directory(output_dir))
+	{
 		return 1;
 	}
+
+	// std::complex<double> is layout-compatible with double[2], so the window is
+	// handed over as interleaved I/Q without copying.
+	const core::WindowStreamer::Callback window_fn =
+		[window_callback, window_user_data](const std::string& receiver, const RealType start_time,
+											const RealType fullscale, const std::span<const ComplexType> samples)
+	{
+		window_callback(receiver.c_str(), start_time, fullscale, reinterpret_cast<const double*>(samples.data()),
+						samples.size(), window_user_data);
+	};
+
+	const core::OutputLocation output{.directory = output_dir ? output_dir : "",
+									  .basename = basename ? basename : ""};
+	return run_simulation_to(reinterpret_cast<FersContext*>(context), output, progress_callback, progress_user_data,
+							 window_fn, cancel_flag, "fers_run_simulation_streaming");
+}
+
+// --- Shared origin check of the geodetic waypoint conversions and the KML export ---
+static math::GeodeticPosition make_geodetic_origin(const double latitude, const double longitude,
+												   const double altitude)
+{
+	if (!std::isfinite(latitude) || !std::isfinite(longitude) || !std::isfinite(altitude) ||
+		std::abs(latitude) > 90.0 || std::abs(longitude) > 180.0)
+	{
+		throw std::invalid_argument("Geodetic origin must have a latitude within [-90, 90] and a longitude within "
+									"[-180, 180] degrees.");
+	}
+	return {latitude, longitude, altitude};
 }
 
 int fers_generate_kml(const fers_context_t* context, const char* output_kml_filepath)
@@ -417,6 +1151,67 @@ int fers_generate_kml(const fers_context_t* context, const char* output_kml_file
 	}
 }
 
+int fers_generate_kml_with_origin(const fers_context_t* context, const char* output_kml_filepath,
+								  const double origin_latitude, const double origin_longitude,
+								  const double origin_altitude)
+{
+	last_error_message.clear();
+	if (!context || !output_kml_filepath)
+	{
+		last_error_message = "Invalid arguments passed to fers_generate_kml_with_origin";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	auto* ctx = reinterpret_cast<const FersContext*>(context);
+
+	try
+	{
+		const auto origin = make_geodetic_origin(origin_latitude, origin_longitude, origin_altitude);
+		if (params::coordinateFrame() != params::CoordinateFrame::ENU)
+		{
+			throw std::invalid_argument("A KML origin override only applies to scenarios in the ENU coordinate frame.");
+		}
+		if (serial::KmlGenerator::generateKml(*ctx->getWorld(), output_kml_filepath, origin))
+		{
+			return 0;
+		}
+
+		last_error_message = "KML generation failed for an unknown reason.";
+		LOG(logging::Level::ERROR, last_error_message);
+		return 2;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_generate_kml_with_origin");
+		return 1;
+	}
+}
+
+int fers_validate_inline_waveform(const char* samples, const double rate, size_t* out_count)
+{
+	last_error_message.clear();
+	if (!samples || !out_count)
+	{
+		last_error_message = "Invalid arguments passed to fers_validate_inline_waveform";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto wave =
+			serial::loadWaveformFromSamples("inline", serial::parseInlineSamples(samples), rate, 1.0, 1.0);
+		*out_count = wave->getInlineSamples()->samples.size();
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_validate_inline_waveform");
+		return 1;
+	}
+}
+
 // --- Helper to convert C-API enum to C++ enum ---
 math::Path::InterpType to_cpp_interp_type(const fers_interp_type_t type)
 {
@@ -447,12 +1242,15 @@ math::RotationPath::InterpType to_cpp_rot_interp_type(const fers_interp_type_t t
 }
 
 
-fers_interpolated_path_t* fers_get_interpolated_motion_path(const fers_motion_waypoint_t* waypoints,
-															const size_t waypoint_count,
-															const fers_interp_type_t interp_type,
-															const size_t num_points)
+// --- Shared implementation of the exact and approximate motion path previews ---
+// A positive tolerance samples a piecewise-linear approximation of cubic paths instead of the exact path.
+// The samples span the window from window_start to window_end, clamped to the waypoint times.
+static fers_interpolated_path_t* interpolate_motion_path(const fers_motion_waypoint_t* waypoints,
+														 const size_t waypoint_count,
+														 const fers_interp_type_t interp_type, const size_t num_points,
+														 const double tolerance, const double window_start,
+														 const double window_end, const char* function_name)
 {
-	last_error_message.clear();
 	if (!waypoints || waypoint_count == 0 || num_points == 0)
 	{
 		last_error_message = "Invalid arguments: waypoints cannot be null and counts must be > 0.";
@@ -483,18 +1281,33 @@ fers_interpolated_path_t* fers_get_interpolated_motion_path(const fers_motion_wa
 
 		path.finalize();
 
+		std::unique_ptr<math::Path> approx_path;
+		const math::Path* sampled_path = &path;
+		if (tolerance > 0)
+		{
+			approx_path = path.approximateLinear(tolerance);
+			sampled_path = approx_path.get();
+		}
+
+		const double first_time = waypoints[0].time;
+		const double last_time = waypoints[waypoint_count - 1].time;
+		const double start_time = std::max(window_start, first_time);
+		const double end_time = std::min(window_end, last_time);
+		if (start_time > end_time)
+		{
+			throw std::invalid_argument(std::format("The time window [{}, {}] s does not overlap the waypoints "
+													"at [{}, {}] s.",
+													window_start, window_end, first_time, last_time));
+		}
+
 		auto* result_path = new fers_interpolated_path_t();
 		result_path->points = new fers_interpolated_point_t[num_points];
 		result_path->count = num_points;
 
-		const double start_time = waypoints[0].time;
-		const double end_time = waypoints[waypoint_count - 1].time;
-		const double duration = end_time - start_time;
-
 		// Handle static case separately
-		if (waypoint_count < 2 || duration <= 0)
+		if (waypoint_count < 2 || last_time <= first_time)
 		{
-			const math::Vec3 pos = path.getPosition(start_time);
+			const math::Vec3 pos = sampled_path->getPosition(first_time);
 			for (size_t i = 0; i < num_points; ++i)
 			{
 				result_path->points[i] = {pos.x, pos.y, pos.z};
@@ -502,13 +1315,13 @@ fers_interpolated_path_t* fers_get_interpolated_motion_path(const fers_motion_wa
 			return result_path;
 		}
 
-		const double time_step = duration / (num_points > 1 ? num_points - 1 : 1);
+		const double time_step = (end_time - start_time) / (num_points > 1 ? num_points - 1 : 1);
 
 		for (size_t i = 0; i < num_points; ++i)
 		{
 			const double t = start_time + i * time_step;
-			const math::Vec3 pos = path.getPosition(t);
-			const math::Vec3 vel = path.getVelocity(t);
+			const math::Vec3 pos = sampled_path->getPosition(t);
+			const math::Vec3 vel = sampled_path->getVelocity(t);
 			result_path->points[i] = {pos.x, pos.y, pos.z, vel.x, vel.y, vel.z};
 		}
 
@@ -516,33 +1329,206 @@ fers_interpolated_path_t* fers_get_interpolated_motion_path(const fers_motion_wa
 	}
 	catch (const std::exception& e)
 	{
-		handle_api_exception(e, "fers_get_interpolated_motion_path");
+		handle_api_exception(e, function_name);
 		return nullptr;
 	}
 }
 
-void fers_free_interpolated_motion_path(fers_interpolated_path_t* path)
+fers_interpolated_path_t* fers_get_interpolated_motion_path(const fers_motion_waypoint_t* waypoints,
+															const size_t waypoint_count,
+															const fers_interp_type_t interp_type,
+															const size_t num_points)
 {
-	if (path)
-	{
-		delete[] path->points;
-		delete path;
-	}
+	last_error_message.clear();
+	return interpolate_motion_path(waypoints, waypoint_count, interp_type, num_points, 0.0,
+								   -std::numeric_limits<double>::infinity(), std::numeric_limits<double>::infinity(),
+								   "fers_get_interpolated_motion_path");
 }
 
-fers_interpolated_rotation_path_t* fers_get_interpolated_rotation_path(const fers_rotation_waypoint_t* waypoints,
-																	   const size_t waypoint_count,
-																	   const fers_interp_type_t interp_type,
-																	   const size_t num_points)
+fers_interpolated_path_t* fers_get_approximate_motion_path(const fers_motion_waypoint_t* waypoints,
+														   const size_t waypoint_count,
+														   const fers_interp_type_t interp_type,
+														   const size_t num_points, const double tolerance)
 {
 	last_error_message.clear();
-	if (!waypoints || waypoint_count == 0 || num_points == 0)
+	if (!std::isfinite(tolerance) || tolerance <= 0)
 	{
-		last_error_message = "Invalid arguments: waypoints cannot be null and counts must be > 0.";
+		last_error_message = "Invalid arguments: approximation tolerance must be a positive number.";
 		LOG(logging::Level::ERROR, last_error_message);
 		return nullptr;
 	}
-	if (interp_type == FERS_INTERP_CUBIC && waypoint_count < 2)
+	return interpolate_motion_path(waypoints, waypoint_count, interp_type, num_points, tolerance,
+								   -std::numeric_limits<double>::infinity(), std::numeric_limits<double>::infinity(),
+								   "fers_get_approximate_motion_path");
+}
+
+fers_interpolated_path_t* fers_get_motion_path_window(const fers_motion_waypoint_t* waypoints,
+													  const size_t waypoint_count, const fers_interp_type_t interp_type,
+													  const size_t num_points, const double start_time,
+													  const double end_time, const double tolerance)
+{
+	last_error_message.clear();
+	if (std::isnan(start_time) || std::isnan(end_time) || !std::isfinite(tolerance) || tolerance < 0)
+	{
+		last_error_message = "Invalid arguments: the time window must be set and the tolerance non-negative.";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+	return interpolate_motion_path(waypoints, waypoint_count, interp_type, num_points, tolerance, start_time, end_time,
+								   "fers_get_motion_path_window");
+}
+
+void fers_free_interpolated_motion_path(fers_interpolated_path_t* path)
+{
+	if (path)
+	{
+		delete[] path->points;
+		delete path;
+	}
+}
+
+int fers_apply_position_noise(fers_interpolated_path_t* path, const double start_time, const double end_time,
+							  const double std_x, const double std_y, const double std_z, const double correlation_time,
+							  const unsigned seed)
+{
+	last_error_message.clear();
+	if (!path || (path->count > 0 && !path->points) || !std::isfinite(start_time) || !std::isfinite(end_time) ||
+		!(std_x >= 0) || !(std_y >= 0) || !(std_z >= 0) || !(correlation_time >= 0) || !std::isfinite(correlation_time))
+	{
+		last_error_message = "Invalid arguments passed to fers_apply_position_noise";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	radar::PositionNoise noise;
+	noise.std_dev = {std_x, std_y, std_z};
+	noise.seed = seed;
+	if (correlation_time > 0)
+	{
+		noise.correlation_time = correlation_time;
+	}
+	const double time_step = path->count > 1 ? (end_time - start_time) / static_cast<double>(path->count - 1) : 0.0;
+	for (size_t i = 0; i < path->count; ++i)
+	{
+		const double time = start_time + static_cast<double>(i) * time_step;
+		const math::Vec3 offset = radar::samplePositionNoise(noise, time);
+		const math::Vec3 rate = radar::samplePositionNoiseVelocity(noise, time);
+		path->points[i].x += offset.x;
+		path->points[i].y += offset.y;
+		path->points[i].z += offset.z;
+		path->points[i].vx += rate.x;
+		path->points[i].vy += rate.y;
+		path->points[i].vz += rate.z;
+	}
+	return 0;
+}
+
+int fers_preview_hop_sequence(const double* frequencies, const size_t frequency_count, const unsigned* pattern,
+							  const size_t pattern_count, const size_t num_pulses, double* out_carriers)
+{
+	last_error_message.clear();
+	if (!frequencies || (pattern_count > 0 && !pattern) || (num_pulses > 0 && !out_carriers))
+	{
+		last_error_message = "Invalid arguments passed to fers_preview_hop_sequence";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		radar::HopSequence hops;
+		hops.frequencies.assign(frequencies, frequencies + frequency_count);
+		if (pattern_count > 0)
+		{
+			hops.pattern.assign(pattern, pattern + pattern_count);
+		}
+		radar::validateHopSequence(hops, "preview");
+		for (size_t i = 0; i < num_pulses; ++i)
+		{
+			out_carriers[i] = radar::hopCarrier(hops, i);
+		}
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_preview_hop_sequence");
+		return 1;
+	}
+}
+
+int fers_geodetic_to_local(const double origin_latitude, const double origin_longitude, const double origin_altitude,
+						   const fers_geodetic_waypoint_t* points, const size_t count,
+						   fers_motion_waypoint_t* out_waypoints)
+{
+	last_error_message.clear();
+	if (count > 0 && (!points || !out_waypoints))
+	{
+		last_error_message = "Invalid arguments passed to fers_geodetic_to_local";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto origin = make_geodetic_origin(origin_latitude, origin_longitude, origin_altitude);
+		for (size_t i = 0; i < count; ++i)
+		{
+			const auto& [time, latitude, longitude, altitude] = points[i];
+			const math::Vec3 local = math::geodeticToLocal({latitude, longitude, altitude}, origin);
+			out_waypoints[i] = {time, local.x, local.y, local.z};
+		}
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_geodetic_to_local");
+		return 1;
+	}
+}
+
+int fers_local_to_geodetic(const double origin_latitude, const double origin_longitude, const double origin_altitude,
+						   const fers_motion_waypoint_t* waypoints, const size_t count,
+						   fers_geodetic_waypoint_t* out_points)
+{
+	last_error_message.clear();
+	if (count > 0 && (!waypoints || !out_points))
+	{
+		last_error_message = "Invalid arguments passed to fers_local_to_geodetic";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto origin = make_geodetic_origin(origin_latitude, origin_longitude, origin_altitude);
+		for (size_t i = 0; i < count; ++i)
+		{
+			const auto& [time, x, y, z] = waypoints[i];
+			const auto [latitude, longitude, altitude] = math::localToGeodetic({x, y, z}, origin);
+			out_points[i] = {time, latitude, longitude, altitude};
+		}
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_local_to_geodetic");
+		return 1;
+	}
+}
+
+fers_interpolated_rotation_path_t* fers_get_interpolated_rotation_path(const fers_rotation_waypoint_t* waypoints,
+																	   const size_t waypoint_count,
+																	   const fers_interp_type_t interp_type,
+																	   const size_t num_points)
+{
+	last_error_message.clear();
+	if (!waypoints || waypoint_count == 0 || num_points == 0)
+	{
+		last_error_message = "Invalid arguments: waypoints cannot be null and counts must be > 0.";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+	if (interp_type == FERS_INTERP_CUBIC && waypoint_count < 2)
 	{
 		last_error_message = "Cubic interpolation requires at least 2 waypoints.";
 		LOG(logging::Level::ERROR, last_error_message);
@@ -720,6 +1706,234 @@ void fers_free_antenna_pattern_data(fers_antenna_pattern_data_t* data)
 	}
 }
 
+// --- Construction of a standalone antenna definition for the antenna utilities ---
+static std::unique_ptr<antenna::Antenna> make_antenna(const fers_antenna_t& def)
+{
+	const auto filename = [&]
+	{
+		if (!def.filename || *def.filename == '\0')
+		{
+			throw std::runtime_error("Antenna pattern needs a filename.");
+		}
+		return std::string(def.filename);
+	};
+
+	std::unique_ptr<antenna::Antenna> ant;
+	switch (def.pattern)
+	{
+	case FERS_ANTENNA_ISOTROPIC:
+		ant = std::make_unique<antenna::Isotropic>("preview");
+		break;
+	case FERS_ANTENNA_SINC:
+		ant = std::make_unique<antenna::Sinc>("preview", def.alpha, def.beta, def.gamma);
+		break;
+	case FERS_ANTENNA_GAUSSIAN:
+		ant = std::make_unique<antenna::Gaussian>("preview", def.azscale, def.elscale);
+		break;
+	case FERS_ANTENNA_SQUAREHORN:
+		ant = std::make_unique<antenna::SquareHorn>("preview", def.diameter);
+		break;
+	case FERS_ANTENNA_PARABOLIC:
+		ant = std::make_unique<antenna::Parabolic>("preview", def.diameter);
+		break;
+	case FERS_ANTENNA_ARRAY:
+	{
+		std::vector<RealType> taper;
+		if (def.taper && def.taper_count > 0)
+		{
+			taper.assign(def.taper, def.taper + def.taper_count);
+		}
+		ant = std::make_unique<antenna::LinearArray>("preview", def.elements, def.spacing, std::move(taper));
+		break;
+	}
+	case FERS_ANTENNA_XML:
+		ant = std::make_unique<antenna::XmlAntenna>("preview", filename());
+		break;
+	case FERS_ANTENNA_FILE:
+		ant = antenna::loadAntennaFromFile("preview", filename());
+		break;
+	default:
+		throw std::runtime_error("Unsupported antenna pattern.");
+	}
+	ant->setEfficiencyFactor(def.efficiency);
+	return ant;
+}
+
+int fers_sample_antenna_pattern(const fers_antenna_t* antenna, const double az_start_deg, const double az_end_deg,
+								const double elevation_deg, const double frequency_hz, const size_t num_points,
+								double* out_gains_db)
+{
+	last_error_message.clear();
+	if (!antenna || num_points == 0 || !out_gains_db)
+	{
+		last_error_message = "Invalid arguments passed to fers_sample_antenna_pattern";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto ant = make_antenna(*antenna);
+		// Same 1 GHz fallback as fers_get_antenna_pattern for wavelength-dependent patterns
+		const RealType wavelength = frequency_hz > 0.0 ? params::c() / frequency_hz : 0.3;
+
+		// Boresight is the local X-axis, so a zero reference angle gives the gain relative to it
+		const math::SVec3 ref_angle(1.0, 0.0, 0.0);
+		const RealType elevation = elevation_deg * PI / 180.0;
+		for (size_t i = 0; i < num_points; ++i)
+		{
+			const RealType fraction = num_points > 1 ? static_cast<RealType>(i) / (num_points - 1) : 0.0;
+			const RealType azimuth = (az_start_deg + (az_end_deg - az_start_deg) * fraction) * PI / 180.0;
+			const RealType gain = ant->getGain(math::SVec3(1.0, azimuth, elevation), ref_angle, wavelength);
+			out_gains_db[i] = gain > 0.0 ? 10.0 * std::log10(gain) : -std::numeric_limits<double>::infinity();
+		}
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_sample_antenna_pattern");
+		return 1;
+	}
+}
+
+fers_grating_lobe_report_t* fers_find_grating_lobes(const fers_context_t* context, const fers_antenna_t* antenna,
+													const char* antenna_name, const double scan_angle_deg,
+													const double frequency_hz)
+{
+	last_error_message.clear();
+	if (!antenna)
+	{
+		last_error_message = "Invalid arguments passed to fers_find_grating_lobes";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	try
+	{
+		const auto ant = make_antenna(*antenna);
+		const auto* array = dynamic_cast<const antenna::LinearArray*>(ant.get());
+		if (!array)
+		{
+			throw std::runtime_error("Antenna is not an array antenna.");
+		}
+
+		RealType carrier = frequency_hz;
+		if (!(carrier > 0) && context && antenna_name)
+		{
+			// Fall back to the carrier of a transmitter fitted with the scenario antenna
+			const auto* ctx = reinterpret_cast<const FersContext*>(context);
+			const auto* scenario_ant = ctx->getWorld()->findAntenna(antenna_name);
+			for (const auto& tx : ctx->getWorld()->getTransmitters())
+			{
+				if (scenario_ant && tx->getAntenna() == scenario_ant && tx->getSignal())
+				{
+					carrier = tx->getSignal()->getCarrier();
+					break;
+				}
+			}
+		}
+		if (!(carrier > 0))
+		{
+			throw std::runtime_error("Antenna is not used by any transmitter; a carrier frequency must be supplied.");
+		}
+
+		const RealType wavelength = params::c() / carrier;
+		const auto lobes = array->getGratingLobes(scan_angle_deg * PI / 180.0, wavelength);
+
+		auto* result = new fers_grating_lobe_report_t();
+		result->wavelength = wavelength;
+		result->spacing_wavelengths = array->getSpacing() / wavelength;
+		result->lobe_count = lobes.size();
+		result->lobe_angles_deg = new double[result->lobe_count];
+		for (size_t i = 0; i < result->lobe_count; ++i)
+		{
+			result->lobe_angles_deg[i] = lobes[i] * 180.0 / PI;
+		}
+		return result;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_find_grating_lobes");
+		return nullptr;
+	}
+}
+
+void fers_free_grating_lobe_report(fers_grating_lobe_report_t* report)
+{
+	if (report)
+	{
+		delete[] report->lobe_angles_deg;
+		delete report;
+	}
+}
+
+int fers_calculate_effective_aperture(const fers_antenna_t* antenna, const double frequency_hz,
+									  double* out_effective_aperture, double* out_physical_aperture)
+{
+	last_error_message.clear();
+	if (!antenna || !out_effective_aperture || !(frequency_hz > 0.0))
+	{
+		last_error_message = "Invalid arguments passed to fers_calculate_effective_aperture";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto ant = make_antenna(*antenna);
+		const RealType effective = antenna::calculateEffectiveAperture(*ant, params::c() / frequency_hz);
+		RealType physical = std::numeric_limits<RealType>::quiet_NaN();
+		if (const auto area = ant->getPhysicalAperture())
+		{
+			physical = *area * ant->getEfficiencyFactor();
+			if (std::abs(effective - physical) > 0.05 * physical)
+			{
+				LOG(logging::Level::WARNING,
+					"Effective aperture of antenna ({} m^2) differs from its physical aperture ({} m^2)", effective,
+					physical);
+			}
+		}
+
+		*out_effective_aperture = effective;
+		if (out_physical_aperture)
+		{
+			*out_physical_aperture = physical;
+		}
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_effective_aperture");
+		return 1;
+	}
+}
+
+int fers_compute_beamwidth(const fers_antenna_t* antenna, const double frequency_hz, double* out_azimuth_deg,
+						   double* out_elevation_deg)
+{
+	last_error_message.clear();
+	if (!antenna || !out_azimuth_deg || !out_elevation_deg || !(frequency_hz > 0.0))
+	{
+		last_error_message = "Invalid arguments passed to fers_compute_beamwidth";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto ant = make_antenna(*antenna);
+		const auto [azimuth, elevation] = antenna::halfPowerBeamwidths(*ant, params::c() / frequency_hz);
+		*out_azimuth_deg = azimuth * 180.0 / PI;
+		*out_elevation_deg = elevation * 180.0 / PI;
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_compute_beamwidth");
+		return 1;
+	}
+}
+
 // --- Preview Link Calculation Implementation ---
 
 fers_visual_link_list_t* fers_calculate_preview_links(const fers_context_t* context, const double time)
@@ -804,4 +2018,1231 @@ void fers_free_preview_links(fers_visual_link_list_t* list)
 		delete list;
 	}
 }
+
+fers_interference_report_t* fers_calculate_interference(const fers_context_t* context, const char* rx_name,
+														const double time)
+{
+	last_error_message.clear();
+	if (!context || !rx_name)
+	{
+		last_error_message = "Invalid arguments passed to fers_calculate_interference";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		const auto cpp_report = simulation::calculateInterference(*ctx->getWorld(), rx_name, time);
+
+		auto* result = new fers_interference_report_t();
+		result->total_power = cpp_report.total_power;
+		result->temperature = cpp_report.temperature;
+		result->count = cpp_report.contributions.size();
+
+		if (!cpp_report.contributions.empty())
+		{
+			result->contributions = new fers_interference_contribution_t[result->count];
+			for (size_t i = 0; i < result->count; ++i)
+			{
+				const auto& src = cpp_report.contributions[i];
+				auto& dst = result->contributions[i];
+				std::strncpy(dst.transmitter_name, src.transmitter_name.c_str(), sizeof(dst.transmitter_name) - 1);
+				dst.transmitter_name[sizeof(dst.transmitter_name) - 1] = '\0';
+				dst.power = src.power;
+			}
+		}
+		else
+		{
+			result->contributions = nullptr;
+		}
+		return result;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_interference");
+		return nullptr;
+	}
+}
+
+void fers_free_interference_report(fers_interference_report_t* report)
+{
+	if (report)
+	{
+		delete[] report->contributions;
+		delete report;
+	}
+}
+
+fers_spectrum_occupancy_t* fers_calculate_spectrum_occupancy(const fers_context_t* context, const size_t num_points)
+{
+	last_error_message.clear();
+	if (!context)
+	{
+		last_error_message = "Invalid context passed to fers_calculate_spectrum_occupancy";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		const auto cpp_samples = simulation::calculateSpectrumOccupancy(*ctx->getWorld(), num_points);
+
+		auto* result = new fers_spectrum_occupancy_t();
+		result->count = cpp_samples.size();
+		result->samples = new fers_spectrum_sample_t[result->count];
+		for (size_t i = 0; i < result->count; ++i)
+		{
+			result->samples[i].time = cpp_samples[i].first;
+			result->samples[i].occupied_bandwidth = cpp_samples[i].second;
+		}
+		return result;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_spectrum_occupancy");
+		return nullptr;
+	}
+}
+
+void fers_free_spectrum_occupancy(fers_spectrum_occupancy_t* occupancy)
+{
+	if (occupancy)
+	{
+		delete[] occupancy->samples;
+		delete occupancy;
+	}
+}
+
+int fers_calculate_peak_emitter_count(const fers_context_t* context, const size_t num_points, double* out_time,
+									  size_t* out_count)
+{
+	last_error_message.clear();
+	if (!context || !out_time || !out_count)
+	{
+		last_error_message = "Invalid arguments passed to fers_calculate_peak_emitter_count";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		const auto [time, count] = simulation::calculatePeakEmitterCount(*ctx->getWorld(), num_points);
+		*out_time = time;
+		*out_count = count;
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_peak_emitter_count");
+		return 1;
+	}
+}
+
+fers_tdm_schedule_t* fers_calculate_tdm_schedule(const fers_context_t* context, const double guard_time)
+{
+	last_error_message.clear();
+	if (!context)
+	{
+		last_error_message = "Invalid context passed to fers_calculate_tdm_schedule";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		const auto cpp_slots = simulation::calculateTdmSchedule(*ctx->getWorld(), guard_time);
+
+		auto* result = new fers_tdm_schedule_t();
+		result->count = cpp_slots.size();
+		result->slots = new fers_tdm_slot_t[result->count];
+		for (size_t i = 0; i < result->count; ++i)
+		{
+			const auto& src = cpp_slots[i];
+			auto& dst = result->slots[i];
+			std::strncpy(dst.transmitter_name, src.transmitter_name.c_str(), sizeof(dst.transmitter_name) - 1);
+			dst.transmitter_name[sizeof(dst.transmitter_name) - 1] = '\0';
+			dst.slot_start = src.start;
+			dst.slot_end = src.end;
+		}
+		return result;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_tdm_schedule");
+		return nullptr;
+	}
+}
+
+void fers_free_tdm_schedule(fers_tdm_schedule_t* schedule)
+{
+	if (schedule)
+	{
+		delete[] schedule->slots;
+		delete schedule;
+	}
+}
+
+fers_pulse_collision_list_t* fers_calculate_pulse_collisions(const fers_context_t* context, const char* rx_name,
+															 const double window_start, const double window_end)
+{
+	last_error_message.clear();
+	if (!context || !rx_name)
+	{
+		last_error_message = "Invalid arguments passed to fers_calculate_pulse_collisions";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		const auto cpp_collisions =
+			simulation::calculatePulseCollisions(*ctx->getWorld(), rx_name, window_start, window_end);
+
+		auto* result = new fers_pulse_collision_list_t();
+		result->count = cpp_collisions.size();
+		result->collisions = new fers_pulse_collision_t[result->count];
+		for (size_t i = 0; i < result->count; ++i)
+		{
+			const auto& src = cpp_collisions[i];
+			auto& dst = result->collisions[i];
+			std::strncpy(dst.first_transmitter, src.first_transmitter.c_str(), sizeof(dst.first_transmitter) - 1);
+			dst.first_transmitter[sizeof(dst.first_transmitter) - 1] = '\0';
+			std::strncpy(dst.second_transmitter, src.second_transmitter.c_str(), sizeof(dst.second_transmitter) - 1);
+			dst.second_transmitter[sizeof(dst.second_transmitter) - 1] = '\0';
+			dst.first_pulse_index = src.first_pulse_index;
+			dst.second_pulse_index = src.second_pulse_index;
+			dst.overlap_start = src.overlap_start;
+			dst.overlap_end = src.overlap_end;
+		}
+		return result;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_pulse_collisions");
+		return nullptr;
+	}
+}
+
+void fers_free_pulse_collisions(fers_pulse_collision_list_t* list)
+{
+	if (list)
+	{
+		delete[] list->collisions;
+		delete list;
+	}
+}
+
+int fers_calculate_required_tx_power(const fers_context_t* context, const char* tx_name, const char* target_name,
+									 const char* rx_name, const double target_snr_db, const double time,
+									 double* out_power_dbw)
+{
+	last_error_message.clear();
+	if (!context || !tx_name || !target_name || !rx_name || !out_power_dbw)
+	{
+		last_error_message = "Invalid arguments passed to fers_calculate_required_tx_power";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		*out_power_dbw = simulation::calculateRequiredTransmitPower(*ctx->getWorld(), tx_name, target_name, rx_name,
+																	target_snr_db, time);
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_required_tx_power");
+		return 1;
+	}
+}
+
+int fers_calculate_max_detection_range(const fers_context_t* context, const char* monostatic_name,
+									   const double target_rcs, const double snr_threshold_db, double* out_range)
+{
+	last_error_message.clear();
+	if (!context || !monostatic_name || !out_range)
+	{
+		last_error_message = "Invalid arguments passed to fers_calculate_max_detection_range";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		*out_range =
+			simulation::calculateMaxDetectionRange(*ctx->getWorld(), monostatic_name, target_rcs, snr_threshold_db);
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_max_detection_range");
+		return 1;
+	}
+}
+
+int fers_calculate_phase_center_range(const fers_context_t* context, const char* tx_name, const char* target_name,
+									  const char* rx_name, const double time, double* out_range)
+{
+	last_error_message.clear();
+	if (!context || !tx_name || !target_name || !rx_name || !out_range)
+	{
+		last_error_message = "Invalid arguments passed to fers_calculate_phase_center_range";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		*out_range = simulation::calculatePhaseCenterRange(*ctx->getWorld(), tx_name, target_name, rx_name, time);
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_phase_center_range");
+		return 1;
+	}
+}
+
+int fers_calculate_noise_floor(const fers_context_t* context, const char* rx_name, double* out_noise_dbm)
+{
+	last_error_message.clear();
+	if (!context || !rx_name || !out_noise_dbm)
+	{
+		last_error_message = "Invalid arguments passed to fers_calculate_noise_floor";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		*out_noise_dbm = simulation::calculateNoiseFloor(*ctx->getWorld(), rx_name);
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_noise_floor");
+		return 1;
+	}
+}
+
+int fers_calculate_link_budget(const fers_context_t* context, const char* tx_name, const char* rx_name,
+							   const char* target_name, const double range, fers_link_budget_t* out_budget)
+{
+	last_error_message.clear();
+	if (!context || !tx_name || !rx_name || !target_name || !out_budget)
+	{
+		last_error_message = "Invalid arguments passed to fers_calculate_link_budget";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		const auto budget = simulation::calculateLinkBudget(*ctx->getWorld(), tx_name, rx_name, target_name, range);
+		out_budget->transmit_power_dbw = budget.transmit_power_dbw;
+		out_budget->tx_gain_dbi = budget.tx_gain_dbi;
+		out_budget->rx_gain_dbi = budget.rx_gain_dbi;
+		out_budget->rcs_dbsm = budget.rcs_dbsm;
+		out_budget->path_loss_db = budget.path_loss_db;
+		out_budget->received_power_dbw = budget.received_power_dbw;
+		out_budget->noise_power_dbw = budget.noise_power_dbw;
+		out_budget->snr_db = budget.snr_db;
+		out_budget->integration_gain_db = budget.integration_gain_db;
+		out_budget->integrated_snr_db = budget.integrated_snr_db;
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_link_budget");
+		return 1;
+	}
+}
+
+fers_phase_noise_series_t* fers_preview_receiver_phase_noise(const fers_context_t* context, const char* rx_name,
+															 const double duration)
+{
+	last_error_message.clear();
+	if (!context || !rx_name || !(duration > 0.0))
+	{
+		last_error_message = "Invalid arguments passed to fers_preview_receiver_phase_noise";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		const auto samples = timing::previewReceiverPhaseNoise(*ctx->getWorld(), rx_name, duration);
+
+		auto* result = new fers_phase_noise_series_t();
+		result->count = samples.size();
+		result->sample_rate = static_cast<double>(samples.size()) / duration;
+		result->samples = new double[result->count];
+		std::ranges::copy(samples, result->samples);
+		return result;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_preview_receiver_phase_noise");
+		return nullptr;
+	}
+}
+
+void fers_free_phase_noise_series(fers_phase_noise_series_t* series)
+{
+	if (series)
+	{
+		delete[] series->samples;
+		delete series;
+	}
+}
+
+fers_received_pulse_t* fers_preview_received_pulse(const fers_context_t* context, const char* tx_name,
+												   const char* target_name, const char* rx_name, const double time,
+												   const size_t num_samples)
+{
+	last_error_message.clear();
+	if (!context || !tx_name || !target_name || !rx_name || num_samples == 0)
+	{
+		last_error_message = "Invalid arguments passed to fers_preview_received_pulse";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		const auto samples =
+			simulation::previewReceivedPulse(*ctx->getWorld(), tx_name, target_name, rx_name, time, num_samples);
+
+		auto* result = new fers_received_pulse_t();
+		result->count = samples.size();
+		result->sample_rate = params::rate();
+		result->iq = new double[2 * result->count];
+		for (std::size_t i = 0; i < samples.size(); ++i)
+		{
+			result->iq[2 * i] = samples[i].real();
+			result->iq[2 * i + 1] = samples[i].imag();
+		}
+		return result;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_preview_received_pulse");
+		return nullptr;
+	}
+}
+
+void fers_free_received_pulse(fers_received_pulse_t* pulse)
+{
+	if (pulse)
+	{
+		delete[] pulse->iq;
+		delete pulse;
+	}
+}
+
+fers_beamforming_weights_t* fers_preview_beamforming_weights(const fers_context_t* context, const char* rx_name,
+															 const double azimuth_deg, const double elevation_deg)
+{
+	last_error_message.clear();
+	if (!context || !rx_name)
+	{
+		last_error_message = "Invalid arguments passed to fers_preview_beamforming_weights";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		const auto weights =
+			simulation::calculateSteeringWeights(*ctx->getWorld(), rx_name, azimuth_deg, elevation_deg);
+
+		auto* result = new fers_beamforming_weights_t();
+		result->count = weights.size();
+		result->iq = new double[2 * result->count];
+		for (std::size_t i = 0; i < weights.size(); ++i)
+		{
+			result->iq[2 * i] = weights[i].real();
+			result->iq[2 * i + 1] = weights[i].imag();
+		}
+		return result;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_preview_beamforming_weights");
+		return nullptr;
+	}
+}
+
+void fers_free_beamforming_weights(fers_beamforming_weights_t* weights)
+{
+	if (weights)
+	{
+		delete[] weights->iq;
+		delete weights;
+	}
+}
+
+int fers_calculate_min_prf_for_velocity(const fers_context_t* context, const char* tx_name, const char* target_name,
+										const char* rx_name, double* out_prf)
+{
+	last_error_message.clear();
+	if (!context || !tx_name || !target_name || !rx_name || !out_prf)
+	{
+		last_error_message = "Invalid arguments passed to fers_calculate_min_prf_for_velocity";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		*out_prf = simulation::calculateMinimumPrfForVelocity(*ctx->getWorld(), tx_name, target_name, rx_name);
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_min_prf_for_velocity");
+		return 1;
+	}
+}
+
+int fers_calculate_data_rate(const fers_context_t* context, fers_data_rate_t* out_rate)
+{
+	last_error_message.clear();
+	if (!context || !out_rate)
+	{
+		last_error_message = "Invalid arguments passed to fers_calculate_data_rate";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		const auto result = simulation::calculateDataRate(*ctx->getWorld());
+		*out_rate = {result.peak_bytes_per_second, result.average_bytes_per_second, result.active_receivers};
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_data_rate");
+		return 1;
+	}
+}
+
+int fers_calculate_required_integration_time(const fers_context_t* context, const char* rx_name,
+											 const double velocity_resolution, const double carrier,
+											 fers_integration_time_t* out_time)
+{
+	last_error_message.clear();
+	if (!context || !rx_name || !out_time)
+	{
+		last_error_message = "Invalid arguments passed to fers_calculate_required_integration_time";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		const auto result =
+			simulation::calculateRequiredIntegrationTime(*ctx->getWorld(), rx_name, velocity_resolution, carrier);
+		*out_time = {result.required_time, result.available_dwell, result.sufficient ? 1 : 0};
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_required_integration_time");
+		return 1;
+	}
+}
+
+int fers_calculate_range_crlb(const fers_context_t* context, const char* tx_name, const char* target_name,
+							  const char* rx_name, const double time, double* out_sigma_m)
+{
+	last_error_message.clear();
+	if (!context || !tx_name || !target_name || !rx_name || !out_sigma_m)
+	{
+		last_error_message = "Invalid arguments passed to fers_calculate_range_crlb";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		*out_sigma_m = simulation::calculateRangeCrlb(*ctx->getWorld(), tx_name, target_name, rx_name, time);
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_range_crlb");
+		return 1;
+	}
+}
+
+int fers_calculate_window_truncation_loss(const fers_context_t* context, const char* rx_name, const char* tx_name,
+										  double* out_loss_db)
+{
+	last_error_message.clear();
+	if (!context || !rx_name || !tx_name || !out_loss_db)
+	{
+		last_error_message = "Invalid arguments passed to fers_calculate_window_truncation_loss";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		*out_loss_db = simulation::calculateWindowTruncationLoss(*ctx->getWorld(), rx_name, tx_name);
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_window_truncation_loss");
+		return 1;
+	}
+}
+
+fers_range_interval_list_t* fers_calculate_blind_ranges(const fers_context_t* context, const char* rx_name,
+														const char* tx_name)
+{
+	last_error_message.clear();
+	if (!context || !rx_name || !tx_name)
+	{
+		last_error_message = "Invalid arguments passed to fers_calculate_blind_ranges";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		const auto cpp_intervals = simulation::calculateBlindRanges(*ctx->getWorld(), rx_name, tx_name);
+
+		auto* result = new fers_range_interval_list_t();
+		result->count = cpp_intervals.size();
+		result->intervals = new fers_range_interval_t[result->count];
+		for (size_t i = 0; i < result->count; ++i)
+		{
+			result->intervals[i].range_start = cpp_intervals[i].first;
+			result->intervals[i].range_end = cpp_intervals[i].second;
+		}
+		return result;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_blind_ranges");
+		return nullptr;
+	}
+}
+
+void fers_free_range_intervals(fers_range_interval_list_t* list)
+{
+	if (list)
+	{
+		delete[] list->intervals;
+		delete list;
+	}
+}
+
+static fers_range_interval_t* copy_range_intervals(const std::vector<std::pair<RealType, RealType>>& intervals)
+{
+	auto* result = new fers_range_interval_t[intervals.size()];
+	for (size_t i = 0; i < intervals.size(); ++i)
+	{
+		result[i] = {intervals[i].first, intervals[i].second};
+	}
+	return result;
+}
+
+static double* copy_speeds(const std::vector<RealType>& speeds)
+{
+	auto* result = new double[speeds.size()];
+	std::ranges::copy(speeds, result);
+	return result;
+}
+
+fers_blind_zone_coverage_t* fers_calculate_blind_zone_coverage(const fers_context_t* context, const char* rx_name,
+															   const char* tx_name, const double* prfs,
+															   const size_t prf_count)
+{
+	last_error_message.clear();
+	if (!context || !rx_name || !tx_name || (!prfs && prf_count > 0))
+	{
+		last_error_message = "Invalid arguments passed to fers_calculate_blind_zone_coverage";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		const std::vector<RealType> prf_set(prfs, prfs + prf_count);
+		const auto cpp_coverage = simulation::calculateBlindZoneCoverage(*ctx->getWorld(), rx_name, tx_name, prf_set);
+
+		auto* result = new fers_blind_zone_coverage_t();
+		result->prf_count = cpp_coverage.prfs.size();
+		result->prfs = new fers_prf_blind_zones_t[result->prf_count];
+		for (size_t i = 0; i < result->prf_count; ++i)
+		{
+			const auto& src = cpp_coverage.prfs[i];
+			auto& dst = result->prfs[i];
+			dst.prf = src.prf;
+			dst.blind_range_count = src.blind_ranges.size();
+			dst.blind_ranges = copy_range_intervals(src.blind_ranges);
+			dst.blind_speed_count = src.blind_speeds.size();
+			dst.blind_speeds = copy_speeds(src.blind_speeds);
+		}
+		result->unrecoverable_range_count = cpp_coverage.unrecoverable_ranges.size();
+		result->unrecoverable_ranges = copy_range_intervals(cpp_coverage.unrecoverable_ranges);
+		result->recoverable_range_count = cpp_coverage.recoverable_ranges.size();
+		result->recoverable_ranges = copy_range_intervals(cpp_coverage.recoverable_ranges);
+		result->unrecoverable_speed_count = cpp_coverage.unrecoverable_speeds.size();
+		result->unrecoverable_speeds = copy_speeds(cpp_coverage.unrecoverable_speeds);
+		result->recoverable_speed_count = cpp_coverage.recoverable_speeds.size();
+		result->recoverable_speeds = copy_speeds(cpp_coverage.recoverable_speeds);
+		result->max_range = cpp_coverage.max_range;
+		result->max_speed = cpp_coverage.max_speed;
+		return result;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_blind_zone_coverage");
+		return nullptr;
+	}
+}
+
+void fers_free_blind_zone_coverage(fers_blind_zone_coverage_t* coverage)
+{
+	if (coverage)
+	{
+		for (size_t i = 0; i < coverage->prf_count; ++i)
+		{
+			delete[] coverage->prfs[i].blind_ranges;
+			delete[] coverage->prfs[i].blind_speeds;
+		}
+		delete[] coverage->prfs;
+		delete[] coverage->unrecoverable_ranges;
+		delete[] coverage->recoverable_ranges;
+		delete[] coverage->unrecoverable_speeds;
+		delete[] coverage->recoverable_speeds;
+		delete coverage;
+	}
+}
+
+int fers_compare_trajectory(const fers_context_t* context, const char* platform_name,
+							const fers_motion_waypoint_t* reference, const size_t reference_count,
+							const size_t num_points, fers_trajectory_error_t* out_error)
+{
+	last_error_message.clear();
+	if (!context || !platform_name || !reference || reference_count == 0 || !out_error)
+	{
+		last_error_message = "Invalid arguments passed to fers_compare_trajectory";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		std::vector<math::Coord> coords(reference_count);
+		for (size_t i = 0; i < reference_count; ++i)
+		{
+			coords[i].t = reference[i].time;
+			coords[i].pos = {reference[i].x, reference[i].y, reference[i].z};
+		}
+
+		const auto result = simulation::compareTrajectory(*ctx->getWorld(), platform_name, coords, num_points);
+		*out_error = {result.rms_error, result.max_error, result.start_time, result.end_time, result.sample_count};
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_compare_trajectory");
+		return 1;
+	}
+}
+
+int fers_calculate_sar_resolution(const fers_context_t* context, const char* tx_name, const char* target_name,
+								  const char* rx_name, fers_sar_resolution_t* out_resolution)
+{
+	last_error_message.clear();
+	if (!context || !tx_name || !target_name || !rx_name || !out_resolution)
+	{
+		last_error_message = "Invalid arguments passed to fers_calculate_sar_resolution";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		const auto result = simulation::calculateSarResolution(*ctx->getWorld(), tx_name, target_name, rx_name);
+		*out_resolution = {result.synthetic_aperture, result.slant_range, result.cross_range_resolution,
+						   result.range_resolution, result.start_time, result.end_time};
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_sar_resolution");
+		return 1;
+	}
+}
+
+int fers_compute_resolution_cell(const fers_context_t* context, const char* tx_name, const char* target_name,
+								 const char* rx_name, const double time, fers_resolution_cell_t* out_cell)
+{
+	last_error_message.clear();
+	if (!context || !tx_name || !target_name || !rx_name || !out_cell)
+	{
+		last_error_message = "Invalid arguments passed to fers_compute_resolution_cell";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		const auto cell = simulation::computeResolutionCell(*ctx->getWorld(), tx_name, target_name, rx_name, time);
+		*out_cell = {cell.range_resolution, cell.azimuth_extent, cell.elevation_extent, cell.volume};
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_compute_resolution_cell");
+		return 1;
+	}
+}
+
+fers_polyline_list_t* fers_compute_isodoppler_contours(const fers_context_t* context, const char* tx_name,
+													   const char* rx_name, const double* doppler_values,
+													   const size_t value_count, const fers_ground_grid_t* grid,
+													   const double time)
+{
+	last_error_message.clear();
+	if (!context || !tx_name || !rx_name || !grid || (value_count > 0 && !doppler_values))
+	{
+		last_error_message = "Invalid arguments passed to fers_compute_isodoppler_contours";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		const std::vector<RealType> levels(doppler_values, doppler_values + value_count);
+		const auto contours =
+			simulation::computeIsodopplerContours(*ctx->getWorld(), tx_name, rx_name, levels, to_cpp_grid(*grid), time);
+		return to_c_polyline_list(contours);
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_compute_isodoppler_contours");
+		return nullptr;
+	}
+}
+
+fers_polyline_list_t* fers_compute_bistatic_footprint_overlap(const fers_context_t* context, const char* tx_name,
+															  const char* rx_name, const double ground_z,
+															  const double time)
+{
+	last_error_message.clear();
+	if (!context || !tx_name || !rx_name)
+	{
+		last_error_message = "Invalid arguments passed to fers_compute_bistatic_footprint_overlap";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		auto overlap = simulation::computeBistaticFootprintOverlap(*ctx->getWorld(), tx_name, rx_name, ground_z, time);
+		std::vector<simulation::Polyline> polygons;
+		if (!overlap.empty())
+		{
+			polygons.push_back(std::move(overlap));
+		}
+		return to_c_polyline_list(polygons);
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_compute_bistatic_footprint_overlap");
+		return nullptr;
+	}
+}
+
+void fers_free_polyline_list(fers_polyline_list_t* list)
+{
+	if (list)
+	{
+		for (size_t i = 0; i < list->count; ++i)
+		{
+			delete[] list->polylines[i].points;
+		}
+		delete[] list->polylines;
+		delete list;
+	}
+}
+
+int fers_compute_grazing_angle(const fers_context_t* context, const char* platform_name,
+							   const fers_ground_point_t* ground_point, const double ground_z, const double time,
+							   double* out_angle_deg)
+{
+	last_error_message.clear();
+	if (!context || !platform_name || !ground_point || !out_angle_deg)
+	{
+		last_error_message = "Invalid arguments passed to fers_compute_grazing_angle";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		*out_angle_deg = simulation::computeGrazingAngle(*ctx->getWorld(), platform_name,
+														 {ground_point->x, ground_point->y}, ground_z, time);
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_compute_grazing_angle");
+		return 1;
+	}
+}
+
+int fers_check_line_of_sight(const fers_context_t* context, const char* from_platform, const char* to_platform,
+							 const double time, int* out_visible)
+{
+	last_error_message.clear();
+	if (!context || !from_platform || !to_platform || !out_visible)
+	{
+		last_error_message = "Invalid arguments passed to fers_check_line_of_sight";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		*out_visible = simulation::hasLineOfSight(*ctx->getWorld(), from_platform, to_platform, time) ? 1 : 0;
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_check_line_of_sight");
+		return 1;
+	}
+}
+
+int fers_compute_clutter_cell_area(const fers_context_t* context, const char* rx_name, const char* tx_name,
+								   const double range, const double time, double* out_area)
+{
+	last_error_message.clear();
+	if (!context || !rx_name || !tx_name || !out_area)
+	{
+		last_error_message = "Invalid arguments passed to fers_compute_clutter_cell_area";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		*out_area = simulation::computeClutterCellArea(*ctx->getWorld(), rx_name, tx_name, range, time);
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_compute_clutter_cell_area");
+		return 1;
+	}
+}
+
+void fers_free_platform_tracks(fers_platform_track_list_t* list)
+{
+	if (list)
+	{
+		for (size_t i = 0; i < list->count; ++i)
+		{
+			delete[] list->tracks[i].points;
+		}
+		delete[] list->tracks;
+		delete list;
+	}
+}
+
+fers_platform_track_list_t* fers_get_platform_tracks(const fers_context_t* context, const size_t num_points)
+{
+	last_error_message.clear();
+	if (!context || num_points < 2)
+	{
+		last_error_message = "Invalid arguments passed to fers_get_platform_tracks";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	try
+	{
+		const auto* ctx = reinterpret_cast<const FersContext*>(context);
+		const auto& world = *ctx->getWorld();
+		const auto& platforms = world.getPlatforms();
+
+		const RealType start = params::startTime();
+		const RealType step = (params::endTime() - start) / static_cast<RealType>(num_points - 1);
+
+		// Owned by the deleter until complete, so a failure part-way through does not leak
+		std::unique_ptr<fers_platform_track_list_t, decltype(&fers_free_platform_tracks)> result(
+			new fers_platform_track_list_t(), &fers_free_platform_tracks);
+		if (!platforms.empty())
+		{
+			result->tracks = new fers_platform_track_t[platforms.size()]();
+			result->count = platforms.size();
+		}
+		for (size_t i = 0; i < result->count; ++i)
+		{
+			const auto* platform = platforms[i].get();
+			auto& track = result->tracks[i];
+			std::strncpy(track.platform_name, platform->getName().c_str(), sizeof(track.platform_name) - 1);
+			track.platform_name[sizeof(track.platform_name) - 1] = '\0';
+			track.kind = classify_platform(world, platform);
+			track.points = new fers_interpolated_point_t[num_points];
+			track.count = num_points;
+			for (size_t j = 0; j < num_points; ++j)
+			{
+				const RealType t = start + static_cast<RealType>(j) * step;
+				const auto pos = platform->getPosition(t);
+				const auto vel = platform->getVelocity(t);
+				track.points[j] = {pos.x, pos.y, pos.z, vel.x, vel.y, vel.z};
+			}
+		}
+		return result.release();
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_get_platform_tracks");
+		return nullptr;
+	}
+}
+
+int fers_compare_output(const char* actual_path, const char* reference_path, const double tolerance,
+						fers_output_comparison_t* out_comparison)
+{
+	last_error_message.clear();
+	if (!actual_path || !reference_path || !out_comparison || tolerance < 0)
+	{
+		last_error_message = "Invalid arguments passed to fers_compare_output";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const auto result = serial::compareOutputFiles(actual_path, reference_path);
+		*out_comparison = {result.max_difference, result.rms_difference, result.sample_count,
+						   result.max_difference <= tolerance ? 1 : 0};
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_compare_output");
+		return 1;
+	}
+}
+
+fers_blanked_interval_list_t* fers_read_blanked_intervals(const char* hdf5_path)
+{
+	last_error_message.clear();
+	if (!hdf5_path)
+	{
+		last_error_message = "Invalid arguments passed to fers_read_blanked_intervals";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	try
+	{
+		const auto cpp_intervals = serial::readBlankedIntervals(hdf5_path);
+
+		auto* result = new fers_blanked_interval_list_t();
+		result->count = cpp_intervals.size();
+		result->intervals = new fers_blanked_interval_t[result->count];
+		for (size_t i = 0; i < result->count; ++i)
+		{
+			result->intervals[i].start_time = cpp_intervals[i].first;
+			result->intervals[i].end_time = cpp_intervals[i].second;
+		}
+		return result;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_read_blanked_intervals");
+		return nullptr;
+	}
+}
+
+void fers_free_blanked_intervals(fers_blanked_interval_list_t* list)
+{
+	if (list)
+	{
+		delete[] list->intervals;
+		delete list;
+	}
+}
+
+int fers_calculate_detection_probabilities(const double snr_db, const double threshold_db, const size_t num_integrated,
+										   const fers_swerling_case_t swerling, double* out_pd, double* out_pfa)
+{
+	last_error_message.clear();
+	if (!out_pd || !out_pfa || num_integrated == 0 || !std::isfinite(snr_db) || !std::isfinite(threshold_db) ||
+		swerling < FERS_SWERLING_0 || swerling > FERS_SWERLING_4)
+	{
+		last_error_message = "Invalid arguments passed to fers_calculate_detection_probabilities";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		const RealType snr = std::pow(10.0, snr_db / 10.0);
+		const RealType threshold = std::pow(10.0, threshold_db / 10.0);
+		*out_pd = simulation::detectionProbability(snr, threshold, num_integrated,
+												   static_cast<simulation::SwerlingCase>(swerling));
+		*out_pfa = simulation::falseAlarmProbability(threshold, num_integrated);
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_detection_probabilities");
+		return 1;
+	}
+}
+
+int fers_calculate_pulse_compression_gain(const double bandwidth, const double duration, double* out_gain_db)
+{
+	last_error_message.clear();
+	if (!out_gain_db)
+	{
+		last_error_message = "Invalid arguments passed to fers_calculate_pulse_compression_gain";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		*out_gain_db = simulation::pulseCompressionGain(bandwidth, duration);
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_calculate_pulse_compression_gain");
+		return 1;
+	}
+}
+
+int fers_compute_waveform_spectrum(const fers_pulse_t* pulse, const size_t num_bins, double* out_freqs,
+								   double* out_power_db)
+{
+	last_error_message.clear();
+	if (!pulse || !out_freqs || !out_power_db || (pulse->modulation == FERS_PULSE_FILE && !pulse->filename))
+	{
+		last_error_message = "Invalid arguments passed to fers_compute_waveform_spectrum";
+		LOG(logging::Level::ERROR, last_error_message);
+		return -1;
+	}
+
+	try
+	{
+		std::vector<std::pair<RealType, RealType>> density;
+		if (pulse->modulation == FERS_PULSE_FILE)
+		{
+			// Power and carrier do not affect the normalized baseband spectrum
+			const auto wave = serial::loadWaveformFromFile("spectrum", pulse->filename, 1.0, 0.0);
+			density =
+				fers_signal::energySpectralDensity(wave->getSignal()->getSamples(), wave->getRate(), num_bins);
+		}
+		else
+		{
+			const RealType bandwidth = pulse->modulation == FERS_PULSE_LFM ? pulse->bandwidth : 0.0;
+			const RealType rate = fers_signal::pulseSampleRate(bandwidth, pulse->duration);
+			const auto samples = fers_signal::synthesizePulse(bandwidth, pulse->duration, rate);
+			density = fers_signal::energySpectralDensity(samples, rate, num_bins);
+		}
+		for (size_t i = 0; i < density.size(); ++i)
+		{
+			out_freqs[i] = density[i].first;
+			out_power_db[i] = density[i].second;
+		}
+		return 0;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_compute_waveform_spectrum");
+		return 1;
+	}
+}
+
+fers_rcs_preview_t* fers_load_rcs_file(const char* filename)
+{
+	last_error_message.clear();
+	if (!filename)
+	{
+		last_error_message = "Invalid arguments passed to fers_load_rcs_file";
+		LOG(logging::Level::ERROR, last_error_message);
+		return nullptr;
+	}
+
+	try
+	{
+		const auto [azimuth, elevation] = radar::loadRcsPattern(filename);
+
+		auto* result = new fers_rcs_preview_t();
+		result->count = azimuth.size() * elevation.size();
+		result->samples = new fers_rcs_sample_t[result->count];
+		result->min_rcs = std::numeric_limits<double>::infinity();
+		result->max_rcs = 0.0;
+		size_t i = 0;
+		for (const auto& [el_angle, el_rcs] : elevation)
+		{
+			for (const auto& [az_angle, az_rcs] : azimuth)
+			{
+				const RealType rcs = az_rcs * el_rcs;
+				result->samples[i++] = {az_angle * 180.0 / PI, el_angle * 180.0 / PI, rcs};
+				result->min_rcs = std::min(result->min_rcs, rcs);
+				result->max_rcs = std::max(result->max_rcs, rcs);
+			}
+		}
+		return result;
+	}
+	catch (const std::exception& e)
+	{
+		handle_api_exception(e, "fers_load_rcs_file");
+		return nullptr;
+	}
+}
+
+void fers_free_rcs_preview(fers_rcs_preview_t* preview)
+{
+	if (preview)
+	{
+		delete[] preview->samples;
+		delete preview;
+	}
+}
 }
@@ -49,3 +49,10 @@ constexpr RealType PI = std::numbers::pi_v<RealType>;
  * between two RealType values, ensuring precision control in numerical operations.
  */
 constexpr RealType EPSILON = std::numeric_limits<RealType>::epsilon();
+
+/**
+ * @brief Standard acceleration due to gravity in m/s^2.
+ *
+ * Used as the default gravity of ballistic motion paths.
+ */
+constexpr RealType STANDARD_GRAVITY = 9.80665;
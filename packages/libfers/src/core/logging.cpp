@@ -22,6 +22,38 @@ namespace logging
 {
 	Logger logger;
 
+	namespace
+	{
+		thread_local LogCapture* active_capture = nullptr;
+	}
+
+	LogCapture::LogCapture(const Level minimum) noexcept : _minimum(minimum), _previous(active_capture)
+	{
+		active_capture = this;
+	}
+
+	LogCapture::~LogCapture() { active_capture = _previous; }
+
+	bool LogCapture::captures(const Level level) noexcept
+	{
+		return active_capture != nullptr && level >= active_capture->_minimum;
+	}
+
+	void LogCapture::record(const Level level, const std::string& message) noexcept
+	{
+		if (captures(level))
+		{
+			try
+			{
+				active_capture->_messages.push_back(message);
+			}
+			catch (...)
+			{
+				// Dropping a captured message is preferable to terminating from a noexcept logger.
+			}
+		}
+	}
+
 	std::string Logger::getCurrentTimestamp() noexcept
 	{
 		const auto now = std::chrono::system_clock::now();
@@ -36,6 +68,8 @@ namespace logging
 
 	void Logger::log(const Level level, const std::string& message, const std::source_location& location) noexcept
 	{
+		LogCapture::record(level, message);
+
 		if (level >= _log_level)
 		{
 			std::scoped_lock lock(_log_mutex);
@@ -25,6 +25,7 @@
 #include <source_location>
 #include <string>
 #include <utility>
+#include <vector>
 
 namespace logging
 {
@@ -42,6 +43,60 @@ namespace logging
 		FATAL ///< Fatal level for severe error events.
 	};
 
+	/**
+	 * @class LogCapture
+	 * @brief Collects the messages logged on the current thread while it is in scope.
+	 *
+	 * Captured messages are recorded regardless of the logger's level, so a caller can
+	 * report parser warnings back to a user even when console output is quieter.
+	 * Captures nest; only the innermost capture on a thread receives messages.
+	 */
+	class LogCapture
+	{
+	public:
+		/**
+		 * @brief Starts capturing messages on the current thread.
+		 *
+		 * @param minimum The lowest level of message to capture.
+		 */
+		explicit LogCapture(Level minimum) noexcept;
+
+		~LogCapture();
+
+		LogCapture(const LogCapture&) = delete;
+		LogCapture& operator=(const LogCapture&) = delete;
+		LogCapture(LogCapture&&) = delete;
+		LogCapture& operator=(LogCapture&&) = delete;
+
+		/**
+		 * @brief Gets the captured messages in the order they were logged.
+		 *
+		 * @return The captured messages, without timestamps or source locations.
+		 */
+		[[nodiscard]] const std::vector<std::string>& getMessages() const noexcept { return _messages; }
+
+		/**
+		 * @brief Checks whether a message at the given level would be captured on this thread.
+		 *
+		 * @param level The log level.
+		 * @return True if an active capture accepts the level.
+		 */
+		[[nodiscard]] static bool captures(Level level) noexcept;
+
+		/**
+		 * @brief Records a message in the active capture on this thread, if any.
+		 *
+		 * @param level The log level.
+		 * @param message The message to record.
+		 */
+		static void record(Level level, const std::string& message) noexcept;
+
+	private:
+		Level _minimum; ///< Lowest level of message captured.
+		std::vector<std::string> _messages; ///< Captured messages.
+		LogCapture* _previous; ///< Capture that was active when this one started.
+	};
+
 	/**
 	 * @class Logger
 	 * @brief Thread-safe logger class for handling logging operations.
@@ -79,7 +134,7 @@ namespace logging
 		void log(const Level level, const std::source_location& location, const std::string& formatStr,
 				 Args&&... args) noexcept
 		{
-			if (level >= _log_level)
+			if (level >= _log_level || LogCapture::captures(level))
 			{
 				const std::string message = std::vformat(formatStr, std::make_format_args(args...));
 				log(level, message, location);
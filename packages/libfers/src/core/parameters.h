@@ -32,6 +32,46 @@ namespace params
 		ECEF ///< Earth-Centered, Earth-Fixed
 	};
 
+	/**
+	 * @struct ScenarioMetadata
+	 * @brief Authorship and provenance information for a scenario.
+	 *
+	 * Timestamps are stored as ISO 8601 strings and are not interpreted by the simulator.
+	 */
+	struct ScenarioMetadata
+	{
+		std::string author; ///< The scenario author.
+		std::string created; ///< Creation timestamp.
+		std::string modified; ///< Last modification timestamp.
+		std::string tool_version; ///< Version of the tool that last wrote the scenario.
+	};
+
+	/**
+	 * @struct MultipathSurface
+	 * @brief A flat reflecting surface, described as the plane `nx*x + ny*y + nz*z = d`.
+	 *
+	 * The normal is stored as written in the scenario and is not required to be unit length.
+	 */
+	struct MultipathSurface
+	{
+		RealType factor = 1; ///< Amplitude reflection factor of the surface.
+		RealType nx = 0; ///< X component of the surface normal.
+		RealType ny = 0; ///< Y component of the surface normal.
+		RealType nz = 1; ///< Z component of the surface normal.
+		RealType d = 0; ///< Plane offset along the normal.
+	};
+
+	/**
+	 * @class ExportOptions
+	 * @brief Selects the output files a simulation writes.
+	 */
+	struct ExportOptions
+	{
+		bool h5 = true; ///< Write each receiver's results to an HDF5 file.
+		bool timestamps = false; ///< Write the emission time of every transmitted pulse to a CSV file.
+		bool debug = false; ///< Write the delay, gain, phase and Doppler of every pulse path to a CSV file.
+	};
+
 	/**
 	 * @class Parameters
 	 * @brief Struct to hold simulation parameters.
@@ -60,7 +100,13 @@ namespace params
 		unsigned filter_length = 33; ///< Default render filter length.
 		unsigned render_threads = 1; ///< Number of worker threads to use for parallel tasks.
 		std::string simulation_name; ///< The name of the simulation, from the XML.
+		std::optional<ScenarioMetadata> metadata; ///< Optional scenario provenance metadata.
+		std::optional<std::string> ui_state; ///< Opaque UI view state (JSON text); never read by the engine.
+		std::optional<MultipathSurface> multipath; ///< Optional ground-bounce reflecting surface.
+		std::optional<std::string> default_antenna; ///< Antenna used by components that do not name one.
+		std::optional<std::string> default_timing; ///< Timing source used by components that do not name one.
 		unsigned oversample_ratio = 1; ///< Oversampling ratio.
+		ExportOptions export_options; ///< Additional output files to write.
 
 		/**
 		 * @brief Resets the parameters to their default-constructed state.
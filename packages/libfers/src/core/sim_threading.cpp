@@ -28,10 +28,14 @@
 #include <atomic>
 #include <chrono>
 #include <cmath>
+#include <filesystem>
 #include <format>
+#include <fstream>
 #include <functional>
 #include <memory>
+#include <string>
 #include <thread>
+#include <unordered_map>
 #include <utility>
 #include <vector>
 
@@ -41,6 +45,9 @@
 #include "radar/receiver.h"
 #include "radar/target.h"
 #include "radar/transmitter.h"
+#include "serial/hdf5_handler.h"
+#include "serial/json_serializer.h"
+#include "serial/response.h"
 #include "sim_events.h"
 #include "simulation/channel_model.h"
 #include "thread_pool.h"
@@ -52,10 +59,158 @@ using radar::Receiver;
 using radar::Target;
 using radar::Transmitter;
 
+namespace
+{
+	/// Emission time of a single transmitted pulse.
+	struct PulseRecord
+	{
+		const Transmitter* transmitter;
+		RealType time;
+	};
+
+	/**
+	 * @brief Writes the recorded pulse emission times to a CSV file.
+	 *
+	 * Pulses are numbered per transmitter in emission order, starting from zero.
+	 *
+	 * @param pulses The recorded pulses, in emission order.
+	 * @param filename The path of the CSV file to write.
+	 */
+	void writePulseTimestamps(const std::vector<PulseRecord>& pulses, const std::string& filename)
+	{
+		std::ofstream file(filename);
+		if (!file)
+		{
+			LOG(Level::ERROR, "Could not open pulse timestamp file '{}' for writing.", filename);
+			return;
+		}
+
+		file << "pulse_index,transmitter,emission_time\n";
+		std::unordered_map<const Transmitter*, std::size_t> next_index;
+		for (const auto& [transmitter, time] : pulses)
+		{
+			file << std::format("{},{},{:.12g}\n", next_index[transmitter]++, transmitter->getName(), time);
+		}
+		LOG(Level::INFO, "Wrote {} pulse timestamps to '{}'.", pulses.size(), filename);
+	}
+
+	/// Propagation of one transmitted pulse along one path to a receiver.
+	struct DebugRecord
+	{
+		std::size_t pulse_index;
+		const Transmitter* transmitter;
+		const Receiver* receiver;
+		const Target* target; ///< The reflecting target, or null for the direct path.
+		RealType emission_time;
+		RealType delay; ///< Propagation delay at the start of the pulse, in seconds.
+		RealType power; ///< Power gain relative to the transmitted power.
+		RealType phase; ///< Propagation phase at the start of the pulse, in radians.
+		RealType doppler; ///< Doppler shift over the pulse, in Hz.
+	};
+
+	/**
+	 * @brief Extracts the debug quantities of a pulse from its computed response.
+	 *
+	 * The Doppler shift is derived from the change in delay over the pulse,
+	 * `-carrier * d(delay)/dt`, so it is zero for single-point responses.
+	 */
+	DebugRecord makeDebugRecord(const std::size_t pulseIndex, const Transmitter* transmitter, const Receiver* receiver,
+								const Target* target, const RealType emissionTime, const RealType carrier,
+								const serial::Response& response)
+	{
+		DebugRecord record{pulseIndex, transmitter, receiver, target, emissionTime, 0, 0, 0, 0};
+		const auto& points = response.getPoints();
+		if (points.empty())
+		{
+			return record;
+		}
+		record.delay = points.front().delay;
+		record.power = points.front().power;
+		record.phase = points.front().phase;
+		if (const RealType span = points.back().time - points.front().time; span > 0)
+		{
+			record.doppler = -carrier * (points.back().delay - points.front().delay) / span;
+		}
+		return record;
+	}
+
+	/**
+	 * @brief Writes the recorded pulse propagation quantities to a CSV file.
+	 *
+	 * The target column is empty for the direct path.
+	 *
+	 * @param records The recorded paths, in emission order.
+	 * @param filename The path of the CSV file to write.
+	 */
+	void writeDebugTrace(const std::vector<DebugRecord>& records, const std::string& filename)
+	{
+		std::ofstream file(filename);
+		if (!file)
+		{
+			LOG(Level::ERROR, "Could not open debug trace file '{}' for writing.", filename);
+			return;
+		}
+
+		file << "pulse_index,transmitter,receiver,target,emission_time,delay,power_gain,phase,doppler\n";
+		for (const auto& record : records)
+		{
+			file << std::format("{},{},{},{},{:.12g},{:.12g},{:.12g},{:.12g},{:.12g}\n", record.pulse_index,
+								record.transmitter->getName(), record.receiver->getName(),
+								record.target ? record.target->getName() : std::string{}, record.emission_time,
+								record.delay, record.power, record.phase, record.doppler);
+		}
+		LOG(Level::INFO, "Wrote {} debug trace records to '{}'.", records.size(), filename);
+	}
+}
+
 namespace core
 {
+	std::string OutputLocation::receiverFile(const std::string_view receiver) const
+	{
+		const auto name = basename.empty() ? std::format("{}_results.h5", receiver)
+										   : std::format("{}_{}_results.h5", basename, receiver);
+		return (std::filesystem::path(directory) / name).string();
+	}
+
+	std::string OutputLocation::pulseTimestampsFile() const
+	{
+		const auto name = basename.empty() ? std::string(PULSE_TIMESTAMPS_FILENAME)
+										   : std::format("{}_{}", basename, PULSE_TIMESTAMPS_FILENAME);
+		return (std::filesystem::path(directory) / name).string();
+	}
+
+	std::string OutputLocation::debugTraceFile() const
+	{
+		const auto name = basename.empty() ? std::string(DEBUG_TRACE_FILENAME)
+										   : std::format("{}_{}", basename, DEBUG_TRACE_FILENAME);
+		return (std::filesystem::path(directory) / name).string();
+	}
+
+	std::vector<std::string> listOutputFiles(const World& world, const OutputLocation& output)
+	{
+		std::vector<std::string> files;
+		if (params::params.export_options.h5)
+		{
+			for (const auto& receiver_ptr : world.getReceivers())
+			{
+				files.push_back(output.receiverFile(receiver_ptr->getName()));
+			}
+		}
+		if (params::params.export_options.timestamps)
+		{
+			files.push_back(output.pulseTimestampsFile());
+		}
+		if (params::params.export_options.debug)
+		{
+			files.push_back(output.debugTraceFile());
+		}
+		return files;
+	}
+
 	void runEventDrivenSim(World* world, pool::ThreadPool& pool,
-						   const std::function<void(const std::string&, int, int)>& progress_callback)
+						   const ProgressReporter::Callback& progress_callback,
+						   const WindowStreamer::Callback& window_callback, const CancelCheck& cancel_requested,
+						   const OutputLocation& output)
 	{
 		auto& event_queue = world->getEventQueue();
 		auto& [t_current, active_cw_transmitters] = world->getSimulationState();
@@ -64,12 +219,18 @@ namespace core
 
 		// Create shared thread-safe reporter
 		auto reporter = std::make_shared<ProgressReporter>(progress_callback);
+		auto streamer = window_callback ? std::make_shared<WindowStreamer>(window_callback) : nullptr;
 
 		if (progress_callback)
 		{
-			reporter->report("Initializing event-driven simulation...", 0, 100);
+			reporter->report(ProgressPhase::INITIALIZING, "Initializing event-driven simulation...", 0, 100);
 		}
 
+		// Finalizers are handed an empty file name when the HDF5 export is disabled
+		const bool write_h5 = params::params.export_options.h5;
+		const auto results_file = [&](const Receiver* receiver)
+		{ return write_h5 ? output.receiverFile(receiver->getName()) : std::string{}; };
+
 		// Start dedicated finalizer threads for each pulsed receiver. This creates a
 		// one-thread-per-receiver pipeline for asynchronous data processing.
 		std::vector<std::jthread> finalizer_threads;
@@ -78,20 +239,38 @@ namespace core
 			if (receiver_ptr->getMode() == OperationMode::PULSED_MODE)
 			{
 				finalizer_threads.emplace_back(processing::runPulsedFinalizer, receiver_ptr.get(), &world->getTargets(),
-											   reporter);
+											   results_file(receiver_ptr.get()), reporter, streamer);
 			}
 		}
 
 		LOG(Level::INFO, "Starting unified event-driven simulation loop.");
 
+		// Emission times are only kept when the timestamp export is enabled
+		std::vector<PulseRecord> emitted_pulses;
+		const bool record_pulses = params::params.export_options.timestamps;
+		// Per-path propagation quantities are only kept when the debug export is enabled
+		std::vector<DebugRecord> debug_records;
+		const bool record_debug = params::params.export_options.debug;
+		// Pulses emitted so far by each transmitter, used to step through hop sequences.
+		std::unordered_map<const Transmitter*, std::size_t> pulse_counts;
+
 		// Throttling state for the main loop
 		auto last_report_time = std::chrono::steady_clock::now();
 		int last_reported_percent = -1;
 		const auto report_interval = std::chrono::milliseconds(100); // Max 10 updates/sec
 
+		bool cancelled = false;
+
 		// Main Simulation Loop
 		while (!event_queue.empty() && t_current <= end_time)
 		{
+			// Events are the pulse and window boundaries, so a cancellation takes effect at the next one
+			if (cancel_requested && cancel_requested())
+			{
+				cancelled = true;
+				break;
+			}
+
 			// Advance Clock to the next scheduled event
 			const auto [timestamp, event_type, source_object] = event_queue.top();
 			event_queue.pop();
@@ -148,6 +327,12 @@ namespace core
 				{
 					// NOLINTNEXTLINE(cppcoreguidelines-pro-type-static-cast-downcast): Type is guaranteed by event_type
 					auto* tx = static_cast<Transmitter*>(source_object);
+					if (record_pulses)
+					{
+						emitted_pulses.push_back({tx, t_event});
+					}
+					const std::size_t pulse_index = pulse_counts[tx]++;
+					const RealType carrier = tx->getPulseCarrier(pulse_index);
 					// For each pulse, calculate its interaction with every receiver and target.
 					for (const auto& rx_ptr : world->getReceivers())
 					{
@@ -155,8 +340,14 @@ namespace core
 						if (!rx_ptr->checkFlag(Receiver::RecvFlag::FLAG_NODIRECT))
 						{
 							if (auto response =
-									simulation::calculateResponse(tx, rx_ptr.get(), tx->getSignal(), t_event))
+									simulation::calculateResponse(tx, rx_ptr.get(), tx->getSignal(), t_event,
+																  nullptr, carrier))
 							{
+								if (record_debug)
+								{
+									debug_records.push_back(makeDebugRecord(pulse_index, tx, rx_ptr.get(), nullptr,
+																			t_event, carrier, *response));
+								}
 								if (rx_ptr->getMode() == OperationMode::PULSED_MODE)
 								{
 									rx_ptr->addResponseToInbox(std::move(response));
@@ -170,8 +361,14 @@ namespace core
 						for (const auto& target_ptr : world->getTargets())
 						{
 							if (auto response = simulation::calculateResponse(tx, rx_ptr.get(), tx->getSignal(),
-																			  t_event, target_ptr.get()))
+																			  t_event, target_ptr.get(), carrier))
 							{
+								if (record_debug)
+								{
+									debug_records.push_back(makeDebugRecord(pulse_index, tx, rx_ptr.get(),
+																			target_ptr.get(), t_event, carrier,
+																			*response));
+								}
 								if (rx_ptr->getMode() == OperationMode::PULSED_MODE)
 								{
 									rx_ptr->addResponseToInbox(std::move(response));
@@ -266,7 +463,8 @@ namespace core
 				if (const auto now = std::chrono::steady_clock::now();
 					progress != last_reported_percent || now - last_report_time >= report_interval)
 				{
-					reporter->report(std::format("Simulating... {:.2f}s / {:.2f}s", t_current, end_time), progress,
+					reporter->report(ProgressPhase::SIMULATING,
+									 std::format("Simulating... {:.2f}s / {:.2f}s", t_current, end_time), progress,
 									 100);
 					last_reported_percent = progress;
 					last_report_time = now;
@@ -274,9 +472,33 @@ namespace core
 			}
 		}
 
+		if (cancelled)
+		{
+			LOG(Level::INFO, "Simulation cancelled at t = {:.6f}s. Stopping finalizer threads...", t_current);
+			for (const auto& receiver_ptr : world->getReceivers())
+			{
+				if (receiver_ptr->getMode() == OperationMode::PULSED_MODE)
+				{
+					RenderingJob shutdown_job{.duration = -1.0};
+					receiver_ptr->enqueueFinalizerJob(std::move(shutdown_job));
+				}
+			}
+			finalizer_threads.clear();
+			throw SimulationCancelled();
+		}
+
 		// Shutdown Phase
 		LOG(Level::INFO, "Main simulation loop finished. Waiting for finalization tasks...");
-		reporter->report("Main simulation finished. Waiting for data export...", 100, 100);
+		reporter->report(ProgressPhase::EXPORTING, "Main simulation finished. Waiting for data export...", 100, 100);
+
+		if (record_pulses)
+		{
+			writePulseTimestamps(emitted_pulses, output.pulseTimestampsFile());
+		}
+		if (record_debug)
+		{
+			writeDebugTrace(debug_records, output.debugTraceFile());
+		}
 
 		// 1. Queue CW Finalization Tasks
 		// We finalize CW receivers here to ensure the full timeline (including all schedule periods) is exported once.
@@ -284,7 +506,8 @@ namespace core
 		{
 			if (receiver_ptr->getMode() == OperationMode::CW_MODE)
 			{
-				pool.enqueue(processing::finalizeCwReceiver, receiver_ptr.get(), &pool, reporter);
+				pool.enqueue(processing::finalizeCwReceiver, receiver_ptr.get(), &pool,
+							 results_file(receiver_ptr.get()), reporter, streamer);
 			}
 		}
 
@@ -301,12 +524,31 @@ namespace core
 		// Wait for any remaining CW finalization tasks in the main pool to complete.
 		pool.wait();
 
-		// The std::jthread finalizer threads are automatically joined here at the end of scope.
+		// Join the pulsed finalizer threads so every output file is complete.
+		finalizer_threads.clear();
 		LOG(Level::INFO, "All finalization tasks complete.");
 
+		// 3. Record the platform geometry in each output file so a run can be replayed as a scenario
+		if (write_h5)
+		{
+			const std::string geometry = serial::world_geometry_to_json(*world).dump();
+			for (const auto& receiver_ptr : world->getReceivers())
+			{
+				const auto hdf5_filename = output.receiverFile(receiver_ptr->getName());
+				try
+				{
+					serial::writeGeometryMetadata(hdf5_filename, geometry);
+				}
+				catch (const std::exception& e)
+				{
+					LOG(Level::ERROR, "Failed to record geometry in '{}': {}", hdf5_filename, e.what());
+				}
+			}
+		}
+
 		if (progress_callback)
 		{
-			reporter->report("Simulation complete", 100, 100);
+			reporter->report(ProgressPhase::COMPLETE, "Simulation complete", 100, 100);
 		}
 		LOG(Level::INFO, "Event-driven simulation loop finished.");
 	}
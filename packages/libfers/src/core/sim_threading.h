@@ -17,7 +17,13 @@
 
 #include <functional>
 #include <mutex>
+#include <span>
+#include <stdexcept>
 #include <string>
+#include <string_view>
+#include <vector>
+
+#include "core/config.h"
 
 namespace pool
 {
@@ -28,6 +34,18 @@ namespace core
 {
 	class World;
 
+	/**
+	 * @brief The stage of a simulation run that a progress update refers to.
+	 */
+	enum class ProgressPhase
+	{
+		INITIALIZING, ///< Setting up receivers and finalizer threads.
+		SIMULATING, ///< Advancing the event-driven simulation loop.
+		RENDERING, ///< Rendering a receiver's signal, interference and noise.
+		EXPORTING, ///< Writing receiver output to HDF5.
+		COMPLETE ///< The run has finished and all output is written.
+	};
+
 	/**
 	 * @class ProgressReporter
 	 * @brief A thread-safe wrapper for the simulation progress callback.
@@ -37,16 +55,47 @@ namespace core
 	class ProgressReporter
 	{
 	public:
-		using Callback = std::function<void(const std::string&, int, int)>;
+		/// Receives the phase, a message, and the current and total step counts (total is 0 if unknown).
+		using Callback = std::function<void(ProgressPhase, const std::string&, int, int)>;
 
 		explicit ProgressReporter(Callback cb) : _callback(std::move(cb)) {}
 
-		void report(const std::string& msg, int current, int total)
+		void report(const ProgressPhase phase, const std::string& msg, int current, int total)
+		{
+			if (_callback)
+			{
+				std::lock_guard<std::mutex> lock(_mutex);
+				_callback(phase, msg, current, total);
+			}
+		}
+
+	private:
+		std::mutex _mutex;
+		Callback _callback;
+	};
+
+	/**
+	 * @class WindowStreamer
+	 * @brief A thread-safe wrapper for the callback that receives finished receive windows.
+	 *
+	 * Finalizer threads publish each window after quantization, alongside writing it to
+	 * HDF5, so that a consumer can display the output while the simulation is running.
+	 */
+	class WindowStreamer
+	{
+	public:
+		/// Receives the receiver name, window start time, fullscale factor and I/Q samples.
+		using Callback = std::function<void(const std::string&, RealType, RealType, std::span<const ComplexType>)>;
+
+		explicit WindowStreamer(Callback cb) : _callback(std::move(cb)) {}
+
+		void publish(const std::string& receiver, const RealType startTime, const RealType fullscale,
+					 const std::span<const ComplexType> samples)
 		{
 			if (_callback)
 			{
 				std::lock_guard<std::mutex> lock(_mutex);
-				_callback(msg, current, total);
+				_callback(receiver, startTime, fullscale, samples);
 			}
 		}
 
@@ -55,6 +104,57 @@ namespace core
 		Callback _callback;
 	};
 
+	/**
+	 * @class SimulationCancelled
+	 * @brief Thrown by `runEventDrivenSim` when a cancellation request stops the run.
+	 */
+	class SimulationCancelled final : public std::runtime_error
+	{
+	public:
+		SimulationCancelled() : std::runtime_error("Simulation cancelled.") {}
+	};
+
+	/// Returns `true` once the caller has asked for the running simulation to stop.
+	using CancelCheck = std::function<bool()>;
+
+	/// Name of the pulse emission time CSV written when the timestamp export is enabled.
+	inline constexpr std::string_view PULSE_TIMESTAMPS_FILENAME = "pulse_timestamps.csv";
+
+	/// Name of the per-pulse propagation CSV written when the debug export is enabled.
+	inline constexpr std::string_view DEBUG_TRACE_FILENAME = "debug_trace.csv";
+
+	/**
+	 * @struct OutputLocation
+	 * @brief Where a simulation run writes its output files.
+	 *
+	 * With the defaults, receivers write `<receiver>_results.h5` to the working directory.
+	 * A basename is prepended to every file name as `<basename>_`.
+	 */
+	struct OutputLocation
+	{
+		std::string directory; ///< The output directory, or empty for the working directory.
+		std::string basename; ///< The file name prefix, or empty for none.
+
+		/// Returns the path of the HDF5 file written for a receiver.
+		[[nodiscard]] std::string receiverFile(std::string_view receiver) const;
+
+		/// Returns the path of the pulse emission time CSV.
+		[[nodiscard]] std::string pulseTimestampsFile() const;
+
+		/// Returns the path of the debug trace CSV.
+		[[nodiscard]] std::string debugTraceFile() const;
+	};
+
+	/**
+	 * @brief Lists the files a simulation run of the world writes.
+	 *
+	 * @param world The simulation world.
+	 * @param output Where the run writes its output.
+	 * @return The HDF5 file of every receiver if the HDF5 export is enabled, followed by the
+	 *         pulse timestamp CSV and the debug trace CSV if those exports are enabled.
+	 */
+	[[nodiscard]] std::vector<std::string> listOutputFiles(const World& world, const OutputLocation& output);
+
 	/**
 	 * @brief Runs the unified, event-driven radar simulation.
 	 *
@@ -66,7 +166,21 @@ namespace core
 	 * @param world A pointer to the simulation world containing all entities and state.
 	 * @param pool A reference to the thread pool for executing tasks.
 	 * @param progress_callback An optional callback function for reporting progress.
+	 * @param window_callback An optional callback that receives each finished receive window.
+	 * @param cancel_requested An optional check polled before each event is processed.
+	 * @param output Where the receiver files and pulse timestamps are written.
+	 * @throws SimulationCancelled If `cancel_requested` returns `true`. Receive windows that
+	 *         were already complete are still written by the pulsed finalizers; CW receivers,
+	 *         pulse timestamps and geometry metadata are not written.
+	 *
+	 * When `params::params.export_options.timestamps` is set, the emission time of every
+	 * transmitted pulse is written to `output.pulseTimestampsFile()`. When
+	 * `params::params.export_options.debug` is set, the delay, power gain, phase and Doppler
+	 * shift of every pulsed path (direct and via each target) are written to
+	 * `output.debugTraceFile()`. CW-to-CW paths are not traced.
 	 */
 	void runEventDrivenSim(World* world, pool::ThreadPool& pool,
-						   const std::function<void(const std::string&, int, int)>& progress_callback);
+						   const ProgressReporter::Callback& progress_callback,
+						   const WindowStreamer::Callback& window_callback = {},
+						   const CancelCheck& cancel_requested = {}, const OutputLocation& output = {});
 }
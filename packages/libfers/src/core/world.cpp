@@ -12,6 +12,7 @@
 
 #include "world.h"
 
+#include <algorithm>
 #include <iomanip>
 #include <sstream>
 
@@ -82,6 +83,30 @@ namespace core
 		return _timings.contains(name) ? _timings[name].get() : nullptr;
 	}
 
+	Platform* World::findPlatform(const std::string& name) const noexcept
+	{
+		const auto it = std::ranges::find_if(_platforms, [&name](const auto& p) { return p->getName() == name; });
+		return it != _platforms.end() ? it->get() : nullptr;
+	}
+
+	Transmitter* World::findTransmitter(const std::string& name) const noexcept
+	{
+		const auto it = std::ranges::find_if(_transmitters, [&name](const auto& t) { return t->getName() == name; });
+		return it != _transmitters.end() ? it->get() : nullptr;
+	}
+
+	Receiver* World::findReceiver(const std::string& name) const noexcept
+	{
+		const auto it = std::ranges::find_if(_receivers, [&name](const auto& r) { return r->getName() == name; });
+		return it != _receivers.end() ? it->get() : nullptr;
+	}
+
+	Target* World::findTarget(const std::string& name) const noexcept
+	{
+		const auto it = std::ranges::find_if(_targets, [&name](const auto& t) { return t->getName() == name; });
+		return it != _targets.end() ? it->get() : nullptr;
+	}
+
 	void World::clear() noexcept
 	{
 		_platforms.clear();
@@ -91,6 +116,7 @@ namespace core
 		_waveforms.clear();
 		_antennas.clear();
 		_timings.clear();
+		_terrain.reset();
 		_event_queue = {};
 		_simulation_state = {};
 	}
@@ -21,6 +21,7 @@
 #include "antenna/antenna_factory.h"
 #include "core/sim_events.h"
 #include "core/simulation_state.h"
+#include "math/terrain.h"
 #include "radar/platform.h"
 #include "radar/receiver.h"
 #include "radar/target.h"
@@ -125,6 +126,38 @@ namespace core
 		 */
 		[[nodiscard]] timing::PrototypeTiming* findTiming(const std::string& name);
 
+		/**
+		 * @brief Finds a platform by name.
+		 *
+		 * @param name The name of the platform to find.
+		 * @return A pointer to the Platform if found, or nullptr if not found.
+		 */
+		[[nodiscard]] radar::Platform* findPlatform(const std::string& name) const noexcept;
+
+		/**
+		 * @brief Finds a transmitter by name.
+		 *
+		 * @param name The name of the transmitter to find.
+		 * @return A pointer to the Transmitter if found, or nullptr if not found.
+		 */
+		[[nodiscard]] radar::Transmitter* findTransmitter(const std::string& name) const noexcept;
+
+		/**
+		 * @brief Finds a receiver by name.
+		 *
+		 * @param name The name of the receiver to find.
+		 * @return A pointer to the Receiver if found, or nullptr if not found.
+		 */
+		[[nodiscard]] radar::Receiver* findReceiver(const std::string& name) const noexcept;
+
+		/**
+		 * @brief Finds a target by name.
+		 *
+		 * @param name The name of the target to find.
+		 * @return A pointer to the Target if found, or nullptr if not found.
+		 */
+		[[nodiscard]] radar::Target* findTarget(const std::string& name) const noexcept;
+
 		/**
 		 * @brief Retrieves the list of platforms.
 		 *
@@ -195,6 +228,20 @@ namespace core
 			return _timings;
 		}
 
+		/**
+		 * @brief Sets the terrain height field that masks line-of-sight paths.
+		 *
+		 * @param terrain A unique pointer to the terrain, or null to remove it.
+		 */
+		void setTerrain(std::unique_ptr<math::Terrain> terrain) noexcept { _terrain = std::move(terrain); }
+
+		/**
+		 * @brief Retrieves the terrain height field.
+		 *
+		 * @return A pointer to the terrain, or nullptr if the scenario has none.
+		 */
+		[[nodiscard]] const math::Terrain* getTerrain() const noexcept { return _terrain.get(); }
+
 		/**
 		 * @brief Clears all objects and assets from the simulation world.
 		 */
@@ -242,6 +289,8 @@ namespace core
 
 		std::unordered_map<std::string, std::unique_ptr<timing::PrototypeTiming>> _timings;
 
+		std::unique_ptr<math::Terrain> _terrain;
+
 		std::priority_queue<Event, std::vector<Event>, EventComparator> _event_queue;
 
 		SimulationState _simulation_state;
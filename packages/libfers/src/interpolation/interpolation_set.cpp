@@ -22,7 +22,7 @@
 namespace interp
 {
 	template <RealConcept T>
-	std::optional<T> InterpSetData::value(T x) const noexcept
+	std::optional<T> InterpSetData::value(T x, const InterpMode mode) const noexcept
 	{
 		if (_data.empty())
 		{
@@ -49,7 +49,26 @@ namespace interp
 		const auto [x1, y1] = *prev;
 		const auto [x2, y2] = *iter;
 
-		return static_cast<T>(y2 * (x - x1) / (x2 - x1) + y1 * (x2 - x) / (x2 - x1));
+		switch (mode)
+		{
+		case InterpMode::NEAREST:
+			return static_cast<T>(x - x1 < x2 - x ? y1 : y2);
+		case InterpMode::CUBIC:
+			{
+				// Catmull-Rom spline, with the end slopes estimated one-sided
+				const RealType y0 = prev == _data.begin() ? 2 * y1 - y2 : std::prev(prev)->second;
+				const auto next = std::next(iter);
+				const RealType y3 = next == _data.end() ? 2 * y2 - y1 : next->second;
+				const RealType t = (x - x1) / (x2 - x1);
+				return static_cast<T>(
+					0.5 *
+					(2 * y1 + (y2 - y0) * t + (2 * y0 - 5 * y1 + 4 * y2 - y3) * t * t +
+					 (3 * y1 - y0 - 3 * y2 + y3) * t * t * t));
+			}
+		case InterpMode::LINEAR:
+		default:
+			return static_cast<T>(y2 * (x - x1) / (x2 - x1) + y1 * (x2 - x) / (x2 - x1));
+		}
 	}
 
 	double InterpSetData::max() const noexcept
@@ -74,11 +93,11 @@ namespace interp
 	}
 
 	// Explicit instantiations for double and float
-	template std::optional<double> InterpSetData::value<double>(double) const noexcept;
+	template std::optional<double> InterpSetData::value<double>(double, InterpMode) const noexcept;
 
 	template void InterpSetData::divide<double>(double);
 
-	template std::optional<float> InterpSetData::value<float>(float) const noexcept;
+	template std::optional<float> InterpSetData::value<float>(float, InterpMode) const noexcept;
 
 	template void InterpSetData::divide<float>(float);
 }
@@ -23,6 +23,17 @@ namespace interp
 	template <typename T>
 	concept RealConcept = std::is_arithmetic_v<T>;
 
+	/**
+	 * @enum InterpMode
+	 * @brief Selects how values between samples are estimated.
+	 */
+	enum class InterpMode
+	{
+		NEAREST, ///< Value of the closest sample; stepped output.
+		LINEAR, ///< Straight line between neighbouring samples; continuous but kinked.
+		CUBIC ///< Catmull-Rom spline through neighbouring samples; smooth, but may overshoot.
+	};
+
 	/**
 	 * @class InterpSetData
 	 * @brief Class for managing a set of data and performing interpolation.
@@ -48,10 +59,11 @@ namespace interp
 		 *
 		 * @tparam T The type of the x-coordinate (must be an arithmetic type).
 		 * @param x The x-coordinate at which to interpolate the value.
+		 * @param mode The interpolation used between samples. Values beyond the end samples are held.
 		 * @return The interpolated value at the given x-coordinate, or std::nullopt if the dataset is empty.
 		 */
 		template <RealConcept T>
-		[[nodiscard]] std::optional<T> value(T x) const noexcept;
+		[[nodiscard]] std::optional<T> value(T x, InterpMode mode = InterpMode::LINEAR) const noexcept;
 
 		/**
 		 * @brief Retrieves the maximum absolute value in the interpolation set.
@@ -111,12 +123,13 @@ namespace interp
 		 *
 		 * @tparam T The type of the x-coordinate (must be an arithmetic type).
 		 * @param x The x-coordinate at which to interpolate the value.
+		 * @param mode The interpolation used between samples.
 		 * @return The interpolated value at the given x-coordinate, or std::nullopt if the dataset is empty.
 		 */
 		template <RealConcept T>
-		[[nodiscard]] std::optional<T> getValueAt(T x) const noexcept
+		[[nodiscard]] std::optional<T> getValueAt(T x, const InterpMode mode = InterpMode::LINEAR) const noexcept
 		{
-			return _data->value(x);
+			return _data->value(x, mode);
 		}
 
 		/**
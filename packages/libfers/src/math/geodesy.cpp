@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+/**
+ * @file geodesy.cpp
+ * @brief Implementation of the geodetic, ECEF and local ENU coordinate conversions.
+ */
+
+#include "math/geodesy.h"
+
+#include <GeographicLib/Geocentric.hpp>
+#include <GeographicLib/LocalCartesian.hpp>
+
+namespace math
+{
+	Vec3 ecefToLocal(const Vec3& ecef, const RealType latitude, const RealType longitude, const RealType altitude)
+	{
+		const GeographicLib::LocalCartesian proj(latitude, longitude, altitude, GeographicLib::Geocentric::WGS84());
+		RealType lat = 0;
+		RealType lon = 0;
+		RealType alt = 0;
+		GeographicLib::Geocentric::WGS84().Reverse(ecef.x, ecef.y, ecef.z, lat, lon, alt);
+		Vec3 local;
+		proj.Forward(lat, lon, alt, local.x, local.y, local.z);
+		return local;
+	}
+
+	Vec3 localToEcef(const Vec3& local, const RealType latitude, const RealType longitude, const RealType altitude)
+	{
+		const GeographicLib::LocalCartesian proj(latitude, longitude, altitude, GeographicLib::Geocentric::WGS84());
+		RealType lat = 0;
+		RealType lon = 0;
+		RealType alt = 0;
+		proj.Reverse(local.x, local.y, local.z, lat, lon, alt);
+		Vec3 ecef;
+		GeographicLib::Geocentric::WGS84().Forward(lat, lon, alt, ecef.x, ecef.y, ecef.z);
+		return ecef;
+	}
+
+	Vec3 geodeticToLocal(const GeodeticPosition& position, const GeodeticPosition& origin)
+	{
+		const GeographicLib::LocalCartesian proj(origin.latitude, origin.longitude, origin.altitude,
+												 GeographicLib::Geocentric::WGS84());
+		Vec3 local;
+		proj.Forward(position.latitude, position.longitude, position.altitude, local.x, local.y, local.z);
+		return local;
+	}
+
+	GeodeticPosition localToGeodetic(const Vec3& local, const GeodeticPosition& origin)
+	{
+		const GeographicLib::LocalCartesian proj(origin.latitude, origin.longitude, origin.altitude,
+												 GeographicLib::Geocentric::WGS84());
+		GeodeticPosition position{};
+		proj.Reverse(local.x, local.y, local.z, position.latitude, position.longitude, position.altitude);
+		return position;
+	}
+}
@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+/**
+ * @file geodesy.h
+ * @brief Conversions between geodetic, Earth-Centered, Earth-Fixed and local East-North-Up coordinates.
+ *
+ * All conversions use the WGS84 ellipsoid. The local frame is the tangent plane at a geodetic
+ * origin, with x pointing East, y North and z Up.
+ */
+
+#pragma once
+
+#include "core/config.h"
+#include "math/geometry_ops.h"
+
+namespace math
+{
+	/**
+	 * @struct GeodeticPosition
+	 * @brief A WGS84 geodetic position.
+	 */
+	struct GeodeticPosition
+	{
+		RealType latitude; ///< Latitude in decimal degrees.
+		RealType longitude; ///< Longitude in decimal degrees.
+		RealType altitude; ///< Altitude above the ellipsoid in meters.
+	};
+
+	/**
+	 * @brief Converts an ECEF position to the local ENU frame of a geodetic origin.
+	 *
+	 * @param ecef The ECEF position in meters.
+	 * @param latitude The origin latitude in decimal degrees.
+	 * @param longitude The origin longitude in decimal degrees.
+	 * @param altitude The origin altitude above the ellipsoid in meters.
+	 * @return The position in the local ENU frame in meters.
+	 */
+	[[nodiscard]] Vec3 ecefToLocal(const Vec3& ecef, RealType latitude, RealType longitude, RealType altitude);
+
+	/**
+	 * @brief Converts a position in the local ENU frame of a geodetic origin to ECEF.
+	 *
+	 * @param local The position in the local ENU frame in meters.
+	 * @param latitude The origin latitude in decimal degrees.
+	 * @param longitude The origin longitude in decimal degrees.
+	 * @param altitude The origin altitude above the ellipsoid in meters.
+	 * @return The ECEF position in meters.
+	 */
+	[[nodiscard]] Vec3 localToEcef(const Vec3& local, RealType latitude, RealType longitude, RealType altitude);
+
+	/**
+	 * @brief Converts a geodetic position to the local ENU frame of a geodetic origin.
+	 *
+	 * @param position The geodetic position to convert.
+	 * @param origin The geodetic origin of the local frame.
+	 * @return The position in the local ENU frame in meters.
+	 */
+	[[nodiscard]] Vec3 geodeticToLocal(const GeodeticPosition& position, const GeodeticPosition& origin);
+
+	/**
+	 * @brief Converts a position in the local ENU frame of a geodetic origin to geodetic coordinates.
+	 *
+	 * This is the exact inverse of `geodeticToLocal`, and is also used to place ENU scenarios in KML exports.
+	 *
+	 * @param local The position in the local ENU frame in meters.
+	 * @param origin The geodetic origin of the local frame.
+	 * @return The geodetic position.
+	 */
+	[[nodiscard]] GeodeticPosition localToGeodetic(const Vec3& local, const GeodeticPosition& origin);
+}
@@ -206,12 +206,17 @@ namespace math
 	 */
 	inline RealType dotProduct(const Vec3& a, const Vec3& b) noexcept { return a.x * b.x + a.y * b.y + a.z * b.z; }
 
-	// Cross product
-	// Note: This function is not used in the codebase
-	/*inline Vec3 crossProduct(const Vec3& a, const Vec3& b) noexcept
+	/**
+	 * @brief Computes the cross product of two Vec3 vectors.
+	 *
+	 * @param a The first vector.
+	 * @param b The second vector.
+	 * @return The cross product `a x b`.
+	 */
+	inline Vec3 crossProduct(const Vec3& a, const Vec3& b) noexcept
 	{
 		return {a.y * b.z - a.z * b.y, a.z * b.x - a.x * b.z, a.x * b.y - a.y * b.x};
-	}*/
+	}
 
 	/// Multiplies two Vec3 vectors component-wise
 	inline Vec3 operator*(const Vec3& a, const Vec3& b) noexcept { return {a.x * b.x, a.y * b.y, a.z * b.z}; }
@@ -13,6 +13,9 @@
 #include "path.h"
 
 #include <algorithm>
+#include <cmath>
+#include <memory>
+#include <utility>
 
 #include "coord.h"
 #include "core/logging.h"
@@ -23,15 +26,103 @@ using logging::Level;
 
 namespace math
 {
-	void Path::addCoord(const Coord& coord) noexcept
+	namespace
+	{
+		/// Finds the index of the right-hand waypoint of the cubic segment used to evaluate time t.
+		std::size_t cubicSegment(const std::vector<Coord>& coords, const RealType t)
+		{
+			const auto xrp = std::ranges::upper_bound(coords, t, {}, &Coord::t);
+			if (xrp == coords.begin())
+			{
+				return 1;
+			}
+			if (xrp == coords.end())
+			{
+				return coords.size() - 1;
+			}
+			return std::distance(coords.begin(), xrp);
+		}
+
+		/// Differentiates the natural cubic spline defined by coords and their second derivatives dd.
+		Vec3 splineVelocity(const RealType t, const std::vector<Coord>& coords, const std::vector<Coord>& dd)
+		{
+			const std::size_t xri = cubicSegment(coords, t);
+			if (xri < 1 || xri >= coords.size())
+			{
+				return {0, 0, 0};
+			}
+
+			const std::size_t xli = xri - 1;
+
+			const RealType h = coords[xri].t - coords[xli].t;
+			if (h <= EPSILON)
+			{
+				return {0, 0, 0};
+			}
+
+			const RealType a = (coords[xri].t - t) / h;
+			const RealType b = (t - coords[xli].t) / h;
+
+			// Derivative coefficients
+			// da/dt = -1/h
+			// db/dt = 1/h
+			// dc/dt = -h/6 * (3a^2 - 1)
+			// dd/dt = h/6 * (3b^2 - 1)
+
+			const RealType da = -1.0 / h;
+			const RealType db = 1.0 / h;
+			const RealType dc = -h / 6.0 * (3.0 * a * a - 1.0);
+			const RealType dd_coeff = h / 6.0 * (3.0 * b * b - 1.0);
+
+			return coords[xli].pos * da + coords[xri].pos * db + dd[xli].pos * dc + dd[xri].pos * dd_coeff;
+		}
+
+		/// Evaluates the cubic Hermite spline through coords with the given tangents, returning position and
+		/// velocity. Times outside the path are clamped to its end points.
+		std::pair<Vec3, Vec3> hermite(const RealType t, const std::vector<Coord>& coords,
+									  const std::vector<Vec3>& tangents)
+		{
+			const RealType tc = std::clamp(t, coords.front().t, coords.back().t);
+			const std::size_t xri = cubicSegment(coords, tc);
+			const std::size_t xli = xri - 1;
+
+			const RealType h = coords[xri].t - coords[xli].t;
+			if (h <= EPSILON)
+			{
+				return {coords[xri].pos, tangents[xri]};
+			}
+
+			const RealType s = (tc - coords[xli].t) / h;
+			const RealType s2 = s * s;
+			const RealType s3 = s2 * s;
+
+			const Vec3& p0 = coords[xli].pos;
+			const Vec3& p1 = coords[xri].pos;
+			const Vec3& v0 = tangents[xli];
+			const Vec3& v1 = tangents[xri];
+
+			const Vec3 pos = p0 * (2 * s3 - 3 * s2 + 1) + v0 * ((s3 - 2 * s2 + s) * h) + p1 * (3 * s2 - 2 * s3) +
+				v1 * ((s3 - s2) * h);
+			const Vec3 vel = (p0 - p1) * ((6 * s2 - 6 * s) / h) + v0 * (3 * s2 - 4 * s + 1) + v1 * (3 * s2 - 2 * s);
+			return {pos, vel};
+		}
+	}
+
+	void Path::addCoord(const Coord& coord, const std::optional<Vec3>& velocity) noexcept
 	{
 		auto comp = [](const Coord& a, const Coord& b) { return a.t < b.t; };
 
 		const auto iter = std::ranges::lower_bound(_coords, coord, comp);
+		_velocities.insert(_velocities.begin() + std::distance(_coords.begin(), iter), velocity);
 		_coords.insert(iter, coord);
 		_final = false;
 	}
 
+	bool Path::hasWaypointVelocities() const noexcept
+	{
+		return std::ranges::any_of(_velocities, [](const auto& velocity) { return velocity.has_value(); });
+	}
+
 	Vec3 Path::getPosition(const RealType t) const
 	{
 		if (!_final)
@@ -50,8 +141,20 @@ namespace math
 			getPositionLinear(t, coord, _coords);
 			break;
 		case InterpType::INTERP_CUBIC:
+			if (!_tangents.empty())
+			{
+				return hermite(t, _coords, _tangents).first;
+			}
 			getPositionCubic(t, coord, _coords, _dd);
 			break;
+		case InterpType::INTERP_BALLISTIC:
+			{
+				const auto& launch = _coords.front();
+				const RealType flight = std::clamp(t, launch.t, _impact_time) - launch.t;
+				coord.pos = launch.pos + _launch_velocity * flight;
+				coord.pos.z = std::max(coord.pos.z - 0.5 * _gravity * flight * flight, 0.0);
+			}
+			break;
 		}
 		return coord.pos;
 	}
@@ -99,40 +202,20 @@ namespace math
 			}
 
 		case InterpType::INTERP_CUBIC:
+			if (!_tangents.empty())
 			{
-				auto xrp = std::ranges::upper_bound(_coords, t, {}, &Coord::t);
-				size_t xri;
-				if (xrp == _coords.begin())
-					xri = 1;
-				else if (xrp == _coords.end())
-					xri = _coords.size() - 1;
-				else
-					xri = std::distance(_coords.begin(), xrp);
-
-				if (xri < 1 || xri >= _coords.size())
-					return {0, 0, 0};
-
-				size_t xli = xri - 1;
+				return hermite(t, _coords, _tangents).second;
+			}
+			return splineVelocity(t, _coords, _dd);
 
-				const RealType h = _coords[xri].t - _coords[xli].t;
-				if (h <= EPSILON)
+		case InterpType::INTERP_BALLISTIC:
+			{
+				const RealType launch_time = _coords.front().t;
+				if (t < launch_time || t >= _impact_time)
+				{
 					return {0, 0, 0};
-
-				const RealType a = (_coords[xri].t - t) / h;
-				const RealType b = (t - _coords[xli].t) / h;
-
-				// Derivative coefficients
-				// da/dt = -1/h
-				// db/dt = 1/h
-				// dc/dt = -h/6 * (3a^2 - 1)
-				// dd/dt = h/6 * (3b^2 - 1)
-
-				const RealType da = -1.0 / h;
-				const RealType db = 1.0 / h;
-				const RealType dc = -h / 6.0 * (3.0 * a * a - 1.0);
-				const RealType dd_coeff = h / 6.0 * (3.0 * b * b - 1.0);
-
-				return _coords[xli].pos * da + _coords[xri].pos * db + _dd[xli].pos * dc + _dd[xri].pos * dd_coeff;
+				}
+				return _launch_velocity - Vec3{0, 0, _gravity * (t - launch_time)};
 			}
 		}
 		return {0, 0, 0};
@@ -149,6 +232,37 @@ namespace math
 				break;
 			case InterpType::INTERP_CUBIC:
 				finalizeCubic<Coord>(_coords, _dd);
+				_tangents.clear();
+				if (hasWaypointVelocities())
+				{
+					// Waypoints without an explicit velocity take the tangent of the natural spline
+					_tangents.reserve(_coords.size());
+					for (std::size_t i = 0; i < _coords.size(); ++i)
+					{
+						_tangents.push_back(_velocities[i].value_or(splineVelocity(_coords[i].t, _coords, _dd)));
+					}
+				}
+				break;
+			case InterpType::INTERP_BALLISTIC:
+				{
+					if (_coords.empty())
+					{
+						throw PathException("Ballistic path requires a launch waypoint");
+					}
+					if (_coords.size() > 1)
+					{
+						LOG(Level::WARNING, "Ballistic path uses only its earliest waypoint; {} others are ignored",
+							_coords.size() - 1);
+					}
+					const auto& [launch_pos, launch_time] = _coords.front();
+					if (launch_pos.z < 0)
+					{
+						throw PathException("Ballistic launch point must not be below ground");
+					}
+					// Positive root of z0 + vz * t - g * t^2 / 2 = 0
+					const RealType vz = _launch_velocity.z;
+					_impact_time = launch_time + (vz + std::sqrt(vz * vz + 2.0 * _gravity * launch_pos.z)) / _gravity;
+				}
 				break;
 			}
 			_final = true;
@@ -160,4 +274,86 @@ namespace math
 		_type = settype;
 		_final = false;
 	}
+
+	void Path::setBallistic(const Vec3& launchVelocity, const RealType gravity)
+	{
+		if (!(gravity > 0))
+		{
+			throw PathException("Ballistic path gravity must be positive");
+		}
+		_launch_velocity = launchVelocity;
+		_gravity = gravity;
+		_final = false;
+	}
+
+	RealType Path::getImpactTime() const
+	{
+		if (!_final || _type != InterpType::INTERP_BALLISTIC)
+		{
+			throw PathException("Impact time is only defined for finalized ballistic paths");
+		}
+		return _impact_time;
+	}
+
+	std::unique_ptr<Path> Path::approximateLinear(const RealType tolerance) const
+	{
+		if (!_final)
+		{
+			throw PathException("Finalize not called before approximateLinear");
+		}
+		if (!(tolerance > 0))
+		{
+			throw PathException("Path approximation tolerance must be positive");
+		}
+
+		auto approx = std::make_unique<Path>(InterpType::INTERP_LINEAR);
+		if (_type != InterpType::INTERP_CUBIC || _coords.size() < 2)
+		{
+			for (std::size_t i = 0; i < _coords.size(); ++i)
+			{
+				approx->addCoord(_coords[i], _velocities[i]);
+			}
+			approx->setInterp(_type);
+			if (_type == InterpType::INTERP_BALLISTIC)
+			{
+				approx->setBallistic(_launch_velocity, _gravity);
+			}
+			approx->finalize();
+			return approx;
+		}
+
+		// The chord error is only sampled at the quarter points of each sub-interval. For a
+		// cubic segment the true maximum is at most ~1.1x the sampled one, so the sampled
+		// error is held below tolerance / 1.2 to bound the actual error.
+		const RealType sampled_tolerance = tolerance / 1.2;
+		constexpr int max_depth = 16;
+
+		const auto subdivide = [&](const auto& self, const Coord& left, const Coord& right, const int depth) -> void
+		{
+			RealType max_error = 0;
+			for (const RealType frac : {0.25, 0.5, 0.75})
+			{
+				const RealType t = left.t + frac * (right.t - left.t);
+				const Vec3 chord = left.pos + (right.pos - left.pos) * frac;
+				max_error = std::max(max_error, (getPosition(t) - chord).length());
+			}
+			if (max_error > sampled_tolerance && depth < max_depth)
+			{
+				const RealType t_mid = (left.t + right.t) / 2.0;
+				const Coord mid{.pos = getPosition(t_mid), .t = t_mid};
+				self(self, left, mid, depth + 1);
+				approx->addCoord(mid);
+				self(self, mid, right, depth + 1);
+			}
+		};
+
+		approx->addCoord(_coords.front());
+		for (std::size_t i = 1; i < _coords.size(); ++i)
+		{
+			subdivide(subdivide, _coords[i - 1], _coords[i], 0);
+			approx->addCoord(_coords[i]);
+		}
+		approx->finalize();
+		return approx;
+	}
 }
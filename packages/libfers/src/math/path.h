@@ -13,6 +13,8 @@
 
 #pragma once
 
+#include <memory>
+#include <optional>
 #include <vector>
 
 #include "coord.h"
@@ -36,7 +38,19 @@ namespace math
 		{
 			INTERP_STATIC,
 			INTERP_LINEAR,
-			INTERP_CUBIC
+			INTERP_CUBIC,
+			INTERP_BALLISTIC
+		};
+
+		/**
+		 * @brief Frames in which the path's waypoints are written in scenario files.
+		 *
+		 * Waypoints are always stored in the local frame; this only controls how they are parsed and serialized.
+		 */
+		enum class WaypointFrame
+		{
+			LOCAL, ///< Local East-North-Up coordinates.
+			ECEF ///< Earth-Centered, Earth-Fixed coordinates, converted about the platform's geodetic origin.
 		};
 
 		/**
@@ -59,9 +73,15 @@ namespace math
 		/**
 		 * @brief Adds a coordinate to the path.
 		 *
+		 * An explicit velocity, e.g. a sample from a flight logger, is honoured by cubic interpolation: the
+		 * path then becomes a cubic Hermite spline whose tangent at this waypoint is the given velocity.
+		 * Waypoints without one take the tangent of the natural cubic spline through all waypoints.
+		 * Static, linear and ballistic interpolation ignore waypoint velocities.
+		 *
 		 * @param coord The coordinate to be added.
+		 * @param velocity The optional velocity at the waypoint in m/s, in the local frame.
 		 */
-		void addCoord(const Coord& coord) noexcept;
+		void addCoord(const Coord& coord, const std::optional<Vec3>& velocity = std::nullopt) noexcept;
 
 		/**
 		 * @brief Finalizes the path, preparing it for interpolation.
@@ -82,6 +102,23 @@ namespace math
 		 */
 		[[nodiscard]] const std::vector<Coord>& getCoords() const noexcept { return _coords; }
 
+		/**
+		 * @brief Gets the explicit waypoint velocities of the path.
+		 *
+		 * @return The velocity of each coordinate in `getCoords()` order, or `std::nullopt` where none was given.
+		 */
+		[[nodiscard]] const std::vector<std::optional<Vec3>>& getWaypointVelocities() const noexcept
+		{
+			return _velocities;
+		}
+
+		/**
+		 * @brief Checks whether any waypoint of the path has an explicit velocity.
+		 *
+		 * @return True if at least one waypoint velocity was given.
+		 */
+		[[nodiscard]] bool hasWaypointVelocities() const noexcept;
+
 		/**
 		 * @brief Retrieves the position at a given time along the path.
 		 *
@@ -107,10 +144,80 @@ namespace math
 		 */
 		void setInterp(InterpType settype) noexcept;
 
+		/**
+		 * @brief Sets the launch velocity and gravity of a ballistic path.
+		 *
+		 * A ballistic path starts at its earliest waypoint, the launch point, and follows the
+		 * projectile arc `p(t) = p0 + v0 * (t - t0) - g * (t - t0)^2 / 2 * z`. The platform rests
+		 * at the launch point before launch and at the impact point once the arc returns to
+		 * zero altitude. Both the velocity and the launch point are in the local frame.
+		 *
+		 * @param launchVelocity The velocity at launch in m/s.
+		 * @param gravity The downward acceleration in m/s^2 (must be positive).
+		 * @throws PathException If the gravity is not positive.
+		 */
+		void setBallistic(const Vec3& launchVelocity, RealType gravity = STANDARD_GRAVITY);
+
+		/**
+		 * @brief Retrieves the launch velocity of a ballistic path.
+		 *
+		 * @return The launch velocity in m/s.
+		 */
+		[[nodiscard]] const Vec3& getLaunchVelocity() const noexcept { return _launch_velocity; }
+
+		/**
+		 * @brief Retrieves the gravity of a ballistic path.
+		 *
+		 * @return The downward acceleration in m/s^2.
+		 */
+		[[nodiscard]] RealType getGravity() const noexcept { return _gravity; }
+
+		/**
+		 * @brief Retrieves the time at which a ballistic path reaches the ground.
+		 *
+		 * @return The impact time in seconds.
+		 * @throws PathException If the path is not a finalized ballistic path.
+		 */
+		[[nodiscard]] RealType getImpactTime() const;
+
+		/**
+		 * @brief Retrieves the frame in which the waypoints are written in scenario files.
+		 *
+		 * @return The waypoint frame of the path.
+		 */
+		[[nodiscard]] WaypointFrame getWaypointFrame() const noexcept { return _waypoint_frame; }
+
+		/**
+		 * @brief Sets the frame in which the waypoints are written in scenario files.
+		 *
+		 * @param frame The new waypoint frame.
+		 */
+		void setWaypointFrame(const WaypointFrame frame) noexcept { _waypoint_frame = frame; }
+
+		/**
+		 * @brief Builds a piecewise-linear approximation of this path.
+		 *
+		 * Each segment between waypoints is recursively bisected until the linear chord stays
+		 * within `tolerance` meters of the exact path. This is intended for fast interactive
+		 * previews of long cubic paths; simulations always use the exact interpolation.
+		 * Static, linear and ballistic paths are returned unchanged.
+		 *
+		 * @param tolerance The maximum allowed position error in meters (must be positive).
+		 * @return A finalized path whose waypoints lie on this path.
+		 * @throws PathException If the path is not finalized or the tolerance is not positive.
+		 */
+		[[nodiscard]] std::unique_ptr<Path> approximateLinear(RealType tolerance) const;
+
 	private:
 		std::vector<Coord> _coords; ///< The list of coordinates in the path.
 		std::vector<Coord> _dd; ///< The list of second derivatives for cubic interpolation.
+		std::vector<std::optional<Vec3>> _velocities; ///< The explicit velocity of each coordinate, if any.
+		std::vector<Vec3> _tangents; ///< Per-waypoint Hermite tangents; empty unless velocities were given.
 		bool _final{false}; ///< Flag indicating whether the path has been finalized.
 		InterpType _type; ///< The current interpolation type of the path.
+		WaypointFrame _waypoint_frame{WaypointFrame::LOCAL}; ///< The frame of the waypoints in scenario files.
+		Vec3 _launch_velocity{}; ///< The launch velocity of a ballistic path.
+		RealType _gravity{STANDARD_GRAVITY}; ///< The downward acceleration of a ballistic path.
+		RealType _impact_time{0}; ///< The time at which a ballistic path reaches the ground.
 	};
 }
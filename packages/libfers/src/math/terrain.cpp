@@ -0,0 +1,213 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+/**
+ * @file terrain.cpp
+ * @brief Implementation of the terrain height field and its ESRI ASCII grid loader.
+ */
+
+#include "math/terrain.h"
+
+#include <algorithm>
+#include <cctype>
+#include <cmath>
+#include <fstream>
+#include <limits>
+#include <map>
+#include <stdexcept>
+#include <utility>
+
+#include <GeographicLib/Geocentric.hpp>
+#include <GeographicLib/LocalCartesian.hpp>
+
+namespace
+{
+	/// Mean Earth radius used to convert the grid spacing to meters.
+	constexpr RealType EARTH_RADIUS = 6371000.0;
+
+	/// Upper bound on the number of samples tested along one path.
+	constexpr std::size_t MAX_PATH_SAMPLES = 100000;
+}
+
+namespace math
+{
+	Terrain::Terrain(std::string filename, const GeodeticPosition& origin, const RealType southLatitude,
+					 const RealType westLongitude, const RealType spacing, const std::size_t columns,
+					 std::vector<RealType> heights) :
+		_filename(std::move(filename)), _origin(origin), _south_latitude(southLatitude),
+		_west_longitude(westLongitude), _spacing(spacing), _columns(columns), _rows(0), _heights(std::move(heights))
+	{
+		if (!(_spacing > 0) || _columns < 2 || _heights.size() % _columns != 0 || _heights.size() / _columns < 2)
+		{
+			throw std::invalid_argument("Terrain grid must have a positive spacing and at least 2x2 nodes.");
+		}
+		_rows = _heights.size() / _columns;
+	}
+
+	std::optional<RealType> Terrain::heightAt(const RealType latitude, const RealType longitude) const noexcept
+	{
+		const RealType u = (longitude - _west_longitude) / _spacing;
+		const RealType v = (latitude - _south_latitude) / _spacing;
+		if (!(u >= 0) || !(v >= 0) || u > static_cast<RealType>(_columns - 1) || v > static_cast<RealType>(_rows - 1))
+		{
+			return std::nullopt;
+		}
+
+		const std::size_t i = std::min(static_cast<std::size_t>(u), _columns - 2);
+		const std::size_t j = std::min(static_cast<std::size_t>(v), _rows - 2);
+		const RealType fu = u - static_cast<RealType>(i);
+		const RealType fv = v - static_cast<RealType>(j);
+		const auto node = [&](const std::size_t col, const std::size_t row) { return _heights[row * _columns + col]; };
+
+		const RealType south = node(i, j) * (1 - fu) + node(i + 1, j) * fu;
+		const RealType north = node(i, j + 1) * (1 - fu) + node(i + 1, j + 1) * fu;
+		const RealType height = south * (1 - fv) + north * fv;
+		if (std::isnan(height))
+		{
+			return std::nullopt;
+		}
+		return height;
+	}
+
+	std::optional<Vec3> Terrain::surfacePoint(const RealType x, const RealType y) const
+	{
+		const GeodeticPosition ground = localToGeodetic({x, y, 0.0}, _origin);
+		const auto height = heightAt(ground.latitude, ground.longitude);
+		if (!height)
+		{
+			return std::nullopt;
+		}
+		return geodeticToLocal({ground.latitude, ground.longitude, *height}, _origin);
+	}
+
+	bool Terrain::hasLineOfSight(const Vec3& from, const Vec3& to) const
+	{
+		// Half the east-west node spacing at the origin, so no node is stepped over
+		const RealType step =
+			0.5 * _spacing * PI / 180.0 * EARTH_RADIUS * std::max(std::cos(_origin.latitude * PI / 180.0), 0.01);
+		const Vec3 path = to - from;
+		const auto samples = std::clamp(static_cast<std::size_t>(std::ceil(path.length() / step)), std::size_t{1},
+										MAX_PATH_SAMPLES);
+
+		const GeographicLib::LocalCartesian proj(_origin.latitude, _origin.longitude, _origin.altitude,
+												 GeographicLib::Geocentric::WGS84());
+		for (std::size_t n = 1; n < samples; ++n)
+		{
+			const Vec3 point = from + path * (static_cast<RealType>(n) / static_cast<RealType>(samples));
+			RealType latitude = 0;
+			RealType longitude = 0;
+			RealType altitude = 0;
+			proj.Reverse(point.x, point.y, point.z, latitude, longitude, altitude);
+			if (const auto height = heightAt(latitude, longitude); height && altitude < *height)
+			{
+				return false;
+			}
+		}
+		return true;
+	}
+
+	std::unique_ptr<Terrain> loadTerrainFromFile(const std::string& filename, const GeodeticPosition& origin)
+	{
+		std::ifstream file(filename);
+		if (!file)
+		{
+			throw std::runtime_error("Could not open terrain file '" + filename + "'.");
+		}
+
+		// The header is a run of "key value" pairs ahead of the first height
+		std::map<std::string, RealType> header;
+		std::string token;
+		while (file >> token)
+		{
+			if (!std::isalpha(static_cast<unsigned char>(token.front())))
+			{
+				break;
+			}
+			std::ranges::transform(token, token.begin(),
+								   [](const unsigned char c) { return static_cast<char>(std::tolower(c)); });
+			RealType value = 0;
+			if (!(file >> value))
+			{
+				throw std::runtime_error("Terrain file '" + filename + "' has no value for header '" + token + "'.");
+			}
+			header[token] = value;
+			token.clear();
+		}
+
+		const auto field = [&](const std::string& key) -> std::optional<RealType>
+		{
+			const auto it = header.find(key);
+			return it == header.end() ? std::nullopt : std::optional(it->second);
+		};
+		const auto columns = field("ncols");
+		const auto rows = field("nrows");
+		const auto spacing = field("cellsize");
+		if (!columns || !rows || !spacing || *columns < 2 || *rows < 2 || !(*spacing > 0))
+		{
+			throw std::runtime_error("Terrain file '" + filename +
+									 "' needs ncols and nrows of at least 2 and a positive cellsize.");
+		}
+
+		// Corner references sit half a cell outside the outermost node
+		RealType west;
+		if (const auto center = field("xllcenter"))
+		{
+			west = *center;
+		}
+		else if (const auto corner = field("xllcorner"))
+		{
+			west = *corner + *spacing / 2.0;
+		}
+		else
+		{
+			throw std::runtime_error("Terrain file '" + filename + "' has no xllcorner or xllcenter.");
+		}
+		RealType south;
+		if (const auto center = field("yllcenter"))
+		{
+			south = *center;
+		}
+		else if (const auto corner = field("yllcorner"))
+		{
+			south = *corner + *spacing / 2.0;
+		}
+		else
+		{
+			throw std::runtime_error("Terrain file '" + filename + "' has no yllcorner or yllcenter.");
+		}
+		const auto no_data = field("nodata_value");
+
+		const auto ncols = static_cast<std::size_t>(*columns);
+		const auto nrows = static_cast<std::size_t>(*rows);
+		std::vector<RealType> heights(ncols * nrows);
+		for (std::size_t n = 0; n < heights.size(); ++n)
+		{
+			RealType value = 0;
+			if (n == 0 && !token.empty())
+			{
+				try
+				{
+					value = std::stod(token);
+				}
+				catch (const std::exception&)
+				{
+					throw std::runtime_error("Terrain file '" + filename + "' has a non-numeric height '" + token +
+											 "'.");
+				}
+			}
+			else if (!(file >> value))
+			{
+				throw std::runtime_error("Terrain file '" + filename + "' holds fewer than ncols * nrows heights.");
+			}
+			if (no_data && value == *no_data)
+			{
+				value = std::numeric_limits<RealType>::quiet_NaN();
+			}
+			// File rows run north to south; the grid is stored from the southern row up
+			const std::size_t row = nrows - 1 - n / ncols;
+			heights[row * ncols + n % ncols] = value;
+		}
+
+		return std::make_unique<Terrain>(filename, origin, south, west, *spacing, ncols, std::move(heights));
+	}
+}
@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+/**
+ * @file terrain.h
+ * @brief A terrain height field used to mask line-of-sight paths.
+ *
+ * The height field is a regular latitude/longitude grid of altitudes above the WGS84
+ * ellipsoid, aligned to the scenario's local ENU frame through its geodetic origin.
+ */
+
+#pragma once
+
+#include <cstddef>
+#include <memory>
+#include <optional>
+#include <string>
+#include <vector>
+
+#include "core/config.h"
+#include "math/geodesy.h"
+#include "math/geometry_ops.h"
+
+namespace math
+{
+	/**
+	 * @class Terrain
+	 * @brief A digital elevation model sampled on a regular geodetic grid.
+	 *
+	 * Heights between grid nodes are interpolated bilinearly. Points outside the grid, or next
+	 * to a no-data node, have no terrain height and never block a path.
+	 */
+	class Terrain
+	{
+	public:
+		/**
+		 * @brief Constructs a terrain from a grid of heights.
+		 *
+		 * @param filename The file the grid was read from, kept for serialization.
+		 * @param origin The geodetic origin of the scenario's local ENU frame.
+		 * @param southLatitude The latitude of the southernmost row of nodes in decimal degrees.
+		 * @param westLongitude The longitude of the westernmost column of nodes in decimal degrees.
+		 * @param spacing The node spacing in decimal degrees along both axes.
+		 * @param columns The number of nodes along each row (west to east, at least 2).
+		 * @param heights The node altitudes in meters in row-major order from the southern row,
+		 *        with NaN marking nodes without data.
+		 * @throws std::invalid_argument If the grid is degenerate or the height count does not match.
+		 */
+		Terrain(std::string filename, const GeodeticPosition& origin, RealType southLatitude, RealType westLongitude,
+				RealType spacing, std::size_t columns, std::vector<RealType> heights);
+
+		/**
+		 * @brief Gets the file the height field was read from.
+		 * @return The filename.
+		 */
+		[[nodiscard]] const std::string& getFilename() const noexcept { return _filename; }
+
+		/**
+		 * @brief Interpolates the terrain altitude at a geodetic location.
+		 * @param latitude The latitude in decimal degrees.
+		 * @param longitude The longitude in decimal degrees.
+		 * @return The terrain altitude above the ellipsoid in meters, or nothing outside the grid.
+		 */
+		[[nodiscard]] std::optional<RealType> heightAt(RealType latitude, RealType longitude) const noexcept;
+
+		/**
+		 * @brief Finds the terrain surface below a point of the local frame.
+		 * @param x The East coordinate in meters.
+		 * @param y The North coordinate in meters.
+		 * @return The surface point in the local frame, or nothing outside the grid.
+		 */
+		[[nodiscard]] std::optional<Vec3> surfacePoint(RealType x, RealType y) const;
+
+		/**
+		 * @brief Checks whether the straight path between two points clears the terrain.
+		 *
+		 * The path is sampled at half the grid spacing. The end points themselves are not
+		 * tested, so a platform resting on the ground does not mask itself.
+		 *
+		 * @param from The start of the path in the local frame.
+		 * @param to The end of the path in the local frame.
+		 * @return True if no sample along the path lies below the terrain.
+		 */
+		[[nodiscard]] bool hasLineOfSight(const Vec3& from, const Vec3& to) const;
+
+	private:
+		std::string _filename;
+		GeodeticPosition _origin;
+		RealType _south_latitude;
+		RealType _west_longitude;
+		RealType _spacing;
+		std::size_t _columns;
+		std::size_t _rows;
+		std::vector<RealType> _heights;
+	};
+
+	/**
+	 * @brief Loads a terrain height field from an ESRI ASCII grid file.
+	 *
+	 * The header gives `ncols`, `nrows`, `xllcorner`/`xllcenter`, `yllcorner`/`yllcenter`,
+	 * `cellsize` and an optional `NODATA_value`, with x as longitude and y as latitude in
+	 * decimal degrees. Rows follow from north to south, and heights are altitudes above the
+	 * WGS84 ellipsoid in meters.
+	 *
+	 * @param filename The path to the grid file.
+	 * @param origin The geodetic origin of the scenario's local ENU frame.
+	 * @return A unique pointer to the loaded terrain.
+	 * @throws std::runtime_error If the file cannot be read or is not a valid grid.
+	 */
+	[[nodiscard]] std::unique_ptr<Terrain> loadTerrainFromFile(const std::string& filename,
+															   const GeodeticPosition& origin);
+}
@@ -23,7 +23,8 @@
  *
  * Both pipelines apply effects like thermal noise, phase noise (jitter),
  * interference, downsampling, and ADC quantization before writing the final
- * I/Q data to an HDF5 file.
+ * I/Q data to an HDF5 file. Pulsed receivers with an element array also write
+ * one channel per element, in an `element_NNN` group of the same file.
  */
 
 #include "finalizer.h"
@@ -33,6 +34,7 @@
 #include <cmath>
 #include <format>
 #include <highfive/highfive.hpp>
+#include <optional>
 #include <ranges>
 #include <tuple>
 
@@ -46,6 +48,7 @@
 #include "radar/transmitter.h"
 #include "serial/hdf5_handler.h"
 #include "signal/dsp_filters.h"
+#include "signal/radar_signal.h"
 #include "simulation/channel_model.h"
 #include "timing/timing.h"
 
@@ -63,12 +66,124 @@ namespace
 			w *= std::polar(1.0, n);
 		}
 	}
+
+	/**
+	 * @brief Zeroes the samples of a receive window that fall inside its blanking gates.
+	 * @param window The window of complex I/Q samples at the output sample rate.
+	 * @param gates The blanking gates, as offsets from the window start.
+	 * @param windowStart The time of the first sample in the window.
+	 * @return The (start, end) times of the blanked intervals.
+	 */
+	std::vector<std::pair<RealType, RealType>> applyBlankingGates(std::span<ComplexType> window,
+																  const std::vector<radar::BlankingGate>& gates,
+																  const RealType windowStart)
+	{
+		std::vector<std::pair<RealType, RealType>> blanked;
+		blanked.reserve(gates.size());
+		const RealType rate = params::rate();
+		for (const auto& [start, length] : gates)
+		{
+			// Blank every sample whose period overlaps the gate
+			const auto first = std::min(static_cast<std::size_t>(std::floor(start * rate)), window.size());
+			const auto last = std::min(static_cast<std::size_t>(std::ceil((start + length) * rate)), window.size());
+			std::fill(window.begin() + static_cast<std::ptrdiff_t>(first),
+					  window.begin() + static_cast<std::ptrdiff_t>(last), ComplexType{0.0, 0.0});
+			blanked.emplace_back(windowStart + start, windowStart + start + length);
+		}
+		return blanked;
+	}
+
+	/**
+	 * @brief Renders one receive window at every element of the receiver's array.
+	 *
+	 * Each element gets independent thermal noise. Every path arrives as a plane wave from its
+	 * source (the target for reflections, the transmitter for direct paths), so an element
+	 * sees it with the phase offset of its position along the arrival direction at the window
+	 * start. Phase noise, downsampling and quantization are left to the caller.
+	 *
+	 * @param receiver The receiver with the element array.
+	 * @param targets The targets that reflect CW interference.
+	 * @param job The window's responses and active CW sources.
+	 * @param windowSamples The number of samples in the window at the oversampled rate.
+	 * @param start The sample-aligned start time of the window.
+	 * @param fracDelay The fractional sample delay of the window start.
+	 * @return One buffer of samples per element.
+	 */
+	std::vector<std::vector<ComplexType>>
+	renderElementChannels(radar::Receiver* receiver, const std::vector<std::unique_ptr<radar::Target>>& targets,
+						  const core::RenderingJob& job, const unsigned windowSamples, const RealType start,
+						  const RealType fracDelay)
+	{
+		std::vector channels(receiver->getElementArray().size(), std::vector<ComplexType>(windowSamples));
+		const RealType noise_temperature = receiver->getNoiseTemperature(receiver->getRotation(start));
+		for (auto& channel : channels)
+		{
+			processing::applyThermalNoise(channel, noise_temperature, receiver->getRngEngine());
+		}
+
+		const math::Vec3 rx_pos = receiver->getPosition(start);
+		std::vector<ComplexType> path(windowSamples);
+		const auto add_path = [&](const math::Vec3& source, const RealType carrier)
+		{
+			const math::Vec3 los = source - rx_pos;
+			const RealType dist = los.length();
+			const auto phases =
+				receiver->getElementPhases(dist > EPSILON ? los / dist : math::Vec3{}, start, params::c() / carrier);
+			for (auto [channel, phase] : std::views::zip(channels, phases))
+			{
+				for (auto [out, in] : std::views::zip(channel, path))
+				{
+					out += in * phase;
+				}
+			}
+		};
+
+		// CW interference, one path at a time so each keeps its own arrival direction
+		const RealType dt = 1.0 / (params::rate() * params::oversampleRatio());
+		for (const auto* cw_source : job.active_cw_sources)
+		{
+			const RealType carrier = cw_source->getSignal()->getCarrier();
+			if (!receiver->checkFlag(radar::Receiver::RecvFlag::FLAG_NODIRECT))
+			{
+				RealType t_sample = start;
+				for (auto& sample : path)
+				{
+					sample = simulation::calculateDirectPathContribution(cw_source, receiver, t_sample);
+					t_sample += dt;
+				}
+				add_path(cw_source->getPosition(start), carrier);
+			}
+			for (const auto& target : targets)
+			{
+				RealType t_sample = start;
+				for (auto& sample : path)
+				{
+					sample =
+						simulation::calculateReflectedPathContribution(cw_source, receiver, target.get(), t_sample);
+					t_sample += dt;
+				}
+				add_path(target->getPosition(start), carrier);
+			}
+		}
+
+		// Pulsed responses
+		for (const auto& response : job.responses)
+		{
+			std::ranges::fill(path, ComplexType{});
+			processing::renderWindow(path, job.duration, start, fracDelay, std::span(&response, 1));
+			const math::Vec3 source = response->getTarget() ? response->getTarget()->getPosition(start)
+															: response->getTransmitter()->getPosition(start);
+			add_path(source, response->getWave()->getCarrier());
+		}
+		return channels;
+	}
 }
 
 namespace processing
 {
 	void runPulsedFinalizer(radar::Receiver* receiver, const std::vector<std::unique_ptr<radar::Target>>* targets,
-							std::shared_ptr<core::ProgressReporter> reporter)
+							const std::string hdf5Filename, std::shared_ptr<core::ProgressReporter> reporter,
+							std::shared_ptr<core::WindowStreamer> streamer)
 	{
 		// Each finalizer thread gets a private, stateful clone of the timing model
 		// to ensure thread safety and independent state progression.
@@ -79,11 +194,14 @@ namespace processing
 			return;
 		}
 
-		const auto hdf5_filename = std::format("{}_results.h5", receiver->getName());
-		HighFive::File h5_file(hdf5_filename, HighFive::File::Truncate);
+		std::optional<HighFive::File> h5_file;
+		if (!hdf5Filename.empty())
+		{
+			h5_file.emplace(hdf5Filename, HighFive::File::Truncate);
+		}
 		unsigned chunk_index = 0;
 		LOG(logging::Level::INFO, "Finalizer thread started for receiver '{}'. Outputting to '{}'.",
-			receiver->getName(), hdf5_filename);
+			receiver->getName(), h5_file ? hdf5Filename : "no file");
 
 		// Throttling state
 		auto last_report_time = std::chrono::steady_clock::now();
@@ -188,11 +306,49 @@ namespace processing
 				window_buffer = std::move(fers_signal::downsample(window_buffer));
 			}
 
-			// 6. Quantize and scale to simulate ADC effects.
+			// 6. Zero the blanking gates, so a blanked interferer does not set the full-scale level.
+			const auto blanked = applyBlankingGates(window_buffer, receiver->getBlankingGates(), actual_start);
+
+			// 7. Quantize and scale to simulate ADC effects.
 			const RealType fullscale = quantizeAndScaleWindow(window_buffer);
 
-			// 7. Write the processed chunk to the HDF5 file.
-			serial::addChunkToFile(h5_file, window_buffer, actual_start, fullscale, chunk_index++);
+			// 8. Write the processed chunk to the HDF5 file.
+			if (h5_file)
+			{
+				serial::addChunkToFile(*h5_file, window_buffer, actual_start, fullscale, chunk_index, blanked);
+			}
+
+			// 9. Record a channel per element of the receive array, sharing the receiver's oscillator.
+			if (!receiver->getElementArray().empty())
+			{
+				auto channels =
+					renderElementChannels(receiver, *targets, job, window_samples, actual_start, frac_delay);
+				for (std::size_t element = 0; element < channels.size(); ++element)
+				{
+					auto& channel = channels[element];
+					if (timing_model->isEnabled())
+					{
+						addPhaseNoiseToWindow(pnoise, channel);
+					}
+					if (params::oversampleRatio() > 1)
+					{
+						channel = std::move(fers_signal::downsample(channel));
+					}
+					const auto element_blanked =
+						applyBlankingGates(channel, receiver->getBlankingGates(), actual_start);
+					const RealType element_fullscale = quantizeAndScaleWindow(channel);
+					if (h5_file)
+					{
+						serial::addChunkToFile(*h5_file, channel, actual_start, element_fullscale, chunk_index,
+											   element_blanked, std::format("element_{:03}", element));
+					}
+				}
+			}
+			++chunk_index;
+			if (streamer)
+			{
+				streamer->publish(receiver->getName(), actual_start, fullscale, window_buffer);
+			}
 
 			// Throttled Reporting: Only acquire mutex and callback if enough time has passed
 			if (reporter)
@@ -200,7 +356,8 @@ namespace processing
 				const auto now = std::chrono::steady_clock::now();
 				if ((now - last_report_time) >= report_interval)
 				{
-					reporter->report(std::format("Exporting {}: Chunk {}", receiver->getName(), chunk_index),
+					reporter->report(core::ProgressPhase::EXPORTING,
+									 std::format("Exporting {}: Chunk {}", receiver->getName(), chunk_index),
 									 static_cast<int>(chunk_index), 0);
 					last_report_time = now;
 				}
@@ -210,20 +367,23 @@ namespace processing
 		if (reporter)
 		{
 			// Always report final status
-			reporter->report(std::format("Finished Exporting {}", receiver->getName()), 100, 100);
+			reporter->report(core::ProgressPhase::EXPORTING,
+							 std::format("Finished Exporting {}", receiver->getName()), 100, 100);
 		}
 		LOG(logging::Level::INFO, "Finalizer thread for receiver '{}' finished.", receiver->getName());
 	}
 
-	void finalizeCwReceiver(radar::Receiver* receiver, pool::ThreadPool* pool,
-							std::shared_ptr<core::ProgressReporter> reporter)
+	void finalizeCwReceiver(radar::Receiver* receiver, pool::ThreadPool* pool, const std::string hdf5Filename,
+							std::shared_ptr<core::ProgressReporter> reporter,
+							std::shared_ptr<core::WindowStreamer> streamer)
 	{
 		// CW Finalization only has ~4 major steps, so throttling isn't strictly necessary,
 		// but reporting is added for visibility.
 		LOG(logging::Level::INFO, "Finalization task started for CW receiver '{}'.", receiver->getName());
 		if (reporter)
 		{
-			reporter->report(std::format("Finalizing CW Receiver {}", receiver->getName()), 0, 100);
+			reporter->report(core::ProgressPhase::RENDERING,
+							 std::format("Finalizing CW Receiver {}", receiver->getName()), 0, 100);
 		}
 
 		// Process the entire collected I/Q buffer for the CW receiver.
@@ -238,7 +398,8 @@ namespace processing
 
 		if (reporter)
 		{
-			reporter->report(std::format("Rendering Interference for {}", receiver->getName()), 25, 100);
+			reporter->report(core::ProgressPhase::RENDERING,
+							 std::format("Rendering Interference for {}", receiver->getName()), 25, 100);
 		}
 
 		// --- Signal Rendering and Processing Pipeline ---
@@ -271,7 +432,8 @@ namespace processing
 
 		if (reporter)
 		{
-			reporter->report(std::format("Applying Noise for {}", receiver->getName()), 50, 100);
+			reporter->report(core::ProgressPhase::RENDERING, std::format("Applying Noise for {}", receiver->getName()),
+							 50, 100);
 		}
 		// 2. Apply thermal noise.
 		applyThermalNoise(iq_buffer, receiver->getNoiseTemperature(), receiver->getRngEngine());
@@ -295,43 +457,52 @@ namespace processing
 		// TODO: Is there any point in normalizing the full buffer for CW receivers?
 		const RealType fullscale = quantizeAndScaleWindow(iq_buffer);
 
+		if (streamer)
+		{
+			streamer->publish(receiver->getName(), params::startTime(), fullscale, iq_buffer);
+		}
+
 		if (reporter)
 		{
-			reporter->report(std::format("Writing HDF5 for {}", receiver->getName()), 75, 100);
+			reporter->report(core::ProgressPhase::EXPORTING,
+							 std::format("Writing HDF5 for {}", receiver->getName()), 75, 100);
 		}
 
 		// 6. Write the entire processed buffer to an HDF5 file.
-		const auto hdf5_filename = std::format("{}_results.h5", receiver->getName());
-		try
+		if (!hdf5Filename.empty())
 		{
-			HighFive::File file(hdf5_filename, HighFive::File::Truncate);
+			try
+			{
+				HighFive::File file(hdf5Filename, HighFive::File::Truncate);
 
-			std::vector<RealType> i_data(iq_buffer.size());
-			std::vector<RealType> q_data(iq_buffer.size());
-			std::ranges::transform(iq_buffer, i_data.begin(), [](const auto& c) { return c.real(); });
-			std::ranges::transform(iq_buffer, q_data.begin(), [](const auto& c) { return c.imag(); });
+				std::vector<RealType> i_data(iq_buffer.size());
+				std::vector<RealType> q_data(iq_buffer.size());
+				std::ranges::transform(iq_buffer, i_data.begin(), [](const auto& c) { return c.real(); });
+				std::ranges::transform(iq_buffer, q_data.begin(), [](const auto& c) { return c.imag(); });
 
-			HighFive::DataSet i_dataset = file.createDataSet<RealType>("I_data", HighFive::DataSpace::From(i_data));
-			i_dataset.write(i_data);
-			HighFive::DataSet q_dataset = file.createDataSet<RealType>("Q_data", HighFive::DataSpace::From(q_data));
-			q_dataset.write(q_data);
+				HighFive::DataSet i_dataset = file.createDataSet<RealType>("I_data", HighFive::DataSpace::From(i_data));
+				i_dataset.write(i_data);
+				HighFive::DataSet q_dataset = file.createDataSet<RealType>("Q_data", HighFive::DataSpace::From(q_data));
+				q_dataset.write(q_data);
 
-			file.createAttribute("sampling_rate", params::rate());
-			file.createAttribute("start_time", params::startTime());
-			file.createAttribute("fullscale", fullscale);
-			file.createAttribute("reference_carrier_frequency", timing_model->getFrequency());
+				file.createAttribute("sampling_rate", params::rate());
+				file.createAttribute("start_time", params::startTime());
+				file.createAttribute("fullscale", fullscale);
+				file.createAttribute("reference_carrier_frequency", timing_model->getFrequency());
 
-			LOG(logging::Level::INFO, "Successfully exported CW data for receiver '{}' to '{}'", receiver->getName(),
-				hdf5_filename);
-		}
-		catch (const HighFive::Exception& err)
-		{
-			LOG(logging::Level::FATAL, "Error writing CW data to HDF5 file '{}': {}", hdf5_filename, err.what());
+				LOG(logging::Level::INFO, "Successfully exported CW data for receiver '{}' to '{}'",
+					receiver->getName(), hdf5Filename);
+			}
+			catch (const HighFive::Exception& err)
+			{
+				LOG(logging::Level::FATAL, "Error writing CW data to HDF5 file '{}': {}", hdf5Filename, err.what());
+			}
 		}
 
 		if (reporter)
 		{
-			reporter->report(std::format("Finalized {}", receiver->getName()), 100, 100);
+			reporter->report(core::ProgressPhase::EXPORTING, std::format("Finalized {}", receiver->getName()), 100,
+							 100);
 		}
 	}
 }
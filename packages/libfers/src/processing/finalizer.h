@@ -12,6 +12,7 @@
 #pragma once
 
 #include <memory>
+#include <string>
 #include <vector>
 
 namespace radar
@@ -29,6 +30,8 @@ namespace pool
 namespace core
 {
 	class ProgressReporter;
+
+	class WindowStreamer;
 }
 
 namespace processing
@@ -42,10 +45,13 @@ namespace processing
 	 *
 	 * @param receiver A pointer to the pulsed-mode receiver to process.
 	 * @param targets A pointer to the world's list of targets for interference calculation.
+	 * @param hdf5Filename The path of the HDF5 file to write, or empty to skip the HDF5 output.
 	 * @param reporter Shared pointer to the progress reporter for status updates.
+	 * @param streamer Optional shared pointer that is handed each finished window.
 	 */
 	void runPulsedFinalizer(radar::Receiver* receiver, const std::vector<std::unique_ptr<radar::Target>>* targets,
-							std::shared_ptr<core::ProgressReporter> reporter);
+							std::string hdf5Filename, std::shared_ptr<core::ProgressReporter> reporter,
+							std::shared_ptr<core::WindowStreamer> streamer = nullptr);
 
 	/**
 	 * @brief The finalization task for a continuous-wave (CW) mode receiver.
@@ -56,8 +62,11 @@ namespace processing
 	 *
 	 * @param receiver A pointer to the CW-mode receiver to finalize.
 	 * @param pool A pointer to the main thread pool for parallelizing sub-tasks.
+	 * @param hdf5Filename The path of the HDF5 file to write, or empty to skip the HDF5 output.
 	 * @param reporter Shared pointer to the progress reporter for status updates.
+	 * @param streamer Optional shared pointer that is handed the whole buffer as a single window.
 	 */
-	void finalizeCwReceiver(radar::Receiver* receiver, pool::ThreadPool* pool,
-							std::shared_ptr<core::ProgressReporter> reporter);
+	void finalizeCwReceiver(radar::Receiver* receiver, pool::ThreadPool* pool, std::string hdf5Filename,
+							std::shared_ptr<core::ProgressReporter> reporter,
+							std::shared_ptr<core::WindowStreamer> streamer = nullptr);
 }
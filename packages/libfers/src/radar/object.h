@@ -43,7 +43,10 @@ namespace radar
 		 * @param time The time at which to get the position of the object.
 		 * @return A math::Vec3 representing the position of the object.
 		 */
-		[[nodiscard]] math::Vec3 getPosition(const RealType time) const { return _platform->getPosition(time); }
+		[[nodiscard]] virtual math::Vec3 getPosition(const RealType time) const
+		{
+			return _platform->getPosition(time);
+		}
 
 		/**
 		 * @brief Retrieves the rotation of the object.
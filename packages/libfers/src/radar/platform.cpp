@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: GPL-2.0-only
+//
+// Copyright (c) 2008-present FERS Contributors (see AUTHORS.md).
+//
+// See the GNU GPLv2 LICENSE file in the FERS project root for more information.
+
+/**
+ * @file platform.cpp
+ * @brief Implementation of platform position noise sampling.
+ */
+
+#include "platform.h"
+
+#include <array>
+#include <cmath>
+#include <cstdint>
+
+#include "core/config.h"
+
+namespace
+{
+	/**
+	 * @brief SplitMix64 finalizer used to hash (seed, draw, axis) into a uniform 64-bit value.
+	 */
+	constexpr std::uint64_t splitmix64(std::uint64_t x) noexcept
+	{
+		x += 0x9E3779B97F4A7C15ULL;
+		x = (x ^ (x >> 30)) * 0xBF58476D1CE4E5B9ULL;
+		x = (x ^ (x >> 27)) * 0x94D049BB133111EBULL;
+		return x ^ (x >> 31);
+	}
+
+	/**
+	 * @brief Maps a 64-bit hash to a uniform deviate in the open interval (0, 1).
+	 */
+	RealType toUniform(const std::uint64_t h) noexcept
+	{
+		return (static_cast<RealType>(h >> 11) + 0.5) * 0x1.0p-53;
+	}
+
+	/**
+	 * @brief Draws a standard normal deviate for one axis using the Box-Muller transform.
+	 */
+	RealType standardNormal(const std::uint64_t key, const std::uint64_t axis) noexcept
+	{
+		const std::uint64_t base = splitmix64(key ^ splitmix64(axis));
+		const RealType u1 = toUniform(splitmix64(base));
+		const RealType u2 = toUniform(splitmix64(base + 1));
+		return std::sqrt(-2.0 * std::log(u1)) * std::cos(2.0 * PI * u2);
+	}
+
+	/**
+	 * @brief The Catmull-Rom spline through the four independent draws around a time.
+	 */
+	struct NoiseSpline
+	{
+		std::array<math::Vec3, 4> draws; ///< Unit-variance draws at the knots `k - 1` to `k + 2`.
+		std::array<RealType, 4> weights; ///< Spline weight of each draw at the fraction `f` of interval `k`.
+		std::array<RealType, 4> slopes; ///< Derivative of each weight with respect to `f`.
+		RealType spread; ///< Root of the sum of squared weights, the standard deviation of the blend.
+		RealType spread_slope; ///< Derivative of `spread` with respect to `f`.
+	};
+
+	/**
+	 * @brief Finds the draws around a time and their Catmull-Rom weights.
+	 */
+	NoiseSpline splineAt(const radar::PositionNoise& noise, const RealType time) noexcept
+	{
+		const RealType position = time / noise.correlation_time;
+		const RealType knot = std::floor(position);
+		const RealType f = position - knot;
+		const auto draw = [&](const RealType index)
+		{
+			const std::uint64_t key = splitmix64(static_cast<std::uint64_t>(noise.seed)) ^
+				splitmix64(static_cast<std::uint64_t>(static_cast<std::int64_t>(index)));
+			return math::Vec3{standardNormal(key, 0), standardNormal(key, 1), standardNormal(key, 2)};
+		};
+
+		NoiseSpline spline{{draw(knot - 1), draw(knot), draw(knot + 1), draw(knot + 2)},
+						   {(-f + 2 * f * f - f * f * f) / 2, (2 - 5 * f * f + 3 * f * f * f) / 2,
+							(f + 4 * f * f - 3 * f * f * f) / 2, (f * f * f - f * f) / 2},
+						   {(-1 + 4 * f - 3 * f * f) / 2, (-10 * f + 9 * f * f) / 2, (1 + 8 * f - 9 * f * f) / 2,
+							(3 * f * f - 2 * f) / 2},
+						   0,
+						   0};
+		RealType variance = 0;
+		RealType variance_slope = 0;
+		for (std::size_t i = 0; i < 4; ++i)
+		{
+			variance += spline.weights[i] * spline.weights[i];
+			variance_slope += 2 * spline.weights[i] * spline.slopes[i];
+		}
+		spline.spread = std::sqrt(variance);
+		spline.spread_slope = variance_slope / (2 * spline.spread);
+		return spline;
+	}
+
+	/**
+	 * @brief Sums the draws weighted by the given per-draw coefficients.
+	 */
+	math::Vec3 combine(const std::array<math::Vec3, 4>& draws, const std::array<RealType, 4>& coefficients) noexcept
+	{
+		math::Vec3 sum{0, 0, 0};
+		for (std::size_t i = 0; i < 4; ++i)
+		{
+			sum += draws[i] * coefficients[i];
+		}
+		return sum;
+	}
+}
+
+namespace radar
+{
+	math::Vec3 samplePositionNoise(const PositionNoise& noise, const RealType time) noexcept
+	{
+		const NoiseSpline spline = splineAt(noise, time);
+		return combine(spline.draws, spline.weights) * noise.std_dev / spline.spread;
+	}
+
+	math::Vec3 samplePositionNoiseVelocity(const PositionNoise& noise, const RealType time) noexcept
+	{
+		const NoiseSpline spline = splineAt(noise, time);
+		// Quotient rule on the normalised blend, then the chain rule from fraction to time
+		const math::Vec3 blend = combine(spline.draws, spline.weights);
+		const math::Vec3 blend_slope = combine(spline.draws, spline.slopes);
+		const math::Vec3 slope =
+			(blend_slope * spline.spread - blend * spline.spread_slope) / (spline.spread * spline.spread);
+		return slope * noise.std_dev / noise.correlation_time;
+	}
+
+	unsigned defaultPositionNoiseSeed(const unsigned scenarioSeed, const std::size_t platformIndex) noexcept
+	{
+		return static_cast<unsigned>(splitmix64(splitmix64(scenarioSeed) ^ static_cast<std::uint64_t>(platformIndex)));
+	}
+}
@@ -12,7 +12,9 @@
 
 #pragma once
 
+#include <cstddef>
 #include <memory>
+#include <optional>
 #include <string>
 #include <utility>
 
@@ -23,6 +25,66 @@
 
 namespace radar
 {
+	/**
+	 * @struct GeodeticOrigin
+	 * @brief The geodetic origin of a platform-local East-North-Up tangent plane.
+	 */
+	struct GeodeticOrigin
+	{
+		RealType latitude; ///< Latitude in decimal degrees.
+		RealType longitude; ///< Longitude in decimal degrees.
+		RealType altitude; ///< Altitude above the WGS84 ellipsoid in meters.
+	};
+
+	/**
+	 * @struct PositionNoise
+	 * @brief Zero-mean Gaussian process noise added to a platform's nominal position.
+	 */
+	struct PositionNoise
+	{
+		math::Vec3 std_dev; ///< Per-axis standard deviation in meters.
+		RealType correlation_time = 1.0; ///< Interval between independent draws in seconds.
+		unsigned seed = 0; ///< Seed selecting the noise realization.
+	};
+
+	/**
+	 * @brief Samples the position noise realization at a specific time.
+	 *
+	 * Independent Gaussian offsets are drawn at multiples of the correlation time and
+	 * joined by a Catmull-Rom spline through the four draws around the time. The spline is
+	 * divided by the root of its summed squared weights, which keeps the per-axis variance
+	 * constant and, because that normaliser is flat at every draw, keeps both the offset and
+	 * its rate continuous. Samples microseconds apart within a pulse see nearly the same
+	 * deviation, and the platform velocity does not jump at a draw. The sample is a pure function of the seed and the time, so the
+	 * simulation engine and any preview of the path see exactly the same deviation.
+	 *
+	 * @param noise The noise specification.
+	 * @param time The time at which to sample the noise.
+	 * @return The positional offset in meters.
+	 */
+	[[nodiscard]] math::Vec3 samplePositionNoise(const PositionNoise& noise, RealType time) noexcept;
+
+	/**
+	 * @brief Samples the rate of change of the position noise realization at a specific time.
+	 *
+	 * @param noise The noise specification.
+	 * @param time The time at which to sample the noise.
+	 * @return The time derivative of `samplePositionNoise` in m/s.
+	 */
+	[[nodiscard]] math::Vec3 samplePositionNoiseVelocity(const PositionNoise& noise, RealType time) noexcept;
+
+	/**
+	 * @brief Derives the position noise seed of a platform that does not set one.
+	 *
+	 * Mixing in the platform index gives each platform its own realization, so the
+	 * offsets of two noisy platforms do not cancel in their relative geometry.
+	 *
+	 * @param scenarioSeed The scenario's random seed.
+	 * @param platformIndex The index of the platform in the scenario.
+	 * @return The seed.
+	 */
+	[[nodiscard]] unsigned defaultPositionNoiseSeed(unsigned scenarioSeed, std::size_t platformIndex) noexcept;
+
 	/**
 	 * @class Platform
 	 * @brief Represents a simulation platform with motion and rotation paths.
@@ -68,10 +130,49 @@ namespace radar
 		/**
 		 * @brief Gets the position of the platform at a specific time.
 		 *
+		 * Includes the position noise realization when position noise is configured.
+		 *
 		 * @param time The time for which the position is requested.
 		 * @return A vector representing the position of the platform.
 		 */
-		[[nodiscard]] math::Vec3 getPosition(const RealType time) const { return _motion_path->getPosition(time); }
+		[[nodiscard]] math::Vec3 getPosition(const RealType time) const
+		{
+			math::Vec3 pos = _motion_path->getPosition(time);
+			if (_position_noise)
+			{
+				pos += samplePositionNoise(*_position_noise, time);
+			}
+			return pos;
+		}
+
+		/**
+		 * @brief Gets the velocity of the platform at a specific time.
+		 *
+		 * Includes the rate of change of the position noise, so it agrees with `getPosition`.
+		 *
+		 * @param time The time for which the velocity is requested.
+		 * @return A vector representing the velocity of the platform.
+		 */
+		[[nodiscard]] math::Vec3 getVelocity(const RealType time) const
+		{
+			math::Vec3 vel = _motion_path->getVelocity(time);
+			if (_position_noise)
+			{
+				vel += samplePositionNoiseVelocity(*_position_noise, time);
+			}
+			return vel;
+		}
+
+		/**
+		 * @brief Gets the nominal (noise-free) position of the platform at a specific time.
+		 *
+		 * @param time The time for which the position is requested.
+		 * @return A vector representing the nominal position of the platform.
+		 */
+		[[nodiscard]] math::Vec3 getNominalPosition(const RealType time) const
+		{
+			return _motion_path->getPosition(time);
+		}
 
 		/**
 		 * @brief Gets the rotation of the platform at a specific time.
@@ -102,9 +203,42 @@ namespace radar
 		 */
 		void setMotionPath(std::unique_ptr<math::Path> path) noexcept { _motion_path = std::move(path); }
 
+		/**
+		 * @brief Gets the platform-specific geodetic origin, if one is declared.
+		 *
+		 * When set, the platform's positions are expressed in a local tangent plane
+		 * centred on this origin instead of the scenario-wide origin.
+		 *
+		 * @return The optional origin of the platform's local frame.
+		 */
+		[[nodiscard]] const std::optional<GeodeticOrigin>& getOrigin() const noexcept { return _origin; }
+
+		/**
+		 * @brief Sets or clears the platform-specific geodetic origin.
+		 *
+		 * @param origin The new origin, or `std::nullopt` to use the scenario-wide origin.
+		 */
+		void setOrigin(const std::optional<GeodeticOrigin>& origin) noexcept { _origin = origin; }
+
+		/**
+		 * @brief Gets the platform's position noise specification, if any.
+		 *
+		 * @return The optional position noise of the platform.
+		 */
+		[[nodiscard]] const std::optional<PositionNoise>& getPositionNoise() const noexcept { return _position_noise; }
+
+		/**
+		 * @brief Sets or clears the platform's position noise.
+		 *
+		 * @param noise The new noise specification, or `std::nullopt` to follow the nominal path exactly.
+		 */
+		void setPositionNoise(const std::optional<PositionNoise>& noise) noexcept { _position_noise = noise; }
+
 	private:
 		std::unique_ptr<math::Path> _motion_path; ///< The motion path of the platform.
 		std::unique_ptr<math::RotationPath> _rotation_path; ///< The rotation path of the platform.
 		std::string _name; ///< The name of the platform.
+		std::optional<GeodeticOrigin> _origin; ///< Optional platform-local tangent plane origin.
+		std::optional<PositionNoise> _position_noise; ///< Optional position process noise.
 	};
 }
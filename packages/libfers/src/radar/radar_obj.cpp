@@ -28,6 +28,25 @@ namespace radar
 		return _antenna->getGain(angle, refangle, wavelength);
 	}
 
+	math::Vec3 Radar::getPosition(const RealType time) const
+	{
+		return Object::getPosition(time) + math::Vec3(0, 0, _mount_height);
+	}
+
+	math::Vec3 Radar::getPhaseCenterPosition(const RealType time) const
+	{
+		return getPosition(time) + bodyToWorld(_phase_center_offset, time);
+	}
+
+	math::Vec3 Radar::bodyToWorld(const math::Vec3& offset, const RealType time) const
+	{
+		const math::SVec3 rotation = getRotation(time);
+		const math::Vec3 forward(math::SVec3(1, rotation.azimuth, rotation.elevation));
+		const math::Vec3 left(math::SVec3(1, rotation.azimuth + PI / 2, 0));
+		const math::Vec3 up(math::SVec3(1, rotation.azimuth, rotation.elevation + PI / 2));
+		return forward * offset.x + left * offset.y + up * offset.z;
+	}
+
 	RealType Radar::getNoiseTemperature(const math::SVec3& angle) const noexcept
 	{
 		return _antenna->getNoiseTemperature(angle);
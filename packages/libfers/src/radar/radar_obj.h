@@ -117,6 +117,63 @@ namespace radar
 		 */
 		void setAntenna(const antenna::Antenna* ant);
 
+		/**
+		 * @brief Gets the antenna phase-center offset in the platform body frame.
+		 *
+		 * The body frame has x along boresight, y to the left and z up.
+		 *
+		 * @return The offset in meters from the platform origin.
+		 */
+		[[nodiscard]] const math::Vec3& getPhaseCenterOffset() const noexcept { return _phase_center_offset; }
+
+		/**
+		 * @brief Sets the antenna phase-center offset in the platform body frame.
+		 *
+		 * @param offset The offset in meters from the platform origin.
+		 */
+		void setPhaseCenterOffset(const math::Vec3& offset) noexcept { _phase_center_offset = offset; }
+
+		/**
+		 * @brief Gets the height of the antenna mount above the platform reference point.
+		 *
+		 * @return The mount height in meters along the vertical (z) axis.
+		 */
+		[[nodiscard]] RealType getMountHeight() const noexcept { return _mount_height; }
+
+		/**
+		 * @brief Sets the height of the antenna mount above the platform reference point.
+		 *
+		 * @param height The mount height in meters along the vertical (z) axis.
+		 */
+		void setMountHeight(const RealType height) noexcept { _mount_height = height; }
+
+		/**
+		 * @brief Gets the position of the antenna mount.
+		 *
+		 * The mount height is vertical, so it is unaffected by the platform's rotation.
+		 *
+		 * @param time The time at which to evaluate the platform position.
+		 * @return The platform position raised by the mount height.
+		 */
+		[[nodiscard]] math::Vec3 getPosition(RealType time) const override;
+
+		/**
+		 * @brief Gets the world position of the antenna phase center.
+		 *
+		 * @param time The time at which to evaluate the platform position and rotation.
+		 * @return The mount position plus the offset rotated into the world frame.
+		 */
+		[[nodiscard]] math::Vec3 getPhaseCenterPosition(RealType time) const;
+
+		/**
+		 * @brief Rotates a vector from the platform body frame into the world frame.
+		 *
+		 * @param offset The vector in the body frame (x along boresight, y to the left, z up).
+		 * @param time The time at which to evaluate the platform rotation.
+		 * @return The same vector expressed in the world frame.
+		 */
+		[[nodiscard]] math::Vec3 bodyToWorld(const math::Vec3& offset, RealType time) const;
+
 		/**
 		 * @brief Attaches another radar object to this radar.
 		 *
@@ -131,5 +188,7 @@ namespace radar
 	private:
 		const antenna::Antenna* _antenna{nullptr}; ///< Antenna object associated with the radar.
 		const Radar* _attached{nullptr}; ///< Attached radar object.
+		math::Vec3 _phase_center_offset{}; ///< Antenna phase-center offset in the body frame.
+		RealType _mount_height{0}; ///< Height of the antenna mount above the platform reference point.
 	};
 }
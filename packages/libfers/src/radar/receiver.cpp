@@ -13,6 +13,10 @@
 #include "receiver.h"
 
 #include <algorithm>
+#include <cmath>
+#include <format>
+#include <fstream>
+#include <sstream>
 #include <utility>
 
 #include "core/parameters.h"
@@ -85,6 +89,79 @@ namespace radar
 		_noise_temperature = temp;
 	}
 
+	void Receiver::loadCalibration(const std::string& filename)
+	{
+		std::ifstream file(filename);
+		if (!file)
+		{
+			throw std::runtime_error("Could not open calibration file '" + filename + "' for receiver '" + getName() +
+									 "'.");
+		}
+
+		std::vector<std::pair<RealType, RealType>> table;
+		std::string line;
+		for (std::size_t line_number = 1; std::getline(file, line); ++line_number)
+		{
+			const auto first = line.find_first_not_of(" \t\r");
+			if (first == std::string::npos || line[first] == '#' ||
+				(table.empty() && std::isalpha(static_cast<unsigned char>(line[first]))))
+			{
+				continue;
+			}
+
+			std::istringstream row(line);
+			RealType frequency = 0;
+			RealType gain_db = 0;
+			char separator = 0;
+			if (!(row >> frequency >> separator >> gain_db) || separator != ',' || !std::isfinite(frequency) ||
+				!std::isfinite(gain_db))
+			{
+				throw std::runtime_error("Calibration file '" + filename + "' line " + std::to_string(line_number) +
+										 " is not a 'frequency,gain' pair.");
+			}
+			if (!table.empty() && frequency <= table.back().first)
+			{
+				throw std::runtime_error("Calibration file '" + filename + "' line " + std::to_string(line_number) +
+										 ": frequencies must be strictly increasing.");
+			}
+			table.emplace_back(frequency, gain_db);
+		}
+		if (table.empty())
+		{
+			throw std::runtime_error("Calibration file '" + filename + "' contains no entries.");
+		}
+
+		_calibration_file = filename;
+		_calibration = std::move(table);
+	}
+
+	RealType Receiver::getCalibrationGain(const RealType frequency) const noexcept
+	{
+		if (_calibration.empty())
+		{
+			return 1.0;
+		}
+
+		RealType gain_db;
+		if (frequency <= _calibration.front().first)
+		{
+			gain_db = _calibration.front().second;
+		}
+		else if (frequency >= _calibration.back().first)
+		{
+			gain_db = _calibration.back().second;
+		}
+		else
+		{
+			const auto upper = std::ranges::upper_bound(_calibration, frequency, {},
+														&std::pair<RealType, RealType>::first);
+			const auto lower = std::prev(upper);
+			const RealType frac = (frequency - lower->first) / (upper->first - lower->first);
+			gain_db = lower->second + frac * (upper->second - lower->second);
+		}
+		return std::pow(10.0, gain_db / 10.0);
+	}
+
 	void Receiver::setWindowProperties(const RealType length, const RealType prf, const RealType skip) noexcept
 	{
 		const auto rate = params::rate() * params::oversampleRatio();
@@ -127,6 +204,57 @@ namespace radar
 
 	void Receiver::setSchedule(std::vector<SchedulePeriod> schedule) { _schedule = std::move(schedule); }
 
+	void Receiver::setBlankingGates(std::vector<BlankingGate> gates)
+	{
+		if (!gates.empty() && _mode != OperationMode::PULSED_MODE)
+		{
+			throw std::runtime_error("Blanking gates of receiver '" + getName() +
+									 "' need receive windows, so the receiver must be pulsed.");
+		}
+		for (const auto& [start, length] : gates)
+		{
+			if (start < 0 || !(length > 0) || start + length > _window_length)
+			{
+				throw std::runtime_error(std::format("Blanking gate [{}, {}] s of receiver '{}' does not fit within "
+													 "its {} s receive window.",
+													 start, start + length, getName(), _window_length));
+			}
+		}
+		_blanking_gates = std::move(gates);
+	}
+
+	void Receiver::setElementArray(std::vector<math::Vec3> elements)
+	{
+		if (!elements.empty() && _mode != OperationMode::PULSED_MODE)
+		{
+			throw std::runtime_error("Element array of receiver '" + getName() +
+									 "' records per-window channels, so the receiver must be pulsed.");
+		}
+		for (std::size_t i = 0; i < elements.size(); ++i)
+		{
+			const auto& element = elements[i];
+			if (!std::isfinite(element.x) || !std::isfinite(element.y) || !std::isfinite(element.z))
+			{
+				throw std::runtime_error(
+					std::format("Element {} of the array of receiver '{}' has a non-finite position.", i, getName()));
+			}
+		}
+		_elements = std::move(elements);
+	}
+
+	std::vector<ComplexType> Receiver::getElementPhases(const math::Vec3& direction, const RealType time,
+														const RealType wavelength) const
+	{
+		std::vector<ComplexType> phases;
+		phases.reserve(_elements.size());
+		for (const auto& element : _elements)
+		{
+			const RealType path = math::dotProduct(direction, bodyToWorld(element, time));
+			phases.push_back(std::polar(1.0, 2.0 * PI * path / wavelength));
+		}
+		return phases;
+	}
+
 	std::optional<RealType> Receiver::getNextWindowTime(RealType time) const
 	{
 		// If no schedule is defined, assume always on.
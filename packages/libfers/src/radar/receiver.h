@@ -28,6 +28,16 @@ namespace pool
 
 namespace radar
 {
+	/**
+	 * @struct BlankingGate
+	 * @brief An interval of each receive window whose samples are zeroed.
+	 */
+	struct BlankingGate
+	{
+		RealType start = 0; ///< Offset of the gate from the opening of the window, in seconds.
+		RealType length = 0; ///< Length of the gate in seconds.
+	};
+
 	/**
 	 * @class Receiver
 	 * @brief Manages radar signal reception and response processing.
@@ -208,6 +218,36 @@ namespace radar
 		 */
 		void setNoiseTemperature(RealType temp);
 
+		/**
+		 * @brief Loads a gain-versus-frequency calibration table for the receive chain.
+		 *
+		 * The file is a CSV of `frequency,gain` rows with the frequency in Hz and the power
+		 * gain in dB. Blank lines, lines starting with `#` and a leading header row are
+		 * ignored. Frequencies must be strictly increasing.
+		 *
+		 * @param filename The path of the calibration file.
+		 * @throws std::runtime_error If the file cannot be read, a row is malformed, the
+		 *         frequencies are not strictly increasing, or the table is empty.
+		 */
+		void loadCalibration(const std::string& filename);
+
+		/**
+		 * @brief Retrieves the path of the loaded calibration file.
+		 * @return The calibration file path, or an empty string if none is loaded.
+		 */
+		[[nodiscard]] const std::string& getCalibrationFile() const noexcept { return _calibration_file; }
+
+		/**
+		 * @brief Gets the calibrated receive-chain power gain at a frequency.
+		 *
+		 * The gain is interpolated linearly in dB between table entries and held at the
+		 * first or last entry outside the table.
+		 *
+		 * @param frequency The signal frequency in Hz.
+		 * @return The linear power gain, or 1 if no calibration is loaded.
+		 */
+		[[nodiscard]] RealType getCalibrationGain(RealType frequency) const noexcept;
+
 		/**
 		 * @brief Prepares the internal storage for CW IQ data.
 		 * @param numSamples The total number of samples to allocate memory for.
@@ -254,6 +294,54 @@ namespace radar
 		 */
 		[[nodiscard]] const std::vector<SchedulePeriod>& getSchedule() const noexcept { return _schedule; }
 
+		/**
+		 * @brief Sets the blanking gates applied to every receive window.
+		 *
+		 * Samples inside a gate are zeroed before ADC quantization, so a known interferer does
+		 * not reach the output or set the window's full-scale level.
+		 *
+		 * @param gates The gates, each an offset and length within the window.
+		 * @throws std::runtime_error If the receiver is not pulsed, or a gate has a negative start,
+		 *         a non-positive length, or does not fit within the window length.
+		 */
+		void setBlankingGates(std::vector<BlankingGate> gates);
+
+		/**
+		 * @brief Retrieves the blanking gates applied to every receive window.
+		 * @return A const reference to the gate vector.
+		 */
+		[[nodiscard]] const std::vector<BlankingGate>& getBlankingGates() const noexcept { return _blanking_gates; }
+
+		/**
+		 * @brief Sets the element positions of a receive array for digital beamforming.
+		 *
+		 * Each element records its own channel alongside the receiver's usual output. The
+		 * positions are offsets from the antenna phase center in the platform body frame
+		 * (x along boresight, y to the left, z up).
+		 *
+		 * @param elements The element offsets in meters.
+		 * @throws std::runtime_error If elements are given and the receiver is not pulsed, or an
+		 *         element position is not finite.
+		 */
+		void setElementArray(std::vector<math::Vec3> elements);
+
+		/**
+		 * @brief Retrieves the element positions of the receive array.
+		 * @return The element offsets in the body frame, empty if the receiver has no array.
+		 */
+		[[nodiscard]] const std::vector<math::Vec3>& getElementArray() const noexcept { return _elements; }
+
+		/**
+		 * @brief Computes the phase of a plane wave at each array element relative to the phase center.
+		 *
+		 * @param direction The unit vector in the world frame pointing from the receiver towards the source.
+		 * @param time The time at which to evaluate the platform rotation.
+		 * @param wavelength The wavelength of the wave in meters.
+		 * @return One unit-magnitude phasor `exp(j * 2 * pi * direction . element / wavelength)` per element.
+		 */
+		[[nodiscard]] std::vector<ComplexType> getElementPhases(const math::Vec3& direction, RealType time,
+																RealType wavelength) const;
+
 		/**
 		 * @brief Determines the next valid window start time at or after the given time.
 		 *
@@ -270,11 +358,15 @@ namespace radar
 		OperationMode _mode; ///< The operational mode of the receiver.
 		std::mt19937 _rng; ///< Per-object random number generator for statistical independence.
 		std::vector<SchedulePeriod> _schedule; ///< The schedule of active periods.
+		std::string _calibration_file; ///< The path of the calibration table, if any.
+		std::vector<std::pair<RealType, RealType>> _calibration; ///< Calibration (frequency, gain in dB) pairs.
+		std::vector<math::Vec3> _elements; ///< Receive array element offsets in the body frame.
 
 		// --- Pulsed Mode Members ---
 		RealType _window_length = 0; ///< The length of the radar window.
 		RealType _window_prf = 0; ///< The pulse repetition frequency (PRF) of the radar window.
 		RealType _window_skip = 0; ///< The skip time between radar windows.
+		std::vector<BlankingGate> _blanking_gates; ///< Intervals of each window that are zeroed.
 		std::vector<std::unique_ptr<serial::Response>>
 			_inbox; /// Mailbox for incoming Response objects during a receive window.
 		std::mutex _inbox_mutex;
@@ -12,11 +12,16 @@
 
 #include "target.h"
 
+#include <algorithm>
 #include <cmath>
+#include <filesystem>
 #include <optional>
 #include <stdexcept>
+#include <string>
+#include <vector>
 
 #include "core/logging.h"
+#include "core/parameters.h"
 #include "math/geometry_ops.h"
 #include "serial/libxml_wrapper.h"
 
@@ -25,51 +30,241 @@ using math::SVec3;
 namespace
 {
 	/**
-	 * @brief Load the target gain axis from an XML element.
+	 * @brief Parses the text of an RCS sample field as a finite number.
 	 *
-	 * @param set The interpolation set to insert the samples into.
-	 * @param axisXml The XML element containing the gain samples.
+	 * @param element The `<angle>` or `<rcs>` element.
+	 * @param axis The name of the axis holding the sample.
+	 * @param filename The file being read, for error messages.
+	 * @return The value.
+	 * @throws std::runtime_error If the element is missing or its text is not a finite number.
 	 */
-	void loadTargetGainAxis(const interp::InterpSet* set, const XmlElement& axisXml) noexcept
+	RealType parseRcsField(const XmlElement& element, const std::string& axis, const std::string& filename)
 	{
-		XmlElement tmp = axisXml.childElement("rcssample");
-		while (tmp.isValid())
+		const std::string text = element.getText();
+		std::size_t used = 0;
+		RealType value = 0;
+		try
 		{
-			XmlElement angle_element = tmp.childElement("angle", 0);
+			value = std::stod(text, &used);
+		}
+		catch (const std::exception&)
+		{
+			used = 0;
+		}
+		if (used == 0 || text.find_first_not_of(" \t\r\n", used) != std::string::npos || !std::isfinite(value))
+		{
+			throw std::runtime_error("RCS file '" + filename + "' has a non-numeric <" + std::string(element.name()) +
+									 "> '" + text + "' in its <" + axis + "> axis.");
+		}
+		return value;
+	}
 
-			if (XmlElement gain_element = tmp.childElement("rcs", 0); angle_element.isValid() && gain_element.isValid())
+	/**
+	 * @brief Reads the samples of one RCS axis.
+	 *
+	 * @param root The root element of the target description.
+	 * @param axis The name of the axis, `azimuth` or `elevation`.
+	 * @param filename The file being read, for error messages.
+	 * @param allowRepeatedAngles Whether a repeated angle is logged and dropped rather than rejected.
+	 * @return The (angle, RCS) samples sorted by angle.
+	 * @throws std::runtime_error If the axis is missing or holds an invalid sample.
+	 */
+	std::vector<std::pair<RealType, RealType>> readRcsAxis(const XmlElement& root, const std::string& axis,
+														   const std::string& filename, const bool allowRepeatedAngles)
+	{
+		const XmlElement axis_element = root.childElement(axis, 0);
+		if (!axis_element.isValid())
+		{
+			throw std::runtime_error("RCS file '" + filename + "' has no <" + axis +
+									 "> axis; only separable azimuth and elevation cuts are supported.");
+		}
+
+		std::vector<std::pair<RealType, RealType>> samples;
+		for (unsigned i = 0;; ++i)
+		{
+			const XmlElement sample = axis_element.childElement("rcssample", i);
+			if (!sample.isValid())
+			{
+				break;
+			}
+			const XmlElement angle_element = sample.childElement("angle", 0);
+			const XmlElement rcs_element = sample.childElement("rcs", 0);
+			if (!angle_element.isValid() || !rcs_element.isValid())
 			{
-				const RealType angle = std::stof(angle_element.getText());
-				const RealType gain = std::stof(gain_element.getText());
-				set->insertSample(angle, gain);
+				throw std::runtime_error("RCS file '" + filename + "' has a sample without <angle> and <rcs> in its <" +
+										 axis + "> axis.");
 			}
+			const RealType angle = parseRcsField(angle_element, axis, filename);
+			const RealType rcs = parseRcsField(rcs_element, axis, filename);
+			if (rcs < 0)
+			{
+				throw std::runtime_error("RCS file '" + filename + "' has a negative RCS in its <" + axis + "> axis.");
+			}
+			samples.emplace_back(angle, rcs);
+		}
+		if (samples.empty())
+		{
+			throw std::runtime_error("RCS file '" + filename + "' has no samples in its <" + axis + "> axis.");
+		}
 
-			tmp = XmlElement(tmp.getNode()->next);
+		// A stable sort keeps repeated angles in file order, so the first of them is the one kept
+		std::ranges::stable_sort(samples, {}, &std::pair<RealType, RealType>::first);
+		const auto same_angle = [](const auto& a, const auto& b) { return a.first == b.first; };
+		if (const auto it = std::ranges::adjacent_find(samples, same_angle); it != samples.end())
+		{
+			if (!allowRepeatedAngles)
+			{
+				throw std::runtime_error("RCS file '" + filename + "' repeats the angle " +
+										 std::to_string(it->first) + " in its <" + axis + "> axis.");
+			}
+			LOG(logging::Level::WARNING,
+				"RCS file '{}' repeats the angle {} in its <{}> axis; only the first sample at each angle is used.",
+				filename, it->first, axis);
+			const auto repeated = std::ranges::unique(samples, same_angle);
+			samples.erase(repeated.begin(), repeated.end());
 		}
+		return samples;
 	}
 }
 
 namespace radar
 {
-	RealType IsoTarget::getRcs(SVec3& /*inAngle*/, SVec3& /*outAngle*/, RealType /*time*/) const noexcept
+	void Target::setMicroMotion(const std::optional<MicroMotion>& motion)
 	{
-		return _model ? _rcs * _model->sampleModel() : _rcs;
+		if (motion)
+		{
+			if (motion->axis.length() <= 0)
+			{
+				throw std::runtime_error("Micro-motion axis of target '" + getName() + "' must be non-zero.");
+			}
+			if (motion->amplitude < 0 || motion->frequency < 0)
+			{
+				throw std::runtime_error("Micro-motion amplitude and frequency of target '" + getName() +
+										 "' must be non-negative.");
+			}
+		}
+		_micro_motion = motion;
 	}
 
-	FileTarget::FileTarget(Platform* platform, std::string name, const std::string& filename, const unsigned seed) :
-		Target(platform, std::move(name), seed), _azi_samples(std::make_unique_for_overwrite<interp::InterpSet>()),
-		_elev_samples(std::make_unique_for_overwrite<interp::InterpSet>()), _filename(filename)
+	void Target::setDecorrelationTime(const std::optional<RealType> time)
+	{
+		if (time && !(*time > 0))
+		{
+			throw std::runtime_error("Decorrelation time of target '" + getName() + "' must be positive.");
+		}
+		if (time && _rcs_sample_rate)
+		{
+			throw std::runtime_error("Target '" + getName() +
+									 "' cannot have both a decorrelation time and an RCS sample rate.");
+		}
+		_decorrelation_time = time;
+		_fluctuation_interval.reset();
+	}
+
+	void Target::setRcsSampleRate(const std::optional<RealType> rate)
+	{
+		if (rate && !(*rate > 0))
+		{
+			throw std::runtime_error("RCS sample rate of target '" + getName() + "' must be positive.");
+		}
+		if (rate && _decorrelation_time)
+		{
+			throw std::runtime_error("Target '" + getName() +
+									 "' cannot have both a decorrelation time and an RCS sample rate.");
+		}
+		_rcs_sample_rate = rate;
+		_fluctuation_interval.reset();
+	}
+
+	void Target::setSwerlingCase(const std::optional<unsigned> swerlingCase)
+	{
+		if (swerlingCase && (*swerlingCase < 1 || *swerlingCase > 4))
+		{
+			throw std::runtime_error("Swerling case of target '" + getName() + "' must be between 1 and 4.");
+		}
+		_swerling_case = swerlingCase;
+	}
+
+	RealType Target::sampleFluctuation(const RealType time) const
+	{
+		if (!_model)
+		{
+			return 1.0;
+		}
+		if (!_decorrelation_time && !_rcs_sample_rate)
+		{
+			return _model->sampleModel();
+		}
+
+		const RealType period = _decorrelation_time ? *_decorrelation_time : 1.0 / *_rcs_sample_rate;
+		const auto interval = static_cast<long long>(std::floor((time - params::startTime()) / period));
+		std::lock_guard lock(_fluctuation_mutex);
+		if (_fluctuation_interval != interval)
+		{
+			_fluctuation_sample = _model->sampleModel();
+			_fluctuation_interval = interval;
+		}
+		return _fluctuation_sample;
+	}
+
+	math::Vec3 Target::getMicroMotionOffset(const RealType time) const noexcept
+	{
+		if (!_micro_motion)
+		{
+			return {};
+		}
+		const auto& [type, amplitude, frequency, axis] = *_micro_motion;
+		const math::Vec3 unit_axis = axis / axis.length();
+		const RealType phase = 2 * PI * frequency * time;
+		if (type == MicroMotion::Type::VIBRATION)
+		{
+			return unit_axis * (amplitude * std::sin(phase));
+		}
+
+		// Build an orthonormal basis of the rotation plane from whichever Cartesian axis is least aligned
+		const math::Vec3 reference = std::abs(unit_axis.z) < 0.9 ? math::Vec3{0, 0, 1} : math::Vec3{1, 0, 0};
+		math::Vec3 u = math::crossProduct(unit_axis, reference);
+		u /= u.length();
+		const math::Vec3 v = math::crossProduct(unit_axis, u);
+		return u * (amplitude * std::cos(phase)) + v * (amplitude * std::sin(phase));
+	}
+
+	RealType IsoTarget::getRcs(SVec3& /*inAngle*/, SVec3& /*outAngle*/, const RealType time) const noexcept
 	{
+		return _rcs * sampleFluctuation(time);
+	}
+
+	RcsPattern loadRcsPattern(const std::string& filename, const bool allowRepeatedAngles)
+	{
+		if (!std::filesystem::exists(filename))
+		{
+			throw std::runtime_error("RCS file '" + filename + "' does not exist.");
+		}
 		XmlDocument doc;
 		if (!doc.loadFile(filename))
 		{
-			throw std::runtime_error("Could not load target description from " + filename);
+			throw std::runtime_error("RCS file '" + filename + "' is not a valid XML target description.");
 		}
 
 		const XmlElement root(doc.getRootElement());
+		return {readRcsAxis(root, "azimuth", filename, allowRepeatedAngles),
+				readRcsAxis(root, "elevation", filename, allowRepeatedAngles)};
+	}
 
-		loadTargetGainAxis(_elev_samples.get(), root.childElement("elevation", 0));
-		loadTargetGainAxis(_azi_samples.get(), root.childElement("azimuth", 0));
+	FileTarget::FileTarget(Platform* platform, std::string name, const std::string& filename, const unsigned seed) :
+		Target(platform, std::move(name), seed), _azi_samples(std::make_unique_for_overwrite<interp::InterpSet>()),
+		_elev_samples(std::make_unique_for_overwrite<interp::InterpSet>()), _filename(filename)
+	{
+		// Files that worked before repeated angles were checked keep loading, with a warning
+		const auto [azimuth, elevation] = loadRcsPattern(filename, true);
+		for (const auto& [angle, rcs] : azimuth)
+		{
+			_azi_samples->insertSample(angle, rcs);
+		}
+		for (const auto& [angle, rcs] : elevation)
+		{
+			_elev_samples->insertSample(angle, rcs);
+		}
 	}
 
 	RealType FileTarget::getRcs(SVec3& inAngle, SVec3& outAngle, const RealType time) const
@@ -85,14 +280,15 @@ namespace radar
 		const SVec3 local_aspect_angle = global_bisector_angle - target_rotation;
 
 		// 4. Use the local aspect angle (bisector is halved) to look up RCS.
-		const auto azi_value = _azi_samples->getValueAt(local_aspect_angle.azimuth / 2.0);
+		const auto azi_value = _azi_samples->getValueAt(local_aspect_angle.azimuth / 2.0, _interpolation);
 
-		if (const auto elev_value = _elev_samples->getValueAt(local_aspect_angle.elevation / 2.0);
+		if (const auto elev_value = _elev_samples->getValueAt(local_aspect_angle.elevation / 2.0, _interpolation);
 			azi_value && elev_value)
 		{
 			// Return the raw RCS value (proportional to power), not its square root.
-			const RealType rcs = *azi_value * *elev_value;
-			return _model ? rcs * _model->sampleModel() : rcs;
+			// Cubic interpolation can undershoot near deep nulls, so never report a negative RCS.
+			const RealType rcs = std::max(*azi_value * *elev_value, 0.0);
+			return rcs * sampleFluctuation(time);
 		}
 
 		LOG(logging::Level::FATAL, "Could not get RCS value for target");
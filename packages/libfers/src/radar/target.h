@@ -13,12 +13,16 @@
 #pragma once
 
 #include <memory>
+#include <mutex>
+#include <optional>
 #include <random>
 #include <string>
 #include <utility>
+#include <vector>
 
 #include "core/config.h"
 #include "interpolation/interpolation_set.h"
+#include "math/geometry_ops.h"
 #include "noise/noise_generators.h"
 #include "object.h"
 
@@ -109,6 +113,49 @@ namespace radar
 		RealType _k; ///< The 'k' parameter (degrees of freedom).
 	};
 
+	/**
+	 * @brief Gets the chi-square `k` of a Swerling case.
+	 *
+	 * Cases I and II have an exponentially distributed RCS (`k = 1`), cases III and IV a
+	 * four-degree-of-freedom chi-square RCS (`k = 2`).
+	 *
+	 * @param swerlingCase The Swerling case (1-4).
+	 * @return The `k` parameter for `RcsChiSquare`.
+	 */
+	[[nodiscard]] constexpr RealType swerlingChiSquareK(const unsigned swerlingCase) noexcept
+	{
+		return swerlingCase <= 2 ? 1.0 : 2.0;
+	}
+
+	/**
+	 * @struct MicroMotion
+	 * @brief Periodic motion of a target's scattering centre about its platform position.
+	 *
+	 * A vibrating scatterer is displaced by `amplitude * sin(2π * frequency * t)` along the axis. A
+	 * rotating scatterer moves on a circle of radius `amplitude` about the axis at `frequency`
+	 * revolutions per second. The engine adds this displacement to the target position when it
+	 * computes each reflected path, so a change `ΔR(t)` in the Tx -> Target -> Rx path length shifts
+	 * the carrier phase by `-2π * ΔR(t) / λ`. For a monostatic radar viewing a vibration along the
+	 * line of sight this is a sinusoidal phase modulation of peak `4π * amplitude / λ`, i.e. a
+	 * micro-Doppler shift of `(4π * amplitude * frequency / λ) * cos(2π * frequency * t)`.
+	 */
+	struct MicroMotion
+	{
+		/**
+		 * @brief The kind of periodic motion.
+		 */
+		enum class Type
+		{
+			VIBRATION, ///< Sinusoidal displacement along the axis.
+			ROTATION ///< Circular motion in the plane perpendicular to the axis.
+		};
+
+		Type type = Type::VIBRATION; ///< The kind of motion.
+		RealType amplitude = 0; ///< Peak displacement (vibration) or radius (rotation) in meters.
+		RealType frequency = 0; ///< Vibration frequency or rotation rate in Hz.
+		math::Vec3 axis{0, 0, 1}; ///< Vibration direction or rotation axis (need not be normalized).
+	};
+
 	/**
 	 * @class Target
 	 * @brief Base class for radar targets.
@@ -158,9 +205,101 @@ namespace radar
 		 */
 		[[nodiscard]] const RcsModel* getFluctuationModel() const { return _model.get(); }
 
+		/**
+		 * @brief Sets or clears the time over which a fluctuating RCS stays correlated.
+		 *
+		 * With a correlation time, the fluctuation model is sampled once per interval of this
+		 * length (counted from the simulation start) and every echo in the interval shares that
+		 * sample. Setting it to the scan or dwell time gives scan-to-scan fluctuation, which with
+		 * a chi-square model of `k = 1` or `k = 2` is Swerling I or III. Without one, the model
+		 * is sampled for every echo, which gives pulse-to-pulse fluctuation (Swerling II or IV).
+		 *
+		 * @param time The correlation time in seconds, or `std::nullopt` to sample every echo.
+		 * @throws std::runtime_error If the time is not positive or an RCS sample rate is set.
+		 */
+		void setDecorrelationTime(std::optional<RealType> time);
+
+		/**
+		 * @brief Gets the time over which a fluctuating RCS stays correlated.
+		 * @return The correlation time in seconds, or `std::nullopt` if every echo is sampled independently.
+		 */
+		[[nodiscard]] std::optional<RealType> getDecorrelationTime() const noexcept { return _decorrelation_time; }
+
+		/**
+		 * @brief Sets or clears the rate at which a fluctuating RCS is redrawn.
+		 *
+		 * This is an alternative to the decorrelation time: the fluctuation model is sampled once
+		 * per `1 / rate` seconds. A target may use one or the other, not both.
+		 *
+		 * @param rate The sample rate in Hz, or `std::nullopt` to clear it.
+		 * @throws std::runtime_error If the rate is not positive or a decorrelation time is set.
+		 */
+		void setRcsSampleRate(std::optional<RealType> rate);
+
+		/**
+		 * @brief Gets the rate at which a fluctuating RCS is redrawn.
+		 * @return The sample rate in Hz, or `std::nullopt` if none is set.
+		 */
+		[[nodiscard]] std::optional<RealType> getRcsSampleRate() const noexcept { return _rcs_sample_rate; }
+
+		/**
+		 * @brief Sets or clears the Swerling case the target's fluctuation model represents.
+		 *
+		 * The case is descriptive: the chi-square `k` it implies is given by `swerlingChiSquareK`,
+		 * and whether the fluctuation is scan-to-scan or pulse-to-pulse is set by the decorrelation
+		 * time or RCS sample rate.
+		 *
+		 * @param swerlingCase The Swerling case (1-4), or `std::nullopt` to clear it.
+		 * @throws std::runtime_error If the case is outside 1-4.
+		 */
+		void setSwerlingCase(std::optional<unsigned> swerlingCase);
+
+		/**
+		 * @brief Gets the Swerling case the target's fluctuation model represents.
+		 * @return The Swerling case (1-4), or `std::nullopt` if none is set.
+		 */
+		[[nodiscard]] std::optional<unsigned> getSwerlingCase() const noexcept { return _swerling_case; }
+
+		/**
+		 * @brief Sets or clears the micro-motion of the target's scattering centre.
+		 *
+		 * @param motion The micro-motion, or `std::nullopt` for a rigid target.
+		 * @throws std::runtime_error If the axis is zero or the amplitude or frequency is negative.
+		 */
+		void setMicroMotion(const std::optional<MicroMotion>& motion);
+
+		/**
+		 * @brief Gets the micro-motion of the target's scattering centre.
+		 * @return The micro-motion, or `std::nullopt` for a rigid target.
+		 */
+		[[nodiscard]] const std::optional<MicroMotion>& getMicroMotion() const noexcept { return _micro_motion; }
+
+		/**
+		 * @brief Gets the micro-motion displacement of the scattering centre at a given time.
+		 *
+		 * @param time The simulation time in seconds.
+		 * @return The displacement from the platform position, or zero for a rigid target.
+		 */
+		[[nodiscard]] math::Vec3 getMicroMotionOffset(RealType time) const noexcept;
+
 	protected:
+		/**
+		 * @brief Samples the fluctuation model, honouring the decorrelation time.
+		 *
+		 * @param time The simulation time of the echo.
+		 * @return The RCS scale factor, or 1 if the target has no fluctuation model.
+		 */
+		[[nodiscard]] RealType sampleFluctuation(RealType time) const;
+
 		std::unique_ptr<RcsModel> _model{nullptr}; ///< The RCS fluctuation model for the target.
 		std::mt19937 _rng; ///< Per-object random number generator for statistical independence.
+		std::optional<MicroMotion> _micro_motion; ///< Optional periodic motion of the scattering centre.
+		std::optional<RealType> _decorrelation_time; ///< Optional correlation time of the RCS fluctuation.
+		std::optional<RealType> _rcs_sample_rate; ///< Optional redraw rate of the RCS fluctuation.
+		std::optional<unsigned> _swerling_case; ///< Optional Swerling case of the fluctuation model.
+		mutable std::mutex _fluctuation_mutex; ///< Guards the held fluctuation sample.
+		mutable std::optional<long long> _fluctuation_interval; ///< Interval index of the held sample.
+		mutable RealType _fluctuation_sample = 1.0; ///< The sample held for the current interval.
 	};
 
 	/**
@@ -187,9 +326,10 @@ namespace radar
 		/**
 		 * @brief Gets the constant RCS value.
 		 *
+		 * @param time The simulation time, which selects the held sample of a correlated fluctuation.
 		 * @return The constant RCS value, possibly modified by the fluctuation model.
 		 */
-		RealType getRcs(math::SVec3& /*inAngle*/, math::SVec3& /*outAngle*/, RealType /*time*/) const noexcept override;
+		RealType getRcs(math::SVec3& /*inAngle*/, math::SVec3& /*outAngle*/, RealType time) const noexcept override;
 
 		/**
 		 * @brief Gets the constant RCS value (without fluctuation model applied).
@@ -202,6 +342,33 @@ namespace radar
 		RealType _rcs; ///< The constant RCS value for the target.
 	};
 
+	/**
+	 * @struct RcsPattern
+	 * @brief The separable RCS cuts read from a target description file.
+	 *
+	 * The RCS at an aspect is the product of the azimuth and elevation cuts at its angles.
+	 */
+	struct RcsPattern
+	{
+		std::vector<std::pair<RealType, RealType>> azimuth; ///< (angle in radians, RCS) samples by angle.
+		std::vector<std::pair<RealType, RealType>> elevation; ///< (angle in radians, RCS) samples by angle.
+	};
+
+	/**
+	 * @brief Reads the RCS cuts of a target description file.
+	 *
+	 * The file holds an `<azimuth>` and an `<elevation>` axis, each with at least one
+	 * `<rcssample>` of an `<angle>` in radians and a non-negative `<rcs>`.
+	 *
+	 * @param filename The path to the target description file.
+	 * @param allowRepeatedAngles If true, a repeated angle is logged as a warning and only its first
+	 *                            sample is kept, instead of being rejected.
+	 * @return The cuts, each sorted by angle.
+	 * @throws std::runtime_error If the file is missing, is not valid XML, lacks either axis,
+	 *         or holds a sample that is incomplete, non-numeric, negative or at a repeated angle.
+	 */
+	[[nodiscard]] RcsPattern loadRcsPattern(const std::string& filename, bool allowRepeatedAngles = false);
+
 	/**
 	 * @class FileTarget
 	 * @brief File-based radar target.
@@ -247,10 +414,28 @@ namespace radar
 		 */
 		[[nodiscard]] const std::string& getFilename() const noexcept { return _filename; }
 
+		/**
+		 * @brief Gets the interpolation used between RCS samples.
+		 * @return The interpolation mode.
+		 */
+		[[nodiscard]] interp::InterpMode getInterpolation() const noexcept { return _interpolation; }
+
+		/**
+		 * @brief Sets the interpolation used between RCS samples.
+		 *
+		 * Nearest-neighbour gives a stepped pattern that reproduces the measured samples exactly, linear
+		 * (the default) joins them with straight segments, and cubic produces a smooth pattern that may
+		 * overshoot between samples near sharp peaks. Negative cubic estimates are clamped to zero.
+		 *
+		 * @param mode The interpolation mode.
+		 */
+		void setInterpolation(const interp::InterpMode mode) noexcept { _interpolation = mode; }
+
 	private:
 		std::unique_ptr<interp::InterpSet> _azi_samples; ///< The azimuthal RCS samples.
 		std::unique_ptr<interp::InterpSet> _elev_samples; ///< The elevation RCS samples.
 		std::string _filename; ///< The original filename for the RCS data.
+		interp::InterpMode _interpolation{interp::InterpMode::LINEAR}; ///< Interpolation between RCS samples.
 	};
 
 	/**
@@ -12,10 +12,64 @@
 
 #include "transmitter.h"
 
+#include <cmath>
+#include <stdexcept>
+
 #include "core/parameters.h"
+#include "signal/radar_signal.h"
 
 namespace radar
 {
+	RealType hopCarrier(const HopSequence& sequence, const std::size_t pulseIndex)
+	{
+		if (sequence.frequencies.empty())
+		{
+			throw std::out_of_range("Hop sequence has no frequencies.");
+		}
+		if (sequence.pattern.empty())
+		{
+			return sequence.frequencies[pulseIndex % sequence.frequencies.size()];
+		}
+		const unsigned index = sequence.pattern[pulseIndex % sequence.pattern.size()];
+		if (index >= sequence.frequencies.size())
+		{
+			throw std::out_of_range("Hop pattern index " + std::to_string(index) + " is outside the hop-set.");
+		}
+		return sequence.frequencies[index];
+	}
+
+	void validateHopSequence(const HopSequence& sequence, const std::string& ownerName)
+	{
+		if (sequence.frequencies.empty())
+		{
+			throw std::runtime_error("Hop sequence of transmitter '" + ownerName + "' has no frequencies.");
+		}
+		for (const RealType frequency : sequence.frequencies)
+		{
+			if (!(frequency > 0) || !std::isfinite(frequency))
+			{
+				throw std::runtime_error("Hop frequencies of transmitter '" + ownerName + "' must be positive.");
+			}
+		}
+		for (const unsigned index : sequence.pattern)
+		{
+			if (index >= sequence.frequencies.size())
+			{
+				throw std::runtime_error("Hop pattern index " + std::to_string(index) + " of transmitter '" +
+										 ownerName + "' is outside the hop-set.");
+			}
+		}
+	}
+
+	void validateMaxDutyCycle(const RealType limit, const std::string& ownerName)
+	{
+		if (!(limit > 0 && limit <= 1))
+		{
+			throw std::runtime_error("Maximum duty cycle of transmitter '" + ownerName +
+									 "' must be greater than 0 and at most 1.");
+		}
+	}
+
 	void Transmitter::setPrf(const RealType mprf) noexcept
 	{
 		const RealType rate = params::rate() * params::oversampleRatio();
@@ -54,4 +108,22 @@ namespace radar
 		// Time is after the last scheduled period.
 		return std::nullopt;
 	}
+
+	RealType Transmitter::getPulseCarrier(const std::size_t pulseIndex) const
+	{
+		if (_hop_sequence)
+		{
+			return hopCarrier(*_hop_sequence, pulseIndex);
+		}
+		return _signal ? _signal->getCarrier() : 0.0;
+	}
+
+	RealType Transmitter::getDutyCycle() const noexcept
+	{
+		if (_mode == OperationMode::CW_MODE)
+		{
+			return 1.0;
+		}
+		return _signal ? _signal->getLength() * _prf : 0.0;
+	}
 }
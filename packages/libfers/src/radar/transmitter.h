@@ -12,7 +12,10 @@
 
 #pragma once
 
+#include <cstddef>
 #include <optional>
+#include <string>
+#include <vector>
 
 #include "radar_obj.h"
 #include "schedule_period.h"
@@ -24,6 +27,48 @@ namespace fers_signal
 
 namespace radar
 {
+	/**
+	 * @struct HopSequence
+	 * @brief A per-pulse carrier frequency hopping specification.
+	 *
+	 * Pulses cycle through `pattern`, whose entries index into `frequencies`. An empty
+	 * pattern cycles through the frequencies in the order they are listed.
+	 */
+	struct HopSequence
+	{
+		std::vector<RealType> frequencies; ///< The hop-set of carrier frequencies in Hz.
+		std::vector<unsigned> pattern; ///< Optional hop pattern of indices into `frequencies`.
+	};
+
+	/**
+	 * @brief Gets the carrier frequency of a pulse in a hop sequence.
+	 *
+	 * @param sequence The hop sequence. Its frequencies must not be empty.
+	 * @param pulseIndex The zero-based index of the pulse.
+	 * @return The carrier frequency of the pulse in Hz.
+	 * @throws std::out_of_range If the sequence has no frequencies or a pattern entry is out of range.
+	 */
+	[[nodiscard]] RealType hopCarrier(const HopSequence& sequence, std::size_t pulseIndex);
+
+	/**
+	 * @brief Checks that a hop sequence can be used by a transmitter.
+	 *
+	 * @param sequence The hop sequence to check.
+	 * @param ownerName The transmitter name used in error messages.
+	 * @throws std::runtime_error If the hop-set is empty, a frequency is not positive, or a pattern
+	 *         entry does not index into the hop-set.
+	 */
+	void validateHopSequence(const HopSequence& sequence, const std::string& ownerName);
+
+	/**
+	 * @brief Checks that a maximum duty cycle is a usable limit.
+	 *
+	 * @param limit The maximum duty cycle as a fraction of time transmitting.
+	 * @param ownerName The transmitter name used in error messages.
+	 * @throws std::runtime_error If the limit is not in the range (0, 1].
+	 */
+	void validateMaxDutyCycle(RealType limit, const std::string& ownerName);
+
 	/**
 	 * @class Transmitter
 	 * @brief Represents a radar transmitter system.
@@ -122,6 +167,45 @@ namespace radar
 		 */
 		[[nodiscard]] std::optional<RealType> getNextPulseTime(RealType time) const;
 
+		/**
+		 * @brief Gets the transmitter's frequency hopping sequence, if any.
+		 * @return The optional hop sequence.
+		 */
+		[[nodiscard]] const std::optional<HopSequence>& getHopSequence() const noexcept { return _hop_sequence; }
+
+		/**
+		 * @brief Sets or clears the transmitter's frequency hopping sequence.
+		 * @param sequence The hop sequence, or `std::nullopt` to use the waveform carrier for every pulse.
+		 */
+		void setHopSequence(std::optional<HopSequence> sequence) noexcept { _hop_sequence = std::move(sequence); }
+
+		/**
+		 * @brief Gets the carrier frequency of an emitted pulse.
+		 *
+		 * @param pulseIndex The zero-based index of the pulse emitted by this transmitter.
+		 * @return The hopped carrier in Hz, or the waveform carrier when the transmitter does not hop.
+		 */
+		[[nodiscard]] RealType getPulseCarrier(std::size_t pulseIndex) const;
+
+		/**
+		 * @brief Gets the fraction of time the transmitter is emitting.
+		 *
+		 * @return The pulse length times the PRF in pulsed mode, or 1 in CW mode.
+		 */
+		[[nodiscard]] RealType getDutyCycle() const noexcept;
+
+		/**
+		 * @brief Gets the hardware duty-cycle limit of the transmitter, if any.
+		 * @return The optional maximum duty cycle as a fraction in (0, 1].
+		 */
+		[[nodiscard]] std::optional<RealType> getMaxDutyCycle() const noexcept { return _max_duty_cycle; }
+
+		/**
+		 * @brief Sets or clears the hardware duty-cycle limit of the transmitter.
+		 * @param limit The maximum duty cycle as a fraction, or `std::nullopt` for no limit.
+		 */
+		void setMaxDutyCycle(const std::optional<RealType> limit) noexcept { _max_duty_cycle = limit; }
+
 	private:
 		fers_signal::RadarSignal* _signal = nullptr; ///< Pointer to the radar signal being transmitted.
 
@@ -129,5 +213,8 @@ namespace radar
 
 		OperationMode _mode; ///< The operational mode of the transmitter.
 		std::vector<SchedulePeriod> _schedule; ///< The schedule of active periods.
+
+		std::optional<HopSequence> _hop_sequence; ///< Optional per-pulse carrier hopping.
+		std::optional<RealType> _max_duty_cycle; ///< Optional hardware duty-cycle limit.
 	};
 }
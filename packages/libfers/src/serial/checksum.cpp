@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+/**
+ * @file checksum.cpp
+ * @brief Implementation of SHA-256 scenario checksums (FIPS 180-4).
+ */
+
+#include "checksum.h"
+
+#include <array>
+#include <bit>
+#include <cstddef>
+#include <cstdint>
+#include <format>
+
+namespace
+{
+	constexpr std::string_view CHECKSUM_PREFIX = "<!-- fers-checksum sha256:";
+	constexpr std::string_view CHECKSUM_SUFFIX = " -->";
+
+	constexpr std::array<std::uint32_t, 64> ROUND_CONSTANTS = {
+		0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+		0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+		0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+		0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+		0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+		0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+		0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+		0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2};
+
+	/**
+	 * @brief Applies the SHA-256 compression function to one 64-byte block.
+	 */
+	void compressBlock(std::array<std::uint32_t, 8>& state, const unsigned char* block)
+	{
+		std::array<std::uint32_t, 64> w{};
+		for (std::size_t i = 0; i < 16; ++i)
+		{
+			w[i] = static_cast<std::uint32_t>(block[4 * i]) << 24 | static_cast<std::uint32_t>(block[4 * i + 1]) << 16 |
+				static_cast<std::uint32_t>(block[4 * i + 2]) << 8 | static_cast<std::uint32_t>(block[4 * i + 3]);
+		}
+		for (std::size_t i = 16; i < 64; ++i)
+		{
+			const std::uint32_t s0 = std::rotr(w[i - 15], 7) ^ std::rotr(w[i - 15], 18) ^ (w[i - 15] >> 3);
+			const std::uint32_t s1 = std::rotr(w[i - 2], 17) ^ std::rotr(w[i - 2], 19) ^ (w[i - 2] >> 10);
+			w[i] = w[i - 16] + s0 + w[i - 7] + s1;
+		}
+
+		auto [a, b, c, d, e, f, g, h] = state;
+		for (std::size_t i = 0; i < 64; ++i)
+		{
+			const std::uint32_t s1 = std::rotr(e, 6) ^ std::rotr(e, 11) ^ std::rotr(e, 25);
+			const std::uint32_t choice = (e & f) ^ (~e & g);
+			const std::uint32_t t1 = h + s1 + choice + ROUND_CONSTANTS[i] + w[i];
+			const std::uint32_t s0 = std::rotr(a, 2) ^ std::rotr(a, 13) ^ std::rotr(a, 22);
+			const std::uint32_t majority = (a & b) ^ (a & c) ^ (b & c);
+			const std::uint32_t t2 = s0 + majority;
+			h = g;
+			g = f;
+			f = e;
+			e = d + t1;
+			d = c;
+			c = b;
+			b = a;
+			a = t1 + t2;
+		}
+		const std::array<std::uint32_t, 8> working = {a, b, c, d, e, f, g, h};
+		for (std::size_t i = 0; i < 8; ++i)
+		{
+			state[i] += working[i];
+		}
+	}
+}
+
+namespace serial
+{
+	std::string sha256Hex(const std::string_view data)
+	{
+		std::array<std::uint32_t, 8> state = {0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a,
+											  0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19};
+
+		const auto* bytes = reinterpret_cast<const unsigned char*>(data.data());
+		std::size_t offset = 0;
+		for (; offset + 64 <= data.size(); offset += 64)
+		{
+			compressBlock(state, bytes + offset);
+		}
+
+		// Pad the tail with a single 1 bit, zeros and the message length in bits
+		std::array<unsigned char, 128> tail{};
+		const std::size_t remaining = data.size() - offset;
+		for (std::size_t i = 0; i < remaining; ++i)
+		{
+			tail[i] = bytes[offset + i];
+		}
+		tail[remaining] = 0x80;
+		const std::size_t tail_size = remaining < 56 ? 64 : 128;
+		const std::uint64_t bit_length = static_cast<std::uint64_t>(data.size()) * 8;
+		for (std::size_t i = 0; i < 8; ++i)
+		{
+			tail[tail_size - 1 - i] = static_cast<unsigned char>(bit_length >> (8 * i));
+		}
+		for (std::size_t block = 0; block < tail_size; block += 64)
+		{
+			compressBlock(state, tail.data() + block);
+		}
+
+		std::string hex;
+		hex.reserve(64);
+		for (const std::uint32_t word : state)
+		{
+			hex += std::format("{:08x}", word);
+		}
+		return hex;
+	}
+
+	std::string appendScenarioChecksum(std::string xml)
+	{
+		const std::string digest = sha256Hex(xml);
+		xml.append(CHECKSUM_PREFIX).append(digest).append(CHECKSUM_SUFFIX).append("\n");
+		return xml;
+	}
+
+	std::optional<bool> verifyScenarioChecksum(const std::string_view xml)
+	{
+		const std::size_t start = xml.rfind(CHECKSUM_PREFIX);
+		if (start == std::string_view::npos)
+		{
+			return std::nullopt;
+		}
+		const std::string_view footer = xml.substr(start + CHECKSUM_PREFIX.size());
+		const std::size_t end = footer.find(CHECKSUM_SUFFIX);
+		if (end == std::string_view::npos)
+		{
+			return false;
+		}
+		// Only the line break written after the comment may follow it
+		if (footer.substr(end + CHECKSUM_SUFFIX.size()).find_first_not_of(" \t\r\n") != std::string_view::npos)
+		{
+			return false;
+		}
+		return footer.substr(0, end) == sha256Hex(xml.substr(0, start));
+	}
+}
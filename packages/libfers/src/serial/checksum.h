@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+/**
+ * @file checksum.h
+ * @brief SHA-256 checksums for detecting corrupted or hand-edited scenario files.
+ *
+ * A checksummed scenario ends with a comment of the form
+ * `<!-- fers-checksum sha256:<hex> -->` after the root element. The digest covers every
+ * byte of the document before the comment, so any change to the scenario text, including
+ * whitespace, is detected.
+ */
+
+#pragma once
+
+#include <optional>
+#include <string>
+#include <string_view>
+
+namespace serial
+{
+	/**
+	 * @brief Computes the SHA-256 digest of a byte string.
+	 * @param data The bytes to hash.
+	 * @return The digest as 64 lowercase hexadecimal characters.
+	 */
+	[[nodiscard]] std::string sha256Hex(std::string_view data);
+
+	/**
+	 * @brief Appends a checksum comment covering the whole of a serialized scenario.
+	 * @param xml The serialized scenario.
+	 * @return The scenario followed by its checksum comment.
+	 */
+	[[nodiscard]] std::string appendScenarioChecksum(std::string xml);
+
+	/**
+	 * @brief Checks the checksum comment of a serialized scenario, if it has one.
+	 * @param xml The serialized scenario, as read from its file.
+	 * @return Nothing if the scenario carries no checksum, otherwise whether the checksum matches.
+	 */
+	[[nodiscard]] std::optional<bool> verifyScenarioChecksum(std::string_view xml);
+}
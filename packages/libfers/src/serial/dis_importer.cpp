@@ -0,0 +1,218 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+/**
+ * @file dis_importer.cpp
+ * @brief Implementation of the DIS Entity State PDU importer.
+ */
+
+#include "dis_importer.h"
+
+#include <GeographicLib/Geocentric.hpp>
+#include <GeographicLib/UTMUPS.hpp>
+#include <algorithm>
+#include <array>
+#include <bit>
+#include <cstdint>
+#include <format>
+#include <fstream>
+#include <iterator>
+#include <map>
+#include <memory>
+#include <optional>
+#include <stdexcept>
+#include <tuple>
+#include <type_traits>
+#include <vector>
+
+#include "core/logging.h"
+#include "core/parameters.h"
+#include "core/world.h"
+#include "math/coord.h"
+#include "math/geodesy.h"
+#include "math/path.h"
+#include "math/rotation_path.h"
+#include "radar/platform.h"
+
+using logging::Level;
+
+namespace
+{
+	constexpr std::size_t HEADER_SIZE = 12;
+	constexpr std::size_t ENTITY_STATE_SIZE = 144;
+	constexpr std::uint8_t ENTITY_STATE_TYPE = 1;
+
+	// Byte offsets within an Entity State PDU
+	constexpr std::size_t PDU_TYPE_OFFSET = 2;
+	constexpr std::size_t TIMESTAMP_OFFSET = 4;
+	constexpr std::size_t LENGTH_OFFSET = 8;
+	constexpr std::size_t ENTITY_ID_OFFSET = 12;
+	constexpr std::size_t LOCATION_OFFSET = 48;
+	constexpr std::size_t MARKING_OFFSET = 129; // Skips the character set byte
+	constexpr std::size_t MARKING_LENGTH = 11;
+
+	/// DIS timestamps count units of 3600 / 2^31 seconds past the hour, above an absolute/relative flag bit.
+	constexpr RealType TIMESTAMP_UNIT = 3600.0 / 2147483648.0;
+
+	using EntityId = std::tuple<std::uint16_t, std::uint16_t, std::uint16_t>;
+
+	struct DisEntity
+	{
+		EntityId id;
+		std::string marking;
+		std::vector<math::Coord> waypoints;
+	};
+
+	template <typename T>
+	T readBigEndian(const std::vector<char>& data, const std::size_t offset)
+	{
+		using Bits = std::conditional_t<sizeof(T) == 8, std::uint64_t,
+										std::conditional_t<sizeof(T) == 4, std::uint32_t, std::uint16_t>>;
+		Bits value = 0;
+		for (std::size_t i = 0; i < sizeof(T); ++i)
+		{
+			value = static_cast<Bits>(value << 8 | static_cast<std::uint8_t>(data[offset + i]));
+		}
+		return std::bit_cast<T>(value);
+	}
+
+	/**
+	 * @brief Converts a geocentric location into the scenario coordinate frame.
+	 */
+	math::Vec3 toScenarioFrame(const math::Vec3& ecef)
+	{
+		switch (params::coordinateFrame())
+		{
+		case params::CoordinateFrame::ECEF:
+			return ecef;
+		case params::CoordinateFrame::UTM:
+			{
+				RealType lat, lon, alt, easting, northing, gamma, k;
+				GeographicLib::Geocentric::WGS84().Reverse(ecef.x, ecef.y, ecef.z, lat, lon, alt);
+				int zone;
+				bool north;
+				GeographicLib::UTMUPS::Forward(lat, lon, zone, north, easting, northing, gamma, k, params::utmZone());
+				return {easting, northing, alt};
+			}
+		case params::CoordinateFrame::ENU:
+			break;
+		}
+		return math::ecefToLocal(ecef, params::originLatitude(), params::originLongitude(), params::originAltitude());
+	}
+}
+
+namespace serial
+{
+	std::size_t importDisEntityStates(core::World& world, const std::string& filename)
+	{
+		std::ifstream file(filename, std::ios::binary);
+		if (!file)
+		{
+			throw std::runtime_error("Could not open DIS log file: " + filename);
+		}
+		const std::vector<char> data{std::istreambuf_iterator(file), std::istreambuf_iterator<char>()};
+
+		std::vector<DisEntity> entities;
+		std::map<EntityId, std::size_t> entity_index;
+		std::optional<RealType> first_time;
+		RealType last_time = 0;
+		RealType hour_offset = 0;
+
+		std::size_t offset = 0;
+		while (offset < data.size())
+		{
+			if (data.size() - offset < HEADER_SIZE)
+			{
+				throw std::runtime_error(std::format("Truncated PDU header at byte {} of '{}'", offset, filename));
+			}
+			const auto length = readBigEndian<std::uint16_t>(data, offset + LENGTH_OFFSET);
+			if (length < HEADER_SIZE || data.size() - offset < length)
+			{
+				throw std::runtime_error(std::format("Truncated PDU at byte {} of '{}'", offset, filename));
+			}
+			if (static_cast<std::uint8_t>(data[offset + PDU_TYPE_OFFSET]) != ENTITY_STATE_TYPE)
+			{
+				offset += length;
+				continue;
+			}
+			if (length < ENTITY_STATE_SIZE)
+			{
+				throw std::runtime_error(
+					std::format("Entity State PDU at byte {} of '{}' is too short", offset, filename));
+			}
+
+			// Timestamps wrap every hour; a large backwards step is taken to be a wrap
+			RealType time = (readBigEndian<std::uint32_t>(data, offset + TIMESTAMP_OFFSET) >> 1) * TIMESTAMP_UNIT;
+			if (first_time && time + hour_offset < last_time - 1800.0)
+			{
+				hour_offset += 3600.0;
+			}
+			time += hour_offset;
+			last_time = time;
+			if (!first_time)
+			{
+				first_time = time;
+			}
+
+			const EntityId id{readBigEndian<std::uint16_t>(data, offset + ENTITY_ID_OFFSET),
+							  readBigEndian<std::uint16_t>(data, offset + ENTITY_ID_OFFSET + 2),
+							  readBigEndian<std::uint16_t>(data, offset + ENTITY_ID_OFFSET + 4)};
+			auto [it, inserted] = entity_index.try_emplace(id, entities.size());
+			if (inserted)
+			{
+				const auto marking_start = data.begin() + static_cast<std::ptrdiff_t>(offset + MARKING_OFFSET);
+				std::string marking(marking_start, marking_start + MARKING_LENGTH);
+				marking = marking.substr(0, marking.find('\0'));
+				marking.erase(marking.find_last_not_of(' ') + 1);
+				entities.push_back({id, std::move(marking), {}});
+			}
+
+			const math::Vec3 ecef{readBigEndian<double>(data, offset + LOCATION_OFFSET),
+								  readBigEndian<double>(data, offset + LOCATION_OFFSET + 8),
+								  readBigEndian<double>(data, offset + LOCATION_OFFSET + 16)};
+			entities[it->second].waypoints.push_back(
+				{.pos = toScenarioFrame(ecef), .t = params::startTime() + time - *first_time});
+			offset += length;
+		}
+
+		// Name every entity and check for clashes before touching the world
+		std::vector<std::string> names;
+		for (const auto& [id, marking, waypoints] : entities)
+		{
+			const bool shared_marking = std::ranges::count(entities, marking, &DisEntity::marking) > 1;
+			const auto& [site, application, entity] = id;
+			names.push_back(marking.empty() || shared_marking
+								? std::format("DIS {}:{}:{}", site, application, entity)
+								: marking);
+			if (world.findPlatform(names.back()))
+			{
+				throw std::runtime_error("A platform named '" + names.back() + "' already exists");
+			}
+		}
+
+		for (std::size_t i = 0; i < entities.size(); ++i)
+		{
+			auto platform = std::make_unique<radar::Platform>(names[i]);
+
+			auto path = std::make_unique<math::Path>(entities[i].waypoints.size() > 1
+														 ? math::Path::InterpType::INTERP_LINEAR
+														 : math::Path::InterpType::INTERP_STATIC);
+			for (const auto& waypoint : entities[i].waypoints)
+			{
+				path->addCoord(waypoint);
+			}
+			path->finalize();
+			platform->setMotionPath(std::move(path));
+
+			// Face north (compass azimuth 0), which is 90 degrees CCW from East internally
+			auto rotation = std::make_unique<math::RotationPath>();
+			rotation->setConstantRate({PI / 2, 0, 0}, {0, 0, 0});
+			rotation->finalize();
+			platform->setRotationPath(std::move(rotation));
+
+			LOG(Level::INFO, "Imported DIS entity '{}' with {} waypoints", names[i], entities[i].waypoints.size());
+			world.add(std::move(platform));
+		}
+		return entities.size();
+	}
+}
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+/**
+ * @file dis_importer.h
+ * @brief Import of platforms from captured DIS Entity State PDUs.
+ */
+
+#pragma once
+
+#include <cstddef>
+#include <string>
+
+namespace core
+{
+	class World;
+}
+
+namespace serial
+{
+	/**
+	 * @brief Creates a platform for each entity in a captured log of DIS PDUs.
+	 *
+	 * The log is a sequence of raw, big-endian IEEE 1278.1 PDUs, each sized by the length
+	 * field of its header. Only Entity State PDUs (type 1) are used; other PDU types are
+	 * skipped. Each unique entity id becomes a platform named after the entity marking, or
+	 * `DIS site:application:entity` if the marking is empty or shared. Successive location
+	 * updates become linearly interpolated waypoints, timed relative to the first PDU in the
+	 * log and offset by the scenario start time. Geocentric locations are converted into the
+	 * scenario coordinate frame using the scenario origin (ENU) or UTM zone. Orientation is
+	 * not imported; the platforms face north.
+	 *
+	 * @param world The simulation world to add the platforms to.
+	 * @param filename The path of the PDU log.
+	 * @return The number of platforms created.
+	 * @throws std::runtime_error If the file cannot be read, a PDU is truncated, or a
+	 *         platform with an imported name already exists. The world is unchanged on error.
+	 */
+	std::size_t importDisEntityStates(core::World& world, const std::string& filename);
+}
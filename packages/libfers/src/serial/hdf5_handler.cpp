@@ -13,6 +13,7 @@
 #include "hdf5_handler.h"
 
 #include <algorithm>
+#include <cmath>
 #include <complex>
 #include <filesystem>
 #include <format>
@@ -74,11 +75,13 @@ namespace serial
 	}
 
 	void addChunkToFile(HighFive::File& file, const std::vector<ComplexType>& data, const RealType time,
-						const RealType fullscale, const unsigned count)
+						const RealType fullscale, const unsigned count,
+						const std::vector<std::pair<RealType, RealType>>& blanked, const std::string& group)
 	{
 		const unsigned size = data.size();
 
-		const std::string base_chunk_name = "chunk_" + std::format("{:06}", count);
+		// Dataset paths create their missing parent groups
+		const std::string base_chunk_name = (group.empty() ? "" : group + "/") + "chunk_" + std::format("{:06}", count);
 		const std::string i_chunk_name = base_chunk_name + "_I";
 		const std::string q_chunk_name = base_chunk_name + "_Q";
 
@@ -109,6 +112,17 @@ namespace serial
 				dataset.createAttribute("time", time);
 				dataset.createAttribute("rate", params::rate());
 				dataset.createAttribute("fullscale", fullscale);
+				if (!blanked.empty())
+				{
+					std::vector<RealType> bounds;
+					bounds.reserve(2 * blanked.size());
+					for (const auto& [start, end] : blanked)
+					{
+						bounds.push_back(start);
+						bounds.push_back(end);
+					}
+					dataset.createAttribute("blanked", bounds);
+				}
 			}
 			catch (const HighFive::Exception& err)
 			{
@@ -158,4 +172,228 @@ namespace serial
 			throw std::runtime_error("Error handling HDF5 file: " + std::string(err.what()));
 		}
 	}
+
+	OutputComparison compareOutputFiles(const std::string& actual, const std::string& reference)
+	{
+		for (const auto& name : {actual, reference})
+		{
+			if (!std::filesystem::exists(name))
+			{
+				LOG(Level::ERROR, "File '{}' not found", name);
+				throw std::runtime_error("File " + name + " not found.");
+			}
+		}
+
+		try
+		{
+			const HighFive::File actual_file(actual, HighFive::File::ReadOnly);
+			const HighFive::File reference_file(reference, HighFive::File::ReadOnly);
+
+			auto list_datasets = [](const HighFive::File& file)
+			{
+				std::vector<std::string> names;
+				for (auto& name : file.listObjectNames())
+				{
+					if (file.getObjectType(name) == HighFive::ObjectType::Dataset)
+					{
+						names.push_back(std::move(name));
+					}
+				}
+				std::ranges::sort(names);
+				return names;
+			};
+
+			const auto actual_names = list_datasets(actual_file);
+			const auto reference_names = list_datasets(reference_file);
+			for (const auto& name : reference_names)
+			{
+				if (!std::ranges::binary_search(actual_names, name))
+				{
+					throw std::runtime_error(
+						std::format("Output structure differs: dataset '{}' is missing from '{}'", name, actual));
+				}
+			}
+			for (const auto& name : actual_names)
+			{
+				if (!std::ranges::binary_search(reference_names, name))
+				{
+					throw std::runtime_error(
+						std::format("Output structure differs: dataset '{}' is missing from '{}'", name, reference));
+				}
+			}
+
+			// Reads a dataset in physical units, undoing the export normalization.
+			auto read_scaled = [](const HighFive::File& file, const std::string& name)
+			{
+				const auto dataset = file.getDataSet(name);
+				std::vector<RealType> data(dataset.getSpace().getDimensions()[0]);
+				dataset.read(data);
+
+				RealType fullscale = 1.0;
+				if (dataset.hasAttribute("fullscale"))
+				{
+					dataset.getAttribute("fullscale").read(fullscale);
+				}
+				else if (file.hasAttribute("fullscale"))
+				{
+					file.getAttribute("fullscale").read(fullscale);
+				}
+				for (auto& value : data)
+				{
+					value *= fullscale;
+				}
+				return data;
+			};
+
+			OutputComparison result{0, 0.0, 0.0};
+			RealType sum_squares = 0.0;
+			for (const auto& i_name : actual_names)
+			{
+				// Pulsed windows are stored as `chunk_NNNNNN_I`/`_Q`, CW data as `I_data`/`Q_data`.
+				std::string q_name;
+				if (i_name == "I_data")
+				{
+					q_name = "Q_data";
+				}
+				else if (i_name.ends_with("_I"))
+				{
+					q_name = i_name.substr(0, i_name.size() - 1) + "Q";
+				}
+				else
+				{
+					continue;
+				}
+				if (!std::ranges::binary_search(actual_names, q_name))
+				{
+					throw std::runtime_error(std::format("Dataset '{}' has no matching '{}'", i_name, q_name));
+				}
+
+				const auto actual_i = read_scaled(actual_file, i_name);
+				const auto actual_q = read_scaled(actual_file, q_name);
+				const auto reference_i = read_scaled(reference_file, i_name);
+				const auto reference_q = read_scaled(reference_file, q_name);
+				if (actual_i.size() != reference_i.size() || actual_q.size() != reference_q.size() ||
+					actual_i.size() != actual_q.size())
+				{
+					throw std::runtime_error(std::format("Output structure differs: window '{}' has {} samples in '{}' "
+														 "but {} in '{}'",
+														 i_name, actual_i.size(), actual, reference_i.size(),
+														 reference));
+				}
+
+				for (size_t k = 0; k < actual_i.size(); ++k)
+				{
+					const RealType diff = std::abs(ComplexType(actual_i[k], actual_q[k]) -
+												   ComplexType(reference_i[k], reference_q[k]));
+					result.max_difference = std::max(result.max_difference, diff);
+					sum_squares += diff * diff;
+				}
+				result.sample_count += actual_i.size();
+			}
+
+			if (result.sample_count > 0)
+			{
+				result.rms_difference = std::sqrt(sum_squares / static_cast<RealType>(result.sample_count));
+			}
+			return result;
+		}
+		catch (const HighFive::Exception& err)
+		{
+			LOG(Level::ERROR, "Error reading HDF5 output: {}", err.what());
+			throw std::runtime_error("Error reading HDF5 output: " + std::string(err.what()));
+		}
+	}
+
+	std::vector<std::pair<RealType, RealType>> readBlankedIntervals(const std::string& name)
+	{
+		if (!std::filesystem::exists(name))
+		{
+			throw std::runtime_error("File " + name + " not found.");
+		}
+
+		try
+		{
+			const HighFive::File file(name, HighFive::File::ReadOnly);
+			std::vector<std::string> chunk_names;
+			for (auto& object_name : file.listObjectNames())
+			{
+				// The I and Q datasets of a chunk carry the same attributes, so read the I half only
+				if (object_name.starts_with("chunk_") && object_name.ends_with("_I"))
+				{
+					chunk_names.push_back(std::move(object_name));
+				}
+			}
+			std::ranges::sort(chunk_names);
+
+			std::vector<std::pair<RealType, RealType>> intervals;
+			for (const auto& chunk_name : chunk_names)
+			{
+				const auto dataset = file.getDataSet(chunk_name);
+				if (!dataset.hasAttribute("blanked"))
+				{
+					continue;
+				}
+				std::vector<RealType> bounds;
+				dataset.getAttribute("blanked").read(bounds);
+				for (size_t i = 0; i + 1 < bounds.size(); i += 2)
+				{
+					intervals.emplace_back(bounds[i], bounds[i + 1]);
+				}
+			}
+			return intervals;
+		}
+		catch (const HighFive::Exception& err)
+		{
+			LOG(Level::ERROR, "Error reading blanked intervals from HDF5 file '{}': {}", name, err.what());
+			throw std::runtime_error("Error reading blanked intervals from HDF5 file " + name + ": " + err.what());
+		}
+	}
+
+	void writeGeometryMetadata(const std::string& name, const std::string& geometryJson)
+	{
+		if (!std::filesystem::exists(name))
+		{
+			throw std::runtime_error("File " + name + " not found.");
+		}
+
+		try
+		{
+			HighFive::File file(name, HighFive::File::ReadWrite);
+			if (file.hasAttribute("geometry"))
+			{
+				file.deleteAttribute("geometry");
+			}
+			file.createAttribute("geometry", geometryJson);
+		}
+		catch (const HighFive::Exception& err)
+		{
+			LOG(Level::ERROR, "Error writing geometry to HDF5 file '{}': {}", name, err.what());
+			throw std::runtime_error("Error writing geometry to HDF5 file " + name + ": " + err.what());
+		}
+	}
+
+	std::string readGeometryMetadata(const std::string& name)
+	{
+		if (!std::filesystem::exists(name))
+		{
+			throw std::runtime_error("File " + name + " not found.");
+		}
+
+		try
+		{
+			const HighFive::File file(name, HighFive::File::ReadOnly);
+			if (!file.hasAttribute("geometry"))
+			{
+				throw std::runtime_error("Output file " + name + " does not contain geometry metadata.");
+			}
+			std::string geometry;
+			file.getAttribute("geometry").read(geometry);
+			return geometry;
+		}
+		catch (const HighFive::Exception& err)
+		{
+			LOG(Level::ERROR, "Error reading geometry from HDF5 file '{}': {}", name, err.what());
+			throw std::runtime_error("Error reading geometry from HDF5 file " + name + ": " + err.what());
+		}
+	}
 }
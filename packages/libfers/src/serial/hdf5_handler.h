@@ -13,6 +13,7 @@
 #pragma once
 
 #include <string>
+#include <utility>
 #include <vector>
 
 #include "core/config.h"
@@ -24,6 +25,16 @@ namespace HighFive
 
 namespace serial
 {
+	/**
+	 * @brief Sample differences between two simulation output files.
+	 */
+	struct OutputComparison
+	{
+		size_t sample_count; ///< Number of I/Q samples compared.
+		RealType max_difference; ///< Largest magnitude of a complex sample difference.
+		RealType rms_difference; ///< Root-mean-square magnitude of the complex sample differences.
+	};
+
 	/**
 	 * @brief Adds a chunk of data to an HDF5 file.
 	 *
@@ -32,10 +43,14 @@ namespace serial
 	 * @param time The time attribute associated with the chunk.
 	 * @param fullscale The fullscale attribute for the chunk.
 	 * @param count The sequential count number for chunk naming.
+	 * @param blanked The (start, end) times of the blanked intervals within the chunk, recorded in
+	 *        its `blanked` attribute as a flat list. No attribute is written if this is empty.
+	 * @param group The group to write the chunk into (created if missing), or empty for the file root.
 	 * @throws std::runtime_error If there is an error writing data or setting attributes.
 	 */
 	void addChunkToFile(HighFive::File& file, const std::vector<ComplexType>& data, RealType time, RealType fullscale,
-						unsigned count);
+						unsigned count, const std::vector<std::pair<RealType, RealType>>& blanked = {},
+						const std::string& group = {});
 
 	/**
 	 * @brief Reads pulse data from an HDF5 file.
@@ -55,4 +70,49 @@ namespace serial
 	 * @throws std::runtime_error If there is an error handling the file or if the dataset dimensions are invalid.
 	 */
 	std::vector<std::vector<RealType>> readPattern(const std::string& name, const std::string& datasetName);
+
+	/**
+	 * @brief Compares the I/Q samples of two receiver output files.
+	 *
+	 * Windows are aligned by dataset name, so both files must contain the same pulsed
+	 * chunks (or the same CW `I_data`/`Q_data` pair) with matching lengths. Samples are
+	 * rescaled by their `fullscale` attribute before differencing, so the comparison is
+	 * independent of the per-window normalization applied on export.
+	 *
+	 * @param actual The name of the HDF5 file under test.
+	 * @param reference The name of the reference HDF5 file.
+	 * @return The maximum and RMS sample difference.
+	 * @throws std::runtime_error If a file is missing, cannot be read, or the two files differ in structure.
+	 */
+	OutputComparison compareOutputFiles(const std::string& actual, const std::string& reference);
+
+	/**
+	 * @brief Reads the blanked intervals recorded in a pulsed receiver's output file.
+	 *
+	 * @param name The name of the HDF5 output file.
+	 * @return The (start, end) times of every blanked interval, in chunk order.
+	 * @throws std::runtime_error If the file does not exist or cannot be read.
+	 */
+	std::vector<std::pair<RealType, RealType>> readBlankedIntervals(const std::string& name);
+
+	/**
+	 * @brief Records the scenario geometry in an existing simulation output file.
+	 *
+	 * The geometry is stored as a JSON string in the file-level `geometry` attribute,
+	 * replacing any geometry already recorded there.
+	 *
+	 * @param name The name of the HDF5 output file.
+	 * @param geometryJson The scenario geometry, as produced by `world_geometry_to_json`.
+	 * @throws std::runtime_error If the file does not exist or cannot be written.
+	 */
+	void writeGeometryMetadata(const std::string& name, const std::string& geometryJson);
+
+	/**
+	 * @brief Reads the scenario geometry recorded in a simulation output file.
+	 *
+	 * @param name The name of the HDF5 output file.
+	 * @return The geometry JSON string written by `writeGeometryMetadata`.
+	 * @throws std::runtime_error If the file does not exist, cannot be read, or carries no geometry metadata.
+	 */
+	std::string readGeometryMetadata(const std::string& name);
 }
@@ -15,9 +15,16 @@
 
 #include "serial/json_serializer.h"
 
+#include <algorithm>
+#include <cctype>
 #include <cmath>
+#include <format>
 #include <nlohmann/json.hpp>
+#include <optional>
 #include <random>
+#include <stdexcept>
+#include <unordered_map>
+#include <unordered_set>
 
 #include "antenna/antenna_factory.h"
 #include "core/parameters.h"
@@ -25,6 +32,7 @@
 #include "math/coord.h"
 #include "math/path.h"
 #include "math/rotation_path.h"
+#include "math/terrain.h"
 #include "radar/platform.h"
 #include "radar/receiver.h"
 #include "radar/target.h"
@@ -87,19 +95,55 @@ namespace math
 	NLOHMANN_JSON_SERIALIZE_ENUM(Path::InterpType,
 								 {{Path::InterpType::INTERP_STATIC, "static"},
 								  {Path::InterpType::INTERP_LINEAR, "linear"},
-								  {Path::InterpType::INTERP_CUBIC, "cubic"}})
+								  {Path::InterpType::INTERP_CUBIC, "cubic"},
+								  {Path::InterpType::INTERP_BALLISTIC, "ballistic"}})
 
+	NLOHMANN_JSON_SERIALIZE_ENUM(Path::WaypointFrame,
+								 {{Path::WaypointFrame::LOCAL, "local"}, {Path::WaypointFrame::ECEF, "ecef"}})
+
+	// JSON waypoints are always local; the waypoint frame is carried along so XML export can restore it.
 	void to_json(nlohmann::json& j, const Path& p)
 	{
-		j = {{"interpolation", p.getType()}, {"positionwaypoints", p.getCoords()}};
+		nlohmann::json waypoints = nlohmann::json::array();
+		for (std::size_t i = 0; i < p.getCoords().size(); ++i)
+		{
+			nlohmann::json& wp = waypoints.emplace_back(p.getCoords()[i]);
+			if (const auto& velocity = p.getWaypointVelocities()[i])
+			{
+				wp["vx"] = velocity->x;
+				wp["vy"] = velocity->y;
+				wp["vz"] = velocity->z;
+			}
+		}
+		j = {{"interpolation", p.getType()}, {"positionwaypoints", waypoints}};
+		if (p.getWaypointFrame() != Path::WaypointFrame::LOCAL)
+		{
+			j["coordinates"] = p.getWaypointFrame();
+		}
+		if (p.getType() == Path::InterpType::INTERP_BALLISTIC)
+		{
+			j["launchvelocity"] = p.getLaunchVelocity();
+			j["gravity"] = p.getGravity();
+		}
 	}
 
 	void from_json(const nlohmann::json& j, Path& p)
 	{
 		p.setInterp(j.at("interpolation").get<Path::InterpType>());
-		for (const auto waypoints = j.at("positionwaypoints").get<std::vector<Coord>>(); const auto& wp : waypoints)
+		p.setWaypointFrame(j.value("coordinates", Path::WaypointFrame::LOCAL));
+		if (p.getType() == Path::InterpType::INTERP_BALLISTIC)
 		{
-			p.addCoord(wp);
+			p.setBallistic(j.at("launchvelocity").get<math::Vec3>(), j.value("gravity", STANDARD_GRAVITY));
+		}
+		for (const auto& wp : j.at("positionwaypoints"))
+		{
+			std::optional<math::Vec3> velocity;
+			if (wp.contains("vx") || wp.contains("vy") || wp.contains("vz"))
+			{
+				velocity = math::Vec3(wp.at("vx").get<RealType>(), wp.at("vy").get<RealType>(),
+									  wp.at("vz").get<RealType>());
+			}
+			p.addCoord(wp.get<Coord>(), velocity);
 		}
 		p.finalize();
 	}
@@ -150,6 +194,14 @@ namespace math
 
 }
 
+namespace interp
+{
+	NLOHMANN_JSON_SERIALIZE_ENUM(InterpMode,
+								 {{InterpMode::NEAREST, "nearest"},
+								  {InterpMode::LINEAR, "linear"},
+								  {InterpMode::CUBIC, "cubic"}})
+}
+
 namespace timing
 {
 	void to_json(nlohmann::json& j, const PrototypeTiming& pt)
@@ -232,6 +284,11 @@ namespace fers_signal
 		{
 			j["cw"] = nlohmann::json::object();
 		}
+		else if (const auto& inline_samples = rs.getInlineSamples())
+		{
+			j["inline_samples"] = {{"rate", inline_samples->rate},
+								   {"samples", serial::formatInlineSamples(inline_samples->samples)}};
+		}
 		else
 		{
 			if (const auto& filename = rs.getFilename(); filename.has_value())
@@ -269,6 +326,13 @@ namespace fers_signal
 			}
 			rs = serial::loadWaveformFromFile(name, filename, power, carrier);
 		}
+		else if (j.contains("inline_samples"))
+		{
+			const auto& inline_samples = j.at("inline_samples");
+			rs = serial::loadWaveformFromSamples(
+				name, serial::parseInlineSamples(inline_samples.at("samples").get<std::string>()),
+				inline_samples.at("rate").get<RealType>(), power, carrier);
+		}
 		else
 		{
 			throw std::runtime_error("Unsupported waveform type in from_json for '" + name + "'");
@@ -305,6 +369,13 @@ namespace antenna
 			j["pattern"] = "parabolic";
 			j["diameter"] = parabolic->getDiameter();
 		}
+		else if (const auto* array = dynamic_cast<const LinearArray*>(&a))
+		{
+			j["pattern"] = "array";
+			j["elements"] = array->getElementCount();
+			j["spacing"] = array->getSpacing();
+			j["taper"] = array->getTaper();
+		}
 		else if (const auto* xml = dynamic_cast<const XmlAntenna*>(&a))
 		{
 			j["pattern"] = "xml";
@@ -315,6 +386,11 @@ namespace antenna
 			j["pattern"] = "file";
 			j["filename"] = h5->getFilename();
 		}
+		else if (const auto* pat = dynamic_cast<const PatAntenna*>(&a))
+		{
+			j["pattern"] = "file";
+			j["filename"] = pat->getFilename();
+		}
 		else
 		{
 			j["pattern"] = "isotropic";
@@ -347,6 +423,12 @@ namespace antenna
 		{
 			ant = std::make_unique<Parabolic>(name, j.at("diameter").get<RealType>());
 		}
+		else if (pattern == "array")
+		{
+			ant = std::make_unique<LinearArray>(name, j.at("elements").get<std::size_t>(),
+												j.at("spacing").get<RealType>(),
+												j.value("taper", std::vector<RealType>{}));
+		}
 		else if (pattern == "xml")
 		{
 			const auto filename = j.value("filename", "");
@@ -362,10 +444,10 @@ namespace antenna
 			const auto filename = j.value("filename", "");
 			if (filename.empty())
 			{
-				LOG(logging::Level::WARNING, "Skipping load of H5 antenna '{}': filename is empty.", name);
+				LOG(logging::Level::WARNING, "Skipping load of file antenna '{}': filename is empty.", name);
 				return; // ant remains nullptr
 			}
-			ant = std::make_unique<H5Antenna>(name, filename);
+			ant = loadAntennaFromFile(name, filename);
 		}
 		else
 		{
@@ -386,6 +468,29 @@ namespace radar
 		j.at("end").get_to(p.end);
 	}
 
+	void to_json(nlohmann::json& j, const BlankingGate& g) { j = {{"start", g.start}, {"length", g.length}}; }
+
+	void from_json(const nlohmann::json& j, BlankingGate& g)
+	{
+		j.at("start").get_to(g.start);
+		j.at("length").get_to(g.length);
+	}
+
+	void to_json(nlohmann::json& j, const HopSequence& h)
+	{
+		j = {{"frequencies", h.frequencies}};
+		if (!h.pattern.empty())
+		{
+			j["pattern"] = h.pattern;
+		}
+	}
+
+	void from_json(const nlohmann::json& j, HopSequence& h)
+	{
+		j.at("frequencies").get_to(h.frequencies);
+		h.pattern = j.value("pattern", std::vector<unsigned>{});
+	}
+
 	void to_json(nlohmann::json& j, const Transmitter& t)
 	{
 		j = nlohmann::json{{"name", t.getName()},
@@ -401,6 +506,22 @@ namespace radar
 		{
 			j["cw_mode"] = nlohmann::json::object();
 		}
+		if (const auto& hops = t.getHopSequence())
+		{
+			j["hop_sequence"] = *hops;
+		}
+		if (const auto limit = t.getMaxDutyCycle())
+		{
+			j["max_duty_cycle"] = *limit;
+		}
+		if (t.getPhaseCenterOffset().length() > 0)
+		{
+			j["phase_center_offset"] = t.getPhaseCenterOffset();
+		}
+		if (t.getMountHeight() != 0)
+		{
+			j["mount_height"] = t.getMountHeight();
+		}
 		if (!t.getSchedule().empty())
 		{
 			j["schedule"] = t.getSchedule();
@@ -425,10 +546,46 @@ namespace radar
 		{
 			j["cw_mode"] = nlohmann::json::object();
 		}
+		if (!r.getCalibrationFile().empty())
+		{
+			j["calibration_file"] = r.getCalibrationFile();
+		}
+		if (r.getPhaseCenterOffset().length() > 0)
+		{
+			j["phase_center_offset"] = r.getPhaseCenterOffset();
+		}
+		if (r.getMountHeight() != 0)
+		{
+			j["mount_height"] = r.getMountHeight();
+		}
 		if (!r.getSchedule().empty())
 		{
 			j["schedule"] = r.getSchedule();
 		}
+		if (!r.getBlankingGates().empty())
+		{
+			j["blanking_gates"] = r.getBlankingGates();
+		}
+		if (!r.getElementArray().empty())
+		{
+			j["element_array"] = r.getElementArray();
+		}
+	}
+
+	NLOHMANN_JSON_SERIALIZE_ENUM(MicroMotion::Type, {{MicroMotion::Type::VIBRATION, "vibration"},
+													 {MicroMotion::Type::ROTATION, "rotation"}})
+
+	void to_json(nlohmann::json& j, const MicroMotion& m)
+	{
+		j = {{"type", m.type}, {"amplitude", m.amplitude}, {"frequency", m.frequency}, {"axis", m.axis}};
+	}
+
+	void from_json(const nlohmann::json& j, MicroMotion& m)
+	{
+		m.type = j.value("type", MicroMotion::Type::VIBRATION);
+		j.at("amplitude").get_to(m.amplitude);
+		j.at("frequency").get_to(m.frequency);
+		j.at("axis").get_to(m.axis);
 	}
 
 	void to_json(nlohmann::json& j, const Target& t)
@@ -444,6 +601,7 @@ namespace radar
 		{
 			rcs_json["type"] = "file";
 			rcs_json["filename"] = file->getFilename();
+			rcs_json["interpolation"] = file->getInterpolation();
 		}
 		j["rcs"] = rcs_json;
 
@@ -455,19 +613,52 @@ namespace radar
 			{
 				model_json["type"] = "chisquare";
 				model_json["k"] = chi_model->getK();
+				if (const auto swerling_case = t.getSwerlingCase())
+				{
+					model_json["swerling"] = *swerling_case;
+				}
 			}
 			else // Default to constant if it's not a recognized type (e.g., RcsConst)
 			{
 				model_json["type"] = "constant";
 			}
+			if (const auto decorrelation_time = t.getDecorrelationTime())
+			{
+				model_json["decorrelation_time"] = *decorrelation_time;
+			}
+			if (const auto sample_rate = t.getRcsSampleRate())
+			{
+				model_json["sample_rate"] = *sample_rate;
+			}
 			j["model"] = model_json;
 		}
+
+		if (const auto& micro_motion = t.getMicroMotion())
+		{
+			j["micromotion"] = *micro_motion;
+		}
 	}
 
 	void to_json(nlohmann::json& j, const Platform& p)
 	{
 		j = {{"name", p.getName()}, {"motionpath", *p.getMotionPath()}};
 
+		if (const auto& origin = p.getOrigin())
+		{
+			j["origin"] = {
+				{"latitude", origin->latitude}, {"longitude", origin->longitude}, {"altitude", origin->altitude}};
+		}
+
+		if (const auto& noise = p.getPositionNoise())
+		{
+			j["position_noise"] = {
+				{"x", noise->std_dev.x},
+				{"y", noise->std_dev.y},
+				{"z", noise->std_dev.z},
+				{"correlation_time", noise->correlation_time},
+				{"seed", noise->seed}};
+		}
+
 		if (p.getRotationPath()->getType() == math::RotationPath::InterpType::INTERP_CONSTANT)
 		{
 			j["fixedrotation"] = *p.getRotationPath();
@@ -487,6 +678,36 @@ namespace params
 								  {CoordinateFrame::UTM, "UTM"},
 								  {CoordinateFrame::ECEF, "ECEF"}})
 
+	void to_json(nlohmann::json& j, const ScenarioMetadata& m)
+	{
+		j = nlohmann::json{{"author", m.author},
+						   {"created", m.created},
+						   {"modified", m.modified},
+						   {"tool_version", m.tool_version}};
+	}
+
+	void from_json(const nlohmann::json& j, ScenarioMetadata& m)
+	{
+		m.author = j.value("author", "");
+		m.created = j.value("created", "");
+		m.modified = j.value("modified", "");
+		m.tool_version = j.value("tool_version", "");
+	}
+
+	void to_json(nlohmann::json& j, const MultipathSurface& m)
+	{
+		j = nlohmann::json{{"factor", m.factor}, {"nx", m.nx}, {"ny", m.ny}, {"nz", m.nz}, {"d", m.d}};
+	}
+
+	void from_json(const nlohmann::json& j, MultipathSurface& m)
+	{
+		j.at("factor").get_to(m.factor);
+		j.at("nx").get_to(m.nx);
+		j.at("ny").get_to(m.ny);
+		j.at("nz").get_to(m.nz);
+		j.at("d").get_to(m.d);
+	}
+
 	void to_json(nlohmann::json& j, const Parameters& p)
 	{
 		j = nlohmann::json{{"starttime", p.start},
@@ -501,6 +722,19 @@ namespace params
 		{
 			j["randomseed"] = p.random_seed.value();
 		}
+		if (p.default_antenna)
+		{
+			j["default_antenna"] = *p.default_antenna;
+		}
+		if (p.default_timing)
+		{
+			j["default_timing"] = *p.default_timing;
+		}
+
+		if (const auto& options = p.export_options; !options.h5 || options.timestamps || options.debug)
+		{
+			j["export"] = {{"h5", options.h5}, {"timestamps", options.timestamps}, {"debug", options.debug}};
+		}
 
 		j["origin"] = {
 			{"latitude", p.origin_latitude}, {"longitude", p.origin_longitude}, {"altitude", p.origin_altitude}};
@@ -523,6 +757,21 @@ namespace params
 		p.adc_bits = j.value("adc_bits", 0);
 		p.oversample_ratio = j.value("oversample", 1);
 		p.random_seed = j.value<std::optional<unsigned>>("randomseed", std::nullopt);
+		if (j.contains("default_antenna"))
+		{
+			p.default_antenna = j.at("default_antenna").get<std::string>();
+		}
+		if (j.contains("default_timing"))
+		{
+			p.default_timing = j.at("default_timing").get<std::string>();
+		}
+
+		if (j.contains("export"))
+		{
+			p.export_options.h5 = j.at("export").value("h5", true);
+			p.export_options.timestamps = j.at("export").value("timestamps", false);
+			p.export_options.debug = j.at("export").value("debug", false);
+		}
 
 		const auto& origin = j.at("origin");
 		p.origin_latitude = origin.at("latitude").get<double>();
@@ -539,6 +788,28 @@ namespace params
 	}
 }
 
+namespace
+{
+	/**
+	 * @brief Reads a component's asset reference, falling back to the scenario default.
+	 *
+	 * @param comp The component JSON object.
+	 * @param key The key of the asset reference (e.g. "antenna").
+	 * @param fallback The scenario-level default for this asset type, if any.
+	 * @return The referenced asset name, or an empty string if neither is set.
+	 */
+	std::string resolve_asset_name(const nlohmann::json& comp, const std::string& key,
+								   const std::optional<std::string>& fallback)
+	{
+		auto name = comp.value(key, "");
+		if (name.empty() && fallback)
+		{
+			name = *fallback;
+		}
+		return name;
+	}
+}
+
 namespace serial
 {
 	nlohmann::json world_to_json(const core::World& world)
@@ -546,7 +817,28 @@ namespace serial
 		nlohmann::json sim_json;
 
 		sim_json["name"] = params::params.simulation_name;
+		if (params::params.metadata)
+		{
+			sim_json["metadata"] = *params::params.metadata;
+		}
+		if (params::params.ui_state)
+		{
+			sim_json["ui_state"] = nlohmann::json::parse(*params::params.ui_state, nullptr, false);
+			if (sim_json["ui_state"].is_discarded())
+			{
+				// Not JSON (e.g. edited by hand in the XML); pass it through as a string
+				sim_json["ui_state"] = *params::params.ui_state;
+			}
+		}
 		sim_json["parameters"] = params::params;
+		if (params::params.multipath)
+		{
+			sim_json["multipath"] = *params::params.multipath;
+		}
+		if (const auto* terrain = world.getTerrain())
+		{
+			sim_json["terrain"] = {{"filename", terrain->getFilename()}};
+		}
 
 		sim_json["waveforms"] = nlohmann::json::array();
 		for (const auto& waveform : world.getWaveforms() | std::views::values)
@@ -593,11 +885,31 @@ namespace serial
 							monostatic_comp["nodirect"] = recv->checkFlag(radar::Receiver::RecvFlag::FLAG_NODIRECT);
 							monostatic_comp["nopropagationloss"] =
 								recv->checkFlag(radar::Receiver::RecvFlag::FLAG_NOPROPLOSS);
+							if (!recv->getCalibrationFile().empty())
+							{
+								monostatic_comp["calibration_file"] = recv->getCalibrationFile();
+							}
+							if (t->getPhaseCenterOffset().length() > 0)
+							{
+								monostatic_comp["phase_center_offset"] = t->getPhaseCenterOffset();
+							}
+							if (t->getMountHeight() != 0)
+							{
+								monostatic_comp["mount_height"] = t->getMountHeight();
+							}
 
 							if (!t->getSchedule().empty())
 							{
 								monostatic_comp["schedule"] = t->getSchedule();
 							}
+							if (!recv->getBlankingGates().empty())
+							{
+								monostatic_comp["blanking_gates"] = recv->getBlankingGates();
+							}
+							if (!recv->getElementArray().empty())
+							{
+								monostatic_comp["element_array"] = recv->getElementArray();
+							}
 
 							if (t->getMode() == radar::OperationMode::PULSED_MODE)
 							{
@@ -609,6 +921,14 @@ namespace serial
 							{
 								monostatic_comp["cw_mode"] = nlohmann::json::object();
 							}
+							if (const auto& hops = t->getHopSequence())
+							{
+								monostatic_comp["hop_sequence"] = *hops;
+							}
+							if (const auto limit = t->getMaxDutyCycle())
+							{
+								monostatic_comp["max_duty_cycle"] = *limit;
+							}
 						}
 						plat_json["components"].push_back({{"monostatic", monostatic_comp}});
 					}
@@ -647,6 +967,303 @@ namespace serial
 		return {{"simulation", sim_json}};
 	}
 
+	nlohmann::json world_geometry_to_json(const core::World& world)
+	{
+		auto j = world_to_json(world);
+		auto& sim = j.at("simulation");
+		for (const auto* key : {"waveforms", "antennas", "timings", "ui_state"})
+		{
+			sim.erase(key);
+		}
+		// Without assets there is nothing for scenario-wide defaults to refer to
+		sim.at("parameters").erase("default_antenna");
+		sim.at("parameters").erase("default_timing");
+		for (auto& plat_json : sim.at("platforms"))
+		{
+			plat_json["components"] = nlohmann::json::array();
+		}
+		return j;
+	}
+
+	void scale_scenario_time(nlohmann::json& j, const RealType factor)
+	{
+		if (!std::isfinite(factor) || factor <= 0.0)
+		{
+			throw std::invalid_argument("Time-scaling factor must be a positive number.");
+		}
+
+		auto& sim = j.at("simulation");
+		auto& parameters = sim.at("parameters");
+		parameters["starttime"] = parameters.at("starttime").get<RealType>() * factor;
+		parameters["endtime"] = parameters.at("endtime").get<RealType>() * factor;
+
+		auto scale_times = [factor](nlohmann::json& waypoints)
+		{
+			for (auto& waypoint : waypoints)
+			{
+				waypoint["time"] = waypoint.at("time").get<RealType>() * factor;
+			}
+		};
+		for (auto& plat_json : sim.at("platforms"))
+		{
+			if (plat_json.contains("motionpath"))
+			{
+				auto& waypoints = plat_json.at("motionpath").at("positionwaypoints");
+				scale_times(waypoints);
+				for (auto& waypoint : waypoints)
+				{
+					for (const char* key : {"vx", "vy", "vz"})
+					{
+						if (waypoint.contains(key))
+						{
+							waypoint[key] = waypoint.at(key).get<RealType>() / factor;
+						}
+					}
+				}
+			}
+			if (plat_json.contains("rotationpath"))
+			{
+				scale_times(plat_json.at("rotationpath").at("rotationwaypoints"));
+			}
+			if (plat_json.contains("fixedrotation"))
+			{
+				auto& fixed_json = plat_json.at("fixedrotation");
+				fixed_json["azimuthrate"] = fixed_json.at("azimuthrate").get<RealType>() / factor;
+				fixed_json["elevationrate"] = fixed_json.at("elevationrate").get<RealType>() / factor;
+			}
+		}
+	}
+
+	std::string duplicate_platform(nlohmann::json& j, const std::string& platformName)
+	{
+		auto& platforms = j.at("simulation").at("platforms");
+		const auto source = std::ranges::find_if(platforms, [&](const nlohmann::json& plat_json)
+												 { return plat_json.at("name").get<std::string>() == platformName; });
+		if (source == platforms.end())
+		{
+			throw std::invalid_argument(std::format("Platform '{}' does not exist.", platformName));
+		}
+
+		// Component names must stay unique too, since they name the receivers' output files
+		std::unordered_set<std::string> names;
+		for (const auto& plat_json : platforms)
+		{
+			names.insert(plat_json.at("name").get<std::string>());
+			for (const auto& comp_json_outer : plat_json.value("components", nlohmann::json::array()))
+			{
+				for (const auto& comp_json : comp_json_outer)
+				{
+					names.insert(comp_json.at("name").get<std::string>());
+				}
+			}
+		}
+
+		nlohmann::json copy = *source;
+		const auto is_free = [&](const std::string& suffix)
+		{
+			if (names.contains(platformName + suffix))
+			{
+				return false;
+			}
+			for (const auto& comp_json_outer : copy.value("components", nlohmann::json::array()))
+			{
+				for (const auto& comp_json : comp_json_outer)
+				{
+					if (names.contains(comp_json.at("name").get<std::string>() + suffix))
+					{
+						return false;
+					}
+				}
+			}
+			return true;
+		};
+		std::string suffix = " (copy)";
+		for (unsigned n = 2; !is_free(suffix); ++n)
+		{
+			suffix = std::format(" (copy {})", n);
+		}
+
+		copy["name"] = platformName + suffix;
+		if (copy.contains("components"))
+		{
+			for (auto& comp_json_outer : copy.at("components"))
+			{
+				for (auto& comp_json : comp_json_outer)
+				{
+					comp_json["name"] = comp_json.at("name").get<std::string>() + suffix;
+				}
+			}
+		}
+		platforms.push_back(copy);
+		return platformName + suffix;
+	}
+
+	void select_scenario_platforms(nlohmann::json& j, const std::vector<std::string>& platformNames)
+	{
+		auto& sim = j.at("simulation");
+		auto& platforms = sim.at("platforms");
+		const std::unordered_set<std::string> selected(platformNames.begin(), platformNames.end());
+		for (const auto& name : selected)
+		{
+			if (std::ranges::none_of(platforms, [&](const nlohmann::json& plat_json)
+									 { return plat_json.at("name").get<std::string>() == name; }))
+			{
+				throw std::invalid_argument(std::format("Platform '{}' does not exist.", name));
+			}
+		}
+
+		nlohmann::json kept = nlohmann::json::array();
+		std::unordered_set<std::string> used_waveforms;
+		std::unordered_set<std::string> used_timings;
+		std::unordered_set<std::string> used_antennas;
+		bool has_transmitter = false;
+		bool has_receiver = false;
+		for (auto& plat_json : platforms)
+		{
+			if (!selected.contains(plat_json.at("name").get<std::string>()))
+			{
+				continue;
+			}
+			for (const auto& comp_json_outer : plat_json.value("components", nlohmann::json::array()))
+			{
+				has_transmitter |= comp_json_outer.contains("transmitter") || comp_json_outer.contains("monostatic");
+				has_receiver |= comp_json_outer.contains("receiver") || comp_json_outer.contains("monostatic");
+				for (const auto& comp_json : comp_json_outer)
+				{
+					used_waveforms.insert(comp_json.value("waveform", ""));
+					used_timings.insert(comp_json.value("timing", ""));
+					used_antennas.insert(comp_json.value("antenna", ""));
+				}
+			}
+			kept.push_back(std::move(plat_json));
+		}
+		if (!has_receiver || !has_transmitter)
+		{
+			throw std::invalid_argument(std::format("The selected platforms contain no {}; a run needs at least one "
+													"transmitter and one receiver, or a monostatic radar.",
+													has_receiver ? "transmitter" : "receiver"));
+		}
+		platforms = std::move(kept);
+
+		// Components without an antenna or timing reference fall back to the defaults
+		const auto& parameters = sim.at("parameters");
+		used_antennas.insert(parameters.value("default_antenna", ""));
+		used_timings.insert(parameters.value("default_timing", ""));
+
+		const auto drop_unused = [&](const char* key, const std::unordered_set<std::string>& used)
+		{
+			if (const auto it = sim.find(key); it != sim.end())
+			{
+				it->erase(std::remove_if(it->begin(), it->end(), [&](const nlohmann::json& asset)
+										 { return !used.contains(asset.at("name").get<std::string>()); }),
+						  it->end());
+			}
+		};
+		drop_unused("waveforms", used_waveforms);
+		drop_unused("timings", used_timings);
+		drop_unused("antennas", used_antennas);
+	}
+
+	MergeSummary merge_scenario_assets(nlohmann::json& j, const nlohmann::json& source, const bool includePlatforms)
+	{
+		auto& sim = j.at("simulation");
+		const auto& source_sim = source.at("simulation");
+
+		const auto unique_name = [](const std::string& name, const std::unordered_set<std::string>& taken)
+		{
+			std::string candidate = name;
+			for (unsigned n = 2; taken.contains(candidate); ++n)
+			{
+				candidate = std::format("{} ({})", name, n);
+			}
+			return candidate;
+		};
+
+		// Appends one asset list, returning the new name of every imported asset
+		const auto merge_assets = [&](const char* key, std::size_t& added)
+		{
+			std::unordered_map<std::string, std::string> renamed;
+			auto& assets = sim[key];
+			std::unordered_set<std::string> taken;
+			for (const auto& asset : assets)
+			{
+				taken.insert(asset.at("name").get<std::string>());
+			}
+			for (auto asset : source_sim.value(key, nlohmann::json::array()))
+			{
+				const auto name = asset.at("name").get<std::string>();
+				const auto new_name = unique_name(name, taken);
+				taken.insert(new_name);
+				renamed[name] = new_name;
+				asset["name"] = new_name;
+				assets.push_back(std::move(asset));
+				++added;
+			}
+			return renamed;
+		};
+
+		MergeSummary summary;
+		const auto waveforms = merge_assets("waveforms", summary.waveforms);
+		const auto timings = merge_assets("timings", summary.timings);
+		const auto antennas = merge_assets("antennas", summary.antennas);
+		if (!includePlatforms)
+		{
+			return summary;
+		}
+
+		// Platform and component names share one namespace, as in duplicate_platform
+		auto& platforms = sim["platforms"];
+		std::unordered_set<std::string> taken;
+		for (const auto& plat_json : platforms)
+		{
+			taken.insert(plat_json.at("name").get<std::string>());
+			for (const auto& comp_json_outer : plat_json.value("components", nlohmann::json::array()))
+			{
+				for (const auto& comp_json : comp_json_outer)
+				{
+					taken.insert(comp_json.at("name").get<std::string>());
+				}
+			}
+		}
+
+		const auto follow = [](nlohmann::json& comp_json, const char* key,
+							   const std::unordered_map<std::string, std::string>& renamed)
+		{
+			if (const auto it = comp_json.find(key); it != comp_json.end() && it->is_string())
+			{
+				if (const auto match = renamed.find(it->get<std::string>()); match != renamed.end())
+				{
+					*it = match->second;
+				}
+			}
+		};
+
+		for (auto plat_json : source_sim.value("platforms", nlohmann::json::array()))
+		{
+			const auto name = unique_name(plat_json.at("name").get<std::string>(), taken);
+			taken.insert(name);
+			plat_json["name"] = name;
+			if (plat_json.contains("components"))
+			{
+				for (auto& comp_json_outer : plat_json.at("components"))
+				{
+					for (auto& comp_json : comp_json_outer)
+					{
+						const auto comp_name = unique_name(comp_json.at("name").get<std::string>(), taken);
+						taken.insert(comp_name);
+						comp_json["name"] = comp_name;
+						follow(comp_json, "waveform", waveforms);
+						follow(comp_json, "timing", timings);
+						follow(comp_json, "antenna", antennas);
+					}
+				}
+			}
+			platforms.push_back(std::move(plat_json));
+			++summary.platforms;
+		}
+		return summary;
+	}
+
 	void json_to_world(const nlohmann::json& j, core::World& world, std::mt19937& masterSeeder)
 	{
 		// 1. Clear the existing world state. This function always performs a full
@@ -674,6 +1291,29 @@ namespace serial
 		params::params = new_params;
 
 		params::params.simulation_name = sim.value("name", "");
+		if (sim.contains("metadata"))
+		{
+			params::params.metadata = sim.at("metadata").get<params::ScenarioMetadata>();
+		}
+		if (sim.contains("ui_state") && !sim.at("ui_state").is_null())
+		{
+			params::params.ui_state = sim.at("ui_state").dump();
+		}
+		if (sim.contains("multipath") && !sim.at("multipath").is_null())
+		{
+			params::params.multipath = sim.at("multipath").get<params::MultipathSurface>();
+		}
+		if (sim.contains("terrain") && !sim.at("terrain").is_null())
+		{
+			// The DEM is aligned to the local frame through the scenario's geodetic origin
+			const auto filename = sim.at("terrain").at("filename").get<std::string>();
+			if (params::coordinateFrame() != params::CoordinateFrame::ENU)
+			{
+				throw std::runtime_error("Terrain '" + filename + "' requires an ENU scenario with a geodetic origin.");
+			}
+			world.setTerrain(math::loadTerrainFromFile(
+				filename, {params::originLatitude(), params::originLongitude(), params::originAltitude()}));
+		}
 
 		// 2. Restore assets (Waveforms, Antennas, Timings). This order is critical
 		//    because platforms, which are restored next, will reference these
@@ -723,6 +1363,37 @@ namespace serial
 				auto name = plat_json.at("name").get<std::string>();
 				auto plat = std::make_unique<radar::Platform>(name);
 
+				if (plat_json.contains("origin"))
+				{
+					const auto& origin = plat_json.at("origin");
+					plat->setOrigin(radar::GeodeticOrigin{origin.at("latitude").get<RealType>(),
+														  origin.at("longitude").get<RealType>(),
+														  origin.value("altitude", 0.0)});
+				}
+
+				if (plat_json.contains("position_noise"))
+				{
+					const auto& noise_json = plat_json.at("position_noise");
+					radar::PositionNoise noise;
+					noise.std_dev = {noise_json.value("x", 0.0), noise_json.value("y", 0.0),
+									 noise_json.value("z", 0.0)};
+					noise.correlation_time = noise_json.value("correlation_time", noise.correlation_time);
+					noise.seed = noise_json.contains("seed")
+						? noise_json.at("seed").get<unsigned>()
+						: radar::defaultPositionNoiseSeed(params::randomSeed(), world.getPlatforms().size());
+					if (!(noise.std_dev.x >= 0) || !(noise.std_dev.y >= 0) || !(noise.std_dev.z >= 0))
+					{
+						throw std::runtime_error("Position noise standard deviations for platform '" + name +
+												 "' must be non-negative.");
+					}
+					if (!(noise.correlation_time > 0) || !std::isfinite(noise.correlation_time))
+					{
+						throw std::runtime_error("Position noise correlation time for platform '" + name +
+												 "' must be positive.");
+					}
+					plat->setPositionNoise(noise);
+				}
+
 				// Paths
 				if (plat_json.contains("motionpath"))
 				{
@@ -757,10 +1428,16 @@ namespace serial
 					plat->setRotationPath(std::move(rot_path));
 				}
 
-				// Components - Strict array format
-				if (plat_json.contains("components"))
+				// Components - older scenarios hold a single "component" object instead of the array
+				const nlohmann::json legacy_components =
+					!plat_json.contains("components") && plat_json.contains("component")
+					? nlohmann::json::array({plat_json.at("component")})
+					: nlohmann::json::array();
+				const auto& components =
+					plat_json.contains("components") ? plat_json.at("components") : legacy_components;
+				if (!components.empty())
 				{
-					for (const auto& comp_json_outer : plat_json.at("components"))
+					for (const auto& comp_json_outer : components)
 					{
 						if (comp_json_outer.contains("transmitter"))
 						{
@@ -769,8 +1446,10 @@ namespace serial
 							// --- Dependency Check ---
 							// Validate Waveform and Timing existence before creation to prevent core crashes.
 							const auto wave_name = comp_json.value("waveform", "");
-							const auto timing_name = comp_json.value("timing", "");
-							const auto antenna_name = comp_json.value("antenna", "");
+							const auto timing_name =
+								resolve_asset_name(comp_json, "timing", params::params.default_timing);
+							const auto antenna_name =
+								resolve_asset_name(comp_json, "antenna", params::params.default_antenna);
 
 							if (wave_name.empty() || !world.findWaveform(wave_name))
 							{
@@ -816,9 +1495,26 @@ namespace serial
 							{
 								trans->setPrf(comp_json.at("pulsed_mode").value("prf", 0.0));
 							}
+							if (comp_json.contains("hop_sequence"))
+							{
+								auto hops = comp_json.at("hop_sequence").get<radar::HopSequence>();
+								radar::validateHopSequence(hops, trans->getName());
+								trans->setHopSequence(std::move(hops));
+							}
+							if (comp_json.contains("max_duty_cycle"))
+							{
+								const auto limit = comp_json.at("max_duty_cycle").get<RealType>();
+								radar::validateMaxDutyCycle(limit, trans->getName());
+								trans->setMaxDutyCycle(limit);
+							}
 
 							trans->setWave(world.findWaveform(wave_name));
 							trans->setAntenna(world.findAntenna(antenna_name));
+							if (comp_json.contains("phase_center_offset"))
+							{
+								trans->setPhaseCenterOffset(comp_json.at("phase_center_offset").get<math::Vec3>());
+							}
+							trans->setMountHeight(comp_json.value("mount_height", 0.0));
 
 							if (const auto timing_proto = world.findTiming(timing_name))
 							{
@@ -847,8 +1543,10 @@ namespace serial
 
 							// --- Dependency Check ---
 							// Receiver strictly requires a Timing source.
-							const auto timing_name = comp_json.value("timing", "");
-							const auto antenna_name = comp_json.value("antenna", "");
+							const auto timing_name =
+								resolve_asset_name(comp_json, "timing", params::params.default_timing);
+							const auto antenna_name =
+								resolve_asset_name(comp_json, "antenna", params::params.default_antenna);
 
 							if (timing_name.empty() || !world.findTiming(timing_name))
 							{
@@ -889,8 +1587,26 @@ namespace serial
 														  mode_json.value("prf", 0.0),
 														  mode_json.value("window_skip", 0.0));
 							}
+							if (comp_json.contains("blanking_gates"))
+							{
+								recv->setBlankingGates(
+									comp_json.at("blanking_gates").get<std::vector<radar::BlankingGate>>());
+							}
+							if (comp_json.contains("element_array"))
+							{
+								recv->setElementArray(comp_json.at("element_array").get<std::vector<math::Vec3>>());
+							}
 
 							recv->setNoiseTemperature(comp_json.value("noise_temp", 0.0));
+							if (comp_json.contains("calibration_file"))
+							{
+								recv->loadCalibration(comp_json.at("calibration_file").get<std::string>());
+							}
+							if (comp_json.contains("phase_center_offset"))
+							{
+								recv->setPhaseCenterOffset(comp_json.at("phase_center_offset").get<math::Vec3>());
+							}
+							recv->setMountHeight(comp_json.value("mount_height", 0.0));
 
 							recv->setAntenna(world.findAntenna(antenna_name));
 
@@ -947,8 +1663,11 @@ namespace serial
 										comp_json.value("name", "Unknown"));
 									continue;
 								}
-								target_obj = radar::createFileTarget(
+								auto file_target = std::make_unique<radar::FileTarget>(
 									plat.get(), comp_json.at("name").get<std::string>(), filename, masterSeeder());
+								file_target->setInterpolation(
+									rcs_json.value("interpolation", interp::InterpMode::LINEAR));
+								target_obj = std::move(file_target);
 							}
 							else
 							{
@@ -963,11 +1682,40 @@ namespace serial
 								if (const auto model_type = model_json.at("type").get<std::string>();
 									model_type == "chisquare" || model_type == "gamma")
 								{
-									auto model = std::make_unique<radar::RcsChiSquare>(
-										world.getTargets().back()->getRngEngine(), model_json.at("k").get<RealType>());
-									world.getTargets().back()->setFluctuationModel(std::move(model));
+									auto& target = *world.getTargets().back();
+									if (model_json.contains("swerling"))
+									{
+										target.setSwerlingCase(model_json.at("swerling").get<unsigned>());
+									}
+									// Older scenarios give only k; a Swerling case implies k when it is omitted
+									const auto swerling_case = target.getSwerlingCase();
+									const RealType k = swerling_case && !model_json.contains("k")
+										? radar::swerlingChiSquareK(*swerling_case)
+										: model_json.at("k").get<RealType>();
+									if (swerling_case && k != radar::swerlingChiSquareK(*swerling_case))
+									{
+										throw std::runtime_error("Model k of target '" + target.getName() +
+																 "' does not match its Swerling case.");
+									}
+									target.setFluctuationModel(
+										std::make_unique<radar::RcsChiSquare>(target.getRngEngine(), k));
 								}
 								// "constant" is the default, so no action is needed if that's the type.
+								if (model_json.contains("decorrelation_time"))
+								{
+									world.getTargets().back()->setDecorrelationTime(
+										model_json.at("decorrelation_time").get<RealType>());
+								}
+								if (model_json.contains("sample_rate"))
+								{
+									world.getTargets().back()->setRcsSampleRate(
+										model_json.at("sample_rate").get<RealType>());
+								}
+							}
+							if (comp_json.contains("micromotion"))
+							{
+								world.getTargets().back()->setMicroMotion(
+									comp_json.at("micromotion").get<radar::MicroMotion>());
 							}
 						}
 						else if (comp_json_outer.contains("monostatic"))
@@ -979,8 +1727,10 @@ namespace serial
 
 							// --- Dependency Check ---
 							const auto wave_name = comp_json.value("waveform", "");
-							const auto timing_name = comp_json.value("timing", "");
-							const auto antenna_name = comp_json.value("antenna", "");
+							const auto timing_name =
+								resolve_asset_name(comp_json, "timing", params::params.default_timing);
+							const auto antenna_name =
+								resolve_asset_name(comp_json, "antenna", params::params.default_antenna);
 
 							if (wave_name.empty() || !world.findWaveform(wave_name))
 							{
@@ -1004,27 +1754,46 @@ namespace serial
 								continue;
 							}
 
-							radar::OperationMode mode;
-							if (comp_json.contains("pulsed_mode"))
+							// A monostatic component without an explicit mode block is treated as pulsed.
+							// Any pulsed parameters that are not specified fall back to sensible defaults:
+							// the PRF may be given inline, the window skip defaults to zero and the window
+							// length defaults to the full pulse repetition interval. The PRF itself has no
+							// default and must be positive.
+							const radar::OperationMode mode = comp_json.contains("cw_mode") &&
+									!comp_json.contains("pulsed_mode")
+								? radar::OperationMode::CW_MODE
+								: radar::OperationMode::PULSED_MODE;
+							const auto mode_json = comp_json.value("pulsed_mode", nlohmann::json::object());
+							const RealType prf = mode_json.value("prf", comp_json.value("prf", 0.0));
+							if (mode == radar::OperationMode::PULSED_MODE && prf <= 0.0)
 							{
-								mode = radar::OperationMode::PULSED_MODE;
-							}
-							else if (comp_json.contains("cw_mode"))
-							{
-								mode = radar::OperationMode::CW_MODE;
-							}
-							else
-							{
-								throw std::runtime_error("Monostatic component '" + comp_json.value("name", "Unnamed") +
-														 "' must have a 'pulsed_mode' or 'cw_mode' block.");
+								throw std::runtime_error("Monostatic component '" +
+														 comp_json.value("name", "Unnamed") +
+														 "' must have a positive PRF in pulsed mode.");
 							}
+							const RealType window_skip =
+								mode_json.value("window_skip", comp_json.value("window_skip", 0.0));
+							const RealType window_length = mode_json.value(
+								"window_length", comp_json.value("window_length", prf > 0.0 ? 1.0 / prf : 0.0));
 
 							// Transmitter part
 							auto trans = std::make_unique<radar::Transmitter>(plat.get(),
 																			  comp_json.value("name", "Unnamed"), mode);
-							if (mode == radar::OperationMode::PULSED_MODE && comp_json.contains("pulsed_mode"))
+							if (mode == radar::OperationMode::PULSED_MODE)
 							{
-								trans->setPrf(comp_json.at("pulsed_mode").value("prf", 0.0));
+								trans->setPrf(prf);
+							}
+							if (comp_json.contains("hop_sequence"))
+							{
+								auto hops = comp_json.at("hop_sequence").get<radar::HopSequence>();
+								radar::validateHopSequence(hops, trans->getName());
+								trans->setHopSequence(std::move(hops));
+							}
+							if (comp_json.contains("max_duty_cycle"))
+							{
+								const auto limit = comp_json.at("max_duty_cycle").get<RealType>();
+								radar::validateMaxDutyCycle(limit, trans->getName());
+								trans->setMaxDutyCycle(limit);
 							}
 
 							trans->setWave(world.findWaveform(wave_name));
@@ -1040,14 +1809,31 @@ namespace serial
 							// Receiver part
 							auto recv = std::make_unique<radar::Receiver>(
 								plat.get(), comp_json.value("name", "Unnamed"), masterSeeder(), mode);
-							if (mode == radar::OperationMode::PULSED_MODE && comp_json.contains("pulsed_mode"))
+							if (mode == radar::OperationMode::PULSED_MODE)
 							{
-								const auto& mode_json = comp_json.at("pulsed_mode");
-								recv->setWindowProperties(mode_json.value("window_length", 0.0),
+								recv->setWindowProperties(window_length,
 														  trans->getPrf(), // Use transmitter's PRF
-														  mode_json.value("window_skip", 0.0));
+														  window_skip);
+							}
+							if (comp_json.contains("blanking_gates"))
+							{
+								recv->setBlankingGates(
+									comp_json.at("blanking_gates").get<std::vector<radar::BlankingGate>>());
+							}
+							if (comp_json.contains("element_array"))
+							{
+								recv->setElementArray(comp_json.at("element_array").get<std::vector<math::Vec3>>());
 							}
 							recv->setNoiseTemperature(comp_json.value("noise_temp", 0.0));
+							if (comp_json.contains("calibration_file"))
+							{
+								recv->loadCalibration(comp_json.at("calibration_file").get<std::string>());
+							}
+							if (comp_json.contains("phase_center_offset"))
+							{
+								recv->setPhaseCenterOffset(comp_json.at("phase_center_offset").get<math::Vec3>());
+							}
+							recv->setMountHeight(comp_json.value("mount_height", 0.0));
 
 							recv->setAntenna(world.findAntenna(antenna_name));
 							const auto rx_timing_proto = world.findTiming(timing_name);
@@ -1083,6 +1869,9 @@ namespace serial
 								recv->setSchedule(processed_schedule);
 							}
 
+							trans->setPhaseCenterOffset(recv->getPhaseCenterOffset());
+							trans->setMountHeight(recv->getMountHeight());
+
 							// Link them and add to world
 							trans->setAttached(recv.get());
 							recv->setAttached(trans.get());
@@ -1115,4 +1904,72 @@ namespace serial
 		// Schedule initial events after all objects are loaded.
 		world.scheduleInitialEvents();
 	}
+
+	std::vector<std::string> find_non_finite_values(const core::World& world)
+	{
+		std::vector<std::string> issues;
+		const auto walk = [&issues](const auto& self, const nlohmann::json& node, const std::string& path) -> void
+		{
+			if (node.is_number_float())
+			{
+				if (const auto value = node.get<RealType>(); !std::isfinite(value))
+				{
+					issues.push_back(std::format("{} is {}", path, std::isnan(value) ? "NaN" : "infinite"));
+				}
+			}
+			else if (node.is_object())
+			{
+				for (const auto& [key, child] : node.items())
+				{
+					self(self, child, path.empty() ? key : path + "." + key);
+				}
+			}
+			else if (node.is_array())
+			{
+				for (std::size_t i = 0; i < node.size(); ++i)
+				{
+					self(self, node[i], std::format("{}[{}]", path, i));
+				}
+			}
+		};
+		walk(walk, world_to_json(world).at("simulation"), "");
+		return issues;
+	}
+
+	std::string describe_json_error(const nlohmann::json::exception& e)
+	{
+		std::string message = e.what();
+		if (const auto end = message.find("] "); message.starts_with("[json.exception.") && end != std::string::npos)
+		{
+			message.erase(0, end + 2);
+		}
+
+		// With JSON_DIAGNOSTICS the pointer of the failing value leads the message, e.g. "(/a/0) ..."
+		const auto close = message.find(") ");
+		if (!message.starts_with("(/") || close == std::string::npos)
+		{
+			return message;
+		}
+		std::string path;
+		std::size_t start = 2;
+		while (start <= close)
+		{
+			const auto stop = std::min(message.find('/', start), close);
+			std::string token = message.substr(start, stop - start);
+			for (std::size_t pos = 0; (pos = token.find('~', pos)) != std::string::npos; ++pos)
+			{
+				token.replace(pos, 2, token.compare(pos, 2, "~1") == 0 ? "/" : "~");
+			}
+			if (!token.empty() && std::ranges::all_of(token, [](const unsigned char c) { return std::isdigit(c); }))
+			{
+				path += "[" + token + "]";
+			}
+			else
+			{
+				path += path.empty() ? token : "." + token;
+			}
+			start = stop + 1;
+		}
+		return path + ": " + message.substr(close + 2);
+	}
 }
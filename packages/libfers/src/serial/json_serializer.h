@@ -15,8 +15,13 @@
 
 #pragma once
 
+#include <cstddef>
 #include <nlohmann/json.hpp>
 #include <random>
+#include <string>
+#include <vector>
+
+#include "core/config.h"
 
 namespace core
 {
@@ -56,4 +61,111 @@ namespace serial
 	 * @param masterSeeder A reference to the master random number generator, which will be re-seeded.
 	 */
 	void json_to_world(const nlohmann::json& j, core::World& world, std::mt19937& masterSeeder);
+
+	/**
+	 * @brief Serializes only the geometry of the simulation world.
+	 *
+	 * The result has the same layout as `world_to_json`, but keeps just the scenario name,
+	 * parameters and each platform's motion, position noise and rotation. Assets, components
+	 * and UI state are dropped, so it can be passed to `json_to_world` to rebuild the
+	 * platform trajectories on their own.
+	 *
+	 * @param world The world object to serialize.
+	 * @return A nlohmann::json scenario containing only platform geometry.
+	 */
+	nlohmann::json world_geometry_to_json(const core::World& world);
+
+	/**
+	 * @brief Stretches the timeline of a serialized scenario by a constant factor.
+	 *
+	 * Multiplies the start and end times and every motion and rotation waypoint time by
+	 * `factor`, and divides fixed rotation rates and waypoint velocities by it, so platforms
+	 * follow the same spatial paths at 1/`factor` of their original speed. Radar schedules,
+	 * PRFs and waveforms are left unchanged.
+	 *
+	 * @param j A scenario in the layout produced by `world_to_json`, modified in place.
+	 * @param factor The time-scaling factor.
+	 * @throws std::invalid_argument If `factor` is not a finite positive number.
+	 */
+	void scale_scenario_time(nlohmann::json& j, RealType factor);
+
+	/**
+	 * @brief Appends a deep copy of a platform to a serialized scenario.
+	 *
+	 * The copy keeps the original's paths and components. Its name and the names of its
+	 * components get a " (copy)" suffix, numbered as " (copy 2)", " (copy 3)", ... when
+	 * the plain suffix would clash with an existing platform or component.
+	 *
+	 * @param j A scenario in the layout produced by `world_to_json`, modified in place.
+	 * @param platformName The name of the platform to copy.
+	 * @return The name of the new platform.
+	 * @throws std::invalid_argument If no platform has the given name.
+	 */
+	std::string duplicate_platform(nlohmann::json& j, const std::string& platformName);
+
+	/**
+	 * @brief Reduces a serialized scenario to the named platforms and the assets they use.
+	 *
+	 * Every other platform is removed, as are waveforms, timings and antennas that no
+	 * remaining component references and that are not the scenario's default antenna or
+	 * timing source.
+	 *
+	 * @param j A scenario in the layout produced by `world_to_json`, modified in place.
+	 * @param platformNames The names of the platforms to keep.
+	 * @throws std::invalid_argument If a name matches no platform, or if the remaining
+	 *         platforms lack a receiver or a transmitter (a monostatic radar counts as both).
+	 */
+	void select_scenario_platforms(nlohmann::json& j, const std::vector<std::string>& platformNames);
+
+	/**
+	 * @brief Counts of the assets added by `merge_scenario_assets`.
+	 */
+	struct MergeSummary
+	{
+		std::size_t waveforms = 0; ///< Number of waveforms added.
+		std::size_t timings = 0; ///< Number of timing sources added.
+		std::size_t antennas = 0; ///< Number of antennas added.
+		std::size_t platforms = 0; ///< Number of platforms added.
+	};
+
+	/**
+	 * @brief Appends the assets of one serialized scenario to another.
+	 *
+	 * The waveforms, timings and antennas of `source` are appended to `j`; its platforms
+	 * are appended too when `includePlatforms` is set. Existing entries are never changed.
+	 * An imported item whose name is already taken is renamed with a numbered suffix
+	 * (" (2)", " (3)", ...), and the asset references of imported components follow the
+	 * renamed assets. Platform positions are copied as-is into the target's frame, and
+	 * the parameters of `source` are ignored.
+	 *
+	 * @param j A scenario in the layout produced by `world_to_json`, modified in place.
+	 * @param source The scenario to import from, in the same layout.
+	 * @param includePlatforms Whether to import platforms as well as assets.
+	 * @return The number of items of each kind that were added.
+	 */
+	MergeSummary merge_scenario_assets(nlohmann::json& j, const nlohmann::json& source, bool includePlatforms);
+
+	/**
+	 * @brief Finds every NaN or infinite number in the serialized simulation world.
+	 *
+	 * Non-finite values cannot be represented in JSON and are written to XML as text the
+	 * parser cannot round-trip, so they are reported by their path in the JSON
+	 * representation, e.g. `platforms[0].motionpath.positionwaypoints[1].x is NaN`.
+	 *
+	 * @param world The world object to check.
+	 * @return One description per non-finite value, empty if all values are finite.
+	 */
+	std::vector<std::string> find_non_finite_values(const core::World& world);
+
+	/**
+	 * @brief Describes a JSON parsing or deserialization error by the field that caused it.
+	 *
+	 * The library's exception code is dropped and the JSON pointer of the failing value
+	 * is rewritten in the same dotted form as `find_non_finite_values`, e.g.
+	 * `simulation.platforms[0].name: type must be string, but is number`.
+	 *
+	 * @param e The exception thrown while parsing or reading the scenario JSON.
+	 * @return A message naming the field path, if known, followed by the reason.
+	 */
+	std::string describe_json_error(const nlohmann::json::exception& e);
 }
@@ -13,7 +13,6 @@
 
 #include <GeographicLib/Geocentric.hpp>
 #include <GeographicLib/Geodesic.hpp>
-#include <GeographicLib/LocalCartesian.hpp>
 #include <GeographicLib/UTMUPS.hpp>
 #include <algorithm>
 #include <cmath>
@@ -25,6 +24,7 @@
 #include <optional>
 #include <ranges>
 #include <sstream>
+#include <stdexcept>
 #include <string>
 #include <vector>
 
@@ -34,6 +34,7 @@
 #include "core/parameters.h"
 #include "core/world.h"
 #include "math/coord.h"
+#include "math/geodesy.h"
 #include "math/path.h"
 #include "radar/platform.h"
 #include "radar/radar_obj.h"
@@ -480,9 +481,10 @@ namespace
 					angle_3db_drop_deg = findSquareHorn3DbDropAngle(squarehorn_ant, *wavelength);
 				}
 			}
-			else if (dynamic_cast<const antenna::XmlAntenna*>(ant) || dynamic_cast<const antenna::H5Antenna*>(ant))
+			else if (dynamic_cast<const antenna::XmlAntenna*>(ant) || dynamic_cast<const antenna::H5Antenna*>(ant) ||
+					 dynamic_cast<const antenna::PatAntenna*>(ant))
 			{
-				// For XmlAntenna and H5Antenna, angle_3db_drop_deg remains nullopt,
+				// For file-based antennas, angle_3db_drop_deg remains nullopt,
 				// resulting in only the boresight arrow being drawn. This is an intentional
 				// symbolic representation. Alert the user about this.
 				LOG(logging::Level::INFO,
@@ -521,7 +523,9 @@ namespace
 		// The sampling time range is now based on the platform's specific motion path duration,
 		// ensuring accurate track resolution for objects with short lifespans.
 		const double start_time = waypoints.front().t;
-		const double end_time = waypoints.back().t;
+		// Ballistic paths are defined by a single launch waypoint and end at impact.
+		const double end_time = path->getType() == math::Path::InterpType::INTERP_BALLISTIC ? path->getImpactTime()
+																							 : waypoints.back().t;
 
 		// Handle single-point paths or paths with zero duration by emitting a single coordinate.
 		if (const double time_diff = end_time - start_time; time_diff <= 0.0)
@@ -608,8 +612,8 @@ namespace
 								 const double refAlt, const ConverterFunc& converter, const std::string& indent)
 	{
 		const auto path_type = platform->getMotionPath()->getType();
-		const bool is_dynamic =
-			path_type == math::Path::InterpType::INTERP_LINEAR || path_type == math::Path::InterpType::INTERP_CUBIC;
+		const bool is_dynamic = path_type == math::Path::InterpType::INTERP_LINEAR ||
+			path_type == math::Path::InterpType::INTERP_CUBIC || path_type == math::Path::InterpType::INTERP_BALLISTIC;
 
 		if (is_dynamic)
 		{
@@ -622,6 +626,44 @@ namespace
 		}
 	}
 
+	/**
+	 * @brief Creates a converter from a local ENU frame to geodetic coordinates.
+	 *
+	 * Uses the same conversion as waypoint input in geodetic coordinates, so exported tracks land on the
+	 * positions the user entered.
+	 *
+	 * @param origin The geodetic origin of the local frame.
+	 * @return A converter from local ENU coordinates to geodetic coordinates.
+	 */
+	ConverterFunc makeLocalConverter(const math::GeodeticPosition& origin)
+	{
+		return [origin](const math::Vec3& pos, double& lat, double& lon, double& alt)
+		{
+			const math::GeodeticPosition geo = math::localToGeodetic(pos, origin);
+			lat = geo.latitude;
+			lon = geo.longitude;
+			alt = geo.altitude;
+		};
+	}
+
+	/**
+	 * @brief Creates a local tangent plane converter for a platform-specific geodetic origin.
+	 *
+	 * @param origin The platform's origin.
+	 * @param platformName The platform name, used in error messages.
+	 * @return A converter from the platform's local ENU coordinates to geodetic coordinates.
+	 * @throws std::runtime_error If the origin is not a valid geodetic position.
+	 */
+	ConverterFunc makeOriginConverter(const radar::GeodeticOrigin& origin, const std::string& platformName)
+	{
+		if (!std::isfinite(origin.latitude) || !std::isfinite(origin.longitude) || !std::isfinite(origin.altitude) ||
+			std::abs(origin.latitude) > 90.0 || std::abs(origin.longitude) > 180.0)
+		{
+			throw std::runtime_error("Platform '" + platformName + "' has an invalid geodetic origin.");
+		}
+		return makeLocalConverter({origin.latitude, origin.longitude, origin.altitude});
+	}
+
 	void processPlatform(const radar::Platform* platform, const std::vector<const radar::Object*>& objects,
 						 std::ofstream& kmlFile, const ConverterFunc& converter, const double referenceAltitude,
 						 const std::string& indent)
@@ -651,7 +693,8 @@ namespace
 
 namespace serial
 {
-	bool KmlGenerator::generateKml(const core::World& world, const std::string& outputKmlPath)
+	bool KmlGenerator::generateKml(const core::World& world, const std::string& outputKmlPath,
+								   const std::optional<math::GeodeticPosition>& origin)
 	{
 		try
 		{
@@ -663,13 +706,12 @@ namespace serial
 			{
 			case params::CoordinateFrame::ENU:
 				{
-					reference_latitude = params::originLatitude();
-					reference_longitude = params::originLongitude();
-					reference_altitude = params::originAltitude();
-					auto proj = std::make_shared<GeographicLib::LocalCartesian>(reference_latitude, reference_longitude,
-																				reference_altitude);
-					converter = [proj](const math::Vec3& pos, double& lat, double& lon, double& alt)
-					{ proj->Reverse(pos.x, pos.y, pos.z, lat, lon, alt); };
+					const auto [latitude, longitude, altitude] = origin.value_or(math::GeodeticPosition{
+						params::originLatitude(), params::originLongitude(), params::originAltitude()});
+					reference_latitude = latitude;
+					reference_longitude = longitude;
+					reference_altitude = altitude;
+					converter = makeLocalConverter({reference_latitude, reference_longitude, reference_altitude});
 					break;
 				}
 			case params::CoordinateFrame::UTM:
@@ -750,6 +792,20 @@ namespace serial
 			const std::string platform_indent = "    ";
 			for (const auto& [platform, objects] : platform_to_objects)
 			{
+				// Platforms declaring their own origin are positioned in a local tangent plane around it.
+				if (const auto& origin = platform->getOrigin())
+				{
+					if (params::coordinateFrame() == params::CoordinateFrame::ENU)
+					{
+						processPlatform(platform, objects, kml_file, makeOriginConverter(*origin, platform->getName()),
+										origin->altitude, platform_indent);
+						continue;
+					}
+					LOG(logging::Level::WARNING,
+						"Platform '{}' declares an origin, which is only supported in the ENU coordinate frame. "
+						"Using the global frame instead.",
+						platform->getName());
+				}
 				processPlatform(platform, objects, kml_file, converter, reference_altitude, platform_indent);
 			}
 
@@ -11,8 +11,11 @@
 
 #pragma once
 
+#include <optional>
 #include <string>
 
+#include "math/geodesy.h"
+
 namespace core
 {
 	class World;
@@ -51,8 +54,10 @@ namespace serial
 		 *
 		 * @param world The simulation world containing all objects and paths.
 		 * @param outputKmlPath The path for the output KML file.
+		 * @param origin If set, the ENU origin to use in place of the scenario's own.
 		 * @return True on success, false on failure.
 		 */
-		static bool generateKml(const core::World& world, const std::string& outputKmlPath);
+		static bool generateKml(const core::World& world, const std::string& outputKmlPath,
+								const std::optional<math::GeodeticPosition>& origin = std::nullopt);
 	};
 }
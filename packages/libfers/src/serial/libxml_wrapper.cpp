@@ -17,6 +17,51 @@
 #include "libxml/xmlIO.h"
 #include "libxml/xmlschemas.h"
 
+#include <array>
+#include <cstdarg>
+#include <cstdio>
+
+namespace
+{
+	/**
+	 * @brief libxml2 error callback that keeps the first message reported during validation.
+	 *
+	 * @param ctx Pointer to the std::string receiving the message.
+	 * @param msg printf-style format of the message.
+	 */
+	void keepFirstError(void* ctx, const char* msg, ...)
+	{
+		auto* first_error = static_cast<std::string*>(ctx);
+		if (!first_error->empty())
+		{
+			return;
+		}
+		std::array<char, 1024> buffer{};
+		va_list args;
+		va_start(args, msg);
+		std::vsnprintf(buffer.data(), buffer.size(), msg, args);
+		va_end(args);
+		*first_error = buffer.data();
+		while (!first_error->empty() && (first_error->back() == '\n' || first_error->back() == ' '))
+		{
+			first_error->pop_back();
+		}
+	}
+
+	/**
+	 * @brief Builds the exception message for a failed validation.
+	 */
+	std::string validationFailure(const std::string_view schema, const std::string& firstError)
+	{
+		std::string message = "XML failed " + std::string(schema) + " validation.";
+		if (!firstError.empty())
+		{
+			message += " " + firstError;
+		}
+		return message;
+	}
+}
+
 bool XmlDocument::validateWithDtd(const std::span<const unsigned char> dtdData) const
 {
 	xmlDtdPtr dtd =
@@ -37,12 +82,17 @@ bool XmlDocument::validateWithDtd(const std::span<const unsigned char> dtdData)
 		throw XmlException("Failed to create validation context.");
 	}
 
+	std::string first_error;
+	validation_ctxt->userData = &first_error;
+	validation_ctxt->error = keepFirstError;
+	validation_ctxt->warning = nullptr;
+
 	const bool is_valid = xmlValidateDtd(validation_ctxt.get(), _doc.get(), dtd);
 	xmlFreeDtd(dtd);
 
 	if (!is_valid)
 	{
-		throw XmlException("XML failed DTD validation.");
+		throw XmlException(validationFailure("DTD", first_error));
 	}
 
 	return true;
@@ -72,9 +122,12 @@ bool XmlDocument::validateWithXsd(const std::span<const unsigned char> xsdData)
 		throw XmlException("Failed to create schema validation context.");
 	}
 
+	std::string first_error;
+	xmlSchemaSetValidErrors(schema_valid_ctxt.get(), keepFirstError, nullptr, &first_error);
+
 	if (const bool is_valid = xmlSchemaValidateDoc(schema_valid_ctxt.get(), _doc.get()) == 0; !is_valid)
 	{
-		throw XmlException("XML failed XSD validation.");
+		throw XmlException(validationFailure("XSD", first_error));
 	}
 
 	return true;
@@ -125,6 +178,19 @@ bool XmlDocument::loadString(const std::string& content)
 	return _doc != nullptr;
 }
 
+void XmlDocument::setDoctype(const std::string& rootName, const std::string& systemId) const
+{
+	if (!_doc)
+	{
+		throw std::runtime_error("Document not created");
+	}
+	if (!xmlCreateIntSubset(_doc.get(), reinterpret_cast<const xmlChar*>(rootName.c_str()), nullptr,
+							reinterpret_cast<const xmlChar*>(systemId.c_str())))
+	{
+		throw XmlException("Failed to add the document type declaration.");
+	}
+}
+
 std::string XmlDocument::dumpToString() const
 {
 	if (!_doc)
@@ -268,6 +268,16 @@ public:
 	 */
 	[[nodiscard]] std::string dumpToString() const;
 
+	/**
+	 * @brief Declare the document type, emitted as `<!DOCTYPE rootName SYSTEM "systemId">`.
+	 *
+	 * @param rootName The name of the root element.
+	 * @param systemId The system identifier of the DTD.
+	 * @throws std::runtime_error if the document is not created.
+	 * @throws XmlException if the declaration could not be added.
+	 */
+	void setDoctype(const std::string& rootName, const std::string& systemId) const;
+
 	/**
 	 * @brief Set the root element of the document.
 	 *
@@ -308,7 +318,8 @@ public:
 	 *
 	 * @param dtdData The DTD data used for validation.
 	 * @return True if the document is valid according to the DTD.
-	 * @throws XmlException if the DTD is invalid or the validation fails.
+	 * @throws XmlException if the DTD is invalid or the validation fails; the message includes the first
+	 *         validation error reported by libxml2.
 	 */
 	[[nodiscard]] bool validateWithDtd(std::span<const unsigned char> dtdData) const;
 
@@ -317,7 +328,8 @@ public:
 	 *
 	 * @param xsdData The XSD data used for validation.
 	 * @return True if the document is valid according to the XSD schema.
-	 * @throws XmlException if the XSD is invalid or the validation fails.
+	 * @throws XmlException if the XSD is invalid or the validation fails; the message includes the first
+	 *         validation error reported by libxml2.
 	 */
 	[[nodiscard]] bool validateWithXsd(std::span<const unsigned char> xsdData) const;
 };
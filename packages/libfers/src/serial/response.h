@@ -27,6 +27,7 @@ namespace fers_signal
 
 namespace radar
 {
+	class Target;
 	class Transmitter;
 }
 
@@ -44,9 +45,11 @@ namespace serial
 		 *
 		 * @param wave Pointer to the radar signal object.
 		 * @param transmitter Pointer to the transmitter object.
+		 * @param target Pointer to the reflecting target, or null for the direct path.
 		 */
-		Response(const fers_signal::RadarSignal* wave, const radar::Transmitter* transmitter) noexcept :
-			_transmitter(transmitter), _wave(wave)
+		Response(const fers_signal::RadarSignal* wave, const radar::Transmitter* transmitter,
+				 const radar::Target* target = nullptr) noexcept :
+			_transmitter(transmitter), _target(target), _wave(wave)
 		{
 		}
 
@@ -102,8 +105,37 @@ namespace serial
 		 */
 		[[nodiscard]] std::string getTransmitterName() const noexcept;
 
+		/**
+		 * @brief Retrieves the transmitter that emitted the signal.
+		 *
+		 * @return A pointer to the transmitter.
+		 */
+		[[nodiscard]] const radar::Transmitter* getTransmitter() const noexcept { return _transmitter; }
+
+		/**
+		 * @brief Retrieves the target the signal reflected off.
+		 *
+		 * @return A pointer to the target, or null for the direct path.
+		 */
+		[[nodiscard]] const radar::Target* getTarget() const noexcept { return _target; }
+
+		/**
+		 * @brief Retrieves the transmitted waveform.
+		 *
+		 * @return A pointer to the radar signal.
+		 */
+		[[nodiscard]] const fers_signal::RadarSignal* getWave() const noexcept { return _wave; }
+
+		/**
+		 * @brief Retrieves the interpolation points of the response.
+		 *
+		 * @return The points in time order.
+		 */
+		[[nodiscard]] const std::vector<interp::InterpPoint>& getPoints() const noexcept { return _points; }
+
 	private:
 		const radar::Transmitter* _transmitter; ///< Pointer to the transmitter object.
+		const radar::Target* _target; ///< Pointer to the reflecting target, or null for the direct path.
 		const fers_signal::RadarSignal* _wave; ///< Pointer to the radar signal object.
 		std::vector<interp::InterpPoint> _points; ///< Vector of interpolation points.
 	};
@@ -12,6 +12,10 @@
 
 #include "waveform_factory.h"
 
+#include <algorithm>
+#include <array>
+#include <charconv>
+#include <cmath>
 #include <complex>
 #include <cstddef>
 #include <filesystem>
@@ -134,4 +138,88 @@ namespace serial
 		LOG(logging::Level::FATAL, "Unrecognized file extension '{}' for file: '{}'", extension, filename);
 		throw std::runtime_error("Unrecognized file extension '" + extension + "' for file: " + filename);
 	}
+
+	std::vector<ComplexType> parseInlineSamples(const std::string_view text)
+	{
+		std::vector<RealType> values;
+		std::size_t start = 0;
+		while (start <= text.size())
+		{
+			const std::size_t comma = std::min(text.find(',', start), text.size());
+			std::string_view field = text.substr(start, comma - start);
+			const auto first = field.find_first_not_of(" \t\r\n");
+			const auto last = field.find_last_not_of(" \t\r\n");
+			field = first == std::string_view::npos ? std::string_view{} : field.substr(first, last - first + 1);
+
+			// A trailing comma after the last value is tolerated
+			if (!(field.empty() && comma == text.size() && !values.empty()))
+			{
+				RealType value{};
+				const auto [ptr, ec] = std::from_chars(field.data(), field.data() + field.size(), value);
+				if (field.empty() || ec != std::errc() || ptr != field.data() + field.size() || !std::isfinite(value))
+				{
+					throw std::runtime_error("Inline sample value " + std::to_string(values.size() + 1) + " ('" +
+											 std::string(field) + "') is not a finite number.");
+				}
+				values.push_back(value);
+			}
+			start = comma + 1;
+		}
+
+		if (values.size() % 2 != 0)
+		{
+			throw std::runtime_error("Inline samples must list an I and a Q value for every sample; got " +
+									 std::to_string(values.size()) + " values.");
+		}
+		std::vector<ComplexType> samples;
+		samples.reserve(values.size() / 2);
+		for (std::size_t i = 0; i < values.size(); i += 2)
+		{
+			samples.emplace_back(values[i], values[i + 1]);
+		}
+		return samples;
+	}
+
+	std::string formatInlineSamples(const std::span<const ComplexType> samples)
+	{
+		std::string text;
+		std::array<char, 64> buffer{};
+		const auto append = [&](const RealType value)
+		{
+			const auto [ptr, ec] = std::to_chars(buffer.data(), buffer.data() + buffer.size(), value);
+			text.append(buffer.data(), ptr);
+		};
+		for (const auto& sample : samples)
+		{
+			if (!text.empty())
+			{
+				text += ", ";
+			}
+			append(sample.real());
+			text += ',';
+			append(sample.imag());
+		}
+		return text;
+	}
+
+	std::unique_ptr<RadarSignal> loadWaveformFromSamples(const std::string& name, std::vector<ComplexType> samples,
+														 const RealType rate, const RealType power,
+														 const RealType carrierFreq)
+	{
+		if (samples.empty())
+		{
+			throw std::runtime_error("Inline waveform '" + name + "' has no samples.");
+		}
+		if (!(rate > 0) || !std::isfinite(rate))
+		{
+			throw std::runtime_error("Sample rate of inline waveform '" + name + "' must be positive.");
+		}
+
+		auto signal = std::make_unique<Signal>();
+		signal->load(samples, samples.size(), rate);
+		auto wave = std::make_unique<RadarSignal>(name, power, carrierFreq,
+												  static_cast<RealType>(samples.size()) / rate, std::move(signal));
+		wave->setInlineSamples({std::move(samples), rate});
+		return wave;
+	}
 }
@@ -13,7 +13,10 @@
 #pragma once
 
 #include <memory>
+#include <span>
 #include <string>
+#include <string_view>
+#include <vector>
 
 #include "core/config.h"
 
@@ -36,4 +39,41 @@ namespace serial
 	 */
 	[[nodiscard]] std::unique_ptr<fers_signal::RadarSignal>
 	loadWaveformFromFile(const std::string& name, const std::string& filename, RealType power, RealType carrierFreq);
+
+	/**
+	 * @brief Decodes an inline CSV list of I/Q samples.
+	 *
+	 * The list holds the in-phase and quadrature parts of each sample in turn, separated by
+	 * commas and optional whitespace, e.g. `1,0, 0.7,0.7, 0,1`.
+	 *
+	 * @param text The CSV list.
+	 * @return The complex samples.
+	 * @throws std::runtime_error If the list is empty, holds a value that is not a finite number, or
+	 *         has an odd number of values.
+	 */
+	[[nodiscard]] std::vector<ComplexType> parseInlineSamples(std::string_view text);
+
+	/**
+	 * @brief Encodes I/Q samples as the CSV list read by `parseInlineSamples`.
+	 *
+	 * @param samples The complex samples.
+	 * @return The CSV list, with full precision.
+	 */
+	[[nodiscard]] std::string formatInlineSamples(std::span<const ComplexType> samples);
+
+	/**
+	 * @brief Builds a RadarSignal from I/Q samples given inline in the scenario.
+	 *
+	 * @param name The name of the radar signal.
+	 * @param samples The complex baseband samples.
+	 * @param rate The sample rate in Hz.
+	 * @param power The power of the radar signal in the waveform.
+	 * @param carrierFreq The carrier frequency of the radar signal.
+	 * @return A unique pointer to a RadarSignal object that remembers its inline samples.
+	 * @throws std::runtime_error If there are no samples or the rate is not a positive finite number.
+	 */
+	[[nodiscard]] std::unique_ptr<fers_signal::RadarSignal> loadWaveformFromSamples(const std::string& name,
+																				   std::vector<ComplexType> samples,
+																				   RealType rate, RealType power,
+																				   RealType carrierFreq);
 }
@@ -15,16 +15,21 @@
 #include <GeographicLib/UTMUPS.hpp>
 #include <cmath>
 #include <filesystem>
+#include <fstream>
 #include <functional>
+#include <iterator>
 #include <memory>
+#include <optional>
 #include <random>
 #include <span>
+#include <sstream>
 #include <string_view>
 #include <utility>
 #include <vector>
 
 // Generated headers
 #include "antenna/antenna_factory.h"
+#include "checksum.h"
 #include "core/config.h"
 #include "core/logging.h"
 #include "core/parameters.h"
@@ -33,9 +38,11 @@
 #include "fers_xml_xsd.h"
 #include "libxml_wrapper.h"
 #include "math/coord.h"
+#include "math/geodesy.h"
 #include "math/geometry_ops.h"
 #include "math/path.h"
 #include "math/rotation_path.h"
+#include "math/terrain.h"
 #include "radar/platform.h"
 #include "radar/radar_obj.h"
 #include "radar/receiver.h"
@@ -158,6 +165,302 @@ namespace
 		return radar::processRawSchedule(std::move(raw_periods), parentName, isPulsed, pri);
 	}
 
+	/**
+	 * @brief Reads an optional <phase_center_offset> child into a radar component.
+	 *
+	 * @param parent The component XmlElement.
+	 * @param radar The component to configure.
+	 */
+	void parsePhaseCenterOffset(const XmlElement& parent, radar::Radar* radar)
+	{
+		if (const XmlElement offset = parent.childElement("phase_center_offset", 0); offset.isValid())
+		{
+			radar->setPhaseCenterOffset({std::stod(XmlElement::getSafeAttribute(offset, "x")),
+										 std::stod(XmlElement::getSafeAttribute(offset, "y")),
+										 std::stod(XmlElement::getSafeAttribute(offset, "z"))});
+		}
+	}
+
+	/**
+	 * @brief Reads the optional <blanking> child of a receiver's <pulsed_mode> element.
+	 *
+	 * @param pulsedMode The <pulsed_mode> XmlElement.
+	 * @param receiver The receiver to configure. Its window properties must already be set.
+	 * @throws XmlException If a gate is missing an attribute.
+	 */
+	void parseBlankingGates(const XmlElement& pulsedMode, radar::Receiver* receiver)
+	{
+		const XmlElement blanking = pulsedMode.childElement("blanking", 0);
+		if (!blanking.isValid())
+		{
+			return;
+		}
+
+		std::vector<radar::BlankingGate> gates;
+		for (unsigned i = 0;; ++i)
+		{
+			const XmlElement gate = blanking.childElement("gate", i);
+			if (!gate.isValid())
+			{
+				break;
+			}
+			gates.push_back({std::stod(XmlElement::getSafeAttribute(gate, "start")),
+							 std::stod(XmlElement::getSafeAttribute(gate, "length"))});
+		}
+		receiver->setBlankingGates(std::move(gates));
+	}
+
+	/**
+	 * @brief Reads the optional <element_array> child of a receiver or monostatic element.
+	 *
+	 * @param parent The component XmlElement.
+	 * @param receiver The receiver to configure. Its operation mode must already be set.
+	 * @throws XmlException If an element is missing a coordinate or its position is not a finite number.
+	 */
+	void parseElementArray(const XmlElement& parent, radar::Receiver* receiver)
+	{
+		const XmlElement array = parent.childElement("element_array", 0);
+		if (!array.isValid())
+		{
+			return;
+		}
+
+		std::vector<math::Vec3> elements;
+		for (unsigned i = 0;; ++i)
+		{
+			const XmlElement element = array.childElement("element", i);
+			if (!element.isValid())
+			{
+				break;
+			}
+			try
+			{
+				elements.emplace_back(std::stod(XmlElement::getSafeAttribute(element, "x")),
+									  std::stod(XmlElement::getSafeAttribute(element, "y")),
+									  std::stod(XmlElement::getSafeAttribute(element, "z")));
+			}
+			catch (const std::exception& e)
+			{
+				throw XmlException("Invalid element " + std::to_string(i) + " in the array of receiver '" +
+								   receiver->getName() + "': " + e.what());
+			}
+		}
+		try
+		{
+			receiver->setElementArray(std::move(elements));
+		}
+		catch (const std::runtime_error& e)
+		{
+			throw XmlException(e.what());
+		}
+	}
+
+	/**
+	 * @brief Reads an optional <mount_height> child into a radar component.
+	 *
+	 * @param parent The component XmlElement.
+	 * @param radar The component to configure.
+	 */
+	void parseMountHeight(const XmlElement& parent, radar::Radar* radar)
+	{
+		if (const XmlElement height = parent.childElement("mount_height", 0); height.isValid())
+		{
+			radar->setMountHeight(std::stod(height.getText()));
+		}
+	}
+
+	/**
+	 * @brief Reads an optional <max_duty_cycle> child into a transmitter.
+	 *
+	 * @param parent The <transmitter> or <monostatic> XmlElement.
+	 * @param transmitter The transmitter to configure.
+	 * @throws XmlException If the limit is not in the range (0, 1].
+	 */
+	void parseMaxDutyCycle(const XmlElement& parent, radar::Transmitter* transmitter)
+	{
+		const XmlElement element = parent.childElement("max_duty_cycle", 0);
+		if (!element.isValid())
+		{
+			return;
+		}
+
+		const RealType limit = std::stod(element.getText());
+		try
+		{
+			radar::validateMaxDutyCycle(limit, transmitter->getName());
+		}
+		catch (const std::runtime_error& e)
+		{
+			throw XmlException(e.what());
+		}
+		transmitter->setMaxDutyCycle(limit);
+	}
+
+	/**
+	 * @brief Reads an optional <hopsequence> child of a transmitter's <pulsed_mode> element.
+	 *
+	 * @param pulsedMode The <pulsed_mode> XmlElement.
+	 * @param transmitter The transmitter to configure.
+	 * @throws XmlException If the hop sequence is malformed.
+	 */
+	void parseHopSequence(const XmlElement& pulsedMode, radar::Transmitter* transmitter)
+	{
+		const XmlElement hop_element = pulsedMode.childElement("hopsequence", 0);
+		if (!hop_element.isValid())
+		{
+			return;
+		}
+
+		radar::HopSequence sequence;
+		try
+		{
+			for (unsigned i = 0;; ++i)
+			{
+				const XmlElement frequency = hop_element.childElement("frequency", i);
+				if (!frequency.isValid())
+				{
+					break;
+				}
+				sequence.frequencies.push_back(std::stod(frequency.getText()));
+			}
+			std::string pattern_text;
+			try
+			{
+				pattern_text = XmlElement::getSafeAttribute(hop_element, "pattern");
+			}
+			catch (const XmlException&)
+			{
+				// Without a pattern the frequencies are cycled in order
+			}
+			std::istringstream pattern(pattern_text);
+			long index;
+			while (pattern >> index)
+			{
+				if (index < 0)
+				{
+					throw XmlException("hop pattern indices must not be negative");
+				}
+				sequence.pattern.push_back(static_cast<unsigned>(index));
+			}
+			if (!pattern.eof())
+			{
+				throw XmlException("hop pattern must be a list of integer indices");
+			}
+			radar::validateHopSequence(sequence, transmitter->getName());
+		}
+		catch (const std::exception& e)
+		{
+			throw XmlException("Invalid hop sequence for transmitter '" + transmitter->getName() + "': " + e.what());
+		}
+		transmitter->setHopSequence(std::move(sequence));
+	}
+
+	/**
+	 * @brief Reads a component's asset reference attribute, falling back to the scenario default.
+	 *
+	 * @param element The component XmlElement.
+	 * @param attribute The name of the asset reference attribute (e.g. "antenna").
+	 * @param fallback The scenario-level default for this asset type, if any.
+	 * @return The referenced asset name.
+	 * @throws XmlException if the attribute is missing and no default is set.
+	 */
+	std::string getAssetAttribute(const XmlElement& element, const std::string_view attribute,
+								  const std::optional<std::string>& fallback)
+	{
+		try
+		{
+			return XmlElement::getSafeAttribute(element, attribute);
+		}
+		catch (const XmlException&)
+		{
+			if (fallback)
+			{
+				return *fallback;
+			}
+			throw;
+		}
+	}
+
+	/**
+	 * @brief Parses the optional <metadata> element of the XML document.
+	 *
+	 * @param metadata The <metadata> XmlElement to parse.
+	 */
+	void parseMetadata(const XmlElement& metadata)
+	{
+		const auto child_text = [&](const std::string& name)
+		{
+			const XmlElement child = metadata.childElement(name, 0);
+			return child.isValid() ? child.getText() : std::string{};
+		};
+
+		params::params.metadata = params::ScenarioMetadata{.author = child_text("author"),
+														   .created = child_text("created"),
+														   .modified = child_text("modified"),
+														   .tool_version = child_text("toolversion")};
+	}
+
+	/**
+	 * @brief Parses the <multipath> element describing a flat reflecting surface.
+	 *
+	 * @param multipath The <multipath> XmlElement to parse.
+	 * @throws XmlException if a child is missing or the surface normal is zero.
+	 */
+	void parseMultipath(const XmlElement& multipath)
+	{
+		const params::MultipathSurface surface{.factor = get_child_real_type(multipath, "factor"),
+											   .nx = get_child_real_type(multipath, "nx"),
+											   .ny = get_child_real_type(multipath, "ny"),
+											   .nz = get_child_real_type(multipath, "nz"),
+											   .d = get_child_real_type(multipath, "d")};
+		if (surface.nx == 0 && surface.ny == 0 && surface.nz == 0)
+		{
+			throw XmlException("Multipath surface normal must not be zero.");
+		}
+		params::params.multipath = surface;
+		LOG(Level::WARNING, "Multipath surface is stored with the scenario but reflections are not simulated.");
+	}
+
+	/**
+	 * @brief Parses the <terrain> element and loads its height field into the world.
+	 *
+	 * @param terrain The <terrain> XmlElement to parse.
+	 * @param world A pointer to the World object the terrain is attached to.
+	 * @param baseDir The base directory of the main scenario file to resolve relative paths.
+	 * @param hasOrigin Whether the <parameters> element declares a geodetic origin.
+	 * @throws XmlException If the scenario has no geodetic origin or is not in the ENU frame,
+	 *         or the file is missing or invalid.
+	 */
+	void parseTerrain(const XmlElement& terrain, World* world, const fs::path& baseDir, const bool hasOrigin)
+	{
+		const std::string filename = XmlElement::getSafeAttribute(terrain, "filename");
+		if (!hasOrigin || params::coordinateFrame() != params::CoordinateFrame::ENU)
+		{
+			throw XmlException("Terrain '" + filename + "' requires an ENU scenario with a geodetic <origin>.");
+		}
+
+		fs::path terrain_path(filename);
+		if (!fs::exists(terrain_path))
+		{
+			terrain_path = baseDir / filename;
+		}
+		if (!fs::exists(terrain_path))
+		{
+			throw XmlException("Terrain file not found: " + filename);
+		}
+
+		try
+		{
+			world->setTerrain(math::loadTerrainFromFile(
+				terrain_path.string(),
+				{params::originLatitude(), params::originLongitude(), params::originAltitude()}));
+		}
+		catch (const std::exception& e)
+		{
+			throw XmlException(e.what());
+		}
+	}
+
 	/**
 	 * @brief Parses the <parameters> element of the XML document.
 	 *
@@ -208,6 +511,46 @@ namespace
 		set_param_with_exception_handling(parameters, "oversample", params::oversampleRatio(),
 										  params::setOversampleRatio);
 
+		if (const XmlElement element = parameters.childElement("default_antenna", 0); element.isValid())
+		{
+			params::params.default_antenna = element.getText();
+		}
+		if (const XmlElement element = parameters.childElement("default_timing", 0); element.isValid())
+		{
+			params::params.default_timing = element.getText();
+		}
+
+		if (const XmlElement element = parameters.childElement("export", 0); element.isValid())
+		{
+			try
+			{
+				const std::string h5 = XmlElement::getSafeAttribute(element, "h5");
+				params::params.export_options.h5 = h5 == "true" || h5 == "1";
+			}
+			catch (const XmlException&)
+			{
+				// Attribute is optional; keep the default
+			}
+			try
+			{
+				const std::string timestamps = XmlElement::getSafeAttribute(element, "timestamps");
+				params::params.export_options.timestamps = timestamps == "true" || timestamps == "1";
+			}
+			catch (const XmlException&)
+			{
+				// Attribute is optional; keep the default
+			}
+			try
+			{
+				const std::string debug = XmlElement::getSafeAttribute(element, "debug");
+				params::params.export_options.debug = debug == "true" || debug == "1";
+			}
+			catch (const XmlException&)
+			{
+				// Attribute is optional; keep the default
+			}
+		}
+
 		// Parse the origin element for the KML generator
 		bool origin_set = false;
 		if (const XmlElement origin_element = parameters.childElement("origin", 0); origin_element.isValid())
@@ -305,7 +648,19 @@ namespace
 
 		if (const XmlElement pulsed_file = waveform.childElement("pulsed_from_file", 0); pulsed_file.isValid())
 		{
-			const std::string filename_str = XmlElement::getSafeAttribute(pulsed_file, "filename");
+			std::string filename_str;
+			try
+			{
+				filename_str = XmlElement::getSafeAttribute(pulsed_file, "filename");
+			}
+			catch (const XmlException&)
+			{
+				// Reported below together with an empty filename
+			}
+			if (filename_str.empty())
+			{
+				throw XmlException("Waveform '" + name + "' is read from a file but no filename is given.");
+			}
 			fs::path pulse_path(filename_str);
 
 			// Check if path exists as is, if not, try relative to the main XML directory
@@ -322,6 +677,19 @@ namespace
 			auto wave = serial::loadWaveformFromFile(name, pulse_path.string(), power, carrier);
 			world->add(std::move(wave));
 		}
+		else if (const XmlElement inline_samples = waveform.childElement("inline_samples", 0); inline_samples.isValid())
+		{
+			try
+			{
+				const RealType rate = std::stod(XmlElement::getSafeAttribute(inline_samples, "rate"));
+				world->add(serial::loadWaveformFromSamples(name, serial::parseInlineSamples(inline_samples.getText()),
+														   rate, power, carrier));
+			}
+			catch (const std::exception& e)
+			{
+				throw XmlException("Invalid inline samples for waveform '" + name + "': " + e.what());
+			}
+		}
 		else if (waveform.childElement("cw", 0).isValid())
 		{
 			auto cw_signal = std::make_unique<fers_signal::CwSignal>();
@@ -444,13 +812,28 @@ namespace
 		{
 			ant = std::make_unique<antenna::Parabolic>(name, get_child_real_type(antenna, "diameter"));
 		}
+		else if (pattern == "array")
+		{
+			std::vector<RealType> taper;
+			if (const XmlElement taper_element = antenna.childElement("taper", 0); taper_element.isValid())
+			{
+				std::istringstream weights(taper_element.getText());
+				for (RealType w; weights >> w;)
+				{
+					taper.push_back(w);
+				}
+			}
+			ant = std::make_unique<antenna::LinearArray>(
+				name, static_cast<std::size_t>(get_child_real_type(antenna, "elements")),
+				get_child_real_type(antenna, "spacing"), std::move(taper));
+		}
 		else if (pattern == "xml")
 		{
 			ant = std::make_unique<antenna::XmlAntenna>(name, XmlElement::getSafeAttribute(antenna, "filename"));
 		}
 		else if (pattern == "file")
 		{
-			ant = std::make_unique<antenna::H5Antenna>(name, XmlElement::getSafeAttribute(antenna, "filename"));
+			ant = antenna::loadAntennaFromFile(name, XmlElement::getSafeAttribute(antenna, "filename"));
 		}
 		else
 		{
@@ -475,8 +858,11 @@ namespace
 	 *
 	 * @param motionPath The <motionpath> XmlElement to parse.
 	 * @param platform A pointer to the Platform object where the motion path is set.
+	 * @param scenarioOrigin The scenario's geodetic origin, if the <parameters> element declares one.
+	 * @throws XmlException If the waypoints are in ECEF but neither the platform nor the scenario declares an origin.
 	 */
-	void parseMotionPath(const XmlElement& motionPath, const Platform* platform)
+	void parseMotionPath(const XmlElement& motionPath, const Platform* platform,
+						 const std::optional<radar::GeodeticOrigin>& scenarioOrigin)
 	{
 		Path* path = platform->getMotionPath();
 		try
@@ -493,6 +879,10 @@ namespace
 			{
 				path->setInterp(Path::InterpType::INTERP_STATIC);
 			}
+			else if (interp == "ballistic")
+			{
+				path->setInterp(Path::InterpType::INTERP_BALLISTIC);
+			}
 			else
 			{
 				LOG(Level::ERROR, "Unsupported interpolation type: {} for platform {}. Defaulting to static", interp,
@@ -507,6 +897,28 @@ namespace
 			path->setInterp(Path::InterpType::INTERP_STATIC);
 		}
 
+		// ECEF waypoints are converted into the local frame of the platform's origin, or the scenario's
+		std::optional<radar::GeodeticOrigin> ecef_origin;
+		std::string coordinates = "local";
+		try
+		{
+			coordinates = XmlElement::getSafeAttribute(motionPath, "coordinates");
+		}
+		catch (const XmlException&)
+		{
+			// The attribute is optional and defaults to local coordinates
+		}
+		if (coordinates == "ecef")
+		{
+			ecef_origin = platform->getOrigin() ? platform->getOrigin() : scenarioOrigin;
+			if (!ecef_origin)
+			{
+				throw XmlException("Motion path of platform '" + platform->getName() +
+								   "' uses ECEF coordinates but no geodetic <origin> is declared.");
+			}
+			path->setWaypointFrame(Path::WaypointFrame::ECEF);
+		}
+
 		unsigned waypoint_index = 0;
 		while (true)
 		{
@@ -522,7 +934,33 @@ namespace
 				coord.t = get_child_real_type(waypoint, "time");
 				coord.pos = math::Vec3(get_child_real_type(waypoint, "x"), get_child_real_type(waypoint, "y"),
 									   get_child_real_type(waypoint, "altitude"));
-				path->addCoord(coord);
+
+				std::optional<math::Vec3> velocity;
+				const bool has_vx = waypoint.childElement("vx", 0).isValid();
+				const bool has_vy = waypoint.childElement("vy", 0).isValid();
+				const bool has_vz = waypoint.childElement("vz", 0).isValid();
+				if (has_vx || has_vy || has_vz)
+				{
+					if (!(has_vx && has_vy && has_vz))
+					{
+						throw XmlException("Waypoint velocity requires all of <vx>, <vy> and <vz>.");
+					}
+					velocity = math::Vec3(get_child_real_type(waypoint, "vx"), get_child_real_type(waypoint, "vy"),
+										  get_child_real_type(waypoint, "vz"));
+				}
+
+				if (ecef_origin)
+				{
+					const auto& [latitude, longitude, altitude] = *ecef_origin;
+					const math::Vec3 local = math::ecefToLocal(coord.pos, latitude, longitude, altitude);
+					if (velocity)
+					{
+						// The frame change is affine, so a velocity maps through the difference of two points
+						velocity = math::ecefToLocal(coord.pos + *velocity, latitude, longitude, altitude) - local;
+					}
+					coord.pos = local;
+				}
+				path->addCoord(coord, velocity);
 				LOG(Level::TRACE, "Added waypoint {} to motion path for platform {}.", waypoint_index,
 					platform->getName());
 			}
@@ -534,6 +972,30 @@ namespace
 			waypoint_index++;
 		}
 
+		if (path->getType() != Path::InterpType::INTERP_CUBIC && path->hasWaypointVelocities())
+		{
+			LOG(Level::WARNING, "Motion path of platform {} is not cubic; its waypoint velocities are ignored.",
+				platform->getName());
+		}
+
+		if (path->getType() == Path::InterpType::INTERP_BALLISTIC)
+		{
+			const XmlElement velocity = motionPath.childElement("launchvelocity", 0);
+			if (!velocity.isValid())
+			{
+				throw XmlException("Ballistic motion path of platform '" + platform->getName() +
+								   "' requires a <launchvelocity> element.");
+			}
+			RealType gravity = STANDARD_GRAVITY;
+			if (motionPath.childElement("gravity", 0).isValid())
+			{
+				gravity = get_child_real_type(motionPath, "gravity");
+			}
+			path->setBallistic(math::Vec3(get_child_real_type(velocity, "x"), get_child_real_type(velocity, "y"),
+										  get_child_real_type(velocity, "z")),
+							   gravity);
+		}
+
 		path->finalize();
 	}
 
@@ -682,9 +1144,10 @@ namespace
 		if (is_pulsed)
 		{
 			transmitter_obj->setPrf(get_child_real_type(pulsed_mode_element, "prf"));
+			parseHopSequence(pulsed_mode_element, transmitter_obj.get());
 		}
 
-		const std::string antenna_name = XmlElement::getSafeAttribute(transmitter, "antenna");
+		const std::string antenna_name = getAssetAttribute(transmitter, "antenna", params::params.default_antenna);
 		const Antenna* ant = world->findAntenna(antenna_name);
 		if (!ant)
 		{
@@ -692,7 +1155,7 @@ namespace
 		}
 		transmitter_obj->setAntenna(ant);
 
-		const std::string timing_name = XmlElement::getSafeAttribute(transmitter, "timing");
+		const std::string timing_name = getAssetAttribute(transmitter, "timing", params::params.default_timing);
 		const auto timing = std::make_shared<Timing>(timing_name, masterSeeder());
 		const PrototypeTiming* proto = world->findTiming(timing_name);
 		if (!proto)
@@ -702,6 +1165,10 @@ namespace
 		timing->initializeModel(proto);
 		transmitter_obj->setTiming(timing);
 
+		parsePhaseCenterOffset(transmitter, transmitter_obj.get());
+		parseMountHeight(transmitter, transmitter_obj.get());
+		parseMaxDutyCycle(transmitter, transmitter_obj.get());
+
 		// Use shared logic for schedule parsing
 		RealType pri = is_pulsed ? (1.0 / transmitter_obj->getPrf()) : 0.0;
 		auto schedule = parseSchedule(transmitter, name, is_pulsed, pri);
@@ -732,7 +1199,7 @@ namespace
 
 		auto receiver_obj = std::make_unique<Receiver>(platform, name, masterSeeder(), mode);
 
-		const std::string ant_name = XmlElement::getSafeAttribute(receiver, "antenna");
+		const std::string ant_name = getAssetAttribute(receiver, "antenna", params::params.default_antenna);
 
 		const Antenna* antenna = world->findAntenna(ant_name);
 		if (!antenna)
@@ -750,6 +1217,15 @@ namespace
 			LOG(Level::INFO, "Receiver '{}' does not specify noise temperature", receiver_obj->getName().c_str());
 		}
 
+		if (const XmlElement calibration = receiver.childElement("calibration", 0); calibration.isValid())
+		{
+			receiver_obj->loadCalibration(XmlElement::getSafeAttribute(calibration, "filename"));
+		}
+
+		parsePhaseCenterOffset(receiver, receiver_obj.get());
+		parseMountHeight(receiver, receiver_obj.get());
+		parseElementArray(receiver, receiver_obj.get());
+
 		if (is_pulsed)
 		{
 			const RealType window_length = get_child_real_type(pulsed_mode_element, "window_length");
@@ -770,13 +1246,14 @@ namespace
 				throw XmlException("<window_skip> must not be negative for receiver '" + name + "'");
 			}
 			receiver_obj->setWindowProperties(window_length, prf, window_skip);
+			parseBlankingGates(pulsed_mode_element, receiver_obj.get());
 		}
 		else if (!receiver.childElement("cw_mode", 0).isValid())
 		{
 			throw XmlException("Receiver '" + name + "' must specify a radar mode (<pulsed_mode> or <cw_mode>).");
 		}
 
-		const std::string timing_name = XmlElement::getSafeAttribute(receiver, "timing");
+		const std::string timing_name = getAssetAttribute(receiver, "timing", params::params.default_timing);
 		const auto timing = std::make_shared<Timing>(timing_name, masterSeeder());
 
 		const PrototypeTiming* proto = world->findTiming(timing_name);
@@ -827,6 +1304,43 @@ namespace
 		recv->setAttached(trans);
 	}
 
+	/**
+	 * @brief Parses the optional interpolation attribute of a file-based <rcs> element.
+	 *
+	 * @param rcsElement The <rcs> XmlElement to parse.
+	 * @param target The file-based target to configure. Linear interpolation is kept if the attribute is absent.
+	 * @throws XmlException If the interpolation mode is not recognised.
+	 */
+	void parseRcsInterpolation(const XmlElement& rcsElement, radar::FileTarget& target)
+	{
+		std::string mode;
+		try
+		{
+			mode = XmlElement::getSafeAttribute(rcsElement, "interpolation");
+		}
+		catch (const XmlException&)
+		{
+			return;
+		}
+
+		if (mode == "nearest")
+		{
+			target.setInterpolation(interp::InterpMode::NEAREST);
+		}
+		else if (mode == "linear")
+		{
+			target.setInterpolation(interp::InterpMode::LINEAR);
+		}
+		else if (mode == "cubic")
+		{
+			target.setInterpolation(interp::InterpMode::CUBIC);
+		}
+		else
+		{
+			throw XmlException("Unsupported RCS interpolation: " + mode);
+		}
+	}
+
 	/**
 	 * @brief Parses the <target> element of the XML document.
 	 *
@@ -858,6 +1372,7 @@ namespace
 		else if (rcs_type == "file")
 		{
 			target_obj = createFileTarget(platform, name, XmlElement::getSafeAttribute(rcs_element, "filename"), seed);
+			parseRcsInterpolation(rcs_element, dynamic_cast<radar::FileTarget&>(*target_obj));
 		}
 		else
 		{
@@ -868,17 +1383,89 @@ namespace
 		{
 			if (const std::string model_type = XmlElement::getSafeAttribute(model, "type"); model_type == "constant")
 			{
+				if (model.childElement("swerling", 0).isValid())
+				{
+					throw XmlException("Swerling case of target '" + name + "' requires a chisquare model.");
+				}
 				target_obj->setFluctuationModel(std::make_unique<radar::RcsConst>());
 			}
 			else if (model_type == "chisquare" || model_type == "gamma")
 			{
-				target_obj->setFluctuationModel(
-					std::make_unique<radar::RcsChiSquare>(target_obj->getRngEngine(), get_child_real_type(model, "k")));
+				// Older scenarios give only k; a Swerling case implies k when it is omitted
+				if (const XmlElement swerling = model.childElement("swerling", 0); swerling.isValid())
+				{
+					target_obj->setSwerlingCase(static_cast<unsigned>(std::stoul(swerling.getText())));
+				}
+				const auto swerling_case = target_obj->getSwerlingCase();
+				RealType k = 0;
+				if (model.childElement("k", 0).isValid())
+				{
+					k = get_child_real_type(model, "k");
+					if (swerling_case && k != radar::swerlingChiSquareK(*swerling_case))
+					{
+						throw XmlException("Model k of target '" + name + "' does not match Swerling case " +
+										   std::to_string(*swerling_case) + ".");
+					}
+				}
+				else if (swerling_case)
+				{
+					k = radar::swerlingChiSquareK(*swerling_case);
+				}
+				else
+				{
+					throw XmlException("Chi-square model of target '" + name + "' needs <k> or <swerling>.");
+				}
+				target_obj->setFluctuationModel(std::make_unique<radar::RcsChiSquare>(target_obj->getRngEngine(), k));
 			}
 			else
 			{
 				throw XmlException("Unsupported model type: " + model_type);
 			}
+
+			if (model.childElement("decorrelation_time", 0).isValid())
+			{
+				target_obj->setDecorrelationTime(get_child_real_type(model, "decorrelation_time"));
+			}
+			if (model.childElement("sample_rate", 0).isValid())
+			{
+				target_obj->setRcsSampleRate(get_child_real_type(model, "sample_rate"));
+			}
+		}
+
+		if (const XmlElement micro = target.childElement("micromotion", 0); micro.isValid())
+		{
+			radar::MicroMotion micro_motion{};
+			std::string micro_type = "vibration";
+			try
+			{
+				micro_type = XmlElement::getSafeAttribute(micro, "type");
+			}
+			catch (const XmlException&)
+			{
+				// Attribute is optional and defaults to vibration.
+			}
+			if (micro_type == "vibration")
+			{
+				micro_motion.type = radar::MicroMotion::Type::VIBRATION;
+			}
+			else if (micro_type == "rotation")
+			{
+				micro_motion.type = radar::MicroMotion::Type::ROTATION;
+			}
+			else
+			{
+				throw XmlException("Unsupported micromotion type: " + micro_type);
+			}
+			micro_motion.amplitude = get_child_real_type(micro, "amplitude");
+			micro_motion.frequency = get_child_real_type(micro, "frequency");
+			const XmlElement axis = micro.childElement("axis", 0);
+			if (!axis.isValid())
+			{
+				throw XmlException("<axis> element is required in <micromotion>!");
+			}
+			micro_motion.axis = math::Vec3(get_child_real_type(axis, "x"), get_child_real_type(axis, "y"),
+										   get_child_real_type(axis, "z"));
+			target_obj->setMicroMotion(micro_motion);
 		}
 
 		LOG(Level::DEBUG, "Added target {} with RCS type {} to platform {}", name, rcs_type, platform->getName());
@@ -914,17 +1501,86 @@ namespace
 	 * @param platform The <platform> XmlElement to parse.
 	 * @param world A pointer to the World object where the Platform object is added.
 	 * @param masterSeeder The master random number generator for seeding.
+	 * @param scenarioOrigin The scenario's geodetic origin, if the <parameters> element declares one.
 	 */
-	void parsePlatform(const XmlElement& platform, World* world, std::mt19937& masterSeeder)
+	void parsePlatform(const XmlElement& platform, World* world, std::mt19937& masterSeeder,
+					   const std::optional<radar::GeodeticOrigin>& scenarioOrigin)
 	{
 		std::string name = XmlElement::getSafeAttribute(platform, "name");
 		auto plat = std::make_unique<Platform>(name);
 
+		// Parse the optional platform-local tangent plane origin
+		if (const XmlElement origin_element = platform.childElement("origin", 0); origin_element.isValid())
+		{
+			try
+			{
+				const RealType latitude = std::stod(XmlElement::getSafeAttribute(origin_element, "latitude"));
+				const RealType longitude = std::stod(XmlElement::getSafeAttribute(origin_element, "longitude"));
+				const RealType altitude = std::stod(XmlElement::getSafeAttribute(origin_element, "altitude"));
+				plat->setOrigin(radar::GeodeticOrigin{latitude, longitude, altitude});
+			}
+			catch (const std::exception& e)
+			{
+				throw XmlException("Invalid origin for platform '" + name + "': " + e.what());
+			}
+		}
+
+		// Parse the optional position process noise
+		if (const XmlElement noise_element = platform.childElement("positionnoise", 0); noise_element.isValid())
+		{
+			radar::PositionNoise noise;
+			try
+			{
+				noise.std_dev = {std::stod(XmlElement::getSafeAttribute(noise_element, "x")),
+								 std::stod(XmlElement::getSafeAttribute(noise_element, "y")),
+								 std::stod(XmlElement::getSafeAttribute(noise_element, "z"))};
+			}
+			catch (const std::exception& e)
+			{
+				throw XmlException("Invalid position noise for platform '" + name + "': " + e.what());
+			}
+			try
+			{
+				noise.seed = static_cast<unsigned>(std::stoul(XmlElement::getSafeAttribute(noise_element, "seed")));
+			}
+			catch (const XmlException&)
+			{
+				// Without a seed, each platform gets its own realization of the scenario seed
+				noise.seed = radar::defaultPositionNoiseSeed(params::randomSeed(), world->getPlatforms().size());
+			}
+			catch (const std::exception& e)
+			{
+				throw XmlException("Invalid position noise for platform '" + name + "': " + e.what());
+			}
+			try
+			{
+				noise.correlation_time = std::stod(XmlElement::getSafeAttribute(noise_element, "correlationtime"));
+			}
+			catch (const XmlException&)
+			{
+				// The correlation time is optional and keeps its default
+			}
+			catch (const std::exception& e)
+			{
+				throw XmlException("Invalid position noise for platform '" + name + "': " + e.what());
+			}
+			if (noise.std_dev.x < 0 || noise.std_dev.y < 0 || noise.std_dev.z < 0)
+			{
+				throw XmlException("Position noise standard deviations for platform '" + name +
+								   "' must be non-negative.");
+			}
+			if (!(noise.correlation_time > 0) || !std::isfinite(noise.correlation_time))
+			{
+				throw XmlException("Position noise correlation time for platform '" + name + "' must be positive.");
+			}
+			plat->setPositionNoise(noise);
+		}
+
 		parsePlatformElements(platform, world, plat.get(), masterSeeder);
 
 		if (const XmlElement motion_path = platform.childElement("motionpath", 0); motion_path.isValid())
 		{
-			parseMotionPath(motion_path, plat.get());
+			parseMotionPath(motion_path, plat.get(), scenarioOrigin);
 		}
 
 		// Parse either <rotationpath> or <fixedrotation>
@@ -1049,6 +1705,30 @@ namespace
 		LOG(Level::DEBUG, "{} XML file passed XSD validation.", didCombine ? "Combined" : "Main");
 	}
 
+	/**
+	 * @brief Warns if a scenario carries a checksum that does not match its content.
+	 *
+	 * @param content The scenario text as read.
+	 * @param source A description of where the scenario came from, for the log.
+	 */
+	void checkScenarioChecksum(const std::string_view content, const std::string& source)
+	{
+		const auto valid = serial::verifyScenarioChecksum(content);
+		if (!valid)
+		{
+			return;
+		}
+		if (*valid)
+		{
+			LOG(Level::DEBUG, "Checksum of {} verified.", source);
+		}
+		else
+		{
+			LOG(Level::WARNING, "Checksum of {} does not match its content; it may be corrupted or edited by hand.",
+				source);
+		}
+	}
+
 	void processParsedDocument(const XmlDocument& doc, World* world, const fs::path& baseDir,
 							   std::mt19937& masterSeeder)
 	{
@@ -1071,13 +1751,37 @@ namespace
 			LOG(logging::Level::WARNING, "No 'name' attribute found in <simulation> tag. KML name will default.");
 		}
 
+		if (const XmlElement metadata = root.childElement("metadata", 0); metadata.isValid())
+		{
+			parseMetadata(metadata);
+		}
+		if (const XmlElement ui = root.childElement("ui", 0); ui.isValid())
+		{
+			params::params.ui_state = ui.getText();
+		}
 		parseParameters(root.childElement("parameters", 0));
+		if (const XmlElement multipath = root.childElement("multipath", 0); multipath.isValid())
+		{
+			parseMultipath(multipath);
+		}
+		const bool has_origin = root.childElement("parameters", 0).childElement("origin", 0).isValid();
+		if (const XmlElement terrain = root.childElement("terrain", 0); terrain.isValid())
+		{
+			parseTerrain(terrain, world, baseDir, has_origin);
+		}
 		auto waveform_parser = [&](const XmlElement& p, World* w) { parseWaveform(p, w, baseDir); };
 		parseElements(root, "waveform", world, waveform_parser);
 		parseElements(root, "timing", world, parseTiming);
 		parseElements(root, "antenna", world, parseAntenna);
 
-		auto platform_parser = [&](const XmlElement& p, World* w) { parsePlatform(p, w, masterSeeder); };
+		std::optional<radar::GeodeticOrigin> scenario_origin;
+		if (has_origin)
+		{
+			scenario_origin = radar::GeodeticOrigin{params::originLatitude(), params::originLongitude(),
+													params::originAltitude()};
+		}
+		auto platform_parser = [&](const XmlElement& p, World* w)
+		{ parsePlatform(p, w, masterSeeder, scenario_origin); };
 		parseElements(root, "platform", world, platform_parser);
 
 		// Prepare CW receiver buffers before starting simulation
@@ -1113,6 +1817,12 @@ namespace serial
 			throw XmlException("Failed to load main XML file: " + filename);
 		}
 
+		if (std::ifstream file(filename, std::ios::binary); file)
+		{
+			const std::string content{std::istreambuf_iterator<char>(file), std::istreambuf_iterator<char>()};
+			checkScenarioChecksum(content, "scenario file '" + filename + "'");
+		}
+
 		const fs::path main_dir = fs::path(filename).parent_path();
 		const bool did_combine = addIncludeFilesToMainDocument(main_doc, main_dir);
 
@@ -1138,6 +1848,7 @@ namespace serial
 		{
 			throw XmlException("Failed to parse XML from memory string.");
 		}
+		checkScenarioChecksum(xmlContent, "scenario string");
 
 		if (validate)
 		{
@@ -1155,4 +1866,23 @@ namespace serial
 
 		processParsedDocument(doc, world, base_dir, masterSeeder);
 	}
+
+	std::optional<std::string> findXmlValidationError(const std::string& xmlContent)
+	{
+		XmlDocument doc;
+		if (!doc.loadString(xmlContent))
+		{
+			return "XML is not well-formed.";
+		}
+		try
+		{
+			(void)doc.validateWithDtd(fers_xml_dtd);
+			(void)doc.validateWithXsd(fers_xml_xsd);
+		}
+		catch (const XmlException& e)
+		{
+			return e.what();
+		}
+		return std::nullopt;
+	}
 }
@@ -12,6 +12,7 @@
 
 #pragma once
 
+#include <optional>
 #include <random>
 #include <string>
 
@@ -40,4 +41,14 @@ namespace serial
 
 	void parseSimulationFromString(const std::string& xmlContent, core::World* world, bool validate,
 								   std::mt19937& masterSeeder);
+
+	/**
+	 * @brief Checks a scenario document against the FERS DTD and XSD without loading it.
+	 *
+	 * `<include>` elements are not expanded, so the document is checked as written.
+	 *
+	 * @param xmlContent The scenario XML.
+	 * @return Nothing if the document is valid, otherwise the first validation error.
+	 */
+	[[nodiscard]] std::optional<std::string> findXmlValidationError(const std::string& xmlContent);
 }
@@ -16,16 +16,21 @@
 
 #include "xml_serializer.h"
 
+#include <format>
 #include <iomanip>
 #include <ranges>
 #include <sstream>
+#include <stdexcept>
 
 #include "antenna/antenna_factory.h"
+#include "checksum.h"
 #include "core/config.h"
 #include "core/parameters.h"
 #include "core/world.h"
+#include "json_serializer.h"
 #include "libxml_wrapper.h"
 #include "math/coord.h"
+#include "math/geodesy.h"
 #include "math/path.h"
 #include "math/rotation_path.h"
 #include "radar/platform.h"
@@ -35,6 +40,7 @@
 #include "signal/radar_signal.h"
 #include "timing/prototype_timing.h"
 #include "timing/timing.h"
+#include "waveform_factory.h"
 
 namespace
 {
@@ -96,6 +102,30 @@ namespace
 		}
 	}
 
+	void serializeMetadata(const params::ScenarioMetadata& metadata, const XmlElement& parent)
+	{
+		const auto add_if_set = [&](const std::string& name, const std::string& value)
+		{
+			if (!value.empty())
+			{
+				addChildWithText(parent, name, value);
+			}
+		};
+		add_if_set("author", metadata.author);
+		add_if_set("created", metadata.created);
+		add_if_set("modified", metadata.modified);
+		add_if_set("toolversion", metadata.tool_version);
+	}
+
+	void serializeMultipath(const params::MultipathSurface& surface, const XmlElement& parent)
+	{
+		addChildWithNumber(parent, "factor", surface.factor);
+		addChildWithNumber(parent, "nx", surface.nx);
+		addChildWithNumber(parent, "ny", surface.ny);
+		addChildWithNumber(parent, "nz", surface.nz);
+		addChildWithNumber(parent, "d", surface.d);
+	}
+
 	void serializeParameters(const XmlElement& parent)
 	{
 		addChildWithNumber(parent, "starttime", params::startTime());
@@ -122,6 +152,31 @@ namespace
 		{
 			addChildWithNumber(parent, "oversample", params::oversampleRatio());
 		}
+		if (params::params.default_antenna)
+		{
+			addChildWithText(parent, "default_antenna", *params::params.default_antenna);
+		}
+		if (params::params.default_timing)
+		{
+			addChildWithText(parent, "default_timing", *params::params.default_timing);
+		}
+
+		if (const auto& options = params::params.export_options; !options.h5 || options.timestamps || options.debug)
+		{
+			const XmlElement export_elem = parent.addChild("export");
+			if (!options.h5)
+			{
+				export_elem.setAttribute("h5", "false");
+			}
+			if (options.timestamps)
+			{
+				export_elem.setAttribute("timestamps", "true");
+			}
+			if (options.debug)
+			{
+				export_elem.setAttribute("debug", "true");
+			}
+		}
 
 		const XmlElement origin = parent.addChild("origin");
 		origin.setAttribute("latitude", std::to_string(params::originLatitude()));
@@ -156,6 +211,12 @@ namespace
 		{
 			(void)parent.addChild("cw"); // Empty element
 		}
+		else if (const auto& inline_samples = waveform.getInlineSamples())
+		{
+			const XmlElement samples_elem = parent.addChild("inline_samples");
+			samples_elem.setAttribute("rate", std::format("{}", inline_samples->rate));
+			samples_elem.setText(serial::formatInlineSamples(inline_samples->samples));
+		}
 		else
 		{
 			const XmlElement pulsed_file = parent.addChild("pulsed_from_file");
@@ -200,11 +261,28 @@ namespace
 		}
 	}
 
+	void setPatternFile(const antenna::Antenna& antenna, const std::string& pattern, const std::string& filename,
+	                    const XmlElement& parent)
+	{
+		// Without a filename the exported scenario could not be loaded again
+		if (filename.empty())
+		{
+			throw std::runtime_error(
+				std::format("Antenna '{}' uses a '{}' pattern but has no filename.", antenna.getName(), pattern));
+		}
+		parent.setAttribute("pattern", pattern);
+		parent.setAttribute("filename", filename);
+	}
+
 	void serializeAntenna(const antenna::Antenna& antenna, const XmlElement& parent)
 	{
 		parent.setAttribute("name", antenna.getName());
 
-		if (const auto* sinc = dynamic_cast<const antenna::Sinc*>(&antenna))
+		if (dynamic_cast<const antenna::Isotropic*>(&antenna))
+		{
+			parent.setAttribute("pattern", "isotropic");
+		}
+		else if (const auto* sinc = dynamic_cast<const antenna::Sinc*>(&antenna))
 		{
 			parent.setAttribute("pattern", "sinc");
 			addChildWithNumber(parent, "alpha", sinc->getAlpha());
@@ -227,19 +305,35 @@ namespace
 			parent.setAttribute("pattern", "parabolic");
 			addChildWithNumber(parent, "diameter", parabolic->getDiameter());
 		}
+		else if (const auto* array = dynamic_cast<const antenna::LinearArray*>(&antenna))
+		{
+			parent.setAttribute("pattern", "array");
+			addChildWithNumber(parent, "elements", array->getElementCount());
+			addChildWithNumber(parent, "spacing", array->getSpacing());
+			std::ostringstream taper;
+			taper << std::setprecision(std::numeric_limits<RealType>::max_digits10);
+			for (std::size_t i = 0; i < array->getTaper().size(); ++i)
+			{
+				taper << (i == 0 ? "" : " ") << array->getTaper()[i];
+			}
+			addChildWithText(parent, "taper", taper.str());
+		}
 		else if (const auto* xml_ant = dynamic_cast<const antenna::XmlAntenna*>(&antenna))
 		{
-			parent.setAttribute("pattern", "xml");
-			parent.setAttribute("filename", xml_ant->getFilename());
+			setPatternFile(antenna, "xml", xml_ant->getFilename(), parent);
 		}
 		else if (const auto* h5_ant = dynamic_cast<const antenna::H5Antenna*>(&antenna))
 		{
-			parent.setAttribute("pattern", "file");
-			parent.setAttribute("filename", h5_ant->getFilename());
+			setPatternFile(antenna, "file", h5_ant->getFilename(), parent);
+		}
+		else if (const auto* pat_ant = dynamic_cast<const antenna::PatAntenna*>(&antenna))
+		{
+			setPatternFile(antenna, "file", pat_ant->getFilename(), parent);
 		}
 		else
 		{
-			parent.setAttribute("pattern", "isotropic");
+			throw std::logic_error(
+				std::format("Antenna '{}' has a pattern that cannot be exported.", antenna.getName()));
 		}
 
 		if (antenna.getEfficiencyFactor() != 1.0)
@@ -248,7 +342,7 @@ namespace
 		}
 	}
 
-	void serializeMotionPath(const math::Path& path, const XmlElement& parent)
+	void serializeMotionPath(const math::Path& path, const radar::GeodeticOrigin& origin, const XmlElement& parent)
 	{
 		switch (path.getType())
 		{
@@ -261,15 +355,46 @@ namespace
 		case math::Path::InterpType::INTERP_CUBIC:
 			parent.setAttribute("interpolation", "cubic");
 			break;
+		case math::Path::InterpType::INTERP_BALLISTIC:
+			parent.setAttribute("interpolation", "ballistic");
+			break;
 		}
 
-		for (const auto& [pos, t] : path.getCoords())
+		const bool ecef = path.getWaypointFrame() == math::Path::WaypointFrame::ECEF;
+		if (ecef)
 		{
+			parent.setAttribute("coordinates", "ecef");
+		}
+
+		const auto& coords = path.getCoords();
+		const auto& velocities = path.getWaypointVelocities();
+		const auto to_frame = [&](const math::Vec3& local)
+		{ return ecef ? math::localToEcef(local, origin.latitude, origin.longitude, origin.altitude) : local; };
+		for (std::size_t i = 0; i < coords.size(); ++i)
+		{
+			const auto& [local_pos, t] = coords[i];
+			const math::Vec3 pos = to_frame(local_pos);
 			XmlElement wp_elem = parent.addChild("positionwaypoint");
 			addChildWithNumber(wp_elem, "x", pos.x);
 			addChildWithNumber(wp_elem, "y", pos.y);
 			addChildWithNumber(wp_elem, "altitude", pos.z);
 			addChildWithNumber(wp_elem, "time", t);
+			if (const auto& velocity = velocities[i])
+			{
+				const math::Vec3 vel = to_frame(local_pos + *velocity) - pos;
+				addChildWithNumber(wp_elem, "vx", vel.x);
+				addChildWithNumber(wp_elem, "vy", vel.y);
+				addChildWithNumber(wp_elem, "vz", vel.z);
+			}
+		}
+
+		if (path.getType() == math::Path::InterpType::INTERP_BALLISTIC)
+		{
+			const XmlElement velocity_elem = parent.addChild("launchvelocity");
+			addChildWithNumber(velocity_elem, "x", path.getLaunchVelocity().x);
+			addChildWithNumber(velocity_elem, "y", path.getLaunchVelocity().y);
+			addChildWithNumber(velocity_elem, "z", path.getLaunchVelocity().z);
+			addChildWithNumber(parent, "gravity", path.getGravity());
 		}
 	}
 
@@ -326,6 +451,91 @@ namespace
 		}
 	}
 
+	void serializePhaseCenterOffset(const radar::Radar& radar, const XmlElement& parent)
+	{
+		const math::Vec3& offset = radar.getPhaseCenterOffset();
+		if (offset.x != 0 || offset.y != 0 || offset.z != 0)
+		{
+			const XmlElement offset_elem = parent.addChild("phase_center_offset");
+			offset_elem.setAttribute("x", std::to_string(offset.x));
+			offset_elem.setAttribute("y", std::to_string(offset.y));
+			offset_elem.setAttribute("z", std::to_string(offset.z));
+		}
+	}
+
+	void serializeMountHeight(const radar::Radar& radar, const XmlElement& parent)
+	{
+		if (radar.getMountHeight() != 0)
+		{
+			addChildWithNumber(parent, "mount_height", radar.getMountHeight());
+		}
+	}
+
+	void serializeMaxDutyCycle(const radar::Transmitter& tx, const XmlElement& parent)
+	{
+		if (const auto limit = tx.getMaxDutyCycle())
+		{
+			addChildWithNumber(parent, "max_duty_cycle", *limit);
+		}
+	}
+
+	void serializeHopSequence(const radar::Transmitter& tx, const XmlElement& pulsedMode)
+	{
+		const auto& sequence = tx.getHopSequence();
+		if (!sequence)
+		{
+			return;
+		}
+
+		const XmlElement hop_elem = pulsedMode.addChild("hopsequence");
+		if (!sequence->pattern.empty())
+		{
+			std::string pattern;
+			for (const unsigned index : sequence->pattern)
+			{
+				pattern += (pattern.empty() ? "" : " ") + std::to_string(index);
+			}
+			hop_elem.setAttribute("pattern", pattern);
+		}
+		for (const RealType frequency : sequence->frequencies)
+		{
+			addChildWithNumber(hop_elem, "frequency", frequency);
+		}
+	}
+
+	void serializeBlankingGates(const radar::Receiver& rx, const XmlElement& pulsedMode)
+	{
+		const auto& gates = rx.getBlankingGates();
+		if (gates.empty())
+		{
+			return;
+		}
+		const XmlElement blanking_elem = pulsedMode.addChild("blanking");
+		for (const auto& [start, length] : gates)
+		{
+			const XmlElement gate_elem = blanking_elem.addChild("gate");
+			gate_elem.setAttribute("start", std::format("{}", start));
+			gate_elem.setAttribute("length", std::format("{}", length));
+		}
+	}
+
+	void serializeElementArray(const radar::Receiver& rx, const XmlElement& parent)
+	{
+		const auto& elements = rx.getElementArray();
+		if (elements.empty())
+		{
+			return;
+		}
+		const XmlElement array_elem = parent.addChild("element_array");
+		for (const auto& element : elements)
+		{
+			const XmlElement element_elem = array_elem.addChild("element");
+			element_elem.setAttribute("x", std::format("{}", element.x));
+			element_elem.setAttribute("y", std::format("{}", element.y));
+			element_elem.setAttribute("z", std::format("{}", element.z));
+		}
+	}
+
 	void serializeTransmitter(const radar::Transmitter& tx, const XmlElement& parent)
 	{
 		const XmlElement tx_elem = parent.addChild("transmitter");
@@ -338,12 +548,16 @@ namespace
 		{
 			const XmlElement mode_elem = tx_elem.addChild("pulsed_mode");
 			addChildWithNumber(mode_elem, "prf", tx.getPrf());
+			serializeHopSequence(tx, mode_elem);
 		}
 		else
 		{
 			(void)tx_elem.addChild("cw_mode");
 		}
 
+		serializePhaseCenterOffset(tx, tx_elem);
+		serializeMountHeight(tx, tx_elem);
+		serializeMaxDutyCycle(tx, tx_elem);
 		serializeSchedule(tx.getSchedule(), tx_elem);
 	}
 
@@ -362,6 +576,7 @@ namespace
 			addChildWithNumber(mode_elem, "prf", rx.getWindowPrf());
 			addChildWithNumber(mode_elem, "window_skip", rx.getWindowSkip());
 			addChildWithNumber(mode_elem, "window_length", rx.getWindowLength());
+			serializeBlankingGates(rx, mode_elem);
 		}
 		else
 		{
@@ -373,7 +588,16 @@ namespace
 			addChildWithNumber(rx_elem, "noise_temp", rx.getNoiseTemperature());
 		}
 
+		if (!rx.getCalibrationFile().empty())
+		{
+			const XmlElement calibration_elem = rx_elem.addChild("calibration");
+			calibration_elem.setAttribute("filename", rx.getCalibrationFile());
+		}
+
+		serializePhaseCenterOffset(rx, rx_elem);
+		serializeMountHeight(rx, rx_elem);
 		serializeSchedule(rx.getSchedule(), rx_elem);
+		serializeElementArray(rx, rx_elem);
 	}
 
 	void serializeMonostatic(const radar::Transmitter& tx, const radar::Receiver& rx, const XmlElement& parent)
@@ -392,6 +616,8 @@ namespace
 			addChildWithNumber(mode_elem, "prf", tx.getPrf());
 			addChildWithNumber(mode_elem, "window_skip", rx.getWindowSkip());
 			addChildWithNumber(mode_elem, "window_length", rx.getWindowLength());
+			serializeHopSequence(tx, mode_elem);
+			serializeBlankingGates(rx, mode_elem);
 		}
 		else
 		{
@@ -403,7 +629,17 @@ namespace
 			addChildWithNumber(mono_elem, "noise_temp", rx.getNoiseTemperature());
 		}
 
+		if (!rx.getCalibrationFile().empty())
+		{
+			const XmlElement calibration_elem = mono_elem.addChild("calibration");
+			calibration_elem.setAttribute("filename", rx.getCalibrationFile());
+		}
+
+		serializePhaseCenterOffset(tx, mono_elem);
+		serializeMountHeight(tx, mono_elem);
+		serializeMaxDutyCycle(tx, mono_elem);
 		serializeSchedule(tx.getSchedule(), mono_elem);
+		serializeElementArray(rx, mono_elem);
 	}
 
 	void serializeTarget(const radar::Target& target, const XmlElement& parent)
@@ -421,6 +657,18 @@ namespace
 		{
 			rcs_elem.setAttribute("type", "file");
 			rcs_elem.setAttribute("filename", file_target->getFilename());
+			switch (file_target->getInterpolation())
+			{
+			case interp::InterpMode::NEAREST:
+				rcs_elem.setAttribute("interpolation", "nearest");
+				break;
+			case interp::InterpMode::LINEAR:
+				// Linear is the default, so the attribute is omitted.
+				break;
+			case interp::InterpMode::CUBIC:
+				rcs_elem.setAttribute("interpolation", "cubic");
+				break;
+			}
 		}
 
 		// Serialize fluctuation model if present (e.g. Swerling/Chi-Square)
@@ -431,16 +679,62 @@ namespace
 				XmlElement model_elem = target_elem.addChild("model");
 				model_elem.setAttribute("type", "chisquare");
 				addChildWithNumber(model_elem, "k", chi->getK());
+				if (const auto swerling_case = target.getSwerlingCase())
+				{
+					addChildWithNumber(model_elem, "swerling", *swerling_case);
+				}
+				if (const auto sample_rate = target.getRcsSampleRate())
+				{
+					addChildWithNumber(model_elem, "sample_rate", *sample_rate);
+				}
+				if (const auto decorrelation_time = target.getDecorrelationTime())
+				{
+					addChildWithNumber(model_elem, "decorrelation_time", *decorrelation_time);
+				}
 			}
 		}
+
+		if (const auto& micro_motion = target.getMicroMotion())
+		{
+			const XmlElement micro_elem = target_elem.addChild("micromotion");
+			const bool rotation = micro_motion->type == radar::MicroMotion::Type::ROTATION;
+			micro_elem.setAttribute("type", rotation ? "rotation" : "vibration");
+			addChildWithNumber(micro_elem, "amplitude", micro_motion->amplitude);
+			addChildWithNumber(micro_elem, "frequency", micro_motion->frequency);
+			const XmlElement axis_elem = micro_elem.addChild("axis");
+			addChildWithNumber(axis_elem, "x", micro_motion->axis.x);
+			addChildWithNumber(axis_elem, "y", micro_motion->axis.y);
+			addChildWithNumber(axis_elem, "z", micro_motion->axis.z);
+		}
 	}
 
 	void serializePlatform(const radar::Platform& platform, const core::World& world, const XmlElement& parent)
 	{
 		parent.setAttribute("name", platform.getName());
 
+		if (const auto& plat_origin = platform.getOrigin())
+		{
+			const XmlElement origin = parent.addChild("origin");
+			origin.setAttribute("latitude", std::to_string(plat_origin->latitude));
+			origin.setAttribute("longitude", std::to_string(plat_origin->longitude));
+			origin.setAttribute("altitude", std::to_string(plat_origin->altitude));
+		}
+
+		if (const auto& noise = platform.getPositionNoise();
+			noise && (noise->std_dev.x > 0 || noise->std_dev.y > 0 || noise->std_dev.z > 0))
+		{
+			const XmlElement noise_elem = parent.addChild("positionnoise");
+			noise_elem.setAttribute("x", std::to_string(noise->std_dev.x));
+			noise_elem.setAttribute("y", std::to_string(noise->std_dev.y));
+			noise_elem.setAttribute("z", std::to_string(noise->std_dev.z));
+			noise_elem.setAttribute("correlationtime", std::format("{}", noise->correlation_time));
+			noise_elem.setAttribute("seed", std::to_string(noise->seed));
+		}
+
 		const XmlElement motion_elem = parent.addChild("motionpath");
-		serializeMotionPath(*platform.getMotionPath(), motion_elem);
+		const auto motion_origin = platform.getOrigin().value_or(
+			radar::GeodeticOrigin{params::originLatitude(), params::originLongitude(), params::originAltitude()});
+		serializeMotionPath(*platform.getMotionPath(), motion_origin, motion_elem);
 
 		serializeRotation(*platform.getRotationPath(), parent);
 
@@ -486,12 +780,24 @@ namespace
 
 namespace serial
 {
-	std::string world_to_xml_string(const core::World& world)
+	std::string world_to_xml_string(const core::World& world, const bool withChecksum)
 	{
+		// NaN and infinity would be written as text the parser cannot read back
+		if (const auto issues = find_non_finite_values(world); !issues.empty())
+		{
+			std::string message = "Scenario contains non-finite values:";
+			for (const auto& issue : issues)
+			{
+				message += "\n  " + issue;
+			}
+			throw std::runtime_error(message);
+		}
+
 		XmlDocument doc;
 		xmlNodePtr sim_node = xmlNewNode(nullptr, reinterpret_cast<const xmlChar*>("simulation"));
 		XmlElement root(sim_node);
 		doc.setRootElement(root);
+		doc.setDoctype("simulation", "fers-xml.dtd");
 
 		if (!params::params.simulation_name.empty())
 		{
@@ -502,8 +808,29 @@ namespace serial
 			root.setAttribute("name", "FERS Scenario");
 		}
 
+		if (params::params.metadata)
+		{
+			const XmlElement metadata_elem = root.addChild("metadata");
+			serializeMetadata(*params::params.metadata, metadata_elem);
+		}
+		if (params::params.ui_state)
+		{
+			// Editor view state; carried through the file but ignored by the simulator.
+			addChildWithText(root, "ui", *params::params.ui_state);
+		}
+
 		const XmlElement params_elem = root.addChild("parameters");
 		serializeParameters(params_elem);
+		if (params::params.multipath)
+		{
+			const XmlElement multipath_elem = root.addChild("multipath");
+			serializeMultipath(*params::params.multipath, multipath_elem);
+		}
+		if (const auto* terrain = world.getTerrain())
+		{
+			const XmlElement terrain_elem = root.addChild("terrain");
+			terrain_elem.setAttribute("filename", terrain->getFilename());
+		}
 
 		// Assets (waveforms, timings, antennas) are serialized first. This is
 		// necessary because platforms reference these assets by name. By defining
@@ -530,6 +857,7 @@ namespace serial
 			serializePlatform(*platform, world, plat_elem);
 		}
 
-		return doc.dumpToString();
+		std::string xml = doc.dumpToString();
+		return withChecksum ? appendScenarioChecksum(std::move(xml)) : xml;
 	}
 }
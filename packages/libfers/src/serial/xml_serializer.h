@@ -28,8 +28,13 @@ namespace serial
 	 * FERS XML file that can be used by the CLI or shared. It iterates through the
 	 * in-memory `core::World` object and reconstructs the corresponding XML structure.
 	 *
+	 * When `withChecksum` is set, the document is followed by a comment holding the SHA-256
+	 * of everything before it, which the parser verifies on load.
+	 *
 	 * @param world The world object to serialize.
+	 * @param withChecksum Whether to append a checksum comment.
 	 * @return A string containing the XML representation of the world.
+	 * @throws std::runtime_error If the world contains NaN or infinite values.
 	 */
-	std::string world_to_xml_string(const core::World& world);
+	std::string world_to_xml_string(const core::World& world, bool withChecksum = false);
 }
@@ -68,6 +68,13 @@ namespace fers_signal
 		 */
 		[[nodiscard]] RealType getRate() const noexcept { return _rate; }
 
+		/**
+		 * @brief Gets the loaded (and oversampled) signal samples.
+		 *
+		 * @return A view of the samples at the rate returned by getRate().
+		 */
+		[[nodiscard]] std::span<const ComplexType> getSamples() const noexcept { return {_data.data(), _size}; }
+
 		/**
 		 * @brief Renders the signal data based on interpolation points.
 		 *
@@ -113,6 +120,16 @@ namespace fers_signal
 									   int iSampleUnwrap) const noexcept;
 	};
 
+	/**
+	 * @struct InlineSamples
+	 * @brief The I/Q samples of a waveform defined directly in the scenario.
+	 */
+	struct InlineSamples
+	{
+		std::vector<ComplexType> samples; ///< The complex baseband samples.
+		RealType rate; ///< The sample rate in Hz.
+	};
+
 	/**
 	 * @class RadarSignal
 	 * @brief Class representing a radar signal with associated properties.
@@ -155,6 +172,18 @@ namespace fers_signal
 		 */
 		[[nodiscard]] const std::optional<std::string>& getFilename() const noexcept { return _filename; }
 
+		/**
+		 * @brief Sets the inline samples this signal was built from.
+		 * @param samples The samples and their rate, as given in the scenario.
+		 */
+		void setInlineSamples(InlineSamples samples) noexcept { _inline_samples = std::move(samples); }
+
+		/**
+		 * @brief Gets the inline samples this signal was built from.
+		 * @return The samples and their rate, if the signal was defined inline.
+		 */
+		[[nodiscard]] const std::optional<InlineSamples>& getInlineSamples() const noexcept { return _inline_samples; }
+
 		/**
 		 * @brief Gets the power of the radar signal.
 		 *
@@ -214,6 +243,7 @@ namespace fers_signal
 		RealType _length; ///< The length of the radar signal.
 		std::unique_ptr<Signal> _signal; ///< The `Signal` object containing the radar signal data.
 		std::optional<std::string> _filename; ///< The original filename for file-based signals.
+		std::optional<InlineSamples> _inline_samples; ///< The original samples for inline signals.
 	};
 
 	class CwSignal final : public Signal
@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+/**
+ * @file spectrum.cpp
+ * @brief Implementation of pulse synthesis and energy spectral density.
+ */
+
+#include "spectrum.h"
+
+#include <algorithm>
+#include <bit>
+#include <cmath>
+#include <complex>
+#include <limits>
+#include <stdexcept>
+
+namespace
+{
+	/**
+	 * @brief In-place iterative radix-2 FFT.
+	 *
+	 * @param data The samples, whose length is a power of two.
+	 */
+	void fft(std::vector<ComplexType>& data)
+	{
+		const std::size_t n = data.size();
+		for (std::size_t i = 1, j = 0; i < n; ++i)
+		{
+			std::size_t bit = n >> 1;
+			for (; j & bit; bit >>= 1)
+			{
+				j ^= bit;
+			}
+			j ^= bit;
+			if (i < j)
+			{
+				std::swap(data[i], data[j]);
+			}
+		}
+		for (std::size_t len = 2; len <= n; len <<= 1)
+		{
+			const ComplexType step = std::polar(1.0, -2.0 * PI / static_cast<RealType>(len));
+			for (std::size_t start = 0; start < n; start += len)
+			{
+				ComplexType twiddle(1.0, 0.0);
+				for (std::size_t k = 0; k < len / 2; ++k)
+				{
+					const ComplexType even = data[start + k];
+					const ComplexType odd = data[start + k + len / 2] * twiddle;
+					data[start + k] = even + odd;
+					data[start + k + len / 2] = even - odd;
+					twiddle *= step;
+				}
+			}
+		}
+	}
+}
+
+namespace fers_signal
+{
+	RealType pulseSampleRate(const RealType bandwidth, const RealType duration)
+	{
+		if (!(duration > 0) || !(bandwidth >= 0) || !std::isfinite(duration) || !std::isfinite(bandwidth))
+		{
+			throw std::invalid_argument("Pulse duration must be positive and bandwidth non-negative.");
+		}
+		return 4.0 * bandwidth + 16.0 / duration;
+	}
+
+	std::vector<ComplexType> synthesizePulse(const RealType bandwidth, const RealType duration, const RealType rate)
+	{
+		if (!(duration > 0) || !(bandwidth >= 0) || !(rate > 0))
+		{
+			throw std::invalid_argument("Pulse duration and sample rate must be positive and bandwidth non-negative.");
+		}
+		const auto count = std::max<std::size_t>(1, static_cast<std::size_t>(std::llround(duration * rate)));
+		std::vector<ComplexType> samples(count);
+		for (std::size_t n = 0; n < count; ++n)
+		{
+			// Time from the pulse center, so the sweep is symmetric about the carrier
+			const RealType t = (static_cast<RealType>(n) + 0.5) / rate - duration / 2.0;
+			samples[n] = std::polar(1.0, PI * bandwidth / duration * t * t);
+		}
+		return samples;
+	}
+
+	std::vector<std::pair<RealType, RealType>> energySpectralDensity(const std::span<const ComplexType> samples,
+																	 const RealType rate, const std::size_t numBins)
+	{
+		if (samples.empty() || !(rate > 0) || numBins < 2 || !std::has_single_bit(numBins))
+		{
+			throw std::invalid_argument("Spectrum needs samples, a positive rate and a power-of-two bin count.");
+		}
+
+		// Folding the samples modulo numBins samples the transform at multiples of rate / numBins
+		std::vector<ComplexType> bins(numBins);
+		for (std::size_t n = 0; n < samples.size(); ++n)
+		{
+			bins[n % numBins] += samples[n];
+		}
+		fft(bins);
+
+		RealType peak = 0;
+		for (const auto& bin : bins)
+		{
+			peak = std::max(peak, std::norm(bin));
+		}
+
+		std::vector<std::pair<RealType, RealType>> density(numBins);
+		const RealType spacing = rate / static_cast<RealType>(numBins);
+		for (std::size_t k = 0; k < numBins; ++k)
+		{
+			// Shift the upper half of the FFT down so the offsets run from -rate / 2
+			const std::size_t bin = (k + numBins / 2) % numBins;
+			const RealType energy = std::norm(bins[bin]);
+			density[k] = {(static_cast<RealType>(k) - static_cast<RealType>(numBins / 2)) * spacing,
+						  energy > 0 && peak > 0 ? 10.0 * std::log10(energy / peak)
+												 : -std::numeric_limits<RealType>::infinity()};
+		}
+		return density;
+	}
+}
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+/**
+ * @file spectrum.h
+ * @brief Synthesis of single pulses and their energy spectral density.
+ */
+
+#pragma once
+
+#include <cstddef>
+#include <span>
+#include <utility>
+#include <vector>
+
+#include "core/config.h"
+
+namespace fers_signal
+{
+	/**
+	 * @brief Picks a sample rate that resolves a pulse and a few of its spectral sidelobes.
+	 *
+	 * The rate is `4 B + 16 / tau`, so a chirp's sweep fills about a quarter of the band and a
+	 * rectangular pulse shows eight sidelobes on each side of its main lobe.
+	 *
+	 * @param bandwidth The swept bandwidth in Hz, or 0 for an unmodulated pulse.
+	 * @param duration The pulse duration in seconds.
+	 * @return The sample rate in Hz.
+	 * @throws std::invalid_argument If the duration is not positive or the bandwidth is negative.
+	 */
+	[[nodiscard]] RealType pulseSampleRate(RealType bandwidth, RealType duration);
+
+	/**
+	 * @brief Synthesizes a unit-amplitude baseband pulse.
+	 *
+	 * A zero bandwidth gives a rectangular pulse; otherwise the pulse is an up-chirp sweeping
+	 * from `-B/2` to `B/2` over its duration.
+	 *
+	 * @param bandwidth The swept bandwidth in Hz, or 0 for an unmodulated pulse.
+	 * @param duration The pulse duration in seconds.
+	 * @param rate The sample rate in Hz.
+	 * @return The pulse samples, at least one.
+	 * @throws std::invalid_argument If the duration or rate is not positive or the bandwidth is negative.
+	 */
+	[[nodiscard]] std::vector<ComplexType> synthesizePulse(RealType bandwidth, RealType duration, RealType rate);
+
+	/**
+	 * @brief Calculates the energy spectral density of a baseband signal.
+	 *
+	 * The signal's Fourier transform is sampled at `numBins` offsets spaced `rate / numBins`
+	 * apart from `-rate / 2` upwards, by folding the samples onto `numBins` points and taking
+	 * their FFT. The density is normalized to its peak, in dB, with negative infinity marking a null.
+	 *
+	 * @param samples The baseband samples.
+	 * @param rate The sample rate in Hz.
+	 * @param numBins The number of frequency bins, a power of two.
+	 * @return `(frequency offset in Hz, density in dB)` pairs in increasing frequency.
+	 * @throws std::invalid_argument If there are no samples, the rate is not positive or
+	 *         `numBins` is not a power of two of at least 2.
+	 */
+	[[nodiscard]] std::vector<std::pair<RealType, RealType>>
+	energySpectralDensity(std::span<const ComplexType> samples, RealType rate, std::size_t numBins);
+}
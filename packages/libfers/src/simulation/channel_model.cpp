@@ -16,13 +16,18 @@
 
 #include "channel_model.h"
 
+#include <algorithm>
 #include <cmath>
+#include <limits>
+#include <stdexcept>
 
 #include "core/logging.h"
 #include "core/parameters.h"
 #include "core/world.h"
 #include "interpolation/interpolation_point.h"
+#include "math/path.h"
 #include "math/geometry_ops.h"
+#include "radar/platform.h"
 #include "radar/radar_obj.h"
 #include "radar/receiver.h"
 #include "radar/target.h"
@@ -88,6 +93,19 @@ namespace
 		return radar->getGain(SVec3(direction_vec), radar->getRotation(time), lambda);
 	}
 
+	/**
+	 * @brief Calculates the receive gain, including the receiver's calibration, for a direction and time.
+	 * @param recv The receiver.
+	 * @param direction_vec The unit vector pointing AWAY from the antenna towards the source.
+	 * @param time The simulation time for rotation lookup.
+	 * @param lambda The signal wavelength.
+	 * @return The linear antenna gain scaled by the calibrated receive-chain gain at the signal frequency.
+	 */
+	RealType computeReceiverGain(const Receiver* recv, const Vec3& direction_vec, RealType time, RealType lambda)
+	{
+		return computeAntennaGain(recv, direction_vec, time, lambda) * recv->getCalibrationGain(params::c() / lambda);
+	}
+
 	/**
 	 * @brief Computes the power scaling factor for a direct path (Friis Transmission Equation).
 	 * @param tx_gain Transmitter gain (linear).
@@ -164,6 +182,12 @@ namespace
 		return power_watts > noise_floor;
 	}
 
+	/**
+	 * @brief Returns the receiver noise bandwidth used by the thermal noise model.
+	 * @return The noise-equivalent bandwidth in Hz.
+	 */
+	RealType noiseBandwidth() { return params::rate() / (2.0 * params::oversampleRatio()); }
+
 	/**
 	 * @brief Converts power in watts to decibels milliwatts (dBm).
 	 *
@@ -217,12 +241,95 @@ namespace
 		}
 		return false;
 	}
+
+	/**
+	 * @brief Returns the thermal noise power kTB of a receiver in watts.
+	 *
+	 * The temperature is the receiver's noise temperature, or the 290 K reference when none is
+	 * set. The bandwidth is the reciprocal of the window length for pulsed receivers and the
+	 * bandwidth of the simulated thermal noise for CW receivers.
+	 *
+	 * @throws std::runtime_error If the noise bandwidth is not positive.
+	 */
+	RealType receiverNoisePower(const Receiver* rx)
+	{
+		const RealType temperature = rx->getNoiseTemperature() > 0 ? rx->getNoiseTemperature() : 290.0;
+		const RealType bandwidth = rx->getMode() == radar::OperationMode::PULSED_MODE && rx->getWindowLength() > 0
+			? 1.0 / rx->getWindowLength()
+			: noiseBandwidth();
+		if (bandwidth <= 0)
+		{
+			throw std::runtime_error("Receiver '" + rx->getName() + "' has no positive noise bandwidth.");
+		}
+		return params::boltzmannK() * temperature * bandwidth;
+	}
+
+	/**
+	 * @brief Finds the echo delays within one PRI that a pulsed receiver cannot see.
+	 *
+	 * @param pulseLength The transmit pulse length in seconds.
+	 * @param windowSkip The delay from the start of the pulse to the opening of the receive window.
+	 * @param windowLength The length of the receive window in seconds.
+	 * @param pri The pulse repetition interval in seconds.
+	 * @return Sorted, disjoint (start, end) delay intervals within [0, pri].
+	 */
+	std::vector<std::pair<RealType, RealType>> findBlindDelays(const RealType pulseLength, const RealType windowSkip,
+															   const RealType windowLength, const RealType pri)
+	{
+		// Echo delays (modulo the PRI) that arrive while the window is open; the window may wrap into the next PRI
+		const RealType pulse_end = std::min(pulseLength, pri);
+		const RealType open = std::fmod(windowSkip, pri);
+		const RealType close = open + std::min(windowLength, pri);
+		std::vector<std::pair<RealType, RealType>> visible;
+		const auto add_visible = [&](const RealType from, const RealType to)
+		{
+			if (const RealType lo = std::max(from, pulse_end), hi = std::min(to, pri); lo < hi)
+			{
+				visible.emplace_back(lo, hi);
+			}
+		};
+		add_visible(open, close);
+		add_visible(0.0, close - pri);
+		std::ranges::sort(visible);
+
+		std::vector<std::pair<RealType, RealType>> blind;
+		RealType cursor = 0.0;
+		for (const auto& [lo, hi] : visible)
+		{
+			if (lo > cursor)
+			{
+				blind.emplace_back(cursor, lo);
+			}
+			cursor = std::max(cursor, hi);
+		}
+		if (cursor < pri)
+		{
+			blind.emplace_back(cursor, pri);
+		}
+		return blind;
+	}
+
+	/**
+	 * @brief Returns the rate of change of the distance between two moving points.
+	 * @param from The position of the first point.
+	 * @param fromVel The velocity of the first point.
+	 * @param to The position of the second point.
+	 * @param toVel The velocity of the second point.
+	 * @return The range rate in m/s, positive when the points move apart.
+	 */
+	RealType rangeRate(const Vec3& from, const Vec3& fromVel, const Vec3& to, const Vec3& toVel)
+	{
+		const Vec3 los = to - from;
+		const RealType dist = los.length();
+		return dist > EPSILON ? math::dotProduct(toVel - fromVel, los) / dist : 0.0;
+	}
 }
 
 namespace simulation
 {
 	void solveRe(const Transmitter* trans, const Receiver* recv, const Target* targ,
-				 const std::chrono::duration<RealType>& time, const RadarSignal* wave, ReResults& results)
+				 const std::chrono::duration<RealType>& time, const RadarSignal* wave, ReResults& results,
+				 const std::optional<RealType> carrier)
 	{
 		// Note: RangeError log messages are handled by the original catch block in calculateResponse
 		// or explicitly here if strict adherence to original logging is required.
@@ -231,7 +338,7 @@ namespace simulation
 		const RealType t_val = time.count();
 		const auto p_tx = trans->getPosition(t_val);
 		const auto p_rx = recv->getPosition(t_val);
-		const auto p_tgt = targ->getPosition(t_val);
+		const auto p_tgt = targ->getPosition(t_val) + targ->getMicroMotionOffset(t_val);
 
 		// Link 1: Tx -> Target
 		LinkGeometry link_tx_tgt;
@@ -260,23 +367,24 @@ namespace simulation
 		SVec3 out_angle(-link_tgt_rx.u_vec);
 		const auto rcs = targ->getRcs(in_angle, out_angle, t_val);
 
-		const auto wavelength = params::c() / wave->getCarrier();
+		const RealType carrier_freq = carrier.value_or(wave->getCarrier());
+		const auto wavelength = params::c() / carrier_freq;
 
 		// Tx Gain: Direction Tx -> Tgt
 		const auto tx_gain = computeAntennaGain(trans, link_tx_tgt.u_vec, t_val, wavelength);
 		// Rx Gain: Direction Rx -> Tgt (Opposite of Tgt->Rx).
 		// Time is time + delay.
-		const auto rx_gain = computeAntennaGain(recv, -link_tgt_rx.u_vec, results.delay + t_val, wavelength);
+		const auto rx_gain = computeReceiverGain(recv, -link_tgt_rx.u_vec, results.delay + t_val, wavelength);
 
 		const bool no_loss = recv->checkFlag(Receiver::RecvFlag::FLAG_NOPROPLOSS);
 		results.power =
 			computeReflectedPathPower(tx_gain, rx_gain, rcs, wavelength, link_tx_tgt.dist, link_tgt_rx.dist, no_loss);
 
-		results.phase = -results.delay * 2 * PI * wave->getCarrier();
+		results.phase = -results.delay * 2 * PI * carrier_freq;
 	}
 
 	void solveReDirect(const Transmitter* trans, const Receiver* recv, const std::chrono::duration<RealType>& time,
-					   const RadarSignal* wave, ReResults& results)
+					   const RadarSignal* wave, ReResults& results, const std::optional<RealType> carrier)
 	{
 		const RealType t_val = time.count();
 		const auto p_tx = trans->getPosition(t_val);
@@ -294,7 +402,8 @@ namespace simulation
 		}
 
 		results.delay = link.dist / params::c();
-		const RealType wavelength = params::c() / wave->getCarrier();
+		const RealType carrier_freq = carrier.value_or(wave->getCarrier());
+		const RealType wavelength = params::c() / carrier_freq;
 
 		// Discrepancy Fix: Original code used (Rx - Tx) for Receiver Gain but (Tx - Rx) logic for Transmitter gain
 		// was ambiguous/incorrect (using `tpos - rpos` which is Rx->Tx).
@@ -303,12 +412,12 @@ namespace simulation
 		// Rx Gain uses Vector Rx -> Tx.
 
 		const auto tx_gain = computeAntennaGain(trans, link.u_vec, t_val, wavelength);
-		const auto rx_gain = computeAntennaGain(recv, -link.u_vec, t_val + results.delay, wavelength);
+		const auto rx_gain = computeReceiverGain(recv, -link.u_vec, t_val + results.delay, wavelength);
 
 		const bool no_loss = recv->checkFlag(Receiver::RecvFlag::FLAG_NOPROPLOSS);
 		results.power = computeDirectPathPower(tx_gain, rx_gain, wavelength, link.dist, no_loss);
 
-		results.phase = -results.delay * 2 * PI * wave->getCarrier();
+		results.phase = -results.delay * 2 * PI * carrier_freq;
 	}
 
 	ComplexType calculateDirectPathContribution(const Transmitter* trans, const Receiver* recv, const RealType timeK)
@@ -321,8 +430,8 @@ namespace simulation
 			return {0.0, 0.0};
 		}
 
-		const auto p_tx = trans->getPlatform()->getPosition(timeK);
-		const auto p_rx = recv->getPlatform()->getPosition(timeK);
+		const auto p_tx = trans->getPosition(timeK);
+		const auto p_rx = recv->getPosition(timeK);
 
 		LinkGeometry link;
 		try
@@ -342,7 +451,7 @@ namespace simulation
 		// Tx Gain: Direction Tx -> Rx
 		const RealType tx_gain = computeAntennaGain(trans, link.u_vec, timeK, lambda);
 		// Rx Gain: Direction Rx -> Tx (-u_vec)
-		const RealType rx_gain = computeAntennaGain(recv, -link.u_vec, timeK + tau, lambda);
+		const RealType rx_gain = computeReceiverGain(recv, -link.u_vec, timeK + tau, lambda);
 
 		const bool no_loss = recv->checkFlag(Receiver::RecvFlag::FLAG_NOPROPLOSS);
 		const RealType scaling_factor = computeDirectPathPower(tx_gain, rx_gain, lambda, link.dist, no_loss);
@@ -371,8 +480,8 @@ namespace simulation
 			return {0.0, 0.0};
 		}
 
-		const auto p_tx = trans->getPlatform()->getPosition(timeK);
-		const auto p_rx = recv->getPlatform()->getPosition(timeK);
+		const auto p_tx = trans->getPosition(timeK);
+		const auto p_rx = recv->getPosition(timeK);
 		const auto p_tgt = targ->getPlatform()->getPosition(timeK);
 
 		LinkGeometry link_tx_tgt;
@@ -401,7 +510,7 @@ namespace simulation
 		// Tx Gain: Direction Tx -> Tgt
 		const RealType tx_gain = computeAntennaGain(trans, link_tx_tgt.u_vec, timeK, lambda);
 		// Rx Gain: Direction Rx -> Tgt (- (Tgt->Rx)). Time: timeK + tau.
-		const RealType rx_gain = computeAntennaGain(recv, -link_tgt_rx.u_vec, timeK + tau, lambda);
+		const RealType rx_gain = computeReceiverGain(recv, -link_tgt_rx.u_vec, timeK + tau, lambda);
 
 		const bool no_loss = recv->checkFlag(Receiver::RecvFlag::FLAG_NOPROPLOSS);
 		const RealType scaling_factor =
@@ -422,7 +531,7 @@ namespace simulation
 
 	std::unique_ptr<serial::Response> calculateResponse(const Transmitter* trans, const Receiver* recv,
 														const RadarSignal* signal, const RealType startTime,
-														const Target* targ)
+														const Target* targ, const std::optional<RealType> carrier)
 	{
 		// If calculating direct path (no target) and components are co-located:
 		// 1. If explicitly attached (monostatic), skip (internal leakage handled elsewhere).
@@ -459,7 +568,7 @@ namespace simulation
 			throw std::runtime_error("No time points are available for execution!");
 		}
 
-		auto response = std::make_unique<serial::Response>(signal, trans);
+		auto response = std::make_unique<serial::Response>(signal, trans, targ);
 
 		try
 		{
@@ -471,11 +580,11 @@ namespace simulation
 				ReResults results{};
 				if (targ)
 				{
-					solveRe(trans, recv, targ, current_time, signal, results);
+					solveRe(trans, recv, targ, current_time, signal, results, carrier);
 				}
 				else
 				{
-					solveReDirect(trans, recv, current_time, signal, results);
+					solveReDirect(trans, recv, current_time, signal, results, carrier);
 				}
 
 				interp::InterpPoint point{.power = results.power,
@@ -501,6 +610,11 @@ namespace simulation
 		// Default wavelength (1GHz) if no waveform is attached, to allow geometric visualization
 		const RealType lambda_default = 0.3;
 
+		// Legs that the terrain masks are not drawn
+		const auto* terrain = world.getTerrain();
+		const auto clear = [terrain](const Vec3& from, const Vec3& to)
+		{ return !terrain || terrain->hasLineOfSight(from, to); };
+
 		for (const auto& tx : world.getTransmitters())
 		{
 			// 1. Check Transmitter Schedule
@@ -527,7 +641,7 @@ namespace simulation
 				const Vec3 vec_tx_tgt = p_tgt - p_tx;
 				const RealType r1 = vec_tx_tgt.length();
 
-				if (r1 <= EPSILON)
+				if (r1 <= EPSILON || !clear(p_tx, p_tgt))
 					continue;
 
 				const Vec3 u_tx_tgt = vec_tx_tgt / r1;
@@ -570,7 +684,7 @@ namespace simulation
 						const Vec3 vec_tx_tgt = p_tgt - p_tx;
 						const RealType dist = vec_tx_tgt.length();
 
-						if (dist <= EPSILON)
+						if (dist <= EPSILON || !clear(p_tx, p_tgt))
 							continue;
 
 						const Vec3 u_tx_tgt = vec_tx_tgt / dist; // Unit vec Tx -> Tgt
@@ -613,7 +727,7 @@ namespace simulation
 						const Vec3 vec_direct = p_rx - p_tx;
 						const RealType dist = vec_direct.length();
 
-						if (dist > EPSILON)
+						if (dist > EPSILON && clear(p_tx, p_rx))
 						{
 							const Vec3 u_tx_rx = vec_direct / dist;
 
@@ -644,7 +758,7 @@ namespace simulation
 						const RealType r1 = vec_tx_tgt.length();
 						const RealType r2 = vec_tgt_rx.length();
 
-						if (r1 <= EPSILON || r2 <= EPSILON)
+						if (r1 <= EPSILON || r2 <= EPSILON || !clear(p_tx, p_tgt) || !clear(p_tgt, p_rx))
 							continue;
 
 						const Vec3 u_tx_tgt = vec_tx_tgt / r1;
@@ -680,4 +794,986 @@ namespace simulation
 		}
 		return links;
 	}
+
+	InterferenceReport calculateInterference(const core::World& world, const std::string& rxName, const RealType time)
+	{
+		const auto* rx = world.findReceiver(rxName);
+		if (!rx)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' not found.");
+		}
+
+		InterferenceReport report{.total_power = 0.0, .temperature = 0.0, .contributions = {}};
+		const auto p_rx = rx->getPosition(time);
+		const bool no_loss = rx->checkFlag(Receiver::RecvFlag::FLAG_NOPROPLOSS);
+
+		for (const auto& tx : world.getTransmitters())
+		{
+			// The paired transmitter and co-located emitters are not modelled as far-field interferers.
+			if (tx->getAttached() == rx || tx->getPlatform() == rx->getPlatform())
+			{
+				continue;
+			}
+			const auto* waveform = tx->getSignal();
+			if (!waveform || !isComponentActive(tx->getSchedule(), time))
+			{
+				continue;
+			}
+
+			const Vec3 vec_direct = p_rx - tx->getPosition(time);
+			const RealType dist = vec_direct.length();
+			if (dist <= EPSILON)
+			{
+				continue;
+			}
+
+			const Vec3 u_tx_rx = vec_direct / dist;
+			const RealType lambda = params::c() / waveform->getCarrier();
+			// Tx Gain: Tx -> Rx
+			const RealType gt = computeAntennaGain(tx.get(), u_tx_rx, time, lambda);
+			// Rx Gain: Rx -> Tx (which is -u_tx_rx)
+			const RealType gr = computeAntennaGain(rx, -u_tx_rx, time, lambda);
+
+			const RealType pr_watts = waveform->getPower() * computeDirectPathPower(gt, gr, lambda, dist, no_loss);
+			report.contributions.push_back({.transmitter_name = tx->getName(), .power = pr_watts});
+			report.total_power += pr_watts;
+		}
+
+		const RealType bandwidth = noiseBandwidth();
+		if (bandwidth > 0)
+		{
+			report.temperature = report.total_power / (params::boltzmannK() * bandwidth);
+		}
+		return report;
+	}
+
+	RealType calculateRequiredTransmitPower(const core::World& world, const std::string& txName,
+											const std::string& targetName, const std::string& rxName,
+											const RealType targetSnrDb, const RealType time)
+	{
+		const auto* tx = world.findTransmitter(txName);
+		if (!tx)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' not found.");
+		}
+		const auto* tgt = world.findTarget(targetName);
+		if (!tgt)
+		{
+			throw std::runtime_error("Target '" + targetName + "' not found.");
+		}
+		const auto* rx = world.findReceiver(rxName);
+		if (!rx)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' not found.");
+		}
+		const auto* waveform = tx->getSignal();
+		if (!waveform)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' has no waveform attached.");
+		}
+
+		ReResults results{};
+		solveRe(tx, rx, tgt, std::chrono::duration<RealType>(time), waveform, results);
+		if (results.power <= 0)
+		{
+			throw std::runtime_error("No power is received from target '" + targetName + "' at this geometry.");
+		}
+
+		const SVec3 rx_to_tgt(tgt->getPosition(time) - rx->getPosition(time));
+		const RealType noise_power = params::boltzmannK() * rx->getNoiseTemperature(rx_to_tgt) * noiseBandwidth();
+		if (noise_power <= 0)
+		{
+			throw std::runtime_error("Receiver '" + rxName +
+									 "' has no thermal noise; the required power is undefined.");
+		}
+
+		const RealType snr_linear = std::pow(10.0, targetSnrDb / 10.0);
+		return wattsToDb(snr_linear * noise_power / results.power);
+	}
+
+	RealType calculateMaxDetectionRange(const core::World& world, const std::string& monostaticName,
+										const RealType targetRcs, const RealType snrThresholdDb)
+	{
+		const auto* tx = world.findTransmitter(monostaticName);
+		if (!tx)
+		{
+			throw std::runtime_error("Monostatic radar '" + monostaticName + "' not found.");
+		}
+		const auto* rx = dynamic_cast<const Receiver*>(tx->getAttached());
+		if (!rx)
+		{
+			throw std::runtime_error("Transmitter '" + monostaticName + "' is not part of a monostatic radar.");
+		}
+		if (!(targetRcs > 0))
+		{
+			throw std::runtime_error("Target RCS must be positive.");
+		}
+		const auto* waveform = tx->getSignal();
+		if (!waveform)
+		{
+			throw std::runtime_error("Monostatic radar '" + monostaticName + "' has no waveform attached.");
+		}
+		if (rx->checkFlag(Receiver::RecvFlag::FLAG_NOPROPLOSS))
+		{
+			throw std::runtime_error("Monostatic radar '" + monostaticName +
+									 "' ignores propagation loss; the detection range is unbounded.");
+		}
+
+		const RealType time = params::startTime();
+		const RealType wavelength = params::c() / waveform->getCarrier();
+		const SVec3 rotation = tx->getRotation(time);
+		const Vec3 boresight(SVec3(1.0, rotation.azimuth, rotation.elevation));
+		const RealType tx_gain = computeAntennaGain(tx, boresight, time, wavelength);
+		const RealType rx_gain = computeReceiverGain(rx, boresight, time, wavelength);
+		if (!(tx_gain > 0 && rx_gain > 0))
+		{
+			throw std::runtime_error("Antenna of monostatic radar '" + monostaticName +
+									 "' has no gain along its boresight.");
+		}
+
+		const RealType noise_power =
+			params::boltzmannK() * rx->getNoiseTemperature(SVec3(boresight)) * noiseBandwidth();
+		if (noise_power <= 0)
+		{
+			throw std::runtime_error("Receiver '" + monostaticName +
+									 "' has no thermal noise; the detection range is undefined.");
+		}
+
+		// Received power at 1 m, which falls off as R^4
+		const RealType unit_range_power = waveform->getPower() *
+			computeReflectedPathPower(tx_gain, rx_gain, targetRcs, wavelength, 1.0, 1.0, false);
+		const RealType snr_linear = std::pow(10.0, snrThresholdDb / 10.0);
+		return std::pow(unit_range_power / (snr_linear * noise_power), 0.25);
+	}
+
+	LinkBudget calculateLinkBudget(const core::World& world, const std::string& txName, const std::string& rxName,
+								   const std::string& targetName, const RealType range)
+	{
+		const auto* tx = world.findTransmitter(txName);
+		if (!tx)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' not found.");
+		}
+		const auto* rx = world.findReceiver(rxName);
+		if (!rx)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' not found.");
+		}
+		const auto* tgt = world.findTarget(targetName);
+		if (!tgt)
+		{
+			throw std::runtime_error("Target '" + targetName + "' not found.");
+		}
+		const auto* waveform = tx->getSignal();
+		if (!waveform)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' has no waveform attached.");
+		}
+		if (!(range > 0))
+		{
+			throw std::runtime_error("Link budget range must be positive.");
+		}
+
+		// Gains and RCS are taken along the actual directions to the target; only the range is assumed
+		const RealType time = params::startTime();
+		const Vec3 tgt_pos = tgt->getPosition(time);
+		Vec3 u_tx = tgt_pos - tx->getPosition(time);
+		Vec3 u_rx = tgt_pos - rx->getPosition(time);
+		if (u_tx.length() <= EPSILON || u_rx.length() <= EPSILON)
+		{
+			throw RangeError();
+		}
+		u_tx /= u_tx.length();
+		u_rx /= u_rx.length();
+
+		const RealType wavelength = params::c() / waveform->getCarrier();
+		const RealType tx_gain = computeAntennaGain(tx, u_tx, time, wavelength);
+		const RealType rx_gain = computeReceiverGain(rx, u_rx, time, wavelength);
+		SVec3 in_angle(u_tx);
+		SVec3 out_angle(-u_rx);
+		const RealType rcs = tgt->getRcs(in_angle, out_angle, time);
+
+		const bool no_prop_loss = rx->checkFlag(Receiver::RecvFlag::FLAG_NOPROPLOSS);
+		const RealType spreading = no_prop_loss ? 1.0 : std::pow(range, 4);
+		const RealType received = waveform->getPower() *
+			computeReflectedPathPower(tx_gain, rx_gain, rcs, wavelength, range, range, no_prop_loss);
+		const RealType noise = receiverNoisePower(rx);
+
+		// Coherent integration over the simulated span: all pulses, or every noise-bandwidth sample of a CW signal
+		const RealType duration = params::endTime() - params::startTime();
+		const RealType integrated = tx->getMode() == radar::OperationMode::PULSED_MODE
+			? std::max(1.0, std::floor(tx->getPrf() * duration))
+			: std::max(1.0, duration * noiseBandwidth());
+
+		LinkBudget budget{};
+		budget.transmit_power_dbw = wattsToDb(waveform->getPower());
+		budget.tx_gain_dbi = wattsToDb(tx_gain);
+		budget.rx_gain_dbi = wattsToDb(rx_gain);
+		budget.rcs_dbsm = wattsToDb(rcs);
+		budget.path_loss_db = wattsToDb(64.0 * PI * PI * PI * spreading / (wavelength * wavelength));
+		budget.received_power_dbw = wattsToDb(received);
+		budget.noise_power_dbw = wattsToDb(noise);
+		budget.snr_db = budget.received_power_dbw - budget.noise_power_dbw;
+		budget.integration_gain_db = wattsToDb(integrated);
+		budget.integrated_snr_db = budget.snr_db + budget.integration_gain_db;
+		return budget;
+	}
+
+	RealType calculatePhaseCenterRange(const core::World& world, const std::string& txName,
+									   const std::string& targetName, const std::string& rxName, const RealType time)
+	{
+		const auto* tx = world.findTransmitter(txName);
+		if (!tx)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' not found.");
+		}
+		const auto* tgt = world.findTarget(targetName);
+		if (!tgt)
+		{
+			throw std::runtime_error("Target '" + targetName + "' not found.");
+		}
+		const auto* rx = world.findReceiver(rxName);
+		if (!rx)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' not found.");
+		}
+
+		const Vec3 tgt_pos = tgt->getPosition(time);
+		return (tgt_pos - tx->getPhaseCenterPosition(time)).length() +
+			(rx->getPhaseCenterPosition(time) - tgt_pos).length();
+	}
+
+	RealType calculateNoiseFloor(const core::World& world, const std::string& rxName)
+	{
+		const auto* rx = world.findReceiver(rxName);
+		if (!rx)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' not found.");
+		}
+
+		return wattsToDbm(receiverNoisePower(rx));
+	}
+
+	RealType calculateMinimumPrfForVelocity(const core::World& world, const std::string& txName,
+											const std::string& targetName, const std::string& rxName)
+	{
+		const auto* tx = world.findTransmitter(txName);
+		if (!tx)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' not found.");
+		}
+		const auto* tgt = world.findTarget(targetName);
+		if (!tgt)
+		{
+			throw std::runtime_error("Target '" + targetName + "' not found.");
+		}
+		const auto* rx = world.findReceiver(rxName);
+		if (!rx)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' not found.");
+		}
+		const auto* waveform = tx->getSignal();
+		if (!waveform)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' has no waveform attached.");
+		}
+
+		constexpr std::size_t num_samples = 1000;
+		const RealType start = params::startTime();
+		const RealType span = params::endTime() - start;
+		RealType max_velocity = 0.0;
+		for (std::size_t i = 0; i <= num_samples; ++i)
+		{
+			const RealType t = start + span * static_cast<RealType>(i) / static_cast<RealType>(num_samples);
+			const Vec3 tx_vel = tx->getPlatform()->getVelocity(t);
+			const Vec3 tgt_vel = tgt->getPlatform()->getVelocity(t);
+			const Vec3 rx_vel = rx->getPlatform()->getVelocity(t);
+			const Vec3 tgt_pos = tgt->getPosition(t);
+
+			const RealType path_rate = rangeRate(tx->getPosition(t), tx_vel, tgt_pos, tgt_vel) +
+				rangeRate(tgt_pos, tgt_vel, rx->getPosition(t), rx_vel);
+			max_velocity = std::max(max_velocity, std::abs(path_rate) / 2.0);
+		}
+
+		const RealType lambda = params::c() / waveform->getCarrier();
+		return 4.0 * max_velocity / lambda;
+	}
+
+	DataRate calculateDataRate(const core::World& world)
+	{
+		const RealType span = params::endTime() - params::startTime();
+		const RealType stream_rate = params::rate() * 2.0 * static_cast<RealType>(sizeof(RealType));
+
+		DataRate result{0.0, 0.0, 0};
+		for (const auto& rx : world.getReceivers())
+		{
+			RealType active_fraction = 1.0;
+			if (const auto& schedule = rx->getSchedule(); !schedule.empty() && span > 0)
+			{
+				RealType active = 0.0;
+				for (const auto& period : schedule)
+				{
+					active += std::max(0.0, std::min(period.end, params::endTime()) -
+										   std::max(period.start, params::startTime()));
+				}
+				active_fraction = std::min(active / span, 1.0);
+			}
+			if (active_fraction <= 0)
+			{
+				continue;
+			}
+
+			RealType duty = 1.0;
+			if (rx->getMode() == radar::OperationMode::PULSED_MODE)
+			{
+				duty = std::clamp(rx->getWindowLength() * rx->getWindowPrf(), 0.0, 1.0);
+			}
+
+			++result.active_receivers;
+			result.peak_bytes_per_second += stream_rate;
+			result.average_bytes_per_second += stream_rate * duty * active_fraction;
+		}
+		return result;
+	}
+
+	IntegrationTime calculateRequiredIntegrationTime(const core::World& world, const std::string& rxName,
+													 const RealType velocityResolution, const RealType carrier)
+	{
+		const auto* rx = world.findReceiver(rxName);
+		if (!rx)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' not found.");
+		}
+		if (!(velocityResolution > 0) || !(carrier > 0))
+		{
+			throw std::runtime_error("Velocity resolution and carrier frequency must be positive.");
+		}
+
+		const RealType wavelength = params::c() / carrier;
+		const RealType required = wavelength / (2.0 * velocityResolution);
+
+		RealType dwell = std::max(0.0, params::endTime() - params::startTime());
+		if (const auto& schedule = rx->getSchedule(); !schedule.empty())
+		{
+			dwell = 0.0;
+			for (const auto& period : schedule)
+			{
+				dwell = std::max(dwell, std::min(period.end, params::endTime()) -
+											std::max(period.start, params::startTime()));
+			}
+		}
+		return {required, dwell, dwell >= required};
+	}
+
+	RealType calculateRangeCrlb(const core::World& world, const std::string& txName, const std::string& targetName,
+								const std::string& rxName, const RealType time)
+	{
+		const auto* tx = world.findTransmitter(txName);
+		if (!tx)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' not found.");
+		}
+		const auto* tgt = world.findTarget(targetName);
+		if (!tgt)
+		{
+			throw std::runtime_error("Target '" + targetName + "' not found.");
+		}
+		const auto* rx = world.findReceiver(rxName);
+		if (!rx)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' not found.");
+		}
+		const auto* waveform = tx->getSignal();
+		if (!waveform)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' has no waveform attached.");
+		}
+
+		// Stored signals are upsampled, so undo the oversampling to recover the source bandwidth
+		const RealType bandwidth = dynamic_cast<const fers_signal::CwSignal*>(waveform->getSignal())
+			? 0.0
+			: waveform->getRate() / params::oversampleRatio();
+		if (!(bandwidth > 0))
+		{
+			throw std::runtime_error("The bandwidth of waveform '" + waveform->getName() +
+									 "' cannot be determined; range accuracy is undefined.");
+		}
+
+		ReResults results{};
+		solveRe(tx, rx, tgt, std::chrono::duration<RealType>(time), waveform, results);
+		const SVec3 rx_to_tgt(tgt->getPosition(time) - rx->getPosition(time));
+		const RealType noise_power = params::boltzmannK() * rx->getNoiseTemperature(rx_to_tgt) * noiseBandwidth();
+		if (noise_power <= 0)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' has no thermal noise; the range bound is undefined.");
+		}
+		const RealType snr = waveform->getPower() * results.power / noise_power;
+		if (snr <= 0)
+		{
+			throw std::runtime_error("No power is received from target '" + targetName + "' at this geometry.");
+		}
+
+		// RMS bandwidth of a flat spectrum of width B is B / sqrt(12)
+		const RealType rms_bandwidth = bandwidth / std::sqrt(12.0);
+		return params::c() / (2.0 * 2.0 * PI * rms_bandwidth * std::sqrt(snr));
+	}
+
+	RealType calculateWindowTruncationLoss(const core::World& world, const std::string& rxName,
+										   const std::string& txName)
+	{
+		const auto* rx = world.findReceiver(rxName);
+		if (!rx)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' not found.");
+		}
+		const auto* tx = world.findTransmitter(txName);
+		if (!tx)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' not found.");
+		}
+		if (rx->getMode() != radar::OperationMode::PULSED_MODE)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' is not pulsed and has no receive window.");
+		}
+		const auto* waveform = tx->getSignal();
+		if (!waveform || tx->getMode() != radar::OperationMode::PULSED_MODE)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' has no pulsed waveform attached.");
+		}
+
+		const RealType pulse_length = waveform->getLength();
+		const RealType window_length = rx->getWindowLength();
+		if (pulse_length <= 0 || window_length >= pulse_length)
+		{
+			return 0.0;
+		}
+		if (window_length <= 0)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' has an empty receive window.");
+		}
+		return -10.0 * std::log10(window_length / pulse_length);
+	}
+
+	std::vector<std::pair<RealType, RealType>> calculateBlindRanges(const core::World& world, const std::string& rxName,
+																	const std::string& txName)
+	{
+		const auto* rx = world.findReceiver(rxName);
+		if (!rx)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' not found.");
+		}
+		const auto* tx = world.findTransmitter(txName);
+		if (!tx)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' not found.");
+		}
+		if (rx->getMode() != radar::OperationMode::PULSED_MODE)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' is not pulsed and has no receive window.");
+		}
+		const auto* waveform = tx->getSignal();
+		if (!waveform || tx->getMode() != radar::OperationMode::PULSED_MODE)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' has no pulsed waveform attached.");
+		}
+		const RealType pri = 1.0 / tx->getPrf();
+		if (std::abs(rx->getWindowPrf() - tx->getPrf()) > 1e-9 * tx->getPrf())
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' window PRF does not match the PRF of transmitter '" +
+									 txName + "'.");
+		}
+
+		const RealType half_c = params::c() / 2.0;
+		auto blind = findBlindDelays(waveform->getLength(), rx->getWindowSkip(), rx->getWindowLength(), pri);
+		for (auto& [lo, hi] : blind)
+		{
+			lo *= half_c;
+			hi *= half_c;
+		}
+		return blind;
+	}
+
+	BlindZoneCoverage calculateBlindZoneCoverage(const core::World& world, const std::string& rxName,
+												 const std::string& txName, const std::vector<RealType>& prfs)
+	{
+		const auto* rx = world.findReceiver(rxName);
+		if (!rx)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' not found.");
+		}
+		const auto* tx = world.findTransmitter(txName);
+		if (!tx)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' not found.");
+		}
+		if (rx->getMode() != radar::OperationMode::PULSED_MODE)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' is not pulsed and has no receive window.");
+		}
+		const auto* waveform = tx->getSignal();
+		if (!waveform || tx->getMode() != radar::OperationMode::PULSED_MODE)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' has no pulsed waveform attached.");
+		}
+		if (prfs.empty())
+		{
+			throw std::runtime_error("The PRF set must contain at least one PRF.");
+		}
+		if (std::ranges::any_of(prfs, [](const RealType prf) { return !std::isfinite(prf) || prf <= 0; }))
+		{
+			throw std::runtime_error("Every PRF in the set must be a positive number.");
+		}
+
+		const RealType half_c = params::c() / 2.0;
+		const RealType half_lambda = params::c() / waveform->getCarrier() / 2.0;
+		const auto [min_prf, max_prf] = std::ranges::minmax(prfs);
+		const RealType max_delay = 1.0 / min_prf;
+
+		// A speed is blind at a PRF when its Doppler shift is a non-zero multiple of the PRF
+		const auto is_blind_speed = [&](const RealType speed, const RealType prf)
+		{
+			const RealType order = speed / (half_lambda * prf);
+			return order >= 0.5 && std::abs(order - std::round(order)) < 1e-6;
+		};
+		const auto blind_everywhere = [&](const RealType speed)
+		{ return std::ranges::all_of(prfs, [&](const RealType prf) { return is_blind_speed(speed, prf); }); };
+		constexpr unsigned max_order = 100;
+		RealType max_speed = max_order * half_lambda * max_prf;
+		for (unsigned n = 1; n <= max_order; ++n)
+		{
+			if (const RealType speed = n * half_lambda * max_prf; blind_everywhere(speed))
+			{
+				max_speed = speed;
+				break;
+			}
+		}
+
+		BlindZoneCoverage coverage{.prfs = {},
+								   .unrecoverable_ranges = {},
+								   .recoverable_ranges = {},
+								   .unrecoverable_speeds = {},
+								   .recoverable_speeds = {},
+								   .max_range = max_delay * half_c,
+								   .max_speed = max_speed};
+		std::vector<RealType> breakpoints{0.0, coverage.max_range};
+		std::vector<RealType> speeds;
+		for (const RealType prf : prfs)
+		{
+			PrfBlindZones zones{.prf = prf, .blind_ranges = {}, .blind_speeds = {}};
+
+			// Eclipsing repeats every PRI, so tile one PRI across the range span and join at the PRI boundaries
+			const RealType pri = 1.0 / prf;
+			const auto delays = findBlindDelays(waveform->getLength(), rx->getWindowSkip(), rx->getWindowLength(), pri);
+			for (std::size_t k = 0; static_cast<RealType>(k) * pri < max_delay; ++k)
+			{
+				const RealType offset = static_cast<RealType>(k) * pri;
+				for (const auto& [lo, hi] : delays)
+				{
+					const RealType start = (lo + offset) * half_c;
+					const RealType end = std::min(hi + offset, max_delay) * half_c;
+					if (start >= end)
+					{
+						continue;
+					}
+					if (!zones.blind_ranges.empty() && start <= zones.blind_ranges.back().second * (1.0 + 1e-12))
+					{
+						zones.blind_ranges.back().second = end;
+					}
+					else
+					{
+						zones.blind_ranges.emplace_back(start, end);
+					}
+					breakpoints.push_back(start);
+					breakpoints.push_back(end);
+				}
+			}
+
+			for (unsigned n = 1; n * half_lambda * prf <= max_speed * (1.0 + 1e-9); ++n)
+			{
+				zones.blind_speeds.push_back(n * half_lambda * prf);
+			}
+			speeds.insert(speeds.end(), zones.blind_speeds.begin(), zones.blind_speeds.end());
+			coverage.prfs.push_back(std::move(zones));
+		}
+
+		// Classify each elementary range segment by how many PRFs are blind across it
+		const auto append = [](std::vector<std::pair<RealType, RealType>>& intervals, const RealType start,
+							   const RealType end)
+		{
+			if (!intervals.empty() && intervals.back().second == start)
+			{
+				intervals.back().second = end;
+			}
+			else
+			{
+				intervals.emplace_back(start, end);
+			}
+		};
+		const auto is_blind_range = [](const PrfBlindZones& zones, const RealType range)
+		{
+			return std::ranges::any_of(zones.blind_ranges,
+									   [&](const auto& interval)
+									   { return interval.first <= range && range <= interval.second; });
+		};
+		std::ranges::sort(breakpoints);
+		for (std::size_t i = 0; i + 1 < breakpoints.size(); ++i)
+		{
+			const RealType start = breakpoints[i];
+			const RealType end = std::min(breakpoints[i + 1], coverage.max_range);
+			if (start >= end)
+			{
+				continue;
+			}
+			const RealType mid = (start + end) / 2.0;
+			const auto blind_count = std::ranges::count_if(coverage.prfs, [&](const PrfBlindZones& zones)
+														   { return is_blind_range(zones, mid); });
+			if (blind_count == static_cast<std::ptrdiff_t>(prfs.size()))
+			{
+				append(coverage.unrecoverable_ranges, start, end);
+			}
+			else if (blind_count > 0)
+			{
+				append(coverage.recoverable_ranges, start, end);
+			}
+		}
+
+		std::ranges::sort(speeds);
+		for (std::size_t i = 0; i < speeds.size(); ++i)
+		{
+			if (i > 0 && speeds[i] - speeds[i - 1] <= 1e-9 * speeds[i])
+			{
+				continue;
+			}
+			auto& speed_class =
+				blind_everywhere(speeds[i]) ? coverage.unrecoverable_speeds : coverage.recoverable_speeds;
+			speed_class.push_back(speeds[i]);
+		}
+		return coverage;
+	}
+
+	std::vector<std::pair<RealType, RealType>> calculateSpectrumOccupancy(const core::World& world,
+																		  const std::size_t numPoints)
+	{
+		if (numPoints < 2)
+		{
+			throw std::runtime_error("Spectrum occupancy requires at least two sample points.");
+		}
+
+		const RealType start = params::startTime();
+		const RealType step = (params::endTime() - start) / static_cast<RealType>(numPoints - 1);
+
+		std::vector<std::pair<RealType, RealType>> occupancy;
+		occupancy.reserve(numPoints);
+		std::vector<std::pair<RealType, RealType>> bands;
+		for (std::size_t i = 0; i < numPoints; ++i)
+		{
+			const RealType time = start + static_cast<RealType>(i) * step;
+
+			bands.clear();
+			for (const auto& tx : world.getTransmitters())
+			{
+				const auto* waveform = tx->getSignal();
+				if (!waveform || !isComponentActive(tx->getSchedule(), time))
+				{
+					continue;
+				}
+				// Stored signals are upsampled, so undo the oversampling to recover the source bandwidth
+				if (const RealType half_bw = waveform->getRate() / (2.0 * params::oversampleRatio()); half_bw > 0)
+				{
+					bands.emplace_back(waveform->getCarrier() - half_bw, waveform->getCarrier() + half_bw);
+				}
+			}
+
+			// Merge overlapping bands in frequency order and sum their widths
+			std::ranges::sort(bands);
+			RealType occupied = 0.0;
+			for (std::size_t b = 0; b < bands.size();)
+			{
+				auto [lo, hi] = bands[b];
+				for (++b; b < bands.size() && bands[b].first <= hi; ++b)
+				{
+					hi = std::max(hi, bands[b].second);
+				}
+				occupied += hi - lo;
+			}
+			occupancy.emplace_back(time, occupied);
+		}
+		return occupancy;
+	}
+
+	std::pair<RealType, std::size_t> calculatePeakEmitterCount(const core::World& world, const std::size_t numPoints)
+	{
+		if (numPoints < 2)
+		{
+			throw std::runtime_error("Peak emitter count requires at least two sample points.");
+		}
+
+		const RealType start = params::startTime();
+		const RealType end = params::endTime();
+		const RealType step = (end - start) / static_cast<RealType>(numPoints - 1);
+
+		// Schedule periods are closed, so the count can only rise at a period start
+		std::vector<RealType> times;
+		times.reserve(numPoints);
+		for (std::size_t i = 0; i < numPoints; ++i)
+		{
+			times.push_back(start + static_cast<RealType>(i) * step);
+		}
+		for (const auto& tx : world.getTransmitters())
+		{
+			for (const auto& period : tx->getSchedule())
+			{
+				if (period.start > start && period.start < end)
+				{
+					times.push_back(period.start);
+				}
+			}
+		}
+		std::ranges::sort(times);
+
+		std::pair<RealType, std::size_t> peak{start, 0};
+		for (const RealType time : times)
+		{
+			const auto count = static_cast<std::size_t>(std::ranges::count_if(
+				world.getTransmitters(), [&](const auto& tx) { return isComponentActive(tx->getSchedule(), time); }));
+			if (count > peak.second)
+			{
+				peak = {time, count};
+			}
+		}
+		return peak;
+	}
+
+	std::vector<TdmSlot> calculateTdmSchedule(const core::World& world, const RealType guardTime)
+	{
+		if (!std::isfinite(guardTime) || guardTime < 0)
+		{
+			throw std::runtime_error("TDM guard time must be a non-negative number of seconds.");
+		}
+		const auto& transmitters = world.getTransmitters();
+		if (transmitters.empty())
+		{
+			throw std::runtime_error("The scenario has no transmitters to schedule.");
+		}
+
+		RealType frame = std::numeric_limits<RealType>::infinity();
+		RealType occupied = 0.0;
+		for (const auto& tx : transmitters)
+		{
+			const auto* waveform = tx->getSignal();
+			if (!waveform || tx->getMode() != radar::OperationMode::PULSED_MODE || tx->getPrf() <= 0)
+			{
+				throw std::runtime_error("Transmitter '" + tx->getName() +
+										 "' is not pulsed and cannot be time-multiplexed.");
+			}
+			frame = std::min(frame, 1.0 / tx->getPrf());
+			occupied += waveform->getLength() + guardTime;
+		}
+		if (occupied > frame)
+		{
+			throw std::runtime_error("The total duty cycle of " + std::to_string(100.0 * occupied / frame) +
+									 "% (including guard times) does not fit within the shortest PRI of " +
+									 std::to_string(frame) + " s.");
+		}
+
+		const RealType start = params::startTime();
+		const RealType end = params::endTime();
+		std::vector<TdmSlot> slots;
+		for (std::size_t k = 0;; ++k)
+		{
+			RealType slot_start = start + static_cast<RealType>(k) * frame;
+			for (const auto& tx : transmitters)
+			{
+				const RealType slot_end = slot_start + tx->getSignal()->getLength();
+				if (slot_end > end)
+				{
+					return slots;
+				}
+				slots.push_back({tx->getName(), slot_start, slot_end});
+				slot_start = slot_end + guardTime;
+			}
+		}
+	}
+
+	std::vector<PulseCollision> calculatePulseCollisions(const core::World& world, const std::string& rxName,
+														 const RealType windowStart, const RealType windowEnd)
+	{
+		const auto* rx = world.findReceiver(rxName);
+		if (!rx)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' not found.");
+		}
+		if (!std::isfinite(windowStart) || !std::isfinite(windowEnd) || windowEnd <= windowStart)
+		{
+			throw std::runtime_error("Pulse collision window must end after it starts.");
+		}
+
+		struct Arrival
+		{
+			const Transmitter* tx;
+			std::size_t index;
+			RealType start;
+			RealType end;
+		};
+
+		std::vector<Arrival> arrivals;
+		const RealType end_time = std::min(params::endTime(), windowEnd);
+		for (const auto& tx : world.getTransmitters())
+		{
+			const auto* waveform = tx->getSignal();
+			if (tx->getAttached() == rx || !waveform || tx->getMode() != radar::OperationMode::PULSED_MODE ||
+				tx->getPrf() <= 0)
+			{
+				continue;
+			}
+
+			// Same emission sequence as the event loop: one PRI after each pulse, moved to the
+			// next scheduled period when it falls in a gap.
+			std::size_t index = 0;
+			for (auto t_emit = tx->getNextPulseTime(params::startTime()); t_emit && *t_emit <= end_time;
+				 t_emit = tx->getNextPulseTime(*t_emit + 1.0 / tx->getPrf()), ++index)
+			{
+				const RealType delay = (rx->getPosition(*t_emit) - tx->getPosition(*t_emit)).length() / params::c();
+				const RealType start = *t_emit + delay;
+				const RealType end = start + waveform->getLength();
+				if (end > windowStart && start < windowEnd)
+				{
+					arrivals.push_back({tx.get(), index, start, end});
+				}
+			}
+		}
+
+		std::ranges::sort(arrivals, {}, &Arrival::start);
+		std::vector<PulseCollision> collisions;
+		for (std::size_t i = 0; i < arrivals.size(); ++i)
+		{
+			const auto& first = arrivals[i];
+			for (std::size_t j = i + 1; j < arrivals.size() && arrivals[j].start < first.end; ++j)
+			{
+				const auto& second = arrivals[j];
+				if (second.tx == first.tx)
+				{
+					continue;
+				}
+				collisions.push_back({.first_transmitter = first.tx->getName(),
+									  .first_pulse_index = first.index,
+									  .second_transmitter = second.tx->getName(),
+									  .second_pulse_index = second.index,
+									  .overlap_start = std::max(second.start, windowStart),
+									  .overlap_end = std::min({first.end, second.end, windowEnd})});
+			}
+		}
+		return collisions;
+	}
+
+	std::vector<ComplexType> previewReceivedPulse(const core::World& world, const std::string& txName,
+												  const std::string& targetName, const std::string& rxName,
+												  const RealType time, const std::size_t numSamples)
+	{
+		const auto* tx = world.findTransmitter(txName);
+		if (!tx)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' not found.");
+		}
+		const auto* tgt = world.findTarget(targetName);
+		if (!tgt)
+		{
+			throw std::runtime_error("Target '" + targetName + "' not found.");
+		}
+		const auto* rx = world.findReceiver(rxName);
+		if (!rx)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' not found.");
+		}
+		if (tx->getMode() != radar::OperationMode::PULSED_MODE)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' is not pulsed.");
+		}
+		const auto* waveform = tx->getSignal();
+		if (!waveform)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' has no waveform attached.");
+		}
+		if (numSamples == 0)
+		{
+			throw std::runtime_error("At least one sample must be requested.");
+		}
+
+		ReResults results{};
+		solveRe(tx, rx, tgt, std::chrono::duration<RealType>(time), waveform, results);
+
+		const Vec3 tx_vel = tx->getPlatform()->getVelocity(time);
+		const Vec3 tgt_vel = tgt->getPlatform()->getVelocity(time);
+		const Vec3 rx_vel = rx->getPlatform()->getVelocity(time);
+		const Vec3 tgt_pos = tgt->getPosition(time);
+		const RealType path_rate = rangeRate(tx->getPosition(time), tx_vel, tgt_pos, tgt_vel) +
+			rangeRate(tgt_pos, tgt_vel, rx->getPosition(time), rx_vel);
+		const RealType scale = 1.0 + path_rate / params::c();
+
+		const auto envelope = waveform->getSignal()->getSamples();
+		const RealType envelope_rate = waveform->getRate();
+		const RealType amplitude = std::sqrt(results.power * waveform->getPower());
+		const RealType carrier = waveform->getCarrier();
+
+		std::vector<ComplexType> samples(numSamples);
+		for (std::size_t n = 0; n < numSamples; ++n)
+		{
+			// Offset into the pulse of the part emitted when this sample's energy left the transmitter
+			const RealType u = (static_cast<RealType>(n) / params::rate() - results.delay) / scale;
+			if (u < 0 || u >= waveform->getLength() || envelope.empty())
+			{
+				continue;
+			}
+
+			const RealType position = u * envelope_rate;
+			const std::size_t index = std::min(static_cast<std::size_t>(position), envelope.size() - 1);
+			const std::size_t next = std::min(index + 1, envelope.size() - 1);
+			const RealType frac = std::min(position - static_cast<RealType>(index), 1.0);
+			const ComplexType value = envelope[index] * (1.0 - frac) + envelope[next] * frac;
+
+			const RealType phase = -2.0 * PI * carrier * (results.delay + path_rate / params::c() * u);
+			samples[n] = amplitude * value * std::polar(1.0, phase);
+		}
+		return samples;
+	}
+
+	std::vector<ComplexType> calculateSteeringWeights(const core::World& world, const std::string& rxName,
+													  const RealType azimuth, const RealType elevation)
+	{
+		const auto* rx = world.findReceiver(rxName);
+		if (!rx)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' not found.");
+		}
+		const auto& elements = rx->getElementArray();
+		if (elements.empty())
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' has no element array.");
+		}
+
+		const fers_signal::RadarSignal* waveform = nullptr;
+		if (const auto* tx = dynamic_cast<const Transmitter*>(rx->getAttached()))
+		{
+			waveform = tx->getSignal();
+		}
+		for (auto it = world.getTransmitters().begin(); !waveform && it != world.getTransmitters().end(); ++it)
+		{
+			waveform = (*it)->getSignal();
+		}
+		if (!waveform)
+		{
+			throw std::runtime_error("No transmitter has a waveform to set the wavelength for receiver '" + rxName +
+									 "'.");
+		}
+
+		const RealType time = params::startTime();
+		const Vec3 look(SVec3(1.0, azimuth * PI / 180.0, elevation * PI / 180.0));
+		auto weights =
+			rx->getElementPhases(rx->bodyToWorld(look, time), time, params::c() / waveform->getCarrier());
+		for (auto& weight : weights)
+		{
+			weight /= static_cast<RealType>(elements.size());
+		}
+		return weights;
+	}
 }
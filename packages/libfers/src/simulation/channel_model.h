@@ -19,8 +19,13 @@
 #pragma once
 
 #include <chrono>
+#include <cstddef>
 #include <exception>
 #include <memory>
+#include <optional>
+#include <string>
+#include <utility>
+#include <vector>
 
 #include "core/config.h"
 #include "math/geometry_ops.h"
@@ -91,10 +96,12 @@ namespace simulation
 	 * @param time The time at which the pulse is transmitted.
 	 * @param wave Pointer to the transmitted radar signal.
 	 * @param results Output struct to store the calculation results.
+	 * @param carrier The carrier of this pulse in Hz, if it differs from the waveform's (frequency hopping).
 	 * @throws RangeError If the target is too close to the transmitter or receiver.
 	 */
 	void solveRe(const radar::Transmitter* trans, const radar::Receiver* recv, const radar::Target* targ,
-				 const std::chrono::duration<RealType>& time, const fers_signal::RadarSignal* wave, ReResults& results);
+				 const std::chrono::duration<RealType>& time, const fers_signal::RadarSignal* wave, ReResults& results,
+				 std::optional<RealType> carrier = std::nullopt);
 
 	/**
 	 * @brief Solves the radar equation for a direct path (Tx -> Rx).
@@ -107,11 +114,12 @@ namespace simulation
 	 * @param time The time at which the pulse is transmitted.
 	 * @param wave Pointer to the transmitted radar signal.
 	 * @param results Output struct to store the calculation results.
+	 * @param carrier The carrier of this pulse in Hz, if it differs from the waveform's (frequency hopping).
 	 * @throws RangeError If the transmitter and receiver are too close.
 	 */
 	void solveReDirect(const radar::Transmitter* trans, const radar::Receiver* recv,
 					   const std::chrono::duration<RealType>& time, const fers_signal::RadarSignal* wave,
-					   ReResults& results);
+					   ReResults& results, std::optional<RealType> carrier = std::nullopt);
 
 	/**
 	 * @brief Calculates the complex envelope contribution for a direct propagation path (Tx -> Rx) at a specific time.
@@ -151,13 +159,15 @@ namespace simulation
 	 * @param signal Pointer to the transmitted pulse signal.
 	 * @param startTime The absolute simulation time when the pulse transmission starts.
 	 * @param targ Optional pointer to a target. If null, a direct path is simulated.
+	 * @param carrier The carrier of this pulse in Hz, if it differs from the waveform's (frequency hopping).
 	 * @return A unique pointer to the generated Response object.
 	 * @throws RangeError If the channel model reports an invalid geometry.
 	 * @throws std::runtime_error If the simulation parameters result in zero time steps.
 	 */
 	std::unique_ptr<serial::Response> calculateResponse(const radar::Transmitter* trans, const radar::Receiver* recv,
 														const fers_signal::RadarSignal* signal, RealType startTime,
-														const radar::Target* targ = nullptr);
+														const radar::Target* targ = nullptr,
+														std::optional<RealType> carrier = std::nullopt);
 
 	/**
 	 * @enum LinkType
@@ -200,11 +210,476 @@ namespace simulation
 	 *
 	 * This function utilizes the core radar equation helpers to determine visibility,
 	 * power levels, and SNR for all Tx/Rx/Target combinations. It is lightweight
-	 * and does not update simulation state.
+	 * and does not update simulation state. Links with a leg masked by the scenario
+	 * terrain are omitted.
 	 *
 	 * @param world The simulation world containing radar components.
 	 * @param time The time at which to calculate geometry.
 	 * @return A vector of renderable links.
 	 */
 	std::vector<PreviewLink> calculatePreviewLinks(const core::World& world, RealType time);
+
+	/**
+	 * @struct InterferenceContribution
+	 * @brief The direct-path power received from a single interfering emitter.
+	 */
+	struct InterferenceContribution
+	{
+		std::string transmitter_name; ///< The name of the interfering transmitter.
+		RealType power; ///< Received power in watts.
+	};
+
+	/**
+	 * @struct InterferenceReport
+	 * @brief The aggregate interference seen by a receiver at a single instant.
+	 */
+	struct InterferenceReport
+	{
+		RealType total_power; ///< Sum of all contributions in watts.
+		RealType temperature; ///< Equivalent interference temperature in Kelvin over the receiver bandwidth.
+		std::vector<InterferenceContribution> contributions; ///< Per-emitter contributions.
+	};
+
+	/**
+	 * @brief Calculates the aggregate interference power at a receiver.
+	 *
+	 * Sums the direct-path (one-way) received power from every active transmitter,
+	 * excluding the receiver's own monostatic transmitter and any transmitter on the
+	 * same platform. Target echoes are not included. The interference temperature is
+	 * the total power divided by kB, using the same bandwidth as the thermal noise model.
+	 *
+	 * @param world The simulation world containing radar components.
+	 * @param rxName The name of the receiver.
+	 * @param time The time at which to evaluate the geometry.
+	 * @return The interference report.
+	 * @throws std::runtime_error If the receiver cannot be found.
+	 */
+	InterferenceReport calculateInterference(const core::World& world, const std::string& rxName, RealType time);
+
+	/**
+	 * @brief Calculates the transmit power needed to reach a target SNR (inverse radar equation).
+	 *
+	 * The two-way path gain (Tx -> Target -> Rx) is evaluated with `solveRe` and the
+	 * receiver noise is kTB, using the receiver and antenna noise temperatures and the
+	 * same bandwidth as the thermal noise model.
+	 *
+	 * @param world The simulation world containing radar components.
+	 * @param txName The name of the transmitter.
+	 * @param targetName The name of the target.
+	 * @param rxName The name of the receiver.
+	 * @param targetSnrDb The desired single-pulse SNR in dB.
+	 * @param time The time at which to evaluate the geometry.
+	 * @return The required transmit power in dBW.
+	 * @throws std::runtime_error If a component is missing or the receiver has no noise.
+	 * @throws RangeError If the target is too close to the transmitter or receiver.
+	 */
+	RealType calculateRequiredTransmitPower(const core::World& world, const std::string& txName,
+											const std::string& targetName, const std::string& rxName,
+											RealType targetSnrDb, RealType time);
+
+	/**
+	 * @brief Calculates the range at which a monostatic radar sees a target at a threshold SNR.
+	 *
+	 * Solves the monostatic radar equation SNR = Pt G^2 lambda^2 sigma / ((4 pi)^3 R^4 kTB) for R,
+	 * with the waveform power and carrier, the transmit and receive gains along the antenna
+	 * boresight at the start of the simulation, and the same receiver noise as
+	 * `calculateRequiredTransmitPower`.
+	 *
+	 * @param world The simulation world containing radar components.
+	 * @param monostaticName The name of the monostatic radar.
+	 * @param targetRcs The radar cross section of the target in square meters.
+	 * @param snrThresholdDb The single-pulse SNR required for detection in dB.
+	 * @return The maximum detection range in meters.
+	 * @throws std::runtime_error If the radar is missing or not monostatic, the RCS is not positive, the
+	 *         radar has no waveform or boresight gain, propagation loss is disabled, or the receiver has no noise.
+	 */
+	RealType calculateMaxDetectionRange(const core::World& world, const std::string& monostaticName,
+										RealType targetRcs, RealType snrThresholdDb);
+
+	/**
+	 * @struct LinkBudget
+	 * @brief The terms of the two-way radar equation for one transmitter, target and receiver.
+	 *
+	 * The received power is `transmit_power_dbw + tx_gain_dbi + rx_gain_dbi + rcs_dbsm - path_loss_db`.
+	 */
+	struct LinkBudget
+	{
+		RealType transmit_power_dbw; ///< Waveform power in dBW.
+		RealType tx_gain_dbi; ///< Transmit antenna gain towards the target in dBi.
+		RealType rx_gain_dbi; ///< Receive antenna gain towards the target, with calibration, in dBi.
+		RealType rcs_dbsm; ///< Target RCS for the transmit and receive directions in dBsm.
+		RealType path_loss_db; ///< Spreading loss (4 pi)^3 Rt^2 Rr^2 / lambda^2 in dB.
+		RealType received_power_dbw; ///< Echo power at the receiver in dBW.
+		RealType noise_power_dbw; ///< Receiver thermal noise kTB in dBW.
+		RealType snr_db; ///< Single-pulse (or single-sample, for CW) SNR in dB.
+		RealType integration_gain_db; ///< Coherent integration gain over the simulated span in dB.
+		RealType integrated_snr_db; ///< SNR after integration in dB.
+	};
+
+	/**
+	 * @brief Calculates the two-way link budget of a transmitter, target and receiver at a given range.
+	 *
+	 * The antenna gains and the target RCS are evaluated along the actual directions from the
+	 * transmitter and receiver to the target at the start of the simulation, while the target
+	 * is assumed to lie `range` from both, so monostatic and bistatic pairs are handled alike.
+	 * The noise is kTB as in `calculateNoiseFloor`. Pulsed transmitters integrate every pulse
+	 * emitted over the simulated span; CW transmitters integrate every sample of the noise
+	 * bandwidth over the span.
+	 *
+	 * @param world The simulation world containing radar components.
+	 * @param txName The name of the transmitter.
+	 * @param rxName The name of the receiver.
+	 * @param targetName The name of the target.
+	 * @param range The assumed range from the transmitter and from the receiver to the target in meters.
+	 * @return The link budget.
+	 * @throws std::runtime_error If a component or the waveform is missing, the range is not
+	 *         positive, or the receiver has no noise bandwidth.
+	 * @throws RangeError If the target coincides with the transmitter or receiver.
+	 */
+	LinkBudget calculateLinkBudget(const core::World& world, const std::string& txName, const std::string& rxName,
+								   const std::string& targetName, RealType range);
+
+	/**
+	 * @brief Calculates the bistatic range Tx -> Target -> Rx between antenna phase centers.
+	 *
+	 * Each end of the path starts at the component's phase center, i.e. its platform
+	 * position plus the phase-center offset rotated by the platform attitude.
+	 *
+	 * @param world The simulation world containing radar components.
+	 * @param txName The name of the transmitter.
+	 * @param targetName The name of the target.
+	 * @param rxName The name of the receiver.
+	 * @param time The time at which to evaluate the geometry.
+	 * @return The total path length in meters.
+	 * @throws std::runtime_error If a component is missing.
+	 */
+	RealType calculatePhaseCenterRange(const core::World& world, const std::string& txName,
+									   const std::string& targetName, const std::string& rxName, RealType time);
+
+	/**
+	 * @brief Calculates the thermal noise floor kTB of a receiver.
+	 *
+	 * The temperature is the receiver's noise temperature, or the 290 K reference when none is
+	 * set. The bandwidth is the reciprocal of the window length for pulsed receivers; CW
+	 * receivers use the bandwidth of the simulated thermal noise.
+	 *
+	 * @param world The simulation world containing radar components.
+	 * @param rxName The name of the receiver.
+	 * @return The noise power in dBm.
+	 * @throws std::runtime_error If the receiver is missing or the noise bandwidth is not positive.
+	 */
+	RealType calculateNoiseFloor(const core::World& world, const std::string& rxName);
+
+	/**
+	 * @brief Calculates the lowest PRF that measures a target's radial velocity without ambiguity.
+	 *
+	 * The radial velocity is half the rate of change of the bistatic path Tx -> Target -> Rx,
+	 * which reduces to the usual line-of-sight velocity for a monostatic radar. Its largest
+	 * magnitude over the simulation span is found by sampling the interpolated platform
+	 * velocities, and the PRF is chosen so that the unambiguous velocity `lambda * prf / 4`
+	 * reaches it.
+	 *
+	 * A high PRF for velocity shortens the unambiguous range `c / (2 * prf)`, so this bound
+	 * often conflicts with the PRF needed to place distant targets unambiguously.
+	 *
+	 * @param world The simulation world containing radar components.
+	 * @param txName The name of the transmitter.
+	 * @param targetName The name of the target.
+	 * @param rxName The name of the receiver.
+	 * @return The minimum PRF in Hz.
+	 * @throws std::runtime_error If a component is missing or the transmitter has no waveform.
+	 */
+	RealType calculateMinimumPrfForVelocity(const core::World& world, const std::string& txName,
+											const std::string& targetName, const std::string& rxName);
+
+	/**
+	 * @struct DataRate
+	 * @brief The rate at which a simulation run writes receiver samples to its output files.
+	 */
+	struct DataRate
+	{
+		RealType peak_bytes_per_second; ///< Output rate while every active receiver is recording.
+		RealType average_bytes_per_second; ///< Output rate averaged over the simulation span.
+		std::size_t active_receivers; ///< Receivers that record during the simulation span.
+	};
+
+	/**
+	 * @brief Calculates the output data rate of a simulation run.
+	 *
+	 * Each complex sample is written as an I and a Q value of `RealType` at the output sample
+	 * rate. CW receivers record continuously, while pulsed receivers record only for the
+	 * fraction `window_length * window_prf` of each period. A receiver's average contribution
+	 * is further scaled by the share of the simulation span covered by its schedule; receivers
+	 * whose schedule never overlaps the span are not counted.
+	 *
+	 * @param world The simulation world containing radar components.
+	 * @return The peak and average output rates in bytes per second.
+	 */
+	DataRate calculateDataRate(const core::World& world);
+
+	/**
+	 * @struct IntegrationTime
+	 * @brief The coherent processing interval needed for a velocity resolution, against a receiver's dwell.
+	 */
+	struct IntegrationTime
+	{
+		RealType required_time; ///< The coherent processing interval in seconds.
+		RealType available_dwell; ///< The longest continuous span the receiver records, in seconds.
+		bool sufficient; ///< True if the available dwell covers the required interval.
+	};
+
+	/**
+	 * @brief Calculates the coherent processing interval needed to resolve a radial velocity difference.
+	 *
+	 * Inverts the velocity resolution `lambda / (2 * CPI)` of a coherent Doppler measurement. The
+	 * receiver's available dwell is the simulation span, or the longest of its schedule periods
+	 * clipped to that span if it has a schedule.
+	 *
+	 * @param world The simulation world containing radar components.
+	 * @param rxName The name of the receiver.
+	 * @param velocityResolution The velocity resolution in m/s.
+	 * @param carrier The carrier frequency in Hz.
+	 * @return The required interval, the receiver's available dwell and whether it suffices.
+	 * @throws std::runtime_error If the receiver is missing or the resolution or carrier is not positive.
+	 */
+	IntegrationTime calculateRequiredIntegrationTime(const core::World& world, const std::string& rxName,
+													 RealType velocityResolution, RealType carrier);
+
+	/**
+	 * @brief Calculates the Cramér-Rao lower bound on the range error of a Tx -> Target -> Rx echo.
+	 *
+	 * The SNR is the single-pulse link budget used by `calculateRequiredTransmitPower`, and the
+	 * effective bandwidth is the RMS bandwidth of a flat spectrum spanning the waveform's source
+	 * sample rate. The delay bound 1 / (2 pi B_rms sqrt(SNR)) is converted to one-way range with c / 2.
+	 *
+	 * @param world The simulation world containing radar components.
+	 * @param txName The name of the transmitter.
+	 * @param targetName The name of the target.
+	 * @param rxName The name of the receiver.
+	 * @param time The time at which to evaluate the geometry.
+	 * @return The standard deviation bound on range in meters.
+	 * @throws std::runtime_error If a component is missing, the waveform has no bandwidth (e.g. CW), or
+	 *         the receiver has no noise.
+	 * @throws RangeError If the target is too close to the transmitter or receiver.
+	 */
+	RealType calculateRangeCrlb(const core::World& world, const std::string& txName, const std::string& targetName,
+								const std::string& rxName, RealType time);
+
+	/**
+	 * @brief Calculates the matched-filter SNR loss caused by a receive window shorter than the pulse.
+	 *
+	 * The pulse energy is assumed to be spread uniformly over its duration, so the captured
+	 * fraction is the ratio of the receiver's window length to the transmitter's pulse length.
+	 *
+	 * @param world The simulation world containing radar components.
+	 * @param rxName The name of the (pulsed) receiver.
+	 * @param txName The name of the transmitter.
+	 * @return The SNR loss in dB (zero when the window contains the whole pulse).
+	 * @throws std::runtime_error If a component is missing, the receiver is not pulsed, or the
+	 *         transmitter has no pulsed waveform.
+	 */
+	RealType calculateWindowTruncationLoss(const core::World& world, const std::string& rxName,
+										   const std::string& txName);
+
+	/**
+	 * @brief Calculates the ranges at which a pulsed receiver cannot see returns from a transmitter.
+	 *
+	 * Within one pulse repetition interval, an echo is received only if it arrives while the
+	 * receive window is open and the transmitter is not radiating. Echo delays that fall in
+	 * the transmit pulse, before the window opens (the protection window set by the window
+	 * skip) or after it closes are eclipsed. Delays are converted to range as `c * delay / 2`,
+	 * which is the monostatic range (half the path length for a bistatic pair).
+	 *
+	 * @param world The simulation world containing radar components.
+	 * @param rxName The name of the pulsed receiver.
+	 * @param txName The name of the pulsed transmitter.
+	 * @return Sorted (range start, range end) pairs in meters, up to the unambiguous range.
+	 * @throws std::runtime_error If a component is missing, either is not pulsed, or the
+	 *         receiver window PRF differs from the transmitter PRF.
+	 */
+	std::vector<std::pair<RealType, RealType>> calculateBlindRanges(const core::World& world, const std::string& rxName,
+																	const std::string& txName);
+
+	/**
+	 * @struct PrfBlindZones
+	 * @brief The blind ranges and blind speeds of a radar operating at a single PRF.
+	 */
+	struct PrfBlindZones
+	{
+		RealType prf; ///< The pulse repetition frequency in Hz.
+		std::vector<std::pair<RealType, RealType>> blind_ranges; ///< Eclipsed (start, end) ranges in meters.
+		std::vector<RealType> blind_speeds; ///< Non-zero radial speeds in m/s with a Doppler shift of a PRF multiple.
+	};
+
+	/**
+	 * @struct BlindZoneCoverage
+	 * @brief The combined blind zones of a radar that staggers between several PRFs.
+	 */
+	struct BlindZoneCoverage
+	{
+		std::vector<PrfBlindZones> prfs; ///< The blind zones of each PRF, in the order given.
+		std::vector<std::pair<RealType, RealType>> unrecoverable_ranges; ///< Ranges blind at every PRF.
+		std::vector<std::pair<RealType, RealType>> recoverable_ranges; ///< Ranges blind at some, but not all, PRFs.
+		std::vector<RealType> unrecoverable_speeds; ///< Speeds blind at every PRF.
+		std::vector<RealType> recoverable_speeds; ///< Speeds blind at some, but not all, PRFs.
+		RealType max_range; ///< The end of the evaluated range span in meters.
+		RealType max_speed; ///< The end of the evaluated speed span in m/s.
+	};
+
+	/**
+	 * @brief Calculates the combined range and velocity blind zones of a staggered PRF set.
+	 *
+	 * For each PRF the receive window keeps its skip and length, the blind ranges follow
+	 * `calculateBlindRanges` and repeat every unambiguous range, and the blind speeds are
+	 * the multiples of `lambda * prf / 2`. Ranges are evaluated up to the unambiguous range
+	 * of the lowest PRF. Speeds are evaluated up to the first speed that is blind at every
+	 * PRF, or to 100 blind speeds of the highest PRF when the set has no common blind speed
+	 * before then.
+	 *
+	 * @param world The simulation world containing radar components.
+	 * @param rxName The name of the pulsed receiver.
+	 * @param txName The name of the pulsed transmitter.
+	 * @param prfs The PRFs to stagger between, in Hz.
+	 * @return The per-PRF and combined blind zones.
+	 * @throws std::runtime_error If a component is missing or not pulsed, or the PRF set is
+	 *         empty or contains a non-positive PRF.
+	 */
+	BlindZoneCoverage calculateBlindZoneCoverage(const core::World& world, const std::string& rxName,
+												 const std::string& txName, const std::vector<RealType>& prfs);
+
+	/**
+	 * @brief Calculates the total occupied bandwidth of all active emitters over time.
+	 *
+	 * Each active transmitter occupies the band centred on its carrier with a width equal
+	 * to its waveform's sample rate. CW waveforms are treated as pure tones and occupy no
+	 * bandwidth. Overlapping bands are merged so shared spectrum is only counted once.
+	 *
+	 * @param world The simulation world containing radar components.
+	 * @param numPoints The number of evenly spaced samples between the start and end time.
+	 * @return A vector of (time, occupied bandwidth in Hz) pairs.
+	 * @throws std::runtime_error If fewer than two points are requested.
+	 */
+	std::vector<std::pair<RealType, RealType>> calculateSpectrumOccupancy(const core::World& world,
+																		  std::size_t numPoints);
+
+	/**
+	 * @brief Finds the moment when the most transmitters are active at once.
+	 *
+	 * Activity is taken from each transmitter's schedule; an unscheduled transmitter is active
+	 * throughout. The count is evaluated on `numPoints` evenly spaced times and at the start of
+	 * every schedule period inside the simulation, so brief overlaps between samples are not missed.
+	 *
+	 * @param world The simulation world containing radar components.
+	 * @param numPoints The number of evenly spaced samples between the start and end time.
+	 * @return The earliest time at which the peak occurs and the number of active transmitters then.
+	 * @throws std::runtime_error If fewer than two points are requested.
+	 */
+	std::pair<RealType, std::size_t> calculatePeakEmitterCount(const core::World& world, std::size_t numPoints);
+
+	/**
+	 * @struct TdmSlot
+	 * @brief An emission slot reserved for one transmitter in a time-division schedule.
+	 */
+	struct TdmSlot
+	{
+		std::string transmitter_name; ///< The name of the transmitter that owns the slot.
+		RealType start; ///< Slot start time in seconds.
+		RealType end; ///< Slot end time in seconds.
+	};
+
+	/**
+	 * @brief Calculates a time-division schedule that keeps pulsed transmitters from overlapping.
+	 *
+	 * Transmitters take turns within a frame as long as the shortest PRI in the scenario, so
+	 * every transmitter emits at least as often as its PRF requires. Each slot is as long as
+	 * the transmitter's pulse and is followed by the guard time. Frames repeat from the start
+	 * time, and only slots that end by the end time are returned. Component schedules are not
+	 * taken into account.
+	 *
+	 * @param world The simulation world containing radar components.
+	 * @param guardTime The idle time in seconds after every slot.
+	 * @return The slots in time order.
+	 * @throws std::runtime_error If the guard time is negative, there are no transmitters, a
+	 *         transmitter is not pulsed, or the pulses and guard times do not fit within the
+	 *         shortest PRI.
+	 */
+	std::vector<TdmSlot> calculateTdmSchedule(const core::World& world, RealType guardTime);
+
+	/**
+	 * @struct PulseCollision
+	 * @brief Two pulses from different transmitters that overlap in time at a receiver.
+	 */
+	struct PulseCollision
+	{
+		std::string first_transmitter; ///< The transmitter whose pulse arrives first.
+		std::size_t first_pulse_index; ///< The index of that pulse among its transmitter's pulses.
+		std::string second_transmitter; ///< The transmitter whose pulse arrives second.
+		std::size_t second_pulse_index; ///< The index of that pulse among its transmitter's pulses.
+		RealType overlap_start; ///< Start of the overlap at the receiver in seconds.
+		RealType overlap_end; ///< End of the overlap at the receiver in seconds.
+	};
+
+	/**
+	 * @brief Finds pulses from different transmitters that arrive at a receiver at the same time.
+	 *
+	 * Pulses are emitted from the start time at each transmitter's PRF, following its schedule,
+	 * and arrive after the direct-path delay evaluated at the emission time. Each arrival lasts
+	 * one pulse length. CW transmitters and the receiver's own monostatic transmitter are
+	 * ignored, and overlaps are clipped to the requested window.
+	 *
+	 * @param world The simulation world containing radar components.
+	 * @param rxName The name of the receiver.
+	 * @param windowStart The start of the arrival window in seconds.
+	 * @param windowEnd The end of the arrival window in seconds.
+	 * @return The collisions ordered by the arrival of the first pulse.
+	 * @throws std::runtime_error If the receiver is not found or the window is empty.
+	 */
+	std::vector<PulseCollision> calculatePulseCollisions(const core::World& world, const std::string& rxName,
+														 RealType windowStart, RealType windowEnd);
+
+	/**
+	 * @brief Computes the closed-form baseband echo of a single pulse off one target.
+	 *
+	 * The pulse is emitted at `time` and the receiver is sampled at the simulation rate from
+	 * that instant, so the echo starts `delay * rate` samples in. The amplitude and round-trip
+	 * delay come from the bistatic radar equation at the emission time. The range rate of the
+	 * whole Tx -> Target -> Rx path, including the transmitter's own motion, both Doppler
+	 * shifts the carrier and time-scales the envelope by `1 + rate / c`. The waveform envelope
+	 * is interpolated linearly between its samples. Noise and timing offsets are not modelled.
+	 *
+	 * @param world The simulation world containing radar components.
+	 * @param txName The name of the transmitter.
+	 * @param targetName The name of the target.
+	 * @param rxName The name of the receiver.
+	 * @param time The emission time of the pulse in seconds.
+	 * @param numSamples The number of receiver samples to compute.
+	 * @return The complex baseband samples of the echo.
+	 * @throws std::runtime_error If a component is missing, the transmitter is not pulsed or has
+	 *         no waveform, or no samples are requested.
+	 * @throws RangeError If the target coincides with the transmitter or receiver.
+	 */
+	std::vector<ComplexType> previewReceivedPulse(const core::World& world, const std::string& txName,
+												  const std::string& targetName, const std::string& rxName,
+												  RealType time, std::size_t numSamples);
+
+	/**
+	 * @brief Computes the beamforming weights that steer a receiver's element array to a look direction.
+	 *
+	 * The weights are the array's plane-wave response in the look direction divided by the
+	 * element count, so the beamformer output `sum(conj(w_n) * x_n)` passes a wave from that
+	 * direction with unit gain. The look direction is relative to the platform body frame
+	 * (azimuth to the left of boresight, elevation up), evaluated at the simulation start. The
+	 * wavelength is that of the monostatic transmitter's waveform, or else of the first
+	 * transmitter with a waveform.
+	 *
+	 * @param world The simulation world containing radar components.
+	 * @param rxName The name of the receiver.
+	 * @param azimuth The look azimuth in degrees.
+	 * @param elevation The look elevation in degrees.
+	 * @return One complex weight per array element.
+	 * @throws std::runtime_error If the receiver is not found, has no element array, or no
+	 *         transmitter has a waveform.
+	 */
+	std::vector<ComplexType> calculateSteeringWeights(const core::World& world, const std::string& rxName,
+													  RealType azimuth, RealType elevation);
 }
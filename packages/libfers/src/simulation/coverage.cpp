@@ -0,0 +1,651 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+/**
+ * @file coverage.cpp
+ * @brief Implementation of ground-plane coverage analysis routines.
+ */
+
+#include "coverage.h"
+
+#include <algorithm>
+#include <array>
+#include <cmath>
+#include <iterator>
+#include <limits>
+#include <optional>
+#include <stdexcept>
+#include <unordered_map>
+#include <utility>
+
+#include "core/parameters.h"
+#include "core/world.h"
+#include "math/path.h"
+#include "radar/platform.h"
+#include "radar/radar_obj.h"
+#include "radar/receiver.h"
+#include "radar/target.h"
+#include "radar/transmitter.h"
+#include "signal/radar_signal.h"
+
+using math::Vec3;
+
+namespace
+{
+	/**
+	 * @brief Computes the rate of change of the distance between a moving object and a fixed point.
+	 * @param pos The object's position.
+	 * @param vel The object's velocity.
+	 * @param point The fixed point.
+	 * @return The range rate in m/s (positive when opening).
+	 */
+	RealType rangeRate(const Vec3& pos, const Vec3& vel, const Vec3& point)
+	{
+		const Vec3 los = point - pos;
+		const RealType dist = los.length();
+		if (dist <= EPSILON)
+		{
+			return 0.0;
+		}
+		return -math::dotProduct(vel, los) / dist;
+	}
+
+	/**
+	 * @brief Returns a unique key for the grid edge between two adjacent nodes.
+	 *
+	 * Horizontal edges (i, j) -> (i + 1, j) map to even keys and vertical edges
+	 * (i, j) -> (i, j + 1) to odd keys, so shared edges of neighbouring cells resolve
+	 * to the same crossing point.
+	 */
+	std::size_t edgeKey(const std::size_t i, const std::size_t j, const std::size_t nx, const bool vertical)
+	{
+		return 2 * (j * nx + i) + (vertical ? 1 : 0);
+	}
+
+	/**
+	 * @brief Returns twice the signed area of a polygon (positive when counter-clockwise).
+	 */
+	RealType signedArea2(const simulation::Polyline& polygon)
+	{
+		RealType area = 0.0;
+		for (std::size_t i = 0; i < polygon.size(); ++i)
+		{
+			const auto& a = polygon[i];
+			const auto& b = polygon[(i + 1) % polygon.size()];
+			area += a.x * b.y - b.x * a.y;
+		}
+		return area;
+	}
+
+	/**
+	 * @brief Returns the side of the directed line a -> b on which p lies (positive on the left).
+	 */
+	RealType sideOf(const simulation::GroundPoint& a, const simulation::GroundPoint& b,
+					const simulation::GroundPoint& p)
+	{
+		return (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x);
+	}
+
+	/**
+	 * @brief Finds the off-boresight angle at which a radar's gain first drops to half power.
+	 *
+	 * The angle is scanned outwards in the plane spanned by the boresight and `across`, then
+	 * refined by bisection.
+	 *
+	 * @param radar The transmitter or receiver whose antenna is evaluated.
+	 * @param wavelength The wavelength at which the antenna gain is evaluated, in meters.
+	 * @param time The simulation time in seconds.
+	 * @param across A unit vector perpendicular to the boresight.
+	 * @return The half-power angle in radians, or nothing if the gain stays above half power
+	 *         within 90 degrees of boresight.
+	 * @throws std::runtime_error If the antenna has no gain along its boresight.
+	 */
+	std::optional<RealType> halfPowerAngle(const radar::Radar* radar, const RealType wavelength, const RealType time,
+										   const Vec3& across)
+	{
+		constexpr std::size_t scan_steps = 900;
+		constexpr int bisection_steps = 40;
+
+		const math::SVec3 rotation = radar->getRotation(time);
+		const Vec3 boresight(math::SVec3(1.0, rotation.azimuth, rotation.elevation));
+		const auto gain = [&](const RealType theta)
+		{
+			const Vec3 direction = boresight * std::cos(theta) + across * std::sin(theta);
+			return radar->getGain(math::SVec3(direction), rotation, wavelength);
+		};
+		const RealType half_power = 0.5 * gain(0.0);
+		if (!(half_power > 0))
+		{
+			throw std::runtime_error("Antenna of '" + radar->getName() + "' has no gain along its boresight.");
+		}
+
+		const RealType step = PI / 2.0 / static_cast<RealType>(scan_steps);
+		RealType inner = 0.0;
+		std::optional<RealType> outer;
+		for (std::size_t n = 1; n <= scan_steps && !outer; ++n)
+		{
+			const RealType theta = step * static_cast<RealType>(n);
+			if (gain(theta) < half_power)
+			{
+				outer = theta;
+			}
+			else
+			{
+				inner = theta;
+			}
+		}
+		if (!outer)
+		{
+			return std::nullopt;
+		}
+		for (int n = 0; n < bisection_steps; ++n)
+		{
+			if (const RealType mid = 0.5 * (inner + *outer); gain(mid) < half_power)
+			{
+				outer = mid;
+			}
+			else
+			{
+				inner = mid;
+			}
+		}
+		return 0.5 * (inner + *outer);
+	}
+
+	/**
+	 * @brief Returns the full half-power beamwidth of a radar in the plane of boresight and `across`.
+	 * @throws std::runtime_error If the beam has no half-power edge on either side.
+	 */
+	RealType halfPowerBeamwidth(const radar::Radar* radar, const RealType wavelength, const RealType time,
+								const Vec3& across)
+	{
+		const auto positive = halfPowerAngle(radar, wavelength, time, across);
+		const auto negative = halfPowerAngle(radar, wavelength, time, -across);
+		if (!positive || !negative)
+		{
+			throw std::runtime_error("Antenna of '" + radar->getName() +
+									 "' has no half-power beam edge within 90 degrees of boresight.");
+		}
+		return *positive + *negative;
+	}
+
+	/**
+	 * @brief Returns a radar's full half-power beamwidths in its azimuth and elevation planes.
+	 * @throws std::runtime_error If the beam has no half-power edge in either plane.
+	 */
+	std::pair<RealType, RealType> azimuthElevationBeamwidths(const radar::Radar* radar, const RealType wavelength,
+															 const RealType time)
+	{
+		const math::SVec3 rotation = radar->getRotation(time);
+		const Vec3 azimuth_dir(math::SVec3(1.0, rotation.azimuth + PI / 2.0, 0.0));
+		const Vec3 elevation_dir(math::SVec3(1.0, rotation.azimuth, rotation.elevation + PI / 2.0));
+		return {halfPowerBeamwidth(radar, wavelength, time, azimuth_dir),
+				halfPowerBeamwidth(radar, wavelength, time, elevation_dir)};
+	}
+}
+
+namespace simulation
+{
+	Vec3 GroundGrid::nodePosition(const std::size_t i, const std::size_t j) const noexcept
+	{
+		const RealType x = x_min + (x_max - x_min) * static_cast<RealType>(i) / static_cast<RealType>(nx - 1);
+		const RealType y = y_min + (y_max - y_min) * static_cast<RealType>(j) / static_cast<RealType>(ny - 1);
+		return {x, y, z};
+	}
+
+	RealType computeStationaryDoppler(const radar::Transmitter* trans, const radar::Receiver* recv,
+									  const Vec3& point, const RealType time)
+	{
+		const auto* signal = trans->getSignal();
+		if (!signal)
+		{
+			throw std::runtime_error("Transmitter '" + trans->getName() + "' has no waveform attached.");
+		}
+		const RealType lambda = params::c() / signal->getCarrier();
+
+		const Vec3 v_tx = trans->getPlatform()->getVelocity(time);
+		const Vec3 v_rx = recv->getPlatform()->getVelocity(time);
+
+		const RealType path_rate =
+			rangeRate(trans->getPosition(time), v_tx, point) + rangeRate(recv->getPosition(time), v_rx, point);
+		return -path_rate / lambda;
+	}
+
+	RealType computeGrazingAngle(const core::World& world, const std::string& platformName,
+								 const GroundPoint& groundPoint, const RealType groundZ, const RealType time)
+	{
+		const auto* platform = world.findPlatform(platformName);
+		if (!platform)
+		{
+			throw std::runtime_error("Platform '" + platformName + "' not found.");
+		}
+
+		const Vec3 pos = platform->getPosition(time);
+		Vec3 ground(groundPoint.x, groundPoint.y, groundZ);
+		if (const auto* terrain = world.getTerrain())
+		{
+			// The terrain replaces the ground plane wherever it has data
+			if (const auto surface = terrain->surfacePoint(groundPoint.x, groundPoint.y))
+			{
+				ground.z = surface->z;
+			}
+			if (!terrain->hasLineOfSight(pos, ground))
+			{
+				throw std::runtime_error("The ground point is hidden from platform '" + platformName +
+										 "' by the terrain.");
+			}
+		}
+		const RealType height = pos.z - ground.z;
+		if (height <= EPSILON)
+		{
+			throw std::runtime_error("Platform '" + platformName + "' is not above the ground.");
+		}
+		const RealType ground_range = std::hypot(ground.x - pos.x, ground.y - pos.y);
+		return std::atan2(height, ground_range) * 180.0 / PI;
+	}
+
+	bool hasLineOfSight(const core::World& world, const std::string& fromPlatform, const std::string& toPlatform,
+						const RealType time)
+	{
+		const auto* from = world.findPlatform(fromPlatform);
+		if (!from)
+		{
+			throw std::runtime_error("Platform '" + fromPlatform + "' not found.");
+		}
+		const auto* to = world.findPlatform(toPlatform);
+		if (!to)
+		{
+			throw std::runtime_error("Platform '" + toPlatform + "' not found.");
+		}
+		const auto* terrain = world.getTerrain();
+		return !terrain || terrain->hasLineOfSight(from->getPosition(time), to->getPosition(time));
+	}
+
+	std::vector<Polyline> extractContours(const GroundGrid& grid, const std::vector<RealType>& values,
+										  const RealType level)
+	{
+		if (grid.nx < 2 || grid.ny < 2)
+		{
+			throw std::invalid_argument("Contour grid must have at least 2 samples along each axis.");
+		}
+		if (values.size() != grid.nx * grid.ny)
+		{
+			throw std::invalid_argument("Contour value count does not match the grid dimensions.");
+		}
+
+		const auto value_at = [&](const std::size_t i, const std::size_t j) { return values[j * grid.nx + i]; };
+
+		std::unordered_map<std::size_t, GroundPoint> crossings;
+		std::unordered_map<std::size_t, std::vector<std::size_t>> adjacency;
+		std::vector<std::array<std::size_t, 2>> segments;
+
+		// Computes (once) the interpolated crossing on the edge between two nodes and returns its key.
+		const auto crossing = [&](const std::size_t i0, const std::size_t j0, const std::size_t i1,
+								  const std::size_t j1) -> std::size_t
+		{
+			const bool vertical = i0 == i1;
+			const std::size_t key = edgeKey(std::min(i0, i1), std::min(j0, j1), grid.nx, vertical);
+			if (!crossings.contains(key))
+			{
+				const RealType v0 = value_at(i0, j0);
+				const RealType v1 = value_at(i1, j1);
+				const RealType t = (level - v0) / (v1 - v0);
+				const Vec3 p0 = grid.nodePosition(i0, j0);
+				const Vec3 p1 = grid.nodePosition(i1, j1);
+				crossings[key] = {p0.x + t * (p1.x - p0.x), p0.y + t * (p1.y - p0.y)};
+			}
+			return key;
+		};
+
+		const auto add_segment = [&](const std::size_t a, const std::size_t b)
+		{
+			adjacency[a].push_back(segments.size());
+			adjacency[b].push_back(segments.size());
+			segments.push_back({a, b});
+		};
+
+		for (std::size_t j = 0; j + 1 < grid.ny; ++j)
+		{
+			for (std::size_t i = 0; i + 1 < grid.nx; ++i)
+			{
+				// Corners in counter-clockwise order: bottom-left, bottom-right, top-right, top-left.
+				const std::array<std::array<std::size_t, 2>, 4> corners{
+					{{i, j}, {i + 1, j}, {i + 1, j + 1}, {i, j + 1}}};
+				std::array<bool, 4> above{};
+				for (std::size_t c = 0; c < 4; ++c)
+				{
+					above[c] = value_at(corners[c][0], corners[c][1]) >= level;
+				}
+
+				// Edge e connects corner e to corner (e + 1) % 4.
+				std::array<std::size_t, 4> edge_keys{};
+				std::vector<std::size_t> crossed;
+				for (std::size_t e = 0; e < 4; ++e)
+				{
+					const auto& a = corners[e];
+					const auto& b = corners[(e + 1) % 4];
+					if (above[e] != above[(e + 1) % 4])
+					{
+						edge_keys[e] = crossing(a[0], a[1], b[0], b[1]);
+						crossed.push_back(e);
+					}
+				}
+
+				if (crossed.size() == 2)
+				{
+					add_segment(edge_keys[crossed[0]], edge_keys[crossed[1]]);
+				}
+				else if (crossed.size() == 4)
+				{
+					const RealType centre =
+						(value_at(i, j) + value_at(i + 1, j) + value_at(i + 1, j + 1) + value_at(i, j + 1)) / 4.0;
+					if (above[0] == (centre >= level))
+					{
+						// The bottom-left and top-right corners are connected through the centre.
+						add_segment(edge_keys[0], edge_keys[1]);
+						add_segment(edge_keys[2], edge_keys[3]);
+					}
+					else
+					{
+						add_segment(edge_keys[3], edge_keys[0]);
+						add_segment(edge_keys[1], edge_keys[2]);
+					}
+				}
+			}
+		}
+
+		// Join segments into polylines, starting with open chains (endpoints with a single segment)
+		// and then tracing any remaining closed loops.
+		std::vector<bool> used(segments.size(), false);
+		std::vector<Polyline> polylines;
+
+		const auto trace = [&](std::size_t key, std::size_t seg)
+		{
+			Polyline line{crossings.at(key)};
+			while (true)
+			{
+				used[seg] = true;
+				key = segments[seg][0] == key ? segments[seg][1] : segments[seg][0];
+				line.push_back(crossings.at(key));
+
+				bool found = false;
+				for (const std::size_t next : adjacency.at(key))
+				{
+					if (!used[next])
+					{
+						seg = next;
+						found = true;
+						break;
+					}
+				}
+				if (!found)
+				{
+					break;
+				}
+			}
+			polylines.push_back(std::move(line));
+		};
+
+		for (const auto& [key, segs] : adjacency)
+		{
+			if (segs.size() == 1 && !used[segs.front()])
+			{
+				trace(key, segs.front());
+			}
+		}
+		for (std::size_t s = 0; s < segments.size(); ++s)
+		{
+			if (!used[s])
+			{
+				trace(segments[s][0], s);
+			}
+		}
+
+		return polylines;
+	}
+
+	std::vector<Polyline> computeIsodopplerContours(const core::World& world, const std::string& txName,
+													const std::string& rxName,
+													const std::vector<RealType>& dopplerValues,
+													const GroundGrid& grid, const RealType time)
+	{
+		const auto* trans = world.findTransmitter(txName);
+		if (!trans)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' not found.");
+		}
+		const auto* recv = world.findReceiver(rxName);
+		if (!recv)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' not found.");
+		}
+		if (grid.nx < 2 || grid.ny < 2)
+		{
+			throw std::invalid_argument("Contour grid must have at least 2 samples along each axis.");
+		}
+
+		std::vector<RealType> doppler(grid.nx * grid.ny);
+		for (std::size_t j = 0; j < grid.ny; ++j)
+		{
+			for (std::size_t i = 0; i < grid.nx; ++i)
+			{
+				doppler[j * grid.nx + i] = computeStationaryDoppler(trans, recv, grid.nodePosition(i, j), time);
+			}
+		}
+
+		std::vector<Polyline> contours;
+		for (const RealType level : dopplerValues)
+		{
+			auto level_contours = extractContours(grid, doppler, level);
+			contours.insert(contours.end(), std::make_move_iterator(level_contours.begin()),
+							std::make_move_iterator(level_contours.end()));
+		}
+		return contours;
+	}
+
+	Polyline computeBeamFootprint(const radar::Radar* radar, const RealType wavelength, const RealType groundZ,
+								  const RealType time)
+	{
+		constexpr std::size_t boundary_rays = 72;
+
+		const Vec3 origin = radar->getPhaseCenterPosition(time);
+		if (origin.z - groundZ <= EPSILON)
+		{
+			throw std::runtime_error("Antenna of '" + radar->getName() + "' is not above the ground plane.");
+		}
+
+		const math::SVec3 rotation = radar->getRotation(time);
+		const Vec3 boresight(math::SVec3(1.0, rotation.azimuth, rotation.elevation));
+
+		// Orthonormal basis (u, v) perpendicular to the boresight
+		const Vec3 helper = std::abs(boresight.z) < 0.9 ? Vec3(0.0, 0.0, 1.0) : Vec3(1.0, 0.0, 0.0);
+		Vec3 u = math::crossProduct(boresight, helper);
+		u /= u.length();
+		const Vec3 v = math::crossProduct(boresight, u);
+
+		Polyline footprint;
+		footprint.reserve(boundary_rays);
+		for (std::size_t k = 0; k < boundary_rays; ++k)
+		{
+			const RealType phi = 2.0 * PI * static_cast<RealType>(k) / static_cast<RealType>(boundary_rays);
+			const Vec3 across = u * std::cos(phi) + v * std::sin(phi);
+			const auto theta = halfPowerAngle(radar, wavelength, time, across);
+			if (!theta)
+			{
+				throw std::runtime_error("Antenna of '" + radar->getName() +
+										 "' has no half-power beam edge within 90 degrees of boresight.");
+			}
+
+			const Vec3 edge = boresight * std::cos(*theta) + across * std::sin(*theta);
+			if (edge.z >= -EPSILON)
+			{
+				throw std::runtime_error("The half-power beam of '" + radar->getName() +
+										 "' does not intersect the ground plane on all sides.");
+			}
+			const RealType distance = (groundZ - origin.z) / edge.z;
+			footprint.push_back({origin.x + edge.x * distance, origin.y + edge.y * distance});
+		}
+
+		if (signedArea2(footprint) < 0)
+		{
+			std::ranges::reverse(footprint);
+		}
+		return footprint;
+	}
+
+	Polyline computeBistaticFootprintOverlap(const core::World& world, const std::string& txName,
+											 const std::string& rxName, const RealType groundZ, const RealType time)
+	{
+		const auto* trans = world.findTransmitter(txName);
+		if (!trans)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' not found.");
+		}
+		const auto* recv = world.findReceiver(rxName);
+		if (!recv)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' not found.");
+		}
+		const auto* signal = trans->getSignal();
+		if (!signal)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' has no waveform attached.");
+		}
+		const RealType lambda = params::c() / signal->getCarrier();
+
+		Polyline overlap = computeBeamFootprint(trans, lambda, groundZ, time);
+		const Polyline clip = computeBeamFootprint(recv, lambda, groundZ, time);
+
+		// Sutherland-Hodgman: clip the transmit footprint against each edge of the receive footprint
+		for (std::size_t e = 0; e < clip.size() && !overlap.empty(); ++e)
+		{
+			const GroundPoint& a = clip[e];
+			const GroundPoint& b = clip[(e + 1) % clip.size()];
+			Polyline input;
+			input.swap(overlap);
+			for (std::size_t i = 0; i < input.size(); ++i)
+			{
+				const GroundPoint& p = input[i];
+				const GroundPoint& q = input[(i + 1) % input.size()];
+				const RealType side_p = sideOf(a, b, p);
+				const RealType side_q = sideOf(a, b, q);
+				if (side_p >= 0)
+				{
+					overlap.push_back(p);
+				}
+				if ((side_p >= 0) != (side_q >= 0))
+				{
+					const RealType t = side_p / (side_p - side_q);
+					overlap.push_back({p.x + t * (q.x - p.x), p.y + t * (q.y - p.y)});
+				}
+			}
+		}
+
+		if (overlap.size() < 3 || std::abs(signedArea2(overlap)) <= EPSILON)
+		{
+			return {};
+		}
+		return overlap;
+	}
+
+	ResolutionCell computeResolutionCell(const core::World& world, const std::string& txName,
+										 const std::string& targetName, const std::string& rxName, const RealType time)
+	{
+		const auto* trans = world.findTransmitter(txName);
+		if (!trans)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' not found.");
+		}
+		const auto* target = world.findTarget(targetName);
+		if (!target)
+		{
+			throw std::runtime_error("Target '" + targetName + "' not found.");
+		}
+		const auto* recv = world.findReceiver(rxName);
+		if (!recv)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' not found.");
+		}
+		const auto* signal = trans->getSignal();
+		if (!signal)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' has no waveform attached.");
+		}
+		const RealType bandwidth = signal->getRate() / params::oversampleRatio();
+		if (bandwidth <= 0)
+		{
+			throw std::runtime_error("Waveform of transmitter '" + txName + "' has no bandwidth.");
+		}
+		const RealType lambda = params::c() / signal->getCarrier();
+		const Vec3 target_pos = target->getPosition(time);
+
+		// Each beam spans its beamwidth times its range at the target; the narrower one bounds the cell
+		RealType azimuth_extent = std::numeric_limits<RealType>::infinity();
+		RealType elevation_extent = std::numeric_limits<RealType>::infinity();
+		const std::array<const radar::Radar*, 2> radars{trans, recv};
+		for (const auto* radar : radars)
+		{
+			const RealType range = (target_pos - radar->getPhaseCenterPosition(time)).length();
+			const auto [azimuth_width, elevation_width] = azimuthElevationBeamwidths(radar, lambda, time);
+			azimuth_extent = std::min(azimuth_extent, range * azimuth_width);
+			elevation_extent = std::min(elevation_extent, range * elevation_width);
+		}
+
+		const RealType range_resolution = params::c() / (2.0 * bandwidth);
+		return {.range_resolution = range_resolution,
+				.azimuth_extent = azimuth_extent,
+				.elevation_extent = elevation_extent,
+				.volume = range_resolution * azimuth_extent * elevation_extent};
+	}
+
+	RealType computeClutterCellArea(const core::World& world, const std::string& rxName, const std::string& txName,
+									const RealType range, const RealType time)
+	{
+		const auto* recv = world.findReceiver(rxName);
+		if (!recv)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' not found.");
+		}
+		const auto* trans = world.findTransmitter(txName);
+		if (!trans)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' not found.");
+		}
+		const auto* signal = trans->getSignal();
+		if (!signal)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' has no waveform attached.");
+		}
+		if (trans->getMode() != radar::OperationMode::PULSED_MODE)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' is not pulsed.");
+		}
+
+		const RealType height = recv->getPhaseCenterPosition(time).z;
+		if (height <= EPSILON)
+		{
+			throw std::runtime_error("Antenna of '" + rxName + "' is not above the ground plane.");
+		}
+		if (!(range > height))
+		{
+			throw std::invalid_argument("The slant range must exceed the receiver height above the ground.");
+		}
+		const RealType grazing = std::asin(height / range);
+
+		const RealType lambda = params::c() / signal->getCarrier();
+		RealType azimuth_width = std::numeric_limits<RealType>::infinity();
+		RealType elevation_width = std::numeric_limits<RealType>::infinity();
+		const std::array<const radar::Radar*, 2> radars{trans, recv};
+		for (const auto* radar : radars)
+		{
+			const auto [az, el] = azimuthElevationBeamwidths(radar, lambda, time);
+			azimuth_width = std::min(azimuth_width, az);
+			elevation_width = std::min(elevation_width, el);
+		}
+
+		// The patch is as deep as the shorter of the pulse and the beam footprint along the ground
+		const RealType pulse_depth = params::c() * signal->getLength() / 2.0 / std::cos(grazing);
+		const RealType beam_depth = range * elevation_width / std::sin(grazing);
+		return range * azimuth_width * std::min(pulse_depth, beam_depth);
+	}
+}
@@ -0,0 +1,249 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+/**
+ * @file coverage.h
+ * @brief Ground-plane coverage analysis for scenario previews.
+ *
+ * This file declares lightweight analysis routines that evaluate a quantity over a
+ * regular grid on a horizontal ground plane and extract iso-contours from it. They
+ * operate on the current world state without running a simulation and are intended
+ * for UI overlays.
+ */
+
+#pragma once
+
+#include <cstddef>
+#include <string>
+#include <vector>
+
+#include "core/config.h"
+#include "math/geometry_ops.h"
+
+namespace core
+{
+	class World;
+}
+
+namespace radar
+{
+	class Radar;
+	class Receiver;
+	class Transmitter;
+}
+
+namespace simulation
+{
+	/**
+	 * @struct GroundGrid
+	 * @brief A regular sampling grid on a horizontal plane at constant altitude.
+	 */
+	struct GroundGrid
+	{
+		RealType x_min; ///< Minimum X (East) coordinate in meters.
+		RealType x_max; ///< Maximum X (East) coordinate in meters.
+		RealType y_min; ///< Minimum Y (North) coordinate in meters.
+		RealType y_max; ///< Maximum Y (North) coordinate in meters.
+		RealType z; ///< Altitude of the ground plane in meters.
+		std::size_t nx; ///< Number of samples along X (at least 2).
+		std::size_t ny; ///< Number of samples along Y (at least 2).
+
+		/**
+		 * @brief Returns the world position of a grid node.
+		 * @param i The column index along X.
+		 * @param j The row index along Y.
+		 * @return The 3D position of the node.
+		 */
+		[[nodiscard]] math::Vec3 nodePosition(std::size_t i, std::size_t j) const noexcept;
+	};
+
+	/**
+	 * @struct GroundPoint
+	 * @brief A 2D point on the ground plane.
+	 */
+	struct GroundPoint
+	{
+		RealType x; ///< X (East) coordinate in meters.
+		RealType y; ///< Y (North) coordinate in meters.
+	};
+
+	/// An ordered list of ground points forming an open or closed contour line.
+	using Polyline = std::vector<GroundPoint>;
+
+	/**
+	 * @brief Computes the bistatic Doppler shift of a stationary scatterer.
+	 *
+	 * The Doppler shift is the negative rate of change of the total path length
+	 * (Tx -> point -> Rx) divided by the carrier wavelength, using the platform
+	 * velocities of the transmitter and receiver at the given time.
+	 *
+	 * @param trans The transmitter (must have a waveform attached).
+	 * @param recv The receiver.
+	 * @param point The position of the stationary scatterer.
+	 * @param time The simulation time in seconds.
+	 * @return The Doppler shift in Hz.
+	 * @throws std::runtime_error If the transmitter has no waveform.
+	 */
+	[[nodiscard]] RealType computeStationaryDoppler(const radar::Transmitter* trans, const radar::Receiver* recv,
+													const math::Vec3& point, RealType time);
+
+	/**
+	 * @brief Computes the grazing angle at which a platform's line of sight meets the ground.
+	 *
+	 * The grazing angle is the angle between the platform-to-ground-point vector and the
+	 * horizontal plane (flat-earth geometry), so a nadir view gives 90 degrees. When the
+	 * scenario has terrain, the ground point is raised onto the terrain surface wherever the
+	 * height field has data, and the view must clear the terrain.
+	 *
+	 * @param world The simulation world.
+	 * @param platformName The name of the platform.
+	 * @param groundPoint The ground intercept on the plane.
+	 * @param groundZ The altitude of the ground plane in meters, used outside the terrain.
+	 * @param time The simulation time in seconds.
+	 * @return The grazing angle in degrees.
+	 * @throws std::runtime_error If the platform cannot be found, is not above the ground,
+	 *         or the terrain hides the ground point.
+	 */
+	[[nodiscard]] RealType computeGrazingAngle(const core::World& world, const std::string& platformName,
+											   const GroundPoint& groundPoint, RealType groundZ, RealType time);
+
+	/**
+	 * @brief Checks whether two platforms can see each other over the scenario terrain.
+	 *
+	 * Without terrain every pair of platforms is intervisible.
+	 *
+	 * @param world The simulation world.
+	 * @param fromPlatform The name of the first platform.
+	 * @param toPlatform The name of the second platform.
+	 * @param time The simulation time in seconds.
+	 * @return True if the straight path between the platforms clears the terrain.
+	 * @throws std::runtime_error If a platform cannot be found.
+	 */
+	[[nodiscard]] bool hasLineOfSight(const core::World& world, const std::string& fromPlatform,
+									  const std::string& toPlatform, RealType time);
+
+	/**
+	 * @brief Extracts iso-contours from a scalar field sampled on a ground grid.
+	 *
+	 * Uses the marching squares algorithm with linear interpolation along cell edges.
+	 * Saddle cells are disambiguated using the cell-centre average. Segments that share
+	 * an edge crossing are joined into continuous polylines.
+	 *
+	 * @param grid The sampling grid.
+	 * @param values The field values in row-major order (`values[j * nx + i]`).
+	 * @param level The contour level to extract.
+	 * @return The contour polylines. Closed contours repeat their first point at the end.
+	 * @throws std::invalid_argument If the grid is degenerate or the value count does not match.
+	 */
+	[[nodiscard]] std::vector<Polyline> extractContours(const GroundGrid& grid, const std::vector<RealType>& values,
+														RealType level);
+
+	/**
+	 * @brief Computes iso-Doppler contours on the ground for a transmitter/receiver pair.
+	 *
+	 * @param world The simulation world.
+	 * @param txName The name of the transmitter.
+	 * @param rxName The name of the receiver.
+	 * @param dopplerValues The Doppler levels in Hz to extract.
+	 * @param grid The ground sampling grid.
+	 * @param time The simulation time in seconds.
+	 * @return The contour polylines for all requested levels, in level order.
+	 * @throws std::runtime_error If the transmitter or receiver cannot be found.
+	 */
+	[[nodiscard]] std::vector<Polyline> computeIsodopplerContours(const core::World& world, const std::string& txName,
+																  const std::string& rxName,
+																  const std::vector<RealType>& dopplerValues,
+																  const GroundGrid& grid, RealType time);
+
+	/**
+	 * @brief Traces the half-power beam footprint of a radar on a horizontal ground plane.
+	 *
+	 * Rays are cast from the antenna phase center around the boresight. Along each ray
+	 * direction the off-boresight angle at which the gain first drops to half its boresight
+	 * value is found, and that edge ray is intersected with the ground plane.
+	 *
+	 * @param radar The transmitter or receiver whose antenna is traced.
+	 * @param wavelength The wavelength at which the antenna gain is evaluated, in meters.
+	 * @param groundZ The altitude of the ground plane in meters.
+	 * @param time The simulation time in seconds.
+	 * @return The footprint boundary as a counter-clockwise polygon (first point not repeated).
+	 * @throws std::runtime_error If the antenna is not above the ground, has no half-power edge
+	 *         within 90 degrees of boresight, or its beam edge does not reach the ground.
+	 */
+	[[nodiscard]] Polyline computeBeamFootprint(const radar::Radar* radar, RealType wavelength, RealType groundZ,
+												RealType time);
+
+	/**
+	 * @brief Computes the ground region illuminated by a transmitter and seen by a receiver.
+	 *
+	 * Both half-power footprints are traced with `computeBeamFootprint` at the transmitter's
+	 * carrier wavelength and intersected. Footprints are treated as convex, which holds for
+	 * the elliptical sections of single-lobe beams.
+	 *
+	 * @param world The simulation world.
+	 * @param txName The name of the transmitter.
+	 * @param rxName The name of the receiver.
+	 * @param groundZ The altitude of the ground plane in meters.
+	 * @param time The simulation time in seconds.
+	 * @return The overlap as a counter-clockwise polygon, or an empty polygon if the footprints are disjoint.
+	 * @throws std::runtime_error If a component cannot be found, the transmitter has no waveform, or
+	 *         either footprint cannot be traced.
+	 */
+	[[nodiscard]] Polyline computeBistaticFootprintOverlap(const core::World& world, const std::string& txName,
+														   const std::string& rxName, RealType groundZ,
+														   RealType time);
+
+	/**
+	 * @struct ResolutionCell
+	 * @brief Dimensions of the radar resolution cell at a target.
+	 */
+	struct ResolutionCell
+	{
+		RealType range_resolution; ///< Slant range resolution in meters.
+		RealType azimuth_extent; ///< Cross-range extent in the azimuth plane, in meters.
+		RealType elevation_extent; ///< Cross-range extent in the elevation plane, in meters.
+		RealType volume; ///< Cell volume in cubic meters.
+	};
+
+	/**
+	 * @brief Computes the resolution cell of a transmitter/receiver pair at a target.
+	 *
+	 * The range resolution is `c / (2B)` for the waveform bandwidth `B`. Each antenna's
+	 * half-power beamwidths in its azimuth and elevation planes are multiplied by its range to
+	 * the target, and the narrower of the two beams sets each cross-range extent. The volume
+	 * is the product of the three dimensions.
+	 *
+	 * @param world The simulation world.
+	 * @param txName The name of the transmitter.
+	 * @param targetName The name of the target.
+	 * @param rxName The name of the receiver.
+	 * @param time The simulation time in seconds.
+	 * @return The cell dimensions and volume.
+	 * @throws std::runtime_error If a component cannot be found, the waveform has no bandwidth,
+	 *         or an antenna has no half-power beam edge within 90 degrees of boresight.
+	 */
+	[[nodiscard]] ResolutionCell computeResolutionCell(const core::World& world, const std::string& txName,
+													   const std::string& targetName, const std::string& rxName,
+													   RealType time);
+
+	/**
+	 * @brief Computes the area of the ground clutter patch illuminated at a slant range.
+	 *
+	 * The ground is the horizontal plane at zero altitude and the grazing angle `ψ` follows
+	 * from the receiver's height and the range. The patch is `R·θ_az` wide, taking the
+	 * narrower of the transmit and receive azimuth beamwidths. It is as deep as the shorter
+	 * of the pulse-limited extent `cτ / (2 cos ψ)` and the beam-limited extent `R·θ_el / sin ψ`.
+	 *
+	 * @param world The simulation world.
+	 * @param rxName The name of the receiver.
+	 * @param txName The name of the transmitter.
+	 * @param range The slant range to the clutter patch in meters.
+	 * @param time The simulation time in seconds.
+	 * @return The clutter patch area in square meters.
+	 * @throws std::runtime_error If a component cannot be found, the transmitter is not pulsed,
+	 *         the receiver is not above the ground, or an antenna has no half-power beam edge.
+	 * @throws std::invalid_argument If the range does not exceed the receiver height.
+	 */
+	[[nodiscard]] RealType computeClutterCellArea(const core::World& world, const std::string& rxName,
+												  const std::string& txName, RealType range, RealType time);
+}
@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+/**
+ * @file detection.cpp
+ * @brief Implementation of square-law detection probabilities.
+ */
+
+#include "detection.h"
+
+#include <algorithm>
+#include <cmath>
+#include <functional>
+#include <stdexcept>
+
+namespace
+{
+	/// Mixture sums stop once the remaining weight is below this value.
+	constexpr RealType MIXTURE_TOLERANCE = 1e-13;
+
+	/// Hard limit on the number of mixture terms.
+	constexpr std::size_t MAX_MIXTURE_TERMS = 10'000'000;
+
+	/**
+	 * @brief Poisson term `e^-x x^n / n!`, evaluated in the log domain to avoid overflow.
+	 */
+	RealType poissonTerm(const std::size_t n, const RealType x)
+	{
+		if (x <= 0)
+		{
+			return n == 0 ? 1.0 : 0.0;
+		}
+		const auto rn = static_cast<RealType>(n);
+		return std::exp(-x + rn * std::log(x) - std::lgamma(rn + 1));
+	}
+
+	/**
+	 * @brief Regularized upper incomplete gamma function `Q(n, x)` for integer `n`.
+	 */
+	RealType upperGamma(const std::size_t n, const RealType x)
+	{
+		RealType sum = 0;
+		for (std::size_t k = 0; k < n; ++k)
+		{
+			sum += poissonTerm(k, x);
+		}
+		return std::min(sum, 1.0);
+	}
+
+	/**
+	 * @brief Evaluates `sum_j w_j Q(order + j, x)` for a discrete weight distribution.
+	 *
+	 * @param logWeight Returns `ln w_j`; the weights must sum to one.
+	 * @param order The Gamma order of the first mixture term.
+	 * @param x The argument of the incomplete gamma functions.
+	 */
+	RealType gammaMixture(const std::function<RealType(std::size_t)>& logWeight, const std::size_t order,
+						  const RealType x)
+	{
+		RealType q = upperGamma(order, x);
+		RealType result = 0;
+		RealType total_weight = 0;
+		RealType previous_weight = 0;
+		for (std::size_t j = 0; j < MAX_MIXTURE_TERMS && total_weight < 1.0 - MIXTURE_TOLERANCE; ++j)
+		{
+			const RealType weight = std::exp(logWeight(j));
+			result += weight * q;
+			total_weight += weight;
+			q += poissonTerm(order + j, x);
+			// The weights are unimodal; past the mode, negligible terms mean rounding is all that is left.
+			if (weight < previous_weight && weight < MIXTURE_TOLERANCE * MIXTURE_TOLERANCE)
+			{
+				break;
+			}
+			previous_weight = weight;
+		}
+		// Q only increases with its order, so the truncated tail is bounded by the last term.
+		return std::clamp(result + std::max(0.0, 1.0 - total_weight) * std::min(q, 1.0), 0.0, 1.0);
+	}
+
+	/**
+	 * @brief Detection probability when the RCS is drawn once for all pulses.
+	 *
+	 * Conditioned on the RCS the statistic is a Poisson mixture of Gamma variates; averaging
+	 * a Gamma-distributed RCS of shape `dof / 2` turns the Poisson weights into negative
+	 * binomial weights.
+	 */
+	RealType scanToScanProbability(const RealType snr, const RealType threshold, const std::size_t n,
+								   const RealType dof)
+	{
+		const RealType shape = dof / 2;
+		const RealType mean = static_cast<RealType>(n) * snr / shape;
+		const RealType log_p = std::log(mean / (1 + mean));
+		const RealType log_1mp = -std::log1p(mean);
+		return gammaMixture(
+			[&](const std::size_t j)
+			{
+				const auto rj = static_cast<RealType>(j);
+				return std::lgamma(rj + shape) - std::lgamma(shape) - std::lgamma(rj + 1) + rj * log_p +
+					shape * log_1mp;
+			},
+			n, threshold);
+	}
+}
+
+namespace simulation
+{
+	RealType falseAlarmProbability(const RealType threshold, const std::size_t numIntegrated)
+	{
+		if (numIntegrated == 0)
+		{
+			throw std::invalid_argument("At least one pulse must be integrated");
+		}
+		if (threshold < 0)
+		{
+			throw std::invalid_argument("Detection threshold must not be negative");
+		}
+		return upperGamma(numIntegrated, threshold);
+	}
+
+	RealType detectionProbability(const RealType snr, const RealType threshold, const std::size_t numIntegrated,
+								  const SwerlingCase swerling)
+	{
+		if (snr < 0)
+		{
+			throw std::invalid_argument("SNR must not be negative");
+		}
+		const RealType pfa = falseAlarmProbability(threshold, numIntegrated);
+		if (snr == 0)
+		{
+			return pfa;
+		}
+
+		const std::size_t n = numIntegrated;
+		const auto rn = static_cast<RealType>(n);
+		switch (swerling)
+		{
+		case SwerlingCase::SWERLING_0:
+			{
+				// Non-central chi-square: Poisson mixture with mean N * SNR
+				const RealType lambda = rn * snr;
+				return gammaMixture(
+					[lambda](const std::size_t j)
+					{
+						const auto rj = static_cast<RealType>(j);
+						return -lambda + rj * std::log(lambda) - std::lgamma(rj + 1);
+					},
+					n, threshold);
+			}
+		case SwerlingCase::SWERLING_1:
+			return scanToScanProbability(snr, threshold, n, 2);
+		case SwerlingCase::SWERLING_2:
+			// Each pulse is exponential with mean 1 + SNR, so the sum is Gamma(N, 1 + SNR)
+			return upperGamma(n, threshold / (1 + snr));
+		case SwerlingCase::SWERLING_3:
+			return scanToScanProbability(snr, threshold, n, 4);
+		case SwerlingCase::SWERLING_4:
+			{
+				// The per-pulse transform (1 + s) / (1 + s b)^2 with b = 1 + SNR / 2 expands into a
+				// binomial mixture of Gamma(2N - k, b) variates, k = 0..N, with success probability 1 / b.
+				const RealType scale = 1 + snr / 2;
+				const RealType log_p = -std::log(scale);
+				const RealType log_1mp = std::log1p(-1 / scale);
+				const RealType x = threshold / scale;
+				RealType q = upperGamma(n, x);
+				RealType result = 0;
+				for (std::size_t i = 0; i <= n; ++i)
+				{
+					// Gamma order n + i corresponds to k = n - i
+					const auto rk = static_cast<RealType>(n - i);
+					const RealType log_weight = std::lgamma(rn + 1) - std::lgamma(rk + 1) - std::lgamma(rn - rk + 1) +
+						rk * log_p + (rn - rk) * log_1mp;
+					result += std::exp(log_weight) * q;
+					q += poissonTerm(n + i, x);
+				}
+				return std::clamp(result, 0.0, 1.0);
+			}
+		}
+		return pfa;
+	}
+
+	RealType pulseCompressionGain(const RealType bandwidth, const RealType duration)
+	{
+		if (!(bandwidth > 0) || !(duration > 0) || !std::isfinite(bandwidth) || !std::isfinite(duration))
+		{
+			throw std::invalid_argument("Pulse bandwidth and duration must be positive and finite.");
+		}
+		return 10.0 * std::log10(bandwidth * duration);
+	}
+}
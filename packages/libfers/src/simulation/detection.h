@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+/**
+ * @file detection.h
+ * @brief Detection and false-alarm probabilities of a square-law detector, and matched-filter gain.
+ */
+
+#pragma once
+
+#include <cstddef>
+
+#include "core/config.h"
+
+namespace simulation
+{
+	/**
+	 * @brief Swerling target fluctuation cases.
+	 */
+	enum class SwerlingCase
+	{
+		SWERLING_0, ///< Non-fluctuating target.
+		SWERLING_1, ///< Exponential RCS (chi-square, 2 DOF), constant over the integrated pulses.
+		SWERLING_2, ///< Exponential RCS, independent from pulse to pulse.
+		SWERLING_3, ///< Chi-square RCS with 4 DOF, constant over the integrated pulses.
+		SWERLING_4 ///< Chi-square RCS with 4 DOF, independent from pulse to pulse.
+	};
+
+	/**
+	 * @brief Calculates the false-alarm probability of non-coherently integrated pulses.
+	 *
+	 * The detector sums the squared magnitudes of `numIntegrated` complex noise samples,
+	 * each normalized to unit noise power, and compares the sum against `threshold`. The
+	 * sum is Gamma distributed, so `Pfa = Q(N, T)`, the regularized upper incomplete gamma
+	 * function.
+	 *
+	 * @param threshold The detection threshold relative to the single-pulse noise power (linear).
+	 * @param numIntegrated The number of integrated pulses (at least 1).
+	 * @return The probability of false alarm.
+	 * @throws std::invalid_argument If `numIntegrated` is zero or the threshold is negative.
+	 */
+	[[nodiscard]] RealType falseAlarmProbability(RealType threshold, std::size_t numIntegrated);
+
+	/**
+	 * @brief Calculates the detection probability of non-coherently integrated pulses.
+	 *
+	 * Uses the same detector as `falseAlarmProbability`. The integrated statistic is a
+	 * mixture of Gamma variates: Poisson-weighted for a non-fluctuating target, negative
+	 * binomial weighted for the scan-to-scan cases (1 and 3) and binomial weighted for
+	 * Swerling 4, while Swerling 2 is a single Gamma variate. The results are exact up to
+	 * the truncation of the mixture sums.
+	 *
+	 * @param snr The average single-pulse SNR (linear).
+	 * @param threshold The detection threshold relative to the single-pulse noise power (linear).
+	 * @param numIntegrated The number of integrated pulses (at least 1).
+	 * @param swerling The target fluctuation case.
+	 * @return The probability of detection.
+	 * @throws std::invalid_argument If `numIntegrated` is zero or the SNR or threshold is negative.
+	 */
+	[[nodiscard]] RealType detectionProbability(RealType snr, RealType threshold, std::size_t numIntegrated,
+												SwerlingCase swerling);
+
+	/**
+	 * @brief Calculates the SNR gain of matched filtering a linear FM pulse.
+	 *
+	 * Compressing a chirp of bandwidth `B` and duration `tau` raises the peak SNR by the
+	 * time-bandwidth product, so the gain is `10 log10(B tau)`.
+	 *
+	 * @param bandwidth The swept bandwidth in Hz.
+	 * @param duration The pulse duration in seconds.
+	 * @return The pulse compression gain in dB.
+	 * @throws std::invalid_argument If the bandwidth or duration is not positive and finite.
+	 */
+	[[nodiscard]] RealType pulseCompressionGain(RealType bandwidth, RealType duration);
+}
@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+/**
+ * @file trajectory_analysis.cpp
+ * @brief Implementation of trajectory comparison and synthetic aperture routines.
+ */
+
+#include "trajectory_analysis.h"
+
+#include <algorithm>
+#include <cmath>
+#include <limits>
+#include <stdexcept>
+#include <utility>
+
+#include "core/parameters.h"
+#include "core/world.h"
+#include "math/path.h"
+#include "radar/platform.h"
+#include "radar/receiver.h"
+#include "radar/target.h"
+#include "radar/transmitter.h"
+#include "signal/radar_signal.h"
+
+namespace
+{
+	/**
+	 * @brief Returns the time span covered by a path's waypoints.
+	 *
+	 * Static and single-waypoint paths hold their position indefinitely and therefore
+	 * cover all times.
+	 */
+	std::pair<RealType, RealType> pathSpan(const math::Path& path)
+	{
+		const auto& coords = path.getCoords();
+		if (path.getType() == math::Path::InterpType::INTERP_STATIC || coords.size() < 2)
+		{
+			return {-std::numeric_limits<RealType>::infinity(), std::numeric_limits<RealType>::infinity()};
+		}
+		return {coords.front().t, coords.back().t};
+	}
+
+	/// Number of segments used to integrate the synthetic aperture length.
+	constexpr std::size_t SAR_APERTURE_SEGMENTS = 1024;
+}
+
+namespace simulation
+{
+	TrajectoryError compareTrajectory(const core::World& world, const std::string& platformName,
+									  const std::vector<math::Coord>& reference, const std::size_t numPoints)
+	{
+		const auto* platform = world.findPlatform(platformName);
+		if (!platform)
+		{
+			throw std::runtime_error("Platform '" + platformName + "' not found.");
+		}
+		if (reference.empty())
+		{
+			throw std::invalid_argument("Reference trajectory must contain at least one point.");
+		}
+		if (numPoints == 0)
+		{
+			throw std::invalid_argument("Trajectory comparison requires at least one sample.");
+		}
+
+		math::Path ref_path(math::Path::InterpType::INTERP_LINEAR);
+		for (const auto& coord : reference)
+		{
+			ref_path.addCoord(coord);
+		}
+		ref_path.finalize();
+
+		const auto [plat_start, plat_end] = pathSpan(*platform->getMotionPath());
+		const auto [ref_start, ref_end] = pathSpan(ref_path);
+		RealType start = std::max(plat_start, ref_start);
+		RealType end = std::min(plat_end, ref_end);
+		if (!std::isfinite(start) && !std::isfinite(end))
+		{
+			// Both paths are static, so a single comparison at the reference time suffices.
+			start = end = ref_path.getCoords().front().t;
+		}
+		if (start > end)
+		{
+			throw std::runtime_error("The platform path and reference trajectory do not overlap in time.");
+		}
+
+		RealType sum_sq = 0;
+		RealType max_error = 0;
+		for (std::size_t i = 0; i < numPoints; ++i)
+		{
+			const RealType t =
+				numPoints > 1 ? start + (end - start) * static_cast<RealType>(i) / static_cast<RealType>(numPoints - 1)
+							  : start;
+			const RealType error = (platform->getPosition(t) - ref_path.getPosition(t)).length();
+			sum_sq += error * error;
+			max_error = std::max(max_error, error);
+		}
+
+		return {.rms_error = std::sqrt(sum_sq / static_cast<RealType>(numPoints)),
+				.max_error = max_error,
+				.start_time = start,
+				.end_time = end,
+				.sample_count = numPoints};
+	}
+
+	SarResolution calculateSarResolution(const core::World& world, const std::string& txName,
+										 const std::string& targetName, const std::string& rxName)
+	{
+		const auto* tx = world.findTransmitter(txName);
+		if (!tx)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' not found.");
+		}
+		const auto* target = world.findTarget(targetName);
+		if (!target)
+		{
+			throw std::runtime_error("Target '" + targetName + "' not found.");
+		}
+		const auto* rx = world.findReceiver(rxName);
+		if (!rx)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' not found.");
+		}
+		const auto* waveform = tx->getSignal();
+		if (!waveform)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' has no waveform attached.");
+		}
+		const RealType bandwidth = waveform->getRate() / params::oversampleRatio();
+		if (bandwidth <= 0)
+		{
+			throw std::runtime_error("Waveform of transmitter '" + txName + "' has no bandwidth.");
+		}
+
+		RealType start = params::startTime();
+		RealType end = params::endTime();
+		if (const auto& schedule = tx->getSchedule(); !schedule.empty())
+		{
+			start = std::max(start, schedule.front().start);
+			end = std::min(end, schedule.back().end);
+		}
+		if (start >= end)
+		{
+			throw std::runtime_error("Transmitter '" + txName + "' is not active during the simulation.");
+		}
+
+		const auto phase_centre = [&](const RealType t) { return (tx->getPosition(t) + rx->getPosition(t)) / 2.0; };
+		RealType aperture = 0;
+		math::Vec3 previous = phase_centre(start);
+		for (std::size_t i = 1; i <= SAR_APERTURE_SEGMENTS; ++i)
+		{
+			const RealType t =
+				start + (end - start) * static_cast<RealType>(i) / static_cast<RealType>(SAR_APERTURE_SEGMENTS);
+			const math::Vec3 current = phase_centre(t);
+			aperture += (current - previous).length();
+			previous = current;
+		}
+		if (aperture <= 0)
+		{
+			throw std::runtime_error("The radar phase centre does not move during illumination.");
+		}
+
+		const RealType mid = (start + end) / 2.0;
+		const RealType range = (target->getPosition(mid) - phase_centre(mid)).length();
+		const RealType wavelength = params::c() / waveform->getCarrier();
+
+		return {.synthetic_aperture = aperture,
+				.slant_range = range,
+				.cross_range_resolution = wavelength * range / (2.0 * aperture),
+				.range_resolution = params::c() / (2.0 * bandwidth),
+				.start_time = start,
+				.end_time = end};
+	}
+}
@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+/**
+ * @file trajectory_analysis.h
+ * @brief Comparison of platform motion paths against reference trajectories and synthetic aperture geometry.
+ */
+
+#pragma once
+
+#include <cstddef>
+#include <string>
+#include <vector>
+
+#include "core/config.h"
+#include "math/coord.h"
+
+namespace core
+{
+	class World;
+}
+
+namespace simulation
+{
+	/**
+	 * @struct TrajectoryError
+	 * @brief Position error statistics between a platform path and a reference trajectory.
+	 */
+	struct TrajectoryError
+	{
+		RealType rms_error; ///< Root-mean-square position error in meters.
+		RealType max_error; ///< Maximum position error in meters.
+		RealType start_time; ///< Start of the compared time span in seconds.
+		RealType end_time; ///< End of the compared time span in seconds.
+		std::size_t sample_count; ///< Number of matched time samples.
+	};
+
+	/**
+	 * @brief Compares a platform's motion path against a reference trajectory.
+	 *
+	 * The reference is linearly interpolated between its points. Both paths are sampled at
+	 * `numPoints` evenly spaced times over the intersection of their time spans; static
+	 * paths are treated as covering all times.
+	 *
+	 * @param world The simulation world.
+	 * @param platformName The name of the platform to evaluate.
+	 * @param reference The reference trajectory points (in any time order).
+	 * @param numPoints The number of time samples (at least 1).
+	 * @return The error statistics over the matched samples.
+	 * @throws std::runtime_error If the platform is missing or the time spans do not overlap.
+	 * @throws std::invalid_argument If the reference is empty or `numPoints` is zero.
+	 */
+	[[nodiscard]] TrajectoryError compareTrajectory(const core::World& world, const std::string& platformName,
+													const std::vector<math::Coord>& reference, std::size_t numPoints);
+
+	/**
+	 * @struct SarResolution
+	 * @brief Synthetic aperture geometry and the resulting image resolution.
+	 */
+	struct SarResolution
+	{
+		RealType synthetic_aperture; ///< Distance travelled by the phase centre during illumination, in meters.
+		RealType slant_range; ///< Phase centre to target range at mid-illumination, in meters.
+		RealType cross_range_resolution; ///< Azimuth resolution in meters.
+		RealType range_resolution; ///< Slant range resolution in meters.
+		RealType start_time; ///< Start of the illumination interval in seconds.
+		RealType end_time; ///< End of the illumination interval in seconds.
+	};
+
+	/**
+	 * @brief Calculates the cross-range and range resolution of a SAR collection.
+	 *
+	 * The phase centre is the midpoint between the transmitter and receiver, so a monostatic
+	 * radar uses its own position. The target is illuminated over the simulation time span,
+	 * narrowed to the transmitter's schedule when it has one. The synthetic aperture is the
+	 * path length of the phase centre over that interval, giving a cross-range resolution of
+	 * `λR / (2L)`. The range resolution is `c / (2B)`, where `B` is the waveform bandwidth.
+	 *
+	 * @param world The simulation world containing radar components.
+	 * @param txName The name of the transmitter.
+	 * @param targetName The name of the imaged target.
+	 * @param rxName The name of the receiver.
+	 * @return The aperture geometry and resolutions.
+	 * @throws std::runtime_error If a component is missing, the waveform has no bandwidth, or
+	 *         the phase centre does not move during illumination.
+	 */
+	[[nodiscard]] SarResolution calculateSarResolution(const core::World& world, const std::string& txName,
+													   const std::string& targetName, const std::string& rxName);
+}
@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+/**
+ * @file phase_noise.cpp
+ * @brief Implementation of timing phase-noise evaluation and synthesis.
+ */
+
+#include "phase_noise.h"
+
+#include <algorithm>
+#include <cmath>
+#include <random>
+#include <stdexcept>
+
+#include "core/parameters.h"
+#include "core/world.h"
+#include "radar/receiver.h"
+#include "timing/prototype_timing.h"
+#include "timing/timing.h"
+
+namespace timing
+{
+	RealType phaseNoisePsd(const PrototypeTiming& timing, const RealType frequency) noexcept
+	{
+		std::vector<RealType> alphas;
+		std::vector<RealType> weights;
+		timing.copyAlphas(alphas, weights);
+
+		RealType psd = 0;
+		for (std::size_t i = 0; i < alphas.size(); ++i)
+		{
+			psd += weights[i] * weights[i] * std::pow(frequency, -alphas[i]);
+		}
+		return psd;
+	}
+
+	RealType integratePhaseNoisePsd(const PrototypeTiming& timing, const RealType lowFrequency,
+									const RealType highFrequency) noexcept
+	{
+		if (highFrequency <= lowFrequency)
+		{
+			return 0;
+		}
+
+		std::vector<RealType> alphas;
+		std::vector<RealType> weights;
+		timing.copyAlphas(alphas, weights);
+
+		RealType power = 0;
+		for (std::size_t i = 0; i < alphas.size(); ++i)
+		{
+			// Closed form of the integral of f^-alpha, with the logarithmic case at alpha = 1
+			const RealType exponent = 1 - alphas[i];
+			const RealType integral = std::abs(exponent) < EPSILON
+				? std::log(highFrequency / lowFrequency)
+				: (std::pow(highFrequency, exponent) - std::pow(lowFrequency, exponent)) / exponent;
+			power += weights[i] * weights[i] * integral;
+		}
+		return power;
+	}
+
+	std::vector<RealType> synthesizePhaseNoise(const PrototypeTiming& timing, const RealType sampleRate,
+											   const std::size_t count, const unsigned seed)
+	{
+		if (sampleRate <= 0)
+		{
+			throw std::invalid_argument("Phase-noise sample rate must be positive.");
+		}
+
+		std::vector<RealType> series(count, 0.0);
+		if (count < 3)
+		{
+			return series;
+		}
+
+		// Sinusoids on the DFT grid are orthogonal over the series, so its variance is
+		// exactly the sum of the per-bin powers.
+		std::vector<RealType> cos_table(count);
+		std::vector<RealType> sin_table(count);
+		for (std::size_t m = 0; m < count; ++m)
+		{
+			const RealType angle = 2 * PI * static_cast<RealType>(m) / static_cast<RealType>(count);
+			cos_table[m] = std::cos(angle);
+			sin_table[m] = std::sin(angle);
+		}
+
+		std::mt19937 rng(seed);
+		std::uniform_real_distribution phase_dist(0.0, 2 * PI);
+		const RealType bin_width = sampleRate / static_cast<RealType>(count);
+		const std::size_t highest_bin = (count - 1) / 2;
+		for (std::size_t k = 1; k <= highest_bin; ++k)
+		{
+			const RealType bin_power = integratePhaseNoisePsd(timing, (static_cast<RealType>(k) - 0.5) * bin_width,
+															  (static_cast<RealType>(k) + 0.5) * bin_width);
+			const RealType amplitude = std::sqrt(2 * bin_power);
+			const RealType phase = phase_dist(rng);
+			const RealType cos_phase = amplitude * std::cos(phase);
+			const RealType sin_phase = amplitude * std::sin(phase);
+			for (std::size_t n = 0; n < count; ++n)
+			{
+				const std::size_t m = k * n % count;
+				series[n] += cos_table[m] * cos_phase - sin_table[m] * sin_phase;
+			}
+		}
+		return series;
+	}
+
+	std::vector<RealType> previewReceiverPhaseNoise(const core::World& world, const std::string& rxName,
+													const RealType duration)
+	{
+		if (!(duration > 0))
+		{
+			throw std::invalid_argument("Phase-noise preview duration must be positive.");
+		}
+
+		const auto* rx = world.findReceiver(rxName);
+		if (!rx)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' not found.");
+		}
+		const auto rx_timing = rx->getTiming();
+		if (!rx_timing)
+		{
+			throw std::runtime_error("Receiver '" + rxName + "' has no timing source.");
+		}
+		const auto& timings = world.getTimings();
+		const auto it = timings.find(rx_timing->getName());
+		if (it == timings.end())
+		{
+			throw std::runtime_error("Timing source '" + rx_timing->getName() + "' not found.");
+		}
+
+		const RealType samples = std::ceil(duration * params::rate());
+		const auto count = static_cast<std::size_t>(
+			std::clamp(samples, 1.0, static_cast<RealType>(MAX_PHASE_NOISE_PREVIEW_SAMPLES)));
+		return synthesizePhaseNoise(*it->second, static_cast<RealType>(count) / duration, count,
+									params::randomSeed());
+	}
+}
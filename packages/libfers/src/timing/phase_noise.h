@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: GPL-2.0-only
+// Copyright (c) 2025-present FERS Contributors (see AUTHORS.md).
+
+/**
+ * @file phase_noise.h
+ * @brief Spectral evaluation and time-domain synthesis of timing phase noise.
+ *
+ * A timing source's noise entries describe a power-law phase-noise spectrum
+ * `S(f) = sum_i weight_i^2 * f^-alpha_i` in rad^2/Hz (single-sided). The routines here
+ * evaluate that spectrum and synthesize seeded time series from it for UI previews.
+ * They work from the nominal spectrum and do not reproduce the sample stream of the
+ * multirate generator used during simulation.
+ */
+
+#pragma once
+
+#include <cstddef>
+#include <string>
+#include <vector>
+
+#include "core/config.h"
+
+namespace core
+{
+	class World;
+}
+
+namespace timing
+{
+	class PrototypeTiming;
+
+	/**
+	 * @brief Evaluates the single-sided phase-noise PSD of a timing source.
+	 *
+	 * @param timing The timing source whose noise entries define the spectrum.
+	 * @param frequency The offset frequency in Hz. Must be positive.
+	 * @return The PSD in rad^2/Hz, or 0 if the timing source has no noise entries.
+	 */
+	[[nodiscard]] RealType phaseNoisePsd(const PrototypeTiming& timing, RealType frequency) noexcept;
+
+	/**
+	 * @brief Integrates the phase-noise PSD of a timing source over a frequency band.
+	 *
+	 * @param timing The timing source whose noise entries define the spectrum.
+	 * @param lowFrequency The lower band edge in Hz. Must be positive.
+	 * @param highFrequency The upper band edge in Hz.
+	 * @return The phase variance contributed by the band in rad^2.
+	 */
+	[[nodiscard]] RealType integratePhaseNoisePsd(const PrototypeTiming& timing, RealType lowFrequency,
+												  RealType highFrequency) noexcept;
+
+	/**
+	 * @brief Synthesizes a zero-mean phase-noise time series from a timing source's PSD.
+	 *
+	 * The series is a sum of sinusoids on the `sampleRate / count` frequency grid with
+	 * seeded random phases. Each bin carries the PSD integrated over its width, so the
+	 * variance of the series equals the PSD integrated from half a bin up to the highest
+	 * bin below Nyquist.
+	 *
+	 * @param timing The timing source whose noise entries define the spectrum.
+	 * @param sampleRate The sample rate of the series in Hz.
+	 * @param count The number of samples to generate.
+	 * @param seed The seed for the random phases.
+	 * @return The phase noise in radians, one value per sample.
+	 * @throws std::invalid_argument If the sample rate is not positive.
+	 */
+	[[nodiscard]] std::vector<RealType> synthesizePhaseNoise(const PrototypeTiming& timing, RealType sampleRate,
+															 std::size_t count, unsigned seed);
+
+	/**
+	 * @brief Previews the phase noise a receiver's timing source adds to its downconversion.
+	 *
+	 * The series is sampled at the simulation rate, lowered when needed to keep the
+	 * preview at most `MAX_PHASE_NOISE_PREVIEW_SAMPLES` long, and seeded with the
+	 * scenario's random seed.
+	 *
+	 * @param world The world containing the receiver.
+	 * @param rxName The name of the receiver.
+	 * @param duration The length of the preview in seconds. Must be positive.
+	 * @return The phase noise in radians, evenly spaced over `duration`.
+	 * @throws std::runtime_error If the receiver or its timing source is not found.
+	 * @throws std::invalid_argument If the duration is not positive.
+	 */
+	[[nodiscard]] std::vector<RealType> previewReceiverPhaseNoise(const core::World& world, const std::string& rxName,
+																  RealType duration);
+
+	/// Upper bound on the number of samples returned by `previewReceiverPhaseNoise`.
+	constexpr std::size_t MAX_PHASE_NOISE_PREVIEW_SAMPLES = 4096;
+}